@@ -1,30 +1,42 @@
-use std::fmt;
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use anyhow::Context;
 use clap::{Args, Parser, Subcommand, ValueEnum};
-use datamize_domain::{secrecy::Secret, EncryptedPassword, Uuid, WebScrapingAccount};
-use datamize_server::services::budget_providers::{
-    ExternalAccountService, ExternalAccountServiceExt,
+use datamize_domain::{
+    secrecy::Secret, BalanceSource, EncryptedPassword, ImportPlan, Utc, Uuid, WebScrapingAccount,
+};
+use datamize_server::{
+    config::StatementSettings,
+    registry::get_repo_registry,
+    services::{
+        backup::{BackupService, BackupServiceExt},
+        balance_sheet::{read_csv, BalanceSheetImportService, BalanceSheetImportServiceExt},
+        budget_providers::{
+            decode_base32, read_statement, ExternalAccountService, ExternalAccountServiceExt,
+            PendingChallenges, ScraperRegistry,
+        },
+    },
 };
-use db_postgres::budget_providers::external::PostgresExternalAccountRepo;
 use db_postgres::Error;
-use db_redis::budget_providers::external::RedisEncryptionKeyRepo;
 use orion::aead;
 use orion::kex::SecretKey;
+use ynab::Milliunits;
 
 /// Simple program to quickly perform some operations
 /// on some Datamize functionnality without a GUI.
 /// In this case, it can be used to create or update some
-/// web scrapping accounts.
+/// web scrapping accounts, to update their balance from a statement,
+/// to backup and restore all the data, or to import the history of the balance sheet.
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
-
-    /// Name of the account
-    name: String,
 }
 
 #[derive(Subcommand, Debug)]
@@ -33,10 +45,23 @@ enum Commands {
     Create(CreateArgs),
     /// Update an existing external account
     Update(UpdateArgs),
+    /// List the scrapers external accounts can use
+    Scrapers,
+    /// Write a backup of all the data to a file
+    Backup(BackupArgs),
+    /// Restore a backup in a database holding no data yet
+    Restore(RestoreArgs),
+    /// Import historical balances of the balance sheet from a CSV file
+    Import(ImportArgs),
+    /// Update the balance of an external account from an OFX, QFX or CSV statement
+    ImportStatement(ImportStatementArgs),
 }
 
 #[derive(Args, Debug)]
 struct CreateArgs {
+    /// Name of the account
+    name: String,
+
     /// The username to connect to the account
     #[arg(short, long)]
     username: String,
@@ -50,12 +75,25 @@ struct CreateArgs {
     account_type: AccountType,
 
     /// A starting balance to use for the account
-    #[arg(short, long, default_value_t = 0.0)]
-    balance: f32,
+    #[arg(short, long, default_value_t = Milliunits::ZERO)]
+    balance: Milliunits,
+
+    /// The id of the scraper getting the balance of the account. See the `scrapers` command.
+    /// The balance is never refreshed without one.
+    #[arg(short, long)]
+    scraper: Option<String>,
+
+    /// The base32 seed of the authenticator app, for institutions asking for a one-time code.
+    /// Will be encrypted as soon as received. Without one, the code is asked through the API.
+    #[arg(long)]
+    totp_seed: Option<String>,
 }
 
 #[derive(Args, Debug)]
 struct UpdateArgs {
+    /// Name of the account
+    name: String,
+
     /// The username to connect to the account
     #[arg(short, long)]
     username: Option<String>,
@@ -70,7 +108,81 @@ struct UpdateArgs {
 
     /// A new balance to use for the account
     #[arg(short, long)]
-    balance: Option<f32>,
+    balance: Option<Milliunits>,
+
+    /// The id of the scraper the account should now use. See the `scrapers` command.
+    #[arg(short, long)]
+    scraper: Option<String>,
+
+    /// The base32 seed of the authenticator app the account should now use.
+    /// Will be encrypted as soon as received.
+    #[arg(long, conflicts_with = "remove_totp_seed")]
+    totp_seed: Option<String>,
+
+    /// Forget the seed of the authenticator app, to ask the one-time codes through the API.
+    #[arg(long)]
+    remove_totp_seed: bool,
+}
+
+#[derive(Args, Debug)]
+struct BackupArgs {
+    /// The file to write the backup to. Written to the standard output if missing.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Also include the encryption key of the passwords, to use them in another database
+    #[arg(long)]
+    include_encryption_key: bool,
+}
+
+#[derive(Args, Debug)]
+struct RestoreArgs {
+    /// The file holding the backup
+    input: PathBuf,
+}
+
+#[derive(Args, Debug)]
+struct ImportArgs {
+    /// The CSV file with the name and type of each resource followed by one column per month,
+    /// i.e. `name,type,2015-01,2015-02`
+    input: PathBuf,
+
+    /// Only show the changes the import would make, without saving them
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+struct ImportStatementArgs {
+    /// Name of the account
+    name: String,
+
+    /// The statement downloaded from the institution
+    input: PathBuf,
+
+    /// The format of the statement. Guessed from the extension of the file when missing.
+    #[arg(short, long)]
+    format: Option<StatementFormat>,
+
+    /// The name of one of the CSV layouts configured, needed for CSV statements
+    #[arg(short, long)]
+    layout: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum StatementFormat {
+    /// OFX or QFX
+    Ofx,
+    Csv,
+}
+
+impl From<StatementFormat> for datamize_domain::StatementFormat {
+    fn from(value: StatementFormat) -> Self {
+        match value {
+            StatementFormat::Ofx => datamize_domain::StatementFormat::Ofx,
+            StatementFormat::Csv => datamize_domain::StatementFormat::Csv,
+        }
+    }
 }
 
 #[derive(Debug, Clone, ValueEnum, Default)]
@@ -102,22 +214,54 @@ async fn main() -> anyhow::Result<()> {
     let args = Cli::parse();
 
     let configuration = datamize_server::config::Settings::build()?;
-    let db_conn_pool = db_postgres::get_connection_pool(configuration.database.with_db());
-    let redis_conn_pool = db_redis::get_connection_pool(&configuration.redis.connection_string())
+    let repos = get_repo_registry(&configuration)
         .await
-        .context("failed to get redis connection manager")?;
+        .context("failed to connect to the database")?;
 
+    let scraper_registry = Arc::new(ScraperRegistry::load(
+        configuration
+            .webdriver
+            .recipes_directory
+            .as_deref()
+            .map(Path::new),
+    ));
     let external_account_service = ExternalAccountService {
-        external_account_repo: PostgresExternalAccountRepo::new_arced(db_conn_pool),
-        encryption_key_repo: RedisEncryptionKeyRepo::new_arced(redis_conn_pool),
+        external_account_repo: repos.external_account_repo(),
+        encryption_key_repo: repos.encryption_key_repo(),
+        external_account_refresh_repo: repos.external_account_refresh_repo(),
+        external_account_balance_repo: repos.external_account_balance_repo(),
+        pending_challenges: PendingChallenges::default(),
+        scraper_registry: scraper_registry.clone(),
+        webdriver_location: configuration.webdriver.connection_string(),
     };
 
+    let import_service = BalanceSheetImportService::new_arced(
+        repos.fin_res_repo(),
+        repos.month_repo(),
+        repos.year_repo(),
+    );
+    let backup_service = BackupService::new_arced(repos);
+
     match args.command {
         Commands::Create(create_args) => {
-            create_account(&external_account_service, args.name, create_args).await?
+            create_account(&external_account_service, &scraper_registry, create_args).await?
         }
         Commands::Update(updated_args) => {
-            update_account(&external_account_service, args.name, updated_args).await?
+            update_account(&external_account_service, &scraper_registry, updated_args).await?
+        }
+        Commands::Scrapers => list_scrapers(&scraper_registry),
+        Commands::Backup(backup_args) => backup(backup_service.as_ref(), backup_args).await?,
+        Commands::Restore(restore_args) => restore(backup_service.as_ref(), restore_args).await?,
+        Commands::Import(import_args) => {
+            import_balance_sheet(import_service.as_ref(), import_args).await?
+        }
+        Commands::ImportStatement(import_statement_args) => {
+            import_statement(
+                &external_account_service,
+                &configuration.statements,
+                import_statement_args,
+            )
+            .await?
         }
     }
 
@@ -126,28 +270,42 @@ async fn main() -> anyhow::Result<()> {
 
 async fn create_account(
     external_account_service: &impl ExternalAccountServiceExt,
-    name: String,
+    scraper_registry: &ScraperRegistry,
     args: CreateArgs,
 ) -> anyhow::Result<()> {
-    let encryption_key = get_encryption_key(external_account_service).await?;
+    if let Some(scraper) = &args.scraper {
+        check_scraper(scraper_registry, scraper)?;
+    }
+    let encryption_key = external_account_service
+        .get_or_create_encryption_key()
+        .await?;
     let encrypted_password = Secret::new(EncryptedPassword::new(aead::seal(
         &encryption_key,
         args.password.as_bytes(),
     )?));
+    let encrypted_totp_seed = args
+        .totp_seed
+        .map(|seed| seal_totp_seed(&encryption_key, &seed))
+        .transpose()?;
 
     let account = WebScrapingAccount {
         id: Uuid::new_v4(),
-        name,
+        name: args.name,
         account_type: args.account_type.to_string().parse().unwrap(),
-        balance: (args.balance * 1000_f32) as i64,
+        balance: args.balance,
         username: args.username,
         encrypted_password,
         deleted: false,
+        scraper_id: args.scraper,
+        encrypted_totp_seed,
     };
 
     external_account_service
         .create_external_account(&account)
         .await?;
+    external_account_service
+        .add_balance(account.id, account.balance, Utc::now(), BalanceSource::Cli)
+        .await?;
     println!("Successfully created {:?}", account.name);
 
     Ok(())
@@ -155,23 +313,25 @@ async fn create_account(
 
 async fn update_account(
     external_account_service: &impl ExternalAccountServiceExt,
-    name: String,
+    scraper_registry: &ScraperRegistry,
     args: UpdateArgs,
 ) -> anyhow::Result<()> {
     // check if  account exists
     let Ok(mut account) = external_account_service
-        .get_external_account_by_name(&name)
+        .get_external_account_by_name(&args.name)
         .await
     else {
         return Err::<(), anyhow::Error>(Error::RowNotFound.into())
-            .with_context(|| format!("Account {} does not exist", name));
+            .with_context(|| format!("Account {} does not exist", args.name));
     };
 
     if let Some(username) = args.username {
         account.username = username;
     }
     if let Some(password) = args.password {
-        let encryption_key = get_encryption_key(external_account_service).await?;
+        let encryption_key = external_account_service
+            .get_or_create_encryption_key()
+            .await?;
         let encrypted_password = Secret::new(EncryptedPassword::new(aead::seal(
             &encryption_key,
             password.as_bytes(),
@@ -182,38 +342,190 @@ async fn update_account(
         account.account_type = account_type.to_string().parse().unwrap();
     }
     if let Some(balance) = args.balance {
-        account.balance = (balance * 1000_f32) as i64;
+        account.balance = balance;
+    }
+    if let Some(scraper) = args.scraper {
+        check_scraper(scraper_registry, &scraper)?;
+        account.scraper_id = Some(scraper);
+    }
+    if let Some(seed) = args.totp_seed {
+        let encryption_key = external_account_service
+            .get_or_create_encryption_key()
+            .await?;
+        account.encrypted_totp_seed = Some(seal_totp_seed(&encryption_key, &seed)?);
+    }
+    if args.remove_totp_seed {
+        account.encrypted_totp_seed = None;
     }
 
     external_account_service
         .update_external_account(&account)
         .await?;
+    if let Some(balance) = args.balance {
+        external_account_service
+            .add_balance(account.id, balance, Utc::now(), BalanceSource::Cli)
+            .await?;
+    }
     println!("Successfully updated {:?}", account.name);
 
     Ok(())
 }
 
-async fn get_encryption_key(
-    external_account_service: &impl ExternalAccountServiceExt,
-) -> anyhow::Result<SecretKey> {
-    Ok(match external_account_service.get_encryption_key().await {
-        Ok(ref val) => {
-            if !val.is_empty() {
-                SecretKey::from_slice(val).unwrap()
-            } else {
-                let key = SecretKey::default();
-                external_account_service
-                    .set_encryption_key(key.unprotected_as_bytes())
-                    .await?;
-                key
-            }
-        }
-        Err(_) => {
-            let key = SecretKey::default();
-            external_account_service
-                .set_encryption_key(key.unprotected_as_bytes())
-                .await?;
-            key
+async fn backup(backup_service: &impl BackupServiceExt, args: BackupArgs) -> anyhow::Result<()> {
+    let backup = backup_service.backup(args.include_encryption_key).await?;
+    let backup = serde_json::to_string_pretty(&backup)?;
+
+    match args.output {
+        Some(output) => {
+            fs::write(&output, backup)
+                .with_context(|| format!("failed to write the backup to {:?}", output))?;
+            eprintln!("Successfully wrote the backup to {:?}", output);
         }
-    })
+        None => println!("{}", backup),
+    }
+
+    Ok(())
+}
+
+async fn restore(backup_service: &impl BackupServiceExt, args: RestoreArgs) -> anyhow::Result<()> {
+    let backup = fs::read_to_string(&args.input)
+        .with_context(|| format!("failed to read the backup from {:?}", args.input))?;
+    let backup = serde_json::from_str(&backup)
+        .with_context(|| format!("{:?} is not a valid backup", args.input))?;
+
+    backup_service.restore(backup).await?;
+    println!("Successfully restored {:?}", args.input);
+
+    Ok(())
+}
+
+async fn import_balance_sheet(
+    import_service: &impl BalanceSheetImportServiceExt,
+    args: ImportArgs,
+) -> anyhow::Result<()> {
+    let data = fs::read(&args.input)
+        .with_context(|| format!("failed to read the balances from {:?}", args.input))?;
+    let resources = read_csv(&data)?;
+
+    let plan = import_service.import(resources, args.dry_run).await?;
+    print_import_plan(&plan);
+    if plan.dry_run {
+        println!(
+            "Nothing was saved, run again without --dry-run to import {:?}",
+            args.input
+        );
+    } else {
+        println!("Successfully imported {:?}", args.input);
+    }
+
+    Ok(())
+}
+
+async fn import_statement(
+    external_account_service: &impl ExternalAccountServiceExt,
+    statement_settings: &StatementSettings,
+    args: ImportStatementArgs,
+) -> anyhow::Result<()> {
+    let format = match args.format {
+        Some(format) => format,
+        None => match args
+            .input
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_lowercase)
+            .as_deref()
+        {
+            Some("ofx" | "qfx") => StatementFormat::Ofx,
+            Some("csv") => StatementFormat::Csv,
+            _ => anyhow::bail!(
+                "Can't tell the format of {:?}, set it with --format",
+                args.input
+            ),
+        },
+    };
+    let data = fs::read(&args.input)
+        .with_context(|| format!("failed to read the statement from {:?}", args.input))?;
+    let statement = read_statement(
+        &data,
+        format.into(),
+        args.layout.as_deref(),
+        statement_settings,
+    )?;
+
+    let account = external_account_service
+        .get_external_account_by_name(&args.name)
+        .await?;
+    let imported = external_account_service
+        .import_statement(account.id, statement)
+        .await?;
+    println!(
+        "Read {} transactions from {:?}",
+        imported.transactions.len(),
+        args.input
+    );
+    println!(
+        "Successfully updated the balance of {:?} to {} (was {})",
+        imported.account.name, imported.account.balance, imported.previous_balance
+    );
+
+    Ok(())
+}
+
+fn print_import_plan(plan: &ImportPlan) {
+    for year in &plan.years_to_create {
+        println!("Create year {}", year);
+    }
+    for month in &plan.months_to_create {
+        println!("Create month {} {}", month.month.name(), month.year);
+    }
+    for name in &plan.resources_to_create {
+        println!("Create resource {:?}", name);
+    }
+    for name in &plan.resources_to_update {
+        println!("Update resource {:?}", name);
+    }
+    for balance in &plan.balances {
+        let previous = balance
+            .previous
+            .map(|previous| previous.to_string())
+            .unwrap_or_else(|| "none".to_owned());
+        println!(
+            "Set {:?} of {} {} to {} (was {})",
+            balance.resource,
+            balance.month.name(),
+            balance.year,
+            balance.balance,
+            previous
+        );
+    }
+}
+
+fn list_scrapers(scraper_registry: &ScraperRegistry) {
+    for scraper in scraper_registry.scrapers() {
+        println!("{}\t{}", scraper.id(), scraper.name());
+    }
+}
+
+fn check_scraper(scraper_registry: &ScraperRegistry, scraper: &str) -> anyhow::Result<()> {
+    if scraper_registry.get(scraper).is_none() {
+        anyhow::bail!(
+            "No scraper {:?}, the `scrapers` command lists the ones available",
+            scraper
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks the seed generates codes before sealing it like the passwords.
+fn seal_totp_seed(
+    encryption_key: &SecretKey,
+    seed: &str,
+) -> anyhow::Result<Secret<EncryptedPassword>> {
+    decode_base32(seed).context("the TOTP seed is not valid base32")?;
+
+    Ok(Secret::new(EncryptedPassword::new(aead::seal(
+        encryption_key,
+        seed.as_bytes(),
+    )?)))
 }
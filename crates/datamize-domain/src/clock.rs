@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Local, NaiveDate};
+
+/// Source of the current time. Computations relative to "now" should get it from here,
+/// so they can be tested with a fixed date instead of depending on the wall time.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Local>;
+
+    fn today(&self) -> NaiveDate {
+        self.now().date_naive()
+    }
+}
+
+pub type DynClock = Arc<dyn Clock>;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    pub fn new_arced() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+/// A clock always returning the same time.
+#[cfg(any(feature = "testutils", test))]
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Local>);
+
+#[cfg(any(feature = "testutils", test))]
+impl FixedClock {
+    /// A clock stuck at noon of the given date.
+    pub fn new_arced(date: NaiveDate) -> Arc<Self> {
+        use chrono::TimeZone;
+
+        let now = Local
+            .from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap())
+            .single()
+            .expect("noon to exist in local time");
+        Arc::new(Self(now))
+    }
+}
+
+#[cfg(any(feature = "testutils", test))]
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Local> {
+        self.0
+    }
+}
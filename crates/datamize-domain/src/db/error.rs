@@ -34,8 +34,8 @@ impl From<sqlx::Error> for DbError {
                 Self::DataIntegrityError(source.to_string())
             }
             sqlx::Error::RowNotFound => Self::NotFound,
-            e if e.to_string().contains("FOREIGN KEY constraint failed") => Self::NotFound,
-            e if e.to_string().contains("UNIQUE constraint failed") => Self::AlreadyExists,
+            sqlx::Error::Database(e) if e.is_foreign_key_violation() => Self::NotFound,
+            sqlx::Error::Database(e) if e.is_unique_violation() => Self::AlreadyExists,
             e => Self::BackendError(e.to_string()),
         }
     }
@@ -1,5 +1,7 @@
 mod error;
 mod models;
+mod registry;
 
 pub use error::*;
 pub use models::*;
+pub use registry::*;
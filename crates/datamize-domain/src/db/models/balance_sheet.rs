@@ -96,6 +96,10 @@ pub trait SavingRateRepo: Send + Sync {
     async fn get_from_year(&self, year: i32) -> DbResult<Vec<SavingRate>>;
     async fn get(&self, saving_rate_id: Uuid) -> DbResult<SavingRate>;
     async fn get_by_name(&self, name: &str) -> DbResult<SavingRate>;
+    /// Saving rates of all years, ordered by year and name.
+    async fn get_all(&self) -> DbResult<Vec<SavingRate>>;
+    /// Saving rates of the same name across all years, ordered by year.
+    async fn get_all_by_name(&self, name: &str) -> DbResult<Vec<SavingRate>>;
     async fn update(&self, saving_rate: &SavingRate) -> DbResult<()>;
     async fn delete(&self, saving_rate_id: Uuid) -> DbResult<()>;
 }
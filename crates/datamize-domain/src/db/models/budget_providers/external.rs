@@ -1,9 +1,13 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use crate::{db::error::DbResult, models::WebScrapingAccount};
+use crate::{
+    db::error::DbResult,
+    models::{ExternalAccountBalance, ExternalAccountRefresh, WebScrapingAccount},
+};
 
 #[async_trait]
 pub trait ExternalAccountRepo: Send + Sync {
@@ -24,3 +28,36 @@ pub trait EncryptionKeyRepo: Send + Sync {
 }
 
 pub type DynEncryptionKeyRepo = Arc<dyn EncryptionKeyRepo>;
+
+#[async_trait]
+pub trait ExternalAccountRefreshRepo: Send + Sync {
+    /// Every refresh of the account, the oldest first.
+    async fn get_all(&self, account_id: Uuid) -> DbResult<Vec<ExternalAccountRefresh>>;
+    /// The most recent refresh of the account.
+    async fn get_last(&self, account_id: Uuid) -> DbResult<ExternalAccountRefresh>;
+    /// The most recent refresh of the account that succeeded.
+    async fn get_last_success(&self, account_id: Uuid) -> DbResult<ExternalAccountRefresh>;
+    async fn add(&self, refresh: &ExternalAccountRefresh) -> DbResult<()>;
+}
+
+pub type DynExternalAccountRefreshRepo = Arc<dyn ExternalAccountRefreshRepo>;
+
+#[async_trait]
+pub trait ExternalAccountBalanceRepo: Send + Sync {
+    /// The balances of the account observed from `from` until before `to`, the oldest first.
+    async fn get_all(
+        &self,
+        account_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> DbResult<Vec<ExternalAccountBalance>>;
+    /// The most recent balance of the account, observed before `to` when there is one.
+    async fn get_last(
+        &self,
+        account_id: Uuid,
+        to: Option<DateTime<Utc>>,
+    ) -> DbResult<ExternalAccountBalance>;
+    async fn add(&self, balance: &ExternalAccountBalance) -> DbResult<()>;
+}
+
+pub type DynExternalAccountBalanceRepo = Arc<dyn ExternalAccountBalanceRepo>;
@@ -102,3 +102,13 @@ pub trait YnabTransactionMetaRepo: Send + Sync {
 }
 
 pub type DynYnabTransactionMetaRepo = Arc<dyn YnabTransactionMetaRepo>;
+
+/// Stores the YNAB OAuth tokens of each household member, encrypted, so the connections
+/// survive restarts.
+#[async_trait]
+pub trait YnabOAuthTokenRepo: Send + Sync {
+    async fn get(&self, member: &str) -> DbResult<Vec<u8>>;
+    async fn set(&self, member: &str, encrypted_tokens: &[u8]) -> DbResult<()>;
+}
+
+pub type DynYnabOAuthTokenRepo = Arc<dyn YnabOAuthTokenRepo>;
@@ -5,7 +5,7 @@ use uuid::Uuid;
 
 use crate::{
     db::error::DbResult,
-    models::{BudgeterConfig, ExpenseCategorization},
+    models::{BudgeterConfig, ExpenseAssociation, ExpenseCategorization},
 };
 
 #[async_trait]
@@ -28,3 +28,13 @@ pub trait ExpenseCategorizationRepo: Send + Sync {
 }
 
 pub type DynExpenseCategorizationRepo = Arc<dyn ExpenseCategorizationRepo>;
+
+#[async_trait]
+pub trait ExpenseAssociationRepo: Send + Sync {
+    async fn get_all(&self) -> DbResult<Vec<ExpenseAssociation>>;
+    async fn get(&self, id: Uuid) -> DbResult<ExpenseAssociation>;
+    async fn update(&self, expense_association: &ExpenseAssociation) -> DbResult<()>;
+    async fn delete(&self, id: Uuid) -> DbResult<()>;
+}
+
+pub type DynExpenseAssociationRepo = Arc<dyn ExpenseAssociationRepo>;
@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{db::error::DbResult, ScheduledJob, SchedulerRun};
+
+#[async_trait]
+pub trait SchedulerRunRepo: Send + Sync {
+    /// The latest runs of all the jobs, the most recent first.
+    async fn get_latest(&self, limit: u32) -> DbResult<Vec<SchedulerRun>>;
+    /// The most recent run of the job.
+    async fn get_last(&self, job: ScheduledJob) -> DbResult<SchedulerRun>;
+    async fn add(&self, run: &SchedulerRun) -> DbResult<()>;
+}
+
+pub type DynSchedulerRunRepo = Arc<dyn SchedulerRunRepo>;
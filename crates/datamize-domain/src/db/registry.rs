@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use super::{
+    external::{
+        DynEncryptionKeyRepo, DynExternalAccountBalanceRepo, DynExternalAccountRefreshRepo,
+        DynExternalAccountRepo,
+    },
+    ynab::{
+        DynYnabAccountMetaRepo, DynYnabAccountRepo, DynYnabCategoryMetaRepo, DynYnabCategoryRepo,
+        DynYnabOAuthTokenRepo, DynYnabPayeeMetaRepo, DynYnabPayeeRepo,
+        DynYnabScheduledTransactionMetaRepo, DynYnabScheduledTransactionRepo,
+        DynYnabTransactionMetaRepo, DynYnabTransactionRepo,
+    },
+    DynBudgeterConfigRepo, DynExpenseAssociationRepo, DynExpenseCategorizationRepo,
+    DynFinResOrderRepo, DynFinResRepo, DynMonthRepo, DynSavingRateRepo, DynSchedulerRunRepo,
+    DynYearRepo,
+};
+
+/// Builds the repositories of a storage backend, so callers don't depend on it.
+/// Repositories of YNAB resources are scoped to a budget.
+pub trait RepoRegistry: Send + Sync {
+    fn year_repo(&self) -> DynYearRepo;
+    fn month_repo(&self) -> DynMonthRepo;
+    fn fin_res_repo(&self) -> DynFinResRepo;
+    fn fin_res_order_repo(&self) -> DynFinResOrderRepo;
+    fn saving_rate_repo(&self) -> DynSavingRateRepo;
+    fn external_account_repo(&self) -> DynExternalAccountRepo;
+    fn external_account_refresh_repo(&self) -> DynExternalAccountRefreshRepo;
+    fn external_account_balance_repo(&self) -> DynExternalAccountBalanceRepo;
+    fn encryption_key_repo(&self) -> DynEncryptionKeyRepo;
+    fn budgeter_config_repo(&self) -> DynBudgeterConfigRepo;
+    fn expense_categorization_repo(&self) -> DynExpenseCategorizationRepo;
+    fn expense_association_repo(&self) -> DynExpenseAssociationRepo;
+    fn ynab_oauth_token_repo(&self) -> DynYnabOAuthTokenRepo;
+    fn scheduler_run_repo(&self) -> DynSchedulerRunRepo;
+    fn ynab_category_repo(&self, budget_id: &str) -> DynYnabCategoryRepo;
+    fn ynab_category_meta_repo(&self, budget_id: &str) -> DynYnabCategoryMetaRepo;
+    fn ynab_scheduled_transaction_repo(&self, budget_id: &str) -> DynYnabScheduledTransactionRepo;
+    fn ynab_scheduled_transaction_meta_repo(
+        &self,
+        budget_id: &str,
+    ) -> DynYnabScheduledTransactionMetaRepo;
+    fn ynab_account_repo(&self, budget_id: &str) -> DynYnabAccountRepo;
+    fn ynab_account_meta_repo(&self, budget_id: &str) -> DynYnabAccountMetaRepo;
+    fn ynab_payee_repo(&self, budget_id: &str) -> DynYnabPayeeRepo;
+    fn ynab_payee_meta_repo(&self, budget_id: &str) -> DynYnabPayeeMetaRepo;
+    fn ynab_transaction_repo(&self, budget_id: &str) -> DynYnabTransactionRepo;
+    fn ynab_transaction_meta_repo(&self, budget_id: &str) -> DynYnabTransactionMetaRepo;
+}
+
+pub type DynRepoRegistry = Arc<dyn RepoRegistry>;
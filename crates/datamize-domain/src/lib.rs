@@ -1,6 +1,8 @@
+mod clock;
 pub mod db;
 mod models;
 
+pub use clock::*;
 pub use models::*;
 
 // Reexport stuff our models expose
@@ -0,0 +1,178 @@
+use chrono::{DateTime, Utc};
+use secrecy::{ExposeSecret, Secret};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use ynab::Milliunits;
+
+use crate::{
+    AccountType, BudgeterConfig, EncryptedPassword, ExpenseAssociation, ExpenseCategorization,
+    ExternalAccountBalance, ExternalAccountRefresh, FinancialResourceYearly, ResourceCategory,
+    SavingRate, WebScrapingAccount, Year,
+};
+
+/// Version of the backup format written by this version of Datamize.
+/// Bumped whenever a backup could not be restored by a previous version anymore.
+pub const BACKUP_VERSION: u32 = 1;
+
+/// Everything owned by Datamize, independently of the database it was taken from.
+/// YNAB resources are left out since they are fetched again from YNAB.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Backup {
+    pub version: u32,
+    pub created_at: DateTime<Utc>,
+    /// Years with their months and net totals. Balances of the months are kept with the resources.
+    pub years: Vec<Year>,
+    pub resources: Vec<FinancialResourceYearly>,
+    pub resources_order: Vec<ResourcesOrder>,
+    pub saving_rates: Vec<SavingRate>,
+    pub budgeters: Vec<BudgeterConfig>,
+    pub expenses_categorization: Vec<ExpenseCategorization>,
+    pub expenses_association: Vec<ExpenseAssociation>,
+    /// Passwords stay encrypted, they can only be used with the encryption key they were encrypted with.
+    pub external_accounts: Vec<BackupExternalAccount>,
+    /// Missing from the archives written before the balances of external accounts were kept.
+    #[serde(default)]
+    pub external_account_balances: Vec<ExternalAccountBalance>,
+    /// Missing from the archives written before the refreshes of external accounts were recorded.
+    #[serde(default)]
+    pub external_account_refreshes: Vec<ExternalAccountRefresh>,
+    /// Only included when asked for, since it gives access to the passwords of the external accounts.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "base64_bytes::option"
+    )]
+    pub encryption_key: Option<Vec<u8>>,
+}
+
+impl Backup {
+    pub fn new() -> Self {
+        Self {
+            version: BACKUP_VERSION,
+            created_at: Utc::now(),
+            years: vec![],
+            resources: vec![],
+            resources_order: vec![],
+            saving_rates: vec![],
+            budgeters: vec![],
+            expenses_categorization: vec![],
+            expenses_association: vec![],
+            external_accounts: vec![],
+            external_account_balances: vec![],
+            external_account_refreshes: vec![],
+            encryption_key: None,
+        }
+    }
+}
+
+impl Default for Backup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct BackupParams {
+    /// Whether to include the encryption key of the external accounts' passwords. Defaults to false.
+    pub include_encryption_key: Option<bool>,
+}
+
+/// The order of the resources of a category within a year.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ResourcesOrder {
+    pub year: i32,
+    pub category: ResourceCategory,
+    pub order: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupExternalAccount {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub account_type: AccountType,
+    pub balance: Milliunits,
+    pub username: String,
+    #[serde(with = "base64_bytes")]
+    pub encrypted_password: Vec<u8>,
+    pub deleted: bool,
+    /// Missing from the archives written before scrapers were chosen explicitly.
+    #[serde(default)]
+    pub scraper_id: Option<String>,
+    /// Missing from the archives written before one-time codes were supported.
+    #[serde(default, with = "base64_bytes::option")]
+    pub encrypted_totp_seed: Option<Vec<u8>>,
+}
+
+impl From<WebScrapingAccount> for BackupExternalAccount {
+    fn from(value: WebScrapingAccount) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            account_type: value.account_type,
+            balance: value.balance,
+            username: value.username,
+            encrypted_password: value.encrypted_password.expose_secret().as_ref().to_vec(),
+            deleted: value.deleted,
+            scraper_id: value.scraper_id,
+            encrypted_totp_seed: value
+                .encrypted_totp_seed
+                .map(|seed| seed.expose_secret().as_ref().to_vec()),
+        }
+    }
+}
+
+impl From<BackupExternalAccount> for WebScrapingAccount {
+    fn from(value: BackupExternalAccount) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            account_type: value.account_type,
+            balance: value.balance,
+            username: value.username,
+            encrypted_password: Secret::new(EncryptedPassword::new(value.encrypted_password)),
+            deleted: value.deleted,
+            scraper_id: value.scraper_id,
+            encrypted_totp_seed: value
+                .encrypted_totp_seed
+                .map(|seed| Secret::new(EncryptedPassword::new(seed))),
+        }
+    }
+}
+
+/// Bytes are written in base64 to keep the archive readable.
+mod base64_bytes {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(D::Error::custom)
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            bytes: &Option<Vec<u8>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match bytes {
+                Some(bytes) => super::serialize(bytes, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Vec<u8>>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|encoded| STANDARD.decode(encoded).map_err(D::Error::custom))
+                .transpose()
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use serde::Deserialize;
+use ynab::Milliunits;
+
+use super::{
+    AssetType, FinancialResourceType, FinancialResourceYearly, LiabilityType, MonthNum, NetTotal,
+    SavingRate, Year,
+};
+use crate::{NetTotals, YearlyBalances};
+
+/// The groups of resources of an exported balance sheet, in the order they are written.
+const RESOURCE_GROUPS: [(FinancialResourceType, &str); 5] = [
+    (
+        FinancialResourceType::Asset(AssetType::Cash),
+        "Assets - Cash",
+    ),
+    (
+        FinancialResourceType::Asset(AssetType::Investment),
+        "Assets - Investment",
+    ),
+    (
+        FinancialResourceType::Asset(AssetType::LongTerm),
+        "Assets - Long Term",
+    ),
+    (
+        FinancialResourceType::Liability(LiabilityType::Cash),
+        "Liabilities - Cash",
+    ),
+    (
+        FinancialResourceType::Liability(LiabilityType::LongTerm),
+        "Liabilities - Long Term",
+    ),
+];
+
+const MONTHS: [MonthNum; 12] = [
+    MonthNum::January,
+    MonthNum::February,
+    MonthNum::March,
+    MonthNum::April,
+    MonthNum::May,
+    MonthNum::June,
+    MonthNum::July,
+    MonthNum::August,
+    MonthNum::September,
+    MonthNum::October,
+    MonthNum::November,
+    MonthNum::December,
+];
+
+#[derive(Debug, Clone, Copy, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ExportParams {
+    /// Defaults to CSV.
+    pub format: Option<ExportFormat>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SheetCell {
+    Empty,
+    Text(String),
+    Amount(Milliunits),
+    /// A proportion, i.e. `0.25` for 25%.
+    Ratio(f64),
+}
+
+impl From<&str> for SheetCell {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_owned())
+    }
+}
+
+impl From<Option<Milliunits>> for SheetCell {
+    fn from(value: Option<Milliunits>) -> Self {
+        value.map(Self::Amount).unwrap_or(Self::Empty)
+    }
+}
+
+/// A table of the balance sheet, laid out the way it is written to a spreadsheet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sheet {
+    pub name: String,
+    pub rows: Vec<Vec<SheetCell>>,
+}
+
+impl Sheet {
+    /// Resources as rows and months as columns, grouped by their type with a total per group.
+    /// Followed by the net totals of each month, the last column holding the ones of the year.
+    pub fn from_year(year: &Year, resources: &[FinancialResourceYearly]) -> Self {
+        let mut header = vec![SheetCell::Empty];
+        header.extend(MONTHS.iter().map(|m| m.name().into()));
+        header.push("Year".into());
+        let mut rows = vec![header];
+
+        for (resource_type, label) in &RESOURCE_GROUPS {
+            let group: Vec<_> = resources
+                .iter()
+                .filter(|r| r.base.resource_type == *resource_type)
+                .collect();
+            if group.is_empty() {
+                continue;
+            }
+
+            rows.push(vec![(*label).into()]);
+            for resource in &group {
+                let mut row = vec![resource.base.name.as_str().into()];
+                row.extend(
+                    MONTHS
+                        .iter()
+                        .map(|m| resource.get_balance(year.year, *m).into()),
+                );
+                rows.push(row);
+            }
+
+            let mut total = vec![format!("Total {}", label).as_str().into()];
+            total.extend(MONTHS.iter().map(|m| {
+                group
+                    .iter()
+                    .filter_map(|r| r.get_balance(year.year, *m))
+                    .reduce(|a, b| a + b)
+                    .into()
+            }));
+            rows.push(total);
+        }
+
+        rows.push(vec![]);
+        rows.extend(net_total_rows(year, "Net Assets", |n| &n.assets));
+        rows.extend(net_total_rows(year, "Net Cash", |n| &n.portfolio));
+
+        Self {
+            name: format!("Balance Sheet {}", year.year),
+            rows,
+        }
+    }
+
+    /// One row per saving rate, with its totals and rate.
+    /// Expects saving rates with their totals already computed.
+    pub fn from_saving_rates(year: i32, saving_rates: &[SavingRate]) -> Self {
+        let mut rows = vec![vec![
+            "Name".into(),
+            "Savings".into(),
+            "Employer Contribution".into(),
+            "Employee Contribution".into(),
+            "Mortgage Capital".into(),
+            "Incomes".into(),
+            "Rate".into(),
+            "Target".into(),
+        ]];
+
+        for saving_rate in saving_rates {
+            rows.push(vec![
+                saving_rate.name.as_str().into(),
+                SheetCell::Amount(saving_rate.savings.total),
+                SheetCell::Amount(saving_rate.employer_contribution),
+                SheetCell::Amount(saving_rate.employee_contribution),
+                SheetCell::Amount(saving_rate.mortgage_capital),
+                SheetCell::Amount(saving_rate.incomes.total),
+                SheetCell::Ratio(saving_rate.rate),
+                saving_rate
+                    .target
+                    .map(SheetCell::Ratio)
+                    .unwrap_or(SheetCell::Empty),
+            ]);
+        }
+
+        Self {
+            name: format!("Saving Rates {}", year),
+            rows,
+        }
+    }
+}
+
+/// The total, variation and variation in percent of a net total, per month then for the year.
+fn net_total_rows(
+    year: &Year,
+    label: &str,
+    net_total: impl Fn(&NetTotals) -> &NetTotal,
+) -> Vec<Vec<SheetCell>> {
+    let cells = |name: &str, cell: &dyn Fn(&NetTotal) -> SheetCell| {
+        let mut row = vec![format!("{} {}", label, name).as_str().into()];
+        row.extend(MONTHS.iter().map(|m| {
+            year.months
+                .iter()
+                .find(|month| month.month == *m)
+                .map(|month| cell(net_total(&month.net_totals)))
+                .unwrap_or(SheetCell::Empty)
+        }));
+        row.push(cell(net_total(&year.net_totals)));
+        row
+    };
+
+    vec![
+        cells("Total", &|n| SheetCell::Amount(n.total)),
+        cells("Variation", &|n| SheetCell::Amount(n.balance_var)),
+        cells("Variation (%)", &|n| SheetCell::Ratio(n.percent_var.into())),
+    ]
+}
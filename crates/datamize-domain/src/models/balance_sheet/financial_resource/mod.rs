@@ -14,10 +14,11 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use ynab::Milliunits;
 
 use crate::MonthNum;
 
-pub type BalancePerMonth = BTreeMap<MonthNum, Option<i64>>;
+pub type BalancePerMonth = BTreeMap<MonthNum, Option<Milliunits>>;
 pub fn get_all_months_empty() -> BalancePerMonth {
     BTreeMap::from([
         (MonthNum::January, None),
@@ -86,7 +87,7 @@ pub trait YearlyBalances {
     fn balances(&self) -> &BalancePerYearPerMonth;
     fn balances_mut(&mut self) -> &mut BalancePerYearPerMonth;
 
-    fn insert_balance(&mut self, year: i32, month: MonthNum, balance: i64) {
+    fn insert_balance(&mut self, year: i32, month: MonthNum, balance: Milliunits) {
         let year_entry = self
             .balances_mut()
             .entry(year)
@@ -95,7 +96,7 @@ pub trait YearlyBalances {
         year_entry.insert(month, Some(balance));
     }
 
-    fn insert_balance_opt(&mut self, year: i32, month: MonthNum, balance: Option<i64>) {
+    fn insert_balance_opt(&mut self, year: i32, month: MonthNum, balance: Option<Milliunits>) {
         let year_entry = self
             .balances_mut()
             .entry(year)
@@ -109,7 +110,7 @@ pub trait YearlyBalances {
     }
 
     /// Returns an iterator that allows extracting all months with balance, in all years
-    fn iter_balances(&self) -> impl Iterator<Item = (i32, MonthNum, i64)> {
+    fn iter_balances(&self) -> impl Iterator<Item = (i32, MonthNum, Milliunits)> {
         self.balances().iter().flat_map(|(&year, month_balances)| {
             month_balances
                 .iter()
@@ -118,7 +119,7 @@ pub trait YearlyBalances {
     }
 
     /// Returns an iterator that allows extracting all months in all years even if they don't have balance.
-    fn iter_all_balances(&self) -> impl Iterator<Item = (i32, MonthNum, Option<i64>)> {
+    fn iter_all_balances(&self) -> impl Iterator<Item = (i32, MonthNum, Option<Milliunits>)> {
         self.balances().iter().flat_map(|(&year, month_balances)| {
             month_balances
                 .iter()
@@ -150,7 +151,7 @@ pub trait YearlyBalances {
         })
     }
 
-    fn get_balance(&self, year: i32, month: MonthNum) -> Option<i64> {
+    fn get_balance(&self, year: i32, month: MonthNum) -> Option<Milliunits> {
         self.balances().get(&year)?.get(&month).copied()?
     }
 
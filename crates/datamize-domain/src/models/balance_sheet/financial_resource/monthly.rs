@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use ynab::Milliunits;
 
 use super::{BaseFinancialResource, FinancialResourceType};
 
@@ -11,7 +12,7 @@ pub struct FinancialResourceMonthly {
     pub base: BaseFinancialResource,
     /// The balance of the resource in the month.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub balance: i64,
+    pub balance: Milliunits,
 }
 
 impl FinancialResourceMonthly {
@@ -34,7 +35,7 @@ impl FinancialResourceMonthly {
         }
     }
 
-    pub fn with_balance(self, balance: i64) -> Self {
+    pub fn with_balance(self, balance: Milliunits) -> Self {
         Self { balance, ..self }
     }
 }
@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use ynab::Milliunits;
+
+use super::{FinancialResourceType, MonthNum};
+
+/// A resource read from a spreadsheet of historical balances.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedResource {
+    pub name: String,
+    pub resource_type: FinancialResourceType,
+    /// Balances by year and month. Months left empty in the spreadsheet are absent.
+    pub balances: BTreeMap<(i32, MonthNum), Milliunits>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ImportParams {
+    /// Only computes the changes the import would make, without saving them. Defaults to false.
+    pub dry_run: Option<bool>,
+}
+
+/// The changes made by an import, or the ones it would make when it is a dry run.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct ImportPlan {
+    pub dry_run: bool,
+    pub years_to_create: Vec<i32>,
+    pub months_to_create: Vec<ImportedMonth>,
+    pub resources_to_create: Vec<String>,
+    /// Existing resources whose type or balances change.
+    pub resources_to_update: Vec<String>,
+    /// Only the balances that are new or different from the ones saved.
+    pub balances: Vec<ImportedBalance>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ImportedMonth {
+    pub year: i32,
+    pub month: MonthNum,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportedBalance {
+    pub resource: String,
+    pub year: i32,
+    pub month: MonthNum,
+    pub previous: Option<Milliunits>,
+    pub balance: Milliunits,
+}
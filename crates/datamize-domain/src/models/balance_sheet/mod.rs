@@ -1,4 +1,6 @@
+mod export;
 mod financial_resource;
+mod import;
 mod month;
 mod month_num;
 mod net_total;
@@ -7,7 +9,9 @@ mod saving_rate;
 mod tests;
 mod year;
 
+pub use export::*;
 pub use financial_resource::*;
+pub use import::*;
 pub use month::*;
 pub use month_num::*;
 pub use net_total::*;
@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use ynab::Milliunits;
 
 use crate::{AssetType, FinancialResourceMonthly, FinancialResourceType};
 
@@ -20,8 +21,8 @@ impl NetTotals {
     }
 
     pub fn compute_totals_from_resources(&mut self, resources: &[FinancialResourceMonthly]) {
-        let mut total_assets = 0;
-        let mut total_portfolio = 0;
+        let mut total_assets = Milliunits::ZERO;
+        let mut total_portfolio = Milliunits::ZERO;
         let mut at_least_one_in_portfolio = false;
 
         for resource in resources {
@@ -58,7 +59,7 @@ pub struct NetTotal {
         any(feature = "testutils", test),
         dummy(faker = "-1000000..1000000000")
     )]
-    pub total: i64,
+    pub total: Milliunits,
     /// The percentage of variation compared to the previous month's section.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0.0..1.0"))]
     pub percent_var: f32,
@@ -67,7 +68,7 @@ pub struct NetTotal {
         any(feature = "testutils", test),
         dummy(faker = "-1000000..1000000000")
     )]
-    pub balance_var: i64,
+    pub balance_var: Milliunits,
     pub last_updated: Option<DateTime<Utc>>,
 }
 
@@ -84,9 +85,9 @@ impl Default for NetTotal {
     fn default() -> Self {
         Self {
             id: Uuid::new_v4(),
-            total: 0,
+            total: Milliunits::ZERO,
             percent_var: 0.0,
-            balance_var: 0,
+            balance_var: Milliunits::ZERO,
             last_updated: None,
         }
     }
@@ -94,16 +95,16 @@ impl Default for NetTotal {
 
 pub struct Variation {
     /// The money balance of variation compared to the previous month's section.
-    pub balance_var: i64,
+    pub balance_var: Milliunits,
     /// The percentage of variation compared to the previous month's section.
     pub percent_var: f32,
 }
 
 impl Variation {
-    pub fn calculate(previous_total: i64, current_total: i64) -> Self {
+    pub fn calculate(previous_total: Milliunits, current_total: Milliunits) -> Self {
         let balance_var = current_total - previous_total;
-        let percent_var = if previous_total != 0 {
-            (current_total as f32 - previous_total as f32) / previous_total as f32
+        let percent_var = if previous_total != Milliunits::ZERO {
+            (current_total.get() as f32 - previous_total.get() as f32) / previous_total.get() as f32
         } else {
             0.0
         };
@@ -1,6 +1,9 @@
+use chrono::Datelike;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use ynab::TransactionDetail;
+use ynab::{Milliunits, TransactionDetail};
+
+use super::MonthNum;
 
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -17,56 +20,170 @@ pub struct SavingRate {
     /// Pension ou contributions de l’employeur au régime de retraite (REER ou autre)
     /// – inclus au numérateur et dénominateur
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub employer_contribution: i64,
+    pub employer_contribution: Milliunits,
     /// Cotisations au régime de retraite – inclus aux numérateur et dénominateur
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub employee_contribution: i64,
+    pub employee_contribution: Milliunits,
     /// Capital remboursé sur l'hypothèque
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub mortgage_capital: i64,
+    pub mortgage_capital: Milliunits,
     /// Revenus nets, soit le montant déposé dans votre compte de banque après toutes les déductions
     /// (impôts, cotisations au régime de retraite, assurance emploi, assurances collectives, RQAP, RRQ)
     /// ainsi que les autres sources de revenus (paies, bonus le cas échéant, RQAP le cas échéant,
     /// allocations pour enfants, remboursement d’impôt, ristourne,
     /// remises en argent de carte de crédit, cadeaux en argent, revenus de location, etc.)
     pub incomes: Incomes,
-    // rate = (savings + employer_contribution + employee_contribution + mortgage_capital) / (incomes + employer_contribution + employee_contribution)
-    // pub rate: f32, // Leave it to be computed on the frontend
+    /// Saving rate of the year, between 0 and 1. Computed along with the totals.
+    /// rate = (savings + employer_contribution + employee_contribution + mortgage_capital) / (incomes + employer_contribution + employee_contribution)
+    #[serde(default)]
+    #[cfg_attr(any(feature = "testutils", test), dummy(default))]
+    pub rate: f64,
+    /// The saving rate aimed for the year, between 0 and 1.
+    #[serde(default)]
+    #[cfg_attr(any(feature = "testutils", test), dummy(default))]
+    pub target: Option<f64>,
+    /// Breakdown of the year, one entry per month. Computed along with the totals.
+    #[serde(default)]
+    #[cfg_attr(any(feature = "testutils", test), dummy(default))]
+    pub months: Vec<SavingRateMonth>,
 }
 
 impl SavingRate {
-    pub fn compute_totals(&mut self, transactions: &[TransactionDetail]) {
-        self.savings.compute_total(transactions);
-        self.incomes.compute_total(transactions);
+    /// Computes the totals and the monthly breakdown from the transactions of the saving rate's year.
+    /// Transactions of any other year are ignored. Returns `None` when the amounts overflow.
+    pub fn compute_totals(&mut self, transactions: &[TransactionDetail]) -> Option<()> {
+        let transactions: Vec<_> = transactions
+            .iter()
+            .filter(|t| t.base.date.year() == self.year)
+            .cloned()
+            .collect();
+
+        self.savings.compute_total(&transactions)?;
+        self.incomes.compute_total(&transactions)?;
+        self.rate = compute_rate(
+            self.savings.total,
+            self.incomes.total,
+            self.employer_contribution,
+            self.employee_contribution,
+            self.mortgage_capital,
+        );
+        self.compute_months(&transactions)
+    }
+
+    /// Whether the computed rate reaches the target, if there is one.
+    pub fn meets_target(&self) -> Option<bool> {
+        self.target.map(|target| self.rate >= target)
+    }
+
+    /// The extra balances and contributions are entered for the whole year,
+    /// so they are spread evenly over its 12 months, December taking what can't be split.
+    fn compute_months(&mut self, transactions: &[TransactionDetail]) -> Option<()> {
+        self.months = (1..=12_u32)
+            .filter_map(|m| MonthNum::try_from(m).ok().map(|month| (m, month)))
+            .map(|(m, month)| {
+                let per_month = |amount: Milliunits| {
+                    let share = amount.get() / 12;
+                    match month {
+                        MonthNum::December => Milliunits::new(share + amount.get() % 12),
+                        _ => Milliunits::new(share),
+                    }
+                };
+
+                let month_transactions: Vec<_> = transactions
+                    .iter()
+                    .filter(|t| t.base.date.month() == m)
+                    .cloned()
+                    .collect();
+
+                let savings = self
+                    .savings
+                    .sum_transactions(&month_transactions)?
+                    .checked_add(per_month(self.savings.extra_balance))?;
+                let incomes = self
+                    .incomes
+                    .sum_transactions(&month_transactions)?
+                    .checked_add(per_month(self.incomes.extra_balance))?;
+
+                Some(SavingRateMonth {
+                    month,
+                    savings,
+                    incomes,
+                    rate: compute_rate(
+                        savings,
+                        incomes,
+                        per_month(self.employer_contribution),
+                        per_month(self.employee_contribution),
+                        per_month(self.mortgage_capital),
+                    ),
+                })
+            })
+            .collect::<Option<_>>()?;
+
+        Some(())
     }
 }
 
+/// Savings, incomes and saving rate of a single month.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SavingRateMonth {
+    pub month: MonthNum,
+    /// Savings of the month, including its part of the extra balance.
+    pub savings: Milliunits,
+    /// Incomes of the month, including its part of the extra balance.
+    pub incomes: Milliunits,
+    /// Saving rate of the month, between 0 and 1.
+    pub rate: f64,
+}
+
+/// Follows the formula documented on `SavingRate::rate`. Zero when there is nothing to divide by.
+pub(crate) fn compute_rate(
+    savings: Milliunits,
+    incomes: Milliunits,
+    employer_contribution: Milliunits,
+    employee_contribution: Milliunits,
+    mortgage_capital: Milliunits,
+) -> f64 {
+    // Summed as floats since the rate is a ratio and milliunits additions can overflow.
+    let contributions = employer_contribution.to_f64() + employee_contribution.to_f64();
+    let denominator = incomes.to_f64() + contributions;
+    if denominator == 0.0 {
+        return 0.0;
+    }
+
+    (savings.to_f64() + contributions + mortgage_capital.to_f64()) / denominator
+}
+
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Savings {
     /// Any categories that should be used to compute the savings.
-    /// Will take all transactions of the category for the current year.
+    /// Will take all transactions of the category for the saving rate's year.
     pub category_ids: Vec<Uuid>,
     /// Any extra balance to be used, will be added to the total of categories included with this saving.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub extra_balance: i64,
+    pub extra_balance: Milliunits,
     /// Total balance computed from all categories and extra_balance.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub total: i64,
+    pub total: Milliunits,
 }
 
 impl Savings {
-    pub(crate) fn compute_total(&mut self, transactions: &[TransactionDetail]) {
-        let cat_total: i64 = transactions
+    pub(crate) fn compute_total(&mut self, transactions: &[TransactionDetail]) -> Option<()> {
+        self.total = self
+            .sum_transactions(transactions)?
+            .checked_add(self.extra_balance)?;
+        Some(())
+    }
+
+    fn sum_transactions(&self, transactions: &[TransactionDetail]) -> Option<Milliunits> {
+        let amounts = transactions
             .iter()
             .filter(|t| match &t.base.category_id {
                 Some(ref id) => self.category_ids.contains(id),
                 None => false,
             })
-            .map(|t| t.base.amount)
-            .sum();
-
-        self.total = cat_total + self.extra_balance;
+            .map(|t| Milliunits::from(t.base.amount));
+        Milliunits::checked_sum(amounts)
     }
 }
 
@@ -74,28 +191,33 @@ impl Savings {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Incomes {
     /// Any payees that should be used to compute the incomes.
-    /// Will take all transactions of the payee for the current year.
+    /// Will take all transactions of the payee for the saving rate's year.
     pub payee_ids: Vec<Uuid>,
     /// Any extra balance to be used, will be added to the total of payees included with this saving.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub extra_balance: i64,
+    pub extra_balance: Milliunits,
     /// Total balance computed from all categories and extra_balance.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub total: i64,
+    pub total: Milliunits,
 }
 
 impl Incomes {
-    pub(crate) fn compute_total(&mut self, transactions: &[TransactionDetail]) {
-        let cat_total: i64 = transactions
+    pub(crate) fn compute_total(&mut self, transactions: &[TransactionDetail]) -> Option<()> {
+        self.total = self
+            .sum_transactions(transactions)?
+            .checked_add(self.extra_balance)?;
+        Some(())
+    }
+
+    fn sum_transactions(&self, transactions: &[TransactionDetail]) -> Option<Milliunits> {
+        let amounts = transactions
             .iter()
             .filter(|t| match &t.base.payee_id {
                 Some(ref id) => self.payee_ids.contains(id),
                 None => false,
             })
-            .map(|t| t.base.amount)
-            .sum();
-
-        self.total = cat_total + self.extra_balance;
+            .map(|t| Milliunits::from(t.base.amount));
+        Milliunits::checked_sum(amounts)
     }
 }
 
@@ -108,12 +230,22 @@ pub struct SaveSavingRate {
     pub year: i32,
     pub savings: SaveSavings,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub employer_contribution: i64,
+    pub employer_contribution: Milliunits,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub employee_contribution: i64,
+    pub employee_contribution: Milliunits,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub mortgage_capital: i64,
+    pub mortgage_capital: Milliunits,
     pub incomes: SaveIncomes,
+    #[serde(default)]
+    #[cfg_attr(any(feature = "testutils", test), dummy(default))]
+    pub target: Option<f64>,
+}
+
+impl SaveSavingRate {
+    /// A target is a rate, so it has to be between 0 and 1.
+    pub fn is_target_valid(&self) -> bool {
+        self.target.map_or(true, |t| (0.0..=1.0).contains(&t))
+    }
 }
 
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
@@ -121,7 +253,7 @@ pub struct SaveSavingRate {
 pub struct SaveSavings {
     pub category_ids: Vec<Uuid>,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub extra_balance: i64,
+    pub extra_balance: Milliunits,
 }
 
 impl From<SaveSavings> for Savings {
@@ -129,7 +261,7 @@ impl From<SaveSavings> for Savings {
         Self {
             category_ids: value.category_ids,
             extra_balance: value.extra_balance,
-            total: 0,
+            total: Milliunits::ZERO,
         }
     }
 }
@@ -139,7 +271,7 @@ impl From<SaveSavings> for Savings {
 pub struct SaveIncomes {
     pub payee_ids: Vec<Uuid>,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub extra_balance: i64,
+    pub extra_balance: Milliunits,
 }
 
 impl From<SaveIncomes> for Incomes {
@@ -147,7 +279,7 @@ impl From<SaveIncomes> for Incomes {
         Self {
             payee_ids: value.payee_ids,
             extra_balance: value.extra_balance,
-            total: 0,
+            total: Milliunits::ZERO,
         }
     }
 }
@@ -163,6 +295,42 @@ impl From<SaveSavingRate> for SavingRate {
             employee_contribution: value.employee_contribution,
             mortgage_capital: value.mortgage_capital,
             incomes: value.incomes.into(),
+            rate: 0.0,
+            target: value.target,
+            months: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SavingRateHistoryParams {
+    /// Name of the saving rate to follow across years.
+    pub name: String,
+}
+
+/// A saving rate of one year, to compare it with the other years of the same name.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SavingRateHistoryEntry {
+    pub id: Uuid,
+    pub year: i32,
+    pub savings: Milliunits,
+    pub incomes: Milliunits,
+    pub rate: f64,
+    pub target: Option<f64>,
+    /// Whether the rate reaches the target, `None` without a target.
+    pub meets_target: Option<bool>,
+}
+
+impl From<&SavingRate> for SavingRateHistoryEntry {
+    fn from(value: &SavingRate) -> Self {
+        Self {
+            id: value.id,
+            year: value.year,
+            savings: value.savings.total,
+            incomes: value.incomes.total,
+            rate: value.rate,
+            target: value.target,
+            meets_target: value.meets_target(),
         }
     }
 }
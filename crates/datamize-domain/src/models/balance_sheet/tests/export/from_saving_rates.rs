@@ -0,0 +1,24 @@
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use ynab::Milliunits;
+
+use crate::{SavingRate, Sheet, SheetCell};
+
+#[test]
+fn writes_a_row_per_saving_rate() {
+    let mut saving_rate: SavingRate = Faker.fake();
+    saving_rate.savings.total = Milliunits::new(2000);
+    saving_rate.incomes.total = Milliunits::new(10000);
+    saving_rate.rate = 0.2;
+    saving_rate.target = None;
+
+    let sheet = Sheet::from_saving_rates(2023, &[saving_rate.clone()]);
+
+    assert_eq!(sheet.name, "Saving Rates 2023");
+    assert_eq!(sheet.rows.len(), 2);
+    assert_eq!(sheet.rows[1][0], SheetCell::Text(saving_rate.name));
+    assert_eq!(sheet.rows[1][1], SheetCell::Amount(Milliunits::new(2000)));
+    assert_eq!(sheet.rows[1][5], SheetCell::Amount(Milliunits::new(10000)));
+    assert_eq!(sheet.rows[1][6], SheetCell::Ratio(0.2));
+    assert_eq!(sheet.rows[1][7], SheetCell::Empty);
+}
@@ -0,0 +1,88 @@
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use ynab::Milliunits;
+
+use crate::{
+    AssetType, BaseFinancialResource, FinancialResourceType, FinancialResourceYearly,
+    LiabilityType, Month, MonthNum, Sheet, SheetCell, Year, YearlyBalances,
+};
+
+fn resource(name: &str, resource_type: FinancialResourceType) -> FinancialResourceYearly {
+    FinancialResourceYearly {
+        base: BaseFinancialResource {
+            name: name.to_owned(),
+            resource_type,
+            ..Faker.fake()
+        },
+        ..Default::default()
+    }
+}
+
+fn text(value: &str) -> SheetCell {
+    SheetCell::Text(value.to_owned())
+}
+
+#[test]
+fn groups_resources_by_type_with_months_as_columns() {
+    let year = Year::new(2023);
+    let mut bank = resource("Bank", FinancialResourceType::Asset(AssetType::Cash));
+    bank.insert_balance(2023, MonthNum::January, Milliunits::new(1000));
+    bank.insert_balance(2023, MonthNum::March, Milliunits::new(3000));
+    let mut wallet = resource("Wallet", FinancialResourceType::Asset(AssetType::Cash));
+    wallet.insert_balance(2023, MonthNum::January, Milliunits::new(500));
+    wallet.insert_balance(2022, MonthNum::December, Milliunits::new(9000));
+    let mut card = resource(
+        "Card",
+        FinancialResourceType::Liability(LiabilityType::Cash),
+    );
+    card.insert_balance(2023, MonthNum::February, Milliunits::new(200));
+
+    let sheet = Sheet::from_year(&year, &[card, bank, wallet]);
+
+    assert_eq!(sheet.name, "Balance Sheet 2023");
+    assert_eq!(sheet.rows[0].len(), 14);
+    assert_eq!(sheet.rows[0][1], text("January"));
+    assert_eq!(sheet.rows[0][13], text("Year"));
+
+    assert_eq!(sheet.rows[1], vec![text("Assets - Cash")]);
+    assert_eq!(sheet.rows[2][0], text("Bank"));
+    assert_eq!(sheet.rows[2][1], SheetCell::Amount(Milliunits::new(1000)));
+    assert_eq!(sheet.rows[2][2], SheetCell::Empty);
+    assert_eq!(sheet.rows[2][3], SheetCell::Amount(Milliunits::new(3000)));
+    assert_eq!(sheet.rows[3][0], text("Wallet"));
+    assert_eq!(sheet.rows[3][12], SheetCell::Empty);
+    assert_eq!(sheet.rows[4][0], text("Total Assets - Cash"));
+    assert_eq!(sheet.rows[4][1], SheetCell::Amount(Milliunits::new(1500)));
+    assert_eq!(sheet.rows[4][2], SheetCell::Empty);
+
+    assert_eq!(sheet.rows[5], vec![text("Liabilities - Cash")]);
+    assert_eq!(sheet.rows[6][0], text("Card"));
+    assert_eq!(sheet.rows[6][2], SheetCell::Amount(Milliunits::new(200)));
+    assert_eq!(sheet.rows[7][0], text("Total Liabilities - Cash"));
+    assert_eq!(sheet.rows[8], vec![]);
+}
+
+#[test]
+fn ends_with_the_net_totals_of_the_months_and_the_year() {
+    let mut year = Year::new(2023);
+    year.net_totals.assets.total = Milliunits::new(7000);
+    year.net_totals.portfolio.percent_var = 0.5;
+    let mut month = Month::new(MonthNum::February, 2023);
+    month.net_totals.assets.total = Milliunits::new(6000);
+    month.net_totals.assets.balance_var = Milliunits::new(-100);
+    year.months.push(month);
+
+    let sheet = Sheet::from_year(&year, &[]);
+
+    let net_rows = &sheet.rows[sheet.rows.len() - 6..];
+    assert_eq!(net_rows[0][0], text("Net Assets Total"));
+    assert_eq!(net_rows[0][1], SheetCell::Empty);
+    assert_eq!(net_rows[0][2], SheetCell::Amount(Milliunits::new(6000)));
+    assert_eq!(net_rows[0][13], SheetCell::Amount(Milliunits::new(7000)));
+    assert_eq!(net_rows[1][0], text("Net Assets Variation"));
+    assert_eq!(net_rows[1][2], SheetCell::Amount(Milliunits::new(-100)));
+    assert_eq!(net_rows[2][0], text("Net Assets Variation (%)"));
+    assert_eq!(net_rows[3][0], text("Net Cash Total"));
+    assert_eq!(net_rows[5][0], text("Net Cash Variation (%)"));
+    assert_eq!(net_rows[5][13], SheetCell::Ratio(0.5));
+}
@@ -0,0 +1,2 @@
+mod from_saving_rates;
+mod from_year;
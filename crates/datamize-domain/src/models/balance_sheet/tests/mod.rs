@@ -1,3 +1,4 @@
+mod export;
 mod financial_resource;
 mod month;
 mod saving_rate;
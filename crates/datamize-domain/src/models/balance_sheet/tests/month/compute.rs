@@ -4,6 +4,7 @@ use crate::{
 use chrono::{Datelike, NaiveDate};
 use fake::{faker::chrono::en::Date, Fake, Faker};
 use pretty_assertions::assert_eq;
+use ynab::Milliunits;
 
 #[test]
 fn sets_total_at_0_when_no_resources() {
@@ -11,8 +12,8 @@ fn sets_total_at_0_when_no_resources() {
     let mut month = Month::new(date.month().try_into().unwrap(), date.year());
 
     month.compute_net_totals();
-    assert_eq!(month.net_assets().total, 0);
-    assert_eq!(month.net_portfolio().total, 0);
+    assert_eq!(month.net_assets().total, Milliunits::ZERO);
+    assert_eq!(month.net_portfolio().total, Milliunits::ZERO);
 }
 
 #[test]
@@ -112,7 +113,7 @@ fn assets_equals_total_of_asset_minus_liability() {
     };
 
     month.compute_net_totals();
-    assert_eq!(0, month.net_assets().total);
+    assert_eq!(Milliunits::ZERO, month.net_assets().total);
     assert_eq!(res_asset.balance, month.net_portfolio().total);
 }
 
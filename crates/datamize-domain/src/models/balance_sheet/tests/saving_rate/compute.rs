@@ -1,7 +1,9 @@
-use crate::{Incomes, SavingRate, Savings};
+use crate::{Incomes, MonthNum, SavingRate, Savings};
+use chrono::NaiveDate;
 use fake::{Fake, Faker};
 use pretty_assertions::{assert_eq, assert_ne};
 use uuid::Uuid;
+use ynab::Milliunits;
 use ynab::TransactionDetail;
 
 #[test]
@@ -34,7 +36,8 @@ fn compute_savings_sets_total_with_linked_transactions() {
     savings.compute_total(&transactions);
 
     assert_ne!(savings.total, savings.extra_balance);
-    let transcations_total = transactions[0].base.amount + transactions[1].base.amount;
+    let transcations_total =
+        Milliunits::new(transactions[0].base.amount + transactions[1].base.amount);
     assert_eq!(savings.total, savings.extra_balance + transcations_total);
 }
 
@@ -68,7 +71,8 @@ fn compute_incomes_sets_total_with_linked_transactions() {
     incomes.compute_total(&transactions);
 
     assert_ne!(incomes.total, incomes.extra_balance);
-    let transcations_total = transactions[0].base.amount + transactions[1].base.amount;
+    let transcations_total =
+        Milliunits::new(transactions[0].base.amount + transactions[1].base.amount);
     assert_eq!(incomes.total, incomes.extra_balance + transcations_total);
 }
 
@@ -87,7 +91,9 @@ fn compute_totals_calls_both_savings_and_incomes() {
     };
     let mut transactions: Vec<TransactionDetail> = fake::vec![TransactionDetail; 3..5];
     transactions[0].base.category_id = Some(saving_rate.savings.category_ids[0]);
+    transactions[0].base.date = date(saving_rate.year, 2, 1);
     transactions[1].base.payee_id = Some(saving_rate.incomes.payee_ids[0]);
+    transactions[1].base.date = date(saving_rate.year, 3, 1);
     let savings_total_before = saving_rate.savings.total;
     let incomes_total_before = saving_rate.incomes.total;
     saving_rate.compute_totals(&transactions);
@@ -95,3 +101,178 @@ fn compute_totals_calls_both_savings_and_incomes() {
     assert_ne!(savings_total_before, saving_rate.savings.total);
     assert_ne!(incomes_total_before, saving_rate.incomes.total);
 }
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn saving_rate_of_2024() -> SavingRate {
+    SavingRate {
+        year: 2024,
+        savings: Savings {
+            category_ids: fake::vec![Uuid; 1],
+            extra_balance: Milliunits::ZERO,
+            ..Faker.fake()
+        },
+        incomes: Incomes {
+            payee_ids: fake::vec![Uuid; 1],
+            extra_balance: Milliunits::ZERO,
+            ..Faker.fake()
+        },
+        employer_contribution: Milliunits::ZERO,
+        employee_contribution: Milliunits::ZERO,
+        mortgage_capital: Milliunits::ZERO,
+        ..Faker.fake()
+    }
+}
+
+fn transaction(date: NaiveDate, amount: i64) -> TransactionDetail {
+    let mut transaction: TransactionDetail = Faker.fake();
+    transaction.base.date = date;
+    transaction.base.amount = amount;
+    transaction
+}
+
+#[test]
+fn compute_totals_ignores_transactions_of_other_years() {
+    let mut saving_rate = saving_rate_of_2024();
+    let mut saving = transaction(date(2024, 5, 10), 100000);
+    saving.base.category_id = Some(saving_rate.savings.category_ids[0]);
+    let mut old_saving = transaction(date(2023, 5, 10), 500000);
+    old_saving.base.category_id = Some(saving_rate.savings.category_ids[0]);
+    let mut income = transaction(date(2024, 5, 15), 1000000);
+    income.base.payee_id = Some(saving_rate.incomes.payee_ids[0]);
+    let mut next_income = transaction(date(2025, 1, 15), 2000000);
+    next_income.base.payee_id = Some(saving_rate.incomes.payee_ids[0]);
+
+    saving_rate.compute_totals(&[saving, old_saving, income, next_income]);
+
+    assert_eq!(saving_rate.savings.total, Milliunits::new(100000));
+    assert_eq!(saving_rate.incomes.total, Milliunits::new(1000000));
+}
+
+#[test]
+fn compute_totals_breaks_down_the_year_by_month() {
+    let mut saving_rate = saving_rate_of_2024();
+    let mut saving = transaction(date(2024, 3, 10), 100000);
+    saving.base.category_id = Some(saving_rate.savings.category_ids[0]);
+    let mut income = transaction(date(2024, 3, 15), 1000000);
+    income.base.payee_id = Some(saving_rate.incomes.payee_ids[0]);
+    let mut other_income = transaction(date(2024, 7, 15), 1000000);
+    other_income.base.payee_id = Some(saving_rate.incomes.payee_ids[0]);
+
+    saving_rate.compute_totals(&[saving, income, other_income]);
+
+    assert_eq!(saving_rate.months.len(), 12);
+    let march = &saving_rate.months[2];
+    assert_eq!(march.month, MonthNum::March);
+    assert_eq!(march.savings, Milliunits::new(100000));
+    assert_eq!(march.incomes, Milliunits::new(1000000));
+    assert_eq!(march.rate, 0.1);
+    let july = &saving_rate.months[6];
+    assert_eq!(july.savings, Milliunits::ZERO);
+    assert_eq!(july.rate, 0.0);
+    assert_eq!(saving_rate.months[0].rate, 0.0);
+}
+
+#[test]
+fn compute_totals_spreads_yearly_amounts_over_each_month() {
+    let mut saving_rate = SavingRate {
+        employer_contribution: Milliunits::new(120000),
+        mortgage_capital: Milliunits::new(240000),
+        ..saving_rate_of_2024()
+    };
+    saving_rate.incomes.extra_balance = Milliunits::new(1200000);
+
+    saving_rate.compute_totals(&[]);
+
+    for month in &saving_rate.months {
+        assert_eq!(month.incomes, Milliunits::new(100000));
+        // (10000 + 20000) / (100000 + 10000)
+        assert_eq!(month.rate, 30000.0 / 110000.0);
+    }
+}
+
+#[test]
+fn compute_totals_puts_what_cannot_be_spread_on_december() {
+    let mut saving_rate = saving_rate_of_2024();
+    saving_rate.incomes.extra_balance = Milliunits::new(1000005);
+
+    saving_rate.compute_totals(&[]);
+
+    for month in &saving_rate.months[..11] {
+        assert_eq!(month.incomes, Milliunits::new(83333));
+    }
+    let december = &saving_rate.months[11];
+    assert_eq!(december.month, MonthNum::December);
+    assert_eq!(december.incomes, Milliunits::new(83342));
+    assert_eq!(
+        saving_rate
+            .months
+            .iter()
+            .map(|m| m.incomes)
+            .sum::<Milliunits>(),
+        saving_rate.incomes.total
+    );
+}
+
+#[test]
+fn compute_totals_computes_the_rate_of_the_year() {
+    let mut saving_rate = SavingRate {
+        employer_contribution: Milliunits::new(50000),
+        employee_contribution: Milliunits::new(50000),
+        mortgage_capital: Milliunits::new(100000),
+        ..saving_rate_of_2024()
+    };
+    let mut saving = transaction(date(2024, 3, 10), 100000);
+    saving.base.category_id = Some(saving_rate.savings.category_ids[0]);
+    let mut income = transaction(date(2024, 3, 15), 900000);
+    income.base.payee_id = Some(saving_rate.incomes.payee_ids[0]);
+
+    saving_rate.compute_totals(&[saving, income]);
+
+    // (100000 + 50000 + 50000 + 100000) / (900000 + 50000 + 50000)
+    assert_eq!(saving_rate.rate, 0.3);
+}
+
+#[test]
+fn rate_is_zero_without_incomes() {
+    let mut saving_rate = saving_rate_of_2024();
+    saving_rate.savings.extra_balance = Milliunits::new(100000);
+
+    saving_rate.compute_totals(&[]);
+
+    assert_eq!(saving_rate.rate, 0.0);
+}
+
+#[test]
+fn compute_totals_fails_when_the_amounts_overflow() {
+    let mut saving_rate = saving_rate_of_2024();
+    saving_rate.incomes.extra_balance = Milliunits::new(i64::MAX);
+    let mut income = transaction(date(2024, 3, 15), 1000000);
+    income.base.payee_id = Some(saving_rate.incomes.payee_ids[0]);
+
+    assert_eq!(saving_rate.compute_totals(&[income]), None);
+}
+
+#[test]
+fn meets_target_compares_the_rate_with_the_target() {
+    let saving_rate = SavingRate {
+        rate: 0.3,
+        target: None,
+        ..Faker.fake()
+    };
+    assert_eq!(saving_rate.meets_target(), None);
+
+    let saving_rate = SavingRate {
+        target: Some(0.3),
+        ..saving_rate
+    };
+    assert_eq!(saving_rate.meets_target(), Some(true));
+
+    let saving_rate = SavingRate {
+        target: Some(0.35),
+        ..saving_rate
+    };
+    assert_eq!(saving_rate.meets_target(), Some(false));
+}
@@ -2,6 +2,7 @@ use crate::{Month, NetTotals, Year};
 use chrono::{Datelike, NaiveDate};
 use fake::{faker::chrono::en::Date, Fake, Faker};
 use pretty_assertions::{assert_eq, assert_ne};
+use ynab::Milliunits;
 
 use crate::NetTotal;
 
@@ -12,11 +13,11 @@ fn sets_percent_var_as_0_when_no_prev_total() {
     let prev_year = Year {
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 ..Faker.fake()
             },
         },
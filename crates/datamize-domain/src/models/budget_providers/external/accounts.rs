@@ -3,6 +3,7 @@ use std::{fmt, str::FromStr};
 use secrecy::{CloneableSecret, DebugSecret, Secret, Zeroize};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use ynab::Milliunits;
 
 #[derive(Clone, Default)]
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
@@ -36,10 +37,16 @@ pub struct WebScrapingAccount {
     pub id: Uuid,
     pub name: String,
     pub account_type: AccountType,
-    pub balance: i64,
+    pub balance: Milliunits,
     pub username: String,
     pub encrypted_password: SecretPassword,
     pub deleted: bool,
+    /// The scraper getting the balance from the institution's website.
+    /// The balance of an account without one is never refreshed.
+    pub scraper_id: Option<String>,
+    /// The seed of the authenticator app, sealed like the password, for the institutions
+    /// asking for a one-time code.
+    pub encrypted_totp_seed: Option<SecretPassword>,
 }
 
 impl Default for WebScrapingAccount {
@@ -48,10 +55,12 @@ impl Default for WebScrapingAccount {
             id: Uuid::default(),
             name: String::default(),
             account_type: AccountType::default(),
-            balance: i64::default(),
+            balance: Milliunits::default(),
             username: String::default(),
             encrypted_password: SecretPassword::new(EncryptedPassword::default()),
             deleted: bool::default(),
+            scraper_id: None,
+            encrypted_totp_seed: None,
         }
     }
 }
@@ -111,7 +120,7 @@ pub struct ExternalAccount {
     #[serde(rename = "type")]
     pub account_type: AccountType,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    pub balance: i64,
+    pub balance: Milliunits,
     pub deleted: bool,
 }
 
@@ -0,0 +1,70 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use ynab::Milliunits;
+
+/// Where an observed balance of an external account comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BalanceSource {
+    /// Read by the scraper of the account.
+    Scrape,
+    /// Entered through the API.
+    Manual,
+    /// Set when creating or updating the account with the CLI.
+    Cli,
+    /// Read from a statement.
+    Import,
+}
+
+impl std::fmt::Display for BalanceSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BalanceSource::Scrape => write!(f, "scrape"),
+            BalanceSource::Manual => write!(f, "manual"),
+            BalanceSource::Cli => write!(f, "cli"),
+            BalanceSource::Import => write!(f, "import"),
+        }
+    }
+}
+
+impl FromStr for BalanceSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scrape" => Ok(Self::Scrape),
+            "manual" => Ok(Self::Manual),
+            "cli" => Ok(Self::Cli),
+            "import" => Ok(Self::Import),
+            _ => Err(format!("Failed to parse {:?} to BalanceSource", s)),
+        }
+    }
+}
+
+/// A balance of an external account, as it was at some point.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalAccountBalance {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub observed_at: DateTime<Utc>,
+    pub balance: Milliunits,
+    pub source: BalanceSource,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct BalanceHistoryParams {
+    /// Only the balances observed from then on.
+    pub from: Option<DateTime<Utc>>,
+    /// Only the balances observed before then.
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewExternalAccountBalance {
+    pub balance: Milliunits,
+    /// Defaults to now. A balance observed before the latest one is only kept in the history.
+    pub observed_at: Option<DateTime<Utc>>,
+}
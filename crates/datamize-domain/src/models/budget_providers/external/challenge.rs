@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A refresh waiting for the one-time code the institution sent by SMS or email.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingChallenge {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub account_name: String,
+    pub asked_at: DateTime<Utc>,
+    /// The refresh of the account fails when no code is submitted by then.
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChallengeCode {
+    pub code: String,
+}
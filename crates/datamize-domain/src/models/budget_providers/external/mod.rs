@@ -1,3 +1,11 @@
 mod accounts;
+mod balance;
+mod challenge;
+mod refresh;
+mod statement;
 
 pub use accounts::*;
+pub use balance::*;
+pub use challenge::*;
+pub use refresh::*;
+pub use statement::*;
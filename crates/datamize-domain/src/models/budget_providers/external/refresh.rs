@@ -0,0 +1,60 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use ynab::Milliunits;
+
+use super::ExternalAccount;
+
+/// The outcome of refreshing the balance of an external account with its scraper.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalAccountRefresh {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub refreshed_at: DateTime<Utc>,
+    /// How long the scraper took, in milliseconds.
+    pub duration_ms: i64,
+    pub succeeded: bool,
+    /// The balance read from the institution's website, when the refresh succeeded.
+    pub balance: Option<Milliunits>,
+    /// Why the refresh failed.
+    pub error: Option<String>,
+}
+
+/// An external account along with how its last refreshes went.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalAccountStatus {
+    #[serde(flatten)]
+    pub account: ExternalAccount,
+    pub scraper_id: Option<String>,
+    pub last_refresh: Option<ExternalAccountRefresh>,
+    pub last_success: Option<ExternalAccountRefresh>,
+    /// When the balance of an account with a scraper was not refreshed successfully for too long.
+    pub stale: bool,
+}
+
+impl ExternalAccountStatus {
+    /// An account is stale after this many days without a successful refresh.
+    pub const STALE_AFTER_DAYS: i64 = 2;
+
+    pub fn new(
+        account: ExternalAccount,
+        scraper_id: Option<String>,
+        last_refresh: Option<ExternalAccountRefresh>,
+        last_success: Option<ExternalAccountRefresh>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let stale = scraper_id.is_some()
+            && !account.deleted
+            && last_success.as_ref().map_or(true, |s| {
+                now - s.refreshed_at > Duration::days(Self::STALE_AFTER_DAYS)
+            });
+
+        Self {
+            account,
+            scraper_id,
+            last_refresh,
+            last_success,
+            stale,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ynab::Milliunits;
+
+use super::ExternalAccount;
+
+/// The formats of the statements institutions let us download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatementFormat {
+    /// OFX, QFX being the same with a few additions from Quicken.
+    Ofx,
+    /// A CSV read with one of the layouts configured.
+    Csv,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementTransaction {
+    /// The id given by the institution, when there is one.
+    pub id: Option<String>,
+    pub date: NaiveDate,
+    pub amount: Milliunits,
+    pub description: String,
+}
+
+/// The balance of an account read from a statement, along with its transactions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Statement {
+    pub balance: Milliunits,
+    /// The day of the balance, when the statement tells it.
+    pub balance_date: Option<NaiveDate>,
+    pub transactions: Vec<StatementTransaction>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StatementImportParams {
+    pub format: StatementFormat,
+    /// The name of the CSV layout, required for CSV statements.
+    pub layout: Option<String>,
+}
+
+/// The account updated with the balance of the statement imported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportedStatement {
+    pub account: ExternalAccount,
+    pub previous_balance: Milliunits,
+    pub balance_date: Option<NaiveDate>,
+    pub transactions: Vec<StatementTransaction>,
+}
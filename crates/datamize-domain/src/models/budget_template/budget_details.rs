@@ -1,14 +1,14 @@
 use rayon::prelude::*;
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt, str::FromStr};
 
-use chrono::{DateTime, Datelike, Local, Months};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Datelike, Local, Months, NaiveDate, TimeZone};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use uuid::Uuid;
-use ynab::types::Category;
+use ynab::{types::Category, Milliunits};
 
 use super::{
     expense::Computed, Budgeter, BudgeterExt, ComputedSalary, DatamizeScheduledTransaction,
-    Expense, ExpenseCategorization, ExpenseType, Uncomputed,
+    Expense, ExpenseAssociation, ExpenseCategorization, ExpenseType, Uncomputed,
 };
 
 #[derive(Debug, Deserialize, Default)]
@@ -17,25 +17,82 @@ pub struct TemplateParams {
     pub use_category_groups_as_sub_type: Option<CategoryGroupsAsSubType>,
 }
 
+/// The month a budget template is computed for.
+/// Deserialized from `previous`, `current`, `next` or any month formatted as `YYYY-MM`.
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
-#[derive(Debug, Deserialize, Default, Copy, Clone, PartialEq, Eq)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum MonthTarget {
     Previous,
     #[default]
     Current,
     Next,
+    /// Any month, represented by its first day.
+    Month(NaiveDate),
 }
 
-impl From<MonthTarget> for DateTime<Local> {
-    fn from(value: MonthTarget) -> Self {
-        match value {
-            MonthTarget::Previous => Local::now().checked_sub_months(Months::new(1)).unwrap(),
-            MonthTarget::Current => Local::now(),
-            MonthTarget::Next => Local::now().checked_add_months(Months::new(1)).unwrap(),
+impl MonthTarget {
+    /// Returns the first day of the targeted month, `Previous`, `Current` and `Next` being relative to `today`.
+    pub fn first_day(&self, today: NaiveDate) -> NaiveDate {
+        let first_day_current = today.with_day(1).unwrap();
+        match self {
+            MonthTarget::Previous => first_day_current
+                .checked_sub_months(Months::new(1))
+                .unwrap(),
+            MonthTarget::Current => first_day_current,
+            MonthTarget::Next => first_day_current
+                .checked_add_months(Months::new(1))
+                .unwrap(),
+            MonthTarget::Month(date) => date.with_day(1).unwrap(),
         }
-        .with_day(1)
-        .unwrap()
+    }
+
+    /// Returns the first day of the targeted month, keeping the time of `now`.
+    /// When that time does not exist on that day (e.g. in a DST gap), it is read as UTC.
+    pub fn first_day_from(&self, now: DateTime<Local>) -> DateTime<Local> {
+        let first_day = self.first_day(now.date_naive()).and_time(now.time());
+
+        first_day
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap_or_else(|| Local.from_utc_datetime(&first_day))
+    }
+
+    pub fn is_current(&self, today: NaiveDate) -> bool {
+        self.first_day(today) == today.with_day(1).unwrap()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseMonthTargetError;
+
+impl fmt::Display for ParseMonthTargetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected `previous`, `current`, `next` or a month as `YYYY-MM`"
+        )
+    }
+}
+
+impl FromStr for MonthTarget {
+    type Err = ParseMonthTargetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "previous" => Ok(Self::Previous),
+            "current" => Ok(Self::Current),
+            "next" => Ok(Self::Next),
+            _ => NaiveDate::parse_from_str(&format!("{}-01", s), "%Y-%m-%d")
+                .map(Self::Month)
+                .map_err(|_| ParseMonthTargetError),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MonthTarget {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
     }
 }
 
@@ -51,7 +108,7 @@ impl Default for CategoryGroupsAsSubType {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GlobalMetadata {
     /// Total income, before substracting health insurance and work-related retirement savings
-    pub total_monthly_income: i64,
+    pub total_monthly_income: Milliunits,
     /// The tartet each expense type should follow. For example, all fixed expenses shouldn't go over 60% of total income.
     pub proportion_target_per_expense_type: HashMap<ExpenseType, f64>,
 }
@@ -90,7 +147,7 @@ impl Default for GlobalMetadata {
         let proportion_target_per_expense_type = tuples.into_iter().collect();
 
         Self {
-            total_monthly_income: 0,
+            total_monthly_income: Milliunits::ZERO,
             proportion_target_per_expense_type,
         }
     }
@@ -142,12 +199,17 @@ impl BudgetDetails {
         hash_map
     }
 
+    /// Builds the details of the month of `date`.
+    /// Scheduled transactions dated after `today` are considered still to come.
+    #[allow(clippy::too_many_arguments)]
     pub fn build(
         categories: Vec<Category>,
         scheduled_transactions: Vec<DatamizeScheduledTransaction>,
         date: &DateTime<Local>,
+        today: NaiveDate,
         expenses_categorization: Vec<ExpenseCategorization>,
         budgeters: &[Budgeter<ComputedSalary>],
+        expenses_association: &[ExpenseAssociation],
         use_category_groups_as_sub_type: bool,
     ) -> Self {
         let mut scheduled_transactions_map =
@@ -162,7 +224,7 @@ impl BudgetDetails {
             .map(Into::<Expense<Uncomputed>>::into)
             .map(|e| {
                 e.set_categorization(&expenses_categorization, use_category_groups_as_sub_type)
-                    .set_individual_association(budgeters)
+                    .set_individual_association(budgeters, expenses_association)
             })
             .filter(|e| e.expense_type() != &ExpenseType::Undefined)
             .map(|e| match scheduled_transactions_map.remove(&e.id()) {
@@ -180,8 +242,8 @@ impl BudgetDetails {
         let expenses = filtered_expenses
             .into_par_iter()
             .map(|e| {
-                e.build_dates()
-                    .compute_amounts()
+                e.build_dates(date)
+                    .compute_amounts(today)
                     .compute_proportions(total_monthly_income)
             })
             .collect();
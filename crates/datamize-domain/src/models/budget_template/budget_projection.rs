@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use ynab::{types::Category, Milliunits};
+
+use super::{
+    expense::Computed, BudgetDetails, BudgetSummary, Budgeter, BudgeterConfig,
+    CategoryGroupsAsSubType, ComputedSalary, Configured, DatamizeScheduledTransaction, Expense,
+    ExpenseAssociation, ExpenseCategorization, ExpenseType, MonthTarget,
+};
+
+/// Number of months projected when no range is specified.
+pub const DEFAULT_PROJECTION_MONTHS: u32 = 12;
+/// Maximum number of months a projection can cover.
+pub const MAX_PROJECTION_MONTHS: u32 = 60;
+
+#[derive(Debug, Deserialize, Default)]
+pub struct ProjectionParams {
+    /// First month of the projection. Defaults to the current month.
+    pub from: Option<MonthTarget>,
+    /// Last month of the projection, included. Defaults to 11 months after `from`.
+    pub to: Option<MonthTarget>,
+    pub use_category_groups_as_sub_type: Option<CategoryGroupsAsSubType>,
+}
+
+/// The budget template computed for a single month of a projection.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct MonthProjection {
+    /// First day of the projected month.
+    month: NaiveDate,
+    expenses: Vec<Expense<Computed>>,
+    budgeters: Vec<Budgeter<ComputedSalary>>,
+    summary: BudgetSummary,
+    /// Total income of all budgeters for the month.
+    total_income: Milliunits,
+    /// Amount expected to be spent in the month, per expense type.
+    total_per_expense_type: HashMap<ExpenseType, Milliunits>,
+}
+
+impl MonthProjection {
+    pub fn month(&self) -> NaiveDate {
+        self.month
+    }
+
+    pub fn expenses(&self) -> &[Expense<Computed>] {
+        &self.expenses
+    }
+
+    pub fn budgeters(&self) -> &[Budgeter<ComputedSalary>] {
+        &self.budgeters
+    }
+
+    pub fn summary(&self) -> &BudgetSummary {
+        &self.summary
+    }
+
+    pub fn total_income(&self) -> Milliunits {
+        self.total_income
+    }
+
+    pub fn total_per_expense_type(&self) -> &HashMap<ExpenseType, Milliunits> {
+        &self.total_per_expense_type
+    }
+}
+
+/// The budget template computed over a range of months, with the totals of the whole range.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct BudgetProjection {
+    months: Vec<MonthProjection>,
+    total_income: Milliunits,
+    total_per_expense_type: HashMap<ExpenseType, Milliunits>,
+}
+
+impl BudgetProjection {
+    pub fn months(&self) -> &[MonthProjection] {
+        &self.months
+    }
+
+    pub fn total_income(&self) -> Milliunits {
+        self.total_income
+    }
+
+    pub fn total_per_expense_type(&self) -> &HashMap<ExpenseType, Milliunits> {
+        &self.total_per_expense_type
+    }
+
+    /// Builds the template of each month in `months`, all from the same categories and scheduled transactions.
+    /// Scheduled transactions dated after `today` are considered still to come.
+    #[allow(clippy::too_many_arguments)]
+    pub fn build(
+        categories: Vec<Category>,
+        scheduled_transactions: Vec<DatamizeScheduledTransaction>,
+        months: &[DateTime<Local>],
+        today: NaiveDate,
+        expenses_categorization: Vec<ExpenseCategorization>,
+        budgeters_config: Vec<BudgeterConfig>,
+        expenses_association: Vec<ExpenseAssociation>,
+        use_category_groups_as_sub_type: bool,
+    ) -> Self {
+        let inflow_cat_id = categories
+            .iter()
+            .find(|c| c.name.contains("Ready to Assign"))
+            .map(|c| c.id);
+
+        let months: Vec<_> = months
+            .iter()
+            .map(|month| {
+                let budgeters: Vec<_> = budgeters_config
+                    .iter()
+                    .cloned()
+                    .map(|bc| {
+                        Budgeter::<Configured>::from(bc).compute_salary(
+                            &scheduled_transactions,
+                            month,
+                            inflow_cat_id,
+                        )
+                    })
+                    .collect();
+
+                let details = BudgetDetails::build(
+                    categories.clone(),
+                    scheduled_transactions.clone(),
+                    month,
+                    today,
+                    expenses_categorization.clone(),
+                    &budgeters,
+                    &expenses_association,
+                    use_category_groups_as_sub_type,
+                );
+                let summary = BudgetSummary::build(&details, budgeters.clone());
+
+                let mut total_per_expense_type = HashMap::new();
+                for e in details.expenses() {
+                    *total_per_expense_type
+                        .entry(e.expense_type().clone())
+                        .or_default() += month_spending(e, month.date_naive());
+                }
+
+                MonthProjection {
+                    month: month.date_naive(),
+                    total_income: details.global_metadata().total_monthly_income,
+                    expenses: details.expenses,
+                    budgeters,
+                    summary,
+                    total_per_expense_type,
+                }
+            })
+            .collect();
+
+        let total_income = months.iter().map(|m| m.total_income).sum();
+        let mut total_per_expense_type = HashMap::new();
+        for (expense_type, amount) in months.iter().flat_map(|m| &m.total_per_expense_type) {
+            *total_per_expense_type
+                .entry(expense_type.clone())
+                .or_default() += *amount;
+        }
+
+        Self {
+            months,
+            total_income,
+            total_per_expense_type,
+        }
+    }
+}
+
+/// The goal's target when it is due in `month`, or the scheduled transactions occurring in the month
+/// when the category has no goal (e.g. an annual bill).
+/// Goals without a due date, or repeating weekly, are spread over the months.
+fn month_spending(expense: &Expense<Computed>, month: NaiveDate) -> Milliunits {
+    let category = expense.category();
+    match category.goal_type {
+        Some(_) => match (
+            goal_interval_in_months(category),
+            category.goal_target_month,
+            category.goal_target,
+        ) {
+            (Some(interval), Some(due_month), Some(target)) => {
+                let months_from_due = (month.year() - due_month.year()) * 12 + month.month() as i32
+                    - due_month.month() as i32;
+                let is_due = match interval {
+                    0 => months_from_due == 0,
+                    interval => months_from_due.rem_euclid(interval) == 0,
+                };
+
+                if is_due {
+                    target
+                } else {
+                    Milliunits::ZERO
+                }
+            }
+            _ => expense.projected_amount(),
+        },
+        None => expense
+            .scheduled_transactions()
+            .iter()
+            .map(|st| -Milliunits::new(st.amount))
+            .sum(),
+    }
+}
+
+/// Number of months between two due dates of the goal, `0` when it does not repeat.
+/// `None` when the goal repeats weekly, as it is then due every month.
+fn goal_interval_in_months(category: &Category) -> Option<i32> {
+    let frequency = category.goal_cadence_frequency.unwrap_or(1).max(1);
+    match category.goal_cadence {
+        None | Some(0) => Some(0),
+        Some(1) => Some(frequency),
+        Some(cadence @ 3..=12) => Some(cadence - 1),
+        Some(13) => Some(12 * frequency),
+        Some(14) => Some(24),
+        Some(_) => None,
+    }
+}
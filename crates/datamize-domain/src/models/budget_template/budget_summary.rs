@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{BudgetDetails, Budgeter, ComputedExpenses, ComputedSalary, TotalBudgeter};
 
-/// A proportionally split budget's expenses.
+/// A budget's expenses, split between budgeters.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct BudgetSummary {
     budgeters: Vec<Budgeter<ComputedExpenses>>,
@@ -19,13 +19,14 @@ impl BudgetSummary {
     }
 
     pub fn build(budget_details: &BudgetDetails, budgeters: Vec<Budgeter<ComputedSalary>>) -> Self {
-        let (total_budgeter, individual_expenses) = TotalBudgeter::new()
+        let (total_budgeter, _) = TotalBudgeter::new()
             .compute_salary(&budgeters)
             .compute_expenses(budget_details.expenses(), &budgeters);
 
         let budgeters: Vec<_> = budgeters
-            .into_iter()
-            .map(|b| b.compute_expenses(&total_budgeter, &individual_expenses))
+            .iter()
+            .cloned()
+            .map(|b| b.compute_expenses(&total_budgeter, budget_details.expenses(), &budgeters))
             .collect();
 
         Self {
@@ -3,11 +3,12 @@ use std::collections::HashMap;
 use chrono::{DateTime, Datelike, Local};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use ynab::Milliunits;
 
 use crate::{
     models::budget_template::expense, BudgeterConfig, BudgeterExt, BudgeterState, ComputedExpenses,
     ComputedSalary, Configured, DatamizeScheduledTransaction, Expense, SalaryFragment,
-    TotalBudgeter,
+    SplitStrategy, TotalBudgeter,
 };
 
 /// A Budgeter represents someone that has income and expenses for the month.
@@ -79,7 +80,7 @@ impl Budgeter<Configured> {
         let salary_month = fragmented_salary
             .values()
             .flatten()
-            .map(|fs| fs.payee_amount * fs.occurrences.len() as i64)
+            .flat_map(|fs| fs.occurrences.iter().map(|_| fs.payee_amount))
             .sum();
 
         Budgeter {
@@ -98,7 +99,7 @@ impl Budgeter<Configured> {
     pub(crate) fn get_payee_amount(
         scheduled_transaction: &DatamizeScheduledTransaction,
         inflow_cat_id: Option<Uuid>,
-    ) -> i64 {
+    ) -> Milliunits {
         if let Some(inflow) = inflow_cat_id.and_then(|inflow_cat_id| {
             scheduled_transaction.subtransactions.iter().find(|st| {
                 st.category_id
@@ -106,9 +107,9 @@ impl Budgeter<Configured> {
                     .unwrap_or(false)
             })
         }) {
-            inflow.amount
+            inflow.amount.into()
         } else {
-            scheduled_transaction.amount
+            scheduled_transaction.amount.into()
         }
     }
 }
@@ -128,20 +129,46 @@ impl BudgeterExt for Budgeter<Configured> {
 }
 
 impl Budgeter<ComputedSalary> {
+    /// Individual expenses are the ones associated with the budgeter. The others are common expenses,
+    /// split between all `budgeters` according to their `SplitStrategy`, the one of their association first.
     pub fn compute_expenses(
         self,
         total_budgeter: &TotalBudgeter<ComputedExpenses>,
-        expenses: &[&Expense<expense::Computed>],
+        expenses: &[Expense<expense::Computed>],
+        budgeters: &[Budgeter<ComputedSalary>],
     ) -> Budgeter<ComputedExpenses> {
-        let proportion = if total_budgeter.salary_month() == 0 {
-            0.0
-        } else {
-            self.extra.salary_month as f64 / total_budgeter.salary_month() as f64
+        let total_salary = total_budgeter.salary_month();
+        let proportion_of = |budgeter: &Budgeter<ComputedSalary>| {
+            if total_salary == Milliunits::ZERO {
+                0.0
+            } else {
+                budgeter.extra.salary_month.to_f64() / total_salary.to_f64()
+            }
         };
-        let common_expenses = (proportion * total_budgeter.common_expenses() as f64) as i64;
-        let individual_expenses = expenses
+        let proportion = proportion_of(&self);
+
+        let (individual_expenses, common_expenses): (Vec<_>, Vec<_>) = expenses
             .iter()
-            .filter(|e| e.name().contains(&self.extra.configured.name))
+            .partition(|e| TotalBudgeter::<ComputedSalary>::is_individual_expense(e, budgeters));
+
+        let mut proportional_expenses = Milliunits::ZERO;
+        let mut other_shares = Milliunits::ZERO;
+        for e in common_expenses {
+            match e.split_strategy_override().unwrap_or(e.split_strategy()) {
+                SplitStrategy::Proportional => proportional_expenses += e.projected_amount(),
+                strategy => {
+                    other_shares += self.part_of(e.projected_amount(), budgeters, |b| {
+                        strategy.share(b.id(), proportion_of(b), budgeters.len())
+                    })
+                }
+            }
+        }
+        let common_expenses =
+            self.part_of(proportional_expenses, budgeters, proportion_of) + other_shares;
+
+        let individual_expenses = individual_expenses
+            .iter()
+            .filter(|e| e.is_individual_expense_of(&self))
             .map(|e| e.projected_amount())
             .sum();
         let left_over = self.extra.salary_month - common_expenses - individual_expenses;
@@ -160,6 +187,26 @@ impl Budgeter<ComputedSalary> {
     pub fn fragmented_salary(&self) -> &HashMap<Uuid, Vec<SalaryFragment>> {
         &self.extra.fragmented_salary
     }
+
+    /// The part of `amount` paid by the budgeter, given the `share` of each of the `budgeters`.
+    /// The cumulated shares are rounded rather than each share, so the parts of all budgeters add up to `amount`.
+    fn part_of(
+        &self,
+        amount: Milliunits,
+        budgeters: &[Budgeter<ComputedSalary>],
+        share: impl Fn(&Budgeter<ComputedSalary>) -> f64,
+    ) -> Milliunits {
+        let mut shares_before = 0.0;
+        for b in budgeters {
+            let share = share(b);
+            if b.id() == self.id() {
+                return amount.mul_f64(shares_before + share) - amount.mul_f64(shares_before);
+            }
+            shares_before += share;
+        }
+
+        amount.mul_f64(share(self))
+    }
 }
 
 impl Budgeter<ComputedExpenses> {
@@ -181,7 +228,7 @@ impl BudgeterExt for Budgeter<ComputedSalary> {
         &self.extra.configured.payee_ids
     }
 
-    fn salary_month(&self) -> i64 {
+    fn salary_month(&self) -> Milliunits {
         self.extra.salary_month
     }
 }
@@ -199,7 +246,7 @@ impl BudgeterExt for Budgeter<ComputedExpenses> {
         &self.extra.compuded_salary.configured.payee_ids
     }
 
-    fn salary_month(&self) -> i64 {
+    fn salary_month(&self) -> Milliunits {
         self.extra.compuded_salary.salary_month
     }
 
@@ -207,15 +254,15 @@ impl BudgeterExt for Budgeter<ComputedExpenses> {
         self.extra.proportion
     }
 
-    fn common_expenses(&self) -> i64 {
+    fn common_expenses(&self) -> Milliunits {
         self.extra.common_expenses
     }
 
-    fn individual_expenses(&self) -> i64 {
+    fn individual_expenses(&self) -> Milliunits {
         self.extra.individual_expenses
     }
 
-    fn left_over(&self) -> i64 {
+    fn left_over(&self) -> Milliunits {
         self.extra.left_over
     }
 }
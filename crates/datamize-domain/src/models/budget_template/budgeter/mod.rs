@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use ynab::Milliunits;
 
 // Individual budgeter
 mod i_budgeter;
@@ -17,7 +18,7 @@ pub trait BudgeterExt {
     fn name(&self) -> &str;
     fn payee_ids(&self) -> &[Uuid];
 
-    fn salary_month(&self) -> i64 {
+    fn salary_month(&self) -> Milliunits {
         Default::default()
     }
 
@@ -25,15 +26,15 @@ pub trait BudgeterExt {
         Default::default()
     }
 
-    fn common_expenses(&self) -> i64 {
+    fn common_expenses(&self) -> Milliunits {
         Default::default()
     }
 
-    fn individual_expenses(&self) -> i64 {
+    fn individual_expenses(&self) -> Milliunits {
         Default::default()
     }
 
-    fn left_over(&self) -> i64 {
+    fn left_over(&self) -> Milliunits {
         Default::default()
     }
 }
@@ -58,7 +59,7 @@ pub struct SalaryFragment {
     /// Name of the payee if defined.
     pub payee_name: Option<String>,
     /// Amount of this salary.
-    pub payee_amount: i64,
+    pub payee_amount: Milliunits,
     /// Dates when this salary fragment is repeated throughout the month.
     #[cfg_attr(any(feature = "testutils", test), dummy(default))]
     pub occurrences: Vec<NaiveDate>,
@@ -71,7 +72,7 @@ pub struct ComputedSalary {
     configured: Configured,
     /// Total salary inflow for this month. This number can vary from one month to the other.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0..10000000"))]
-    salary_month: i64,
+    salary_month: Milliunits,
     /// Gives a breakdown of what is composing the salary for this month.
     fragmented_salary: HashMap<Uuid, Vec<SalaryFragment>>,
 }
@@ -86,13 +87,13 @@ pub struct ComputedExpenses {
     proportion: f64,
     /// The common expenses of this budgeter for this month.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0..100000"))]
-    common_expenses: i64,
+    common_expenses: Milliunits,
     /// The individual expenses of this budgeter for this month.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0..100000"))]
-    individual_expenses: i64,
+    individual_expenses: Milliunits,
     /// The left over amount for this budgeter.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0..10000"))]
-    left_over: i64,
+    left_over: Milliunits,
 }
 
 pub trait BudgeterState {}
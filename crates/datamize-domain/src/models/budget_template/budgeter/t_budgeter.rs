@@ -3,6 +3,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use ynab::Milliunits;
 
 use crate::{
     models::budget_template::expense, Budgeter, BudgeterExt, BudgeterState, ComputedExpenses,
@@ -57,11 +58,11 @@ impl BudgeterExt for TotalBudgeter<Configured> {
 }
 
 impl TotalBudgeter<ComputedSalary> {
-    fn is_individual_expense(
+    pub(crate) fn is_individual_expense(
         e: &Expense<expense::Computed>,
         budgeters: &[Budgeter<ComputedSalary>],
     ) -> bool {
-        budgeters.iter().any(|b| e.name().contains(b.name()))
+        budgeters.iter().any(|b| e.is_individual_expense_of(b))
     }
 
     pub fn compute_expenses<'a>(
@@ -124,7 +125,7 @@ impl BudgeterExt for TotalBudgeter<ComputedSalary> {
         &self.extra.configured.payee_ids
     }
 
-    fn salary_month(&self) -> i64 {
+    fn salary_month(&self) -> Milliunits {
         self.extra.salary_month
     }
 }
@@ -142,7 +143,7 @@ impl BudgeterExt for TotalBudgeter<ComputedExpenses> {
         &self.extra.compuded_salary.configured.payee_ids
     }
 
-    fn salary_month(&self) -> i64 {
+    fn salary_month(&self) -> Milliunits {
         self.extra.compuded_salary.salary_month
     }
 
@@ -150,15 +151,15 @@ impl BudgeterExt for TotalBudgeter<ComputedExpenses> {
         self.extra.proportion
     }
 
-    fn common_expenses(&self) -> i64 {
+    fn common_expenses(&self) -> Milliunits {
         self.extra.common_expenses
     }
 
-    fn individual_expenses(&self) -> i64 {
+    fn individual_expenses(&self) -> Milliunits {
         self.extra.individual_expenses
     }
 
-    fn left_over(&self) -> i64 {
+    fn left_over(&self) -> Milliunits {
         self.extra.left_over
     }
 }
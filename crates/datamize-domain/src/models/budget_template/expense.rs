@@ -1,13 +1,17 @@
 use std::{fmt, str::FromStr};
 
-use chrono::{DateTime, Datelike, Days, Local, Months, TimeZone};
+use chrono::{DateTime, Datelike, Days, Local, Months, NaiveDate, TimeZone};
 use rrule::{Frequency, NWeekday, RRule, Tz, Weekday};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use ynab::types::{Category, GoalType};
+use ynab::{
+    types::{Category, GoalType},
+    Milliunits,
+};
 
 use super::{
-    Budgeter, BudgeterExt, ComputedSalary, DatamizeScheduledTransaction, ExpenseCategorization,
+    AssociationType, Budgeter, BudgeterExt, ComputedSalary, DatamizeScheduledTransaction,
+    ExpenseAssociation, ExpenseCategorization, SplitStrategy,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,6 +34,18 @@ pub struct Expense<S: ExpenseState> {
     sub_expense_type: String,
     /// The individual associated with the expense. This is used to let know this expense is associated with a person in particular.
     individual_associated: Option<String>,
+    /// The id of the budgeter associated with the expense, see `individual_associated`.
+    #[serde(default)]
+    individual_budgeter_id: Option<Uuid>,
+    /// Whether an association explicitly marks the expense as shared, so it is never matched to a budgeter by name.
+    #[serde(default)]
+    shared: bool,
+    /// How the expense is split between budgeters when it is not an individual one.
+    #[serde(default)]
+    split_strategy: SplitStrategy,
+    /// The split strategy of the expense's association, used instead of `split_strategy` when set.
+    #[serde(default)]
+    split_strategy_override: Option<SplitStrategy>,
     #[serde(skip)]
     category: Category,
     #[serde(skip)]
@@ -61,6 +77,32 @@ impl<S: ExpenseState> Expense<S> {
         self.individual_associated.as_ref()
     }
 
+    pub fn individual_budgeter_id(&self) -> Option<Uuid> {
+        self.individual_budgeter_id
+    }
+
+    /// Whether the expense is paid only by `budgeter`. When the expense was neither associated with any budgeter
+    /// nor marked as shared, falls back to checking if its name contains the budgeter's name.
+    pub fn is_individual_expense_of<B: BudgeterExt>(&self, budgeter: &B) -> bool {
+        match self.individual_budgeter_id {
+            Some(budgeter_id) => budgeter_id == budgeter.id(),
+            None => !self.shared && self.is_named_after(budgeter),
+        }
+    }
+
+    /// How an expense without association is matched to a budgeter.
+    fn is_named_after<B: BudgeterExt>(&self, budgeter: &B) -> bool {
+        self.name.contains(budgeter.name())
+    }
+
+    pub fn split_strategy(&self) -> &SplitStrategy {
+        &self.split_strategy
+    }
+
+    pub fn split_strategy_override(&self) -> Option<&SplitStrategy> {
+        self.split_strategy_override.as_ref()
+    }
+
     pub fn category(&self) -> &Category {
         &self.category
     }
@@ -69,10 +111,11 @@ impl<S: ExpenseState> Expense<S> {
         &self.scheduled_transactions
     }
 
-    pub fn build_dates(mut self) -> Self {
+    /// Builds the dates needed to compute goals repeating weekly during the month of `date`.
+    pub fn build_dates(mut self, date: &DateTime<Local>) -> Self {
         if let Some(start_date) = self.category.goal_creation_month {
             // Last day previous month
-            let dt_start = Local::now()
+            let dt_start = date
                 .with_day(1)
                 .and_then(|d| d.checked_sub_days(Days::new(1)))
                 .and_then(|d| {
@@ -86,7 +129,7 @@ impl<S: ExpenseState> Expense<S> {
                 .and_then(|d| Tz::Local(Local).from_local_datetime(&d).single());
 
             // Last day current month
-            let dt_end = Local::now()
+            let dt_end = date
                 .checked_add_months(Months::new(1))
                 .and_then(|d| d.with_day(1))
                 .and_then(|d| d.checked_sub_days(Days::new(1)))
@@ -124,17 +167,59 @@ impl<S: ExpenseState> Expense<S> {
                 } else {
                     categorization.sub_expense_type.clone().to_string()
                 };
+                self.split_strategy = categorization.split_strategy.clone();
                 self
             }
             None => self,
         }
     }
 
-    pub fn set_individual_association(mut self, budgeters: &[Budgeter<ComputedSalary>]) -> Self {
-        self.individual_associated = budgeters
-            .iter()
-            .find(|b| self.name.contains(b.name()))
-            .map(|b| b.name().to_string());
+    /// Associates the expense with the budgeter mapped to its category, or else to its category group.
+    /// An association without budgeter, or with a deleted one, marks the expense as shared, and may override its
+    /// split strategy. Only when neither is mapped, falls back to the budgeter whose name the expense contains.
+    pub fn set_individual_association(
+        mut self,
+        budgeters: &[Budgeter<ComputedSalary>],
+        expenses_association: &[ExpenseAssociation],
+    ) -> Self {
+        let find_association = |id: Uuid, association_type: AssociationType| {
+            expenses_association
+                .iter()
+                .find(|a| a.id == id && a.association_type == association_type)
+        };
+
+        // The association of the category wins over the one of its group, even when it marks it as shared.
+        let association =
+            find_association(self.category.id, AssociationType::Category).or_else(|| {
+                find_association(
+                    self.category.category_group_id,
+                    AssociationType::CategoryGroup,
+                )
+            });
+
+        // Likewise, the split strategy of the category wins over the one of its group.
+        self.split_strategy_override =
+            find_association(self.category.id, AssociationType::Category)
+                .and_then(|a| a.split_strategy.clone())
+                .or_else(|| {
+                    find_association(
+                        self.category.category_group_id,
+                        AssociationType::CategoryGroup,
+                    )
+                    .and_then(|a| a.split_strategy.clone())
+                });
+
+        let budgeter = match association {
+            // An association to a budgeter since deleted leaves the expense shared.
+            Some(association) => association
+                .budgeter_id
+                .and_then(|budgeter_id| budgeters.iter().find(|b| b.id() == budgeter_id)),
+            None => budgeters.iter().find(|b| self.is_named_after(*b)),
+        };
+        self.shared = association.is_some() && budgeter.is_none();
+
+        self.individual_associated = budgeter.map(|b| b.name().to_string());
+        self.individual_budgeter_id = budgeter.map(|b| b.id());
         self
     }
 }
@@ -152,11 +237,12 @@ impl Expense<Uncomputed> {
         self
     }
 
-    pub fn compute_amounts(mut self) -> Expense<PartiallyComputed> {
+    /// Scheduled transactions dated after `today` are considered still to come in the current amount.
+    pub fn compute_amounts(mut self, today: NaiveDate) -> Expense<PartiallyComputed> {
         Expense {
             extra: PartiallyComputed {
                 projected_amount: self.compute_projected_amount(),
-                current_amount: self.compute_current_amount(),
+                current_amount: self.compute_current_amount(today),
             },
             id: self.id,
             name: self.name,
@@ -164,12 +250,16 @@ impl Expense<Uncomputed> {
             sub_expense_type: self.sub_expense_type,
             category: self.category,
             individual_associated: self.individual_associated,
+            individual_budgeter_id: self.individual_budgeter_id,
+            shared: self.shared,
+            split_strategy: self.split_strategy,
+            split_strategy_override: self.split_strategy_override,
             scheduled_transactions: self.scheduled_transactions,
             weekly_cadence_data: self.weekly_cadence_data,
         }
     }
 
-    fn compute_projected_amount(&mut self) -> i64 {
+    fn compute_projected_amount(&mut self) -> Milliunits {
         match self.category.goal_type {
             Some(GoalType::PlanYourSpending) => {
                 match (
@@ -187,7 +277,7 @@ impl Expense<Uncomputed> {
                         ), // Goal repeats weekly
                     (Some(cad @ 3..=13), _, Some(target)) => target / (cad - 1) as i64, // Goal repeats X months (up to yearly)
                     (Some(14), _, Some(target)) => target / 24, // Goal repeats every 2 years
-                    (_, _, _) => 0,
+                    (_, _, _) => Milliunits::ZERO,
                 }
             }
             Some(GoalType::TargetBalanceByDate) => {
@@ -195,12 +285,12 @@ impl Expense<Uncomputed> {
                     // If goal was fully funded, simply return what was budgeted
                     Some(0) => self.category.budgeted,
                     // If goal was partially funded, add the budgeted amount + what is left to reach goal
-                    Some(i) => i + self.category.budgeted,
-                    None => 0,
+                    Some(i) => Milliunits::new(i) + self.category.budgeted,
+                    None => Milliunits::ZERO,
                 }
             }
-            Some(_) => self.category.goal_target.unwrap_or(0),
-            None => 0,
+            Some(_) => self.category.goal_target.unwrap_or_default(),
+            None => Milliunits::ZERO,
         }
     }
 
@@ -208,8 +298,8 @@ impl Expense<Uncomputed> {
         &self,
         interval: u16,
         weekday: Option<Weekday>,
-        target: i64,
-    ) -> i64 {
+        target: Milliunits,
+    ) -> Milliunits {
         if let Some(dates) = &self.weekly_cadence_data {
             let mut rrule = RRule::new(Frequency::Weekly)
                 .interval(interval)
@@ -230,48 +320,44 @@ impl Expense<Uncomputed> {
             }
         }
 
-        0
+        Milliunits::ZERO
     }
 
-    fn compute_current_amount(&mut self) -> i64 {
+    fn compute_current_amount(&mut self, today: NaiveDate) -> Milliunits {
         let current_amount_budgeted = match self.category.goal_type {
             Some(_) => match self.category.goal_under_funded {
                 // If goal was fully funded, simply return what was budgeted
                 Some(0) => self.category.budgeted,
                 // If goal was partially funded, add the budgeted amount + what is left to reach goal
-                Some(i) => i + self.category.budgeted,
-                None => 0,
+                Some(i) => Milliunits::new(i) + self.category.budgeted,
+                None => Milliunits::ZERO,
             },
             None => self.category.budgeted,
         };
 
-        let mut current_amount = if current_amount_budgeted >= 0 {
-            current_amount_budgeted
-        } else {
-            0
-        };
+        let mut current_amount = current_amount_budgeted.max(Milliunits::ZERO);
 
         if !self.scheduled_transactions.is_empty() && self.category.goal_type.is_none() {
             let scheduled_transactions_total = self
                 .scheduled_transactions
                 .iter()
-                .map(|v| -v.amount)
-                .sum::<i64>();
+                .map(|v| -Milliunits::new(v.amount))
+                .sum::<Milliunits>();
 
             if current_amount != scheduled_transactions_total {
-                let current_date = Local::now().date_naive();
                 let future_transactions_amount = self
                     .scheduled_transactions
                     .iter()
                     // Current amount should only take into account scheduled transactions from future. those in past should instead be taken from budgeted section.
-                    .filter(|&st| st.date_next > current_date)
-                    .map(|st| -st.amount)
-                    .sum::<i64>();
+                    .filter(|&st| st.date_next > today)
+                    .map(|st| -Milliunits::new(st.amount))
+                    .sum::<Milliunits>();
 
-                if future_transactions_amount > 0
-                    && future_transactions_amount > self.category.balance
+                let balance = self.category.balance;
+                if future_transactions_amount > Milliunits::ZERO
+                    && future_transactions_amount > balance
                 {
-                    current_amount = future_transactions_amount - self.category.balance;
+                    current_amount = future_transactions_amount - balance;
                 }
             }
         }
@@ -285,34 +371,34 @@ impl Expense<Uncomputed> {
 pub struct PartiallyComputed {
     /// Will either be the goal_under_funded, the goal_target for the month or the amount of the linked scheduled transaction coming in the month.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    projected_amount: i64,
+    projected_amount: Milliunits,
     /// At the begining of the month, this amount will be the same as projected_amount,
     /// but it will get updated during the month when some expenses occur in the category.
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-1000000..1000000"))]
-    current_amount: i64,
+    current_amount: Milliunits,
 }
 
 impl Expense<PartiallyComputed> {
-    pub fn projected_amount(&self) -> i64 {
+    pub fn projected_amount(&self) -> Milliunits {
         self.extra.projected_amount
     }
 
-    pub fn current_amount(&self) -> i64 {
+    pub fn current_amount(&self) -> Milliunits {
         self.extra.current_amount
     }
 
-    pub fn compute_proportions(self, total_income: i64) -> Expense<Computed> {
+    pub fn compute_proportions(self, total_income: Milliunits) -> Expense<Computed> {
         Expense {
             extra: Computed {
-                projected_proportion: if total_income == 0 {
+                projected_proportion: if total_income == Milliunits::ZERO {
                     0.0
                 } else {
-                    self.extra.projected_amount as f64 / total_income as f64
+                    self.extra.projected_amount.to_f64() / total_income.to_f64()
                 },
-                current_proportion: if total_income == 0 {
+                current_proportion: if total_income == Milliunits::ZERO {
                     0.0
                 } else {
-                    self.extra.current_amount as f64 / total_income as f64
+                    self.extra.current_amount.to_f64() / total_income.to_f64()
                 },
                 partially_computed: PartiallyComputed {
                     projected_amount: self.extra.projected_amount,
@@ -325,6 +411,10 @@ impl Expense<PartiallyComputed> {
             sub_expense_type: self.sub_expense_type,
             category: self.category,
             individual_associated: self.individual_associated,
+            individual_budgeter_id: self.individual_budgeter_id,
+            shared: self.shared,
+            split_strategy: self.split_strategy,
+            split_strategy_override: self.split_strategy_override,
             scheduled_transactions: self.scheduled_transactions,
             weekly_cadence_data: self.weekly_cadence_data,
         }
@@ -345,11 +435,11 @@ pub struct Computed {
 }
 
 impl Expense<Computed> {
-    pub fn projected_amount(&self) -> i64 {
+    pub fn projected_amount(&self) -> Milliunits {
         self.extra.partially_computed.projected_amount
     }
 
-    pub fn current_amount(&self) -> i64 {
+    pub fn current_amount(&self) -> Milliunits {
         self.extra.partially_computed.current_amount
     }
 
@@ -515,6 +605,10 @@ impl<S: ExpenseState + fake::Dummy<fake::Faker>> fake::Dummy<fake::Faker> for Ex
             expense_type,
             sub_expense_type,
             individual_associated,
+            individual_budgeter_id: None,
+            shared: false,
+            split_strategy: SplitStrategy::default(),
+            split_strategy_override: None,
             category,
             scheduled_transactions,
             extra,
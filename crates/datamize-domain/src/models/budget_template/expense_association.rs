@@ -0,0 +1,82 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::SplitStrategy;
+
+/// Associates a YNAB category, or all the categories of a category group, with the budgeter paying for it alone,
+/// or marks it as shared between all the budgeters.
+#[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, PartialEq, Eq)]
+pub struct ExpenseAssociation {
+    /// The id of the category, or of the category group when `association_type` is `CategoryGroup`.
+    pub id: Uuid,
+    #[serde(rename = "type", default)]
+    #[sqlx(rename = "type")]
+    pub association_type: AssociationType,
+    /// `None` marks the expenses as shared, i.e. to share a category of a group associated with a budgeter.
+    pub budgeter_id: Option<Uuid>,
+    /// Overrides the split strategy of the category group for the shared expenses. `None` keeps the group's one.
+    #[serde(default)]
+    #[cfg_attr(any(feature = "testutils", test), dummy(default))]
+    pub split_strategy: Option<SplitStrategy>,
+}
+
+#[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+pub enum AssociationType {
+    #[default]
+    Category,
+    CategoryGroup,
+}
+
+impl fmt::Display for AssociationType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AssociationType::Category => write!(f, "category"),
+            AssociationType::CategoryGroup => write!(f, "categoryGroup"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseAssociationTypeError;
+
+impl FromStr for AssociationType {
+    type Err = ParseAssociationTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "category" => Ok(Self::Category),
+            "categoryGroup" => Ok(Self::CategoryGroup),
+            _ => Err(ParseAssociationTypeError),
+        }
+    }
+}
+
+#[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveExpenseAssociation {
+    #[serde(rename = "type", default)]
+    pub association_type: AssociationType,
+    /// Omitted or `null` to mark the expenses as shared.
+    #[serde(default)]
+    pub budgeter_id: Option<Uuid>,
+    /// Omitted or `null` to keep the split strategy of the category group.
+    #[serde(default)]
+    #[cfg_attr(any(feature = "testutils", test), dummy(default))]
+    pub split_strategy: Option<SplitStrategy>,
+}
+
+impl ExpenseAssociation {
+    pub fn new(id: Uuid, value: SaveExpenseAssociation) -> Self {
+        Self {
+            id,
+            association_type: value.association_type,
+            budgeter_id: value.budgeter_id,
+            split_strategy: value.split_strategy,
+        }
+    }
+}
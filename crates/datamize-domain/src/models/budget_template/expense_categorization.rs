@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use ynab::types::{Category, CategoryGroup, CategoryGroupWithCategories};
 
-use super::{ExpenseType, SubExpenseType};
+use super::{ExpenseType, SplitStrategy, SubExpenseType};
 
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
 #[derive(Debug, Serialize, Deserialize, Clone, Default, sqlx::FromRow, PartialEq, Eq, Hash)]
@@ -17,6 +17,10 @@ pub struct ExpenseCategorization {
     #[serde(rename = "sub_type")]
     #[sqlx(rename = "sub_type")]
     pub sub_expense_type: SubExpenseType,
+    /// How expenses of the category group are split between budgeters.
+    #[serde(default)]
+    #[cfg_attr(any(feature = "testutils", test), dummy(default))]
+    pub split_strategy: SplitStrategy,
 }
 
 impl ExpenseCategorization {
@@ -1,19 +1,25 @@
 mod budget_details;
+mod budget_projection;
 mod budget_summary;
 mod budgeter;
 mod budgeter_config;
 mod expense;
+mod expense_association;
 mod expense_categorization;
 mod scheduled_transaction;
 mod scheduled_transactions_distribution;
+mod split_strategy;
 #[cfg(test)]
 mod tests;
 
 pub use budget_details::*;
+pub use budget_projection::*;
 pub use budget_summary::*;
 pub use budgeter::*;
 pub use budgeter_config::*;
 pub use expense::*;
+pub use expense_association::*;
 pub use expense_categorization::*;
 pub use scheduled_transaction::*;
 pub use scheduled_transactions_distribution::*;
+pub use split_strategy::*;
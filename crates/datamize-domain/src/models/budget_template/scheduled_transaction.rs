@@ -75,16 +75,15 @@ impl DatamizeScheduledTransaction {
         self
     }
 
-    /// Check if the date is in the following 30 days, including the last day of the interval.
-    pub fn is_in_next_30_days(&self) -> Option<bool> {
-        let current_date = Local::now().date_naive();
-        let next_month_date = current_date.checked_add_months(Months::new(1))?;
+    /// Check if the date is in the 30 days following `today`, including the last day of the interval.
+    pub fn is_in_next_30_days(&self, today: NaiveDate) -> Option<bool> {
+        let next_month_date = today.checked_add_months(Months::new(1))?;
 
-        Some(self.date_next >= current_date && self.date_next <= next_month_date)
+        Some(self.date_next >= today && self.date_next <= next_month_date)
     }
 
     /// Method to find any transactions that will be repeated more than once in a month period.
-    /// This means it checks from the current day of a month to next month's same day (E.g. January 15th to February 15th).
+    /// This means it checks from `today` to next month's same day (E.g. January 15th to February 15th).
     /// Those transactions will typically have a frequency of
     /// * Daily
     /// * Weekly
@@ -97,7 +96,10 @@ impl DatamizeScheduledTransaction {
     /// **Returns** an option vec because when the transaction has valid data but nothing repeats, it will return vec![],
     /// but when the data received was invalid, or there was an issue with building the rrule, it returns None,
     /// as nothing could be done to determine if future transactions repeat
-    pub fn get_repeated_transactions(&self) -> Option<Vec<DatamizeScheduledTransaction>> {
+    pub fn get_repeated_transactions(
+        &self,
+        today: NaiveDate,
+    ) -> Option<Vec<DatamizeScheduledTransaction>> {
         if let RecurFrequency::Daily
         | RecurFrequency::Weekly
         | RecurFrequency::EveryOtherWeek
@@ -110,13 +112,10 @@ impl DatamizeScheduledTransaction {
                     .single()
             })?;
 
-            let next_30_days = Local::now()
+            let next_30_days = today
                 .checked_add_months(Months::new(1))
-                .and_then(|d| {
-                    Tz::Local(Local)
-                        .from_local_datetime(&d.naive_local())
-                        .single()
-                })?;
+                .and_then(|d| d.and_hms_opt(23, 59, 59))
+                .and_then(|d| Tz::Local(Local).from_local_datetime(&d).single())?;
 
             if date_time <= next_30_days {
                 if let Some(rrule) = self.frequency.as_rfc5545_rule() {
@@ -1,7 +1,7 @@
 use rayon::prelude::*;
 use std::collections::{BTreeMap, HashMap};
 
-use chrono::NaiveDate;
+use chrono::{DateTime, Datelike, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 use super::DatamizeScheduledTransaction;
@@ -32,6 +32,7 @@ impl ScheduledTransactionsDistribution {
 pub struct ScheduledTransactionsDistributionBuilder {
     scheduled_transactions: Vec<DatamizeScheduledTransaction>,
     category_id_to_name_map: Option<CategoryIdToNameMap>,
+    month: Option<DateTime<Local>>,
 }
 
 impl ScheduledTransactionsDistributionBuilder {
@@ -42,6 +43,7 @@ impl ScheduledTransactionsDistributionBuilder {
                 .flat_map(|dst| dst.flatten())
                 .collect(),
             category_id_to_name_map: None,
+            month: None,
         }
     }
 
@@ -50,24 +52,52 @@ impl ScheduledTransactionsDistributionBuilder {
         self
     }
 
-    pub fn build(self) -> ScheduledTransactionsDistribution {
+    /// Distributes the transactions occurring during the month of `month`,
+    /// instead of those in the 30 days following `today`.
+    pub fn with_month(mut self, month: DateTime<Local>) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    pub fn build(self, today: NaiveDate) -> ScheduledTransactionsDistribution {
         let mut scheduled_transactions = self
             .scheduled_transactions
             .into_par_iter()
             .filter(|t| !t.deleted)
             .collect::<Vec<_>>();
 
-        for i in 0..scheduled_transactions.len() {
-            let dst = &scheduled_transactions[i];
+        match self.month {
+            Some(month) => {
+                scheduled_transactions = scheduled_transactions
+                    .into_par_iter()
+                    .flat_map(|dst| match dst.get_transactions_within_month(&month) {
+                        Some(transactions) if !transactions.is_empty() => transactions,
+                        // Transactions not repeating only occur on their next date.
+                        _ if dst.date_next.year() == month.year()
+                            && dst.date_next.month() == month.month() =>
+                        {
+                            vec![dst]
+                        }
+                        _ => vec![],
+                    })
+                    .collect();
+            }
+            None => {
+                for i in 0..scheduled_transactions.len() {
+                    let dst = &scheduled_transactions[i];
+
+                    if let Some(repeated_trans) = dst.get_repeated_transactions(today) {
+                        scheduled_transactions.extend(repeated_trans);
+                    }
+                }
 
-            if let Some(repeated_trans) = dst.get_repeated_transactions() {
-                scheduled_transactions.extend(repeated_trans);
+                scheduled_transactions.retain(|dst| dst.is_in_next_30_days(today).unwrap_or(false));
             }
         }
 
         let scheduled_transactions: Vec<_> = scheduled_transactions
             .into_par_iter()
-            .filter(|dst| !dst.deleted && dst.is_in_next_30_days().unwrap_or(false))
+            .filter(|dst| !dst.deleted)
             .flat_map(|dst| dst.flatten())
             .map(|dst| {
                 let category_name = dst.category_name.clone().or_else(|| {
@@ -0,0 +1,115 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{
+    database::{HasArguments, HasValueRef},
+    encode::IsNull,
+    error::BoxDynError,
+    Database, Decode, Encode, Type,
+};
+use uuid::Uuid;
+
+/// How a common expense is split between budgeters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SplitStrategy {
+    /// Each budgeter pays in proportion to their salary.
+    #[default]
+    Proportional,
+    /// Each budgeter pays the same amount.
+    Equal,
+    /// Each budgeter pays a fixed percentage, by budgeter id. Percentages should add up to 100.
+    FixedPercentages { percentages: BTreeMap<Uuid, u8> },
+    /// A single budgeter pays the whole expense.
+    Single { budgeter_id: Uuid },
+}
+
+impl SplitStrategy {
+    /// Returns the part of an expense, between 0 and 1, paid by the budgeter `budgeter_id`.
+    /// `proportion` is the budgeter's part of the total salary, and `num_budgeters` the number of budgeters sharing expenses.
+    pub fn share(&self, budgeter_id: Uuid, proportion: f64, num_budgeters: usize) -> f64 {
+        match self {
+            SplitStrategy::Proportional => proportion,
+            SplitStrategy::Equal if num_budgeters > 0 => 1.0 / num_budgeters as f64,
+            SplitStrategy::Equal => 0.0,
+            SplitStrategy::FixedPercentages { percentages } => {
+                percentages.get(&budgeter_id).copied().unwrap_or_default() as f64 / 100.0
+            }
+            SplitStrategy::Single { budgeter_id: id } if *id == budgeter_id => 1.0,
+            SplitStrategy::Single { .. } => 0.0,
+        }
+    }
+
+    /// Fixed percentages need to add up to 100%, otherwise part of the expense would not be paid by anyone.
+    /// The budgeters paying the expense need to be among the `budgeter_ids` configured for the same reason.
+    pub fn is_valid(&self, budgeter_ids: &[Uuid]) -> bool {
+        match self {
+            SplitStrategy::FixedPercentages { percentages } => {
+                percentages.keys().all(|id| budgeter_ids.contains(id))
+                    && percentages.values().map(|p| *p as u32).sum::<u32>() == 100
+            }
+            SplitStrategy::Single { budgeter_id } => budgeter_ids.contains(budgeter_id),
+            _ => true,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SplitStrategy::Proportional => "proportional",
+            SplitStrategy::Equal => "equal",
+            SplitStrategy::FixedPercentages { .. } => "fixed_percentages",
+            SplitStrategy::Single { .. } => "single",
+        }
+    }
+}
+
+/// Formatted as JSON, which is how it is persisted.
+impl fmt::Display for SplitStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_| fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for SplitStrategy {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+impl<DB: Database> Type<DB> for SplitStrategy
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for SplitStrategy
+where
+    String: Decode<'r, DB>,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        let value = <String as Decode<DB>>::decode(value)?;
+        Ok(value.parse()?)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for SplitStrategy
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        self.to_string().encode_by_ref(buf)
+    }
+}
@@ -4,16 +4,17 @@ use chrono::{DateTime, Local};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use uuid::Uuid;
-use ynab::Category;
+use ynab::{Category, Milliunits};
 
 use crate::{
-    BudgetDetails, Budgeter, BudgeterExt, ComputedSalary, DatamizeScheduledTransaction,
-    ExpenseCategorization, ExpenseType, SubExpenseType,
+    models::budget_template::tests::fixed_now, BudgetDetails, Budgeter, BudgeterExt,
+    ComputedSalary, DatamizeScheduledTransaction, ExpenseCategorization, ExpenseType,
+    SubExpenseType,
 };
 
 #[derive(Debug, Clone)]
 struct Expected {
-    total_monthly_income: i64,
+    total_monthly_income: Milliunits,
     expenses: Vec<Uuid>,
 }
 
@@ -37,8 +38,10 @@ fn check_method(
         categories,
         scheduled_transactions,
         date,
+        date.date_naive(),
         expenses_categorization,
         budgeters,
+        &[],
         false,
     );
 
@@ -68,11 +71,11 @@ fn empty_when_no_categories() {
     check_method(
         vec![],
         vec![],
-        &Local::now(),
+        &fixed_now(),
         vec![],
         &[],
         Expected {
-            total_monthly_income: 0,
+            total_monthly_income: Milliunits::ZERO,
             expenses: vec![],
         },
     );
@@ -85,7 +88,7 @@ fn empty_when_no_expense_categorization() {
     check_method(
         fake::vec![Category; 3..5],
         fake::vec![DatamizeScheduledTransaction; 3..5],
-        &Local::now(),
+        &fixed_now(),
         vec![],
         &budgeters,
         Expected {
@@ -119,7 +122,7 @@ fn empty_when_all_categories_are_hidden_or_deleted() {
     check_method(
         categories,
         fake::vec![DatamizeScheduledTransaction; 3..5],
-        &Local::now(),
+        &fixed_now(),
         fake::vec![ExpenseCategorization; 1..3],
         &budgeters,
         Expected {
@@ -178,7 +181,7 @@ fn only_expenses_with_categorization() {
     check_method(
         categories,
         fake::vec![DatamizeScheduledTransaction; 3..5],
-        &Local::now(),
+        &fixed_now(),
         expenses_categorization,
         &budgeters,
         Expected {
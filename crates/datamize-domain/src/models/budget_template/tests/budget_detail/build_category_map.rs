@@ -4,7 +4,9 @@ use pretty_assertions::assert_eq;
 use uuid::Uuid;
 use ynab::{RecurFrequency, ScheduledSubTransaction};
 
-use crate::{BudgetDetails, DatamizeScheduledTransaction};
+use crate::{
+    models::budget_template::tests::fixed_now, BudgetDetails, DatamizeScheduledTransaction,
+};
 
 #[derive(Debug, Clone)]
 struct Expected {
@@ -42,7 +44,7 @@ fn check_method(
 fn empty_when_no_transactions() {
     check_method(
         vec![],
-        &Local::now(),
+        &fixed_now(),
         Expected {
             empty: true,
             contains: vec![],
@@ -53,7 +55,7 @@ fn empty_when_no_transactions() {
 #[test]
 fn empty_when_transaction_deleted() {
     let trans = DatamizeScheduledTransaction {
-        date_first: Local::now()
+        date_first: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -66,7 +68,7 @@ fn empty_when_transaction_deleted() {
 
     check_method(
         vec![trans],
-        &Local::now(),
+        &fixed_now(),
         Expected {
             empty: true,
             contains: vec![],
@@ -77,7 +79,7 @@ fn empty_when_transaction_deleted() {
 #[test]
 fn empty_when_transaction_does_not_have_cat_id() {
     let trans = DatamizeScheduledTransaction {
-        date_first: Local::now()
+        date_first: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -90,7 +92,7 @@ fn empty_when_transaction_does_not_have_cat_id() {
 
     check_method(
         vec![trans],
-        &Local::now(),
+        &fixed_now(),
         Expected {
             empty: true,
             contains: vec![],
@@ -113,7 +115,7 @@ fn sub_trans_replace_trans() {
         ..Faker.fake()
     };
     let trans = DatamizeScheduledTransaction {
-        date_first: Local::now().date_naive(),
+        date_first: fixed_now().date_naive(),
         frequency: RecurFrequency::Every3Months,
         subtransactions: vec![sub_trans1.clone(), sub_trans2.clone()],
         deleted: false,
@@ -149,7 +151,7 @@ fn sub_trans_replace_trans() {
 
     check_method(
         vec![trans.clone()],
-        &Local::now(),
+        &fixed_now(),
         Expected {
             empty: false,
             contains: vec![
@@ -162,7 +164,7 @@ fn sub_trans_replace_trans() {
 
 #[test]
 fn all_trans_that_repeats_in_current_month() {
-    let date_first = Local::now().date_naive().with_day(5).unwrap();
+    let date_first = fixed_now().date_naive().with_day(5).unwrap();
     let trans = DatamizeScheduledTransaction {
         date_first,
         date_next: date_first,
@@ -190,7 +192,7 @@ fn all_trans_that_repeats_in_current_month() {
 
     check_method(
         vec![trans.clone()],
-        &Local::now(),
+        &fixed_now(),
         Expected {
             empty: false,
             contains: vec![(
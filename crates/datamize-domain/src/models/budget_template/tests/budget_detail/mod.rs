@@ -1,2 +1,3 @@
 mod build;
 mod build_category_map;
+mod month_target;
@@ -0,0 +1,68 @@
+use chrono::{Local, NaiveDate, NaiveTime};
+use pretty_assertions::assert_eq;
+
+use crate::MonthTarget;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+#[test]
+fn parses_relative_months() {
+    assert_eq!("previous".parse(), Ok(MonthTarget::Previous));
+    assert_eq!("current".parse(), Ok(MonthTarget::Current));
+    assert_eq!("next".parse(), Ok(MonthTarget::Next));
+}
+
+#[test]
+fn parses_year_and_month() {
+    assert_eq!("2024-05".parse(), Ok(MonthTarget::Month(date(2024, 5, 1))));
+}
+
+#[test]
+fn fails_to_parse_invalid_month() {
+    assert!("2024-13".parse::<MonthTarget>().is_err());
+    assert!("2024-05-12".parse::<MonthTarget>().is_err());
+    assert!("tomorrow".parse::<MonthTarget>().is_err());
+}
+
+#[test]
+fn first_day_is_relative_to_today() {
+    let today = date(2024, 1, 15);
+
+    assert_eq!(MonthTarget::Previous.first_day(today), date(2023, 12, 1));
+    assert_eq!(MonthTarget::Current.first_day(today), date(2024, 1, 1));
+    assert_eq!(MonthTarget::Next.first_day(today), date(2024, 2, 1));
+    assert_eq!(
+        MonthTarget::Month(date(2026, 7, 1)).first_day(today),
+        date(2026, 7, 1)
+    );
+}
+
+#[test]
+fn first_day_from_keeps_the_time_of_now() {
+    let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+    let now = date(2024, 3, 15)
+        .and_time(noon)
+        .and_local_timezone(Local)
+        .unwrap();
+
+    let first_day = MonthTarget::Previous.first_day_from(now);
+
+    assert_eq!(first_day.date_naive(), date(2024, 2, 1));
+    assert_eq!(first_day.time(), noon);
+    assert_eq!(
+        MonthTarget::Next.first_day_from(now).date_naive(),
+        date(2024, 4, 1)
+    );
+}
+
+#[test]
+fn month_of_today_is_current() {
+    let today = date(2024, 1, 15);
+
+    assert!(MonthTarget::Current.is_current(today));
+    assert!(MonthTarget::Month(date(2024, 1, 1)).is_current(today));
+    assert!(!MonthTarget::Month(date(2025, 1, 1)).is_current(today));
+    assert!(!MonthTarget::Next.is_current(today));
+}
@@ -0,0 +1,204 @@
+use chrono::{DateTime, Local, NaiveDate};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+use ynab::{Category, GoalType, Milliunits, RecurFrequency};
+
+use crate::{
+    BudgetProjection, BudgeterConfig, BudgeterExt, Clock, DatamizeScheduledTransaction,
+    ExpenseCategorization, ExpenseType, FixedClock, SubExpenseType,
+};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn months_of_2024() -> Vec<DateTime<Local>> {
+    (1..=12)
+        .map(|m| FixedClock::new_arced(date(2024, m, 1)).now())
+        .collect()
+}
+
+fn paycheck(payee_id: Uuid) -> DatamizeScheduledTransaction {
+    DatamizeScheduledTransaction {
+        date_first: date(2024, 1, 5),
+        date_next: date(2024, 1, 5),
+        frequency: RecurFrequency::EveryOtherWeek,
+        amount: 100000,
+        payee_id: Some(payee_id),
+        category_id: None,
+        deleted: false,
+        subtransactions: vec![],
+        ..Faker.fake()
+    }
+}
+
+#[test]
+fn empty_when_no_months() {
+    let projection = BudgetProjection::build(
+        fake::vec![Category; 1..3],
+        fake::vec![DatamizeScheduledTransaction; 1..3],
+        &[],
+        date(2024, 1, 1),
+        fake::vec![ExpenseCategorization; 1..3],
+        fake::vec![BudgeterConfig; 1..3],
+        vec![],
+        false,
+    );
+
+    assert_eq!(projection, BudgetProjection::default());
+}
+
+#[test]
+fn salary_follows_paychecks_of_each_month() {
+    let payee_id = Faker.fake();
+    let budgeter = BudgeterConfig {
+        payee_ids: vec![payee_id],
+        ..Faker.fake()
+    };
+
+    let projection = BudgetProjection::build(
+        vec![],
+        vec![paycheck(payee_id)],
+        &months_of_2024(),
+        date(2024, 1, 1),
+        vec![],
+        vec![budgeter],
+        vec![],
+        false,
+    );
+
+    let salaries: Vec<_> = projection
+        .months()
+        .iter()
+        .map(|m| m.budgeters()[0].salary_month().get())
+        .collect();
+    // Every other friday starting January 5th, 2024 gives 3 paychecks in March and in August.
+    assert_eq!(
+        salaries,
+        vec![
+            200000, 200000, 300000, 200000, 200000, 200000, 200000, 300000, 200000, 200000, 200000,
+            200000
+        ]
+    );
+    assert_eq!(
+        projection.total_income().get(),
+        salaries.iter().sum::<i64>()
+    );
+}
+
+#[test]
+fn yearly_bill_only_lands_in_its_month() {
+    let category = Category {
+        hidden: false,
+        deleted: false,
+        goal_type: None,
+        ..Faker.fake()
+    };
+    let categorization = ExpenseCategorization {
+        id: category.category_group_id,
+        expense_type: ExpenseType::Fixed,
+        sub_expense_type: SubExpenseType::Housing,
+        ..Faker.fake()
+    };
+    let bill = DatamizeScheduledTransaction {
+        date_first: date(2024, 3, 10),
+        date_next: date(2024, 3, 10),
+        frequency: RecurFrequency::Yearly,
+        amount: -120000,
+        category_id: Some(category.id),
+        payee_id: None,
+        deleted: false,
+        subtransactions: vec![],
+        ..Faker.fake()
+    };
+
+    let projection = BudgetProjection::build(
+        vec![category],
+        vec![bill],
+        &months_of_2024(),
+        date(2024, 1, 1),
+        vec![categorization],
+        vec![],
+        vec![],
+        false,
+    );
+
+    let fixed_per_month: Vec<_> = projection
+        .months()
+        .iter()
+        .map(|m| m.total_per_expense_type()[&ExpenseType::Fixed].get())
+        .collect();
+    assert_eq!(
+        fixed_per_month,
+        vec![0, 0, 120000, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        projection.total_per_expense_type()[&ExpenseType::Fixed].get(),
+        120000
+    );
+    assert_eq!(projection.months()[2].month(), date(2024, 3, 1));
+}
+
+fn fixed_per_month_of_goal(category: Category) -> Vec<i64> {
+    let categorization = ExpenseCategorization {
+        id: category.category_group_id,
+        expense_type: ExpenseType::Fixed,
+        sub_expense_type: SubExpenseType::Housing,
+        ..Faker.fake()
+    };
+
+    let projection = BudgetProjection::build(
+        vec![category],
+        vec![],
+        &months_of_2024(),
+        date(2024, 1, 1),
+        vec![categorization],
+        vec![],
+        vec![],
+        false,
+    );
+
+    projection
+        .months()
+        .iter()
+        .map(|m| m.total_per_expense_type()[&ExpenseType::Fixed].get())
+        .collect()
+}
+
+#[test]
+fn repeating_goal_lands_in_the_months_it_is_due() {
+    let category = Category {
+        hidden: false,
+        deleted: false,
+        goal_type: Some(GoalType::PlanYourSpending),
+        // Every 3 months
+        goal_cadence: Some(4),
+        goal_target: Some(Milliunits::new(90000)),
+        goal_target_month: Some(date(2024, 5, 31)),
+        ..Faker.fake()
+    };
+
+    assert_eq!(
+        fixed_per_month_of_goal(category),
+        vec![0, 90000, 0, 0, 90000, 0, 0, 90000, 0, 0, 90000, 0]
+    );
+}
+
+#[test]
+fn goal_not_repeating_only_lands_in_its_due_month() {
+    let category = Category {
+        hidden: false,
+        deleted: false,
+        goal_type: Some(GoalType::TargetBalanceByDate),
+        goal_cadence: Some(0),
+        goal_target: Some(Milliunits::new(500000)),
+        goal_target_month: Some(date(2024, 10, 1)),
+        ..Faker.fake()
+    };
+
+    assert_eq!(
+        fixed_per_month_of_goal(category),
+        vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 500000, 0, 0]
+    );
+}
@@ -2,14 +2,17 @@ use crate::{
     models::budget_template::{
         expense,
         tests::budgeter::testutils::{
+            associate_first_expense_to_first_budgeter, setup_budgeters_with_first_salary,
             setup_budgeters_with_salary, setup_budgeters_with_salary_with_name,
             setup_computed_expenses, setup_computed_expenses_with_first_non_external,
         },
     },
-    Budgeter, BudgeterExt, ComputedExpenses, ComputedSalary, Expense, TotalBudgeter,
+    AssociationType, Budgeter, BudgeterExt, ComputedExpenses, ComputedSalary, Expense,
+    ExpenseAssociation, ExpenseCategorization, SplitStrategy, TotalBudgeter,
 };
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
+use ynab::Milliunits;
 
 #[track_caller]
 fn check_method_budgeter(
@@ -29,11 +32,28 @@ fn check_method_budgeter(
         .compute_salary(budgeters)
         .compute_expenses(expenses, budgeters);
 
-    let budgeter = budgeter.compute_expenses(&total_budgeter, &individual_expenses);
+    let budgeter = budgeter.compute_expenses(&total_budgeter, expenses, budgeters);
 
     (budgeter, individual_expenses.into_iter().cloned().collect())
 }
 
+fn with_split_strategy(
+    expenses: Vec<Expense<expense::Computed>>,
+    split_strategy: SplitStrategy,
+) -> Vec<Expense<expense::Computed>> {
+    expenses
+        .into_iter()
+        .map(|e| {
+            let categorization = ExpenseCategorization {
+                id: e.category().category_group_id,
+                split_strategy: split_strategy.clone(),
+                ..Faker.fake()
+            };
+            e.set_categorization(&[categorization], false)
+        })
+        .collect()
+}
+
 #[test]
 fn proportion_is_0_when_no_total_salary() {
     let expenses = setup_computed_expenses();
@@ -41,8 +61,8 @@ fn proportion_is_0_when_no_total_salary() {
     let (budgeter, _) = check_method_budgeter(budgeter, &[], &expenses);
 
     assert_eq!(budgeter.proportion(), 0.0);
-    assert_eq!(budgeter.common_expenses(), 0);
-    assert_eq!(budgeter.individual_expenses(), 0);
+    assert_eq!(budgeter.common_expenses(), Milliunits::ZERO);
+    assert_eq!(budgeter.individual_expenses(), Milliunits::ZERO);
     assert_eq!(budgeter.left_over(), budgeter.salary_month());
 }
 
@@ -53,8 +73,8 @@ fn left_over_is_salary_when_no_expenses() {
         check_method_budgeter(budgeters[0].clone(), &budgeters, &[]);
 
     assert!(individual_expenses.is_empty());
-    assert_eq!(budgeter.common_expenses(), 0);
-    assert_eq!(budgeter.individual_expenses(), 0);
+    assert_eq!(budgeter.common_expenses(), Milliunits::ZERO);
+    assert_eq!(budgeter.individual_expenses(), Milliunits::ZERO);
     assert_eq!(budgeter.left_over(), budgeter.salary_month());
 }
 
@@ -65,13 +85,13 @@ fn left_over_is_salary_minus_all_expenses_when_no_budgeters_match() {
     let (budgeter, individual_expenses) =
         check_method_budgeter(budgeters[0].clone(), &budgeters, &expenses);
 
-    let total_expense: i64 = expenses.iter().map(|e| e.projected_amount()).sum();
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
 
-    let common_expenses = (budgeter.proportion() * total_expense as f64) as i64;
+    let common_expenses = total_expense.mul_f64(budgeter.proportion());
 
     assert!(individual_expenses.is_empty());
     assert_eq!(budgeter.common_expenses(), common_expenses);
-    assert_eq!(budgeter.individual_expenses(), 0);
+    assert_eq!(budgeter.individual_expenses(), Milliunits::ZERO);
     assert_eq!(
         budgeter.left_over(),
         budgeter.salary_month() - common_expenses
@@ -81,15 +101,21 @@ fn left_over_is_salary_minus_all_expenses_when_no_budgeters_match() {
 #[test]
 fn left_over_is_salary_minus_common_expenses_proportionally_minus_individual_expenses_when_budgeter_match(
 ) {
-    let expenses = setup_computed_expenses_with_first_non_external();
-    let budgeters = setup_budgeters_with_salary_with_name(expenses[0].name());
+    let budgeters = setup_budgeters_with_first_salary();
+    let expenses = associate_first_expense_to_first_budgeter(
+        setup_computed_expenses_with_first_non_external(),
+        &budgeters,
+    );
     let (budgeter, individual_expenses) =
         check_method_budgeter(budgeters[0].clone(), &budgeters, &expenses);
 
-    let total_expense =
-        expenses.iter().map(|e| e.projected_amount()).sum::<i64>() - expenses[0].projected_amount();
+    let total_expense = expenses
+        .iter()
+        .map(|e| e.projected_amount())
+        .sum::<Milliunits>()
+        - expenses[0].projected_amount();
 
-    let common_expenses = (budgeter.proportion() * total_expense as f64) as i64;
+    let common_expenses = total_expense.mul_f64(budgeter.proportion());
 
     assert_eq!(individual_expenses.len(), 1);
     assert_eq!(budgeter.common_expenses(), common_expenses);
@@ -98,10 +124,153 @@ fn left_over_is_salary_minus_common_expenses_proportionally_minus_individual_exp
         individual_expenses
             .into_iter()
             .map(|e| e.projected_amount())
-            .sum::<i64>()
+            .sum::<Milliunits>()
     );
     assert_eq!(
         budgeter.left_over(),
         budgeter.salary_month() - common_expenses - budgeter.individual_expenses()
     );
 }
+
+#[test]
+fn individual_expenses_fall_back_to_budgeter_name_when_not_associated() {
+    let expenses = setup_computed_expenses_with_first_non_external();
+    let budgeters = setup_budgeters_with_salary_with_name(expenses[0].name());
+    let (budgeter, individual_expenses) =
+        check_method_budgeter(budgeters[0].clone(), &budgeters, &expenses);
+
+    let total_expense = expenses
+        .iter()
+        .map(|e| e.projected_amount())
+        .sum::<Milliunits>()
+        - expenses[0].projected_amount();
+
+    let common_expenses = total_expense.mul_f64(budgeter.proportion());
+
+    assert_eq!(individual_expenses.len(), 1);
+    assert_eq!(budgeter.common_expenses(), common_expenses);
+    assert_eq!(
+        budgeter.individual_expenses(),
+        expenses[0].projected_amount()
+    );
+}
+
+#[test]
+fn common_expenses_are_split_equally_when_equal_strategy() {
+    let expenses = with_split_strategy(setup_computed_expenses(), SplitStrategy::Equal);
+    let budgeters = setup_budgeters_with_salary();
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
+
+    let (first, _) = check_method_budgeter(budgeters[0].clone(), &budgeters, &expenses);
+    let (second, _) = check_method_budgeter(budgeters[1].clone(), &budgeters, &expenses);
+
+    assert_eq!(
+        first.common_expenses(),
+        expenses
+            .iter()
+            .map(|e| e.projected_amount().mul_f64(0.5))
+            .sum::<Milliunits>()
+    );
+    assert_eq!(
+        second.common_expenses(),
+        total_expense - first.common_expenses()
+    );
+    for budgeter in [first, second] {
+        assert_eq!(
+            budgeter.left_over(),
+            budgeter.salary_month() - budgeter.common_expenses()
+        );
+    }
+}
+
+#[test]
+fn common_expenses_are_split_with_percentages_when_fixed_percentages_strategy() {
+    let budgeters = setup_budgeters_with_salary();
+    let percentages = [(budgeters[0].id(), 30), (budgeters[1].id(), 70)]
+        .into_iter()
+        .collect();
+    let expenses = with_split_strategy(
+        setup_computed_expenses(),
+        SplitStrategy::FixedPercentages { percentages },
+    );
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
+
+    let (first, _) = check_method_budgeter(budgeters[0].clone(), &budgeters, &expenses);
+    let (second, _) = check_method_budgeter(budgeters[1].clone(), &budgeters, &expenses);
+
+    assert_eq!(
+        first.common_expenses(),
+        expenses
+            .iter()
+            .map(|e| e.projected_amount().mul_f64(0.3))
+            .sum::<Milliunits>()
+    );
+    assert_eq!(
+        first.common_expenses() + second.common_expenses(),
+        total_expense
+    );
+}
+
+#[test]
+fn proportional_common_expenses_add_up_to_the_expenses() {
+    let expenses = setup_computed_expenses();
+    let budgeters = setup_budgeters_with_salary();
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
+
+    let common_expenses: Milliunits = budgeters
+        .iter()
+        .map(|b| {
+            check_method_budgeter(b.clone(), &budgeters, &expenses)
+                .0
+                .common_expenses()
+        })
+        .sum();
+
+    assert_eq!(common_expenses, total_expense);
+}
+
+#[test]
+fn common_expenses_are_all_paid_by_one_budgeter_when_single_strategy() {
+    let budgeters = setup_budgeters_with_salary();
+    let expenses = with_split_strategy(
+        setup_computed_expenses(),
+        SplitStrategy::Single {
+            budgeter_id: budgeters[1].id(),
+        },
+    );
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
+
+    let (first, _) = check_method_budgeter(budgeters[0].clone(), &budgeters, &expenses);
+    let (second, _) = check_method_budgeter(budgeters[1].clone(), &budgeters, &expenses);
+
+    assert_eq!(first.common_expenses(), Milliunits::ZERO);
+    assert_eq!(first.left_over(), first.salary_month());
+    assert_eq!(second.common_expenses(), total_expense);
+    assert_eq!(second.left_over(), second.salary_month() - total_expense);
+}
+
+#[test]
+fn split_strategy_of_the_association_takes_precedence_over_the_one_of_the_group() {
+    let budgeters = setup_budgeters_with_salary();
+    let expenses: Vec<_> = with_split_strategy(setup_computed_expenses(), SplitStrategy::Equal)
+        .into_iter()
+        .map(|e| {
+            let association = ExpenseAssociation {
+                id: e.category().id,
+                association_type: AssociationType::Category,
+                budgeter_id: None,
+                split_strategy: Some(SplitStrategy::Single {
+                    budgeter_id: budgeters[1].id(),
+                }),
+            };
+            e.set_individual_association(&budgeters, &[association])
+        })
+        .collect();
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
+
+    let (first, _) = check_method_budgeter(budgeters[0].clone(), &budgeters, &expenses);
+    let (second, _) = check_method_budgeter(budgeters[1].clone(), &budgeters, &expenses);
+
+    assert_eq!(first.common_expenses(), Milliunits::ZERO);
+    assert_eq!(second.common_expenses(), total_expense);
+}
@@ -1,10 +1,13 @@
-use chrono::{Datelike, Days, Local, Months};
+use chrono::{Datelike, Days, Months};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use uuid::Uuid;
 use ynab::{RecurFrequency, ScheduledSubTransaction};
 
-use crate::{Budgeter, BudgeterConfig, BudgeterExt, Configured, DatamizeScheduledTransaction};
+use crate::{
+    models::budget_template::tests::fixed_now, Budgeter, BudgeterConfig, BudgeterExt, Configured,
+    DatamizeScheduledTransaction,
+};
 
 #[derive(Debug, Clone)]
 struct Expected {
@@ -25,8 +28,8 @@ fn check_method_budgeter(
         caller_line_number
     );
 
-    let budgeter = budgeter.compute_salary(scheduled_transactions, &Local::now(), inflow_cat_id);
-    assert_eq!(budgeter.salary_month(), salary_month);
+    let budgeter = budgeter.compute_salary(scheduled_transactions, &fixed_now(), inflow_cat_id);
+    assert_eq!(budgeter.salary_month().get(), salary_month);
 }
 
 #[test]
@@ -58,11 +61,11 @@ fn salary_is_linked_scheduled_transactions_when_not_repeating() {
     let transaction = DatamizeScheduledTransaction {
         amount: (1..100000).fake(),
         frequency: RecurFrequency::Never,
-        date_first: Local::now()
+        date_first: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -87,7 +90,7 @@ fn salary_month_is_twice_linked_scheduled_transactions() {
         ..Faker.fake()
     };
     let budgeter: Budgeter<Configured> = config.clone().into();
-    let date_first = Local::now().date_naive().with_day(5).unwrap();
+    let date_first = fixed_now().date_naive().with_day(5).unwrap();
     let transaction = DatamizeScheduledTransaction {
         amount: (1..100000).fake(),
         frequency: RecurFrequency::EveryOtherWeek,
@@ -119,7 +122,7 @@ fn salary_takes_all_linked_scheduled_transactions() {
         frequency: RecurFrequency::Never,
         payee_id: Some(budgeter.payee_ids()[0]),
         payee_name: Some(Faker.fake()),
-        date_next: Local::now().date_naive(),
+        date_next: fixed_now().date_naive(),
         ..Faker.fake()
     };
     let sec_trans = DatamizeScheduledTransaction {
@@ -127,7 +130,7 @@ fn salary_takes_all_linked_scheduled_transactions() {
         frequency: RecurFrequency::Never,
         payee_id: Some(budgeter.payee_ids()[1]),
         payee_name: Some(Faker.fake()),
-        date_next: Local::now().date_naive(),
+        date_next: fixed_now().date_naive(),
         ..Faker.fake()
     };
     check_method_budgeter(
@@ -155,7 +158,7 @@ fn salary_takes_all_scheduled_transactions_of_same_payee() {
         frequency: RecurFrequency::Monthly,
         payee_id: Some(payee_id),
         payee_name: Some(payee_name.clone()),
-        date_first: Local::now().date_naive().with_day(15).unwrap(),
+        date_first: fixed_now().date_naive().with_day(15).unwrap(),
         ..Faker.fake()
     };
     let sec_trans = DatamizeScheduledTransaction {
@@ -163,7 +166,7 @@ fn salary_takes_all_scheduled_transactions_of_same_payee() {
         frequency: RecurFrequency::Monthly,
         payee_id: Some(payee_id),
         payee_name: Some(payee_name),
-        date_first: Local::now().date_naive().with_day(28).unwrap(),
+        date_first: fixed_now().date_naive().with_day(28).unwrap(),
         ..Faker.fake()
     };
     check_method_budgeter(
@@ -183,7 +186,7 @@ fn salary_takes_all_linked_scheduled_transactions_even_when_repeated() {
         ..Faker.fake()
     };
     let budgeter: Budgeter<Configured> = config.clone().into();
-    let date_first = Local::now().date_naive().with_day(5).unwrap();
+    let date_first = fixed_now().date_naive().with_day(5).unwrap();
     let first_trans = DatamizeScheduledTransaction {
         amount: (1..100000).fake(),
         frequency: RecurFrequency::EveryOtherWeek,
@@ -198,7 +201,7 @@ fn salary_takes_all_linked_scheduled_transactions_even_when_repeated() {
         frequency: RecurFrequency::Never,
         payee_id: Some(budgeter.payee_ids()[1]),
         payee_name: Some(Faker.fake()),
-        date_next: Local::now().date_naive(),
+        date_next: fixed_now().date_naive(),
         ..Faker.fake()
     };
     check_method_budgeter(
@@ -218,7 +221,7 @@ fn salary_repeats_5_times_when_frequency_is_every_week_on_first_day_of_month() {
         ..Faker.fake()
     };
     let budgeter: Budgeter<Configured> = config.clone().into();
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let repeated = if date_first.month0() == 1 && !date_first.leap_year() {
         4
     } else {
@@ -267,7 +270,7 @@ fn salary_takes_inflow_from_sub_transaction() {
         frequency: RecurFrequency::Never,
         payee_id: Some(budgeter.payee_ids()[0]),
         payee_name: Some(Faker.fake()),
-        date_next: Local::now().date_naive(),
+        date_next: fixed_now().date_naive(),
         subtransactions,
         ..Faker.fake()
     };
@@ -297,7 +300,7 @@ fn salary_does_not_take_inflow_from_sub_transaction_even_if_defined() {
         frequency: RecurFrequency::Never,
         payee_id: Some(budgeter.payee_ids()[0]),
         payee_name: Some(Faker.fake()),
-        date_next: Local::now().date_naive(),
+        date_next: fixed_now().date_naive(),
         subtransactions,
         ..Faker.fake()
     };
@@ -321,7 +324,7 @@ fn salary_does_not_take_amount_from_transaction_when_first_occurence_is_the_mont
     let budgeter: Budgeter<Configured> = config.clone().into();
     let inflow_cat_id = Faker.fake();
 
-    let date_first = Local::now()
+    let date_first = fixed_now()
         .date_naive()
         .checked_add_days(Days::new(2))
         .and_then(|d| d.checked_add_months(Months::new(1)))
@@ -1,10 +1,12 @@
-use chrono::{Datelike, Days, Local};
+use chrono::{Datelike, Days};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use rand::seq::SliceRandom;
 use ynab::{Category, GoalType};
 
-use crate::{DatamizeScheduledTransaction, Expense, Uncomputed};
+use crate::{
+    models::budget_template::tests::fixed_now, DatamizeScheduledTransaction, Expense, Uncomputed,
+};
 
 #[test]
 fn correctly_adds_scheduled_transactions() {
@@ -39,12 +41,17 @@ fn check_method_projected_amount(
     let expense: Expense<Uncomputed> = expense.into();
     let expense = expense
         .with_scheduled_transactions(st)
-        .build_dates()
-        .compute_amounts();
+        .build_dates(&fixed_now())
+        .compute_amounts(fixed_now().date_naive());
 
     match panic_msg {
-        None => assert_eq!(expense.projected_amount(), projected_amount),
-        Some(msg) => assert_eq!(expense.projected_amount(), projected_amount, "{}", msg),
+        None => assert_eq!(expense.projected_amount().get(), projected_amount),
+        Some(msg) => assert_eq!(
+            expense.projected_amount().get(),
+            projected_amount,
+            "{}",
+            msg
+        ),
     };
 }
 
@@ -68,11 +75,13 @@ fn check_method_current_amount(
     );
 
     let expense: Expense<Uncomputed> = expense.into();
-    let expense = expense.with_scheduled_transactions(st).compute_amounts();
+    let expense = expense
+        .with_scheduled_transactions(st)
+        .compute_amounts(fixed_now().date_naive());
 
     match panic_msg {
-        None => assert_eq!(expense.current_amount(), current_amount),
-        Some(msg) => assert_eq!(expense.current_amount(), current_amount, "{}", msg),
+        None => assert_eq!(expense.current_amount().get(), current_amount),
+        Some(msg) => assert_eq!(expense.current_amount().get(), current_amount, "{}", msg),
     };
 }
 
@@ -89,7 +98,7 @@ fn compute_projected_amount_is_goal_target_when_goal_not_plan_spending() {
         ..Faker.fake()
     };
     let st = fake::vec![DatamizeScheduledTransaction; 1];
-    let goal_target = category.goal_target.unwrap();
+    let goal_target = category.goal_target.unwrap().get();
 
     check_method_projected_amount(
         category,
@@ -110,7 +119,7 @@ fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_monthl
         goal_target: Some((0..100000).fake()),
         ..Faker.fake()
     };
-    let goal_target = category.goal_target.unwrap();
+    let goal_target = category.goal_target.unwrap().get();
     let goal_cadence_frequency = category.goal_cadence_frequency.unwrap() as i64;
     check_method_projected_amount(
         category.clone(),
@@ -122,7 +131,7 @@ fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_monthl
     );
 
     category.goal_cadence_frequency = None;
-    let goal_target = category.goal_target.unwrap();
+    let goal_target = category.goal_target.unwrap().get();
     check_method_projected_amount(
         category.clone(),
         vec![],
@@ -145,7 +154,7 @@ fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_monthl
 
 #[test]
 fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_weekly() {
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let repeated = if date_first.month0() == 1 && !date_first.leap_year() {
         4
     } else {
@@ -160,7 +169,7 @@ fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_weekly
         goal_target: Some((0..100000).fake()),
         ..Faker.fake()
     };
-    let goal_target = category.goal_target.unwrap();
+    let goal_target = category.goal_target.unwrap().get();
     check_method_projected_amount(
         category.clone(),
         vec![],
@@ -171,7 +180,7 @@ fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_weekly
     );
 
     category.goal_creation_month = Some(date_first.checked_add_days(Days::new(7)).unwrap());
-    let goal_target = category.goal_target.unwrap();
+    let goal_target = category.goal_target.unwrap().get();
     check_method_projected_amount(
         category.clone(),
         vec![],
@@ -183,7 +192,7 @@ fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_weekly
 
     category.goal_creation_month = Some(date_first);
     category.goal_cadence_frequency = Some(2);
-    let goal_target = category.goal_target.unwrap();
+    let goal_target = category.goal_target.unwrap().get();
     check_method_projected_amount(
         category.clone(),
         vec![],
@@ -225,7 +234,7 @@ fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_every_
         goal_target: Some((0..100000).fake()),
         ..Faker.fake()
     };
-    let goal_target = category.goal_target.unwrap();
+    let goal_target = category.goal_target.unwrap().get();
     check_method_projected_amount(
         category,
         vec![],
@@ -247,7 +256,7 @@ fn compute_projected_amount_when_goal_target_is_plan_spending_and_cadence_every_
         goal_target: Some((0..100000).fake()),
         ..Faker.fake()
     };
-    let goal_target = category.goal_target.unwrap();
+    let goal_target = category.goal_target.unwrap().get();
     check_method_projected_amount(
         category,
         vec![],
@@ -268,7 +277,7 @@ fn compute_projected_amount_when_goal_target_is_target_by_date() {
         ..Faker.fake()
     };
     let goal_under_funded = category.goal_under_funded.unwrap();
-    let budgeted = category.budgeted;
+    let budgeted = category.budgeted.get();
     check_method_projected_amount(
         category.clone(),
         vec![],
@@ -279,7 +288,7 @@ fn compute_projected_amount_when_goal_target_is_target_by_date() {
     );
 
     category.goal_under_funded = Some(0);
-    let budgeted = category.budgeted;
+    let budgeted = category.budgeted.get();
     check_method_projected_amount(
         category.clone(),
         vec![],
@@ -307,7 +316,7 @@ fn compute_current_amount_is_inverted_total_of_scheduled_transactions() {
         ..Faker.fake()
     };
     let mut st = fake::vec![DatamizeScheduledTransaction; 1];
-    st[0].amount = -category.budgeted;
+    st[0].amount = -category.budgeted.get();
     check_method_current_amount(
         category,
         st.clone(),
@@ -326,7 +335,7 @@ fn compute_current_amount_when_category_has_goal() {
         ..Faker.fake()
     };
     let st = fake::vec![DatamizeScheduledTransaction; 1..3];
-    let budgeted = category.budgeted;
+    let budgeted = category.budgeted.get();
     check_method_current_amount(
         category.clone(),
         st.clone(),
@@ -337,7 +346,7 @@ fn compute_current_amount_when_category_has_goal() {
     );
 
     category.goal_under_funded = Some((0..100000).fake());
-    let budgeted = category.budgeted;
+    let budgeted = category.budgeted.get();
     let goal_under_funded = category.goal_under_funded.unwrap();
     check_method_current_amount(
         category.clone(),
@@ -363,7 +372,7 @@ fn compute_current_amount_when_category_has_no_goal() {
         goal_type: None,
         ..Faker.fake()
     };
-    let budgeted = category.budgeted;
+    let budgeted = category.budgeted.get();
     check_method_current_amount(
         category.clone(),
         vec![],
@@ -383,13 +392,13 @@ fn compute_current_amount_when_category_has_no_goal() {
 
     category.budgeted = (0..100000).fake();
     let past_transaction = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_sub_days(Days::new(1))
             .unwrap(),
         ..Faker.fake()
     };
-    let budgeted = category.budgeted;
+    let budgeted = category.budgeted.get();
     check_method_current_amount(
         category.clone(),
         vec![past_transaction],
@@ -401,27 +410,27 @@ fn compute_current_amount_when_category_has_no_goal() {
 
     category.balance = (0..100).fake();
     let past_transaction = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_sub_days(Days::new(1))
             .unwrap(),
         ..Faker.fake()
     };
     let future_transaction = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
         ..Faker.fake()
     };
     let sec_future_transaction = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(10))
             .unwrap(),
         ..Faker.fake()
     };
-    let balance = category.balance;
+    let balance = category.balance.get();
     let first_trans_amount = future_transaction.amount;
     let sec_trans_amount = sec_future_transaction.amount;
     check_method_current_amount(
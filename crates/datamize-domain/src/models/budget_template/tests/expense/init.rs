@@ -3,8 +3,8 @@ use pretty_assertions::assert_eq;
 use ynab::Category;
 
 use crate::{
-    Budgeter, BudgeterExt, ComputedSalary, Expense, ExpenseCategorization, ExpenseType,
-    SubExpenseType, Uncomputed,
+    AssociationType, Budgeter, BudgeterExt, ComputedSalary, Expense, ExpenseAssociation,
+    ExpenseCategorization, ExpenseType, SplitStrategy, SubExpenseType, Uncomputed,
 };
 
 #[test]
@@ -65,7 +65,7 @@ fn no_individual_association_when_expense_does_not_have_budgeter_name() {
     let category: Category = Faker.fake();
     let expense: Expense<Uncomputed> = category.clone().into();
     let budgeters = fake::vec![Budgeter<ComputedSalary>; 1..5];
-    let expense = expense.set_individual_association(&budgeters);
+    let expense = expense.set_individual_association(&budgeters, &[]);
 
     assert_eq!(expense.individual_associated(), None);
 }
@@ -80,10 +80,182 @@ fn sets_individual_association_when_expense_does_have_budgeter_name() {
         ..Faker.fake()
     };
     let expense: Expense<Uncomputed> = category.clone().into();
-    let expense = expense.set_individual_association(&budgeters);
+    let expense = expense.set_individual_association(&budgeters, &[]);
 
     assert_eq!(
         expense.individual_associated(),
         Some(&budgeters[0].name().to_owned())
     );
 }
+
+#[test]
+fn shared_association_takes_precedence_over_budgeter_name() {
+    let budgeters = fake::vec![Budgeter<ComputedSalary>; 1..5];
+    let mut name = Faker.fake::<String>();
+    name.push_str(budgeters[0].name());
+    let category = Category {
+        name,
+        ..Faker.fake()
+    };
+    let associations = vec![ExpenseAssociation {
+        id: category.category_group_id,
+        association_type: AssociationType::CategoryGroup,
+        budgeter_id: None,
+        split_strategy: None,
+    }];
+    let expense: Expense<Uncomputed> = category.into();
+    let expense = expense.set_individual_association(&budgeters, &associations);
+
+    assert_eq!(expense.individual_associated(), None);
+    assert!(!expense.is_individual_expense_of(&budgeters[0]));
+}
+
+#[test]
+fn category_association_takes_precedence_over_budgeter_name() {
+    let budgeters = fake::vec![Budgeter<ComputedSalary>; 2..5];
+    let mut name = Faker.fake::<String>();
+    name.push_str(budgeters[0].name());
+    let category = Category {
+        name,
+        ..Faker.fake()
+    };
+    let associations = vec![ExpenseAssociation {
+        id: category.id,
+        association_type: AssociationType::Category,
+        budgeter_id: Some(budgeters[1].id()),
+        split_strategy: None,
+    }];
+    let expense: Expense<Uncomputed> = category.into();
+    let expense = expense.set_individual_association(&budgeters, &associations);
+
+    assert_eq!(
+        expense.individual_associated(),
+        Some(&budgeters[1].name().to_owned())
+    );
+    assert_eq!(expense.individual_budgeter_id(), Some(budgeters[1].id()));
+    assert!(expense.is_individual_expense_of(&budgeters[1]));
+    assert!(!expense.is_individual_expense_of(&budgeters[0]));
+}
+
+#[test]
+fn category_association_takes_precedence_over_category_group_association() {
+    let budgeters = fake::vec![Budgeter<ComputedSalary>; 2..5];
+    let category: Category = Faker.fake();
+    let associations = vec![
+        ExpenseAssociation {
+            id: category.category_group_id,
+            association_type: AssociationType::CategoryGroup,
+            budgeter_id: Some(budgeters[0].id()),
+            split_strategy: None,
+        },
+        ExpenseAssociation {
+            id: category.id,
+            association_type: AssociationType::Category,
+            budgeter_id: Some(budgeters[1].id()),
+            split_strategy: None,
+        },
+    ];
+    let expense: Expense<Uncomputed> = category.into();
+    let expense = expense.set_individual_association(&budgeters, &associations);
+
+    assert_eq!(expense.individual_budgeter_id(), Some(budgeters[1].id()));
+}
+
+#[test]
+fn shared_category_association_takes_precedence_over_category_group_association() {
+    let budgeters = fake::vec![Budgeter<ComputedSalary>; 1..5];
+    let category: Category = Faker.fake();
+    let associations = vec![
+        ExpenseAssociation {
+            id: category.category_group_id,
+            association_type: AssociationType::CategoryGroup,
+            budgeter_id: Some(budgeters[0].id()),
+            split_strategy: None,
+        },
+        ExpenseAssociation {
+            id: category.id,
+            association_type: AssociationType::Category,
+            budgeter_id: None,
+            split_strategy: None,
+        },
+    ];
+    let expense: Expense<Uncomputed> = category.into();
+    let expense = expense.set_individual_association(&budgeters, &associations);
+
+    assert_eq!(expense.individual_budgeter_id(), None);
+    assert!(!expense.is_individual_expense_of(&budgeters[0]));
+}
+
+#[test]
+fn sets_individual_association_from_category_group_association() {
+    let budgeters = fake::vec![Budgeter<ComputedSalary>; 1..5];
+    let category: Category = Faker.fake();
+    let associations = vec![ExpenseAssociation {
+        id: category.category_group_id,
+        association_type: AssociationType::CategoryGroup,
+        budgeter_id: Some(budgeters[0].id()),
+        split_strategy: None,
+    }];
+    let expense: Expense<Uncomputed> = category.into();
+    let expense = expense.set_individual_association(&budgeters, &associations);
+
+    assert_eq!(
+        expense.individual_associated(),
+        Some(&budgeters[0].name().to_owned())
+    );
+    assert_eq!(expense.individual_budgeter_id(), Some(budgeters[0].id()));
+}
+
+#[test]
+fn association_to_a_deleted_budgeter_marks_the_expense_as_shared() {
+    let budgeters = fake::vec![Budgeter<ComputedSalary>; 1..5];
+    let mut name = Faker.fake::<String>();
+    name.push_str(budgeters[0].name());
+    let category = Category {
+        name,
+        ..Faker.fake()
+    };
+    let associations = vec![ExpenseAssociation {
+        id: category.id,
+        association_type: AssociationType::Category,
+        budgeter_id: Some(Faker.fake()),
+        split_strategy: None,
+    }];
+    let expense: Expense<Uncomputed> = category.into();
+    let expense = expense.set_individual_association(&budgeters, &associations);
+
+    assert_eq!(expense.individual_budgeter_id(), None);
+    assert!(!expense.is_individual_expense_of(&budgeters[0]));
+}
+
+#[test]
+fn split_strategy_of_category_association_takes_precedence_over_category_group_association() {
+    let budgeters = fake::vec![Budgeter<ComputedSalary>; 1..5];
+    let category: Category = Faker.fake();
+    let associations = vec![
+        ExpenseAssociation {
+            id: category.category_group_id,
+            association_type: AssociationType::CategoryGroup,
+            budgeter_id: None,
+            split_strategy: Some(SplitStrategy::Equal),
+        },
+        ExpenseAssociation {
+            id: category.id,
+            association_type: AssociationType::Category,
+            budgeter_id: None,
+            split_strategy: Some(SplitStrategy::Single {
+                budgeter_id: budgeters[0].id(),
+            }),
+        },
+    ];
+    let expense: Expense<Uncomputed> = category.into();
+    let expense = expense.set_individual_association(&budgeters, &associations);
+
+    assert_eq!(
+        expense.split_strategy_override(),
+        Some(&SplitStrategy::Single {
+            budgeter_id: budgeters[0].id()
+        })
+    );
+    assert_eq!(expense.split_strategy(), &SplitStrategy::Proportional);
+}
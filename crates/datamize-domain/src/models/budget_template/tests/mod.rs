@@ -1,7 +1,20 @@
 mod budget_detail;
+mod budget_projection;
 mod budgeter;
 mod expense;
 mod expense_categorization;
 mod scheduled_transaction;
 mod scheduled_transaction_distribution;
+mod split_strategy;
 mod total_budgeter;
+
+use chrono::{DateTime, Local, NaiveDate};
+
+use crate::{Clock, FixedClock};
+
+/// The time the tests run at, so they don't depend on the day they are run.
+/// A day in the middle of a month, away from the month ends and from the June and December of
+/// twice a year transactions.
+fn fixed_now() -> DateTime<Local> {
+    FixedClock::new_arced(NaiveDate::from_ymd_opt(2024, 10, 17).unwrap()).now()
+}
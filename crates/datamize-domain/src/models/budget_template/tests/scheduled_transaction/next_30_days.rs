@@ -1,8 +1,8 @@
-use chrono::{Days, Local, Months};
+use chrono::{Days, Months};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 
-use crate::DatamizeScheduledTransaction;
+use crate::{models::budget_template::tests::fixed_now, DatamizeScheduledTransaction};
 
 #[track_caller]
 fn check_method(st: &DatamizeScheduledTransaction, expected: bool) {
@@ -10,13 +10,16 @@ fn check_method(st: &DatamizeScheduledTransaction, expected: bool) {
     let caller_line_number = caller_location.line();
     println!("check_method called from line: {}", caller_line_number);
 
-    assert_eq!(st.is_in_next_30_days().unwrap(), expected);
+    assert_eq!(
+        st.is_in_next_30_days(fixed_now().date_naive()).unwrap(),
+        expected
+    );
 }
 
 #[test]
 fn date_is_in_past() {
     let st = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_sub_days(Days::new(1))
             .unwrap(),
@@ -29,7 +32,7 @@ fn date_is_in_past() {
 #[test]
 fn date_is_too_far_in_future() {
     let st = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_months(Months::new(1))
             .and_then(|d| d.checked_add_days(Days::new(1)))
@@ -43,7 +46,7 @@ fn date_is_too_far_in_future() {
 #[test]
 fn date_is_in_next_30_days() {
     let st = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -53,7 +56,7 @@ fn date_is_in_next_30_days() {
     check_method(&st, true);
 
     let st = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_months(Months::new(1))
             .and_then(|d| d.checked_sub_days(Days::new(1)))
@@ -1,9 +1,9 @@
-use chrono::{Days, Local, Months};
+use chrono::{Days, Months};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use ynab::RecurFrequency;
 
-use crate::DatamizeScheduledTransaction;
+use crate::{models::budget_template::tests::fixed_now, DatamizeScheduledTransaction};
 
 #[track_caller]
 fn check_method(st: &DatamizeScheduledTransaction, expected: usize) {
@@ -11,7 +11,9 @@ fn check_method(st: &DatamizeScheduledTransaction, expected: usize) {
     let caller_line_number = caller_location.line();
     println!("check_method called from line: {}", caller_line_number);
 
-    let repeated = st.get_repeated_transactions().unwrap();
+    let repeated = st
+        .get_repeated_transactions(fixed_now().date_naive())
+        .unwrap();
     assert_eq!(repeated.len(), expected);
     if !repeated.is_empty() {
         for r in repeated {
@@ -45,7 +47,7 @@ fn empty_when_no_frequency_that_repeats_within_a_month() {
 
 #[test]
 fn is_4_when_every_week_and_starting_yesterday() {
-    let date_first = Local::now()
+    let date_first = fixed_now()
         .date_naive()
         .checked_sub_days(Days::new(1))
         .unwrap();
@@ -62,12 +64,12 @@ fn is_4_when_every_week_and_starting_yesterday() {
 
 #[test]
 fn is_5_when_every_week_and_starting_7_days_ago() {
-    let date_first = Local::now()
+    let date_first = fixed_now()
         .date_naive()
         .checked_sub_days(Days::new(7))
         .unwrap();
     let mut date_next = date_first.checked_add_days(Days::new(7)).unwrap();
-    let current_date = Local::now();
+    let current_date = fixed_now();
     while date_next < current_date.date_naive() {
         date_next = date_next.checked_add_days(Days::new(7)).unwrap();
     }
@@ -83,7 +85,7 @@ fn is_5_when_every_week_and_starting_7_days_ago() {
 
 #[test]
 fn is_2_when_every_other_week_and_starting_yesterday() {
-    let date_first = Local::now()
+    let date_first = fixed_now()
         .date_naive()
         .checked_sub_days(Days::new(1))
         .unwrap();
@@ -100,7 +102,7 @@ fn is_2_when_every_other_week_and_starting_yesterday() {
 
 #[test]
 fn is_2_when_every_other_week_and_starting_7_days_ago() {
-    let date_first = Local::now()
+    let date_first = fixed_now()
         .date_naive()
         .checked_sub_days(Days::new(7))
         .unwrap();
@@ -117,11 +119,11 @@ fn is_2_when_every_other_week_and_starting_7_days_ago() {
 
 #[test]
 fn is_1_when_every_4_week_and_next_date_in_middle() {
-    let date_first = Local::now()
+    let date_first = fixed_now()
         .date_naive()
         .checked_sub_days(Days::new(14))
         .unwrap();
-    let date_next = Local::now()
+    let date_next = fixed_now()
         .date_naive()
         .checked_add_days(Days::new(14))
         .unwrap();
@@ -137,11 +139,11 @@ fn is_1_when_every_4_week_and_next_date_in_middle() {
 
 #[test]
 fn is_2_when_every_4_week_and_next_date_tomorrow() {
-    let date_first = Local::now()
+    let date_first = fixed_now()
         .date_naive()
         .checked_sub_days(Days::new(27))
         .unwrap();
-    let date_next = Local::now()
+    let date_next = fixed_now()
         .date_naive()
         .checked_add_days(Days::new(1))
         .unwrap();
@@ -157,11 +159,11 @@ fn is_2_when_every_4_week_and_next_date_tomorrow() {
 
 #[test]
 fn is_num_days_current_month_when_daily() {
-    let date_first = Local::now()
+    let date_first = fixed_now()
         .date_naive()
         .checked_sub_days(Days::new(27))
         .unwrap();
-    let date_next = Local::now()
+    let date_next = fixed_now()
         .date_naive()
         .checked_add_days(Days::new(1))
         .unwrap();
@@ -172,12 +174,9 @@ fn is_num_days_current_month_when_daily() {
         ..Faker.fake()
     };
 
-    let next_30_days = Local::now()
-        .checked_add_months(Months::new(1))
-        .and_then(|d| d.checked_add_days(Days::new(1)))
-        .unwrap();
+    let next_30_days = fixed_now().checked_add_months(Months::new(1)).unwrap();
 
-    let num_days = next_30_days.signed_duration_since(Local::now()).num_days();
+    let num_days = next_30_days.signed_duration_since(fixed_now()).num_days();
 
     check_method(&st, num_days as usize - 1); // Last one is st itself
 }
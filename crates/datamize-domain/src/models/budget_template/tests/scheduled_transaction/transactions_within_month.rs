@@ -3,7 +3,7 @@ use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use ynab::RecurFrequency;
 
-use crate::DatamizeScheduledTransaction;
+use crate::{models::budget_template::tests::fixed_now, DatamizeScheduledTransaction};
 
 #[track_caller]
 fn check_method(st: &DatamizeScheduledTransaction, date: &DateTime<Local>, expected: usize) {
@@ -22,58 +22,58 @@ fn zero_when_no_frequency() {
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), 0);
+    check_method(&st, &fixed_now(), 0);
 }
 
 #[test]
 fn zero_when_no_frequency_that_repeats_within_a_month() {
-    let now = Local::now().date_naive();
+    let now = fixed_now().date_naive();
     let mut st = DatamizeScheduledTransaction {
         date_first: now.checked_sub_months(Months::new(1)).unwrap(),
         frequency: RecurFrequency::EveryOtherMonth,
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), 0);
+    check_method(&st, &fixed_now(), 0);
 
     st.frequency = RecurFrequency::Never;
-    check_method(&st, &Local::now(), 0);
+    check_method(&st, &fixed_now(), 0);
 
     st.frequency = RecurFrequency::Every3Months;
     st.date_first = now.checked_sub_months(Months::new(1)).unwrap();
-    check_method(&st, &Local::now(), 0);
+    check_method(&st, &fixed_now(), 0);
 
     st.frequency = RecurFrequency::Every4Months;
-    check_method(&st, &Local::now(), 0);
+    check_method(&st, &fixed_now(), 0);
 
     st.frequency = RecurFrequency::TwiceAYear;
-    check_method(&st, &Local::now(), 0);
+    check_method(&st, &fixed_now(), 0);
 
     st.frequency = RecurFrequency::Yearly;
-    check_method(&st, &Local::now(), 0);
+    check_method(&st, &fixed_now(), 0);
 
     st.frequency = RecurFrequency::EveryOtherYear;
-    check_method(&st, &Local::now(), 0);
+    check_method(&st, &fixed_now(), 0);
 }
 
 #[test]
 fn one_when_non_monthly_frequency_that_is_due_in_current_month() {
-    let now = Local::now().date_naive();
+    let now = fixed_now().date_naive();
     let mut st = DatamizeScheduledTransaction {
         date_first: now.checked_sub_months(Months::new(2)).unwrap(),
         frequency: RecurFrequency::EveryOtherMonth,
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), 1);
+    check_method(&st, &fixed_now(), 1);
 
     st.frequency = RecurFrequency::Every3Months;
     st.date_first = now.checked_sub_months(Months::new(3)).unwrap();
-    check_method(&st, &Local::now(), 1);
+    check_method(&st, &fixed_now(), 1);
 
     st.frequency = RecurFrequency::Every4Months;
     st.date_first = now.checked_sub_months(Months::new(4)).unwrap();
-    check_method(&st, &Local::now(), 1);
+    check_method(&st, &fixed_now(), 1);
 
     // For now, since twice a year repeats only on june and december.
     st.frequency = RecurFrequency::TwiceAYear;
@@ -83,23 +83,23 @@ fn one_when_non_monthly_frequency_that_is_due_in_current_month() {
 
     st.frequency = RecurFrequency::Yearly;
     st.date_first = now.checked_sub_months(Months::new(12)).unwrap();
-    check_method(&st, &Local::now(), 1);
+    check_method(&st, &fixed_now(), 1);
 
     st.frequency = RecurFrequency::EveryOtherYear;
     st.date_first = now.checked_sub_months(Months::new(24)).unwrap();
-    check_method(&st, &Local::now(), 1);
+    check_method(&st, &fixed_now(), 1);
 }
 
 #[test]
 fn is_1_when_monthly() {
-    let date_first = Local::now().date_naive();
+    let date_first = fixed_now().date_naive();
     let st = DatamizeScheduledTransaction {
         frequency: RecurFrequency::Monthly,
         date_first,
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), 1);
+    check_method(&st, &fixed_now(), 1);
 }
 
 #[test]
@@ -118,31 +118,31 @@ fn is_1_when_monthly_even_on_last_day() {
 
 #[test]
 fn is_2_when_twice_a_month() {
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let st = DatamizeScheduledTransaction {
         frequency: RecurFrequency::TwiceAMonth,
         date_first,
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), 2);
+    check_method(&st, &fixed_now(), 2);
 }
 
 #[test]
 fn is_4_when_every_week_and_starting_on_fifth_day() {
-    let date_first = Local::now().date_naive().with_day(5).unwrap();
+    let date_first = fixed_now().date_naive().with_day(5).unwrap();
     let st = DatamizeScheduledTransaction {
         frequency: RecurFrequency::Weekly,
         date_first,
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), 4);
+    check_method(&st, &fixed_now(), 4);
 }
 
 #[test]
 fn is_5_when_every_week_and_starting_first_day_of_month() {
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let repeated = if date_first.month0() == 1 && !date_first.leap_year() {
         4
     } else {
@@ -154,12 +154,12 @@ fn is_5_when_every_week_and_starting_first_day_of_month() {
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), repeated);
+    check_method(&st, &fixed_now(), repeated);
 }
 
 #[test]
 fn is_3_when_every_other_week_and_starting_beginning_of_month() {
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let repeated = if date_first.month0() == 1 && !date_first.leap_year() {
         2
     } else {
@@ -171,36 +171,36 @@ fn is_3_when_every_other_week_and_starting_beginning_of_month() {
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), repeated);
+    check_method(&st, &fixed_now(), repeated);
 }
 
 #[test]
 fn is_2_when_every_other_week_and_starting_fifth_day_of_month() {
-    let date_first = Local::now().date_naive().with_day(5).unwrap();
+    let date_first = fixed_now().date_naive().with_day(5).unwrap();
     let st = DatamizeScheduledTransaction {
         frequency: RecurFrequency::EveryOtherWeek,
         date_first,
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), 2);
+    check_method(&st, &fixed_now(), 2);
 }
 
 #[test]
 fn is_1_when_every_4_week_and_starting_fifth_day_of_month() {
-    let date_first = Local::now().date_naive().with_day(5).unwrap();
+    let date_first = fixed_now().date_naive().with_day(5).unwrap();
     let st = DatamizeScheduledTransaction {
         frequency: RecurFrequency::Every4Weeks,
         date_first,
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), 1);
+    check_method(&st, &fixed_now(), 1);
 }
 
 #[test]
 fn is_2_when_every_4_week_and_starting_first_day_of_month() {
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let repeated = if date_first.month0() == 1 && !date_first.leap_year() {
         1
     } else {
@@ -212,27 +212,26 @@ fn is_2_when_every_4_week_and_starting_first_day_of_month() {
         ..Faker.fake()
     };
 
-    check_method(&st, &Local::now(), repeated);
+    check_method(&st, &fixed_now(), repeated);
 }
 
 #[test]
 fn is_num_days_current_month_when_daily() {
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let st = DatamizeScheduledTransaction {
         frequency: RecurFrequency::Daily,
         date_first,
         ..Faker.fake()
     };
 
-    // Second day since duration difference exclude upper bound limit.
-    let second_day_next_month = Local::now()
+    let first_day_next_month = fixed_now()
         .checked_add_months(Months::new(1))
-        .and_then(|d| d.with_day(2))
+        .and_then(|d| d.with_day(1))
         .unwrap();
 
-    let num_days = second_day_next_month
-        .signed_duration_since(Local::now().with_day(1).unwrap())
+    let num_days = first_day_next_month
+        .signed_duration_since(fixed_now().with_day(1).unwrap())
         .num_days();
 
-    check_method(&st, &Local::now(), num_days as usize);
+    check_method(&st, &fixed_now(), num_days as usize);
 }
@@ -1,12 +1,15 @@
 use std::collections::HashMap;
 
-use chrono::{Days, Local, NaiveDate};
+use chrono::{Days, NaiveDate};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use uuid::Uuid;
 use ynab::{RecurFrequency, ScheduledSubTransaction};
 
-use crate::{CategoryIdToNameMap, DatamizeScheduledTransaction, ScheduledTransactionsDistribution};
+use crate::{
+    models::budget_template::tests::fixed_now, CategoryIdToNameMap, Clock,
+    DatamizeScheduledTransaction, FixedClock, ScheduledTransactionsDistribution,
+};
 
 #[derive(Debug, Clone)]
 struct Expected {
@@ -32,7 +35,7 @@ fn check_method(
             scheduled_distribution_builder.with_category_map(category_id_to_name_map);
     }
 
-    let scheduled_distribution = scheduled_distribution_builder.build();
+    let scheduled_distribution = scheduled_distribution_builder.build(fixed_now().date_naive());
 
     let map = scheduled_distribution.map();
     assert_eq!(map.is_empty(), empty);
@@ -62,7 +65,7 @@ fn empty_when_no_transactions() {
 #[test]
 fn empty_when_transaction_deleted() {
     let trans = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -85,7 +88,7 @@ fn empty_when_transaction_deleted() {
 #[test]
 fn trans_present_once_when_not_repeating_in_month() {
     let trans = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -107,7 +110,7 @@ fn trans_present_once_when_not_repeating_in_month() {
 
 #[test]
 fn adds_both_trans_due_on_same_date() {
-    let date_next = Local::now()
+    let date_next = fixed_now()
         .date_naive()
         .checked_add_days(Days::new(1))
         .unwrap();
@@ -141,7 +144,7 @@ fn adds_both_trans_due_on_same_date() {
 
 #[test]
 fn adds_all_occurence_of_trans_repeating_in_month() {
-    let first_date = Local::now().date_naive();
+    let first_date = fixed_now().date_naive();
     let trans = DatamizeScheduledTransaction {
         date_next: first_date,
         frequency: RecurFrequency::EveryOtherWeek,
@@ -171,7 +174,7 @@ fn adds_all_occurence_of_trans_repeating_in_month() {
 
 #[test]
 fn empty_when_date_is_too_far_in_future() {
-    let date_next = Local::now()
+    let date_next = fixed_now()
         .date_naive()
         .checked_add_days(Days::new(33))
         .unwrap();
@@ -196,7 +199,7 @@ fn empty_when_date_is_too_far_in_future() {
 #[test]
 fn only_trans_when_sub_trans_are_deleted() {
     let trans = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -236,7 +239,7 @@ fn sub_trans_replace_trans() {
         ..Faker.fake()
     };
     let trans = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -286,7 +289,7 @@ fn sub_trans_replace_trans() {
 #[test]
 fn no_category_name_when_no_cat_id() {
     let trans = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -313,7 +316,7 @@ fn use_category_name_found_in_cat_id_to_name_map() {
     let cat_id: Uuid = Faker.fake();
     let cat_name: String = Faker.fake();
     let trans = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -347,7 +350,7 @@ fn no_category_name_when_not_found_in_cat_id_to_name_map() {
     let cat_id: Uuid = Faker.fake();
     let cat_name: String = Faker.fake();
     let trans = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -375,7 +378,7 @@ fn no_category_name_when_not_found_in_cat_id_to_name_map() {
 fn no_category_name_when_cat_id_to_name_map_not_defined() {
     let cat_id: Uuid = Faker.fake();
     let trans = DatamizeScheduledTransaction {
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -396,3 +399,42 @@ fn no_category_name_when_cat_id_to_name_map_not_defined() {
         },
     );
 }
+
+#[test]
+fn distributes_transactions_of_given_month() {
+    let trans = DatamizeScheduledTransaction {
+        date_first: NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+        date_next: NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+        frequency: RecurFrequency::Monthly,
+        subtransactions: vec![],
+        deleted: false,
+        category_name: Some(Faker.fake()),
+        ..Faker.fake()
+    };
+    let once = DatamizeScheduledTransaction {
+        date_next: NaiveDate::from_ymd_opt(2024, 6, 3).unwrap(),
+        frequency: RecurFrequency::Never,
+        subtransactions: vec![],
+        deleted: false,
+        category_name: Some(Faker.fake()),
+        ..Faker.fake()
+    };
+    let month = FixedClock::new_arced(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()).now();
+
+    let scheduled_distribution =
+        ScheduledTransactionsDistribution::builder(vec![trans.clone(), once.clone()])
+            .with_month(month)
+            .build(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap());
+
+    let map = scheduled_distribution.map();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map[&once.date_next], vec![once]);
+    let june_10 = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+    assert_eq!(
+        map[&june_10],
+        vec![DatamizeScheduledTransaction {
+            date_next: june_10,
+            ..trans
+        }]
+    );
+}
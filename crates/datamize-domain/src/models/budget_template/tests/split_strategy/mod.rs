@@ -0,0 +1,2 @@
+mod parse;
+mod share;
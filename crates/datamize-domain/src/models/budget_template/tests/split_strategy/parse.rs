@@ -0,0 +1,44 @@
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::SplitStrategy;
+
+#[test]
+fn parses_unit_variants() {
+    assert_eq!(
+        r#"{"type":"proportional"}"#.parse::<SplitStrategy>().unwrap(),
+        SplitStrategy::Proportional
+    );
+    assert_eq!(
+        r#"{"type":"equal"}"#.parse::<SplitStrategy>().unwrap(),
+        SplitStrategy::Equal
+    );
+}
+
+#[test]
+fn round_trips_through_string() {
+    let strategies = [
+        SplitStrategy::Proportional,
+        SplitStrategy::Equal,
+        SplitStrategy::FixedPercentages {
+            percentages: [(Uuid::new_v4(), 25), (Uuid::new_v4(), 75)]
+                .into_iter()
+                .collect(),
+        },
+        SplitStrategy::Single {
+            budgeter_id: Uuid::new_v4(),
+        },
+    ];
+
+    for strategy in strategies {
+        assert_eq!(
+            strategy.to_string().parse::<SplitStrategy>().unwrap(),
+            strategy
+        );
+    }
+}
+
+#[test]
+fn fails_on_unknown_type() {
+    assert!(r#"{"type":"random"}"#.parse::<SplitStrategy>().is_err());
+}
@@ -0,0 +1,79 @@
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::SplitStrategy;
+
+#[test]
+fn proportional_share_is_budgeter_proportion() {
+    let share = SplitStrategy::Proportional.share(Faker.fake(), 0.42, 2);
+
+    assert_eq!(share, 0.42);
+}
+
+#[test]
+fn equal_share_depends_on_number_of_budgeters() {
+    assert_eq!(SplitStrategy::Equal.share(Faker.fake(), 0.42, 4), 0.25);
+    assert_eq!(SplitStrategy::Equal.share(Faker.fake(), 0.42, 0), 0.0);
+}
+
+#[test]
+fn fixed_percentages_share_is_0_when_budgeter_not_listed() {
+    let budgeter_id: Uuid = Faker.fake();
+    let strategy = SplitStrategy::FixedPercentages {
+        percentages: [(budgeter_id, 60), (Faker.fake(), 40)]
+            .into_iter()
+            .collect(),
+    };
+
+    assert_eq!(strategy.share(budgeter_id, 0.42, 2), 0.6);
+    assert_eq!(strategy.share(Faker.fake(), 0.42, 2), 0.0);
+}
+
+#[test]
+fn single_share_is_all_or_nothing() {
+    let budgeter_id: Uuid = Faker.fake();
+    let strategy = SplitStrategy::Single { budgeter_id };
+
+    assert_eq!(strategy.share(budgeter_id, 0.42, 2), 1.0);
+    assert_eq!(strategy.share(Faker.fake(), 0.42, 2), 0.0);
+}
+
+#[test]
+fn fixed_percentages_are_valid_only_when_adding_up_to_100() {
+    let budgeter_ids: Vec<Uuid> = vec![Faker.fake(), Faker.fake()];
+    let valid = SplitStrategy::FixedPercentages {
+        percentages: [(budgeter_ids[0], 60), (budgeter_ids[1], 40)]
+            .into_iter()
+            .collect(),
+    };
+    let invalid = SplitStrategy::FixedPercentages {
+        percentages: [(budgeter_ids[0], 60), (budgeter_ids[1], 60)]
+            .into_iter()
+            .collect(),
+    };
+
+    assert!(valid.is_valid(&budgeter_ids));
+    assert!(!invalid.is_valid(&budgeter_ids));
+    assert!(SplitStrategy::Equal.is_valid(&budgeter_ids));
+}
+
+#[test]
+fn strategies_are_invalid_when_a_budgeter_is_unknown() {
+    let budgeter_ids: Vec<Uuid> = vec![Faker.fake(), Faker.fake()];
+    let fixed_percentages = SplitStrategy::FixedPercentages {
+        percentages: [(budgeter_ids[0], 60), (Faker.fake(), 40)]
+            .into_iter()
+            .collect(),
+    };
+    let single = SplitStrategy::Single {
+        budgeter_id: Faker.fake(),
+    };
+
+    assert!(!fixed_percentages.is_valid(&budgeter_ids));
+    assert!(!single.is_valid(&budgeter_ids));
+    assert!(SplitStrategy::Single {
+        budgeter_id: budgeter_ids[1]
+    }
+    .is_valid(&budgeter_ids));
+}
@@ -2,6 +2,7 @@ use crate::{
     models::budget_template::{
         expense,
         tests::total_budgeter::testutils::{
+            associate_first_expense_to_first_budgeter, setup_budgeters_with_first_salary,
             setup_budgeters_with_salary, setup_budgeters_with_salary_with_name,
             setup_computed_expenses, setup_computed_expenses_with_first_non_external,
         },
@@ -9,13 +10,14 @@ use crate::{
     Budgeter, BudgeterExt, ComputedSalary, Expense, TotalBudgeter,
 };
 use pretty_assertions::assert_eq;
+use ynab::Milliunits;
 
 #[derive(Debug, Clone)]
 struct Expected {
     individual_expenses_len: usize,
-    individual_expenses: i64,
-    common_expenses: i64,
-    left_over: i64,
+    individual_expenses: Milliunits,
+    common_expenses: Milliunits,
+    left_over: Milliunits,
 }
 
 #[track_caller]
@@ -57,9 +59,9 @@ fn total_is_0_when_no_budgeters_or_expenses() {
         &[],
         Expected {
             individual_expenses_len: 0,
-            individual_expenses: 0,
-            common_expenses: 0,
-            left_over: 0,
+            individual_expenses: Milliunits::ZERO,
+            common_expenses: Milliunits::ZERO,
+            left_over: Milliunits::ZERO,
         },
     );
 }
@@ -72,8 +74,8 @@ fn total_left_over_is_salary_when_no_expenses() {
         &[],
         Expected {
             individual_expenses_len: 0,
-            individual_expenses: 0,
-            common_expenses: 0,
+            individual_expenses: Milliunits::ZERO,
+            common_expenses: Milliunits::ZERO,
             left_over: budgeters.iter().map(|b| b.salary_month()).sum(),
         },
     );
@@ -82,14 +84,14 @@ fn total_left_over_is_salary_when_no_expenses() {
 #[test]
 fn total_left_over_is_inverse_of_all_expenses_when_no_budgeters() {
     let expenses = setup_computed_expenses();
-    let total_expense: i64 = expenses.iter().map(|e| e.projected_amount()).sum();
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
 
     check_method_total_budgeter(
         &[],
         &expenses,
         Expected {
             individual_expenses_len: 0,
-            individual_expenses: 0,
+            individual_expenses: Milliunits::ZERO,
             common_expenses: total_expense,
             left_over: -total_expense,
         },
@@ -100,25 +102,54 @@ fn total_left_over_is_inverse_of_all_expenses_when_no_budgeters() {
 fn total_left_over_is_salary_minus_all_expenses_when_no_budgeters_match() {
     let expenses = setup_computed_expenses();
     let budgeters = setup_budgeters_with_salary();
-    let total_expense: i64 = expenses.iter().map(|e| e.projected_amount()).sum();
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
 
     check_method_total_budgeter(
         &budgeters,
         &expenses,
         Expected {
             individual_expenses_len: 0,
-            individual_expenses: 0,
+            individual_expenses: Milliunits::ZERO,
             common_expenses: total_expense,
-            left_over: budgeters.iter().map(|b| b.salary_month()).sum::<i64>() - total_expense,
+            left_over: budgeters
+                .iter()
+                .map(|b| b.salary_month())
+                .sum::<Milliunits>()
+                - total_expense,
         },
     );
 }
 
 #[test]
 fn total_left_over_is_salary_minus_all_expenses_even_when_budgeters_match() {
+    let budgeters = setup_budgeters_with_first_salary();
+    let expenses = associate_first_expense_to_first_budgeter(
+        setup_computed_expenses_with_first_non_external(),
+        &budgeters,
+    );
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
+
+    check_method_total_budgeter(
+        &budgeters,
+        &expenses,
+        Expected {
+            individual_expenses_len: 1,
+            individual_expenses: expenses[0].projected_amount(),
+            common_expenses: total_expense - expenses[0].projected_amount(),
+            left_over: budgeters
+                .iter()
+                .map(|b| b.salary_month())
+                .sum::<Milliunits>()
+                - total_expense,
+        },
+    );
+}
+
+#[test]
+fn total_individual_expenses_fall_back_to_budgeter_name_when_not_associated() {
     let expenses = setup_computed_expenses_with_first_non_external();
     let budgeters = setup_budgeters_with_salary_with_name(expenses[0].name());
-    let total_expense: i64 = expenses.iter().map(|e| e.projected_amount()).sum();
+    let total_expense: Milliunits = expenses.iter().map(|e| e.projected_amount()).sum();
 
     check_method_total_budgeter(
         &budgeters,
@@ -127,7 +158,11 @@ fn total_left_over_is_salary_minus_all_expenses_even_when_budgeters_match() {
             individual_expenses_len: 1,
             individual_expenses: expenses[0].projected_amount(),
             common_expenses: total_expense - expenses[0].projected_amount(),
-            left_over: budgeters.iter().map(|b| b.salary_month()).sum::<i64>() - total_expense,
+            left_over: budgeters
+                .iter()
+                .map(|b| b.salary_month())
+                .sum::<Milliunits>()
+                - total_expense,
         },
     );
 }
@@ -1,16 +1,17 @@
-use chrono::{Datelike, Days, Local, Months};
+use chrono::{Datelike, Days, Months};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use uuid::Uuid;
+use ynab::Milliunits;
 
 use crate::{
-    Budgeter, BudgeterConfig, BudgeterExt, ComputedSalary, Configured,
-    DatamizeScheduledTransaction, TotalBudgeter,
+    models::budget_template::tests::fixed_now, Budgeter, BudgeterConfig, BudgeterExt,
+    ComputedSalary, Configured, DatamizeScheduledTransaction, TotalBudgeter,
 };
 
 #[derive(Debug, Clone)]
 struct Expected {
-    salary_month: i64,
+    salary_month: Milliunits,
 }
 
 #[track_caller]
@@ -33,7 +34,12 @@ fn check_method_total_budgeter(
 
 #[test]
 fn total_salary_is_0_when_no_budgeters() {
-    check_method_total_budgeter(&[], Expected { salary_month: 0 });
+    check_method_total_budgeter(
+        &[],
+        Expected {
+            salary_month: Milliunits::ZERO,
+        },
+    );
 }
 
 #[test]
@@ -49,7 +55,7 @@ fn total_salary_is_sum_of_all_budgeters() {
         },
     ];
     let budgeter1: Budgeter<Configured> = configs[0].clone().into();
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let date_first = if date_first.month0() == 1 {
         date_first.checked_sub_months(Months::new(1)).unwrap()
     } else {
@@ -64,9 +70,9 @@ fn total_salary_is_sum_of_all_budgeters() {
         ..Faker.fake()
     };
     let budgeter1 =
-        budgeter1.compute_salary(&vec![transaction.clone()], &Local::now(), Faker.fake());
+        budgeter1.compute_salary(&vec![transaction.clone()], &fixed_now(), Faker.fake());
     let budgeter2: Budgeter<Configured> = configs[0].clone().into();
-    let date_first = Local::now().date_naive().with_day(1).unwrap();
+    let date_first = fixed_now().date_naive().with_day(1).unwrap();
     let date_first = if date_first.month0() == 1 {
         date_first.checked_sub_months(Months::new(1)).unwrap()
     } else {
@@ -81,7 +87,7 @@ fn total_salary_is_sum_of_all_budgeters() {
         ..Faker.fake()
     };
     let budgeter2 =
-        budgeter2.compute_salary(&vec![transaction.clone()], &Local::now(), Faker.fake());
+        budgeter2.compute_salary(&vec![transaction.clone()], &fixed_now(), Faker.fake());
 
     check_method_total_budgeter(
         &[budgeter1.clone(), budgeter2.clone()],
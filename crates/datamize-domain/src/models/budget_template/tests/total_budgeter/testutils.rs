@@ -1,17 +1,22 @@
-use chrono::{Days, Local};
+use chrono::Days;
 use fake::{Fake, Faker};
 use uuid::Uuid;
 use ynab::{Category, RecurFrequency};
 
 use crate::{
-    models::budget_template::expense, Budgeter, BudgeterConfig, BudgeterExt, ComputedSalary,
-    Configured, DatamizeScheduledTransaction, Expense, Uncomputed,
+    models::budget_template::{expense, tests::fixed_now},
+    AssociationType, Budgeter, BudgeterConfig, BudgeterExt, ComputedSalary, Configured,
+    DatamizeScheduledTransaction, Expense, ExpenseAssociation, Uncomputed,
 };
 
 pub fn setup_budgeters_with_salary() -> Vec<Budgeter<ComputedSalary>> {
     fake::vec![Budgeter<ComputedSalary>; 2]
 }
 
+pub fn setup_budgeters_with_first_salary() -> Vec<Budgeter<ComputedSalary>> {
+    setup_budgeters_with_salary_with_name(&Faker.fake::<String>())
+}
+
 pub fn setup_budgeters_with_salary_with_name(first_name: &str) -> Vec<Budgeter<ComputedSalary>> {
     let config = BudgeterConfig {
         payee_ids: fake::vec![Uuid; 1..3],
@@ -22,11 +27,11 @@ pub fn setup_budgeters_with_salary_with_name(first_name: &str) -> Vec<Budgeter<C
     let transaction = DatamizeScheduledTransaction {
         amount: (1..100000).fake(),
         frequency: RecurFrequency::Never,
-        date_first: Local::now()
+        date_first: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
-        date_next: Local::now()
+        date_next: fixed_now()
             .date_naive()
             .checked_add_days(Days::new(1))
             .unwrap(),
@@ -34,7 +39,7 @@ pub fn setup_budgeters_with_salary_with_name(first_name: &str) -> Vec<Budgeter<C
         payee_name: Some(Faker.fake()),
         ..Faker.fake()
     };
-    let budgeter = budgeter.compute_salary(&vec![transaction.clone()], &Local::now(), Faker.fake());
+    let budgeter = budgeter.compute_salary(&vec![transaction.clone()], &fixed_now(), Faker.fake());
 
     vec![budgeter, Faker.fake()]
 }
@@ -54,10 +59,26 @@ pub fn setup_computed_expenses_with_first_non_external() -> Vec<Expense<expense:
     let expense: Expense<Uncomputed> = category.clone().into();
     let expense = expense
         .with_scheduled_transactions(scheduled_transactions.clone())
-        .compute_amounts()
+        .compute_amounts(fixed_now().date_naive())
         .compute_proportions((1..1000).fake());
     let fake_vec = fake::vec![Expense<expense::Computed>; 5..10];
     let mut vec = vec![expense];
     vec.extend(fake_vec);
     vec
 }
+
+pub fn associate_first_expense_to_first_budgeter(
+    mut expenses: Vec<Expense<expense::Computed>>,
+    budgeters: &[Budgeter<ComputedSalary>],
+) -> Vec<Expense<expense::Computed>> {
+    let association = ExpenseAssociation {
+        id: expenses[0].category().id,
+        association_type: AssociationType::Category,
+        budgeter_id: Some(budgeters[0].id()),
+        split_strategy: None,
+    };
+    expenses[0] = expenses[0]
+        .clone()
+        .set_individual_association(budgeters, &[association]);
+    expenses
+}
@@ -1,7 +1,11 @@
+mod backup;
 mod balance_sheet;
 mod budget_providers;
 mod budget_template;
+mod scheduler;
 
+pub use backup::*;
 pub use balance_sheet::*;
 pub use budget_providers::*;
 pub use budget_template::*;
+pub use scheduler::*;
@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The jobs run in the background by the scheduler.
+#[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledJob {
+    /// Creates the year and the month that just started and carries over the resources still active.
+    Rollover,
+    /// Refreshes the balances of the resources linked to YNAB or external accounts.
+    Refresh,
+}
+
+impl std::fmt::Display for ScheduledJob {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ScheduledJob::Rollover => write!(f, "rollover"),
+            ScheduledJob::Refresh => write!(f, "refresh"),
+        }
+    }
+}
+
+impl FromStr for ScheduledJob {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rollover" => Ok(Self::Rollover),
+            "refresh" => Ok(Self::Refresh),
+            _ => Err(format!("Failed to parse {:?} to ScheduledJob", s)),
+        }
+    }
+}
+
+/// The outcome of a job run by the scheduler.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchedulerRun {
+    pub id: Uuid,
+    pub job: ScheduledJob,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub succeeded: bool,
+    /// What the job did, or why it failed.
+    pub details: String,
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct SchedulerRunsParams {
+    /// Defaults to the 50 latest runs.
+    pub limit: Option<u32>,
+}
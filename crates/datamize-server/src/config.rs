@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use datamize_domain::secrecy::{ExposeSecret, Secret};
 use db_postgres::{PgConnectOptions, PgSslMode};
-use serde::Deserialize;
+use db_sqlite::SqliteConnectOptions;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use sqlx::ConnectOptions;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -8,8 +12,13 @@ pub struct Settings {
     pub application: ApplicationSettings,
     pub ynab_client: YnabClientSettings,
     pub database: DatabaseSettings,
-    pub redis: RedisSettings,
+    #[serde(default)]
+    pub redis: Option<RedisSettings>,
     pub webdriver: WebDriverSettings,
+    #[serde(default)]
+    pub scheduler: SchedulerSettings,
+    #[serde(default)]
+    pub statements: StatementSettings,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -24,9 +33,44 @@ pub struct YnabClientSettings {
     pub pat: Secret<String>,
     pub pat_file: String,
     pub base_url: String,
+    #[serde(default)]
+    pub budgets: Vec<YnabBudgetSettings>,
+    #[serde(default)]
+    pub oauth: Option<YnabOAuthSettings>,
+}
+
+/// A YNAB budget the server can work with. Its routes are exposed under `/api/budgets/:budget_id`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct YnabBudgetSettings {
+    pub id: String,
+    pub name: String,
+    /// The household member whose YNAB account holds the budget, when using OAuth.
+    /// Budgets without one are read with the account of the `DEFAULT_YNAB_MEMBER`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member: Option<String>,
+}
+
+/// The member connecting when none is given to `/auth/ynab/login`.
+pub const DEFAULT_YNAB_MEMBER: &str = "default";
+
+/// An OAuth application registered in YNAB, used instead of the personal access token when set.
+/// Each household member connects their YNAB account by visiting `/auth/ynab/login?member=`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct YnabOAuthSettings {
+    pub client_id: String,
+    pub client_secret: Secret<String>,
+    pub redirect_uri: String,
 }
 
 impl YnabClientSettings {
+    /// The household members holding the budgets, starting with the `DEFAULT_YNAB_MEMBER`.
+    pub fn members(&self) -> Vec<String> {
+        std::iter::once(DEFAULT_YNAB_MEMBER.to_owned())
+            .chain(self.budgets.iter().filter_map(|b| b.member.clone()))
+            .unique()
+            .collect()
+    }
+
     pub fn client(mut self) -> ynab::Client {
         self.pat = match self.pat_file {
             file_path if !file_path.is_empty() => {
@@ -36,13 +80,37 @@ impl YnabClientSettings {
             _ => self.pat,
         };
 
-        ynab::Client::new(self.pat.expose_secret(), &self.base_url)
-            .expect("Failed to build ynab client.")
+        let client = ynab::Client::new(self.pat.expose_secret(), &self.base_url)
+            .expect("Failed to build ynab client.");
+
+        match self.oauth {
+            Some(oauth) => client.with_oauth(ynab::OAuthConfig::new(
+                &oauth.client_id,
+                oauth.client_secret.expose_secret(),
+                &oauth.redirect_uri,
+            )),
+            None => client,
+        }
     }
 }
 
+/// Where the server stores its data.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseBackend {
+    #[default]
+    Postgres,
+    /// Everything in a single SQLite file.
+    Sqlite,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct DatabaseSettings {
+    #[serde(default)]
+    pub backend: DatabaseBackend,
+    /// Path of the database file when using the SQLite backend. Created if missing.
+    #[serde(default = "default_sqlite_path")]
+    pub sqlite_path: String,
     pub username: String,
     pub password: Secret<String>,
     pub port: u16,
@@ -71,8 +139,20 @@ impl DatabaseSettings {
             .database(&self.database_name)
             .log_statements(tracing::log::LevelFilter::Trace)
     }
+
+    pub fn sqlite(&self) -> SqliteConnectOptions {
+        SqliteConnectOptions::new()
+            .filename(&self.sqlite_path)
+            .log_statements(tracing::log::LevelFilter::Trace)
+    }
 }
 
+fn default_sqlite_path() -> String {
+    "datamize.db".to_owned()
+}
+
+/// A Redis instance used by previous versions to hold the delta requests state, the resources
+/// order and the secrets. When set with the Postgres backend, what it holds is copied to the database on startup.
 #[derive(Debug, Clone, Deserialize)]
 pub struct RedisSettings {
     pub host: String,
@@ -89,6 +169,9 @@ impl RedisSettings {
 pub struct WebDriverSettings {
     pub host: String,
     pub port: u16,
+    /// A directory of JSON or TOML scraper recipes, added to the built-in ones.
+    #[serde(default)]
+    pub recipes_directory: Option<String>,
 }
 
 impl WebDriverSettings {
@@ -97,6 +180,85 @@ impl WebDriverSettings {
     }
 }
 
+/// Jobs run in the background: the rollover creating the year and month that just started,
+/// and the refresh of the balances.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SchedulerSettings {
+    pub enabled: bool,
+    pub refresh: RefreshSchedule,
+}
+
+impl Default for SchedulerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            refresh: RefreshSchedule::Daily,
+        }
+    }
+}
+
+/// How often the balances of the resources linked to YNAB or external accounts are refreshed.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RefreshSchedule {
+    Never,
+    Hourly,
+    #[default]
+    Daily,
+    Weekly,
+}
+
+impl RefreshSchedule {
+    pub fn interval(&self) -> Option<chrono::Duration> {
+        match self {
+            RefreshSchedule::Never => None,
+            RefreshSchedule::Hourly => Some(chrono::Duration::hours(1)),
+            RefreshSchedule::Daily => Some(chrono::Duration::days(1)),
+            RefreshSchedule::Weekly => Some(chrono::Duration::weeks(1)),
+        }
+    }
+}
+
+/// The statements external accounts can be updated from, for institutions that can't be scraped.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+pub struct StatementSettings {
+    /// The layouts of the CSV statements, by the name given when importing one.
+    #[serde(default)]
+    pub csv_layouts: BTreeMap<String, CsvLayout>,
+}
+
+/// How to read the CSV statements of an institution. Columns are referred to by their header.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct CsvLayout {
+    #[serde(default = "default_delimiter")]
+    pub delimiter: char,
+    /// Lines before the header, i.e. the account number some institutions write first.
+    #[serde(default)]
+    pub skip_lines: usize,
+    pub date_column: String,
+    /// As understood by chrono, i.e. `%Y-%m-%d` or `%d/%m/%Y`.
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    pub balance_column: String,
+    pub amount_column: Option<String>,
+    /// For statements with withdrawals and deposits in separate columns instead of an amount.
+    pub debit_column: Option<String>,
+    pub credit_column: Option<String>,
+    pub description_column: Option<String>,
+    /// When the decimals are separated by a comma instead of a dot.
+    #[serde(default)]
+    pub decimal_comma: bool,
+}
+
+fn default_delimiter() -> char {
+    ','
+}
+
+fn default_date_format() -> String {
+    "%Y-%m-%d".to_owned()
+}
+
 impl Settings {
     pub fn build() -> Result<Self, config::ConfigError> {
         let base_path = {
@@ -4,7 +4,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use config::ConfigError;
-use datamize_domain::db::DbError;
+use datamize_domain::{db::DbError, BACKUP_VERSION, MAX_PROJECTION_MONTHS};
 use serde::Serialize;
 
 pub type DatamizeResult<T> = Result<T, AppError>;
@@ -39,6 +39,36 @@ pub enum AppError {
     ParseError(#[from] chrono::ParseError),
     #[error("Error in the YNAB API")]
     YnabError(#[from] ynab::Error),
+    #[error("Invalid or expired OAuth state")]
+    InvalidOAuthState,
+    #[error("Invalid range of months to project")]
+    InvalidProjectionRange,
+    #[error("Invalid split strategy")]
+    InvalidSplitStrategy,
+    #[error("Invalid saving rate target")]
+    InvalidSavingRateTarget,
+    #[error("Balance observed in the future")]
+    BalanceInTheFuture,
+    #[error("Backup version {0} is not supported")]
+    UnsupportedBackupVersion(u32),
+    #[error("The database must be empty to restore a backup")]
+    DatabaseNotEmpty,
+    #[error("The backup was taken with another encryption key")]
+    EncryptionKeyMismatch,
+    #[error("Invalid amount")]
+    InvalidAmount(#[from] ynab::ParseMilliunitsError),
+    #[error("Amounts are too large to be added up")]
+    AmountOverflow,
+    #[error("Invalid file to import: {0}")]
+    InvalidImport(String),
+    #[error("Error while writing a CSV file")]
+    CsvError(#[from] csv::Error),
+    #[error("Error while writing an XLSX file")]
+    XlsxError(#[from] rust_xlsxwriter::XlsxError),
+    #[error("Error with encryption")]
+    EncryptionError(#[from] orion::errors::UnknownCryptoError),
+    #[error("The encryption key of the external accounts' passwords is missing")]
+    MissingEncryptionKey,
 }
 
 impl std::fmt::Debug for AppError {
@@ -89,16 +119,137 @@ impl IntoResponse for AppError {
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Something went wrong".to_owned(),
             ),
-            AppError::YnabError(_) => (
+            AppError::YnabError(err) => ynab_error_status(&err),
+            AppError::InvalidOAuthState => (
+                StatusCode::BAD_REQUEST,
+                "Invalid or expired OAuth state, try to login again".to_owned(),
+            ),
+            AppError::InvalidProjectionRange => (
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "The end of the projection must not be before its start, and it must cover at most {} months",
+                    MAX_PROJECTION_MONTHS
+                ),
+            ),
+            AppError::InvalidSplitStrategy => (
+                StatusCode::BAD_REQUEST,
+                "Fixed percentages of a split strategy must add up to 100".to_owned(),
+            ),
+            AppError::InvalidSavingRateTarget => (
+                StatusCode::BAD_REQUEST,
+                "The target of a saving rate must be between 0 and 1".to_owned(),
+            ),
+            AppError::BalanceInTheFuture => (
+                StatusCode::BAD_REQUEST,
+                "A balance can't be observed in the future".to_owned(),
+            ),
+            AppError::UnsupportedBackupVersion(version) => (
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "Backup version {} is not supported, the latest supported is {}",
+                    version, BACKUP_VERSION
+                ),
+            ),
+            AppError::DatabaseNotEmpty => (
+                StatusCode::CONFLICT,
+                "The database must be empty to restore a backup".to_owned(),
+            ),
+            AppError::EncryptionKeyMismatch => (
+                StatusCode::CONFLICT,
+                "The backup was taken with another encryption key than the one in use".to_owned(),
+            ),
+            AppError::InvalidAmount(err) => (StatusCode::BAD_REQUEST, err.to_string()),
+            AppError::AmountOverflow => (
+                StatusCode::BAD_REQUEST,
+                "Amounts are too large to be added up".to_owned(),
+            ),
+            AppError::InvalidImport(message) => (StatusCode::BAD_REQUEST, message),
+            AppError::CsvError(_) | AppError::XlsxError(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Something went wrong".to_owned(),
             ),
+            AppError::EncryptionError(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Something went wrong".to_owned(),
+            ),
+            AppError::MissingEncryptionKey => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "The encryption key of the external accounts' passwords is missing".to_owned(),
+            ),
         };
 
         (status, AppJson(ErrorResponse { message })).into_response()
     }
 }
 
+/// Maps an error coming from YNAB to the status code and message returned by our API,
+/// so clients can tell an expired token apart from YNAB being unavailable.
+fn ynab_error_status(err: &ynab::Error) -> (StatusCode, String) {
+    use ynab::ApiErrorKind;
+
+    match err {
+        ynab::Error::Http(e) if e.is_connect() || e.is_timeout() => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "YNAB could not be reached".to_owned(),
+        ),
+        ynab::Error::OAuth(_) => (
+            StatusCode::UNAUTHORIZED,
+            "YNAB authorization failed, login again through /auth/ynab/login".to_owned(),
+        ),
+        ynab::Error::OAuthNotConfigured => (
+            StatusCode::NOT_FOUND,
+            "YNAB OAuth is not configured".to_owned(),
+        ),
+        ynab::Error::Http(_) | ynab::Error::Conversion(_) => (
+            StatusCode::BAD_GATEWAY,
+            "Invalid response received from YNAB".to_owned(),
+        ),
+        _ => match err.api_error_kind() {
+            Some(ApiErrorKind::NotAuthorized) => (
+                StatusCode::UNAUTHORIZED,
+                "YNAB access token is missing, invalid, revoked or expired".to_owned(),
+            ),
+            Some(ApiErrorKind::SubscriptionLapsed) => (
+                StatusCode::FORBIDDEN,
+                "YNAB subscription has lapsed".to_owned(),
+            ),
+            Some(ApiErrorKind::TrialExpired) => {
+                (StatusCode::FORBIDDEN, "YNAB trial has expired".to_owned())
+            }
+            Some(ApiErrorKind::UnauthorizedScope) => (
+                StatusCode::FORBIDDEN,
+                "YNAB access token does not have the required scope".to_owned(),
+            ),
+            Some(ApiErrorKind::DataLimitReached) => {
+                (StatusCode::FORBIDDEN, "YNAB data limit reached".to_owned())
+            }
+            Some(ApiErrorKind::NotFound) | Some(ApiErrorKind::ResourceNotFound) => (
+                StatusCode::NOT_FOUND,
+                "Resource does not exist in YNAB".to_owned(),
+            ),
+            Some(ApiErrorKind::Conflict) => (
+                StatusCode::CONFLICT,
+                "Resource conflicts with an existing one in YNAB".to_owned(),
+            ),
+            Some(ApiErrorKind::TooManyRequests) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "YNAB rate limit reached, try again later".to_owned(),
+            ),
+            Some(ApiErrorKind::ServiceUnavailable) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "YNAB is temporarily unavailable".to_owned(),
+            ),
+            Some(ApiErrorKind::BadRequest)
+            | Some(ApiErrorKind::InternalServerError)
+            | Some(ApiErrorKind::Unknown)
+            | None => (
+                StatusCode::BAD_GATEWAY,
+                "Something went wrong with YNAB".to_owned(),
+            ),
+        },
+    }
+}
+
 pub fn error_chain_fmt(
     e: &impl std::error::Error,
     f: &mut std::fmt::Formatter<'_>,
@@ -1,5 +1,6 @@
 pub mod config;
 pub mod error;
+pub mod registry;
 pub mod routes;
 pub mod services;
 pub mod startup;
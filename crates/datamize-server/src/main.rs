@@ -1,10 +1,10 @@
 use anyhow::Context;
 use datamize_server::{
     config,
+    registry::get_repo_registry,
     startup::Application,
     telemetry::{get_subscriber, init_subscriber},
 };
-use db_postgres::get_connection_pool;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -16,9 +16,10 @@ async fn main() -> anyhow::Result<()> {
     init_subscriber(subscriber);
 
     let configuration = config::Settings::build()?;
-    let db_conn_pool = get_connection_pool(configuration.database.with_db());
-    // TODO: Check https://willcrichton.net/rust-api-type-patterns/registries.html for a different way of build a server with different dependencies
-    let application = Application::build(configuration, db_conn_pool)
+    let repos = get_repo_registry(&configuration)
+        .await
+        .context("failed to connect to the database")?;
+    let application = Application::build(configuration, repos)
         .await
         .context("failed to build application")?;
 
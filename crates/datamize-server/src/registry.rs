@@ -0,0 +1,147 @@
+use anyhow::{bail, Context, Result};
+use datamize_domain::{
+    db::{
+        external::DynEncryptionKeyRepo, ynab::DynYnabOAuthTokenRepo, DbError, DbResult,
+        DynFinResOrderRepo,
+    },
+    ResourceCategory,
+};
+use db_postgres::PostgresRepoRegistry;
+use db_redis::{
+    balance_sheet::resource::RedisFinResOrderRepo,
+    budget_providers::{external::RedisEncryptionKeyRepo, ynab::RedisYnabOAuthTokenRepo},
+    RedisPool,
+};
+use db_sqlite::SqliteRepoRegistry;
+
+pub use datamize_domain::db::{DynRepoRegistry, RepoRegistry};
+
+use crate::config::{DatabaseBackend, Settings, DEFAULT_YNAB_MEMBER};
+
+/// Connects to the configured database backend. The SQLite database is migrated on the way,
+/// since it is meant to run without any other tooling.
+pub async fn get_repo_registry(configuration: &Settings) -> Result<DynRepoRegistry> {
+    let repos: DynRepoRegistry = match configuration.database.backend {
+        DatabaseBackend::Postgres => {
+            let db_conn_pool = db_postgres::get_connection_pool(configuration.database.with_db());
+            PostgresRepoRegistry::new_arced(db_conn_pool)
+        }
+        DatabaseBackend::Sqlite => {
+            let db_conn_pool = db_sqlite::get_connection_pool(configuration.database.sqlite());
+            db_sqlite::migrate(&db_conn_pool)
+                .await
+                .context("failed to migrate the sqlite database")?;
+            SqliteRepoRegistry::new_arced(db_conn_pool)
+        }
+    };
+
+    // Only previous versions backed by Postgres kept some of their state in Redis.
+    if let (DatabaseBackend::Postgres, Some(redis)) =
+        (configuration.database.backend, &configuration.redis)
+    {
+        let redis_conn_pool = db_redis::get_connection_pool(&redis.connection_string())
+            .await
+            .context("failed to get redis connection pool")?;
+        import_redis_state(&redis_conn_pool, repos.as_ref())
+            .await
+            .context("failed to import the state held in redis")?;
+    }
+
+    check_encryption_key(repos.as_ref()).await?;
+
+    Ok(repos)
+}
+
+/// Refuses to start when external accounts were saved but the key encrypting their passwords is not,
+/// e.g. when it was left behind in Redis. A new key would make all the passwords impossible to decrypt.
+pub async fn check_encryption_key(repos: &dyn RepoRegistry) -> Result<()> {
+    let has_key = non_empty(repos.encryption_key_repo().get().await)
+        .context("failed to get the encryption key")?
+        .is_some();
+
+    if !has_key && !repos.external_account_repo().get_all().await?.is_empty() {
+        bail!(
+            "external accounts are saved without the key encrypting their passwords, \
+            configure [redis] to import it from the instance used by previous versions"
+        );
+    }
+
+    Ok(())
+}
+
+/// A missing value reads the same as an empty one, any other error is returned.
+fn non_empty(value: DbResult<Vec<u8>>) -> DbResult<Option<Vec<u8>>> {
+    match value {
+        Ok(value) if !value.is_empty() => Ok(Some(value)),
+        Ok(_) | Err(DbError::NotFound) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Copies what previous versions kept in Redis to the database, without overwriting anything
+/// already there. The delta requests state is left behind, YNAB resources are simply fetched
+/// in full once more.
+#[tracing::instrument(skip_all)]
+pub async fn import_redis_state(
+    redis_conn_pool: &RedisPool,
+    repos: &dyn RepoRegistry,
+) -> Result<()> {
+    let redis_encryption_key_repo: DynEncryptionKeyRepo =
+        RedisEncryptionKeyRepo::new_arced(redis_conn_pool.clone());
+    let encryption_key_repo = repos.encryption_key_repo();
+    if non_empty(encryption_key_repo.get().await)
+        .context("failed to get the encryption key")?
+        .is_none()
+    {
+        if let Some(encryption_key) = non_empty(redis_encryption_key_repo.get().await)
+            .context("failed to get the encryption key from redis")?
+        {
+            encryption_key_repo.set(&encryption_key).await?;
+            tracing::info!("imported the encryption key from redis");
+        }
+    }
+
+    let redis_oauth_token_repo: DynYnabOAuthTokenRepo =
+        RedisYnabOAuthTokenRepo::new_arced(redis_conn_pool.clone());
+    let oauth_token_repo = repos.ynab_oauth_token_repo();
+    if non_empty(oauth_token_repo.get(DEFAULT_YNAB_MEMBER).await)
+        .context("failed to get the YNAB tokens")?
+        .is_none()
+    {
+        if let Some(tokens) = non_empty(redis_oauth_token_repo.get(DEFAULT_YNAB_MEMBER).await)
+            .context("failed to get the YNAB tokens from redis")?
+        {
+            oauth_token_repo.set(DEFAULT_YNAB_MEMBER, &tokens).await?;
+            tracing::info!("imported the YNAB tokens from redis");
+        }
+    }
+
+    let redis_fin_res_order_repo: DynFinResOrderRepo =
+        RedisFinResOrderRepo::new_arced(redis_conn_pool.clone());
+    let fin_res_order_repo = repos.fin_res_order_repo();
+    for year_data in repos.year_repo().get_years_data().await? {
+        for category in [ResourceCategory::Asset, ResourceCategory::Liability] {
+            if !fin_res_order_repo
+                .get_order(year_data.year, &category)
+                .await?
+                .is_empty()
+            {
+                continue;
+            }
+
+            let order = redis_fin_res_order_repo
+                .get_order(year_data.year, &category)
+                .await?;
+            if !order.is_empty() {
+                fin_res_order_repo
+                    .set_order(year_data.year, &category, &order)
+                    .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
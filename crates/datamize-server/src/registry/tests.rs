@@ -0,0 +1,92 @@
+use datamize_domain::{db::DbError, ResourceCategory, Uuid, WebScrapingAccount, Year};
+use db_redis::{
+    balance_sheet::resource::RedisFinResOrderRepo,
+    budget_providers::external::RedisEncryptionKeyRepo, get_test_pool,
+};
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+
+use super::*;
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn copies_the_state_held_in_redis(pool: SqlitePool) {
+    let repos = SqliteRepoRegistry::new_arced(pool);
+    repos.year_repo().add(&Year::new(2024)).await.unwrap();
+    let redis_conn_pool = get_test_pool().await;
+    let order = vec![Uuid::new_v4(), Uuid::new_v4()];
+    let redis_encryption_key_repo: DynEncryptionKeyRepo =
+        RedisEncryptionKeyRepo::new_arced(redis_conn_pool.clone());
+    redis_encryption_key_repo.set(b"key").await.unwrap();
+    let redis_fin_res_order_repo: DynFinResOrderRepo =
+        RedisFinResOrderRepo::new_arced(redis_conn_pool.clone());
+    redis_fin_res_order_repo
+        .set_order(2024, &ResourceCategory::Asset, &order)
+        .await
+        .unwrap();
+
+    import_redis_state(&redis_conn_pool, repos.as_ref())
+        .await
+        .unwrap();
+
+    assert_eq!(repos.encryption_key_repo().get().await.unwrap(), b"key");
+    assert_eq!(
+        repos
+            .fin_res_order_repo()
+            .get_order(2024, &ResourceCategory::Asset)
+            .await
+            .unwrap(),
+        order
+    );
+    assert!(repos
+        .fin_res_order_repo()
+        .get_order(2024, &ResourceCategory::Liability)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(matches!(
+        repos.ynab_oauth_token_repo().get(DEFAULT_YNAB_MEMBER).await,
+        Err(DbError::NotFound)
+    ));
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn keeps_what_the_database_already_holds(pool: SqlitePool) {
+    let repos = SqliteRepoRegistry::new_arced(pool);
+    repos.encryption_key_repo().set(b"current").await.unwrap();
+    let redis_conn_pool = get_test_pool().await;
+    let redis_encryption_key_repo: DynEncryptionKeyRepo =
+        RedisEncryptionKeyRepo::new_arced(redis_conn_pool.clone());
+    redis_encryption_key_repo.set(b"previous").await.unwrap();
+
+    import_redis_state(&redis_conn_pool, repos.as_ref())
+        .await
+        .unwrap();
+
+    assert_eq!(repos.encryption_key_repo().get().await.unwrap(), b"current");
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn refuses_to_start_when_accounts_are_saved_without_encryption_key(pool: SqlitePool) {
+    let repos = SqliteRepoRegistry::new_arced(pool);
+    repos
+        .external_account_repo()
+        .add(&WebScrapingAccount {
+            id: Uuid::new_v4(),
+            name: "Savings".to_owned(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert!(check_encryption_key(repos.as_ref()).await.is_err());
+
+    repos.encryption_key_repo().set(b"key").await.unwrap();
+    assert!(check_encryption_key(repos.as_ref()).await.is_ok());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn starts_without_encryption_key_when_no_account_is_saved(pool: SqlitePool) {
+    let repos = SqliteRepoRegistry::new_arced(pool);
+
+    assert!(check_encryption_key(repos.as_ref()).await.is_ok());
+}
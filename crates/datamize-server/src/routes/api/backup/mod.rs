@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Router,
+};
+use datamize_domain::{Backup, BackupParams};
+
+use crate::{
+    error::{AppJson, DatamizeResult, HttpJsonDatamizeResult},
+    services::backup::DynBackupService,
+};
+
+pub fn get_backup_routes<S>(backup_service: DynBackupService) -> Router<S> {
+    Router::new()
+        .route("/", get(backup))
+        .route("/restore", post(restore))
+        .with_state(backup_service)
+}
+
+/// Returns all the data owned by Datamize, to be restored later with `/backup/restore`.
+/// /backup?include_encryption_key=true also returns the key the external accounts' passwords are encrypted with.
+#[tracing::instrument(skip_all)]
+pub async fn backup(
+    State(backup_service): State<DynBackupService>,
+    params: Query<BackupParams>,
+) -> HttpJsonDatamizeResult<Backup> {
+    Ok(AppJson(
+        backup_service
+            .backup(params.include_encryption_key.unwrap_or_default())
+            .await?,
+    ))
+}
+
+/// Restores a backup in a database holding no data yet.
+#[tracing::instrument(skip_all)]
+pub async fn restore(
+    State(backup_service): State<DynBackupService>,
+    AppJson(body): AppJson<Backup>,
+) -> DatamizeResult<StatusCode> {
+    backup_service.restore(body).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,115 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use datamize_domain::{
+    db::{DynRepoRegistry, RepoRegistry},
+    Backup, BudgeterConfig, Year, BACKUP_VERSION,
+};
+use db_memory::MemoryRepoRegistry;
+use db_sqlite::SqliteRepoRegistry;
+use fake::{Fake, Faker};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+
+use super::get_backup_routes;
+use crate::services::backup::BackupService;
+
+fn app(repos: DynRepoRegistry) -> Router {
+    get_backup_routes(BackupService::new_arced(repos))
+}
+
+async fn restore(repos: DynRepoRegistry, body: String) -> StatusCode {
+    app(repos)
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/restore")
+                .header("Content-Type", "application/json")
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+        .status()
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn backup_can_be_restored_through_the_api(pool: SqlitePool) {
+    let repos = SqliteRepoRegistry::new_arced(pool);
+    repos.year_repo().add(&Year::new(2023)).await.unwrap();
+    let budgeter: BudgeterConfig = Faker.fake();
+    repos
+        .budgeter_config_repo()
+        .update(&budgeter)
+        .await
+        .unwrap();
+    repos.encryption_key_repo().set(b"key").await.unwrap();
+
+    let response = app(repos)
+        .oneshot(
+            Request::builder()
+                .uri("/?include_encryption_key=true")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let backup: Backup = serde_json::from_slice(&body).unwrap();
+    assert_eq!(backup.version, BACKUP_VERSION);
+    assert_eq!(backup.budgeters, vec![budgeter.clone()]);
+
+    let memory_repos = MemoryRepoRegistry::new_arced();
+    let status = restore(
+        memory_repos.clone(),
+        String::from_utf8(body.to_vec()).unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(
+        memory_repos.budgeter_config_repo().get_all().await.unwrap(),
+        vec![budgeter]
+    );
+    assert!(memory_repos
+        .year_repo()
+        .get_year_data_by_number(2023)
+        .await
+        .is_ok());
+    assert_eq!(
+        memory_repos.encryption_key_repo().get().await.unwrap(),
+        b"key"
+    );
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn restore_conflicts_with_existing_data(pool: SqlitePool) {
+    let repos = SqliteRepoRegistry::new_arced(pool);
+    repos.year_repo().add(&Year::new(2023)).await.unwrap();
+    let backup = Backup::new();
+
+    let status = restore(repos, serde_json::to_string(&backup).unwrap()).await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn restore_rejects_a_newer_version() {
+    let backup = Backup {
+        version: BACKUP_VERSION + 1,
+        ..Backup::new()
+    };
+
+    let status = restore(
+        MemoryRepoRegistry::new_arced(),
+        serde_json::to_string(&backup).unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,46 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::IntoResponse,
+};
+use datamize_domain::{ExportFormat, ExportParams};
+
+use crate::{
+    error::DatamizeResult,
+    services::balance_sheet::{write_csv, write_xlsx, DynYearExportService},
+};
+
+/// Returns the balance sheet of the year, with its net totals and saving rates, as a spreadsheet.
+/// /balance_sheet/years/2024/export?format=xlsx
+/// Accepts `csv` or `xlsx`, defaults to `csv`.
+#[tracing::instrument(skip_all)]
+pub async fn export_balance_sheet_year(
+    Path(year): Path<i32>,
+    State(year_export_service): State<DynYearExportService>,
+    params: Query<ExportParams>,
+) -> DatamizeResult<impl IntoResponse> {
+    let sheets = year_export_service.get_year_sheets(year).await?;
+
+    let (content, content_type, extension) = match params.format.unwrap_or_default() {
+        ExportFormat::Csv => (write_csv(&sheets)?, "text/csv; charset=utf-8", "csv"),
+        ExportFormat::Xlsx => (
+            write_xlsx(&sheets)?,
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            "xlsx",
+        ),
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"balance-sheet-{}.{}\"",
+                    year, extension
+                ),
+            ),
+        ],
+        content,
+    ))
+}
@@ -0,0 +1,29 @@
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+};
+use datamize_domain::{ImportParams, ImportPlan};
+
+use crate::{
+    error::{AppJson, HttpJsonDatamizeResult},
+    services::balance_sheet::{read_csv, DynBalanceSheetImportService},
+};
+
+/// Imports historical balances from a CSV with the name and type of each resource followed by
+/// one column per month, i.e. `name,type,2015-01,2015-02`.
+/// /balance_sheet/import?dry_run=true
+/// Returns the changes made, or the ones that would be made when it is a dry run.
+#[tracing::instrument(skip_all)]
+pub async fn import_balance_sheet(
+    State(import_service): State<DynBalanceSheetImportService>,
+    params: Query<ImportParams>,
+    body: Bytes,
+) -> HttpJsonDatamizeResult<ImportPlan> {
+    let resources = read_csv(&body)?;
+
+    Ok(AppJson(
+        import_service
+            .import(resources, params.dry_run.unwrap_or_default())
+            .await?,
+    ))
+}
@@ -1,3 +1,5 @@
+mod export;
+mod import;
 mod month;
 mod months;
 mod refresh_resources;
@@ -14,16 +16,8 @@ use axum::{
     routing::{get, post},
     Router,
 };
-use db_postgres::{
-    balance_sheet::{
-        PostgresFinResRepo, PostgresMonthRepo, PostgresSavingRateRepo, PostgresYearRepo,
-    },
-    budget_providers::{external::PostgresExternalAccountRepo, ynab::PostgresYnabTransactionRepo},
-};
-use db_redis::{
-    balance_sheet::resource::RedisFinResOrderRepo,
-    budget_providers::{external::RedisEncryptionKeyRepo, ynab::RedisYnabTransactionMetaRepo},
-};
+use export::*;
+use import::*;
 use month::*;
 use months::*;
 use refresh_resources::*;
@@ -37,9 +31,10 @@ use years::*;
 use crate::{
     services::{
         balance_sheet::{
-            DynFinResService, DynMonthService, DynRefreshFinResService, DynSavingRateService,
-            DynYearService, FinResService, MonthService, RefreshFinResService, SavingRateService,
-            YearService,
+            BalanceSheetImportService, DynBalanceSheetImportService, DynFinResService,
+            DynMonthService, DynRefreshFinResService, DynSavingRateService, DynYearExportService,
+            DynYearService, FinResService, MonthService, RefreshFinResService, RolloverService,
+            SavingRateService, YearExportService, YearService,
         },
         budget_providers::{ExternalAccountService, TransactionService},
     },
@@ -49,10 +44,11 @@ use crate::{
 pub fn get_balance_sheets_routes<S: Clone + Send + Sync + 'static>(
     app_state: &AppState,
 ) -> Router<S> {
-    let year_repo = PostgresYearRepo::new_arced(app_state.db_conn_pool.clone());
-    let month_repo = PostgresMonthRepo::new_arced(app_state.db_conn_pool.clone());
-    let fin_res_repo = PostgresFinResRepo::new_arced(app_state.db_conn_pool.clone());
-    let fin_res_order_repo = RedisFinResOrderRepo::new_arced(app_state.redis_conn_pool.clone());
+    let budget_id = app_state.default_budget_id();
+    let year_repo = app_state.repos.year_repo();
+    let month_repo = app_state.repos.month_repo();
+    let fin_res_repo = app_state.repos.fin_res_repo();
+    let fin_res_order_repo = app_state.repos.fin_res_order_repo();
     let year_service = YearService::new_arced(year_repo.clone(), month_repo.clone());
     let month_service = MonthService::new_arced(month_repo.clone());
     let fin_res_service = FinResService::new_arced(
@@ -61,28 +57,49 @@ pub fn get_balance_sheets_routes<S: Clone + Send + Sync + 'static>(
         year_repo.clone(),
         fin_res_order_repo,
     );
-    let saving_rate_repo = PostgresSavingRateRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_transaction_repo =
-        PostgresYnabTransactionRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_transaction_meta_repo =
-        RedisYnabTransactionMetaRepo::new_arced(app_state.redis_conn_pool.clone());
+    let saving_rate_repo = app_state.repos.saving_rate_repo();
+    let ynab_transaction_repo = app_state.repos.ynab_transaction_repo(budget_id);
+    let ynab_transaction_meta_repo = app_state.repos.ynab_transaction_meta_repo(budget_id);
     let transaction_service = TransactionService::new_arced(
         ynab_transaction_repo,
         ynab_transaction_meta_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
     let saving_rate_service = SavingRateService::new_arced(saving_rate_repo, transaction_service);
-    let external_account_repo =
-        PostgresExternalAccountRepo::new_arced(app_state.db_conn_pool.clone());
-    let encryption_key_repo = RedisEncryptionKeyRepo::new_arced(app_state.redis_conn_pool.clone());
-    let external_acount_service =
-        ExternalAccountService::new_arced(external_account_repo, encryption_key_repo);
+    let year_export_service = YearExportService::new_arced(
+        year_service.clone(),
+        fin_res_service.clone(),
+        saving_rate_service.clone(),
+    );
+    let import_service = BalanceSheetImportService::new_arced(
+        fin_res_repo.clone(),
+        month_repo.clone(),
+        year_repo.clone(),
+    );
+    let external_account_repo = app_state.repos.external_account_repo();
+    let encryption_key_repo = app_state.repos.encryption_key_repo();
+    let external_account_refresh_repo = app_state.repos.external_account_refresh_repo();
+    let external_account_balance_repo = app_state.repos.external_account_balance_repo();
+    let external_acount_service = ExternalAccountService::new_arced(
+        external_account_repo,
+        encryption_key_repo,
+        external_account_refresh_repo,
+        external_account_balance_repo,
+        app_state.pending_challenges.clone(),
+        app_state.scraper_registry.clone(),
+        app_state.webdriver_location.clone(),
+    );
+    let rollover_service =
+        RolloverService::new_arced(fin_res_repo.clone(), month_repo.clone(), year_repo.clone());
     let refresh_fin_res_service = RefreshFinResService::new_arced(
         fin_res_repo,
         month_repo,
         year_repo,
+        rollover_service,
         external_acount_service,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
 
     Router::new()
@@ -91,6 +108,8 @@ pub fn get_balance_sheets_routes<S: Clone + Send + Sync + 'static>(
         .merge(get_fin_res_routes(fin_res_service))
         .merge(get_saving_rate_routes(saving_rate_service))
         .merge(get_refresh_fin_res_routes(refresh_fin_res_service))
+        .merge(get_year_export_routes(year_export_service))
+        .merge(get_import_routes(import_service))
 }
 
 fn get_year_routes<S>(year_service: DynYearService) -> Router<S> {
@@ -139,6 +158,10 @@ fn get_fin_res_routes<S>(fin_res_service: DynFinResService) -> Router<S> {
 fn get_saving_rate_routes<S>(saving_rate_service: DynSavingRateService) -> Router<S> {
     Router::new()
         .route("/saving_rates", post(create_balance_sheet_saving_rate))
+        .route(
+            "/saving_rates/history",
+            get(balance_sheet_saving_rates_history),
+        )
         .route(
             "/saving_rates/:saving_rate_id",
             get(balance_sheet_saving_rate)
@@ -149,8 +172,24 @@ fn get_saving_rate_routes<S>(saving_rate_service: DynSavingRateService) -> Route
         .with_state(saving_rate_service)
 }
 
+fn get_year_export_routes<S>(year_export_service: DynYearExportService) -> Router<S> {
+    Router::new()
+        .route("/years/:year/export", get(export_balance_sheet_year))
+        .with_state(year_export_service)
+}
+
+fn get_import_routes<S>(import_service: DynBalanceSheetImportService) -> Router<S> {
+    Router::new()
+        .route("/import", post(import_balance_sheet))
+        .with_state(import_service)
+}
+
 fn get_refresh_fin_res_routes<S>(refresh_fin_res_service: DynRefreshFinResService) -> Router<S> {
     Router::new()
         .route("/resources/refresh", post(refresh_balance_sheet_resources))
+        .route(
+            "/resources/backfill/:year/:month",
+            post(backfill_balance_sheet_resources),
+        )
         .with_state(refresh_fin_res_service)
 }
@@ -1,6 +1,6 @@
-use axum::extract::State;
+use axum::extract::{Path, State};
 use axum_extra::extract::OptionalQuery;
-use datamize_domain::{ResourcesToRefresh, Uuid};
+use datamize_domain::{MonthNum, ResourcesToRefresh, Uuid};
 
 use crate::{
     error::{AppJson, HttpJsonDatamizeResult},
@@ -21,3 +21,20 @@ pub async fn refresh_balance_sheet_resources(
 ) -> HttpJsonDatamizeResult<Vec<Uuid>> {
     Ok(AppJson(fin_res_service.refresh_fin_res(params).await?))
 }
+
+/// Endpoint to set the balances of a past month from the balances observed during that month
+/// for the external accounts. The same optionnal query parameter selects the resources.
+/// Resources with an external account without any balance observed in the month are left as is.
+/// Will return an array of ids for Financial Resources updated.
+#[tracing::instrument(skip_all)]
+pub async fn backfill_balance_sheet_resources(
+    Path((year, month)): Path<(i32, MonthNum)>,
+    State(fin_res_service): State<DynRefreshFinResService>,
+    OptionalQuery(params): OptionalQuery<ResourcesToRefresh>,
+) -> HttpJsonDatamizeResult<Vec<Uuid>> {
+    Ok(AppJson(
+        fin_res_service
+            .backfill_fin_res(year, month, params)
+            .await?,
+    ))
+}
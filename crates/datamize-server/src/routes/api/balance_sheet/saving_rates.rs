@@ -1,9 +1,11 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
-use datamize_domain::{SaveSavingRate, SavingRate};
+use datamize_domain::{
+    SaveSavingRate, SavingRate, SavingRateHistoryEntry, SavingRateHistoryParams,
+};
 
 use crate::{
     error::{AppError, AppJson, HttpJsonDatamizeResult},
@@ -29,3 +31,14 @@ pub async fn create_balance_sheet_saving_rate(
         AppJson(saving_rate_service.create_saving_rate(body).await?),
     ))
 }
+
+/// Endpoint to compare a saving rate across all the years it exists in, ordered by year.
+#[tracing::instrument(name = "Get the history of a saving rate", skip_all)]
+pub async fn balance_sheet_saving_rates_history(
+    Query(params): Query<SavingRateHistoryParams>,
+    State(saving_rate_service): State<DynSavingRateService>,
+) -> HttpJsonDatamizeResult<Vec<SavingRateHistoryEntry>> {
+    Ok(AppJson(
+        saving_rate_service.get_history(&params.name).await?,
+    ))
+}
@@ -0,0 +1,113 @@
+use axum::{
+    body::Body,
+    http::{header, Request, Response, StatusCode},
+};
+use datamize_domain::{
+    AssetType, BaseFinancialResource, FinancialResourceType, FinancialResourceYearly, MonthNum,
+    SavingRate, YearlyBalances,
+};
+use fake::{Fake, Faker};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+use ynab::Milliunits;
+
+use crate::routes::api::balance_sheet::tests::export::testutils::TestContext;
+
+async fn get_export(context: TestContext, uri: &str) -> Response<Body> {
+    context
+        .into_app()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+}
+
+async fn fill_year(context: &TestContext) -> SavingRate {
+    context
+        .insert_year(2023, &[MonthNum::January, MonthNum::February])
+        .await;
+    let mut resource = FinancialResourceYearly {
+        base: BaseFinancialResource {
+            name: "Bank".to_owned(),
+            resource_type: FinancialResourceType::Asset(AssetType::Cash),
+            ..Faker.fake()
+        },
+        ..Default::default()
+    };
+    resource.insert_balance(2023, MonthNum::January, Milliunits::new(1234560));
+    context.set_resources(&[resource]).await;
+    let saving_rate = SavingRate {
+        year: 2023,
+        ..Faker.fake()
+    };
+    context
+        .set_saving_rates(std::slice::from_ref(&saving_rate))
+        .await;
+
+    saving_rate
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_404_when_year_does_not_exist(pool: SqlitePool) {
+    let context = TestContext::setup(pool);
+
+    let response = get_export(context, "/years/2023/export").await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_csv_by_default(pool: SqlitePool) {
+    let context = TestContext::setup(pool);
+    let saving_rate = fill_year(&context).await;
+
+    let response = get_export(context, "/years/2023/export").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/csv; charset=utf-8"
+    );
+    assert_eq!(
+        response.headers()[header::CONTENT_DISPOSITION],
+        "attachment; filename=\"balance-sheet-2023.csv\""
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    let lines: Vec<_> = body.lines().collect();
+    assert_eq!(lines[0], "Balance Sheet 2023");
+    assert!(lines[1].starts_with(",January,February,March"));
+    assert_eq!(lines[2], "Assets - Cash");
+    assert!(lines[3].starts_with("Bank,1234.560,,"));
+    assert!(lines[4].starts_with("Total Assets - Cash,1234.560,,"));
+    assert!(lines.contains(&"Saving Rates 2023"));
+    assert!(lines.iter().any(|l| l.starts_with(&saving_rate.name)));
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_xlsx_when_asked(pool: SqlitePool) {
+    let context = TestContext::setup(pool);
+    fill_year(&context).await;
+
+    let response = get_export(context, "/years/2023/export?format=xlsx").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    // XLSX files are zip archives.
+    assert_eq!(&body[..2], b"PK");
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_400_for_unknown_format(pool: SqlitePool) {
+    let context = TestContext::setup(pool);
+    fill_year(&context).await;
+
+    let response = get_export(context, "/years/2023/export?format=pdf").await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
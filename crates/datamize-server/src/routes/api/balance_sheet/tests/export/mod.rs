@@ -0,0 +1,2 @@
+mod get;
+pub(crate) mod testutils;
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use axum::Router;
+use datamize_domain::{
+    db::DynRepoRegistry, FinancialResourceYearly, Month, MonthNum, SavingRate, Year,
+};
+use db_sqlite::SqliteRepoRegistry;
+use fake::{Fake, Faker};
+use sqlx::SqlitePool;
+use ynab::MockTransactionRequestsImpl;
+
+use crate::{
+    routes::api::balance_sheet::get_year_export_routes,
+    services::{
+        balance_sheet::{FinResService, SavingRateService, YearExportService, YearService},
+        budget_providers::TransactionService,
+    },
+};
+
+pub(crate) struct TestContext {
+    repos: DynRepoRegistry,
+    app: Router,
+}
+
+impl TestContext {
+    pub(crate) fn setup(pool: SqlitePool) -> Self {
+        let repos: DynRepoRegistry = SqliteRepoRegistry::new_arced(pool);
+
+        let mut ynab_client = Arc::new(MockTransactionRequestsImpl::new());
+        let ynab_client_mock = Arc::make_mut(&mut ynab_client);
+        ynab_client_mock
+            .expect_get_transactions_delta()
+            .returning(|_, _| Ok(Faker.fake()));
+
+        let transaction_service = TransactionService::new_arced(
+            repos.ynab_transaction_repo(ynab::LAST_USED_BUDGET_ID),
+            repos.ynab_transaction_meta_repo(ynab::LAST_USED_BUDGET_ID),
+            ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
+        );
+        let year_export_service = YearExportService::new_arced(
+            YearService::new_arced(repos.year_repo(), repos.month_repo()),
+            FinResService::new_arced(
+                repos.fin_res_repo(),
+                repos.month_repo(),
+                repos.year_repo(),
+                repos.fin_res_order_repo(),
+            ),
+            SavingRateService::new_arced(repos.saving_rate_repo(), transaction_service),
+        );
+        let app = get_year_export_routes(year_export_service);
+
+        Self { repos, app }
+    }
+
+    pub(crate) fn into_app(self) -> Router {
+        self.app
+    }
+
+    pub(crate) async fn insert_year(&self, year: i32, months: &[MonthNum]) {
+        self.repos.year_repo().add(&Year::new(year)).await.unwrap();
+        for month in months {
+            self.repos
+                .month_repo()
+                .add(&Month::new(*month, year), year)
+                .await
+                .unwrap();
+        }
+    }
+
+    pub(crate) async fn set_resources(&self, resources: &[FinancialResourceYearly]) {
+        for resource in resources {
+            self.repos.fin_res_repo().update(resource).await.unwrap();
+        }
+    }
+
+    pub(crate) async fn set_saving_rates(&self, saving_rates: &[SavingRate]) {
+        for saving_rate in saving_rates {
+            self.repos
+                .saving_rate_repo()
+                .update(saving_rate)
+                .await
+                .unwrap();
+        }
+    }
+}
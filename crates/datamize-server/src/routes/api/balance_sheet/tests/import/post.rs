@@ -0,0 +1,115 @@
+use axum::{
+    body::Body,
+    http::{Request, Response, StatusCode},
+    Router,
+};
+use datamize_domain::{db::DynRepoRegistry, ImportPlan, MonthNum, YearlyBalances};
+use db_sqlite::SqliteRepoRegistry;
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+use ynab::Milliunits;
+
+use crate::{
+    routes::api::balance_sheet::get_import_routes,
+    services::balance_sheet::BalanceSheetImportService,
+};
+
+const HISTORY: &str = "\
+name,type,2015-12,2016-01
+Bank,asset_cash,10,30.5
+Car Loan,liability_cash,,5
+";
+
+fn app(repos: &DynRepoRegistry) -> Router {
+    get_import_routes(BalanceSheetImportService::new_arced(
+        repos.fin_res_repo(),
+        repos.month_repo(),
+        repos.year_repo(),
+    ))
+}
+
+async fn post_import(repos: &DynRepoRegistry, uri: &str, body: &str) -> Response<Body> {
+    app(repos)
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .header("Content-Type", "text/csv")
+                .body(Body::from(body.to_owned()))
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+}
+
+async fn into_plan(response: Response<Body>) -> ImportPlan {
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn dry_run_returns_the_plan_without_saving(pool: SqlitePool) {
+    let repos: DynRepoRegistry = SqliteRepoRegistry::new_arced(pool);
+
+    let response = post_import(&repos, "/import?dry_run=true", HISTORY).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let plan = into_plan(response).await;
+    assert!(plan.dry_run);
+    assert_eq!(plan.years_to_create, vec![2015, 2016]);
+    assert_eq!(plan.resources_to_create, vec!["Bank", "Car Loan"]);
+    assert_eq!(plan.balances.len(), 3);
+    assert!(repos
+        .fin_res_repo()
+        .get_from_all_years()
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn import_saves_the_balances(pool: SqlitePool) {
+    let repos: DynRepoRegistry = SqliteRepoRegistry::new_arced(pool);
+
+    let response = post_import(&repos, "/import", HISTORY).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!into_plan(response).await.dry_run);
+    let bank = repos.fin_res_repo().get_by_name("Bank").await.unwrap();
+    assert_eq!(
+        bank.get_balance(2016, MonthNum::January),
+        Some(Milliunits::new(30500))
+    );
+    let january = repos
+        .month_repo()
+        .get(MonthNum::January, 2016)
+        .await
+        .unwrap();
+    assert_eq!(january.net_totals.assets.total, Milliunits::new(25500));
+
+    // Importing the same file again changes nothing.
+    let plan = into_plan(post_import(&repos, "/import", HISTORY).await).await;
+    assert_eq!(
+        plan,
+        ImportPlan {
+            dry_run: false,
+            ..Default::default()
+        }
+    );
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn invalid_file_is_a_bad_request(pool: SqlitePool) {
+    let repos: DynRepoRegistry = SqliteRepoRegistry::new_arced(pool);
+
+    let response = post_import(
+        &repos,
+        "/import",
+        "name,type,2016-01\nBank,asset_cash,ten\n",
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -1,3 +1,5 @@
+mod export;
+mod import;
 mod months;
 mod refresh_resources;
 mod resources;
@@ -12,6 +12,7 @@ use sqlx::SqlitePool;
 use tower::ServiceExt;
 
 use crate::routes::api::balance_sheet::tests::months::testutils::TestContext;
+use ynab::Milliunits;
 
 #[derive(Debug, Deserialize, Serialize, Clone, fake::Dummy)]
 struct CreateBody {
@@ -158,9 +159,9 @@ async fn update_net_totals_if_prev_month_exists(pool: SqlitePool) {
         year,
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_month.net_assets().total,
-                percent_var: if prev_month.net_assets().total == 0 {
+                percent_var: if prev_month.net_assets().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -168,9 +169,9 @@ async fn update_net_totals_if_prev_month_exists(pool: SqlitePool) {
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_month.net_portfolio().total,
-                percent_var: if prev_month.net_portfolio().total == 0 {
+                percent_var: if prev_month.net_portfolio().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -216,9 +217,9 @@ async fn update_net_totals_if_prev_month_exists_in_prev_year(pool: SqlitePool) {
         year,
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_month.net_assets().total,
-                percent_var: if prev_month.net_assets().total == 0 {
+                percent_var: if prev_month.net_assets().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -226,9 +227,9 @@ async fn update_net_totals_if_prev_month_exists_in_prev_year(pool: SqlitePool) {
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_month.net_portfolio().total,
-                percent_var: if prev_month.net_portfolio().total == 0 {
+                percent_var: if prev_month.net_portfolio().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -0,0 +1,51 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use datamize_domain::{MonthNum, Uuid};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+
+use crate::routes::api::balance_sheet::tests::refresh_resources::testutils::TestContext;
+
+async fn backfill(context: &TestContext, uri: &str) -> (StatusCode, Vec<u8>) {
+    let response = context
+        .app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    (status, body.to_vec())
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn creates_the_month_to_backfill(pool: SqlitePool) {
+    // YNAB is never called, the balances come from the history of the external accounts.
+    let context = TestContext::setup(pool, 0, vec![]).await;
+
+    let (status, body) = backfill(&context, "/resources/backfill/2023/3").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let body: Vec<Uuid> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body, vec![]);
+    assert!(context.get_month(MonthNum::March, 2023).await.is_ok());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_400_for_an_invalid_month(pool: SqlitePool) {
+    let context = TestContext::setup(pool, 0, vec![]).await;
+
+    let (status, _) = backfill(&context, "/resources/backfill/2023/13").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
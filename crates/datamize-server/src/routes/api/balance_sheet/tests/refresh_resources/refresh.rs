@@ -11,7 +11,7 @@ use pretty_assertions::{assert_eq, assert_ne};
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 use tower::ServiceExt;
-use ynab::Account;
+use ynab::{Account, Milliunits};
 
 use crate::routes::api::balance_sheet::tests::refresh_resources::testutils::{
     correctly_stub_resources, TestContext,
@@ -31,11 +31,8 @@ async fn check_refresh(
     expected_status: StatusCode,
     expected_resp: Option<Vec<Uuid>>,
 ) {
-    let context = match create_year {
-        true => TestContext::setup(pool, 1, ynab_accounts),
-        false => TestContext::setup(pool, 0, ynab_accounts),
-    }
-    .await;
+    // YNAB is called even when the year is missing, the year being created by the refresh.
+    let context = TestContext::setup(pool, 1, ynab_accounts).await;
 
     let date = Local::now().date_naive();
     let year = date.year();
@@ -95,21 +92,21 @@ async fn check_refresh(
 
             let saved_month = saved_month.unwrap();
             // Since net_assets are computed from all resources' type
-            assert_ne!(saved_month.net_assets().total, 0);
+            assert_ne!(saved_month.net_assets().total, Milliunits::ZERO);
         }
     }
 }
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
-async fn returns_404_when_no_year(pool: SqlitePool) {
+async fn returns_200_and_creates_the_year_when_missing(pool: SqlitePool) {
     check_refresh(
         pool,
         false,
         None,
         fake::vec![Account; 3..6],
         fake::vec![FinancialResourceYearly; 3..6],
-        StatusCode::NOT_FOUND,
-        None,
+        StatusCode::OK,
+        Some(vec![]),
     )
     .await;
 }
@@ -190,7 +187,7 @@ async fn only_update_when_different_balance(pool: SqlitePool) {
     let current_date = Local::now().date_naive();
     let month = current_date.month().try_into().unwrap();
     let year = current_date.year();
-    resource.insert_balance(year, month, ynab_account.balance.abs());
+    resource.insert_balance(year, month, ynab_account.balance.abs().into());
 
     check_refresh(
         pool,
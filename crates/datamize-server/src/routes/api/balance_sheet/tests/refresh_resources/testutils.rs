@@ -8,14 +8,20 @@ use datamize_domain::{
 use db_redis::{budget_providers::external::RedisEncryptionKeyRepo, get_test_pool};
 use db_sqlite::{
     balance_sheet::{SqliteFinResRepo, SqliteMonthRepo, SqliteYearRepo},
-    budget_providers::external::SqliteExternalAccountRepo,
+    budget_providers::external::{
+        SqliteExternalAccountBalanceRepo, SqliteExternalAccountRefreshRepo,
+        SqliteExternalAccountRepo,
+    },
 };
 use sqlx::SqlitePool;
 use ynab::{Account, MockAccountRequestsImpl};
 
 use crate::{
     routes::api::balance_sheet::get_refresh_fin_res_routes,
-    services::{balance_sheet::RefreshFinResService, budget_providers::ExternalAccountService},
+    services::{
+        balance_sheet::{RefreshFinResService, RolloverService},
+        budget_providers::{ExternalAccountService, PendingChallenges, ScraperRegistry},
+    },
 };
 
 pub(crate) struct TestContext {
@@ -38,21 +44,30 @@ impl TestContext {
         let external_account_repo = SqliteExternalAccountRepo::new_arced(pool.clone());
         let encryption_key_repo = RedisEncryptionKeyRepo::new_arced(redis_conn_pool);
         encryption_key_repo.set(&fake::vec![u8; 6]).await.unwrap();
-        let external_account_service =
-            ExternalAccountService::new_arced(external_account_repo.clone(), encryption_key_repo);
+        let external_account_service = ExternalAccountService::new_arced(
+            external_account_repo.clone(),
+            encryption_key_repo,
+            SqliteExternalAccountRefreshRepo::new_arced(pool.clone()),
+            SqliteExternalAccountBalanceRepo::new_arced(pool.clone()),
+            PendingChallenges::default(),
+            Arc::new(ScraperRegistry::default()),
+            "http://localhost:4444/".to_string(),
+        );
         let mut ynab_client = Arc::new(MockAccountRequestsImpl::new());
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_accounts()
             .times(ynab_calls)
-            .returning(move || Ok(ynab_accounts.clone()));
+            .returning(move |_| Ok(ynab_accounts.clone()));
 
         let fin_res_service = RefreshFinResService::new_arced(
             fin_res_repo.clone(),
             month_repo.clone(),
             year_repo.clone(),
+            RolloverService::new_arced(fin_res_repo.clone(), month_repo.clone(), year_repo.clone()),
             external_account_service,
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
         );
         let app = get_refresh_fin_res_routes(fin_res_service);
         Self {
@@ -18,13 +18,14 @@ use sqlx::SqlitePool;
 use tower::ServiceExt;
 
 use crate::routes::api::balance_sheet::tests::resources::testutils::TestContext;
+use ynab::Milliunits;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 struct CreateBody {
     pub name: String,
     #[serde(with = "datamize_domain::string")]
     pub resource_type: FinancialResourceType,
-    pub balances: BTreeMap<i32, BTreeMap<MonthNum, Option<i64>>>,
+    pub balances: BTreeMap<i32, BTreeMap<MonthNum, Option<Milliunits>>>,
     pub ynab_account_ids: Option<Vec<Uuid>>,
     pub external_account_ids: Option<Vec<Uuid>>,
 }
@@ -100,7 +101,7 @@ async fn check_create(
 
                 let saved_month = saved_month.unwrap();
                 // Since net_assets are computed from all resources' type
-                assert_ne!(saved_month.net_assets().total, 0);
+                assert_ne!(saved_month.net_assets().total, Milliunits::ZERO);
             }
         }
 
@@ -109,7 +110,7 @@ async fn check_create(
         assert!(saved_years.is_ok());
         let saved_years = saved_years.unwrap();
         for saved_year in saved_years {
-            assert_ne!(saved_year.net_assets().total, 0);
+            assert_ne!(saved_year.net_assets().total, Milliunits::ZERO);
         }
     }
 }
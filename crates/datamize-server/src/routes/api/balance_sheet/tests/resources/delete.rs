@@ -12,6 +12,7 @@ use sqlx::SqlitePool;
 use tower::ServiceExt;
 
 use crate::routes::api::balance_sheet::tests::resources::testutils::TestContext;
+use ynab::Milliunits;
 
 async fn check_delete(
     pool: SqlitePool,
@@ -95,7 +96,7 @@ async fn check_delete(
                 let saved_month = saved_month.unwrap();
                 if !saved_month.resources.is_empty() {
                     // Since net_assets are computed from all resources' type
-                    assert_ne!(saved_month.net_assets().total, 0);
+                    assert_ne!(saved_month.net_assets().total, Milliunits::ZERO);
                 }
             }
         }
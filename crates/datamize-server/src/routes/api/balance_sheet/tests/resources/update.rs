@@ -17,6 +17,7 @@ use sqlx::SqlitePool;
 use tower::ServiceExt;
 
 use crate::routes::api::balance_sheet::tests::resources::testutils::TestContext;
+use ynab::Milliunits;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 struct UpdateBody {
@@ -24,7 +25,7 @@ struct UpdateBody {
     pub name: String,
     #[serde(with = "datamize_domain::string")]
     pub resource_type: FinancialResourceType,
-    pub balances: BTreeMap<i32, BTreeMap<MonthNum, Option<i64>>>,
+    pub balances: BTreeMap<i32, BTreeMap<MonthNum, Option<Milliunits>>>,
     pub ynab_account_ids: Option<Vec<Uuid>>,
     pub external_account_ids: Option<Vec<Uuid>>,
 }
@@ -122,7 +123,7 @@ async fn check_update(
                 let saved_month = saved_month.unwrap();
                 if !saved_month.resources.is_empty() {
                     // Since net_assets are computed from all resources' type
-                    assert_ne!(saved_month.net_assets().total, 0);
+                    assert_ne!(saved_month.net_assets().total, Milliunits::ZERO);
                 }
             }
         }
@@ -9,7 +9,7 @@ use pretty_assertions::assert_eq;
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 use tower::ServiceExt;
-use ynab::TransactionDetail;
+use ynab::{Milliunits, TransactionDetail};
 
 use crate::routes::api::balance_sheet::tests::saving_rates::testutils::TestContext;
 
@@ -19,8 +19,11 @@ struct CreateBody {
     pub name: String,
     pub year: i32,
     pub savings: SaveSavings,
+    #[dummy(faker = "-1000000..1000000")]
     pub employer_contribution: i64,
+    #[dummy(faker = "-1000000..1000000")]
     pub employee_contribution: i64,
+    #[dummy(faker = "-1000000..1000000")]
     pub mortgage_capital: i64,
     pub incomes: SaveIncomes,
 }
@@ -28,12 +31,14 @@ struct CreateBody {
 #[derive(Debug, Deserialize, Serialize, Clone, fake::Dummy)]
 struct SaveSavings {
     pub category_ids: Vec<Uuid>,
+    #[dummy(faker = "-1000000..1000000")]
     pub extra_balance: i64,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, fake::Dummy)]
 struct SaveIncomes {
     pub payee_ids: Vec<Uuid>,
+    #[dummy(faker = "-1000000..1000000")]
     pub extra_balance: i64,
 }
 
@@ -43,19 +48,22 @@ impl From<CreateBody> for SavingRate {
             id: value.id,
             name: value.name,
             year: value.year,
-            employee_contribution: value.employee_contribution,
-            employer_contribution: value.employer_contribution,
-            mortgage_capital: value.mortgage_capital,
+            employee_contribution: value.employee_contribution.into(),
+            employer_contribution: value.employer_contribution.into(),
+            mortgage_capital: value.mortgage_capital.into(),
             savings: Savings {
                 category_ids: value.savings.category_ids,
-                extra_balance: value.savings.extra_balance,
-                total: 0,
+                extra_balance: value.savings.extra_balance.into(),
+                total: Milliunits::ZERO,
             },
             incomes: Incomes {
                 payee_ids: value.incomes.payee_ids,
-                extra_balance: value.incomes.extra_balance,
-                total: 0,
+                extra_balance: value.incomes.extra_balance.into(),
+                total: Milliunits::ZERO,
             },
+            rate: 0.0,
+            target: None,
+            months: vec![],
         }
     }
 }
@@ -55,7 +55,7 @@ async fn check_get_all(
     if let Some(mut expected_resp) = expected_resp {
         expected_resp
             .iter_mut()
-            .for_each(|resp| resp.compute_totals(&transactions));
+            .for_each(|resp| resp.compute_totals(&transactions).unwrap());
         let body: Vec<SavingRate> = serde_json::from_slice(&body).unwrap();
         assert_eq!(body, expected_resp);
     }
@@ -31,20 +31,23 @@ impl TestContext {
         let redis_conn_pool = get_test_pool().await;
         let year_repo = SqliteYearRepo::new_arced(pool.clone());
         let saving_rate_repo = SqliteSavingRateRepo::new_arced(pool.clone());
-        let ynab_transaction_repo = SqliteYnabTransactionRepo::new_arced(pool);
+        let ynab_transaction_repo =
+            SqliteYnabTransactionRepo::new_arced(pool, ynab::LAST_USED_BUDGET_ID);
 
-        let ynab_transaction_meta_repo = RedisYnabTransactionMetaRepo::new_arced(redis_conn_pool);
+        let ynab_transaction_meta_repo =
+            RedisYnabTransactionMetaRepo::new_arced(redis_conn_pool, ynab::LAST_USED_BUDGET_ID);
 
         let mut ynab_client = Arc::new(MockTransactionRequestsImpl::new());
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_transactions_delta()
-            .returning(|_| Ok(Faker.fake()));
+            .returning(|_, _| Ok(Faker.fake()));
 
         let transaction_service = TransactionService::new_arced(
             ynab_transaction_repo.clone(),
             ynab_transaction_meta_repo,
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
         );
         let saving_rate_service =
             SavingRateService::new_arced(saving_rate_repo.clone(), transaction_service);
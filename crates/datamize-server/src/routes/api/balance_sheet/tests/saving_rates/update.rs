@@ -117,6 +117,9 @@ async fn returns_success_with_the_update(pool: SqlitePool) {
         employee_contribution: body.employee_contribution,
         employer_contribution: body.employer_contribution,
         mortgage_capital: body.mortgage_capital,
+        rate: 0.0,
+        target: body.target,
+        months: vec![],
     };
 
     check_update(pool, true, Some(body), StatusCode::OK, Some(expected_resp)).await;
@@ -14,6 +14,7 @@ use tower::ServiceExt;
 use crate::routes::api::balance_sheet::tests::years::testutils::{
     correctly_stub_year, TestContext,
 };
+use ynab::Milliunits;
 
 #[derive(Debug, Deserialize, Serialize, Clone, fake::Dummy)]
 struct CreateBody {
@@ -107,9 +108,9 @@ async fn update_net_totals_if_prev_year_exists(pool: SqlitePool) {
         year: body.year,
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_year.net_assets().total,
-                percent_var: if prev_year.net_assets().total == 0 {
+                percent_var: if prev_year.net_assets().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -117,9 +118,9 @@ async fn update_net_totals_if_prev_year_exists(pool: SqlitePool) {
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_year.net_portfolio().total,
-                percent_var: if prev_year.net_portfolio().total == 0 {
+                percent_var: if prev_year.net_portfolio().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -157,9 +158,9 @@ async fn update_net_totals_of_prev_and_next_years_if_exists(pool: SqlitePool) {
         year: body.year,
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_year.net_assets().total,
-                percent_var: if prev_year.net_assets().total == 0 {
+                percent_var: if prev_year.net_assets().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -167,9 +168,9 @@ async fn update_net_totals_of_prev_and_next_years_if_exists(pool: SqlitePool) {
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_year.net_portfolio().total,
-                percent_var: if prev_year.net_portfolio().total == 0 {
+                percent_var: if prev_year.net_portfolio().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -1,5 +1,5 @@
 use axum::extract::State;
-use datamize_domain::ExternalAccount;
+use datamize_domain::{ExternalAccount, ExternalAccountStatus};
 
 use crate::error::{AppJson, HttpJsonDatamizeResult};
 use crate::services::budget_providers::DynExternalAccountService;
@@ -13,3 +13,15 @@ pub async fn get_external_accounts(
         external_account_service.get_all_external_accounts().await?,
     ))
 }
+
+/// Returns all external accounts with how their last refreshes went, flagging the stale ones.
+#[tracing::instrument(name = "Get the status of the external accounts", skip_all)]
+pub async fn get_external_accounts_status(
+    State(external_account_service): State<DynExternalAccountService>,
+) -> HttpJsonDatamizeResult<Vec<ExternalAccountStatus>> {
+    Ok(AppJson(
+        external_account_service
+            .get_external_account_statuses()
+            .await?,
+    ))
+}
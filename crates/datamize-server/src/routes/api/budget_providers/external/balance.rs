@@ -0,0 +1,51 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::Utc;
+use datamize_domain::{
+    BalanceHistoryParams, BalanceSource, ExternalAccountBalance, NewExternalAccountBalance, Uuid,
+};
+
+use crate::{
+    error::{AppError, AppJson, HttpJsonDatamizeResult},
+    services::budget_providers::DynExternalAccountService,
+};
+
+/// Returns the balances observed for the external account, the oldest first.
+/// /external/accounts/:account_id/balances?from=2024-01-01T00:00:00Z&to=2024-02-01T00:00:00Z
+#[tracing::instrument(name = "Get the balance history of an external account", skip_all)]
+pub async fn get_balance_history(
+    Path(account_id): Path<Uuid>,
+    State(external_account_service): State<DynExternalAccountService>,
+    params: Query<BalanceHistoryParams>,
+) -> HttpJsonDatamizeResult<Vec<ExternalAccountBalance>> {
+    Ok(AppJson(
+        external_account_service
+            .get_balance_history(account_id, params.0)
+            .await?,
+    ))
+}
+
+/// Records a balance of the external account entered by hand.
+#[tracing::instrument(skip_all)]
+pub async fn add_balance(
+    Path(account_id): Path<Uuid>,
+    State(external_account_service): State<DynExternalAccountService>,
+    AppJson(body): AppJson<NewExternalAccountBalance>,
+) -> Result<impl IntoResponse, AppError> {
+    Ok((
+        StatusCode::CREATED,
+        AppJson(
+            external_account_service
+                .add_balance(
+                    account_id,
+                    body.balance,
+                    body.observed_at.unwrap_or_else(Utc::now),
+                    BalanceSource::Manual,
+                )
+                .await?,
+        ),
+    ))
+}
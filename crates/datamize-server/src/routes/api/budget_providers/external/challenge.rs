@@ -0,0 +1,31 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+use datamize_domain::{ChallengeCode, PendingChallenge, Uuid};
+
+use crate::{
+    error::{AppError, AppJson, HttpJsonDatamizeResult},
+    services::budget_providers::DynExternalAccountService,
+};
+
+/// Returns the refreshes waiting for a one-time code the institution sent by SMS or email.
+#[tracing::instrument(name = "Get the pending challenges", skip_all)]
+pub async fn get_pending_challenges(
+    State(external_account_service): State<DynExternalAccountService>,
+) -> HttpJsonDatamizeResult<Vec<PendingChallenge>> {
+    Ok(AppJson(external_account_service.get_pending_challenges()))
+}
+
+/// Submits the one-time code to the refresh of the external account waiting for it.
+/// Returns 404 when no refresh of the account waits for a code.
+#[tracing::instrument(skip_all)]
+pub async fn submit_challenge_code(
+    Path(account_id): Path<Uuid>,
+    State(external_account_service): State<DynExternalAccountService>,
+    AppJson(body): AppJson<ChallengeCode>,
+) -> Result<StatusCode, AppError> {
+    external_account_service.submit_challenge_code(account_id, body.code)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
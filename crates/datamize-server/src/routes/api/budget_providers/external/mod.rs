@@ -1,16 +1,46 @@
 #[cfg(test)]
 mod tests;
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 
-use crate::services::budget_providers::DynExternalAccountService;
+use crate::{config::StatementSettings, services::budget_providers::DynExternalAccountService};
 
 mod accounts;
+mod balance;
+mod challenge;
+mod statement;
 
 use accounts::*;
+use balance::*;
+use challenge::*;
+use statement::*;
 
-pub fn get_external_routes<S>(external_account_service: DynExternalAccountService) -> Router<S> {
+pub fn get_external_routes<S>(
+    external_account_service: DynExternalAccountService,
+    statement_settings: StatementSettings,
+) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
     Router::new()
         .route("/accounts", get(get_external_accounts))
-        .with_state(external_account_service)
+        .route("/accounts/status", get(get_external_accounts_status))
+        .route(
+            "/accounts/:account_id/balances",
+            get(get_balance_history).post(add_balance),
+        )
+        .route("/challenges", get(get_pending_challenges))
+        .route(
+            "/accounts/:account_id/challenge",
+            post(submit_challenge_code),
+        )
+        .with_state(external_account_service.clone())
+        .merge(
+            Router::new()
+                .route("/accounts/:account_id/statement", post(import_statement))
+                .with_state((external_account_service, statement_settings)),
+        )
 }
@@ -0,0 +1,39 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+};
+use datamize_domain::{ImportedStatement, StatementImportParams, Uuid};
+
+use crate::{
+    config::StatementSettings,
+    error::{AppJson, HttpJsonDatamizeResult},
+    services::budget_providers::{read_statement, DynExternalAccountService},
+};
+
+/// Updates the balance of the external account from an OFX, QFX or CSV statement.
+/// /external/accounts/:account_id/statement?format=csv&layout=desjardins
+/// The layout names one of the CSV layouts configured, it is only needed for CSV statements.
+/// Returns the account with its new balance, along with the transactions of the statement.
+#[tracing::instrument(skip_all)]
+pub async fn import_statement(
+    Path(account_id): Path<Uuid>,
+    State((external_account_service, statement_settings)): State<(
+        DynExternalAccountService,
+        StatementSettings,
+    )>,
+    params: Query<StatementImportParams>,
+    body: Bytes,
+) -> HttpJsonDatamizeResult<ImportedStatement> {
+    let statement = read_statement(
+        &body,
+        params.format,
+        params.layout.as_deref(),
+        &statement_settings,
+    )?;
+
+    Ok(AppJson(
+        external_account_service
+            .import_statement(account_id, statement)
+            .await?,
+    ))
+}
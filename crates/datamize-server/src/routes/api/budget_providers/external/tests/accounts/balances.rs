@@ -0,0 +1,170 @@
+use axum::{
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use datamize_domain::{BalanceSource, ExternalAccount, ExternalAccountBalance, Uuid};
+use fake::{Fake, Faker};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+use ynab::Milliunits;
+
+use crate::routes::api::budget_providers::external::tests::accounts::testutils::{
+    correctly_stub_accounts, TestContext,
+};
+
+async fn send(context: &TestContext, request: Request<Body>) -> (StatusCode, Bytes) {
+    let response = context.app().oneshot(request).await.unwrap();
+    let status = response.status();
+    (
+        status,
+        response.into_body().collect().await.unwrap().to_bytes(),
+    )
+}
+
+fn post_balance(account_id: Uuid, body: serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(format!("/accounts/{}/balances", account_id))
+        .header("Content-Type", "application/json")
+        .body(Body::from(serde_json::to_vec(&body).unwrap()))
+        .unwrap()
+}
+
+fn observed(account_id: Uuid, observed_at: DateTime<Utc>, balance: i64) -> ExternalAccountBalance {
+    ExternalAccountBalance {
+        id: Uuid::new_v4(),
+        account_id,
+        observed_at,
+        balance: Milliunits::new(balance),
+        source: BalanceSource::Scrape,
+    }
+}
+
+async fn setup_account(context: &TestContext, balance: i64) -> ExternalAccount {
+    let account = ExternalAccount {
+        balance: Milliunits::new(balance),
+        ..Faker.fake()
+    };
+    context
+        .set_accounts(&correctly_stub_accounts(vec![account.clone()]))
+        .await;
+
+    account
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_the_balances_observed_in_the_range_oldest_first(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account = setup_account(&context, 3000).await;
+    let january = "2024-01-20T12:00:00Z".parse().unwrap();
+    let february = "2024-02-10T12:00:00Z".parse().unwrap();
+    let march = "2024-03-05T12:00:00Z".parse().unwrap();
+    let balances = [
+        observed(account.id, march, 3000),
+        observed(account.id, january, 1000),
+        observed(account.id, february, 2000),
+    ];
+    context.set_balances(&balances).await;
+
+    let (status, body) = send(
+        &context,
+        Request::builder()
+            .uri(format!(
+                "/accounts/{}/balances?from=2024-01-01T00:00:00Z&to=2024-03-01T00:00:00Z",
+                account.id
+            ))
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    let history: Vec<ExternalAccountBalance> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(history, vec![balances[1].clone(), balances[2].clone()]);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_404_when_the_account_does_not_exist(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+
+    let (status, _) = send(
+        &context,
+        Request::builder()
+            .uri(format!("/accounts/{}/balances", Uuid::new_v4()))
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn records_a_manual_balance_as_the_current_one(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account = setup_account(&context, 1000).await;
+
+    let (status, body) = send(
+        &context,
+        post_balance(account.id, json!({ "balance": 2500 })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    let added: ExternalAccountBalance = serde_json::from_slice(&body).unwrap();
+    assert_eq!(added.source, BalanceSource::Manual);
+    assert_eq!(added.balance, Milliunits::new(2500));
+    assert_eq!(
+        context.get_account(account.id).await.unwrap().balance,
+        Milliunits::new(2500)
+    );
+    assert_eq!(context.get_balances(account.id).await, vec![added]);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn keeps_an_older_balance_in_the_history_only(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account = setup_account(&context, 1000).await;
+    let now = Utc::now().duration_trunc(Duration::seconds(1)).unwrap();
+    let latest = observed(account.id, now, 1000);
+    context.set_balances(std::slice::from_ref(&latest)).await;
+
+    let (status, body) = send(
+        &context,
+        post_balance(
+            account.id,
+            json!({ "balance": 500, "observed_at": now - Duration::days(40) }),
+        ),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    let added: ExternalAccountBalance = serde_json::from_slice(&body).unwrap();
+    assert_eq!(
+        context.get_account(account.id).await.unwrap().balance,
+        Milliunits::new(1000)
+    );
+    assert_eq!(context.get_balances(account.id).await, vec![added, latest]);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_400_for_a_balance_observed_in_the_future(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account = setup_account(&context, 1000).await;
+
+    let (status, _) = send(
+        &context,
+        post_balance(
+            account.id,
+            json!({ "balance": 500, "observed_at": Utc::now() + Duration::days(1) }),
+        ),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(context.get_balances(account.id).await, vec![]);
+}
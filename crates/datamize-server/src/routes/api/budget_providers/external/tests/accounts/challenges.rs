@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use datamize_domain::{PendingChallenge, Uuid};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+
+use crate::routes::api::budget_providers::external::tests::accounts::testutils::TestContext;
+
+fn post_code(account_id: Uuid, code: &str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(format!("/accounts/{}/challenge", account_id))
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            serde_json::to_vec(&json!({ "code": code })).unwrap(),
+        ))
+        .unwrap()
+}
+
+async fn wait_until_pending(context: &TestContext) {
+    while context.pending_challenges().get_all().is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_no_challenge_when_no_refresh_waits(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+
+    let response = context
+        .into_app()
+        .oneshot(
+            Request::builder()
+                .uri("/challenges")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Vec<PendingChallenge> = serde_json::from_slice(&body).unwrap();
+    assert!(body.is_empty());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_the_challenges_of_the_refreshes_waiting_for_a_code(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account_id = Uuid::new_v4();
+    let challenges = context.pending_challenges().clone();
+    let waiting = tokio::spawn(async move {
+        challenges
+            .wait_for_code(account_id, "Pension", Duration::from_secs(60))
+            .await
+    });
+    wait_until_pending(&context).await;
+
+    let response = context
+        .app()
+        .oneshot(
+            Request::builder()
+                .uri("/challenges")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body: Vec<PendingChallenge> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0].account_id, account_id);
+    assert_eq!(body[0].account_name, "Pension");
+    assert!(body[0].expires_at > body[0].asked_at);
+    waiting.abort();
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn submitted_code_is_given_to_the_waiting_refresh(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account_id = Uuid::new_v4();
+    let challenges = context.pending_challenges().clone();
+    let waiting = tokio::spawn(async move {
+        challenges
+            .wait_for_code(account_id, "Pension", Duration::from_secs(60))
+            .await
+    });
+    wait_until_pending(&context).await;
+
+    let response = context
+        .app()
+        .oneshot(post_code(account_id, "123456"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert_eq!(waiting.await.unwrap().unwrap(), "123456");
+    assert!(context.pending_challenges().get_all().is_empty());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_404_when_no_refresh_waits_for_a_code(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+
+    let response = context
+        .into_app()
+        .oneshot(post_code(Uuid::new_v4(), "123456"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
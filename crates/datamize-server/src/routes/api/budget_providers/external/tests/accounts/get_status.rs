@@ -0,0 +1,125 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use chrono::{Duration, DurationRound, Utc};
+use datamize_domain::{
+    AccountType, ExternalAccount, ExternalAccountRefresh, ExternalAccountStatus, Uuid,
+    WebScrapingAccount,
+};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+use ynab::Milliunits;
+
+use crate::routes::api::budget_providers::external::tests::accounts::testutils::{
+    correctly_stub_accounts, TestContext,
+};
+
+fn account(name: &str, scraper_id: Option<&str>) -> WebScrapingAccount {
+    let account = ExternalAccount {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        account_type: AccountType::Tfsa,
+        balance: Milliunits::new(1000),
+        deleted: false,
+    };
+    WebScrapingAccount {
+        scraper_id: scraper_id.map(str::to_string),
+        ..correctly_stub_accounts(vec![account]).remove(0)
+    }
+}
+
+fn refresh(
+    account: &WebScrapingAccount,
+    days_ago: i64,
+    balance: Option<i64>,
+) -> ExternalAccountRefresh {
+    ExternalAccountRefresh {
+        id: Uuid::new_v4(),
+        account_id: account.id,
+        refreshed_at: Utc::now().duration_trunc(Duration::seconds(1)).unwrap()
+            - Duration::days(days_ago),
+        duration_ms: 2000,
+        succeeded: balance.is_some(),
+        balance: balance.map(Milliunits::new),
+        error: balance
+            .is_none()
+            .then(|| "Step 2 of the recipe \"peak_tfsa\" failed".to_string()),
+    }
+}
+
+async fn get_statuses(context: TestContext) -> Vec<ExternalAccountStatus> {
+    let response = context
+        .into_app()
+        .oneshot(
+            Request::builder()
+                .uri("/accounts/status")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn empty_list_when_nothing_in_db(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+
+    assert_eq!(get_statuses(context).await, vec![]);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn accounts_without_a_recent_success_are_stale(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let fresh = account("Bank", Some("ia_rrsp"));
+    let failing = account("Broker", Some("peak_tfsa"));
+    let never_refreshed = account("Pension", Some("canada_life_rpp"));
+    let manual = account("House", None);
+    context
+        .set_accounts(&[
+            fresh.clone(),
+            failing.clone(),
+            never_refreshed.clone(),
+            manual.clone(),
+        ])
+        .await;
+    let fresh_refresh = refresh(&fresh, 0, Some(2000));
+    let failing_success = refresh(&failing, 3, Some(1500));
+    let failing_failure = refresh(&failing, 0, None);
+    context
+        .set_refreshes(&[
+            fresh_refresh.clone(),
+            failing_success.clone(),
+            failing_failure.clone(),
+        ])
+        .await;
+
+    let statuses = get_statuses(context).await;
+
+    assert_eq!(
+        statuses
+            .iter()
+            .map(|s| (s.account.name.as_str(), s.stale))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Bank", false),
+            ("Broker", true),
+            ("House", false),
+            ("Pension", true)
+        ]
+    );
+    assert_eq!(statuses[0].last_refresh, Some(fresh_refresh.clone()));
+    assert_eq!(statuses[0].last_success, Some(fresh_refresh));
+    assert_eq!(statuses[1].last_refresh, Some(failing_failure));
+    assert_eq!(statuses[1].last_success, Some(failing_success));
+    assert_eq!(statuses[2].last_refresh, None);
+    assert_eq!(statuses[3].scraper_id, Some("canada_life_rpp".to_string()));
+    assert_eq!(statuses[3].last_success, None);
+}
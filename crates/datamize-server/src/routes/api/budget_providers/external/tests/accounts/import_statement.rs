@@ -0,0 +1,116 @@
+use axum::{
+    body::{Body, Bytes},
+    http::{Request, StatusCode},
+};
+use datamize_domain::{BalanceSource, ExternalAccount, ImportedStatement, Uuid};
+use fake::{Fake, Faker};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+use ynab::Milliunits;
+
+use crate::routes::api::budget_providers::external::tests::accounts::testutils::{
+    correctly_stub_accounts, TestContext,
+};
+
+const CHECKING_OFX: &str = include_str!(
+    "../../../../../../services/budget_providers/external/statements/tests/fixtures/checking.ofx"
+);
+
+async fn post_statement(
+    context: &TestContext,
+    account_id: Uuid,
+    query: &str,
+    statement: &'static str,
+) -> (StatusCode, Bytes) {
+    let response = context
+        .app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(format!("/accounts/{}/statement?{}", account_id, query))
+                .body(Body::from(statement))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    (
+        status,
+        response.into_body().collect().await.unwrap().to_bytes(),
+    )
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn updates_the_balance_of_the_account(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account = ExternalAccount {
+        balance: Milliunits::new(1000),
+        ..Faker.fake()
+    };
+    context
+        .set_accounts(&correctly_stub_accounts(vec![account.clone()]))
+        .await;
+
+    let (status, body) = post_statement(&context, account.id, "format=ofx", CHECKING_OFX).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let imported: ImportedStatement = serde_json::from_slice(&body).unwrap();
+    assert_eq!(imported.previous_balance, Milliunits::new(1000));
+    assert_eq!(imported.account.balance, Milliunits::new(2345670));
+    assert_eq!(imported.transactions.len(), 2);
+    assert_eq!(
+        context.get_account(account.id).await.unwrap().balance,
+        Milliunits::new(2345670)
+    );
+    let refresh = context.get_last_refresh(account.id).await.unwrap();
+    assert!(refresh.succeeded);
+    assert_eq!(refresh.balance, Some(Milliunits::new(2345670)));
+    let history = context.get_balances(account.id).await;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].source, BalanceSource::Import);
+    assert_eq!(history[0].balance, Milliunits::new(2345670));
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_404_when_the_account_does_not_exist(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+
+    let (status, _) = post_statement(&context, Uuid::new_v4(), "format=ofx", CHECKING_OFX).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_400_when_the_statement_is_invalid(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account: ExternalAccount = Faker.fake();
+    context
+        .set_accounts(&correctly_stub_accounts(vec![account.clone()]))
+        .await;
+
+    let (status, _) = post_statement(&context, account.id, "format=ofx", "Not a statement").await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_400_when_the_csv_layout_is_unknown(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let account: ExternalAccount = Faker.fake();
+    context
+        .set_accounts(&correctly_stub_accounts(vec![account.clone()]))
+        .await;
+
+    let (status, _) = post_statement(
+        &context,
+        account.id,
+        "format=csv&layout=savings",
+        "date,balance",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
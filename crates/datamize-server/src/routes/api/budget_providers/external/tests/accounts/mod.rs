@@ -1,2 +1,6 @@
+mod balances;
+mod challenges;
 mod get_all;
+mod get_status;
+mod import_statement;
 pub(crate) mod testutils;
@@ -2,21 +2,34 @@ use std::sync::Arc;
 
 use axum::Router;
 use datamize_domain::{
-    db::external::{EncryptionKeyRepo, ExternalAccountRepo},
-    ExternalAccount, SecretPassword, WebScrapingAccount,
+    db::{
+        external::{
+            EncryptionKeyRepo, ExternalAccountBalanceRepo, ExternalAccountRefreshRepo,
+            ExternalAccountRepo,
+        },
+        DbResult,
+    },
+    ExternalAccount, ExternalAccountBalance, ExternalAccountRefresh, SecretPassword, Uuid,
+    WebScrapingAccount,
 };
 use db_redis::{budget_providers::external::RedisEncryptionKeyRepo, get_test_pool};
-use db_sqlite::budget_providers::external::SqliteExternalAccountRepo;
+use db_sqlite::budget_providers::external::{
+    SqliteExternalAccountBalanceRepo, SqliteExternalAccountRefreshRepo, SqliteExternalAccountRepo,
+};
 use fake::{Fake, Faker};
 use sqlx::SqlitePool;
 
 use crate::{
+    config::StatementSettings,
     routes::api::budget_providers::external::get_external_routes,
-    services::budget_providers::ExternalAccountService,
+    services::budget_providers::{ExternalAccountService, PendingChallenges, ScraperRegistry},
 };
 
 pub(crate) struct TestContext {
     external_account_repo: Arc<SqliteExternalAccountRepo>,
+    external_account_refresh_repo: Arc<SqliteExternalAccountRefreshRepo>,
+    external_account_balance_repo: Arc<SqliteExternalAccountBalanceRepo>,
+    pending_challenges: PendingChallenges,
     app: Router,
 }
 
@@ -26,12 +39,26 @@ impl TestContext {
         let external_account_repo = SqliteExternalAccountRepo::new_arced(pool.clone());
         let encryption_key_repo = RedisEncryptionKeyRepo::new_arced(redis_conn_pool);
         encryption_key_repo.set(&fake::vec![u8; 6]).await.unwrap();
-        let external_account_service =
-            ExternalAccountService::new_arced(external_account_repo.clone(), encryption_key_repo);
+        let external_account_refresh_repo =
+            SqliteExternalAccountRefreshRepo::new_arced(pool.clone());
+        let external_account_balance_repo = SqliteExternalAccountBalanceRepo::new_arced(pool);
+        let pending_challenges = PendingChallenges::default();
+        let external_account_service = ExternalAccountService::new_arced(
+            external_account_repo.clone(),
+            encryption_key_repo,
+            external_account_refresh_repo.clone(),
+            external_account_balance_repo.clone(),
+            pending_challenges.clone(),
+            Arc::new(ScraperRegistry::default()),
+            "http://localhost:4444/".to_string(),
+        );
 
-        let app = get_external_routes(external_account_service);
+        let app = get_external_routes(external_account_service, StatementSettings::default());
         Self {
             external_account_repo,
+            external_account_refresh_repo,
+            external_account_balance_repo,
+            pending_challenges,
             app,
         }
     }
@@ -40,11 +67,51 @@ impl TestContext {
         self.app
     }
 
+    pub(crate) fn app(&self) -> Router {
+        self.app.clone()
+    }
+
+    pub(crate) fn pending_challenges(&self) -> &PendingChallenges {
+        &self.pending_challenges
+    }
+
     pub(crate) async fn set_accounts(&self, accounts: &[WebScrapingAccount]) {
         for a in accounts {
             self.external_account_repo.update(a).await.unwrap();
         }
     }
+
+    pub(crate) async fn get_account(&self, account_id: Uuid) -> DbResult<WebScrapingAccount> {
+        self.external_account_repo.get(account_id).await
+    }
+
+    pub(crate) async fn get_last_refresh(
+        &self,
+        account_id: Uuid,
+    ) -> DbResult<ExternalAccountRefresh> {
+        self.external_account_refresh_repo
+            .get_last(account_id)
+            .await
+    }
+
+    pub(crate) async fn set_refreshes(&self, refreshes: &[ExternalAccountRefresh]) {
+        for r in refreshes {
+            self.external_account_refresh_repo.add(r).await.unwrap();
+        }
+    }
+
+    pub(crate) async fn get_balances(&self, account_id: Uuid) -> Vec<ExternalAccountBalance> {
+        self.external_account_balance_repo
+            .get_all(account_id, None, None)
+            .await
+            .unwrap()
+    }
+
+    pub(crate) async fn set_balances(&self, balances: &[ExternalAccountBalance]) {
+        for b in balances {
+            self.external_account_balance_repo.add(b).await.unwrap();
+        }
+    }
 }
 
 pub(crate) fn correctly_stub_accounts(accounts: Vec<ExternalAccount>) -> Vec<WebScrapingAccount> {
@@ -58,6 +125,8 @@ pub(crate) fn correctly_stub_accounts(accounts: Vec<ExternalAccount>) -> Vec<Web
             deleted: a.deleted,
             username: Faker.fake(),
             encrypted_password: SecretPassword::new(Faker.fake()),
+            scraper_id: None,
+            encrypted_totp_seed: None,
         })
         .collect()
 }
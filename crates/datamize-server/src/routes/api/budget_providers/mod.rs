@@ -1,12 +1,4 @@
 use axum::Router;
-use db_postgres::budget_providers::{
-    external::PostgresExternalAccountRepo,
-    ynab::{PostgresYnabAccountRepo, PostgresYnabPayeeRepo},
-};
-use db_redis::budget_providers::{
-    external::RedisEncryptionKeyRepo,
-    ynab::{RedisYnabAccountMetaRepo, RedisYnabPayeeMetaRepo},
-};
 
 use crate::{
     services::budget_providers::{ExternalAccountService, YnabAccountService, YnabPayeeService},
@@ -20,33 +12,55 @@ use self::ynab::*;
 use external::*;
 
 pub fn get_budget_providers_routes(app_state: &AppState) -> Router<AppState> {
-    let ynab_account_repo = PostgresYnabAccountRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_account_meta_repo =
-        RedisYnabAccountMetaRepo::new_arced(app_state.redis_conn_pool.clone());
+    let external_account_repo = app_state.repos.external_account_repo();
+    let encryption_key_repo = app_state.repos.encryption_key_repo();
+    let external_account_refresh_repo = app_state.repos.external_account_refresh_repo();
+    let external_account_balance_repo = app_state.repos.external_account_balance_repo();
+    let external_acount_service = ExternalAccountService::new_arced(
+        external_account_repo,
+        encryption_key_repo,
+        external_account_refresh_repo,
+        external_account_balance_repo,
+        app_state.pending_challenges.clone(),
+        app_state.scraper_registry.clone(),
+        app_state.webdriver_location.clone(),
+    );
+
+    Router::new()
+        .nest(
+            "/ynab",
+            get_ynab_budget_providers_routes(app_state, app_state.default_budget_id()),
+        )
+        .nest(
+            "/external",
+            get_external_routes(
+                external_acount_service,
+                app_state.statement_settings.clone(),
+            ),
+        )
+}
+
+pub fn get_ynab_budget_providers_routes<S: Clone + Send + Sync + 'static>(
+    app_state: &AppState,
+    budget_id: &str,
+) -> Router<S> {
+    let ynab_account_repo = app_state.repos.ynab_account_repo(budget_id);
+    let ynab_account_meta_repo = app_state.repos.ynab_account_meta_repo(budget_id);
     let ynab_account_service = YnabAccountService::new_arced(
         ynab_account_repo,
         ynab_account_meta_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
 
-    let ynab_payee_repo = PostgresYnabPayeeRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_payee_meta_repo = RedisYnabPayeeMetaRepo::new_arced(app_state.redis_conn_pool.clone());
+    let ynab_payee_repo = app_state.repos.ynab_payee_repo(budget_id);
+    let ynab_payee_meta_repo = app_state.repos.ynab_payee_meta_repo(budget_id);
     let ynab_payee_service = YnabPayeeService::new_arced(
         ynab_payee_repo,
         ynab_payee_meta_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
 
-    let external_account_repo =
-        PostgresExternalAccountRepo::new_arced(app_state.db_conn_pool.clone());
-    let encryption_key_repo = RedisEncryptionKeyRepo::new_arced(app_state.redis_conn_pool.clone());
-    let external_acount_service =
-        ExternalAccountService::new_arced(external_account_repo, encryption_key_repo);
-
-    Router::new()
-        .nest(
-            "/ynab",
-            get_ynab_routes(ynab_account_service, ynab_payee_service),
-        )
-        .nest("/external", get_external_routes(external_acount_service))
+    get_ynab_routes(ynab_account_service, ynab_payee_service)
 }
@@ -7,7 +7,7 @@ use http_body_util::BodyExt;
 use pretty_assertions::assert_eq;
 use sqlx::SqlitePool;
 use tower::ServiceExt;
-use ynab::{Account, AccountsDelta};
+use ynab::{Account, AccountsDelta, ApiError};
 
 use crate::routes::api::budget_providers::ynab::tests::accounts::testutils::TestContext;
 
@@ -69,3 +69,83 @@ async fn returns_success_with_what_is_in_db(pool: SqlitePool) {
     )
     .await;
 }
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn does_not_return_accounts_of_other_budgets(pool: SqlitePool) {
+    let mut ynab_data: AccountsDelta = Faker.fake();
+    ynab_data.accounts.retain(|a| !a.deleted);
+    let context = TestContext::setup(pool, ynab_data.clone()).await;
+    let other_accounts: Vec<Account> = Faker.fake();
+    context
+        .set_accounts_of_budget("other-budget", &other_accounts)
+        .await;
+
+    let response = context
+        .into_app()
+        .oneshot(
+            Request::builder()
+                .uri("/accounts")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let mut body: Vec<Account> = serde_json::from_slice(&body).unwrap();
+    let mut expected = ynab_data.accounts;
+    body.sort_by(|a, b| a.name.cmp(&b.name));
+    expected.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(body, expected);
+}
+
+async fn check_get_all_with_ynab_error(
+    pool: SqlitePool,
+    error_id: &str,
+    expected_status: StatusCode,
+) {
+    let context = TestContext::setup_with_ynab_error(
+        pool,
+        ApiError {
+            id: error_id.to_string(),
+            name: Faker.fake(),
+            detail: Faker.fake(),
+        },
+    )
+    .await;
+
+    let response = context
+        .into_app()
+        .oneshot(
+            Request::builder()
+                .uri("/accounts")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), expected_status);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_401_when_ynab_token_is_not_authorized(pool: SqlitePool) {
+    check_get_all_with_ynab_error(pool, "401", StatusCode::UNAUTHORIZED).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_403_when_ynab_subscription_lapsed(pool: SqlitePool) {
+    check_get_all_with_ynab_error(pool, "403.1", StatusCode::FORBIDDEN).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_429_when_ynab_rate_limit_is_reached(pool: SqlitePool) {
+    check_get_all_with_ynab_error(pool, "429", StatusCode::TOO_MANY_REQUESTS).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_503_when_ynab_is_unavailable(pool: SqlitePool) {
+    check_get_all_with_ynab_error(pool, "503", StatusCode::SERVICE_UNAVAILABLE).await;
+}
@@ -6,7 +6,7 @@ use db_redis::{budget_providers::ynab::RedisYnabAccountMetaRepo, get_test_pool};
 use db_sqlite::budget_providers::ynab::SqliteYnabAccountRepo;
 use fake::{Fake, Faker};
 use sqlx::SqlitePool;
-use ynab::{Account, AccountsDelta, MockAccountRequestsImpl};
+use ynab::{Account, AccountsDelta, ApiError, MockAccountRequestsImpl};
 
 use crate::{
     routes::api::budget_providers::ynab::get_ynab_account_routes,
@@ -20,23 +20,39 @@ pub(crate) struct TestContext {
 
 impl TestContext {
     pub(crate) async fn setup(pool: SqlitePool, ynab_accounts: AccountsDelta) -> Self {
+        let mut ynab_client = MockAccountRequestsImpl::new();
+        ynab_client
+            .expect_get_accounts_delta()
+            .returning(move |_, _| Ok(ynab_accounts.clone()));
+
+        Self::build(pool, ynab_client).await
+    }
+
+    pub(crate) async fn setup_with_ynab_error(pool: SqlitePool, ynab_error: ApiError) -> Self {
+        let mut ynab_client = MockAccountRequestsImpl::new();
+        ynab_client
+            .expect_get_accounts_delta()
+            .returning(move |_, _| Err(ynab::Error::Api(ynab_error.clone())));
+
+        Self::build(pool, ynab_client).await
+    }
+
+    async fn build(pool: SqlitePool, ynab_client: MockAccountRequestsImpl) -> Self {
         let redis_conn_pool = get_test_pool().await;
-        let ynab_account_repo = SqliteYnabAccountRepo::new_arced(pool.clone());
-        let ynab_account_meta_repo = RedisYnabAccountMetaRepo::new_arced(redis_conn_pool);
+        let ynab_account_repo =
+            SqliteYnabAccountRepo::new_arced(pool.clone(), ynab::LAST_USED_BUDGET_ID);
+        let ynab_account_meta_repo =
+            RedisYnabAccountMetaRepo::new_arced(redis_conn_pool, ynab::LAST_USED_BUDGET_ID);
         ynab_account_meta_repo
             .set_delta(Faker.fake())
             .await
             .unwrap();
-        let mut ynab_client = Arc::new(MockAccountRequestsImpl::new());
-        let ynab_client_mock = Arc::make_mut(&mut ynab_client);
-        ynab_client_mock
-            .expect_get_accounts_delta()
-            .returning(move |_| Ok(ynab_accounts.clone()));
 
         let ynab_account_service = YnabAccountService::new_arced(
             ynab_account_repo.clone(),
             ynab_account_meta_repo,
-            ynab_client,
+            Arc::new(ynab_client),
+            ynab::LAST_USED_BUDGET_ID,
         );
         let app = get_ynab_account_routes(ynab_account_service);
         Self {
@@ -52,4 +68,11 @@ impl TestContext {
     pub(crate) async fn set_accounts(&self, accounts: &[Account]) {
         self.ynab_account_repo.update_all(accounts).await.unwrap();
     }
+
+    pub(crate) async fn set_accounts_of_budget(&self, budget_id: &str, accounts: &[Account]) {
+        SqliteYnabAccountRepo::new_arced(self.ynab_account_repo.db_conn_pool.clone(), budget_id)
+            .update_all(accounts)
+            .await
+            .unwrap();
+    }
 }
@@ -7,8 +7,8 @@ use crate::error::HttpJsonDatamizeResult;
 /// Returns a budget template details
 /// Can specify the month to get details from.
 /// /template/details?month=previous
-/// Possible values to pass in query params are `previous` and `next`. If nothing is specified,
-/// the current month will be used.
+/// Possible values to pass in query params are `previous`, `next` or any month as `YYYY-MM`
+/// (e.g. `2024-05`). If nothing is specified, the current month will be used.
 pub async fn template_details(
     State(template_detail_service): State<DynTemplateDetailService>,
     template_params: Query<TemplateParams>,
@@ -0,0 +1,49 @@
+use axum::extract::{Path, State};
+use datamize_domain::{ExpenseAssociation, SaveExpenseAssociation, Uuid};
+
+use crate::{
+    error::{AppJson, HttpJsonDatamizeResult},
+    services::budget_template::DynExpenseAssociationService,
+};
+
+/// Returns the budgeter associated with a category or category group.
+#[tracing::instrument(skip_all)]
+pub async fn get_expense_association(
+    Path(id): Path<Uuid>,
+    State(expense_association_service): State<DynExpenseAssociationService>,
+) -> HttpJsonDatamizeResult<ExpenseAssociation> {
+    Ok(AppJson(
+        expense_association_service
+            .get_expense_association(id)
+            .await?,
+    ))
+}
+
+/// Associates the category or category group with a budgeter and returns the entity.
+/// Expenses of the category will then be individual expenses of the budgeter, or shared ones
+/// when no budgeter is given.
+#[tracing::instrument(skip_all)]
+pub async fn save_expense_association(
+    Path(id): Path<Uuid>,
+    State(expense_association_service): State<DynExpenseAssociationService>,
+    AppJson(body): AppJson<SaveExpenseAssociation>,
+) -> HttpJsonDatamizeResult<ExpenseAssociation> {
+    Ok(AppJson(
+        expense_association_service
+            .save_expense_association(id, body)
+            .await?,
+    ))
+}
+
+/// Deletes the association and returns the entity.
+#[tracing::instrument(skip_all)]
+pub async fn delete_expense_association(
+    Path(id): Path<Uuid>,
+    State(expense_association_service): State<DynExpenseAssociationService>,
+) -> HttpJsonDatamizeResult<ExpenseAssociation> {
+    Ok(AppJson(
+        expense_association_service
+            .delete_expense_association(id)
+            .await?,
+    ))
+}
@@ -0,0 +1,19 @@
+use axum::extract::State;
+use datamize_domain::ExpenseAssociation;
+
+use crate::{
+    error::{AppJson, HttpJsonDatamizeResult},
+    services::budget_template::DynExpenseAssociationService,
+};
+
+/// Returns all the associations between categories and budgeters.
+#[tracing::instrument(skip_all)]
+pub async fn get_all_expenses_association(
+    State(expense_association_service): State<DynExpenseAssociationService>,
+) -> HttpJsonDatamizeResult<Vec<ExpenseAssociation>> {
+    Ok(AppJson(
+        expense_association_service
+            .get_all_expenses_association()
+            .await?,
+    ))
+}
@@ -1,8 +1,11 @@
 mod budgeter;
 mod budgeters;
 mod details;
+mod expense_association;
 mod expense_categorization;
+mod expenses_association;
 mod expenses_categorization;
+mod projection;
 mod summary;
 #[cfg(test)]
 mod tests;
@@ -14,16 +17,13 @@ use axum::{
 };
 use budgeter::*;
 use budgeters::*;
-use db_postgres::{
-    budget_providers::ynab::{PostgresYnabCategoryRepo, PostgresYnabScheduledTransactionRepo},
-    budget_template::{PostgresBudgeterConfigRepo, PostgresExpenseCategorizationRepo},
-};
-use db_redis::budget_providers::ynab::{
-    RedisYnabCategoryMetaRepo, RedisYnabScheduledTransactionMetaRepo,
-};
+use datamize_domain::SystemClock;
 use details::*;
+use expense_association::*;
 use expense_categorization::*;
+use expenses_association::*;
 use expenses_categorization::*;
+use projection::*;
 use summary::*;
 use transactions::*;
 
@@ -31,10 +31,11 @@ use crate::{
     services::{
         budget_providers::{CategoryService, ScheduledTransactionService},
         budget_template::{
-            BudgeterService, DynBudgeterService, DynExpenseCategorizationService,
-            DynTemplateDetailService, DynTemplateSummaryService, DynTemplateTransactionService,
-            ExpenseCategorizationService, TemplateDetailService, TemplateSummaryService,
-            TemplateTransactionService,
+            BudgeterService, DynBudgeterService, DynExpenseAssociationService,
+            DynExpenseCategorizationService, DynTemplateDetailService,
+            DynTemplateProjectionService, DynTemplateSummaryService, DynTemplateTransactionService,
+            ExpenseAssociationService, ExpenseCategorizationService, TemplateDetailService,
+            TemplateProjectionService, TemplateSummaryService, TemplateTransactionService,
         },
     },
     startup::AppState,
@@ -42,61 +43,86 @@ use crate::{
 
 pub fn get_budget_template_routes<S: Clone + Send + Sync + 'static>(
     app_state: &AppState,
+    budget_id: &str,
 ) -> Router<S> {
-    let ynab_category_repo = PostgresYnabCategoryRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_category_meta_repo =
-        RedisYnabCategoryMetaRepo::new_arced(app_state.redis_conn_pool.clone());
+    let ynab_category_repo = app_state.repos.ynab_category_repo(budget_id);
+    let ynab_category_meta_repo = app_state.repos.ynab_category_meta_repo(budget_id);
     let ynab_scheduled_transaction_repo =
-        PostgresYnabScheduledTransactionRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_scheduled_transaction_meta_repo =
-        RedisYnabScheduledTransactionMetaRepo::new_arced(app_state.redis_conn_pool.clone());
-    let expense_categorization_repo =
-        PostgresExpenseCategorizationRepo::new_arced(app_state.db_conn_pool.clone());
-    let budgeter_config_repo =
-        PostgresBudgeterConfigRepo::new_arced(app_state.db_conn_pool.clone());
+        app_state.repos.ynab_scheduled_transaction_repo(budget_id);
+    let ynab_scheduled_transaction_meta_repo = app_state
+        .repos
+        .ynab_scheduled_transaction_meta_repo(budget_id);
+    let expense_categorization_repo = app_state.repos.expense_categorization_repo();
+    let budgeter_config_repo = app_state.repos.budgeter_config_repo();
+    let expense_association_repo = app_state.repos.expense_association_repo();
+    let clock = SystemClock::new_arced();
     let category_service = CategoryService::new_arced(
         ynab_category_repo.clone(),
         ynab_category_meta_repo,
         expense_categorization_repo.clone(),
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
+        clock.clone(),
     );
     let scheduled_transaction_service = ScheduledTransactionService::new_arced(
         ynab_scheduled_transaction_repo,
         ynab_scheduled_transaction_meta_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
 
     let template_detail_service = TemplateDetailService::new_arced(
         category_service.clone(),
         scheduled_transaction_service.clone(),
         budgeter_config_repo.clone(),
+        expense_association_repo.clone(),
+        clock.clone(),
     );
 
     let template_summary_service = TemplateSummaryService::new_arced(
+        category_service.clone(),
+        scheduled_transaction_service.clone(),
+        budgeter_config_repo.clone(),
+        expense_association_repo.clone(),
+        clock.clone(),
+    );
+
+    let template_projection_service = TemplateProjectionService::new_arced(
         category_service,
         scheduled_transaction_service.clone(),
         budgeter_config_repo.clone(),
+        expense_association_repo.clone(),
+        clock.clone(),
     );
 
     let template_transaction_service = TemplateTransactionService::new_arced(
         scheduled_transaction_service,
         ynab_category_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
+        clock,
+    );
+
+    let expense_association_service = ExpenseAssociationService::new_arced(
+        expense_association_repo,
+        budgeter_config_repo.clone(),
     );
 
-    let budgeter_service = BudgeterService::new_arced(budgeter_config_repo);
+    let budgeter_service = BudgeterService::new_arced(budgeter_config_repo.clone());
 
     let expense_categorization_service =
-        ExpenseCategorizationService::new_arced(expense_categorization_repo);
+        ExpenseCategorizationService::new_arced(expense_categorization_repo, budgeter_config_repo);
 
     Router::new()
         .merge(get_detail_routes(template_detail_service))
         .merge(get_summary_routes(template_summary_service))
+        .merge(get_projection_routes(template_projection_service))
         .merge(get_transaction_routes(template_transaction_service))
         .merge(get_budgeter_routes(budgeter_service))
         .merge(get_expense_categorization_routes(
             expense_categorization_service,
         ))
+        .merge(get_expense_association_routes(expense_association_service))
 }
 
 fn get_detail_routes<S>(template_detail_service: DynTemplateDetailService) -> Router<S> {
@@ -111,6 +137,14 @@ fn get_summary_routes<S>(template_summary_service: DynTemplateSummaryService) ->
         .with_state(template_summary_service)
 }
 
+fn get_projection_routes<S>(
+    template_projection_service: DynTemplateProjectionService,
+) -> Router<S> {
+    Router::new()
+        .route("/projection", get(template_projection))
+        .with_state(template_projection_service)
+}
+
 fn get_transaction_routes<S>(
     template_transaction_service: DynTemplateTransactionService,
 ) -> Router<S> {
@@ -146,3 +180,17 @@ fn get_expense_categorization_routes<S>(
         )
         .with_state(expense_categorization_service)
 }
+
+fn get_expense_association_routes<S>(
+    expense_association_service: DynExpenseAssociationService,
+) -> Router<S> {
+    Router::new()
+        .route("/expenses_association", get(get_all_expenses_association))
+        .route(
+            "/expense_association/:expense_association_id",
+            get(get_expense_association)
+                .put(save_expense_association)
+                .delete(delete_expense_association),
+        )
+        .with_state(expense_association_service)
+}
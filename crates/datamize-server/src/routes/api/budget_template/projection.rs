@@ -0,0 +1,29 @@
+use axum::extract::{Query, State};
+use datamize_domain::{BudgetProjection, ProjectionParams};
+
+use crate::{
+    error::{AppJson, HttpJsonDatamizeResult},
+    services::budget_template::DynTemplateProjectionService,
+};
+
+/// Returns the budget template projected on a range of months.
+/// Can specify the first and last months of the projection.
+/// /template/projection?from=2024-05&to=2025-04
+/// Both accept `previous`, `current`, `next` or any month as `YYYY-MM`. If nothing is specified,
+/// the next 12 months starting from the current one will be used.
+pub async fn template_projection(
+    State(template_projection_service): State<DynTemplateProjectionService>,
+    projection_params: Query<ProjectionParams>,
+) -> HttpJsonDatamizeResult<BudgetProjection> {
+    let use_category_groups_as_sub_type = projection_params
+        .use_category_groups_as_sub_type
+        .unwrap_or_default()
+        .0;
+    let from = projection_params.from.unwrap_or_default();
+
+    Ok(AppJson(
+        template_projection_service
+            .get_template_projection(from, projection_params.to, use_category_groups_as_sub_type)
+            .await?,
+    ))
+}
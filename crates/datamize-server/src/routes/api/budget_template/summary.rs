@@ -9,8 +9,8 @@ use crate::{
 /// Returns a budget template summary.
 /// Can specify the month to get summary from.
 /// /template/summary?month=previous
-/// Possible values to pass in query params are `previous` and `next`. If nothing is specified,
-/// the current month will be used.
+/// Possible values to pass in query params are `previous`, `next` or any month as `YYYY-MM`
+/// (e.g. `2024-05`). If nothing is specified, the current month will be used.
 pub async fn template_summary(
     State(template_summary_service): State<DynTemplateSummaryService>,
     template_params: Query<TemplateParams>,
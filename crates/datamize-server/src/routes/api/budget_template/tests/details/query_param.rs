@@ -4,6 +4,7 @@ use axum::{
     body::Body,
     http::{Request, StatusCode},
 };
+use chrono::NaiveDate;
 use datamize_domain::{async_trait, BudgetDetails, MonthTarget};
 use http_body_util::BodyExt;
 use pretty_assertions::assert_eq;
@@ -86,6 +87,40 @@ async fn get_template_details_success_with_query_params() {
     assert_eq!(body, BudgetDetails::default());
 }
 
+#[tokio::test]
+async fn get_template_details_success_with_year_month_query_param() {
+    #[derive(Clone)]
+    struct MockTemplateDetailService {}
+    #[async_trait]
+    impl TemplateDetailServiceExt for MockTemplateDetailService {
+        async fn get_template_details(
+            &self,
+            month: MonthTarget,
+            _use_category_groups_as_sub_type: bool,
+        ) -> DatamizeResult<BudgetDetails> {
+            assert_eq!(
+                month,
+                MonthTarget::Month(NaiveDate::from_ymd_opt(2024, 5, 1).unwrap())
+            );
+            Ok(BudgetDetails::default())
+        }
+    }
+    let template_detail_service = Arc::new(MockTemplateDetailService {});
+
+    let app = get_detail_routes(template_detail_service);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/details?month=2024-05")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
 #[tokio::test]
 async fn get_template_details_error_400_with_unsupported_query_param_value() {
     #[derive(Clone)]
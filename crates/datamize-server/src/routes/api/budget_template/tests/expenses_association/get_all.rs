@@ -0,0 +1,46 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use datamize_domain::ExpenseAssociation;
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+
+use crate::routes::api::budget_template::tests::expenses_association::testutils::TestContext;
+
+async fn check_get_all(pool: SqlitePool, expected_resp: Vec<ExpenseAssociation>) {
+    let context = TestContext::setup(pool);
+    context.set_expenses_association(&expected_resp).await;
+
+    let response = context
+        .into_app()
+        .oneshot(
+            Request::builder()
+                .uri("/expenses_association")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let mut body: Vec<ExpenseAssociation> = serde_json::from_slice(&body).unwrap();
+    body.sort_by_key(|a| a.id);
+    let mut expected_resp = expected_resp;
+    expected_resp.sort_by_key(|a| a.id);
+    assert_eq!(body, expected_resp);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_empty_list_when_nothing_in_db(pool: SqlitePool) {
+    check_get_all(pool, vec![]).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_all_that_is_in_db(pool: SqlitePool) {
+    check_get_all(pool, fake::vec![ExpenseAssociation; 1..5]).await;
+}
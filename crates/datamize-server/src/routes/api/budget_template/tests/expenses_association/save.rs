@@ -0,0 +1,155 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use datamize_domain::{AssociationType, ExpenseAssociation, SplitStrategy, Uuid};
+use fake::{Fake, Faker};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+
+use crate::routes::api::budget_template::tests::expenses_association::testutils::TestContext;
+
+async fn check_save(
+    pool: SqlitePool,
+    body: serde_json::Value,
+    budgeter_id: Option<Uuid>,
+    expected_status: StatusCode,
+    expected_resp: Option<ExpenseAssociation>,
+) {
+    let context = TestContext::setup(pool);
+
+    if let Some(budgeter_id) = budgeter_id {
+        context.set_budgeter(budgeter_id).await;
+    }
+
+    let id = expected_resp
+        .as_ref()
+        .map(|a| a.id)
+        .unwrap_or_else(|| Faker.fake());
+    let response = context
+        .app()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(format!("/expense_association/{:?}", id))
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), expected_status);
+
+    if let Some(expected) = expected_resp {
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: ExpenseAssociation = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, expected);
+
+        // Make sure the association is persisted in db
+        let saved = context.get_expense_association(id).await.unwrap();
+        assert_eq!(saved, expected);
+    }
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_404_when_budgeter_does_not_exist(pool: SqlitePool) {
+    check_save(
+        pool,
+        json!({ "budgeter_id": Faker.fake::<Uuid>() }),
+        None,
+        StatusCode::NOT_FOUND,
+        None,
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_category_association_by_default(pool: SqlitePool) {
+    let budgeter_id: Uuid = Faker.fake();
+    check_save(
+        pool,
+        json!({ "budgeter_id": budgeter_id }),
+        Some(budgeter_id),
+        StatusCode::OK,
+        Some(ExpenseAssociation {
+            id: Faker.fake(),
+            association_type: AssociationType::Category,
+            budgeter_id: Some(budgeter_id),
+            split_strategy: None,
+        }),
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_category_group_association(pool: SqlitePool) {
+    let budgeter_id: Uuid = Faker.fake();
+    check_save(
+        pool,
+        json!({ "type": "categoryGroup", "budgeter_id": budgeter_id }),
+        Some(budgeter_id),
+        StatusCode::OK,
+        Some(ExpenseAssociation {
+            id: Faker.fake(),
+            association_type: AssociationType::CategoryGroup,
+            budgeter_id: Some(budgeter_id),
+            split_strategy: None,
+        }),
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_422_for_unknown_association_type(pool: SqlitePool) {
+    let budgeter_id: Uuid = Faker.fake();
+    check_save(
+        pool,
+        json!({ "type": "payee", "budgeter_id": budgeter_id }),
+        Some(budgeter_id),
+        StatusCode::UNPROCESSABLE_ENTITY,
+        None,
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_shared_association_without_budgeter(pool: SqlitePool) {
+    check_save(
+        pool,
+        json!({ "type": "category", "budgeter_id": null }),
+        None,
+        StatusCode::OK,
+        Some(ExpenseAssociation {
+            id: Faker.fake(),
+            association_type: AssociationType::Category,
+            budgeter_id: None,
+            split_strategy: None,
+        }),
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_split_strategy(pool: SqlitePool) {
+    let budgeter_id: Uuid = Faker.fake();
+    check_save(
+        pool,
+        json!({
+            "budgeter_id": null,
+            "split_strategy": { "type": "single", "budgeter_id": budgeter_id }
+        }),
+        Some(budgeter_id),
+        StatusCode::OK,
+        Some(ExpenseAssociation {
+            id: Faker.fake(),
+            association_type: AssociationType::Category,
+            budgeter_id: None,
+            split_strategy: Some(SplitStrategy::Single { budgeter_id }),
+        }),
+    )
+    .await;
+}
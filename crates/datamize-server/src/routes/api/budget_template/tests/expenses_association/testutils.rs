@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use axum::Router;
+use datamize_domain::{
+    db::{BudgeterConfigRepo, DbResult, ExpenseAssociationRepo},
+    BudgeterConfig, ExpenseAssociation, Uuid,
+};
+use db_sqlite::budget_template::{SqliteBudgeterConfigRepo, SqliteExpenseAssociationRepo};
+use fake::{Fake, Faker};
+use sqlx::SqlitePool;
+
+use crate::{
+    routes::api::budget_template::get_expense_association_routes,
+    services::budget_template::ExpenseAssociationService,
+};
+
+pub(crate) struct TestContext {
+    expense_association_repo: Arc<SqliteExpenseAssociationRepo>,
+    budgeter_config_repo: Arc<SqliteBudgeterConfigRepo>,
+    app: Router,
+}
+
+impl TestContext {
+    pub(crate) fn setup(pool: SqlitePool) -> Self {
+        let expense_association_repo = SqliteExpenseAssociationRepo::new_arced(pool.clone());
+        let budgeter_config_repo = SqliteBudgeterConfigRepo::new_arced(pool.clone());
+
+        let expense_association_service = ExpenseAssociationService::new_arced(
+            expense_association_repo.clone(),
+            budgeter_config_repo.clone(),
+        );
+        let app = get_expense_association_routes(expense_association_service);
+        Self {
+            expense_association_repo,
+            budgeter_config_repo,
+            app,
+        }
+    }
+
+    pub(crate) fn app(&self) -> Router {
+        self.app.clone()
+    }
+
+    pub(crate) fn into_app(self) -> Router {
+        self.app
+    }
+
+    pub(crate) async fn set_budgeter(&self, budgeter_id: Uuid) {
+        let budgeter = BudgeterConfig {
+            id: budgeter_id,
+            ..Faker.fake()
+        };
+        self.budgeter_config_repo.update(&budgeter).await.unwrap();
+    }
+
+    /// Also saves the budgeters of the associations.
+    pub(crate) async fn set_expenses_association(
+        &self,
+        expenses_association: &[ExpenseAssociation],
+    ) {
+        for a in expenses_association {
+            if let Some(budgeter_id) = a.budgeter_id {
+                self.set_budgeter(budgeter_id).await;
+            }
+            self.expense_association_repo.update(a).await.unwrap();
+        }
+    }
+
+    pub(crate) async fn get_expense_association(&self, id: Uuid) -> DbResult<ExpenseAssociation> {
+        self.expense_association_repo.get(id).await
+    }
+}
@@ -5,7 +5,7 @@ use datamize_domain::{
     db::{DbResult, ExpenseCategorizationRepo},
     ExpenseCategorization, Uuid,
 };
-use db_sqlite::budget_template::SqliteExpenseCategorizationRepo;
+use db_sqlite::budget_template::{SqliteBudgeterConfigRepo, SqliteExpenseCategorizationRepo};
 use sqlx::SqlitePool;
 
 use crate::{
@@ -22,8 +22,10 @@ impl TestContext {
     pub(crate) fn setup(pool: SqlitePool) -> Self {
         let expense_categorization_repo = SqliteExpenseCategorizationRepo::new_arced(pool.clone());
 
-        let expense_categorization_service =
-            ExpenseCategorizationService::new_arced(expense_categorization_repo.clone());
+        let expense_categorization_service = ExpenseCategorizationService::new_arced(
+            expense_categorization_repo.clone(),
+            SqliteBudgeterConfigRepo::new_arced(pool.clone()),
+        );
         let app = get_expense_categorization_routes(expense_categorization_service);
         Self {
             expense_categorization_repo,
@@ -1,5 +1,7 @@
 mod budgeters;
 mod details;
+mod expenses_association;
 mod expenses_categorization;
+mod projection;
 mod summary;
 mod transactions;
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use chrono::NaiveDate;
+use datamize_domain::{async_trait, BudgetProjection, MonthTarget};
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use tower::ServiceExt;
+
+use crate::{
+    error::{AppError, DatamizeResult},
+    routes::api::budget_template::get_projection_routes,
+    services::budget_template::TemplateProjectionServiceExt,
+};
+
+#[tokio::test]
+async fn get_template_projection_success_with_no_query_params() {
+    #[derive(Clone)]
+    struct MockTemplateProjectionService {}
+    #[async_trait]
+    impl TemplateProjectionServiceExt for MockTemplateProjectionService {
+        async fn get_template_projection(
+            &self,
+            from: MonthTarget,
+            to: Option<MonthTarget>,
+            use_category_groups_as_sub_type: bool,
+        ) -> DatamizeResult<BudgetProjection> {
+            assert_eq!(from, MonthTarget::Current);
+            assert_eq!(to, None);
+            assert_eq!(use_category_groups_as_sub_type, true);
+            Ok(BudgetProjection::default())
+        }
+    }
+    let template_projection_service = Arc::new(MockTemplateProjectionService {});
+
+    let app = get_projection_routes(template_projection_service);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/projection")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let body: BudgetProjection = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body, BudgetProjection::default());
+}
+
+#[tokio::test]
+async fn get_template_projection_success_with_range_query_params() {
+    #[derive(Clone)]
+    struct MockTemplateProjectionService {}
+    #[async_trait]
+    impl TemplateProjectionServiceExt for MockTemplateProjectionService {
+        async fn get_template_projection(
+            &self,
+            from: MonthTarget,
+            to: Option<MonthTarget>,
+            use_category_groups_as_sub_type: bool,
+        ) -> DatamizeResult<BudgetProjection> {
+            assert_eq!(
+                from,
+                MonthTarget::Month(NaiveDate::from_ymd_opt(2024, 5, 1).unwrap())
+            );
+            assert_eq!(
+                to,
+                Some(MonthTarget::Month(
+                    NaiveDate::from_ymd_opt(2025, 4, 1).unwrap()
+                ))
+            );
+            assert_eq!(use_category_groups_as_sub_type, false);
+            Ok(BudgetProjection::default())
+        }
+    }
+    let template_projection_service = Arc::new(MockTemplateProjectionService {});
+
+    let app = get_projection_routes(template_projection_service);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/projection?from=2024-05&to=2025-04&use_category_groups_as_sub_type=false")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn get_template_projection_error_400_with_invalid_range() {
+    #[derive(Clone)]
+    struct MockTemplateProjectionService {}
+    #[async_trait]
+    impl TemplateProjectionServiceExt for MockTemplateProjectionService {
+        async fn get_template_projection(
+            &self,
+            _from: MonthTarget,
+            _to: Option<MonthTarget>,
+            _use_category_groups_as_sub_type: bool,
+        ) -> DatamizeResult<BudgetProjection> {
+            Err(AppError::InvalidProjectionRange)
+        }
+    }
+    let template_projection_service = Arc::new(MockTemplateProjectionService {});
+
+    let app = get_projection_routes(template_projection_service);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/projection?from=2024-05&to=2024-04")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn get_template_projection_error_400_with_unsupported_query_param_value() {
+    #[derive(Clone)]
+    struct MockTemplateProjectionService {}
+    #[async_trait]
+    impl TemplateProjectionServiceExt for MockTemplateProjectionService {
+        async fn get_template_projection(
+            &self,
+            _from: MonthTarget,
+            _to: Option<MonthTarget>,
+            _use_category_groups_as_sub_type: bool,
+        ) -> DatamizeResult<BudgetProjection> {
+            Ok(BudgetProjection::default())
+        }
+    }
+    let template_projection_service = Arc::new(MockTemplateProjectionService {});
+
+    let app = get_projection_routes(template_projection_service);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/projection?from=2024-5-1")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -4,6 +4,7 @@ use axum::{
     body::Body,
     http::{Request, StatusCode},
 };
+use chrono::NaiveDate;
 use datamize_domain::{async_trait, BudgetSummary, MonthTarget};
 use http_body_util::BodyExt;
 use pretty_assertions::assert_eq;
@@ -86,6 +87,40 @@ async fn get_template_summary_success_with_query_params() {
     assert_eq!(body, BudgetSummary::default());
 }
 
+#[tokio::test]
+async fn get_template_summary_success_with_year_month_query_param() {
+    #[derive(Clone)]
+    struct MockTemplateSummaryService {}
+    #[async_trait]
+    impl TemplateSummaryServiceExt for MockTemplateSummaryService {
+        async fn get_template_summary(
+            &self,
+            month: MonthTarget,
+            _use_category_groups_as_sub_type: bool,
+        ) -> DatamizeResult<BudgetSummary> {
+            assert_eq!(
+                month,
+                MonthTarget::Month(NaiveDate::from_ymd_opt(2024, 5, 1).unwrap())
+            );
+            Ok(BudgetSummary::default())
+        }
+    }
+    let template_summary_service = Arc::new(MockTemplateSummaryService {});
+
+    let app = get_summary_routes(template_summary_service);
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/summary?month=2024-05")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
 #[tokio::test]
 async fn get_template_summary_error_400_with_unsupported_query_param_value() {
     #[derive(Clone)]
@@ -1,5 +1,5 @@
-use axum::extract::State;
-use datamize_domain::ScheduledTransactionsDistribution;
+use axum::extract::{Query, State};
+use datamize_domain::{ScheduledTransactionsDistribution, TemplateParams};
 
 use crate::{
     error::{AppJson, HttpJsonDatamizeResult},
@@ -7,12 +7,19 @@ use crate::{
 };
 
 /// Returns a budget template transactions, i.e. all the scheduled transactions in the upcoming 30 days.
+/// Can specify the month to get transactions from.
+/// /template/transactions?month=2024-05
+/// Possible values to pass in query params are `previous`, `next` or any month as `YYYY-MM`.
+/// When a month is specified, all the scheduled transactions occurring during that month are returned.
 pub async fn template_transactions(
     State(template_transaction_service): State<DynTemplateTransactionService>,
+    template_params: Query<TemplateParams>,
 ) -> HttpJsonDatamizeResult<ScheduledTransactionsDistribution> {
+    let month = template_params.month.unwrap_or_default();
+
     Ok(AppJson(
         template_transaction_service
-            .get_template_transactions()
+            .get_template_transactions(month)
             .await?,
     ))
 }
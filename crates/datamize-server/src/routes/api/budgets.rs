@@ -0,0 +1,15 @@
+use axum::extract::State;
+
+use crate::{
+    config::YnabBudgetSettings,
+    error::{AppJson, HttpJsonDatamizeResult},
+    startup::AppState,
+};
+
+/// Returns the YNAB budgets that can be targeted with `/api/budgets/:budget_id`.
+#[tracing::instrument(skip_all)]
+pub async fn get_budgets(
+    State(app_state): State<AppState>,
+) -> HttpJsonDatamizeResult<Vec<YnabBudgetSettings>> {
+    Ok(AppJson(app_state.ynab_budgets))
+}
@@ -1,18 +1,71 @@
-use axum::Router;
+use axum::{routing::get, Router};
 
-use crate::startup::AppState;
+use crate::{services::backup::BackupService, startup::AppState};
 
+mod backup;
 mod balance_sheet;
 mod budget_providers;
 mod budget_template;
+mod budgets;
+mod scheduler;
 
+use backup::*;
 use balance_sheet::*;
 use budget_providers::*;
 use budget_template::*;
+use budgets::*;
+use scheduler::*;
 
 pub fn get_api_routes(app_state: &AppState) -> Router<AppState> {
-    Router::new()
-        .nest("/template", get_budget_template_routes(app_state))
+    let mut router = Router::new()
+        .route("/budgets", get(get_budgets))
+        .nest(
+            "/template",
+            get_budget_template_routes(app_state, app_state.default_budget_id()),
+        )
         .nest("/balance_sheet", get_balance_sheets_routes(app_state))
+        .nest(
+            "/backup",
+            get_backup_routes(BackupService::new_arced(app_state.repos.clone())),
+        )
         .nest("/budget_providers", get_budget_providers_routes(app_state))
+        .nest(
+            "/scheduler",
+            get_scheduler_routes(app_state.scheduler_service()),
+        );
+
+    for budget_id in budget_ids(app_state) {
+        router = router.nest(
+            &format!("/budgets/{}", budget_id),
+            get_budget_routes(app_state, budget_id),
+        );
+    }
+
+    router
+}
+
+/// Routes scoped to a single YNAB budget.
+fn get_budget_routes(app_state: &AppState, budget_id: &str) -> Router<AppState> {
+    Router::new()
+        .nest(
+            "/template",
+            get_budget_template_routes(app_state, budget_id),
+        )
+        .nest(
+            "/budget_providers/ynab",
+            get_ynab_budget_providers_routes(app_state, budget_id),
+        )
+}
+
+/// When no budget is configured, YNAB's last used budget is still reachable by its alias.
+fn budget_ids(app_state: &AppState) -> Vec<&str> {
+    if app_state.ynab_budgets.is_empty() {
+        vec![ynab::LAST_USED_BUDGET_ID]
+    } else {
+        app_state
+            .ynab_budgets
+            .iter()
+            .map(|b| b.id.as_str())
+            .collect()
+    }
 }
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Router,
+};
+use datamize_domain::{SchedulerRun, SchedulerRunsParams};
+
+use crate::{
+    error::{AppJson, HttpJsonDatamizeResult},
+    services::scheduler::DynSchedulerService,
+};
+
+pub fn get_scheduler_routes<S>(scheduler_service: DynSchedulerService) -> Router<S> {
+    Router::new()
+        .route("/runs", get(scheduler_runs))
+        .with_state(scheduler_service)
+}
+
+/// Returns the latest runs of the scheduled jobs, the most recent first.
+/// /scheduler/runs?limit=10 only returns the 10 latest ones.
+#[tracing::instrument(skip_all)]
+pub async fn scheduler_runs(
+    State(scheduler_service): State<DynSchedulerService>,
+    params: Query<SchedulerRunsParams>,
+) -> HttpJsonDatamizeResult<Vec<SchedulerRun>> {
+    Ok(AppJson(
+        scheduler_service
+            .get_runs(params.limit.unwrap_or(50))
+            .await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests;
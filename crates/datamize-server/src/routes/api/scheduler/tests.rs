@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Router,
+};
+use chrono::{Duration, Utc};
+use datamize_domain::{
+    async_trait, db::DynRepoRegistry, MonthNum, ResourcesToRefresh, ScheduledJob, SchedulerRun,
+    Uuid,
+};
+use db_memory::MemoryRepoRegistry;
+use http_body_util::BodyExt;
+use pretty_assertions::assert_eq;
+use tower::ServiceExt;
+
+use super::get_scheduler_routes;
+use crate::{
+    config::RefreshSchedule,
+    error::DatamizeResult,
+    services::{
+        balance_sheet::{RefreshFinResServiceExt, RolloverService},
+        scheduler::SchedulerService,
+    },
+};
+
+struct NoRefresh;
+
+#[async_trait]
+impl RefreshFinResServiceExt for NoRefresh {
+    async fn refresh_fin_res(
+        &self,
+        _resources_to_refresh: Option<ResourcesToRefresh>,
+    ) -> DatamizeResult<Vec<Uuid>> {
+        Ok(vec![])
+    }
+
+    async fn backfill_fin_res(
+        &self,
+        _year: i32,
+        _month: MonthNum,
+        _resources_to_refresh: Option<ResourcesToRefresh>,
+    ) -> DatamizeResult<Vec<Uuid>> {
+        Ok(vec![])
+    }
+}
+
+fn app(repos: &DynRepoRegistry) -> Router {
+    get_scheduler_routes(SchedulerService::new_arced(
+        RolloverService::new_arced(repos.fin_res_repo(), repos.month_repo(), repos.year_repo()),
+        Arc::new(NoRefresh),
+        repos.scheduler_run_repo(),
+        RefreshSchedule::Daily,
+    ))
+}
+
+async fn get_runs(repos: &DynRepoRegistry, uri: &str) -> Vec<SchedulerRun> {
+    let response = app(repos)
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn returns_the_latest_runs_first() {
+    let repos: DynRepoRegistry = MemoryRepoRegistry::new_arced();
+    let now = Utc::now();
+    let mut runs = vec![];
+    for (hours_ago, job, succeeded) in [
+        (2, ScheduledJob::Rollover, true),
+        (1, ScheduledJob::Refresh, false),
+        (0, ScheduledJob::Refresh, true),
+    ] {
+        let started_at = now - Duration::hours(hours_ago);
+        let run = SchedulerRun {
+            id: Uuid::new_v4(),
+            job,
+            started_at,
+            finished_at: started_at + Duration::seconds(3),
+            succeeded,
+            details: "done".to_owned(),
+        };
+        repos.scheduler_run_repo().add(&run).await.unwrap();
+        runs.insert(0, run);
+    }
+
+    assert_eq!(get_runs(&repos, "/runs").await, runs);
+    assert_eq!(get_runs(&repos, "/runs?limit=1").await, runs[..1]);
+}
+
+#[tokio::test]
+async fn returns_400_when_limit_is_invalid() {
+    let repos: DynRepoRegistry = MemoryRepoRegistry::new_arced();
+
+    let response = app(&repos)
+        .oneshot(
+            Request::builder()
+                .uri("/runs?limit=-1")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
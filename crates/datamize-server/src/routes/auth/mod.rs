@@ -0,0 +1,13 @@
+mod ynab;
+
+use axum::Router;
+
+use crate::services::budget_providers::DynYnabOAuthService;
+
+use self::ynab::*;
+
+pub fn get_auth_routes<S: Clone + Send + Sync + 'static>(
+    ynab_oauth_service: DynYnabOAuthService,
+) -> Router<S> {
+    Router::new().nest("/ynab", get_ynab_auth_routes(ynab_oauth_service))
+}
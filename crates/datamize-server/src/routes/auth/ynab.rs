@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Query, State},
+    response::Redirect,
+    routing::get,
+    Router,
+};
+use serde::Deserialize;
+
+use crate::{
+    config::DEFAULT_YNAB_MEMBER, error::DatamizeResult,
+    services::budget_providers::DynYnabOAuthService,
+};
+
+pub fn get_ynab_auth_routes<S>(ynab_oauth_service: DynYnabOAuthService) -> Router<S> {
+    Router::new()
+        .route("/login", get(ynab_login))
+        .route("/callback", get(ynab_callback))
+        .with_state(ynab_oauth_service)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct YnabLoginParams {
+    /// The household member connecting their YNAB account.
+    #[serde(default = "default_member")]
+    pub member: String,
+}
+
+fn default_member() -> String {
+    DEFAULT_YNAB_MEMBER.to_owned()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct YnabCallbackParams {
+    pub code: String,
+    pub state: String,
+}
+
+/// Redirects to YNAB's authorization page. Returns 404 when the member holds no budget.
+#[tracing::instrument(skip_all)]
+pub async fn ynab_login(
+    State(ynab_oauth_service): State<DynYnabOAuthService>,
+    Query(params): Query<YnabLoginParams>,
+) -> DatamizeResult<Redirect> {
+    let url = ynab_oauth_service.get_login_url(&params.member).await?;
+
+    Ok(Redirect::to(&url))
+}
+
+/// Where YNAB redirects once the user authorized the application.
+#[tracing::instrument(skip_all)]
+pub async fn ynab_callback(
+    State(ynab_oauth_service): State<DynYnabOAuthService>,
+    Query(params): Query<YnabCallbackParams>,
+) -> DatamizeResult<Redirect> {
+    ynab_oauth_service
+        .handle_callback(&params.code, &params.state)
+        .await?;
+
+    Ok(Redirect::to("/"))
+}
@@ -1,7 +1,9 @@
 mod api;
+mod auth;
 mod health_check;
 mod ui;
 
 pub use api::*;
+pub use auth::*;
 pub use health_check::*;
 pub use ui::*;
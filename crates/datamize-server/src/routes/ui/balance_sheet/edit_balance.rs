@@ -6,16 +6,21 @@ use axum::extract::{Path, State};
 use axum_extra::extract::Form;
 use datamize_domain::{BalancePerMonth, MonthNum, Uuid, YearlyBalances};
 use serde::Deserialize;
+use ynab::{CurrencyFormat, Milliunits};
 
 use crate::{
     error::DatamizeResult,
-    routes::ui::{num_to_currency, num_to_currency_rounded},
+    routes::ui::{
+        num_to_currency, num_to_currency_rounded, num_to_input, parse_amount_field,
+        BudgetCurrencyFormat,
+    },
     services::balance_sheet::DynFinResService,
 };
 
 pub async fn get(
     Path((year, month, fin_res_id)): Path<(i32, MonthNum, Uuid)>,
     State(fin_res_service): State<DynFinResService>,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
 ) -> DatamizeResult<impl IntoResponse> {
     let fin_res = fin_res_service.get_fin_res(fin_res_id).await?;
     let balance = fin_res.get_balance(year, month);
@@ -24,6 +29,7 @@ pub async fn get(
         year,
         month,
         balance,
+        currency_format,
     })
 }
 
@@ -33,19 +39,21 @@ struct YearDetailsBalanceFormTemplate {
     fin_res_id: Uuid,
     year: i32,
     month: MonthNum,
-    balance: Option<i64>,
+    balance: Option<Milliunits>,
+    currency_format: CurrencyFormat,
 }
 
 pub async fn put(
     Path((year, month, fin_res_id)): Path<(i32, MonthNum, Uuid)>,
     State(fin_res_service): State<DynFinResService>,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
     Form(payload): Form<Payload>,
 ) -> DatamizeResult<impl IntoResponse> {
+    let balance = parse_amount_field(payload.balance.as_deref(), &currency_format)?;
     let mut fin_res = fin_res_service.get_fin_res(fin_res_id).await?;
     // clear other balances to only update net assets of modified month
     fin_res.clear_all_balances();
-    let balance: BalancePerMonth =
-        BTreeMap::from([(month, payload.balance.map(|b| (b * 1000_f64) as i64))]);
+    let balance: BalancePerMonth = BTreeMap::from([(month, balance)]);
     fin_res.insert_balance_for_year(year, balance);
     let fin_res = fin_res_service.update_fin_res(fin_res).await?;
 
@@ -56,13 +64,14 @@ pub async fn put(
             year,
             month,
             balance: fin_res.get_balance(year, month),
+            currency_format,
         },
     ))
 }
 
 #[derive(Deserialize)]
 pub struct Payload {
-    balance: Option<f64>,
+    balance: Option<String>,
 }
 
 #[derive(Template)]
@@ -71,5 +80,6 @@ struct YearDetailsSingleBalanceTemplate {
     fin_res_id: Uuid,
     year: i32,
     month: MonthNum,
-    balance: Option<i64>,
+    balance: Option<Milliunits>,
+    currency_format: CurrencyFormat,
 }
@@ -11,10 +11,11 @@ use chrono::{Datelike, Local};
 use datamize_domain::{BalancePerMonth, FinancialResourceYearly, Uuid, YearQuery, YearlyBalances};
 use http::HeaderMap;
 use serde_json::json;
+use ynab::CurrencyFormat;
 
 use crate::{
     error::DatamizeResult,
-    routes::ui::num_to_currency,
+    routes::ui::{num_to_currency, BudgetCurrencyFormat},
     services::{
         balance_sheet::{DynFinResService, DynYearService},
         budget_providers::{DynExternalAccountService, DynYnabAccountService},
@@ -33,6 +34,7 @@ pub async fn get(
             DynYearService,
         ),
     >,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
 ) -> DatamizeResult<impl IntoResponse> {
     let fin_res = fin_res_service.get_fin_res(fin_res_id).await?;
     let year = param.map_or(Local::now().date_naive().year(), |p| p.year);
@@ -48,15 +50,17 @@ pub async fn get(
             .map_or(false, |accounts| accounts.contains(&a.id))
     });
 
-    let mut external_accounts: Vec<datamize_domain::ExternalAccount> =
-        external_account_service.get_all_external_accounts().await?;
+    let mut external_accounts: Vec<datamize_domain::ExternalAccountStatus> =
+        external_account_service
+            .get_external_account_statuses()
+            .await?;
 
     external_accounts.retain(|a| {
         fin_res
             .base
             .external_account_ids
             .clone()
-            .map_or(false, |accounts| accounts.contains(&a.id))
+            .map_or(false, |accounts| accounts.contains(&a.account.id))
     });
 
     Ok(FinancialResourceTemplate {
@@ -66,6 +70,7 @@ pub async fn get(
         ynab_accounts,
         external_accounts,
         years: year_service.get_all_years_num().await?,
+        currency_format,
     })
 }
 
@@ -77,7 +82,8 @@ struct FinancialResourceTemplate {
     years: Vec<i32>,
     balances: Option<BalancePerMonth>,
     ynab_accounts: Vec<ynab::Account>,
-    external_accounts: Vec<datamize_domain::ExternalAccount>,
+    external_accounts: Vec<datamize_domain::ExternalAccountStatus>,
+    currency_format: CurrencyFormat,
 }
 
 pub async fn delete(
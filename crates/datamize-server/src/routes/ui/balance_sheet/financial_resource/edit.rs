@@ -13,9 +13,11 @@ use datamize_domain::{
     ResourceCategory, ResourceCategoryOption, Uuid, YearQuery, YearlyBalances,
 };
 use serde::Deserialize;
+use ynab::CurrencyFormat;
 
 use crate::{
     error::DatamizeResult,
+    routes::ui::{num_to_input, parse_amount_field, BudgetCurrencyFormat},
     services::{
         balance_sheet::{DynFinResService, DynYearService},
         budget_providers::{DynExternalAccountService, DynYnabAccountService},
@@ -35,6 +37,7 @@ struct FinancialResourceFormTemplate {
     selected_external_accounts: Vec<Uuid>,
     years: Vec<i32>,
     error: Option<String>,
+    currency_format: CurrencyFormat,
 }
 
 impl FinancialResourceFormTemplate {
@@ -45,6 +48,7 @@ impl FinancialResourceFormTemplate {
         ynab_account_service: DynYnabAccountService,
         external_account_service: DynExternalAccountService,
         year_service: DynYearService,
+        currency_format: CurrencyFormat,
     ) -> DatamizeResult<Self> {
         let balances = fin_res.get_balance_for_year(year);
         let ynab_accounts: Vec<ynab::Account> =
@@ -72,6 +76,7 @@ impl FinancialResourceFormTemplate {
             selected_external_accounts,
             years: year_service.get_all_years_num().await?,
             error,
+            currency_format,
         })
     }
 }
@@ -87,6 +92,7 @@ pub async fn get(
             DynYearService,
         ),
     >,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
 ) -> DatamizeResult<impl IntoResponse> {
     let fin_res = fin_res_service.get_fin_res(fin_res_id).await?;
     let year = param.map_or(Local::now().date_naive().year(), |p| p.year);
@@ -98,6 +104,7 @@ pub async fn get(
         ynab_account_service,
         external_account_service,
         year_service,
+        currency_format,
     )
     .await
 }
@@ -113,8 +120,10 @@ pub async fn put(
             DynYearService,
         ),
     >,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
     Form(payload): Form<Payload>,
 ) -> DatamizeResult<impl IntoResponse> {
+    let balances = payload.balances(&currency_format)?;
     let mut fin_res = FinancialResourceYearly {
         base: BaseFinancialResource {
             id: fin_res_id,
@@ -127,47 +136,6 @@ pub async fn put(
         },
         balances: Default::default(),
     };
-    let balances: BalancePerMonth = BTreeMap::from([
-        (
-            MonthNum::January,
-            payload.january.map(|b| (b * 1000_f64) as i64),
-        ),
-        (
-            MonthNum::February,
-            payload.february.map(|b| (b * 1000_f64) as i64),
-        ),
-        (
-            MonthNum::March,
-            payload.march.map(|b| (b * 1000_f64) as i64),
-        ),
-        (
-            MonthNum::April,
-            payload.april.map(|b| (b * 1000_f64) as i64),
-        ),
-        (MonthNum::May, payload.may.map(|b| (b * 1000_f64) as i64)),
-        (MonthNum::June, payload.june.map(|b| (b * 1000_f64) as i64)),
-        (MonthNum::July, payload.july.map(|b| (b * 1000_f64) as i64)),
-        (
-            MonthNum::August,
-            payload.august.map(|b| (b * 1000_f64) as i64),
-        ),
-        (
-            MonthNum::September,
-            payload.september.map(|b| (b * 1000_f64) as i64),
-        ),
-        (
-            MonthNum::October,
-            payload.october.map(|b| (b * 1000_f64) as i64),
-        ),
-        (
-            MonthNum::November,
-            payload.november.map(|b| (b * 1000_f64) as i64),
-        ),
-        (
-            MonthNum::December,
-            payload.december.map(|b| (b * 1000_f64) as i64),
-        ),
-    ]);
     let year = param.map_or(Local::now().date_naive().year(), |p| p.year);
     fin_res.insert_balance_for_year(year, balances.clone());
 
@@ -184,6 +152,7 @@ pub async fn put(
             ynab_account_service,
             external_account_service,
             year_service,
+            currency_format,
         )
         .await?
         .into_response()),
@@ -198,16 +167,71 @@ pub struct Payload {
     resource_type: String,
     ynab_account_ids: Option<Vec<Uuid>>,
     external_account_ids: Option<Vec<Uuid>>,
-    january: Option<f64>,
-    february: Option<f64>,
-    march: Option<f64>,
-    april: Option<f64>,
-    may: Option<f64>,
-    june: Option<f64>,
-    july: Option<f64>,
-    august: Option<f64>,
-    september: Option<f64>,
-    october: Option<f64>,
-    november: Option<f64>,
-    december: Option<f64>,
+    january: Option<String>,
+    february: Option<String>,
+    march: Option<String>,
+    april: Option<String>,
+    may: Option<String>,
+    june: Option<String>,
+    july: Option<String>,
+    august: Option<String>,
+    september: Option<String>,
+    october: Option<String>,
+    november: Option<String>,
+    december: Option<String>,
+}
+
+impl Payload {
+    fn balances(&self, currency_format: &CurrencyFormat) -> DatamizeResult<BalancePerMonth> {
+        Ok(BTreeMap::from([
+            (
+                MonthNum::January,
+                parse_amount_field(self.january.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::February,
+                parse_amount_field(self.february.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::March,
+                parse_amount_field(self.march.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::April,
+                parse_amount_field(self.april.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::May,
+                parse_amount_field(self.may.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::June,
+                parse_amount_field(self.june.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::July,
+                parse_amount_field(self.july.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::August,
+                parse_amount_field(self.august.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::September,
+                parse_amount_field(self.september.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::October,
+                parse_amount_field(self.october.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::November,
+                parse_amount_field(self.november.as_deref(), currency_format)?,
+            ),
+            (
+                MonthNum::December,
+                parse_amount_field(self.december.as_deref(), currency_format)?,
+            ),
+        ]))
+    }
 }
@@ -6,28 +6,13 @@ use axum::{
     routing::{get, post},
     Router,
 };
-use db_postgres::{
-    balance_sheet::{
-        PostgresFinResRepo, PostgresMonthRepo, PostgresSavingRateRepo, PostgresYearRepo,
-    },
-    budget_providers::{
-        external::PostgresExternalAccountRepo,
-        ynab::{PostgresYnabAccountRepo, PostgresYnabTransactionRepo},
-    },
-};
-use db_redis::{
-    balance_sheet::resource::RedisFinResOrderRepo,
-    budget_providers::{
-        external::RedisEncryptionKeyRepo,
-        ynab::{RedisYnabAccountMetaRepo, RedisYnabTransactionMetaRepo},
-    },
-};
 
 use crate::{
     services::{
         balance_sheet::{
             DynFinResService, DynMonthService, DynRefreshFinResService, DynYearService,
-            FinResService, MonthService, RefreshFinResService, SavingRateService, YearService,
+            FinResService, MonthService, RefreshFinResService, RolloverService, SavingRateService,
+            YearService,
         },
         budget_providers::{
             DynExternalAccountService, DynYnabAccountService, ExternalAccountService,
@@ -40,10 +25,11 @@ use crate::{
 pub fn get_balance_sheets_routes<S: Clone + Send + Sync + 'static>(
     app_state: &AppState,
 ) -> Router<S> {
-    let year_repo = PostgresYearRepo::new_arced(app_state.db_conn_pool.clone());
-    let month_repo = PostgresMonthRepo::new_arced(app_state.db_conn_pool.clone());
-    let fin_res_repo = PostgresFinResRepo::new_arced(app_state.db_conn_pool.clone());
-    let fin_res_order_repo = RedisFinResOrderRepo::new_arced(app_state.redis_conn_pool.clone());
+    let budget_id = app_state.default_budget_id();
+    let year_repo = app_state.repos.year_repo();
+    let month_repo = app_state.repos.month_repo();
+    let fin_res_repo = app_state.repos.fin_res_repo();
+    let fin_res_order_repo = app_state.repos.fin_res_order_repo();
     let year_service = YearService::new_arced(year_repo.clone(), month_repo.clone());
     let month_service = MonthService::new_arced(month_repo.clone());
     let fin_res_service = FinResService::new_arced(
@@ -52,37 +38,48 @@ pub fn get_balance_sheets_routes<S: Clone + Send + Sync + 'static>(
         year_repo.clone(),
         fin_res_order_repo,
     );
-    let saving_rate_repo = PostgresSavingRateRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_transaction_repo =
-        PostgresYnabTransactionRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_transaction_meta_repo =
-        RedisYnabTransactionMetaRepo::new_arced(app_state.redis_conn_pool.clone());
+    let saving_rate_repo = app_state.repos.saving_rate_repo();
+    let ynab_transaction_repo = app_state.repos.ynab_transaction_repo(budget_id);
+    let ynab_transaction_meta_repo = app_state.repos.ynab_transaction_meta_repo(budget_id);
     let transaction_service = TransactionService::new_arced(
         ynab_transaction_repo,
         ynab_transaction_meta_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
     let _saving_rate_service = SavingRateService::new_arced(saving_rate_repo, transaction_service);
-    let external_account_repo =
-        PostgresExternalAccountRepo::new_arced(app_state.db_conn_pool.clone());
-    let encryption_key_repo = RedisEncryptionKeyRepo::new_arced(app_state.redis_conn_pool.clone());
-    let external_acount_service =
-        ExternalAccountService::new_arced(external_account_repo, encryption_key_repo);
+    let external_account_repo = app_state.repos.external_account_repo();
+    let encryption_key_repo = app_state.repos.encryption_key_repo();
+    let external_account_refresh_repo = app_state.repos.external_account_refresh_repo();
+    let external_account_balance_repo = app_state.repos.external_account_balance_repo();
+    let external_acount_service = ExternalAccountService::new_arced(
+        external_account_repo,
+        encryption_key_repo,
+        external_account_refresh_repo,
+        external_account_balance_repo,
+        app_state.pending_challenges.clone(),
+        app_state.scraper_registry.clone(),
+        app_state.webdriver_location.clone(),
+    );
+    let rollover_service =
+        RolloverService::new_arced(fin_res_repo.clone(), month_repo.clone(), year_repo.clone());
     let refresh_fin_res_service = RefreshFinResService::new_arced(
         fin_res_repo,
         month_repo,
         year_repo,
+        rollover_service,
         external_acount_service.clone(),
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
 
-    let ynab_account_repo = PostgresYnabAccountRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_account_meta_repo =
-        RedisYnabAccountMetaRepo::new_arced(app_state.redis_conn_pool.clone());
+    let ynab_account_repo = app_state.repos.ynab_account_repo(budget_id);
+    let ynab_account_meta_repo = app_state.repos.ynab_account_meta_repo(budget_id);
     let ynab_account_service = YnabAccountService::new_arced(
         ynab_account_repo,
         ynab_account_meta_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
 
     Router::new()
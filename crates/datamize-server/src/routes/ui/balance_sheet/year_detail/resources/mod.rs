@@ -6,16 +6,18 @@ use axum::extract::{Path, State};
 use axum_extra::extract::Form;
 use datamize_domain::{FinancialResourceYearly, ResourceCategory, Uuid, YearlyBalances};
 use serde::Deserialize;
+use ynab::CurrencyFormat;
 
 use crate::{
     error::DatamizeResult,
-    routes::ui::{num_to_currency, num_to_currency_rounded},
+    routes::ui::{num_to_currency, num_to_currency_rounded, BudgetCurrencyFormat},
     services::balance_sheet::DynFinResService,
 };
 
 pub async fn get(
     Path((year, category)): Path<(i32, ResourceCategory)>,
     State(fin_res_service): State<DynFinResService>,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
 ) -> DatamizeResult<impl IntoResponse> {
     let resources = fin_res_service
         .get_from_year_and_category(year, &category)
@@ -25,6 +27,7 @@ pub async fn get(
         year,
         category,
         resources,
+        currency_format,
     })
 }
 
@@ -32,6 +35,7 @@ pub async fn get(
 pub async fn post(
     Path((year, category)): Path<(i32, ResourceCategory)>,
     State(fin_res_service): State<DynFinResService>,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
     Form(payload): Form<Payload>,
 ) -> DatamizeResult<impl IntoResponse> {
     fin_res_service
@@ -46,6 +50,7 @@ pub async fn post(
         year,
         category,
         resources,
+        currency_format,
     })
 }
 
@@ -61,4 +66,5 @@ struct ResourceRowsTemplate {
     year: i32,
     category: ResourceCategory,
     resources: Vec<FinancialResourceYearly>,
+    currency_format: CurrencyFormat,
 }
@@ -2,16 +2,21 @@ use askama::Template;
 use askama_axum::IntoResponse;
 use axum::extract::{Path, State};
 use datamize_domain::{ResourceCategory, YearlyBalances};
+use ynab::CurrencyFormat;
 
 use crate::{
     error::DatamizeResult,
-    routes::ui::{balance_sheet::year_detail::TotalRow, num_to_currency, num_to_currency_rounded},
+    routes::ui::{
+        balance_sheet::year_detail::TotalRow, num_to_currency, num_to_currency_rounded,
+        BudgetCurrencyFormat,
+    },
     services::balance_sheet::DynFinResService,
 };
 
 pub async fn get(
     Path((year, category)): Path<(i32, ResourceCategory)>,
     State(fin_res_service): State<DynFinResService>,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
 ) -> DatamizeResult<impl IntoResponse> {
     let resources = fin_res_service
         .get_from_year_and_category(year, &category)
@@ -31,11 +36,15 @@ pub async fn get(
         }
     }
 
-    Ok(YearDetailsTotalAssetsTemplate { total_row })
+    Ok(YearDetailsTotalAssetsTemplate {
+        total_row,
+        currency_format,
+    })
 }
 
 #[derive(Template)]
 #[template(path = "partials/year-details/total-row.html")]
 struct YearDetailsTotalAssetsTemplate {
     total_row: TotalRow,
+    currency_format: CurrencyFormat,
 }
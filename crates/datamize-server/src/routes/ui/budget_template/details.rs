@@ -6,8 +6,11 @@ use askama_axum::IntoResponse;
 use axum::extract::{Query, State};
 use datamize_domain::{ExpenseType, MonthTarget, TemplateParams};
 use itertools::Itertools;
+use ynab::{CurrencyFormat, Milliunits};
 
-use crate::routes::ui::{curr_month, next_month, num_to_currency, num_to_percentage, prev_month};
+use crate::routes::ui::{
+    curr_month, next_month, num_to_currency, num_to_percentage, prev_month, BudgetCurrencyFormat,
+};
 
 /// Returns a budget template details
 /// Can specify the month to get details from.
@@ -17,6 +20,7 @@ use crate::routes::ui::{curr_month, next_month, num_to_currency, num_to_percenta
 pub async fn template_details(
     State(template_detail_service): State<DynTemplateDetailService>,
     template_params: Query<TemplateParams>,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
 ) -> DatamizeResult<impl IntoResponse> {
     let use_category_groups_as_sub_type = template_params
         .use_category_groups_as_sub_type
@@ -171,6 +175,7 @@ pub async fn template_details(
         projected_income_left_over,
         income_left_over,
         total_income,
+        currency_format,
     })
 }
 
@@ -180,9 +185,10 @@ struct DetailsTemplate {
     month: MonthTarget,
     groups: Vec<Group>,
     total_row: Row,
-    projected_income_left_over: i64,
-    income_left_over: i64,
-    total_income: i64,
+    projected_income_left_over: Milliunits,
+    income_left_over: Milliunits,
+    total_income: Milliunits,
+    currency_format: CurrencyFormat,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -206,9 +212,9 @@ struct SubGroup {
 #[derive(Debug, Clone, Default)]
 struct Row {
     name: String,
-    budgeted: i64,
-    spent: i64,
-    difference: i64,
+    budgeted: Milliunits,
+    spent: Milliunits,
+    difference: Milliunits,
     proportion: f64,
     target_proportion: Option<f64>,
 }
@@ -0,0 +1,160 @@
+use askama::Template;
+use askama_axum::IntoResponse;
+use axum::{
+    extract::{Path, State},
+    response::Redirect,
+};
+use axum_extra::extract::Form;
+use datamize_domain::{BudgeterConfig, ExpenseCategorization, SplitStrategy, Uuid};
+use serde::Deserialize;
+
+use crate::{
+    error::DatamizeResult,
+    services::budget_template::{DynBudgeterService, DynExpenseCategorizationService},
+};
+
+pub async fn get(
+    Path(id): Path<Uuid>,
+    State((expense_categorization_service, budgeter_service)): State<(
+        DynExpenseCategorizationService,
+        DynBudgeterService,
+    )>,
+) -> DatamizeResult<impl IntoResponse> {
+    let expense_categorization = expense_categorization_service
+        .get_expense_categorization(id)
+        .await?;
+    let budgeters = budgeter_service.get_all_budgeters().await?;
+
+    Ok(EditExpenseSplitTemplate::new(
+        expense_categorization,
+        budgeters,
+        None,
+    ))
+}
+
+#[derive(Template)]
+#[template(path = "partials/expense-split/edit.html")]
+struct EditExpenseSplitTemplate {
+    id: Uuid,
+    name: String,
+    strategies: Vec<StrategyOption>,
+    budgeters: Vec<SplitBudgeter>,
+    error: Option<String>,
+}
+
+struct StrategyOption {
+    value: &'static str,
+    label: &'static str,
+    selected: bool,
+}
+
+struct SplitBudgeter {
+    id: Uuid,
+    name: String,
+    /// Whether the budgeter is the one paying everything with a single budgeter strategy.
+    single: bool,
+    percentage: u8,
+}
+
+impl EditExpenseSplitTemplate {
+    fn new(
+        expense_categorization: ExpenseCategorization,
+        budgeters: Vec<BudgeterConfig>,
+        error: Option<String>,
+    ) -> Self {
+        let split_strategy = &expense_categorization.split_strategy;
+        let strategies = [
+            ("proportional", "Proportional to salary"),
+            ("equal", "Equal"),
+            ("fixed_percentages", "Fixed percentages"),
+            ("single", "Single budgeter"),
+        ]
+        .into_iter()
+        .map(|(value, label)| StrategyOption {
+            value,
+            label,
+            selected: value == split_strategy.name(),
+        })
+        .collect();
+        let budgeters = budgeters
+            .into_iter()
+            .map(|b| SplitBudgeter {
+                single: matches!(
+                    split_strategy,
+                    SplitStrategy::Single { budgeter_id } if *budgeter_id == b.id
+                ),
+                percentage: match split_strategy {
+                    SplitStrategy::FixedPercentages { percentages } => {
+                        percentages.get(&b.id).copied().unwrap_or_default()
+                    }
+                    _ => 0,
+                },
+                id: b.id,
+                name: b.name,
+            })
+            .collect();
+
+        Self {
+            id: expense_categorization.id,
+            name: expense_categorization.name,
+            strategies,
+            budgeters,
+            error,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct Payload {
+    strategy: String,
+    single_budgeter_id: Option<Uuid>,
+    #[serde(rename = "budgeter", default)]
+    budgeters: Vec<Uuid>,
+    #[serde(rename = "percentage", default)]
+    percentages: Vec<u8>,
+}
+
+impl Payload {
+    fn into_split_strategy(self) -> SplitStrategy {
+        match (self.strategy.as_str(), self.single_budgeter_id) {
+            ("equal", _) => SplitStrategy::Equal,
+            ("fixed_percentages", _) => SplitStrategy::FixedPercentages {
+                percentages: self.budgeters.into_iter().zip(self.percentages).collect(),
+            },
+            ("single", Some(budgeter_id)) => SplitStrategy::Single { budgeter_id },
+            _ => SplitStrategy::Proportional,
+        }
+    }
+}
+
+pub async fn post(
+    Path(id): Path<Uuid>,
+    State((expense_categorization_service, budgeter_service)): State<(
+        DynExpenseCategorizationService,
+        DynBudgeterService,
+    )>,
+    Form(payload): Form<Payload>,
+) -> DatamizeResult<impl IntoResponse> {
+    let expense_categorization = ExpenseCategorization {
+        split_strategy: payload.into_split_strategy(),
+        ..expense_categorization_service
+            .get_expense_categorization(id)
+            .await?
+    };
+
+    match expense_categorization_service
+        .update_expense_categorization(expense_categorization.clone())
+        .await
+    {
+        Ok(_) => Ok(Redirect::to("/budget/summary").into_response()),
+        Err(e) => {
+            let budgeters = budgeter_service.get_all_budgeters().await?;
+            Ok(EditExpenseSplitTemplate::new(
+                expense_categorization,
+                budgeters,
+                Some(e.to_string()),
+            )
+            .into_response())
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use askama::Template;
+use askama_axum::IntoResponse;
+use axum::extract::State;
+use datamize_domain::{BudgeterConfig, ExpenseCategorization, SplitStrategy};
+
+use crate::{
+    error::DatamizeResult,
+    services::budget_template::{DynBudgeterService, DynExpenseCategorizationService},
+};
+
+pub async fn get(
+    State((expense_categorization_service, budgeter_service)): State<(
+        DynExpenseCategorizationService,
+        DynBudgeterService,
+    )>,
+) -> DatamizeResult<impl IntoResponse> {
+    let mut expenses_categorization = expense_categorization_service
+        .get_all_expenses_categorization()
+        .await?;
+    expenses_categorization.sort_by(|a, b| a.name.cmp(&b.name));
+    let budgeters = budgeter_service.get_all_budgeters().await?;
+
+    Ok(ExpenseSplitsTemplate {
+        expenses_categorization,
+        budgeters,
+    })
+}
+
+#[derive(Template)]
+#[template(path = "partials/expense-split/list.html")]
+struct ExpenseSplitsTemplate {
+    expenses_categorization: Vec<ExpenseCategorization>,
+    budgeters: Vec<BudgeterConfig>,
+}
+
+impl ExpenseSplitsTemplate {
+    fn describe(&self, split_strategy: &SplitStrategy) -> String {
+        let budgeter_name = |id| {
+            self.budgeters
+                .iter()
+                .find(|b| b.id == id)
+                .map(|b| b.name.as_str())
+                .unwrap_or("Unknown")
+        };
+
+        match split_strategy {
+            SplitStrategy::Proportional => "Proportional to salary".to_string(),
+            SplitStrategy::Equal => "Equal".to_string(),
+            SplitStrategy::FixedPercentages { percentages } => percentages
+                .iter()
+                .map(|(id, p)| format!("{} {}%", budgeter_name(*id), p))
+                .collect::<Vec<_>>()
+                .join(", "),
+            SplitStrategy::Single { budgeter_id } => {
+                format!("Only {}", budgeter_name(*budgeter_id))
+            }
+        }
+    }
+}
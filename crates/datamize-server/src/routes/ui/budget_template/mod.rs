@@ -1,20 +1,13 @@
 mod budgeter;
 mod details;
+mod expense_split;
 mod summary;
 #[cfg(test)]
 mod tests;
 mod transactions;
 
 use axum::{routing::get, Router};
-use db_postgres::{
-    budget_providers::ynab::{
-        PostgresYnabCategoryRepo, PostgresYnabPayeeRepo, PostgresYnabScheduledTransactionRepo,
-    },
-    budget_template::{PostgresBudgeterConfigRepo, PostgresExpenseCategorizationRepo},
-};
-use db_redis::budget_providers::ynab::{
-    RedisYnabCategoryMetaRepo, RedisYnabPayeeMetaRepo, RedisYnabScheduledTransactionMetaRepo,
-};
+use datamize_domain::SystemClock;
 use details::*;
 use summary::*;
 use transactions::*;
@@ -25,9 +18,10 @@ use crate::{
             CategoryService, DynYnabPayeeService, ScheduledTransactionService, YnabPayeeService,
         },
         budget_template::{
-            BudgeterService, DynBudgeterService, DynTemplateDetailService,
-            DynTemplateSummaryService, DynTemplateTransactionService, ExpenseCategorizationService,
-            TemplateDetailService, TemplateSummaryService, TemplateTransactionService,
+            BudgeterService, DynBudgeterService, DynExpenseCategorizationService,
+            DynTemplateDetailService, DynTemplateSummaryService, DynTemplateTransactionService,
+            ExpenseCategorizationService, TemplateDetailService, TemplateSummaryService,
+            TemplateTransactionService,
         },
     },
     startup::AppState,
@@ -36,65 +30,83 @@ use crate::{
 pub fn get_budget_template_routes<S: Clone + Send + Sync + 'static>(
     app_state: &AppState,
 ) -> Router<S> {
-    let ynab_category_repo = PostgresYnabCategoryRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_category_meta_repo =
-        RedisYnabCategoryMetaRepo::new_arced(app_state.redis_conn_pool.clone());
+    let budget_id = app_state.default_budget_id();
+    let ynab_category_repo = app_state.repos.ynab_category_repo(budget_id);
+    let ynab_category_meta_repo = app_state.repos.ynab_category_meta_repo(budget_id);
     let ynab_scheduled_transaction_repo =
-        PostgresYnabScheduledTransactionRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_scheduled_transaction_meta_repo =
-        RedisYnabScheduledTransactionMetaRepo::new_arced(app_state.redis_conn_pool.clone());
-    let expense_categorization_repo =
-        PostgresExpenseCategorizationRepo::new_arced(app_state.db_conn_pool.clone());
-    let budgeter_config_repo =
-        PostgresBudgeterConfigRepo::new_arced(app_state.db_conn_pool.clone());
+        app_state.repos.ynab_scheduled_transaction_repo(budget_id);
+    let ynab_scheduled_transaction_meta_repo = app_state
+        .repos
+        .ynab_scheduled_transaction_meta_repo(budget_id);
+    let expense_categorization_repo = app_state.repos.expense_categorization_repo();
+    let budgeter_config_repo = app_state.repos.budgeter_config_repo();
+    let expense_association_repo = app_state.repos.expense_association_repo();
+    let clock = SystemClock::new_arced();
     let category_service = CategoryService::new_arced(
         ynab_category_repo.clone(),
         ynab_category_meta_repo,
         expense_categorization_repo.clone(),
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
+        clock.clone(),
     );
     let scheduled_transaction_service = ScheduledTransactionService::new_arced(
         ynab_scheduled_transaction_repo,
         ynab_scheduled_transaction_meta_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
 
     let template_detail_service = TemplateDetailService::new_arced(
         category_service.clone(),
         scheduled_transaction_service.clone(),
         budgeter_config_repo.clone(),
+        expense_association_repo.clone(),
+        clock.clone(),
     );
 
     let template_summary_service = TemplateSummaryService::new_arced(
         category_service,
         scheduled_transaction_service.clone(),
         budgeter_config_repo.clone(),
+        expense_association_repo,
+        clock.clone(),
     );
 
     let template_transaction_service = TemplateTransactionService::new_arced(
         scheduled_transaction_service,
         ynab_category_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
+        clock,
     );
 
-    let budgeter_service = BudgeterService::new_arced(budgeter_config_repo);
+    let budgeter_service = BudgeterService::new_arced(budgeter_config_repo.clone());
 
-    let ynab_payee_repo = PostgresYnabPayeeRepo::new_arced(app_state.db_conn_pool.clone());
-    let ynab_payee_meta_repo = RedisYnabPayeeMetaRepo::new_arced(app_state.redis_conn_pool.clone());
+    let ynab_payee_repo = app_state.repos.ynab_payee_repo(budget_id);
+    let ynab_payee_meta_repo = app_state.repos.ynab_payee_meta_repo(budget_id);
     let ynab_payee_service = YnabPayeeService::new_arced(
         ynab_payee_repo,
         ynab_payee_meta_repo,
-        app_state.ynab_client.clone(),
+        app_state.ynab_client_of(budget_id),
+        budget_id,
     );
 
-    let _expense_categorization_service =
-        ExpenseCategorizationService::new_arced(expense_categorization_repo);
+    let expense_categorization_service =
+        ExpenseCategorizationService::new_arced(expense_categorization_repo, budgeter_config_repo);
 
     Router::new()
         .merge(get_detail_routes(template_detail_service))
         .merge(get_summary_routes(template_summary_service))
         .merge(get_transaction_routes(template_transaction_service))
-        .merge(get_budgeter_routes(budgeter_service, ynab_payee_service))
+        .merge(get_budgeter_routes(
+            budgeter_service.clone(),
+            ynab_payee_service,
+        ))
+        .merge(get_expense_split_routes(
+            expense_categorization_service,
+            budgeter_service,
+        ))
 }
 
 fn get_detail_routes<S>(template_detail_service: DynTemplateDetailService) -> Router<S> {
@@ -134,3 +146,16 @@ fn get_budgeter_routes<S>(
         )
         .with_state((budgeter_service, ynab_payee_service))
 }
+
+fn get_expense_split_routes<S>(
+    expense_categorization_service: DynExpenseCategorizationService,
+    budgeter_service: DynBudgeterService,
+) -> Router<S> {
+    Router::new()
+        .route("/expense_split", get(expense_split::list::get))
+        .route(
+            "/expense_split/:expense_categorization_id",
+            get(expense_split::edit::get).post(expense_split::edit::post),
+        )
+        .with_state((expense_categorization_service, budgeter_service))
+}
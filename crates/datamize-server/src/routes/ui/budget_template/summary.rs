@@ -7,10 +7,14 @@ use datamize_domain::{
     Budgeter, BudgeterExt, ComputedExpenses, MonthTarget, SalaryFragment, TemplateParams,
     TotalBudgeter,
 };
+use ynab::CurrencyFormat;
 
 use crate::{
     error::DatamizeResult,
-    routes::ui::{curr_month, next_month, num_to_currency, num_to_percentage, prev_month},
+    routes::ui::{
+        curr_month, next_month, num_to_currency, num_to_percentage, prev_month,
+        BudgetCurrencyFormat,
+    },
     services::budget_template::DynTemplateSummaryService,
 };
 
@@ -22,6 +26,7 @@ use crate::{
 pub async fn template_summary(
     State(template_summary_service): State<DynTemplateSummaryService>,
     template_params: Query<TemplateParams>,
+    BudgetCurrencyFormat(currency_format): BudgetCurrencyFormat,
 ) -> DatamizeResult<impl IntoResponse> {
     let use_category_groups_as_sub_type = template_params
         .use_category_groups_as_sub_type
@@ -39,6 +44,7 @@ pub async fn template_summary(
         month,
         budgeters,
         total_budgeter,
+        currency_format,
     })
 }
 
@@ -48,6 +54,7 @@ struct SummaryTemplate {
     month: MonthTarget,
     budgeters: Vec<Budgeter<ComputedExpenses>>,
     total_budgeter: TotalBudgeter<ComputedExpenses>,
+    currency_format: CurrencyFormat,
 }
 
 pub struct Salary {
@@ -56,7 +63,10 @@ pub struct Salary {
     dates: Vec<String>,
 }
 
-pub fn fragmented_salary(budgeter: &Budgeter<ComputedExpenses>) -> Vec<Salary> {
+pub fn fragmented_salary(
+    budgeter: &Budgeter<ComputedExpenses>,
+    currency_format: &CurrencyFormat,
+) -> Vec<Salary> {
     let mut map: HashMap<String, SalaryFragment> = HashMap::new();
     for payees in budgeter.fragmented_salary().values() {
         for payee in payees {
@@ -79,7 +89,7 @@ pub fn fragmented_salary(budgeter: &Budgeter<ComputedExpenses>) -> Vec<Salary> {
 
             Salary {
                 name: payee.payee_name.unwrap_or(String::from("")),
-                amount: num_to_currency(payee.payee_amount),
+                amount: num_to_currency(payee.payee_amount, currency_format),
                 dates,
             }
         })
@@ -1,15 +1,34 @@
-use currency_rs::{Currency, CurrencyOpts};
+use ynab::{CurrencyFormat, Milliunits};
 
-pub fn num_to_currency(num: i64) -> String {
-    let opts = CurrencyOpts::new().set_negative_pattern("(!#)");
-    Currency::new_float(num as f64 / 1000_f64, Some(opts)).format()
+use crate::error::DatamizeResult;
+
+pub fn num_to_currency(num: impl Into<Milliunits>, currency_format: &CurrencyFormat) -> String {
+    to_accounting(num.into().format(currency_format))
+}
+
+pub fn num_to_currency_rounded(
+    num: impl Into<Milliunits>,
+    currency_format: &CurrencyFormat,
+) -> String {
+    to_accounting(num.into().format_rounded(currency_format))
+}
+
+/// Formats an amount to be edited in a form, i.e. `1234.56` or `1234,56`, without the currency symbol
+/// nor group separators so `parse_amount_field` reads it back.
+pub fn num_to_input(num: impl Into<Milliunits>, currency_format: &CurrencyFormat) -> String {
+    num.into().format(&CurrencyFormat {
+        display_symbol: false,
+        group_separator: String::new(),
+        ..currency_format.clone()
+    })
 }
 
-pub fn num_to_currency_rounded(num: i64) -> String {
-    let opts = CurrencyOpts::new()
-        .set_negative_pattern("(!#)")
-        .set_precision(0);
-    Currency::new_float(num as f64 / 1000_f64, Some(opts)).format()
+/// Displays negative amounts between parentheses, i.e. `($1,234.56)`.
+fn to_accounting(formatted: String) -> String {
+    match formatted.strip_prefix('-') {
+        Some(abs) => format!("({})", abs),
+        None => formatted,
+    }
 }
 
 pub fn num_to_percentage(num: f64) -> String {
@@ -19,3 +38,69 @@ pub fn num_to_percentage(num: f64) -> String {
 pub fn num_to_percentage_f32(num: f32) -> String {
     format!("{:.2}%", num * 100_f32)
 }
+
+/// Reads an optional amount typed in a form with the budget's format, i.e. `1234.56` or `$1,234.56`.
+/// An empty field gives `None`.
+pub fn parse_amount_field(
+    amount: Option<&str>,
+    currency_format: &CurrencyFormat,
+) -> DatamizeResult<Option<Milliunits>> {
+    match amount {
+        Some(amount) if !amount.trim().is_empty() => {
+            Ok(Some(Milliunits::parse(amount, currency_format)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn german_format() -> CurrencyFormat {
+        CurrencyFormat {
+            iso_code: "EUR".to_string(),
+            example_format: "123.456,78".to_string(),
+            decimal_digits: 2,
+            decimal_separator: ",".to_string(),
+            symbol_first: false,
+            group_separator: ".".to_string(),
+            currency_symbol: "€".to_string(),
+            display_symbol: true,
+        }
+    }
+
+    #[test]
+    fn formats_with_the_budget_currency_format() {
+        let amount = Milliunits::new(-1234560);
+
+        assert_eq!(num_to_currency(amount, &german_format()), "(1.234,56€)");
+        assert_eq!(
+            num_to_currency_rounded(amount, &german_format()),
+            "(1.235€)"
+        );
+        assert_eq!(num_to_input(amount, &german_format()), "-1234,56");
+    }
+
+    #[test]
+    fn reads_back_amounts_of_form_inputs() {
+        let amount = Milliunits::new(1234560);
+        let input = num_to_input(amount, &german_format());
+
+        assert_eq!(
+            parse_amount_field(Some(&input), &german_format()).unwrap(),
+            Some(amount)
+        );
+        assert_eq!(
+            parse_amount_field(Some("1.234,56 €"), &german_format()).unwrap(),
+            Some(amount)
+        );
+        assert_eq!(
+            parse_amount_field(Some(" "), &german_format()).unwrap(),
+            None
+        );
+        assert!(parse_amount_field(Some("12,3456"), &german_format()).is_err());
+    }
+}
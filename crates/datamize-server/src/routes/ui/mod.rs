@@ -1,7 +1,13 @@
-use axum::{async_trait, extract::FromRequestParts, response::Redirect, routing::get, Router};
+use axum::{
+    async_trait, extract::FromRequestParts, response::Redirect, routing::get, Extension, Router,
+};
 use http::request::Parts;
+use ynab::CurrencyFormat;
 
-use crate::startup::AppState;
+use crate::{
+    services::budget_providers::{DynYnabCurrencyFormatService, YnabCurrencyFormatService},
+    startup::AppState,
+};
 
 mod balance_sheet;
 mod budget_providers;
@@ -16,11 +22,16 @@ use fmt::*;
 use utils::*;
 
 pub fn get_ui_routes(app_state: &AppState) -> Router<AppState> {
+    let budget_id = app_state.default_budget_id();
+    let currency_format_service: DynYnabCurrencyFormatService =
+        YnabCurrencyFormatService::new_arced(app_state.ynab_client_of(budget_id), budget_id);
+
     Router::new()
         .route("/", get(|| async { Redirect::to("/budget/summary") }))
         .nest("/budget", get_budget_template_routes(app_state))
         .nest("/balance_sheet", get_balance_sheets_routes(app_state))
-    // .nest("/budget_providers", get_budget_providers_routes(app_state))
+        // .nest("/budget_providers", get_budget_providers_routes(app_state))
+        .layer(Extension(currency_format_service))
 }
 
 /// Always `true`.
@@ -84,3 +95,27 @@ where
         }
     }
 }
+
+/// The currency format of the budget shown, to display and read amounts the way YNAB does.
+///
+/// This extractor will always return a value. If the UI routes were not given a
+/// `DynYnabCurrencyFormatService`, it will return the default format.
+#[derive(Debug, Clone)]
+pub struct BudgetCurrencyFormat(pub CurrencyFormat);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for BudgetCurrencyFormat
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        match parts.extensions.get::<DynYnabCurrencyFormatService>() {
+            Some(currency_format_service) => Ok(BudgetCurrencyFormat(
+                currency_format_service.get_currency_format().await,
+            )),
+            None => Ok(BudgetCurrencyFormat(CurrencyFormat::default())),
+        }
+    }
+}
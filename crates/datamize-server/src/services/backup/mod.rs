@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datamize_domain::{
+    db::{DbError, DynRepoRegistry},
+    Backup, ExpenseCategorization, ResourceCategory, ResourcesOrder, BACKUP_VERSION,
+};
+
+use crate::error::{AppError, DatamizeResult};
+
+#[async_trait]
+pub trait BackupServiceExt: Send + Sync {
+    async fn backup(&self, include_encryption_key: bool) -> DatamizeResult<Backup>;
+    async fn restore(&self, backup: Backup) -> DatamizeResult<()>;
+}
+
+pub type DynBackupService = Arc<dyn BackupServiceExt>;
+
+/// Only goes through the repositories, so a backup taken from a backend can be restored in another one.
+pub struct BackupService {
+    pub repos: DynRepoRegistry,
+}
+
+impl BackupService {
+    pub fn new_arced(repos: DynRepoRegistry) -> Arc<Self> {
+        Arc::new(Self { repos })
+    }
+
+    async fn current_encryption_key(&self) -> DatamizeResult<Option<Vec<u8>>> {
+        match self.repos.encryption_key_repo().get().await {
+            Ok(key) if !key.is_empty() => Ok(Some(key)),
+            Ok(_) | Err(DbError::NotFound) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn is_empty(&self) -> DatamizeResult<bool> {
+        Ok(self.repos.year_repo().get_years_data().await?.is_empty()
+            && self
+                .repos
+                .fin_res_repo()
+                .get_from_all_years()
+                .await?
+                .is_empty()
+            && self.repos.saving_rate_repo().get_all().await?.is_empty()
+            && self
+                .repos
+                .budgeter_config_repo()
+                .get_all()
+                .await?
+                .is_empty()
+            && self
+                .repos
+                .expense_association_repo()
+                .get_all()
+                .await?
+                .is_empty()
+            && self
+                .repos
+                .external_account_repo()
+                .get_all()
+                .await?
+                .is_empty())
+    }
+}
+
+#[async_trait]
+impl BackupServiceExt for BackupService {
+    #[tracing::instrument(skip(self))]
+    async fn backup(&self, include_encryption_key: bool) -> DatamizeResult<Backup> {
+        let mut backup = Backup::new();
+
+        backup.years = self
+            .repos
+            .year_repo()
+            .get_years_starting_from(i32::MIN)
+            .await?;
+        backup.resources = self.repos.fin_res_repo().get_from_all_years().await?;
+
+        let fin_res_order_repo = self.repos.fin_res_order_repo();
+        let saving_rate_repo = self.repos.saving_rate_repo();
+        for year in &backup.years {
+            for category in [ResourceCategory::Asset, ResourceCategory::Liability] {
+                let order = fin_res_order_repo.get_order(year.year, &category).await?;
+                if !order.is_empty() {
+                    backup.resources_order.push(ResourcesOrder {
+                        year: year.year,
+                        category,
+                        order,
+                    });
+                }
+            }
+            backup
+                .saving_rates
+                .extend(saving_rate_repo.get_from_year(year.year).await?);
+        }
+
+        backup.budgeters = self.repos.budgeter_config_repo().get_all().await?;
+        backup.expenses_categorization = self.repos.expense_categorization_repo().get_all().await?;
+        backup.expenses_association = self.repos.expense_association_repo().get_all().await?;
+        let external_accounts = self.repos.external_account_repo().get_all().await?;
+        let external_account_balance_repo = self.repos.external_account_balance_repo();
+        let external_account_refresh_repo = self.repos.external_account_refresh_repo();
+        for account in &external_accounts {
+            backup.external_account_balances.extend(
+                external_account_balance_repo
+                    .get_all(account.id, None, None)
+                    .await?,
+            );
+            backup
+                .external_account_refreshes
+                .extend(external_account_refresh_repo.get_all(account.id).await?);
+        }
+        backup.external_accounts = external_accounts.into_iter().map(Into::into).collect();
+
+        if include_encryption_key {
+            backup.encryption_key = self.current_encryption_key().await?;
+        }
+
+        Ok(backup)
+    }
+
+    /// Restores in a database without any balance sheet, budgeter or external account,
+    /// so nothing already there is silently merged with the backup.
+    /// When a write fails, what was already restored is deleted so the restore can be tried again.
+    #[tracing::instrument(skip_all)]
+    async fn restore(&self, backup: Backup) -> DatamizeResult<()> {
+        if backup.version > BACKUP_VERSION {
+            return Err(AppError::UnsupportedBackupVersion(backup.version));
+        }
+
+        if !self.is_empty().await? {
+            return Err(AppError::DatabaseNotEmpty);
+        }
+
+        // Only set when no key is in use, otherwise it must be the same.
+        let current_encryption_key = self.current_encryption_key().await?;
+        let new_encryption_key = match (&backup.encryption_key, &current_encryption_key) {
+            (Some(encryption_key), Some(current)) if current != encryption_key => {
+                return Err(AppError::EncryptionKeyMismatch);
+            }
+            (Some(encryption_key), None) => Some(encryption_key.as_slice()),
+            // The passwords of the external accounts could never be decrypted.
+            (None, None) if !backup.external_accounts.is_empty() => {
+                return Err(AppError::MissingEncryptionKey);
+            }
+            _ => None,
+        };
+
+        let expenses_categorization = self.repos.expense_categorization_repo().get_all().await?;
+
+        if let Err(e) = self.write_backup(&backup, new_encryption_key).await {
+            tracing::error!(error = ?e, "failed to restore the backup, deleting what was restored");
+            self.undo_restore(
+                &backup,
+                &expenses_categorization,
+                new_encryption_key.is_some(),
+            )
+            .await;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+}
+
+impl BackupService {
+    async fn write_backup(
+        &self,
+        backup: &Backup,
+        new_encryption_key: Option<&[u8]>,
+    ) -> DatamizeResult<()> {
+        if let Some(encryption_key) = new_encryption_key {
+            self.repos.encryption_key_repo().set(encryption_key).await?;
+        }
+
+        let year_repo = self.repos.year_repo();
+        let month_repo = self.repos.month_repo();
+        for year in &backup.years {
+            year_repo.add(year).await?;
+            for month in &year.months {
+                month_repo.add(month, year.year).await?;
+            }
+        }
+
+        let fin_res_repo = self.repos.fin_res_repo();
+        for resource in &backup.resources {
+            fin_res_repo.update(resource).await?;
+        }
+
+        let fin_res_order_repo = self.repos.fin_res_order_repo();
+        for resources_order in &backup.resources_order {
+            fin_res_order_repo
+                .set_order(
+                    resources_order.year,
+                    &resources_order.category,
+                    &resources_order.order,
+                )
+                .await?;
+        }
+
+        let saving_rate_repo = self.repos.saving_rate_repo();
+        for saving_rate in &backup.saving_rates {
+            saving_rate_repo.update(saving_rate).await?;
+        }
+
+        let budgeter_config_repo = self.repos.budgeter_config_repo();
+        for budgeter in &backup.budgeters {
+            budgeter_config_repo.update(budgeter).await?;
+        }
+
+        self.repos
+            .expense_categorization_repo()
+            .update_all(&backup.expenses_categorization)
+            .await?;
+
+        let expense_association_repo = self.repos.expense_association_repo();
+        for expense_association in &backup.expenses_association {
+            expense_association_repo.update(expense_association).await?;
+        }
+
+        let external_account_repo = self.repos.external_account_repo();
+        for account in &backup.external_accounts {
+            external_account_repo.add(&account.clone().into()).await?;
+        }
+
+        let external_account_balance_repo = self.repos.external_account_balance_repo();
+        for balance in &backup.external_account_balances {
+            external_account_balance_repo.add(balance).await?;
+        }
+
+        let external_account_refresh_repo = self.repos.external_account_refresh_repo();
+        for refresh in &backup.external_account_refreshes {
+            external_account_refresh_repo.add(refresh).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The database was empty before the restore, so everything in the backup can be deleted.
+    /// Deleting the external accounts, budgeters and years also deletes what belongs to them.
+    /// Categorizations already existed, they get back the values they had.
+    async fn undo_restore(
+        &self,
+        backup: &Backup,
+        expenses_categorization: &[ExpenseCategorization],
+        restores_encryption_key: bool,
+    ) {
+        let mut results = vec![];
+
+        let external_account_repo = self.repos.external_account_repo();
+        for account in &backup.external_accounts {
+            results.push(external_account_repo.delete(account.id).await);
+        }
+
+        let expense_association_repo = self.repos.expense_association_repo();
+        for expense_association in &backup.expenses_association {
+            results.push(
+                expense_association_repo
+                    .delete(expense_association.id)
+                    .await,
+            );
+        }
+
+        results.push(
+            self.repos
+                .expense_categorization_repo()
+                .update_all(expenses_categorization)
+                .await,
+        );
+
+        let budgeter_config_repo = self.repos.budgeter_config_repo();
+        for budgeter in &backup.budgeters {
+            results.push(budgeter_config_repo.delete(budgeter.id).await);
+        }
+
+        let saving_rate_repo = self.repos.saving_rate_repo();
+        for saving_rate in &backup.saving_rates {
+            results.push(saving_rate_repo.delete(saving_rate.id).await);
+        }
+
+        let fin_res_order_repo = self.repos.fin_res_order_repo();
+        for resources_order in &backup.resources_order {
+            results.push(
+                fin_res_order_repo
+                    .set_order(resources_order.year, &resources_order.category, &[])
+                    .await,
+            );
+        }
+
+        let fin_res_repo = self.repos.fin_res_repo();
+        for resource in &backup.resources {
+            results.push(fin_res_repo.delete(resource.base.id).await);
+        }
+
+        let year_repo = self.repos.year_repo();
+        for year in &backup.years {
+            results.push(year_repo.delete(year.year).await);
+        }
+
+        if restores_encryption_key {
+            results.push(self.repos.encryption_key_repo().set(&[]).await);
+        }
+
+        for e in results.into_iter().filter_map(Result::err) {
+            tracing::error!(error = ?e, "failed to delete part of a restored backup");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,242 @@
+use chrono::{TimeZone, Utc};
+use datamize_domain::{
+    db::RepoRegistry, secrecy::Secret, BalanceSource, BaseFinancialResource, BudgeterConfig,
+    EncryptedPassword, ExpenseAssociation, ExpenseCategorization, ExternalAccountBalance,
+    ExternalAccountRefresh, FinancialResourceYearly, Month, MonthNum, SaveExpenseAssociation,
+    SavingRate, Uuid, WebScrapingAccount, Year, YearlyBalances,
+};
+use db_memory::MemoryRepoRegistry;
+use db_sqlite::SqliteRepoRegistry;
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use ynab::Milliunits;
+
+use super::*;
+
+async fn fill(repos: &dyn RepoRegistry) {
+    let mut year = Year::new(2023);
+    year.net_totals = Faker.fake();
+    repos.year_repo().add(&year).await.unwrap();
+    for month_num in [MonthNum::November, MonthNum::December] {
+        let mut month = Month::new(month_num, 2023);
+        month.net_totals = Faker.fake();
+        repos.month_repo().add(&month, 2023).await.unwrap();
+    }
+
+    let mut resource = FinancialResourceYearly {
+        base: Faker.fake::<BaseFinancialResource>(),
+        ..Default::default()
+    };
+    resource.insert_balance(2023, MonthNum::November, Milliunits::new(1000));
+    resource.insert_balance(2023, MonthNum::December, Milliunits::new(2000));
+    repos.fin_res_repo().update(&resource).await.unwrap();
+    repos
+        .fin_res_order_repo()
+        .set_order(2023, &ResourceCategory::Asset, &[resource.base.id])
+        .await
+        .unwrap();
+
+    let saving_rate = SavingRate {
+        year: 2023,
+        ..Faker.fake()
+    };
+    repos.saving_rate_repo().update(&saving_rate).await.unwrap();
+
+    let budgeter: BudgeterConfig = Faker.fake();
+    repos
+        .budgeter_config_repo()
+        .update(&budgeter)
+        .await
+        .unwrap();
+    let expense_categorization: ExpenseCategorization = Faker.fake();
+    repos
+        .expense_categorization_repo()
+        .update(&expense_categorization)
+        .await
+        .unwrap();
+    let expense_association = ExpenseAssociation::new(
+        Uuid::new_v4(),
+        SaveExpenseAssociation {
+            budgeter_id: Some(budgeter.id),
+            ..Faker.fake()
+        },
+    );
+    repos
+        .expense_association_repo()
+        .update(&expense_association)
+        .await
+        .unwrap();
+
+    repos.encryption_key_repo().set(b"key").await.unwrap();
+    let account = WebScrapingAccount {
+        id: Uuid::new_v4(),
+        name: Faker.fake(),
+        username: Faker.fake(),
+        encrypted_password: Secret::new(EncryptedPassword::new(b"encrypted".to_vec())),
+        ..Default::default()
+    };
+    repos.external_account_repo().add(&account).await.unwrap();
+
+    let observed_at = Utc.with_ymd_and_hms(2023, 12, 15, 0, 0, 0).unwrap();
+    repos
+        .external_account_balance_repo()
+        .add(&ExternalAccountBalance {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            observed_at,
+            balance: Milliunits::new(3000),
+            source: BalanceSource::Scrape,
+        })
+        .await
+        .unwrap();
+    repos
+        .external_account_refresh_repo()
+        .add(&ExternalAccountRefresh {
+            id: Uuid::new_v4(),
+            account_id: account.id,
+            refreshed_at: observed_at,
+            duration_ms: 1500,
+            succeeded: true,
+            balance: Some(Milliunits::new(3000)),
+            error: None,
+        })
+        .await
+        .unwrap();
+}
+
+/// Backups are compared without the order in which the backends return their rows.
+fn sorted(mut backup: Backup) -> Backup {
+    backup.resources.sort_by_key(|r| r.base.id);
+    backup.saving_rates.sort_by_key(|s| s.id);
+    backup.budgeters.sort_by_key(|b| b.id);
+    backup.expenses_categorization.sort_by_key(|e| e.id);
+    backup.expenses_association.sort_by_key(|e| e.id);
+    backup.external_accounts.sort_by_key(|a| a.id);
+    backup.external_account_balances.sort_by_key(|b| b.id);
+    backup.external_account_refreshes.sort_by_key(|r| r.id);
+    backup
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn restores_a_backup_in_another_backend(pool: SqlitePool) {
+    let sqlite_repos = SqliteRepoRegistry::new_arced(pool);
+    fill(sqlite_repos.as_ref()).await;
+    let backup = BackupService::new_arced(sqlite_repos)
+        .backup(true)
+        .await
+        .unwrap();
+    assert_eq!(backup.version, BACKUP_VERSION);
+    assert_eq!(backup.encryption_key.as_deref(), Some(b"key".as_slice()));
+    assert_eq!(backup.external_account_balances.len(), 1);
+    assert_eq!(backup.external_account_refreshes.len(), 1);
+
+    let memory_repos = MemoryRepoRegistry::new_arced();
+    let memory_service = BackupService::new_arced(memory_repos.clone());
+    memory_service.restore(backup.clone()).await.unwrap();
+
+    let restored = memory_service.backup(true).await.unwrap();
+    assert_eq!(
+        sorted(Backup {
+            created_at: backup.created_at,
+            ..restored
+        }),
+        sorted(backup)
+    );
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn deletes_what_was_restored_when_the_restore_fails(pool: SqlitePool) {
+    let memory_repos = MemoryRepoRegistry::new_arced();
+    fill(memory_repos.as_ref()).await;
+    let backup = BackupService::new_arced(memory_repos)
+        .backup(true)
+        .await
+        .unwrap();
+    let mut broken = backup.clone();
+    broken
+        .external_account_balances
+        .push(ExternalAccountBalance {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            ..backup.external_account_balances[0].clone()
+        });
+
+    let sqlite_repos = SqliteRepoRegistry::new_arced(pool);
+    let sqlite_service = BackupService::new_arced(sqlite_repos.clone());
+    sqlite_service.restore(broken).await.unwrap_err();
+
+    assert!(sqlite_service.is_empty().await.unwrap());
+    assert_eq!(sqlite_service.current_encryption_key().await.unwrap(), None);
+    sqlite_service.restore(backup).await.unwrap();
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn leaves_the_encryption_key_out_unless_asked(pool: SqlitePool) {
+    let repos = SqliteRepoRegistry::new_arced(pool);
+    fill(repos.as_ref()).await;
+
+    let backup = BackupService::new_arced(repos).backup(false).await.unwrap();
+
+    assert_eq!(backup.encryption_key, None);
+    assert_eq!(backup.external_accounts.len(), 1);
+    assert_eq!(backup.external_accounts[0].encrypted_password, b"encrypted");
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn refuses_to_restore_in_a_database_with_data(pool: SqlitePool) {
+    let repos = SqliteRepoRegistry::new_arced(pool);
+    fill(repos.as_ref()).await;
+    let service = BackupService::new_arced(repos);
+    let backup = service.backup(true).await.unwrap();
+
+    let err = service.restore(backup).await.unwrap_err();
+
+    assert!(matches!(err, AppError::DatabaseNotEmpty));
+}
+
+#[tokio::test]
+async fn refuses_backups_of_a_newer_version() {
+    let service = BackupService::new_arced(MemoryRepoRegistry::new_arced());
+    let backup = Backup {
+        version: BACKUP_VERSION + 1,
+        ..Backup::new()
+    };
+
+    let err = service.restore(backup).await.unwrap_err();
+
+    assert!(matches!(err, AppError::UnsupportedBackupVersion(_)));
+}
+
+#[tokio::test]
+async fn refuses_another_encryption_key() {
+    let repos = MemoryRepoRegistry::new_arced();
+    repos.encryption_key_repo().set(b"current").await.unwrap();
+    let service = BackupService::new_arced(repos.clone());
+    let backup = Backup {
+        encryption_key: Some(b"other".to_vec()),
+        ..Backup::new()
+    };
+
+    let err = service.restore(backup).await.unwrap_err();
+
+    assert!(matches!(err, AppError::EncryptionKeyMismatch));
+    assert_eq!(repos.encryption_key_repo().get().await.unwrap(), b"current");
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn refuses_external_accounts_without_encryption_key(pool: SqlitePool) {
+    let memory_repos = MemoryRepoRegistry::new_arced();
+    fill(memory_repos.as_ref()).await;
+    let backup = BackupService::new_arced(memory_repos)
+        .backup(false)
+        .await
+        .unwrap();
+
+    let sqlite_service = BackupService::new_arced(SqliteRepoRegistry::new_arced(pool));
+    let err = sqlite_service.restore(backup).await.unwrap_err();
+
+    assert!(matches!(err, AppError::MissingEncryptionKey));
+    assert!(sqlite_service.is_empty().await.unwrap());
+    assert_eq!(sqlite_service.current_encryption_key().await.unwrap(), None);
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datamize_domain::{ResourceCategory, Sheet, SheetCell};
+use rust_xlsxwriter::{Format, Workbook};
+
+use super::{DynFinResService, DynSavingRateService, DynYearService};
+use crate::error::DatamizeResult;
+
+#[async_trait]
+pub trait YearExportServiceExt: Send + Sync {
+    /// The balance sheet of the year followed by its saving rates.
+    async fn get_year_sheets(&self, year: i32) -> DatamizeResult<Vec<Sheet>>;
+}
+
+pub type DynYearExportService = Arc<dyn YearExportServiceExt>;
+
+pub struct YearExportService {
+    pub year_service: DynYearService,
+    pub fin_res_service: DynFinResService,
+    pub saving_rate_service: DynSavingRateService,
+}
+
+impl YearExportService {
+    pub fn new_arced(
+        year_service: DynYearService,
+        fin_res_service: DynFinResService,
+        saving_rate_service: DynSavingRateService,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            year_service,
+            fin_res_service,
+            saving_rate_service,
+        })
+    }
+}
+
+#[async_trait]
+impl YearExportServiceExt for YearExportService {
+    #[tracing::instrument(skip(self))]
+    async fn get_year_sheets(&self, year: i32) -> DatamizeResult<Vec<Sheet>> {
+        let year_detail = self.year_service.get_year(year).await?;
+
+        // Fetched per category to keep the order chosen by the user.
+        let mut resources = self
+            .fin_res_service
+            .get_from_year_and_category(year, &ResourceCategory::Asset)
+            .await?;
+        resources.extend(
+            self.fin_res_service
+                .get_from_year_and_category(year, &ResourceCategory::Liability)
+                .await?,
+        );
+
+        let saving_rates = self.saving_rate_service.get_all_from_year(year).await?;
+
+        Ok(vec![
+            Sheet::from_year(&year_detail, &resources),
+            Sheet::from_saving_rates(year, &saving_rates),
+        ])
+    }
+}
+
+/// Writes the sheets one after the other, separated by an empty line and preceded by their name.
+pub fn write_csv(sheets: &[Sheet]) -> DatamizeResult<Vec<u8>> {
+    let mut writer = csv::WriterBuilder::new().flexible(true).from_writer(vec![]);
+
+    for (i, sheet) in sheets.iter().enumerate() {
+        if i > 0 {
+            writer.write_record([""])?;
+        }
+        writer.write_record([&sheet.name])?;
+        for row in &sheet.rows {
+            if row.is_empty() {
+                writer.write_record([""])?;
+                continue;
+            }
+            writer.write_record(row.iter().map(|cell| match cell {
+                SheetCell::Empty => String::new(),
+                SheetCell::Text(text) => text.clone(),
+                SheetCell::Amount(amount) => amount.to_string(),
+                SheetCell::Ratio(ratio) => ratio.to_string(),
+            }))?;
+        }
+    }
+
+    writer
+        .into_inner()
+        .map_err(|e| csv::Error::from(e.into_error()).into())
+}
+
+/// Writes each sheet in its own worksheet, with amounts and ratios kept as numbers.
+pub fn write_xlsx(sheets: &[Sheet]) -> DatamizeResult<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let bold = Format::new().set_bold();
+    let amount = Format::new().set_num_format("#,##0.00");
+    let ratio = Format::new().set_num_format("0.00%");
+
+    for sheet in sheets {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(&sheet.name)?;
+        worksheet.set_column_width(0, 30)?;
+
+        for (row_num, row) in sheet.rows.iter().enumerate() {
+            let row_num = row_num as u32;
+            for (col_num, cell) in row.iter().enumerate() {
+                let col_num = col_num as u16;
+                match cell {
+                    SheetCell::Empty => {}
+                    // Headers and the labels of the groups stand alone in their row or are on the first one.
+                    SheetCell::Text(text) if row_num == 0 || row.len() == 1 => {
+                        worksheet.write_string_with_format(row_num, col_num, text, &bold)?;
+                    }
+                    SheetCell::Text(text) => {
+                        worksheet.write_string(row_num, col_num, text)?;
+                    }
+                    SheetCell::Amount(value) => {
+                        worksheet.write_number_with_format(
+                            row_num,
+                            col_num,
+                            value.to_f64(),
+                            &amount,
+                        )?;
+                    }
+                    SheetCell::Ratio(value) => {
+                        worksheet.write_number_with_format(row_num, col_num, *value, &ratio)?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(workbook.save_to_buffer()?)
+}
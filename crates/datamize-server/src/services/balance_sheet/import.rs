@@ -0,0 +1,234 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    sync::Arc,
+};
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DynFinResRepo, DynMonthRepo, DynYearRepo},
+    FinancialResourceYearly, ImportPlan, ImportedBalance, ImportedMonth, ImportedResource, Month,
+    MonthNum, Uuid, Year, YearlyBalances,
+};
+use ynab::Milliunits;
+
+use crate::error::{AppError, DatamizeResult};
+
+#[async_trait]
+pub trait BalanceSheetImportServiceExt: Send + Sync {
+    /// Creates the missing years and months, then creates or updates the resources by their name.
+    /// Nothing is saved when it is a dry run, only the plan of the changes is returned.
+    async fn import(
+        &self,
+        resources: Vec<ImportedResource>,
+        dry_run: bool,
+    ) -> DatamizeResult<ImportPlan>;
+}
+
+pub type DynBalanceSheetImportService = Arc<dyn BalanceSheetImportServiceExt>;
+
+pub struct BalanceSheetImportService {
+    pub fin_res_repo: DynFinResRepo,
+    pub month_repo: DynMonthRepo,
+    pub year_repo: DynYearRepo,
+}
+
+impl BalanceSheetImportService {
+    pub fn new_arced(
+        fin_res_repo: DynFinResRepo,
+        month_repo: DynMonthRepo,
+        year_repo: DynYearRepo,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            fin_res_repo,
+            month_repo,
+            year_repo,
+        })
+    }
+}
+
+#[async_trait]
+impl BalanceSheetImportServiceExt for BalanceSheetImportService {
+    #[tracing::instrument(skip(self, resources))]
+    async fn import(
+        &self,
+        resources: Vec<ImportedResource>,
+        dry_run: bool,
+    ) -> DatamizeResult<ImportPlan> {
+        let mut plan = ImportPlan {
+            dry_run,
+            ..Default::default()
+        };
+        let mut to_save = vec![];
+        let mut changed_months = BTreeSet::<ImportedMonth>::new();
+        // Net totals are computed again from the first month affected, the following ones depending on it.
+        let mut affected_months = BTreeSet::<(i32, MonthNum)>::new();
+
+        for imported in resources {
+            let (mut resource, is_new) = match self.fin_res_repo.get_by_name(&imported.name).await {
+                Ok(resource) => (resource, false),
+                Err(DbError::NotFound) => (
+                    FinancialResourceYearly::new(
+                        Uuid::new_v4(),
+                        imported.name.clone(),
+                        imported.resource_type.clone(),
+                        None,
+                        None,
+                    ),
+                    true,
+                ),
+                Err(e) => return Err(e.into()),
+            };
+
+            let mut changed = false;
+            if resource.base.resource_type != imported.resource_type {
+                changed = true;
+                resource.base.resource_type = imported.resource_type;
+                affected_months.extend(resource.get_first_month());
+            }
+
+            for ((year, month), balance) in imported.balances {
+                let previous = resource.get_balance(year, month);
+                if previous == Some(balance) {
+                    continue;
+                }
+
+                changed = true;
+                resource.insert_balance(year, month, balance);
+                changed_months.insert(ImportedMonth { year, month });
+                affected_months.insert((year, month));
+                plan.balances.push(ImportedBalance {
+                    resource: resource.base.name.clone(),
+                    year,
+                    month,
+                    previous,
+                    balance,
+                });
+            }
+
+            if is_new {
+                plan.resources_to_create.push(resource.base.name.clone());
+            } else if changed {
+                plan.resources_to_update.push(resource.base.name.clone());
+            }
+            if is_new || changed {
+                to_save.push(resource);
+            }
+        }
+
+        let mut checked_years = HashSet::<i32>::new();
+        for ImportedMonth { year, month } in changed_months {
+            if checked_years.insert(year) {
+                if let Err(DbError::NotFound) = self.year_repo.get_year_data_by_number(year).await {
+                    plan.years_to_create.push(year);
+                }
+            }
+
+            if plan.years_to_create.contains(&year) {
+                plan.months_to_create.push(ImportedMonth { year, month });
+            } else if let Err(DbError::NotFound) =
+                self.month_repo.get_month_data_by_number(month, year).await
+            {
+                plan.months_to_create.push(ImportedMonth { year, month });
+            }
+        }
+
+        if dry_run {
+            return Ok(plan);
+        }
+
+        for year in &plan.years_to_create {
+            self.year_repo.add(&Year::new(*year)).await?;
+        }
+        for ImportedMonth { year, month } in &plan.months_to_create {
+            self.month_repo
+                .add(&Month::new(*month, *year), *year)
+                .await?;
+        }
+        for resource in &to_save {
+            self.fin_res_repo.update(resource).await?;
+        }
+        if let Some(&(year, month)) = affected_months.first() {
+            self.month_repo.update_net_totals(month, year).await?;
+            self.year_repo.update_net_totals(year).await?;
+        }
+
+        Ok(plan)
+    }
+}
+
+/// Reads resources from a CSV with the name and type of a resource followed by its balance of each month.
+/// The header names the months as `YYYY-MM`, i.e. `name,type,2015-01,2015-02`, and types are written
+/// as `asset_investment` or `liability_longTerm`. Months without a balance are left empty.
+pub fn read_csv(data: &[u8]) -> DatamizeResult<Vec<ImportedResource>> {
+    // Spreadsheets often leave out the trailing empty cells of a row.
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(data);
+
+    let headers = reader.headers().map_err(invalid_csv)?.clone();
+    if headers.len() < 2 {
+        return Err(AppError::InvalidImport(
+            "The header must start with the name and the type of the resources".to_owned(),
+        ));
+    }
+    let mut months = Vec::with_capacity(headers.len() - 2);
+    for header in headers.iter().skip(2) {
+        let month = parse_month(header).ok_or_else(|| {
+            AppError::InvalidImport(format!("`{}` is not a month written as YYYY-MM", header))
+        })?;
+        if months.contains(&month) {
+            return Err(AppError::InvalidImport(format!(
+                "The month `{}` is there more than once",
+                header
+            )));
+        }
+        months.push(month);
+    }
+
+    let mut resources: Vec<ImportedResource> = vec![];
+    for record in reader.records() {
+        let record = record.map_err(invalid_csv)?;
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+        let invalid =
+            |message: String| AppError::InvalidImport(format!("Line {}: {}", line, message));
+
+        let name = record.get(0).unwrap_or_default();
+        if name.is_empty() {
+            return Err(invalid("the resource has no name".to_owned()));
+        }
+        if resources.iter().any(|r| r.name == name) {
+            return Err(invalid(format!("`{}` is there more than once", name)));
+        }
+        let resource_type = record.get(1).unwrap_or_default().parse().map_err(invalid)?;
+
+        let mut balances = BTreeMap::new();
+        for (month, cell) in months.iter().zip(record.iter().skip(2)) {
+            if cell.is_empty() {
+                continue;
+            }
+            let balance: Milliunits = cell.parse().map_err(|e| invalid(format!("{}", e)))?;
+            balances.insert(*month, balance);
+        }
+
+        resources.push(ImportedResource {
+            name: name.to_owned(),
+            resource_type,
+            balances,
+        });
+    }
+
+    Ok(resources)
+}
+
+fn parse_month(header: &str) -> Option<(i32, MonthNum)> {
+    let (year, month) = header.split_once('-')?;
+    let year = year.parse().ok()?;
+    let month = month.parse::<u32>().ok()?.try_into().ok()?;
+
+    Some((year, month))
+}
+
+fn invalid_csv(e: csv::Error) -> AppError {
+    AppError::InvalidImport(e.to_string())
+}
@@ -1,13 +1,19 @@
+mod export;
 mod financial_resource;
+mod import;
 mod month;
 mod refresh_financial_resource;
+mod rollover;
 mod saving_rate;
 #[cfg(test)]
 mod tests;
 mod year;
 
+pub use export::*;
 pub use financial_resource::*;
+pub use import::*;
 pub use month::*;
 pub use refresh_financial_resource::*;
+pub use rollover::*;
 pub use saving_rate::*;
 pub use year::*;
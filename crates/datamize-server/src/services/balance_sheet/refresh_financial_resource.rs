@@ -3,11 +3,13 @@ use std::{collections::HashSet, sync::Arc};
 use chrono::{Datelike, Local};
 use datamize_domain::{
     async_trait,
-    db::{DbError, DynFinResRepo, DynMonthRepo, DynYearRepo},
-    FinancialResourceYearly, Month, MonthNum, ResourcesToRefresh, Uuid, YearlyBalances,
+    db::{DbError, DynFinResRepo, DynMonthRepo, DynYearRepo, YearData},
+    FinancialResourceYearly, Month, MonthNum, ResourcesToRefresh, Uuid, Year, YearlyBalances,
 };
-use ynab::AccountRequests;
+use itertools::Itertools;
+use ynab::{AccountRequests, Milliunits};
 
+use super::DynRolloverService;
 use crate::{error::DatamizeResult, services::budget_providers::DynExternalAccountService};
 
 #[async_trait]
@@ -16,6 +18,14 @@ pub trait RefreshFinResServiceExt: Send + Sync {
         &self,
         resources_to_refresh: Option<ResourcesToRefresh>,
     ) -> DatamizeResult<Vec<Uuid>>;
+    /// Sets the balances of a past month from the last balances observed during that month
+    /// for the external accounts linked to the resources.
+    async fn backfill_fin_res(
+        &self,
+        year: i32,
+        month: MonthNum,
+        resources_to_refresh: Option<ResourcesToRefresh>,
+    ) -> DatamizeResult<Vec<Uuid>>;
 }
 
 pub type DynRefreshFinResService = Arc<dyn RefreshFinResServiceExt>;
@@ -25,8 +35,10 @@ pub struct RefreshFinResService {
     pub fin_res_repo: DynFinResRepo,
     pub month_repo: DynMonthRepo,
     pub year_repo: DynYearRepo,
+    pub rollover_service: DynRolloverService,
     pub external_account_service: DynExternalAccountService,
     pub ynab_client: Arc<dyn AccountRequests + Send + Sync>,
+    pub budget_id: String,
 }
 
 #[async_trait]
@@ -38,12 +50,12 @@ impl RefreshFinResServiceExt for RefreshFinResService {
     ) -> DatamizeResult<Vec<Uuid>> {
         let current_date = Local::now().date_naive();
         let current_year = current_date.year();
-        // The only condition is that the year exists...
-        let mut year_data = self.year_repo.get_year_data_by_number(current_year).await?;
-
         let current_month: MonthNum = current_date.month().try_into().unwrap();
-        self.ensure_month_exists(current_year, current_month)
+        // Carries the resources over to the month, and the year, when they are new.
+        self.rollover_service
+            .rollover(current_year, current_month)
             .await?;
+        let mut year_data = self.year_repo.get_year_data_by_number(current_year).await?;
 
         let mut resources = self.fin_res_repo.get_from_year(current_year).await?;
         resources.retain(|r| {
@@ -52,11 +64,20 @@ impl RefreshFinResServiceExt for RefreshFinResService {
                 .map_or(true, |refresh| refresh.ids.contains(&r.base.id))
         });
 
-        let accounts = self.ynab_client.get_accounts().await?;
+        let accounts = self.ynab_client.get_accounts(&self.budget_id).await?;
+        // The resources linked to YNAB accounts are still refreshed when the external ones can't be.
         let external_accounts = self
             .external_account_service
             .refresh_web_scraping_accounts(self.get_external_account_ids(&resources))
-            .await?;
+            .await
+            .unwrap_or_else(|e| {
+                tracing::error!(
+                    error.cause_chain = ?e,
+                    error.message = %e,
+                    "Failed to refresh the external accounts. Skipping."
+                );
+                vec![]
+            });
 
         let mut refreshed = HashSet::new();
 
@@ -72,8 +93,8 @@ impl RefreshFinResServiceExt for RefreshFinResService {
                     let balance = accounts
                         .iter()
                         .filter(|a| account_ids.contains(&a.id))
-                        .map(|a| a.balance.abs())
-                        .sum::<i64>();
+                        .map(|a| Milliunits::from(a.balance.abs()))
+                        .sum::<Milliunits>();
 
                     match res.get_balance(current_year, current_month) {
                         Some(current_balance) => {
@@ -91,18 +112,17 @@ impl RefreshFinResServiceExt for RefreshFinResService {
             }
 
             if let Some(ref account_ids) = res.base.external_account_ids {
-                let is_account_included = external_accounts
+                // A partial sum would be wrong, so the balance is kept until all the accounts refresh.
+                let are_accounts_refreshed = account_ids
                     .iter()
-                    .filter(|a| account_ids.contains(&a.id))
-                    .count()
-                    > 0;
+                    .all(|id| external_accounts.iter().any(|a| &a.id == id));
 
-                if !account_ids.is_empty() && is_account_included {
+                if !account_ids.is_empty() && are_accounts_refreshed {
                     let balance = external_accounts
                         .iter()
                         .filter(|a| account_ids.contains(&a.id))
                         .map(|a| a.balance.abs())
-                        .sum::<i64>();
+                        .sum::<Milliunits>();
 
                     match res.get_balance(current_year, current_month) {
                         Some(current_balance) => {
@@ -135,6 +155,65 @@ impl RefreshFinResServiceExt for RefreshFinResService {
 
         Ok(refreshed.into_iter().collect())
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn backfill_fin_res(
+        &self,
+        year: i32,
+        month: MonthNum,
+        resources_to_refresh: Option<ResourcesToRefresh>,
+    ) -> DatamizeResult<Vec<Uuid>> {
+        self.ensure_year_exists(year).await?;
+        self.ensure_month_exists(year, month).await?;
+
+        let mut resources = self.fin_res_repo.get_from_year(year).await?;
+        resources.retain(|r| {
+            resources_to_refresh
+                .as_ref()
+                .map_or(true, |refresh| refresh.ids.contains(&r.base.id))
+        });
+
+        let balances = self
+            .external_account_service
+            .get_balances_of_month(&self.get_external_account_ids(&resources), year, month)
+            .await?;
+
+        let mut refreshed = HashSet::new();
+
+        for res in &mut resources {
+            let Some(ref account_ids) = res.base.external_account_ids else {
+                continue;
+            };
+            // Like a refresh, a partial sum would be wrong.
+            let are_accounts_observed = account_ids
+                .iter()
+                .all(|id| balances.iter().any(|b| &b.account_id == id));
+
+            if !account_ids.is_empty() && are_accounts_observed {
+                let balance = balances
+                    .iter()
+                    .filter(|b| account_ids.contains(&b.account_id))
+                    .map(|b| b.balance.abs())
+                    .sum::<Milliunits>();
+
+                if res.get_balance(year, month) != Some(balance) {
+                    res.insert_balance(year, month, balance);
+                    refreshed.insert(res.base.id);
+                }
+            }
+        }
+
+        if !refreshed.is_empty() {
+            resources.retain(|r| refreshed.contains(&r.base.id));
+            for r in resources {
+                self.fin_res_repo.update(&r).await?;
+            }
+            self.month_repo.update_net_totals(month, year).await?;
+            self.year_repo.update_net_totals(year).await?;
+        }
+
+        Ok(refreshed.into_iter().collect())
+    }
 }
 
 impl RefreshFinResService {
@@ -142,18 +221,33 @@ impl RefreshFinResService {
         fin_res_repo: DynFinResRepo,
         month_repo: DynMonthRepo,
         year_repo: DynYearRepo,
+        rollover_service: DynRolloverService,
         external_account_service: DynExternalAccountService,
         ynab_client: Arc<dyn AccountRequests + Send + Sync>,
+        budget_id: &str,
     ) -> Arc<Self> {
         Arc::new(Self {
             year_repo,
             month_repo,
             fin_res_repo,
+            rollover_service,
             external_account_service,
             ynab_client,
+            budget_id: budget_id.to_string(),
         })
     }
 
+    /// Check if year exists, create if not
+    async fn ensure_year_exists(&self, year: i32) -> DatamizeResult<YearData> {
+        if let Err(DbError::NotFound) = self.year_repo.get_year_data_by_number(year).await {
+            // If year doesn't exist, create it
+            let year = Year::new(year);
+            self.year_repo.add(&year).await?;
+        }
+
+        Ok(self.year_repo.get_year_data_by_number(year).await?)
+    }
+
     /// Check if month exists, create if not
     async fn ensure_month_exists(&self, year: i32, month: MonthNum) -> DatamizeResult<()> {
         if let Err(DbError::NotFound) = self.month_repo.get_month_data_by_number(month, year).await
@@ -172,6 +266,7 @@ impl RefreshFinResService {
             .flat_map(|r| r.base.external_account_ids.as_ref())
             .flatten()
             .copied()
+            .unique()
             .collect::<Vec<_>>()
     }
 }
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DynFinResRepo, DynMonthRepo, DynYearRepo},
+    FinancialResourceYearly, Month, MonthNum, Uuid, Year, YearlyBalances,
+};
+use ynab::Milliunits;
+
+use crate::error::DatamizeResult;
+
+#[async_trait]
+pub trait RolloverServiceExt: Send + Sync {
+    /// Creates the year and the month when they don't exist yet, then carries over the balance
+    /// of the previous month of the resources still active, i.e. with a balance other than zero.
+    /// Resources already having a balance in the month are left as is.
+    async fn rollover(&self, year: i32, month: MonthNum) -> DatamizeResult<Rollover>;
+}
+
+pub type DynRolloverService = Arc<dyn RolloverServiceExt>;
+
+/// What a rollover changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rollover {
+    pub year: i32,
+    pub month: MonthNum,
+    pub year_created: bool,
+    pub month_created: bool,
+    /// Resources whose balance of the previous month was carried over.
+    pub carried_over: Vec<Uuid>,
+}
+
+impl std::fmt::Display for Rollover {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut changes = vec![];
+        if self.year_created {
+            changes.push(format!("created year {}", self.year));
+        }
+        if self.month_created {
+            changes.push(format!("created {} {}", self.month.name(), self.year));
+        }
+        if !self.carried_over.is_empty() {
+            changes.push(format!(
+                "carried over {} resource(s)",
+                self.carried_over.len()
+            ));
+        }
+
+        match changes.is_empty() {
+            true => write!(f, "{} {} already up to date", self.month.name(), self.year),
+            false => write!(f, "{}", changes.join(", ")),
+        }
+    }
+}
+
+pub struct RolloverService {
+    pub fin_res_repo: DynFinResRepo,
+    pub month_repo: DynMonthRepo,
+    pub year_repo: DynYearRepo,
+}
+
+impl RolloverService {
+    pub fn new_arced(
+        fin_res_repo: DynFinResRepo,
+        month_repo: DynMonthRepo,
+        year_repo: DynYearRepo,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            fin_res_repo,
+            month_repo,
+            year_repo,
+        })
+    }
+}
+
+#[async_trait]
+impl RolloverServiceExt for RolloverService {
+    #[tracing::instrument(skip(self))]
+    async fn rollover(&self, year: i32, month: MonthNum) -> DatamizeResult<Rollover> {
+        let mut rollover = Rollover {
+            year,
+            month,
+            ..Default::default()
+        };
+
+        match self.year_repo.get_year_data_by_number(year).await {
+            Ok(_) => (),
+            Err(DbError::NotFound) => {
+                self.year_repo.add(&Year::new(year)).await?;
+                rollover.year_created = true;
+            }
+            Err(e) => return Err(e.into()),
+        }
+        match self.month_repo.get_month_data_by_number(month, year).await {
+            Ok(_) => (),
+            Err(DbError::NotFound) => {
+                self.month_repo.add(&Month::new(month, year), year).await?;
+                rollover.month_created = true;
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let (prev_year, prev_month) = match month {
+            MonthNum::January => (year - 1, MonthNum::December),
+            _ => (year, month.pred()),
+        };
+        for resource in self.fin_res_repo.get_from_all_years().await? {
+            let Some(balance) = resource.get_balance(prev_year, prev_month) else {
+                continue;
+            };
+            if balance == Milliunits::ZERO || resource.get_balance(year, month).is_some() {
+                continue;
+            }
+
+            // Only the new balance is saved, the ones of the other months are left untouched.
+            let mut carried_over = FinancialResourceYearly {
+                base: resource.base,
+                balances: Default::default(),
+            };
+            carried_over.insert_balance(year, month, balance);
+            self.fin_res_repo.update(&carried_over).await?;
+            rollover.carried_over.push(carried_over.base.id);
+        }
+
+        if rollover.month_created || !rollover.carried_over.is_empty() {
+            self.month_repo.update_net_totals(month, year).await?;
+            self.year_repo.update_net_totals(year).await?;
+        }
+
+        Ok(rollover)
+    }
+}
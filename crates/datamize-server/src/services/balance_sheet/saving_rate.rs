@@ -1,12 +1,11 @@
 use std::{collections::HashSet, sync::Arc};
 
+use chrono::Datelike;
 use futures::{stream::FuturesUnordered, StreamExt};
 use ynab::TransactionDetail;
 
 use datamize_domain::{
-    async_trait,
-    db::{DbError, DynSavingRateRepo},
-    SaveSavingRate, SavingRate, Uuid,
+    async_trait, db::DynSavingRateRepo, SaveSavingRate, SavingRate, SavingRateHistoryEntry, Uuid,
 };
 
 use crate::{
@@ -18,6 +17,7 @@ use crate::{
 pub trait SavingRateServiceExt: Send + Sync {
     async fn get_all_from_year(&self, year: i32) -> DatamizeResult<Vec<SavingRate>>;
     async fn get_saving_rate(&self, saving_rate_id: Uuid) -> DatamizeResult<SavingRate>;
+    async fn get_history(&self, name: &str) -> DatamizeResult<Vec<SavingRateHistoryEntry>>;
     async fn create_saving_rate(
         &self,
         new_saving_rate: SaveSavingRate,
@@ -53,7 +53,9 @@ impl SavingRateServiceExt for SavingRateService {
         for saving_rate in &mut saving_rates {
             let transactions = self.get_transactions_for(saving_rate).await;
 
-            saving_rate.compute_totals(&transactions);
+            saving_rate
+                .compute_totals(&transactions)
+                .ok_or(AppError::AmountOverflow)?;
         }
 
         Ok(saving_rates)
@@ -67,23 +69,52 @@ impl SavingRateServiceExt for SavingRateService {
             .await?;
         let transactions = self.get_transactions_for(&saving_rate).await;
 
-        saving_rate.compute_totals(&transactions);
+        saving_rate
+            .compute_totals(&transactions)
+            .ok_or(AppError::AmountOverflow)?;
 
         Ok(saving_rate)
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn get_history(&self, name: &str) -> DatamizeResult<Vec<SavingRateHistoryEntry>> {
+        let mut saving_rates = self.saving_rate_repo.get_all_by_name(name).await?;
+        if saving_rates.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.transaction_service
+            .refresh_saved_transactions()
+            .await?;
+
+        for saving_rate in &mut saving_rates {
+            let transactions = self.get_transactions_for(saving_rate).await;
+
+            saving_rate
+                .compute_totals(&transactions)
+                .ok_or(AppError::AmountOverflow)?;
+        }
+
+        Ok(saving_rates.iter().map(Into::into).collect())
+    }
+
     #[tracing::instrument(skip_all)]
     async fn create_saving_rate(
         &self,
         new_saving_rate: SaveSavingRate,
     ) -> DatamizeResult<SavingRate> {
-        let Err(DbError::NotFound) = self
+        if !new_saving_rate.is_target_valid() {
+            return Err(AppError::InvalidSavingRateTarget);
+        }
+
+        // The same name can be reused on other years, to follow the saving rate over time.
+        let same_name = self
             .saving_rate_repo
-            .get_by_name(&new_saving_rate.name)
-            .await
-        else {
+            .get_all_by_name(&new_saving_rate.name)
+            .await?;
+        if same_name.iter().any(|s| s.year == new_saving_rate.year) {
             return Err(AppError::ResourceAlreadyExist);
-        };
+        }
 
         let mut saving_rate: SavingRate = new_saving_rate.into();
         self.saving_rate_repo.update(&saving_rate).await?;
@@ -93,7 +124,9 @@ impl SavingRateServiceExt for SavingRateService {
             .await?;
         let transactions = self.get_transactions_for(&saving_rate).await;
 
-        saving_rate.compute_totals(&transactions);
+        saving_rate
+            .compute_totals(&transactions)
+            .ok_or(AppError::AmountOverflow)?;
 
         Ok(saving_rate)
     }
@@ -102,10 +135,25 @@ impl SavingRateServiceExt for SavingRateService {
         &self,
         new_saving_rate: SaveSavingRate,
     ) -> DatamizeResult<SavingRate> {
+        if !new_saving_rate.is_target_valid() {
+            return Err(AppError::InvalidSavingRateTarget);
+        }
+
         let Ok(_) = self.saving_rate_repo.get(new_saving_rate.id).await else {
             return Err(AppError::ResourceNotFound);
         };
 
+        let same_name = self
+            .saving_rate_repo
+            .get_all_by_name(&new_saving_rate.name)
+            .await?;
+        if same_name
+            .iter()
+            .any(|s| s.year == new_saving_rate.year && s.id != new_saving_rate.id)
+        {
+            return Err(AppError::ResourceAlreadyExist);
+        }
+
         let mut saving_rate: SavingRate = new_saving_rate.into();
 
         self.saving_rate_repo.update(&saving_rate).await?;
@@ -115,7 +163,9 @@ impl SavingRateServiceExt for SavingRateService {
             .await?;
         let transactions = self.get_transactions_for(&saving_rate).await;
 
-        saving_rate.compute_totals(&transactions);
+        saving_rate
+            .compute_totals(&transactions)
+            .ok_or(AppError::AmountOverflow)?;
 
         Ok(saving_rate)
     }
@@ -130,7 +180,9 @@ impl SavingRateServiceExt for SavingRateService {
             .await?;
         let transactions = self.get_transactions_for(&saving_rate).await;
 
-        saving_rate.compute_totals(&transactions);
+        saving_rate
+            .compute_totals(&transactions)
+            .ok_or(AppError::AmountOverflow)?;
 
         Ok(saving_rate)
     }
@@ -147,6 +199,7 @@ impl SavingRateService {
         })
     }
 
+    /// Transactions of the saving rate's categories and payees, restricted to the saving rate's year.
     pub(crate) async fn get_transactions_for(
         &self,
         saving_rate: &SavingRate,
@@ -185,6 +238,7 @@ impl SavingRateService {
             .into_iter()
             .flatten()
             .flatten()
+            .filter(|t| t.base.date.year() == saving_rate.year)
             .collect::<Vec<_>>();
 
         let mut transactions = <HashSet<TransactionDetail> as IntoIterator>::into_iter(
@@ -11,6 +11,7 @@ use crate::services::{
     balance_sheet::tests::financial_resource::testutils::TestContext,
     testutils::{assert_err, ErrorType},
 };
+use ynab::Milliunits;
 
 async fn check_create(
     pool: SqlitePool,
@@ -58,7 +59,7 @@ async fn check_create(
 
                 let saved_month = saved_month.unwrap();
                 // Since net_assets are computed from all resources' type
-                assert_ne!(saved_month.net_assets().total, 0);
+                assert_ne!(saved_month.net_assets().total, Milliunits::ZERO);
             }
         }
 
@@ -67,7 +68,7 @@ async fn check_create(
         assert!(saved_years.is_ok());
         let saved_years = saved_years.unwrap();
         for saved_year in saved_years {
-            assert_ne!(saved_year.net_assets().total, 0); // TODO: To be fixed, now that we create all months of a year, the last month will be 0 until December arrives
+            assert_ne!(saved_year.net_assets().total, Milliunits::ZERO); // TODO: To be fixed, now that we create all months of a year, the last month will be 0 until December arrives
         }
     } else {
         println!("{response:#?}");
@@ -9,6 +9,7 @@ use crate::services::{
     balance_sheet::tests::financial_resource::testutils::TestContext,
     testutils::{assert_err, ErrorType},
 };
+use ynab::Milliunits;
 
 async fn check_delete(
     pool: SqlitePool,
@@ -79,7 +80,7 @@ async fn check_delete(
                 let saved_month = saved_month.unwrap();
                 if !saved_month.resources.is_empty() {
                     // Since net_assets are computed from all resources' type
-                    assert_ne!(saved_month.net_assets().total, 0);
+                    assert_ne!(saved_month.net_assets().total, Milliunits::ZERO);
                 }
             }
         }
@@ -10,6 +10,7 @@ use crate::services::{
     balance_sheet::tests::financial_resource::testutils::TestContext,
     testutils::{assert_err, ErrorType},
 };
+use ynab::Milliunits;
 
 async fn check_update(
     pool: SqlitePool,
@@ -58,7 +59,7 @@ async fn check_update(
                 let saved_month = saved_month.unwrap();
                 if !saved_month.resources.is_empty() {
                     // Since net_assets are computed from all resources' type
-                    assert_ne!(saved_month.net_assets().total, 0);
+                    assert_ne!(saved_month.net_assets().total, Milliunits::ZERO);
                 }
             }
         }
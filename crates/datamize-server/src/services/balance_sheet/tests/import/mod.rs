@@ -0,0 +1,2 @@
+mod read_csv;
+mod save;
@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+
+use datamize_domain::{
+    AssetType, FinancialResourceType, ImportedResource, LiabilityType, MonthNum,
+};
+use pretty_assertions::assert_eq;
+use ynab::Milliunits;
+
+use crate::{error::AppError, services::balance_sheet::read_csv};
+
+fn assert_invalid(data: &str, expected: &str) {
+    let err = read_csv(data.as_bytes()).unwrap_err();
+    let AppError::InvalidImport(message) = err else {
+        panic!("Expected an invalid import, got {:?}", err);
+    };
+    assert!(
+        message.contains(expected),
+        "{:?} does not contain {:?}",
+        message,
+        expected
+    );
+}
+
+#[test]
+fn reads_one_resource_per_row_and_one_balance_per_month() {
+    let data = "\
+name,type,2015-12,2016-01,2016-02
+Bank, asset_cash ,1234.56,,-0.5
+Mortgage,liability_longTerm,200000
+";
+
+    let resources = read_csv(data.as_bytes()).unwrap();
+
+    assert_eq!(
+        resources,
+        vec![
+            ImportedResource {
+                name: "Bank".to_owned(),
+                resource_type: FinancialResourceType::Asset(AssetType::Cash),
+                balances: BTreeMap::from([
+                    ((2015, MonthNum::December), Milliunits::new(1234560)),
+                    ((2016, MonthNum::February), Milliunits::new(-500)),
+                ]),
+            },
+            ImportedResource {
+                name: "Mortgage".to_owned(),
+                resource_type: FinancialResourceType::Liability(LiabilityType::LongTerm),
+                balances: BTreeMap::from([(
+                    (2015, MonthNum::December),
+                    Milliunits::new(200000000)
+                )]),
+            },
+        ]
+    );
+}
+
+#[test]
+fn rejects_months_not_written_as_year_and_month() {
+    assert_invalid("name,type,January\n", "`January` is not a month");
+    assert_invalid("name,type,2015-13\n", "`2015-13` is not a month");
+}
+
+#[test]
+fn rejects_months_there_more_than_once() {
+    assert_invalid("name,type,2015-01,2015-1\n", "more than once");
+}
+
+#[test]
+fn rejects_unknown_types() {
+    assert_invalid("name,type,2015-01\nBank,asset,10\n", "Line 2");
+}
+
+#[test]
+fn rejects_invalid_amounts() {
+    assert_invalid(
+        "name,type,2015-01\nBank,asset_cash,ten\n",
+        "Line 2: `ten` is not a valid amount",
+    );
+}
+
+#[test]
+fn rejects_resources_there_more_than_once() {
+    assert_invalid(
+        "name,type,2015-01\nBank,asset_cash,10\nBank,asset_cash,20\n",
+        "Line 3: `Bank` is there more than once",
+    );
+}
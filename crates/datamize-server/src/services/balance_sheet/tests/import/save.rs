@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+
+use datamize_domain::{
+    db::DynRepoRegistry, AssetType, FinancialResourceType, FinancialResourceYearly,
+    ImportedBalance, ImportedMonth, ImportedResource, LiabilityType, Month, MonthNum, Uuid, Year,
+    YearlyBalances,
+};
+use db_sqlite::SqliteRepoRegistry;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use ynab::Milliunits;
+
+use crate::services::balance_sheet::{BalanceSheetImportService, BalanceSheetImportServiceExt};
+
+fn service(repos: &DynRepoRegistry) -> BalanceSheetImportService {
+    BalanceSheetImportService {
+        fin_res_repo: repos.fin_res_repo(),
+        month_repo: repos.month_repo(),
+        year_repo: repos.year_repo(),
+    }
+}
+
+fn history() -> Vec<ImportedResource> {
+    vec![
+        ImportedResource {
+            name: "Bank".to_owned(),
+            resource_type: FinancialResourceType::Asset(AssetType::Cash),
+            balances: BTreeMap::from([
+                ((2015, MonthNum::December), Milliunits::new(1000)),
+                ((2016, MonthNum::January), Milliunits::new(3000)),
+            ]),
+        },
+        ImportedResource {
+            name: "Car Loan".to_owned(),
+            resource_type: FinancialResourceType::Liability(LiabilityType::Cash),
+            balances: BTreeMap::from([((2016, MonthNum::January), Milliunits::new(500))]),
+        },
+    ]
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn dry_run_only_plans_the_changes(pool: SqlitePool) {
+    let repos: DynRepoRegistry = SqliteRepoRegistry::new_arced(pool);
+
+    let plan = service(&repos).import(history(), true).await.unwrap();
+
+    assert!(plan.dry_run);
+    assert_eq!(plan.years_to_create, vec![2015, 2016]);
+    assert_eq!(
+        plan.months_to_create,
+        vec![
+            ImportedMonth {
+                year: 2015,
+                month: MonthNum::December
+            },
+            ImportedMonth {
+                year: 2016,
+                month: MonthNum::January
+            },
+        ]
+    );
+    assert_eq!(plan.resources_to_create, vec!["Bank", "Car Loan"]);
+    assert!(plan.resources_to_update.is_empty());
+    assert_eq!(plan.balances.len(), 3);
+
+    assert!(repos.year_repo().get_years().await.unwrap().is_empty());
+    assert!(repos
+        .fin_res_repo()
+        .get_from_all_years()
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn creates_years_months_and_resources_with_their_net_totals(pool: SqlitePool) {
+    let repos: DynRepoRegistry = SqliteRepoRegistry::new_arced(pool);
+
+    let plan = service(&repos).import(history(), false).await.unwrap();
+    assert!(!plan.dry_run);
+
+    let bank = repos.fin_res_repo().get_by_name("Bank").await.unwrap();
+    assert_eq!(
+        bank.get_balance(2016, MonthNum::January),
+        Some(Milliunits::new(3000))
+    );
+    repos.fin_res_repo().get_by_name("Car Loan").await.unwrap();
+
+    let january = repos
+        .month_repo()
+        .get(MonthNum::January, 2016)
+        .await
+        .unwrap();
+    assert_eq!(january.net_totals.assets.total, Milliunits::new(2500));
+    assert_eq!(january.net_totals.assets.balance_var, Milliunits::new(1500));
+    let year = repos.year_repo().get(2016).await.unwrap();
+    assert_eq!(year.net_totals.assets.total, Milliunits::new(2500));
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn updates_existing_resources_by_name(pool: SqlitePool) {
+    let repos: DynRepoRegistry = SqliteRepoRegistry::new_arced(pool);
+    repos.year_repo().add(&Year::new(2015)).await.unwrap();
+    repos
+        .month_repo()
+        .add(&Month::new(MonthNum::December, 2015), 2015)
+        .await
+        .unwrap();
+    let mut bank = FinancialResourceYearly::new(
+        Uuid::new_v4(),
+        "Bank".to_owned(),
+        FinancialResourceType::Asset(AssetType::Cash),
+        None,
+        None,
+    );
+    bank.insert_balance(2015, MonthNum::December, Milliunits::new(1000));
+    repos.fin_res_repo().update(&bank).await.unwrap();
+    let mut imported = history();
+    imported[0]
+        .balances
+        .insert((2015, MonthNum::December), Milliunits::new(1000));
+    imported[0]
+        .balances
+        .insert((2016, MonthNum::January), Milliunits::new(4000));
+
+    let plan = service(&repos).import(imported, false).await.unwrap();
+
+    assert_eq!(plan.years_to_create, vec![2016]);
+    assert_eq!(plan.resources_to_create, vec!["Car Loan"]);
+    assert_eq!(plan.resources_to_update, vec!["Bank"]);
+    assert_eq!(
+        plan.balances[0],
+        ImportedBalance {
+            resource: "Bank".to_owned(),
+            year: 2016,
+            month: MonthNum::January,
+            previous: None,
+            balance: Milliunits::new(4000),
+        }
+    );
+    let saved = repos.fin_res_repo().get_by_name("Bank").await.unwrap();
+    assert_eq!(saved.base.id, bank.base.id);
+    assert_eq!(
+        saved.get_balance(2016, MonthNum::January),
+        Some(Milliunits::new(4000))
+    );
+}
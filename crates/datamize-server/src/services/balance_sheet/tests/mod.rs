@@ -1,5 +1,7 @@
 mod financial_resource;
+mod import;
 mod month;
 mod refresh_resource;
+mod rollover;
 mod saving_rate;
 mod year;
@@ -8,6 +8,7 @@ use crate::services::{
     balance_sheet::tests::month::testutils::TestContext,
     testutils::{assert_err, ErrorType},
 };
+use ynab::Milliunits;
 
 fn are_equal(a: &Month, b: &Month) {
     assert_eq!(a.month, b.month);
@@ -128,9 +129,9 @@ async fn update_net_totals_if_prev_month_exists(pool: SqlitePool) {
         year,
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_month.net_assets().total,
-                percent_var: if prev_month.net_assets().total == 0 {
+                percent_var: if prev_month.net_assets().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -138,9 +139,9 @@ async fn update_net_totals_if_prev_month_exists(pool: SqlitePool) {
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_month.net_portfolio().total,
-                percent_var: if prev_month.net_portfolio().total == 0 {
+                percent_var: if prev_month.net_portfolio().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -178,9 +179,9 @@ async fn update_net_totals_if_prev_month_exists_in_prev_year(pool: SqlitePool) {
         year,
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_month.net_assets().total,
-                percent_var: if prev_month.net_assets().total == 0 {
+                percent_var: if prev_month.net_assets().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -188,9 +189,9 @@ async fn update_net_totals_if_prev_month_exists_in_prev_year(pool: SqlitePool) {
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_month.net_portfolio().total,
-                percent_var: if prev_month.net_portfolio().total == 0 {
+                percent_var: if prev_month.net_portfolio().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -0,0 +1,144 @@
+use chrono::{Local, TimeZone, Utc};
+use datamize_domain::{
+    BalanceSource, ExternalAccountBalance, FinancialResourceYearly, MonthNum, ResourcesToRefresh,
+    Uuid, WebScrapingAccount, YearlyBalances,
+};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use ynab::Milliunits;
+
+use crate::services::balance_sheet::tests::refresh_resource::testutils::TestContext;
+
+const YEAR: i32 = 2023;
+
+fn observed(account_id: Uuid, month: u32, day: u32, balance: i64) -> ExternalAccountBalance {
+    ExternalAccountBalance {
+        id: Uuid::new_v4(),
+        account_id,
+        observed_at: Local
+            .with_ymd_and_hms(YEAR, month, day, 12, 0, 0)
+            .unwrap()
+            .with_timezone(&Utc),
+        balance: Milliunits::new(balance),
+        source: BalanceSource::Scrape,
+    }
+}
+
+fn resource(name: &str, external_account_ids: Vec<Uuid>) -> FinancialResourceYearly {
+    let mut res = FinancialResourceYearly::new(
+        Uuid::new_v4(),
+        name.to_string(),
+        Faker.fake(),
+        None,
+        Some(external_account_ids),
+    );
+    res.insert_balance(YEAR, MonthNum::January, Milliunits::new(100));
+
+    res
+}
+
+async fn setup_accounts(context: &TestContext, names: &[&str]) -> Vec<WebScrapingAccount> {
+    let accounts: Vec<_> = names
+        .iter()
+        .map(|name| WebScrapingAccount {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            ..Default::default()
+        })
+        .collect();
+    context.set_external_accounts(&accounts).await;
+
+    accounts
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn uses_the_last_balances_observed_in_the_month(pool: SqlitePool) {
+    let context = TestContext::setup(pool, 0, vec![]).await;
+    context.insert_year(YEAR).await;
+    context.insert_month(MonthNum::January, YEAR).await;
+    let accounts = setup_accounts(&context, &["Broker", "Credit Card", "House"]).await;
+    let (broker, credit_card, house) = (accounts[0].id, accounts[1].id, accounts[2].id);
+    context
+        .set_balances(&[
+            observed(broker, 3, 5, 1000),
+            observed(broker, 3, 25, 1500),
+            observed(broker, 4, 2, 9999),
+            observed(credit_card, 3, 10, -200),
+            observed(house, 2, 15, 300000),
+        ])
+        .await;
+
+    let backfilled = resource("Investments", vec![broker, credit_card]);
+    let not_observed = resource("House", vec![broker, house]);
+    context
+        .set_resources(&[backfilled.clone(), not_observed.clone()])
+        .await;
+
+    let response = context
+        .service()
+        .backfill_fin_res(YEAR, MonthNum::March, None)
+        .await
+        .unwrap();
+
+    assert_eq!(response, vec![backfilled.base.id]);
+    let saved_resources = context.get_resources().await;
+    let saved_balance = |id: Uuid, month: MonthNum| {
+        saved_resources
+            .iter()
+            .find(|r| r.base.id == id)
+            .and_then(|r| r.get_balance(YEAR, month))
+    };
+    assert_eq!(
+        saved_balance(backfilled.base.id, MonthNum::March),
+        Some(Milliunits::new(1700))
+    );
+    assert_eq!(
+        saved_balance(backfilled.base.id, MonthNum::January),
+        Some(Milliunits::new(100))
+    );
+    assert_eq!(saved_balance(not_observed.base.id, MonthNum::March), None);
+    // The month is created so its net totals include the new balance.
+    assert!(context.get_month(MonthNum::March, YEAR).await.is_ok());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn only_backfills_the_resources_asked_for(pool: SqlitePool) {
+    let context = TestContext::setup(pool, 0, vec![]).await;
+    context.insert_year(YEAR).await;
+    context.insert_month(MonthNum::January, YEAR).await;
+    let accounts = setup_accounts(&context, &["Broker", "Savings"]).await;
+    context
+        .set_balances(&[
+            observed(accounts[0].id, 6, 30, 1000),
+            observed(accounts[1].id, 6, 1, 2000),
+        ])
+        .await;
+
+    let asked = resource("Investments", vec![accounts[0].id]);
+    let other = resource("Savings", vec![accounts[1].id]);
+    context.set_resources(&[asked.clone(), other.clone()]).await;
+
+    let response = context
+        .service()
+        .backfill_fin_res(
+            YEAR,
+            MonthNum::June,
+            Some(ResourcesToRefresh {
+                ids: vec![asked.base.id],
+            }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response, vec![asked.base.id]);
+
+    // The resource already backfilled is left as is.
+    let response = context
+        .service()
+        .backfill_fin_res(YEAR, MonthNum::June, None)
+        .await
+        .unwrap();
+
+    assert_eq!(response, vec![other.base.id]);
+}
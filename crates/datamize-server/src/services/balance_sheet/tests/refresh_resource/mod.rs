@@ -1,2 +1,3 @@
+mod backfill;
 mod refresh;
 mod testutils;
@@ -1,11 +1,12 @@
 use chrono::{Datelike, Local};
 use datamize_domain::{
-    BaseFinancialResource, FinancialResourceYearly, ResourcesToRefresh, Uuid, YearlyBalances,
+    BaseFinancialResource, FinancialResourceYearly, MonthNum, ResourcesToRefresh, Uuid,
+    WebScrapingAccount, YearlyBalances,
 };
 use fake::{Fake, Faker};
 use pretty_assertions::{assert_eq, assert_ne};
 use sqlx::SqlitePool;
-use ynab::Account;
+use ynab::{Account, Milliunits};
 
 use crate::services::{
     balance_sheet::tests::refresh_resource::testutils::{correctly_stub_resources, TestContext},
@@ -21,11 +22,8 @@ async fn check_refresh(
     expected_resp: Option<Vec<Uuid>>,
     expected_err: Option<ErrorType>,
 ) {
-    let context = match create_year {
-        true => TestContext::setup(pool, 1, ynab_accounts),
-        false => TestContext::setup(pool, 0, ynab_accounts),
-    }
-    .await;
+    // YNAB is called even when the year is missing, the year being created by the refresh.
+    let context = TestContext::setup(pool, 1, ynab_accounts).await;
 
     let date = Local::now().date_naive();
     let year = date.year();
@@ -65,7 +63,7 @@ async fn check_refresh(
 
             let saved_month = saved_month.unwrap();
             // Since net_assets are computed from all resources' type
-            assert_ne!(saved_month.net_assets().total, 0);
+            assert_ne!(saved_month.net_assets().total, Milliunits::ZERO);
 
             let saved_resources = context.get_resources().await;
             // At least one resource should have been updated
@@ -77,17 +75,52 @@ async fn check_refresh(
 }
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
-async fn returns_error_not_found_when_no_year(pool: SqlitePool) {
-    check_refresh(
-        pool,
-        false,
-        None,
-        fake::vec![Account; 3..6],
-        fake::vec![FinancialResourceYearly; 3..6],
-        None,
-        Some(ErrorType::NotFound),
-    )
-    .await;
+async fn creates_the_current_year_and_month_when_missing(pool: SqlitePool) {
+    let context = TestContext::setup(pool, 1, fake::vec![Account; 3..6]).await;
+    let date = Local::now().date_naive();
+
+    let response = context.service().refresh_fin_res(None).await.unwrap();
+
+    assert!(response.is_empty());
+    assert!(context
+        .get_month_data(date.month().try_into().unwrap(), date.year())
+        .await
+        .is_ok());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn carries_the_resources_over_to_the_current_month(pool: SqlitePool) {
+    let context = TestContext::setup(pool, 1, vec![]).await;
+    let date = Local::now().date_naive();
+    let month: MonthNum = date.month().try_into().unwrap();
+    let (prev_year, prev_month) = match month {
+        MonthNum::January => (date.year() - 1, MonthNum::December),
+        _ => (date.year(), month.pred()),
+    };
+    context.insert_year(prev_year).await;
+    context.insert_month(prev_month, prev_year).await;
+    let mut res = FinancialResourceYearly {
+        base: BaseFinancialResource {
+            ynab_account_ids: None,
+            external_account_ids: None,
+            ..Faker.fake()
+        },
+        balances: Default::default(),
+    };
+    res.insert_balance(prev_year, prev_month, Milliunits::new(1000));
+    context.set_resources(&[res]).await;
+
+    let response = context.service().refresh_fin_res(None).await.unwrap();
+
+    assert!(response.is_empty());
+    assert_eq!(
+        context
+            .get_resources()
+            .await
+            .iter()
+            .find_map(|r| r.get_balance(date.year(), month)),
+        Some(Milliunits::new(1000))
+    );
 }
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
@@ -166,7 +199,7 @@ async fn only_update_when_different_balance(pool: SqlitePool) {
     let current_date = Local::now().date_naive();
     let month = current_date.month().try_into().unwrap();
     let year = current_date.year();
-    resource.insert_balance(year, month, ynab_account.balance.abs());
+    resource.insert_balance(year, month, ynab_account.balance.abs().into());
 
     check_refresh(
         pool,
@@ -227,3 +260,74 @@ async fn only_update_requested_ids(pool: SqlitePool) {
 }
 
 //TODO: Add test for external accounts
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn keeps_refreshing_when_an_external_account_fails(pool: SqlitePool) {
+    let context = TestContext::setup(pool, 1, vec![]).await;
+    let date = Local::now().date_naive();
+    let (year, month) = (date.year(), date.month().try_into().unwrap());
+    context.insert_year(year).await;
+    context.insert_month(month, year).await;
+
+    let manual = WebScrapingAccount {
+        id: Uuid::new_v4(),
+        name: "House".to_string(),
+        balance: Milliunits::new(300000),
+        ..Default::default()
+    };
+    let failing = WebScrapingAccount {
+        id: Uuid::new_v4(),
+        name: "Broker".to_string(),
+        balance: Milliunits::new(2000),
+        scraper_id: Some("unknown".to_string()),
+        ..Default::default()
+    };
+    context
+        .set_external_accounts(&[manual.clone(), failing.clone()])
+        .await;
+
+    let mut refreshed = FinancialResourceYearly::new(
+        Uuid::new_v4(),
+        "House".to_string(),
+        Faker.fake(),
+        None,
+        Some(vec![manual.id]),
+    );
+    let mut partially_refreshed = FinancialResourceYearly::new(
+        Uuid::new_v4(),
+        "Investments".to_string(),
+        Faker.fake(),
+        None,
+        Some(vec![manual.id, failing.id]),
+    );
+    refreshed.insert_balance(year, month, Milliunits::new(1000));
+    partially_refreshed.insert_balance(year, month, Milliunits::new(1000));
+    context
+        .set_resources(&[refreshed.clone(), partially_refreshed.clone()])
+        .await;
+
+    let response = context.service().refresh_fin_res(None).await.unwrap();
+
+    assert_eq!(response, vec![refreshed.base.id]);
+    let saved_resources = context.get_resources().await;
+    let saved_balance = |id: Uuid| {
+        saved_resources
+            .iter()
+            .find(|r| r.base.id == id)
+            .and_then(|r| r.get_balance(year, month))
+    };
+    assert_eq!(
+        saved_balance(refreshed.base.id),
+        Some(Milliunits::new(300000))
+    );
+    assert_eq!(
+        saved_balance(partially_refreshed.base.id),
+        Some(Milliunits::new(1000))
+    );
+
+    let refresh = context.get_last_refresh(failing.id).await.unwrap();
+    assert!(!refresh.succeeded);
+    assert_eq!(refresh.balance, None);
+    assert_eq!(refresh.error, Some("No scraper \"unknown\"".to_string()));
+    assert!(context.get_last_refresh(manual.id).await.is_err());
+}
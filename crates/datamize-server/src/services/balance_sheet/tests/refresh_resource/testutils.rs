@@ -1,26 +1,41 @@
 use std::sync::Arc;
 
 use datamize_domain::{
-    db::{external::EncryptionKeyRepo, DbResult, FinResRepo, MonthData, MonthRepo, YearRepo},
-    FinancialResourceYearly, Month, MonthNum, Uuid, Year, YearlyBalances,
+    db::{
+        external::{
+            EncryptionKeyRepo, ExternalAccountBalanceRepo, ExternalAccountRefreshRepo,
+            ExternalAccountRepo,
+        },
+        DbResult, FinResRepo, MonthData, MonthRepo, YearRepo,
+    },
+    ExternalAccountBalance, ExternalAccountRefresh, FinancialResourceYearly, Month, MonthNum, Uuid,
+    WebScrapingAccount, Year, YearlyBalances,
 };
 use db_redis::{budget_providers::external::RedisEncryptionKeyRepo, get_test_pool};
 use db_sqlite::{
     balance_sheet::{SqliteFinResRepo, SqliteMonthRepo, SqliteYearRepo},
-    budget_providers::external::SqliteExternalAccountRepo,
+    budget_providers::external::{
+        SqliteExternalAccountBalanceRepo, SqliteExternalAccountRefreshRepo,
+        SqliteExternalAccountRepo,
+    },
 };
 use sqlx::SqlitePool;
 use ynab::{Account, MockAccountRequestsImpl};
 
 use crate::services::{
-    balance_sheet::{DynRefreshFinResService, RefreshFinResService, RefreshFinResServiceExt},
-    budget_providers::ExternalAccountService,
+    balance_sheet::{
+        DynRefreshFinResService, RefreshFinResService, RefreshFinResServiceExt, RolloverService,
+    },
+    budget_providers::{ExternalAccountService, PendingChallenges, ScraperRegistry},
 };
 
 pub(crate) struct TestContext {
     year_repo: Arc<SqliteYearRepo>,
     month_repo: Arc<SqliteMonthRepo>,
     fin_res_repo: Arc<SqliteFinResRepo>,
+    external_account_repo: Arc<SqliteExternalAccountRepo>,
+    external_account_refresh_repo: Arc<SqliteExternalAccountRefreshRepo>,
+    external_account_balance_repo: Arc<SqliteExternalAccountBalanceRepo>,
     fin_res_service: DynRefreshFinResService,
 }
 
@@ -37,26 +52,42 @@ impl TestContext {
         let external_account_repo = SqliteExternalAccountRepo::new_arced(pool.clone());
         let encryption_key_repo = RedisEncryptionKeyRepo::new_arced(redis_conn_pool);
         encryption_key_repo.set(&fake::vec![u8; 6]).await.unwrap();
-        let external_account_service =
-            ExternalAccountService::new_arced(external_account_repo.clone(), encryption_key_repo);
+        let external_account_refresh_repo =
+            SqliteExternalAccountRefreshRepo::new_arced(pool.clone());
+        let external_account_balance_repo =
+            SqliteExternalAccountBalanceRepo::new_arced(pool.clone());
+        let external_account_service = ExternalAccountService::new_arced(
+            external_account_repo.clone(),
+            encryption_key_repo,
+            external_account_refresh_repo.clone(),
+            external_account_balance_repo.clone(),
+            PendingChallenges::default(),
+            Arc::new(ScraperRegistry::default()),
+            "http://localhost:4444/".to_string(),
+        );
         let mut ynab_client = Arc::new(MockAccountRequestsImpl::new());
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_accounts()
             .times(ynab_calls)
-            .returning(move || Ok(ynab_accounts.clone()));
+            .returning(move |_| Ok(ynab_accounts.clone()));
 
         let fin_res_service = RefreshFinResService::new_arced(
             fin_res_repo.clone(),
             month_repo.clone(),
             year_repo.clone(),
+            RolloverService::new_arced(fin_res_repo.clone(), month_repo.clone(), year_repo.clone()),
             external_account_service,
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
         );
         Self {
             year_repo,
             month_repo,
             fin_res_repo,
+            external_account_repo,
+            external_account_refresh_repo,
+            external_account_balance_repo,
             fin_res_service,
         }
     }
@@ -98,6 +129,27 @@ impl TestContext {
             self.fin_res_repo.update(res).await.unwrap();
         }
     }
+
+    pub(crate) async fn set_external_accounts(&self, accounts: &[WebScrapingAccount]) {
+        for a in accounts {
+            self.external_account_repo.add(a).await.unwrap();
+        }
+    }
+
+    pub(crate) async fn get_last_refresh(
+        &self,
+        account_id: Uuid,
+    ) -> DbResult<ExternalAccountRefresh> {
+        self.external_account_refresh_repo
+            .get_last(account_id)
+            .await
+    }
+
+    pub(crate) async fn set_balances(&self, balances: &[ExternalAccountBalance]) {
+        for b in balances {
+            self.external_account_balance_repo.add(b).await.unwrap();
+        }
+    }
 }
 
 /// Will make sure the resources have the appropriate date associated to them
@@ -0,0 +1,137 @@
+use datamize_domain::{
+    db::DynRepoRegistry, AssetType, FinancialResourceType, FinancialResourceYearly, LiabilityType,
+    Month, MonthNum, Uuid, Year, YearlyBalances,
+};
+use db_memory::MemoryRepoRegistry;
+use db_sqlite::SqliteRepoRegistry;
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use ynab::Milliunits;
+
+use crate::services::balance_sheet::{RolloverService, RolloverServiceExt};
+
+fn service(repos: &DynRepoRegistry) -> RolloverService {
+    RolloverService {
+        fin_res_repo: repos.fin_res_repo(),
+        month_repo: repos.month_repo(),
+        year_repo: repos.year_repo(),
+    }
+}
+
+async fn add_resource(
+    repos: &DynRepoRegistry,
+    name: &str,
+    resource_type: FinancialResourceType,
+    balances: &[(i32, MonthNum, i64)],
+) -> FinancialResourceYearly {
+    let mut resource =
+        FinancialResourceYearly::new(Uuid::new_v4(), name.to_owned(), resource_type, None, None);
+    for &(year, month, balance) in balances {
+        resource.insert_balance(year, month, Milliunits::new(balance));
+    }
+    repos.fin_res_repo().update(&resource).await.unwrap();
+    resource
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn creates_the_new_year_and_carries_over_active_resources(pool: SqlitePool) {
+    let repos: DynRepoRegistry = SqliteRepoRegistry::new_arced(pool);
+    repos.year_repo().add(&Year::new(2023)).await.unwrap();
+    repos
+        .month_repo()
+        .add(&Month::new(MonthNum::December, 2023), 2023)
+        .await
+        .unwrap();
+    let bank = add_resource(
+        &repos,
+        "Bank",
+        FinancialResourceType::Asset(AssetType::Cash),
+        &[(2023, MonthNum::December, 5000)],
+    )
+    .await;
+    let loan = add_resource(
+        &repos,
+        "Loan",
+        FinancialResourceType::Liability(LiabilityType::Cash),
+        &[(2023, MonthNum::December, 2000)],
+    )
+    .await;
+    add_resource(
+        &repos,
+        "Closed",
+        FinancialResourceType::Asset(AssetType::Cash),
+        &[(2023, MonthNum::December, 0)],
+    )
+    .await;
+
+    let rollover = service(&repos)
+        .rollover(2024, MonthNum::January)
+        .await
+        .unwrap();
+
+    assert!(rollover.year_created);
+    assert!(rollover.month_created);
+    let mut carried_over = rollover.carried_over.clone();
+    carried_over.sort();
+    let mut expected = vec![bank.base.id, loan.base.id];
+    expected.sort();
+    assert_eq!(carried_over, expected);
+    assert_eq!(
+        rollover.to_string(),
+        "created year 2024, created January 2024, carried over 2 resource(s)"
+    );
+
+    let bank = repos.fin_res_repo().get(bank.base.id).await.unwrap();
+    assert_eq!(
+        bank.get_balance(2024, MonthNum::January),
+        Some(Milliunits::new(5000))
+    );
+    assert_eq!(
+        bank.get_balance(2023, MonthNum::December),
+        Some(Milliunits::new(5000))
+    );
+    let month = repos
+        .month_repo()
+        .get(MonthNum::January, 2024)
+        .await
+        .unwrap();
+    assert_eq!(month.net_totals.assets.total, Milliunits::new(3000));
+}
+
+#[tokio::test]
+async fn leaves_balances_already_set_untouched() {
+    let repos: DynRepoRegistry = MemoryRepoRegistry::new_arced();
+    repos.year_repo().add(&Year::new(2024)).await.unwrap();
+    for month in [MonthNum::January, MonthNum::February] {
+        repos
+            .month_repo()
+            .add(&Month::new(month, 2024), 2024)
+            .await
+            .unwrap();
+    }
+    let bank = add_resource(
+        &repos,
+        "Bank",
+        FinancialResourceType::Asset(AssetType::Cash),
+        &[
+            (2024, MonthNum::January, 5000),
+            (2024, MonthNum::February, 7000),
+        ],
+    )
+    .await;
+
+    let rollover = service(&repos)
+        .rollover(2024, MonthNum::February)
+        .await
+        .unwrap();
+
+    assert!(!rollover.year_created);
+    assert!(!rollover.month_created);
+    assert!(rollover.carried_over.is_empty());
+    assert_eq!(rollover.to_string(), "February 2024 already up to date");
+    let bank = repos.fin_res_repo().get(bank.base.id).await.unwrap();
+    assert_eq!(
+        bank.get_balance(2024, MonthNum::February),
+        Some(Milliunits::new(7000))
+    );
+}
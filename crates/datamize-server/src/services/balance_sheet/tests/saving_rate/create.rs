@@ -1,8 +1,8 @@
-use datamize_domain::{SaveSavingRate, SavingRate};
+use datamize_domain::{SaveIncomes, SaveSavingRate, SavingRate};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use sqlx::SqlitePool;
-use ynab::TransactionDetail;
+use ynab::{Milliunits, TransactionDetail};
 
 use crate::services::{
     balance_sheet::{tests::saving_rate::testutils::TestContext, SavingRateServiceExt},
@@ -19,6 +19,7 @@ fn are_equal(a: &SavingRate, b: &SavingRate) {
     assert_eq!(a.savings.extra_balance, b.savings.extra_balance);
     assert_eq!(a.incomes.payee_ids, b.incomes.payee_ids);
     assert_eq!(a.incomes.extra_balance, b.incomes.extra_balance);
+    assert_eq!(a.target, b.target);
 }
 
 async fn check_create(
@@ -77,3 +78,62 @@ async fn returns_error_already_exists_when_saving_rate_already_exists(pool: Sqli
     }
     check_create(pool, false, body, None, Some(ErrorType::AlreadyExist)).await;
 }
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn allows_the_same_name_on_another_year(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let body: SaveSavingRate = Faker.fake();
+    let previous_year = body.year - 1;
+    context.insert_year(previous_year).await;
+    context.insert_year(body.year).await;
+    context
+        .set_saving_rates(&[SavingRate {
+            id: Faker.fake(),
+            year: previous_year,
+            ..body.clone().into()
+        }])
+        .await;
+
+    let response = context.service().create_saving_rate(body.clone()).await;
+
+    are_equal(&response.unwrap(), &body.into());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_invalid_target_when_target_is_not_a_rate(pool: SqlitePool) {
+    let body = SaveSavingRate {
+        target: Some(1.5),
+        ..Faker.fake()
+    };
+    check_create(
+        pool,
+        true,
+        body,
+        None,
+        Some(ErrorType::InvalidSavingRateTarget),
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_amount_overflow_when_the_totals_overflow(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let payee_id = Faker.fake();
+    let body = SaveSavingRate {
+        incomes: SaveIncomes {
+            payee_ids: vec![payee_id],
+            extra_balance: Milliunits::new(i64::MAX),
+        },
+        ..Faker.fake()
+    };
+    context.insert_year(body.year).await;
+    let mut transaction: TransactionDetail = Faker.fake();
+    transaction.base.date = chrono::NaiveDate::from_ymd_opt(body.year, 3, 15).unwrap();
+    transaction.base.amount = 1000000;
+    transaction.base.payee_id = Some(payee_id);
+    context.set_transactions(&[transaction]).await;
+
+    let response = context.service().create_saving_rate(body).await;
+
+    assert_err(response.unwrap_err(), Some(ErrorType::AmountOverflow));
+}
@@ -39,7 +39,7 @@ async fn check_get_all(
     if let Some(mut expected_resp) = expected_resp {
         expected_resp
             .iter_mut()
-            .for_each(|resp| resp.compute_totals(&transactions));
+            .for_each(|resp| resp.compute_totals(&transactions).unwrap());
         assert_eq!(response.unwrap(), expected_resp);
     } else {
         assert_err(response.unwrap_err(), expected_err);
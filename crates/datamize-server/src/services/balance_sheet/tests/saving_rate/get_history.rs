@@ -0,0 +1,56 @@
+use datamize_domain::{SavingRate, SavingRateHistoryEntry};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use ynab::TransactionDetail;
+
+use crate::services::balance_sheet::{
+    tests::saving_rate::testutils::TestContext, SavingRateServiceExt,
+};
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn empty_list_when_nothing_in_db(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+
+    let response = context.into_service().get_history("Global").await;
+
+    assert_eq!(response.unwrap(), vec![]);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_every_year_of_the_same_name_ordered_by_year(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let name: String = Faker.fake();
+    let mut saving_rates = vec![];
+    for (year, target) in [(2024, Some(0.2)), (2022, None), (2023, Some(0.5))] {
+        context.insert_year(year).await;
+        saving_rates.push(SavingRate {
+            name: name.clone(),
+            year,
+            target,
+            ..Faker.fake()
+        });
+    }
+    let other = SavingRate {
+        year: 2023,
+        ..Faker.fake()
+    };
+    context.set_saving_rates(&saving_rates).await;
+    context.set_saving_rates(&[other]).await;
+
+    let transactions = fake::vec![TransactionDetail; 1..5];
+    context.set_transactions(&transactions).await;
+
+    let response = context.into_service().get_history(&name).await;
+
+    saving_rates.sort_by_key(|s| s.year);
+    let expected: Vec<SavingRateHistoryEntry> = saving_rates
+        .iter_mut()
+        .map(|s| {
+            s.compute_totals(&transactions);
+            (&*s).into()
+        })
+        .collect();
+    assert_eq!(response.unwrap(), expected);
+    assert_eq!(expected[0].meets_target, None);
+}
@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use datamize_domain::{Incomes, SavingRate, Savings, Uuid};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
@@ -23,6 +24,10 @@ async fn check_get(
     assert_eq!(actual, expected_resp);
 }
 
+fn in_year(year: i32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, (1..=12).fake(), (1..=28).fake()).unwrap()
+}
+
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
 async fn empty_when_no_categories_or_payees(pool: SqlitePool) {
     let saving_rate = SavingRate {
@@ -88,6 +93,7 @@ async fn returns_multiple_transactions_of_linked_categories_only(pool: SqlitePoo
     let trans1 = TransactionDetail {
         base: BaseTransactionDetail {
             category_id: Some(cat_id1),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -96,6 +102,7 @@ async fn returns_multiple_transactions_of_linked_categories_only(pool: SqlitePoo
     let trans2 = TransactionDetail {
         base: BaseTransactionDetail {
             category_id: Some(cat_id2),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -126,6 +133,7 @@ async fn returns_all_transactions_of_same_linked_categories_only(pool: SqlitePoo
     let trans1 = TransactionDetail {
         base: BaseTransactionDetail {
             category_id: Some(cat_id1),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -134,6 +142,7 @@ async fn returns_all_transactions_of_same_linked_categories_only(pool: SqlitePoo
     let trans2 = TransactionDetail {
         base: BaseTransactionDetail {
             category_id: Some(cat_id1),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -164,6 +173,7 @@ async fn returns_multiple_transactions_of_linked_payees_only(pool: SqlitePool) {
     let trans1 = TransactionDetail {
         base: BaseTransactionDetail {
             payee_id: Some(payee_id1),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -172,6 +182,7 @@ async fn returns_multiple_transactions_of_linked_payees_only(pool: SqlitePool) {
     let trans2 = TransactionDetail {
         base: BaseTransactionDetail {
             payee_id: Some(payee_id2),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -202,6 +213,7 @@ async fn returns_all_transactions_of_same_linked_payee_only(pool: SqlitePool) {
     let trans1 = TransactionDetail {
         base: BaseTransactionDetail {
             payee_id: Some(payee_id1),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -210,6 +222,7 @@ async fn returns_all_transactions_of_same_linked_payee_only(pool: SqlitePool) {
     let trans2 = TransactionDetail {
         base: BaseTransactionDetail {
             payee_id: Some(payee_id2),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -241,6 +254,7 @@ async fn does_not_return_twice_same_transaction(pool: SqlitePool) {
         base: BaseTransactionDetail {
             payee_id: Some(payee_id1),
             category_id: Some(cat_id1),
+            date: in_year(saving_rate.year),
             ..Faker.fake()
         },
         ..Faker.fake()
@@ -250,3 +264,38 @@ async fn does_not_return_twice_same_transaction(pool: SqlitePool) {
 
     check_get(pool, &fake_trans, &saving_rate, vec![trans1]).await;
 }
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_only_transactions_of_the_saving_rate_year(pool: SqlitePool) {
+    let payee_id1 = Faker.fake();
+    let saving_rate = SavingRate {
+        savings: Savings {
+            category_ids: vec![],
+            ..Faker.fake()
+        },
+        incomes: Incomes {
+            payee_ids: vec![payee_id1],
+            ..Faker.fake()
+        },
+        ..Faker.fake()
+    };
+
+    let trans1 = TransactionDetail {
+        base: BaseTransactionDetail {
+            payee_id: Some(payee_id1),
+            date: in_year(saving_rate.year),
+            ..Faker.fake()
+        },
+        ..Faker.fake()
+    };
+    let trans2 = TransactionDetail {
+        base: BaseTransactionDetail {
+            payee_id: Some(payee_id1),
+            date: in_year(saving_rate.year - 1),
+            ..Faker.fake()
+        },
+        ..Faker.fake()
+    };
+
+    check_get(pool, &[trans1.clone(), trans2], &saving_rate, vec![trans1]).await;
+}
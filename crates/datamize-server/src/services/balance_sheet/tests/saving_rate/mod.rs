@@ -2,6 +2,7 @@ mod create;
 mod delete;
 mod get;
 mod get_all;
+mod get_history;
 mod get_transactions;
 pub(crate) mod testutils;
 mod update;
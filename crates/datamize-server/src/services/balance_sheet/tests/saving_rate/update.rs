@@ -19,6 +19,7 @@ fn are_equal(a: &SavingRate, b: &SavingRate) {
     assert_eq!(a.savings.extra_balance, b.savings.extra_balance);
     assert_eq!(a.incomes.payee_ids, b.incomes.payee_ids);
     assert_eq!(a.incomes.extra_balance, b.incomes.extra_balance);
+    assert_eq!(a.target, b.target);
 }
 
 async fn check_update(
@@ -81,7 +82,10 @@ async fn returns_error_not_found_when_nothing_in_db(pool: SqlitePool) {
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
 async fn returns_success_with_the_update(pool: SqlitePool) {
-    let body: SaveSavingRate = Faker.fake();
+    let body = SaveSavingRate {
+        target: Some(0.25),
+        ..Faker.fake()
+    };
     let expected_resp = SavingRate {
         savings: Savings {
             total: Faker.fake(),
@@ -99,7 +103,51 @@ async fn returns_success_with_the_update(pool: SqlitePool) {
         employee_contribution: body.employee_contribution,
         employer_contribution: body.employer_contribution,
         mortgage_capital: body.mortgage_capital,
+        rate: 0.0,
+        target: body.target,
+        months: vec![],
     };
 
     check_update(pool, true, body, Some(expected_resp), None).await;
 }
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_invalid_target_when_target_is_not_a_rate(pool: SqlitePool) {
+    let body = SaveSavingRate {
+        target: Some(-0.1),
+        ..Faker.fake()
+    };
+    check_update(
+        pool,
+        true,
+        body,
+        None,
+        Some(ErrorType::InvalidSavingRateTarget),
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_already_exists_when_name_is_taken_on_the_year(pool: SqlitePool) {
+    let context = TestContext::setup(pool).await;
+    let body: SaveSavingRate = Faker.fake();
+    let previous_year = body.year - 1;
+    context.insert_year(previous_year).await;
+    context.insert_year(body.year).await;
+    context
+        .set_saving_rates(&[
+            SavingRate {
+                id: Faker.fake(),
+                ..body.clone().into()
+            },
+            SavingRate {
+                year: previous_year,
+                ..body.clone().into()
+            },
+        ])
+        .await;
+
+    let response = context.service().update_saving_rate(body).await;
+
+    assert_err(response.unwrap_err(), Some(ErrorType::AlreadyExist));
+}
@@ -7,6 +7,7 @@ use crate::services::{
     balance_sheet::tests::year::testutils::{correctly_stub_year, TestContext},
     testutils::{assert_err, ErrorType},
 };
+use ynab::Milliunits;
 fn are_equal(a: &Year, b: &Year) {
     assert_eq!(a.year, b.year);
     assert_eq!(a.net_assets().total, b.net_assets().total);
@@ -80,9 +81,9 @@ async fn update_net_totals_if_prev_year_exists(pool: SqlitePool) {
         year: body.year,
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_year.net_assets().total,
-                percent_var: if prev_year.net_assets().total == 0 {
+                percent_var: if prev_year.net_assets().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -90,9 +91,9 @@ async fn update_net_totals_if_prev_year_exists(pool: SqlitePool) {
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_year.net_portfolio().total,
-                percent_var: if prev_year.net_portfolio().total == 0 {
+                percent_var: if prev_year.net_portfolio().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -130,9 +131,9 @@ async fn update_net_totals_of_prev_and_next_years_if_exists(pool: SqlitePool) {
         year: body.year,
         net_totals: NetTotals {
             assets: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_year.net_assets().total,
-                percent_var: if prev_year.net_assets().total == 0 {
+                percent_var: if prev_year.net_assets().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -140,9 +141,9 @@ async fn update_net_totals_of_prev_and_next_years_if_exists(pool: SqlitePool) {
                 ..Faker.fake()
             },
             portfolio: NetTotal {
-                total: 0,
+                total: Milliunits::ZERO,
                 balance_var: -prev_year.net_portfolio().total,
-                percent_var: if prev_year.net_portfolio().total == 0 {
+                percent_var: if prev_year.net_portfolio().total == Milliunits::ZERO {
                     0.0
                 } else {
                     -1.0
@@ -1,4 +0,0 @@
-pub mod parsing;
-pub mod rpp;
-pub mod rrsp;
-pub mod tfsa;
@@ -1,26 +1,75 @@
-mod internal;
+mod scrapers;
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
+use anyhow::anyhow;
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, Utc};
 use datamize_domain::{
     async_trait,
-    db::external::{DynEncryptionKeyRepo, DynExternalAccountRepo},
-    AccountType, ExternalAccount, Uuid, WebScrapingAccount,
+    db::{
+        external::{
+            DynEncryptionKeyRepo, DynExternalAccountBalanceRepo, DynExternalAccountRefreshRepo,
+            DynExternalAccountRepo,
+        },
+        DbError,
+    },
+    BalanceHistoryParams, BalanceSource, ExternalAccount, ExternalAccountBalance,
+    ExternalAccountRefresh, ExternalAccountStatus, ImportedStatement, MonthNum, PendingChallenge,
+    Statement, Uuid, WebScrapingAccount,
 };
-use futures::{future::BoxFuture, stream::FuturesOrdered, StreamExt};
-use internal::*;
+use futures::{stream::FuturesOrdered, StreamExt};
+pub use scrapers::*;
 
 use orion::kex::SecretKey;
 
-use crate::{config, error::DatamizeResult};
+use ynab::Milliunits;
+
+use crate::error::{AppError, DatamizeResult};
 
 #[async_trait]
 pub trait ExternalAccountServiceExt: Send + Sync {
     async fn get_all_external_accounts(&self) -> DatamizeResult<Vec<ExternalAccount>>;
+    /// Refreshes the accounts with their scraper and records how each refresh went.
+    /// Only the accounts with a current balance are returned, so the ones whose refresh
+    /// failed are left out.
     async fn refresh_web_scraping_accounts(
         &self,
         accounts_to_refresh: Vec<Uuid>,
     ) -> DatamizeResult<Vec<WebScrapingAccount>>;
+    async fn get_external_account_statuses(&self) -> DatamizeResult<Vec<ExternalAccountStatus>>;
+    /// Updates the balance of the account with the one of the statement, recorded as a refresh
+    /// so the account is used like the scraped ones.
+    async fn import_statement(
+        &self,
+        account_id: Uuid,
+        statement: Statement,
+    ) -> DatamizeResult<ImportedStatement>;
+    /// Keeps the balance in the history of the account. It only becomes the balance of the
+    /// account when no later one was observed.
+    async fn add_balance(
+        &self,
+        account_id: Uuid,
+        balance: Milliunits,
+        observed_at: DateTime<Utc>,
+        source: BalanceSource,
+    ) -> DatamizeResult<ExternalAccountBalance>;
+    async fn get_balance_history(
+        &self,
+        account_id: Uuid,
+        params: BalanceHistoryParams,
+    ) -> DatamizeResult<Vec<ExternalAccountBalance>>;
+    /// The last balance observed during the month of each account, leaving out the accounts
+    /// without one.
+    async fn get_balances_of_month(
+        &self,
+        account_ids: &[Uuid],
+        year: i32,
+        month: MonthNum,
+    ) -> DatamizeResult<Vec<ExternalAccountBalance>>;
+    /// The refreshes waiting for a one-time code sent by SMS or email.
+    fn get_pending_challenges(&self) -> Vec<PendingChallenge>;
+    /// Hands the code to the refresh of the account waiting for it.
+    fn submit_challenge_code(&self, account_id: Uuid, code: String) -> DatamizeResult<()>;
 
     async fn create_external_account(&self, account: &WebScrapingAccount) -> DatamizeResult<()>;
     async fn get_external_account_by_name(&self, name: &str) -> DatamizeResult<WebScrapingAccount>;
@@ -28,6 +77,9 @@ pub trait ExternalAccountServiceExt: Send + Sync {
 
     async fn get_encryption_key(&self) -> DatamizeResult<Vec<u8>>;
     async fn set_encryption_key(&self, key: &[u8]) -> DatamizeResult<()>;
+    /// The key the passwords are encrypted with. It is only created while no account is saved,
+    /// since a new key could not decrypt the passwords of the existing ones.
+    async fn get_or_create_encryption_key(&self) -> DatamizeResult<SecretKey>;
 }
 
 pub type DynExternalAccountService = Arc<dyn ExternalAccountServiceExt>;
@@ -36,6 +88,11 @@ pub type DynExternalAccountService = Arc<dyn ExternalAccountServiceExt>;
 pub struct ExternalAccountService {
     pub external_account_repo: DynExternalAccountRepo,
     pub encryption_key_repo: DynEncryptionKeyRepo,
+    pub external_account_refresh_repo: DynExternalAccountRefreshRepo,
+    pub external_account_balance_repo: DynExternalAccountBalanceRepo,
+    pub pending_challenges: PendingChallenges,
+    pub scraper_registry: Arc<ScraperRegistry>,
+    pub webdriver_location: String,
 }
 
 #[async_trait]
@@ -56,87 +113,268 @@ impl ExternalAccountServiceExt for ExternalAccountService {
         &self,
         accounts_to_refresh: Vec<Uuid>,
     ) -> DatamizeResult<Vec<WebScrapingAccount>> {
-        let configuration = config::Settings::build()?;
-        let webdriver_location = configuration.webdriver.connection_string();
-
-        let encryption_key = match self.encryption_key_repo.get().await {
-            Ok(ref val) => SecretKey::from_slice(val).unwrap_or_default(),
-            Err(_) => {
-                let key = SecretKey::default();
-                self.encryption_key_repo
-                    .set(key.unprotected_as_bytes())
-                    .await?;
-                key
-            }
-        };
+        let encryption_key = self.get_or_create_encryption_key().await?;
 
         let mut initial_accounts = self.external_account_repo.get_all().await?;
         initial_accounts.retain(|account| accounts_to_refresh.contains(&account.id));
-        let updated_accounts = initial_accounts
-            .clone()
-            .into_iter()
+        let refreshes = initial_accounts
+            .iter()
             .map(|account| {
-                let r: BoxFuture<_> = match account.account_type {
-                    AccountType::Tfsa => Box::pin(tfsa::get_tfsa(
-                        account,
-                        &encryption_key,
-                        &webdriver_location,
-                    )),
-                    AccountType::Rpp => Box::pin(rpp::get_rpp_canada_life_sandryne(
-                        account,
-                        &encryption_key,
-                        &webdriver_location,
-                    )),
-                    AccountType::Rrsp => Box::pin(rrsp::get_rrsp_ia_sandryne(
-                        account,
-                        &encryption_key,
-                        &webdriver_location,
-                    )),
-                    _ => Box::pin(async { Ok(account) }),
-                };
-                r
+                scrape_balance(
+                    account,
+                    &self.scraper_registry,
+                    &encryption_key,
+                    &self.pending_challenges,
+                    &self.webdriver_location,
+                )
             })
-            .collect::<FuturesOrdered<BoxFuture<_>>>()
+            .collect::<FuturesOrdered<_>>()
             .collect::<Vec<_>>()
             .await;
 
         let mut accounts = vec![];
 
-        for (updated_account_res, i_account) in updated_accounts.into_iter().zip(initial_accounts) {
-            let account = match updated_account_res {
-                Ok(u_account) => {
-                    if u_account.balance != i_account.balance {
-                        match self.external_account_repo.update(&u_account).await {
-                            Ok(_) => u_account,
-                            Err(e) => {
-                                tracing::error!(
-                                    error.cause_chain = ?e,
-                                    error.message = %e,
-                                    "Failed to save latest balance for account {}. Skipping.",
-                                    i_account.name
-                                );
-                                i_account
-                            }
-                        }
-                    } else {
-                        i_account
-                    }
-                }
-                Err(e) => {
-                    tracing::error!(
+        for (account, refresh) in initial_accounts.into_iter().zip(refreshes) {
+            let Some(refresh) = refresh else {
+                accounts.push(account);
+                continue;
+            };
+
+            if let Err(e) = self.external_account_refresh_repo.add(&refresh).await {
+                tracing::error!(
+                    error.cause_chain = ?e,
+                    error.message = %e,
+                    "Failed to save the refresh of account {}.",
+                    account.name
+                );
+            }
+
+            let Some(balance) = refresh.balance else {
+                continue;
+            };
+
+            let observed = ExternalAccountBalance {
+                id: Uuid::new_v4(),
+                account_id: account.id,
+                observed_at: refresh.refreshed_at,
+                balance,
+                source: BalanceSource::Scrape,
+            };
+            if let Err(e) = self.external_account_balance_repo.add(&observed).await {
+                tracing::error!(
+                    error.cause_chain = ?e,
+                    error.message = %e,
+                    "Failed to save the balance history of account {}.",
+                    account.name
+                );
+            }
+
+            if balance != account.balance {
+                let account = WebScrapingAccount { balance, ..account };
+                match self.external_account_repo.update(&account).await {
+                    Ok(_) => accounts.push(account),
+                    Err(e) => tracing::error!(
                         error.cause_chain = ?e,
                         error.message = %e,
-                        "Failed to get latest balance for account {}. Skipping.",
-                        i_account.name
-                    );
-                    i_account
+                        "Failed to save latest balance for account {}. Skipping.",
+                        account.name
+                    ),
                 }
+            } else {
+                accounts.push(account);
+            }
+        }
+
+        Ok(accounts)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_external_account_statuses(&self) -> DatamizeResult<Vec<ExternalAccountStatus>> {
+        let now = Utc::now();
+        let mut statuses = vec![];
+
+        for account in self.external_account_repo.get_all().await? {
+            let last_refresh = match self
+                .external_account_refresh_repo
+                .get_last(account.id)
+                .await
+            {
+                Ok(refresh) => Some(refresh),
+                Err(DbError::NotFound) => None,
+                Err(e) => return Err(e.into()),
             };
+            let last_success = match last_refresh {
+                Some(ref refresh) if refresh.succeeded => Some(refresh.clone()),
+                Some(_) => match self
+                    .external_account_refresh_repo
+                    .get_last_success(account.id)
+                    .await
+                {
+                    Ok(refresh) => Some(refresh),
+                    Err(DbError::NotFound) => None,
+                    Err(e) => return Err(e.into()),
+                },
+                None => None,
+            };
+            let scraper_id = account.scraper_id.clone();
 
-            accounts.push(account);
+            statuses.push(ExternalAccountStatus::new(
+                account.into(),
+                scraper_id,
+                last_refresh,
+                last_success,
+                now,
+            ));
         }
 
-        Ok(accounts)
+        statuses.sort_by(|a, b| a.account.name.cmp(&b.account.name));
+
+        Ok(statuses)
+    }
+
+    #[tracing::instrument(skip(self, statement))]
+    async fn import_statement(
+        &self,
+        account_id: Uuid,
+        statement: Statement,
+    ) -> DatamizeResult<ImportedStatement> {
+        let previous_balance = self.external_account_repo.get(account_id).await?.balance;
+        let now = Utc::now();
+        // An older statement is kept as of the end of its day, so it doesn't hide the balances
+        // observed since.
+        let observed_at = statement
+            .balance_date
+            .and_then(end_of_day)
+            .filter(|end_of_day| end_of_day < &now)
+            .unwrap_or(now);
+
+        self.add_balance(
+            account_id,
+            statement.balance,
+            observed_at,
+            BalanceSource::Import,
+        )
+        .await?;
+        self.external_account_refresh_repo
+            .add(&ExternalAccountRefresh {
+                id: Uuid::new_v4(),
+                account_id,
+                refreshed_at: now,
+                duration_ms: 0,
+                succeeded: true,
+                balance: Some(statement.balance),
+                error: None,
+            })
+            .await?;
+        let account = self.external_account_repo.get(account_id).await?;
+
+        Ok(ImportedStatement {
+            account: account.into(),
+            previous_balance,
+            balance_date: statement.balance_date,
+            transactions: statement.transactions,
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add_balance(
+        &self,
+        account_id: Uuid,
+        balance: Milliunits,
+        observed_at: DateTime<Utc>,
+        source: BalanceSource,
+    ) -> DatamizeResult<ExternalAccountBalance> {
+        if observed_at > Utc::now() {
+            return Err(AppError::BalanceInTheFuture);
+        }
+
+        let account = self.external_account_repo.get(account_id).await?;
+        let is_latest = match self
+            .external_account_balance_repo
+            .get_last(account_id, None)
+            .await
+        {
+            Ok(last) => last.observed_at <= observed_at,
+            Err(DbError::NotFound) => true,
+            Err(e) => return Err(e.into()),
+        };
+
+        let observed = ExternalAccountBalance {
+            id: Uuid::new_v4(),
+            account_id,
+            observed_at,
+            balance,
+            source,
+        };
+        self.external_account_balance_repo.add(&observed).await?;
+
+        if is_latest && account.balance != balance {
+            self.external_account_repo
+                .update(&WebScrapingAccount { balance, ..account })
+                .await?;
+        }
+
+        Ok(observed)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_balance_history(
+        &self,
+        account_id: Uuid,
+        params: BalanceHistoryParams,
+    ) -> DatamizeResult<Vec<ExternalAccountBalance>> {
+        self.external_account_repo.get(account_id).await?;
+
+        Ok(self
+            .external_account_balance_repo
+            .get_all(account_id, params.from, params.to)
+            .await?)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_balances_of_month(
+        &self,
+        account_ids: &[Uuid],
+        year: i32,
+        month: MonthNum,
+    ) -> DatamizeResult<Vec<ExternalAccountBalance>> {
+        let next_month_year = match month {
+            MonthNum::December => year + 1,
+            _ => year,
+        };
+        let (Some(from), Some(to)) = (
+            first_day(year, month).and_then(start_of_day),
+            first_day(next_month_year, month.succ()).and_then(start_of_day),
+        ) else {
+            return Ok(vec![]);
+        };
+
+        let mut balances = vec![];
+        for &account_id in account_ids {
+            match self
+                .external_account_balance_repo
+                .get_last(account_id, Some(to))
+                .await
+            {
+                Ok(balance) if balance.observed_at >= from => balances.push(balance),
+                Ok(_) | Err(DbError::NotFound) => (),
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(balances)
+    }
+
+    fn get_pending_challenges(&self) -> Vec<PendingChallenge> {
+        self.pending_challenges.get_all()
+    }
+
+    #[tracing::instrument(skip(self, code))]
+    fn submit_challenge_code(&self, account_id: Uuid, code: String) -> DatamizeResult<()> {
+        match self.pending_challenges.submit(account_id, code) {
+            true => Ok(()),
+            false => Err(AppError::ResourceNotFound),
+        }
     }
 
     #[tracing::instrument(skip_all)]
@@ -163,16 +401,111 @@ impl ExternalAccountServiceExt for ExternalAccountService {
     async fn set_encryption_key(&self, key: &[u8]) -> DatamizeResult<()> {
         Ok(self.encryption_key_repo.set(key).await?)
     }
+
+    #[tracing::instrument(skip_all)]
+    async fn get_or_create_encryption_key(&self) -> DatamizeResult<SecretKey> {
+        get_or_create_encryption_key(&self.encryption_key_repo, &self.external_account_repo).await
+    }
+}
+
+/// Returns the key the passwords of the external accounts, and the YNAB OAuth tokens, are encrypted with.
+/// It is only created while no account is saved, since a new key could not decrypt the passwords of the
+/// existing ones. Any other failure is returned as well.
+pub async fn get_or_create_encryption_key(
+    encryption_key_repo: &DynEncryptionKeyRepo,
+    external_account_repo: &DynExternalAccountRepo,
+) -> DatamizeResult<SecretKey> {
+    match encryption_key_repo.get().await {
+        Ok(key) if !key.is_empty() => Ok(SecretKey::from_slice(&key)?),
+        Ok(_) | Err(DbError::NotFound) => {
+            if !external_account_repo.get_all().await?.is_empty() {
+                return Err(AppError::MissingEncryptionKey);
+            }
+
+            let key = SecretKey::default();
+            encryption_key_repo.set(key.unprotected_as_bytes()).await?;
+
+            Ok(key)
+        }
+        Err(e) => Err(e.into()),
+    }
 }
 
 impl ExternalAccountService {
     pub fn new_arced(
         external_account_repo: DynExternalAccountRepo,
         encryption_key_repo: DynEncryptionKeyRepo,
+        external_account_refresh_repo: DynExternalAccountRefreshRepo,
+        external_account_balance_repo: DynExternalAccountBalanceRepo,
+        pending_challenges: PendingChallenges,
+        scraper_registry: Arc<ScraperRegistry>,
+        webdriver_location: String,
     ) -> Arc<Self> {
         Arc::new(Self {
             external_account_repo,
             encryption_key_repo,
+            external_account_refresh_repo,
+            external_account_balance_repo,
+            pending_challenges,
+            scraper_registry,
+            webdriver_location,
         })
     }
 }
+
+fn first_day(year: i32, month: MonthNum) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month.to_num() as u32, 1)
+}
+
+fn start_of_day(date: NaiveDate) -> Option<DateTime<Utc>> {
+    date.and_time(NaiveTime::MIN)
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|d| d.with_timezone(&Utc))
+}
+
+fn end_of_day(date: NaiveDate) -> Option<DateTime<Utc>> {
+    date.succ_opt()
+        .and_then(start_of_day)
+        .map(|d| d - chrono::Duration::seconds(1))
+}
+
+/// Reads the balance of the account with its scraper. Accounts without a scraper are not refreshed.
+async fn scrape_balance(
+    account: &WebScrapingAccount,
+    scraper_registry: &ScraperRegistry,
+    encryption_key: &SecretKey,
+    challenges: &PendingChallenges,
+    webdriver_location: &str,
+) -> Option<ExternalAccountRefresh> {
+    let scraper_id = account.scraper_id.as_deref()?;
+    let refreshed_at = Utc::now();
+    let started = Instant::now();
+
+    let balance = match scraper_registry.get(scraper_id) {
+        Some(scraper) => match Credentials::from_account(account, encryption_key, challenges) {
+            Ok(credentials) => scraper.get_balance(&credentials, webdriver_location).await,
+            Err(e) => Err(e),
+        },
+        None => Err(anyhow!("No scraper {:?}", scraper_id)),
+    };
+
+    if let Err(ref e) = balance {
+        tracing::error!(
+            error.cause_chain = ?e,
+            error.message = %e,
+            "Failed to get latest balance for account {}. Skipping.",
+            account.name
+        );
+    }
+
+    Some(ExternalAccountRefresh {
+        id: Uuid::new_v4(),
+        account_id: account.id,
+        refreshed_at,
+        duration_ms: started.elapsed().as_millis().try_into().unwrap_or(i64::MAX),
+        succeeded: balance.is_ok(),
+        error: balance.as_ref().err().map(|e| format!("{:#}", e)),
+        balance: balance.ok(),
+    })
+}
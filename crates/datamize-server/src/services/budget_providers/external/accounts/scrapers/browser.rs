@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use datamize_domain::async_trait;
+use fantoccini::{Client, ClientBuilder, Locator};
+
+/// The actions recipes take on a website. Elements are located with CSS selectors.
+#[async_trait]
+pub trait Browser: Send {
+    async fn goto(&mut self, url: &str) -> anyhow::Result<()>;
+    async fn click(&mut self, selector: &str) -> anyhow::Result<()>;
+    /// Replaces the value of the input.
+    async fn fill(&mut self, selector: &str, value: &str) -> anyhow::Result<()>;
+    /// Submits the form, with the button when the form is not submitted by a regular submit button.
+    async fn submit(&mut self, form: &str, button: Option<&str>) -> anyhow::Result<()>;
+    async fn wait_for(&mut self, selector: &str, timeout: Duration) -> anyhow::Result<()>;
+    async fn text(&mut self, selector: &str) -> anyhow::Result<String>;
+
+    async fn pause(&mut self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
+}
+
+/// A browser driven through WebDriver.
+pub struct WebDriverBrowser {
+    client: Client,
+}
+
+impl WebDriverBrowser {
+    pub async fn connect(webdriver_location: &str) -> anyhow::Result<Self> {
+        let client = ClientBuilder::rustls().connect(webdriver_location).await?;
+
+        Ok(Self { client })
+    }
+
+    pub async fn close(self) -> anyhow::Result<()> {
+        Ok(self.client.close().await?)
+    }
+}
+
+#[async_trait]
+impl Browser for WebDriverBrowser {
+    async fn goto(&mut self, url: &str) -> anyhow::Result<()> {
+        Ok(self.client.goto(url).await?)
+    }
+
+    async fn click(&mut self, selector: &str) -> anyhow::Result<()> {
+        Ok(self
+            .client
+            .find(Locator::Css(selector))
+            .await?
+            .click()
+            .await?)
+    }
+
+    async fn fill(&mut self, selector: &str, value: &str) -> anyhow::Result<()> {
+        let input = self.client.find(Locator::Css(selector)).await?;
+        input.clear().await?;
+        Ok(input.send_keys(value).await?)
+    }
+
+    async fn submit(&mut self, form: &str, button: Option<&str>) -> anyhow::Result<()> {
+        let form = self.client.form(Locator::Css(form)).await?;
+        match button {
+            Some(button) => form.submit_with(Locator::Css(button)).await?,
+            None => form.submit().await?,
+        };
+
+        Ok(())
+    }
+
+    async fn wait_for(&mut self, selector: &str, timeout: Duration) -> anyhow::Result<()> {
+        self.client
+            .wait()
+            .every(Duration::from_secs(1))
+            .at_most(timeout)
+            .for_element(Locator::Css(selector))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn text(&mut self, selector: &str) -> anyhow::Result<String> {
+        Ok(self
+            .client
+            .find(Locator::Css(selector))
+            .await?
+            .text()
+            .await?)
+    }
+}
@@ -0,0 +1,145 @@
+mod browser;
+mod one_time_code;
+pub mod parsing;
+mod recipe;
+#[cfg(test)]
+mod tests;
+
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+
+use datamize_domain::{
+    async_trait,
+    secrecy::{ExposeSecret, Secret},
+    WebScrapingAccount,
+};
+use orion::{aead, kex::SecretKey};
+use ynab::Milliunits;
+
+pub use browser::*;
+pub use one_time_code::*;
+pub use recipe::*;
+
+/// What a scraper needs to log in the institution's website.
+pub struct Credentials {
+    pub username: String,
+    pub password: Secret<String>,
+    pub one_time_codes: OneTimeCodes,
+}
+
+impl Credentials {
+    /// Decrypts the password and the TOTP seed of the account. Without a seed, the one-time
+    /// codes are asked to the user through the challenges.
+    pub fn from_account(
+        account: &WebScrapingAccount,
+        encryption_key: &SecretKey,
+        challenges: &PendingChallenges,
+    ) -> anyhow::Result<Self> {
+        let password = String::from_utf8(aead::open(
+            encryption_key,
+            account.encrypted_password.expose_secret().as_ref(),
+        )?)?;
+        let one_time_codes = match account.encrypted_totp_seed {
+            Some(ref seed) => OneTimeCodes::Totp(Secret::new(String::from_utf8(aead::open(
+                encryption_key,
+                seed.expose_secret().as_ref(),
+            )?)?)),
+            None => OneTimeCodes::Challenge {
+                account_id: account.id,
+                account_name: account.name.clone(),
+                challenges: challenges.clone(),
+            },
+        };
+
+        Ok(Self {
+            username: account.username.clone(),
+            password: Secret::new(password),
+            one_time_codes,
+        })
+    }
+}
+
+#[async_trait]
+pub trait Scraper: Send + Sync {
+    /// The id external accounts refer to the scraper with.
+    fn id(&self) -> &str;
+    /// A name describing the institution and the account scraped.
+    fn name(&self) -> &str;
+    /// Logs in the institution's website with the WebDriver to read the balance of the account.
+    async fn get_balance(
+        &self,
+        credentials: &Credentials,
+        webdriver_location: &str,
+    ) -> anyhow::Result<Milliunits>;
+}
+
+pub type DynScraper = Arc<dyn Scraper>;
+
+/// The scrapers available to the external accounts, by their id.
+#[derive(Clone, Default)]
+pub struct ScraperRegistry {
+    scrapers: BTreeMap<String, DynScraper>,
+}
+
+const BUILTIN_RECIPES: [&str; 3] = [
+    include_str!("recipes/peak_tfsa.toml"),
+    include_str!("recipes/ia_rrsp.toml"),
+    include_str!("recipes/canada_life_rpp.toml"),
+];
+
+impl ScraperRegistry {
+    /// The built-in recipes, followed by the ones of the directory if there is one.
+    /// Recipes that can't be read are skipped so the other accounts are still refreshed.
+    pub fn load(recipes_directory: Option<&Path>) -> Self {
+        let mut registry = Self::with_builtin_recipes();
+        let Some(recipes_directory) = recipes_directory else {
+            return registry;
+        };
+
+        match read_recipes(recipes_directory) {
+            Ok(recipes) => {
+                for recipe in recipes {
+                    match recipe {
+                        Ok(recipe) => registry.register(RecipeScraper::new_arced(recipe)),
+                        Err(e) => tracing::error!(
+                            error.cause_chain = ?e,
+                            error.message = %e,
+                            "Failed to read a scraper recipe. Skipping."
+                        ),
+                    }
+                }
+            }
+            Err(e) => tracing::error!(
+                error.cause_chain = ?e,
+                error.message = %e,
+                "Failed to read the scraper recipes of {}.",
+                recipes_directory.display()
+            ),
+        }
+
+        registry
+    }
+
+    pub fn with_builtin_recipes() -> Self {
+        let mut registry = Self::default();
+        for recipe in BUILTIN_RECIPES {
+            let recipe = ScraperRecipe::from_toml(recipe).expect("built-in recipes are valid");
+            registry.register(RecipeScraper::new_arced(recipe));
+        }
+
+        registry
+    }
+
+    /// Adds the scraper, replacing the one with the same id.
+    pub fn register(&mut self, scraper: DynScraper) {
+        self.scrapers.insert(scraper.id().to_owned(), scraper);
+    }
+
+    pub fn get(&self, id: &str) -> Option<DynScraper> {
+        self.scrapers.get(id).cloned()
+    }
+
+    /// All the scrapers, sorted by their id.
+    pub fn scrapers(&self) -> impl Iterator<Item = &DynScraper> {
+        self.scrapers.values()
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context};
+use chrono::Utc;
+use datamize_domain::{
+    secrecy::{ExposeSecret, Secret},
+    PendingChallenge, Uuid,
+};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use tokio::sync::oneshot;
+
+/// How the one-time codes asked by an institution are found.
+pub enum OneTimeCodes {
+    /// Generated from the base32 seed of the authenticator app.
+    Totp(Secret<String>),
+    /// Asked to the user, who submits the code received by SMS or email through the API.
+    Challenge {
+        account_id: Uuid,
+        account_name: String,
+        challenges: PendingChallenges,
+    },
+}
+
+impl OneTimeCodes {
+    /// The current code, waiting at most `timeout` for the user to submit it.
+    pub async fn get(&self, timeout: Duration) -> anyhow::Result<String> {
+        match self {
+            OneTimeCodes::Totp(seed) => {
+                let unix_time = Utc::now().timestamp().try_into()?;
+                totp(seed.expose_secret(), unix_time)
+            }
+            OneTimeCodes::Challenge {
+                account_id,
+                account_name,
+                challenges,
+            } => {
+                challenges
+                    .wait_for_code(*account_id, account_name, timeout)
+                    .await
+            }
+        }
+    }
+}
+
+const TOTP_STEP_SECS: u64 = 30;
+const TOTP_DIGITS: u32 = 6;
+
+/// The RFC 6238 code at the time, with the defaults of authenticator apps:
+/// HMAC-SHA1, 30 seconds steps and 6 digits.
+pub fn totp(seed: &str, unix_time: u64) -> anyhow::Result<String> {
+    let key = decode_base32(seed)?;
+    let counter = unix_time / TOTP_STEP_SECS;
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key)?;
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    // Dynamic truncation of RFC 4226
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes(hash[offset..offset + 4].try_into()?) & 0x7fff_ffff;
+
+    Ok(format!(
+        "{:0width$}",
+        binary % 10u32.pow(TOTP_DIGITS),
+        width = TOTP_DIGITS as usize
+    ))
+}
+
+/// Decodes the RFC 4648 base32 the seeds are given in, ignoring spaces, padding and case.
+pub fn decode_base32(seed: &str) -> anyhow::Result<Vec<u8>> {
+    let mut bytes = vec![];
+    let mut buffer = 0u32;
+    let mut bits = 0;
+
+    for c in seed.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+        let value = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u32 - 'A' as u32,
+            c @ '2'..='7' => c as u32 - '2' as u32 + 26,
+            _ => bail!("{:?} is not a base32 character", c),
+        };
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    if bytes.is_empty() {
+        bail!("The seed is empty");
+    }
+
+    Ok(bytes)
+}
+
+/// The refreshes waiting for a one-time code, by account.
+#[derive(Clone, Default)]
+pub struct PendingChallenges {
+    waiting: Arc<Mutex<HashMap<Uuid, Waiting>>>,
+}
+
+struct Waiting {
+    challenge: PendingChallenge,
+    code: oneshot::Sender<String>,
+}
+
+impl PendingChallenges {
+    /// Waits for the code of the account to be submitted. A new challenge for the same account
+    /// replaces the one waiting.
+    pub async fn wait_for_code(
+        &self,
+        account_id: Uuid,
+        account_name: &str,
+        timeout: Duration,
+    ) -> anyhow::Result<String> {
+        let asked_at = Utc::now();
+        let challenge = PendingChallenge {
+            id: Uuid::new_v4(),
+            account_id,
+            account_name: account_name.to_owned(),
+            asked_at,
+            expires_at: asked_at + chrono::Duration::from_std(timeout)?,
+        };
+        let challenge_id = challenge.id;
+        let (sender, receiver) = oneshot::channel();
+        self.lock().insert(
+            account_id,
+            Waiting {
+                challenge,
+                code: sender,
+            },
+        );
+        tracing::info!("Waiting for the one-time code of account {}.", account_name);
+
+        let code = tokio::time::timeout(timeout, receiver).await;
+        self.lock()
+            .retain(|_, waiting| waiting.challenge.id != challenge_id);
+
+        code.with_context(|| {
+            format!(
+                "No one-time code was submitted within {} seconds",
+                timeout.as_secs()
+            )
+        })?
+        .map_err(|_| anyhow!("The one-time code is no longer waited for"))
+    }
+
+    /// The challenges waiting for a code, the oldest first.
+    pub fn get_all(&self) -> Vec<PendingChallenge> {
+        let mut challenges: Vec<_> = self
+            .lock()
+            .values()
+            .map(|waiting| waiting.challenge.clone())
+            .collect();
+        challenges.sort_by_key(|c| c.asked_at);
+
+        challenges
+    }
+
+    /// Hands the code to the refresh waiting for it. Returns false when none waits.
+    pub fn submit(&self, account_id: Uuid, code: String) -> bool {
+        match self.lock().remove(&account_id) {
+            Some(waiting) => waiting.code.send(code).is_ok(),
+            None => false,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Uuid, Waiting>> {
+        self.waiting.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::anyhow;
+use ynab::Milliunits;
+
+use crate::services::budget_providers::external::parse_amount;
+
+/// Parses inpute string as a balance, i.e. `$9,073.09` or `9 073.09$`, or `9.073,09 €` with `decimal_comma`.
+/// Returns the amount in milliunits format, read from the digits without going through a float.
+pub fn parse_balance(input: &str, decimal_comma: bool) -> anyhow::Result<Milliunits> {
+    parse_amount(input, decimal_comma).map_err(|e| anyhow!(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        /// Optionnal leading dollar sign with optionnal commas
+        /// ldscs = Leading Dollar Sign Comma Separated
+        fn parse_valid_amounts_ldscs(a in r#"(\$)?(([0-9]{1,12})|([0-9]{1,3})(\,[0-9]{3}){0,3})(\.[0-9]{1,2})?"#) {
+            parse_balance(&a, false).unwrap();
+        }
+
+        #[test]
+        /// Optionnal leading dollar sign with optionnal spaces
+        /// ldsss = Leading Dollar Sign Space Separated
+        fn parse_valid_amounts_ldsss(a in r#"(\$)?(([0-9]{1,12})|([0-9]{1,3})(\s[0-9]{3}){0,3})(\.[0-9]{1,2})?"#) {
+            parse_balance(&a, false).unwrap();
+        }
+
+        #[test]
+        /// Optionnal Ending dollar sign with optionnal commas
+        /// edscs = Ending Dollar Sign Comma Separated
+        fn parse_valid_amounts_edscs(a in r#"(([0-9]{1,12})|([0-9]{1,3})(\,[0-9]{3}){0,3})(\.[0-9]{1,2})?(\$)?"#) {
+            parse_balance(&a, false).unwrap();
+        }
+
+        #[test]
+        /// Optionnal Ending dollar sign with optionnal spaces
+        /// edsss = Ending Dollar Sign Space Separated
+        fn parse_valid_amounts_edsss(a in r#"(([0-9]{1,12})|([0-9]{1,3})(\s[0-9]{3}){0,3})(\.[0-9]{1,2})?(\$)?"#) {
+            parse_balance(&a, false).unwrap();
+        }
+    }
+
+    #[test]
+    fn parse_with_0_in_hundred_position() {
+        assert_eq!(
+            Milliunits::new(9073090),
+            parse_balance("$9,073.09", false).unwrap()
+        );
+        assert_eq!(
+            Milliunits::new(9073090),
+            parse_balance("$9 073.09", false).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_without_rounding() {
+        assert_eq!(Milliunits::new(70), parse_balance("0.07", false).unwrap());
+        assert_eq!(
+            Milliunits::new(12345678910),
+            parse_balance("$12,345,678.91", false).unwrap()
+        );
+        assert_eq!(
+            Milliunits::new(1234567890),
+            parse_balance("1 234 567.89 $", false).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_with_decimal_comma() {
+        assert_eq!(
+            Milliunits::new(9073090),
+            parse_balance("9.073,09 €", true).unwrap()
+        );
+        assert_eq!(
+            Milliunits::new(9073090),
+            parse_balance("9 073,09", true).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_balance() {
+        assert!(parse_balance("N/A", false).is_err());
+        assert!(parse_balance("1.2345", false).is_err());
+    }
+}
@@ -0,0 +1,252 @@
+use std::{fs, path::Path, sync::Arc, time::Duration};
+
+use anyhow::{bail, Context};
+use datamize_domain::{async_trait, secrecy::ExposeSecret};
+use serde::Deserialize;
+use ynab::Milliunits;
+
+use super::{parsing::parse_balance, Browser, Credentials, Scraper, WebDriverBrowser};
+
+/// The steps to log in an institution's website and read the balance of an account,
+/// written as JSON or TOML so new institutions don't need a new build.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct ScraperRecipe {
+    pub id: String,
+    pub name: String,
+    pub steps: Vec<ScraperStep>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ScraperStep {
+    Goto {
+        url: String,
+    },
+    Click {
+        selector: String,
+    },
+    /// `{username}` and `{password}` in the value are replaced by the ones of the account.
+    Fill {
+        selector: String,
+        value: String,
+    },
+    /// Fills the one-time code, generated from the TOTP seed of the account or else
+    /// submitted through the API by the user receiving it by SMS or email.
+    FillOneTimeCode {
+        selector: String,
+        /// How long to wait for the user to submit the code.
+        #[serde(default = "default_code_timeout_secs")]
+        timeout_secs: u64,
+    },
+    Submit {
+        form: String,
+        button: Option<String>,
+    },
+    WaitFor {
+        selector: String,
+        #[serde(default = "default_timeout_secs")]
+        timeout_secs: u64,
+    },
+    Sleep {
+        millis: u64,
+    },
+    /// Reads the balance from the text of the element.
+    ExtractBalance {
+        selector: String,
+        /// Characters removed before parsing, i.e. narrow no-break spaces used as thousands separators.
+        #[serde(default)]
+        remove: Vec<String>,
+        /// When the decimals are separated by a comma instead of a dot.
+        #[serde(default)]
+        decimal_comma: bool,
+    },
+}
+
+fn default_timeout_secs() -> u64 {
+    30
+}
+
+fn default_code_timeout_secs() -> u64 {
+    300
+}
+
+impl ScraperRecipe {
+    pub fn from_toml(recipe: &str) -> anyhow::Result<Self> {
+        toml::from_str::<Self>(recipe)?.validated()
+    }
+
+    pub fn from_json(recipe: &str) -> anyhow::Result<Self> {
+        serde_json::from_str::<Self>(recipe)?.validated()
+    }
+
+    fn validated(self) -> anyhow::Result<Self> {
+        if self.id.is_empty() {
+            bail!("The recipe {:?} has no id", self.name);
+        }
+        if !self
+            .steps
+            .iter()
+            .any(|s| matches!(s, ScraperStep::ExtractBalance { .. }))
+        {
+            bail!("The recipe {:?} never extracts the balance", self.id);
+        }
+
+        Ok(self)
+    }
+
+    /// Runs the steps in order and returns the last balance extracted.
+    pub async fn run(
+        &self,
+        browser: &mut dyn Browser,
+        credentials: &Credentials,
+    ) -> anyhow::Result<Milliunits> {
+        let mut balance = None;
+
+        for (i, step) in self.steps.iter().enumerate() {
+            run_step(step, browser, credentials, &mut balance)
+                .await
+                .with_context(|| format!("Step {} of the recipe {:?} failed", i + 1, self.id))?;
+        }
+
+        balance.with_context(|| format!("The recipe {:?} extracted no balance", self.id))
+    }
+}
+
+/// Replaces `{username}` and `{password}` in a single pass, so placeholders within the credentials are kept as is.
+fn expand_credentials(value: &str, credentials: &Credentials) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('{') {
+        expanded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(after) = rest.strip_prefix("{username}") {
+            expanded.push_str(&credentials.username);
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("{password}") {
+            expanded.push_str(credentials.password.expose_secret());
+            rest = after;
+        } else {
+            expanded.push('{');
+            rest = &rest[1..];
+        }
+    }
+    expanded.push_str(rest);
+
+    expanded
+}
+
+async fn run_step(
+    step: &ScraperStep,
+    browser: &mut dyn Browser,
+    credentials: &Credentials,
+    balance: &mut Option<Milliunits>,
+) -> anyhow::Result<()> {
+    match step {
+        ScraperStep::Goto { url } => browser.goto(url).await,
+        ScraperStep::Click { selector } => browser.click(selector).await,
+        ScraperStep::Fill { selector, value } => {
+            browser
+                .fill(selector, &expand_credentials(value, credentials))
+                .await
+        }
+        ScraperStep::FillOneTimeCode {
+            selector,
+            timeout_secs,
+        } => {
+            let code = credentials
+                .one_time_codes
+                .get(Duration::from_secs(*timeout_secs))
+                .await?;
+            browser.fill(selector, &code).await
+        }
+        ScraperStep::Submit { form, button } => browser.submit(form, button.as_deref()).await,
+        ScraperStep::WaitFor {
+            selector,
+            timeout_secs,
+        } => {
+            browser
+                .wait_for(selector, Duration::from_secs(*timeout_secs))
+                .await
+        }
+        ScraperStep::Sleep { millis } => {
+            browser.pause(Duration::from_millis(*millis)).await;
+            Ok(())
+        }
+        ScraperStep::ExtractBalance {
+            selector,
+            remove,
+            decimal_comma,
+        } => {
+            let mut text = browser.text(selector).await?;
+            for removed in remove {
+                text = text.replace(removed.as_str(), "");
+            }
+            *balance = Some(parse_balance(text.trim(), *decimal_comma)?);
+            Ok(())
+        }
+    }
+}
+
+/// Reads the `.json` and `.toml` recipes of the directory. Other files are ignored.
+pub fn read_recipes(directory: &Path) -> anyhow::Result<Vec<anyhow::Result<ScraperRecipe>>> {
+    let mut paths = fs::read_dir(directory)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.sort();
+
+    Ok(paths
+        .into_iter()
+        .filter_map(|path| {
+            let read: fn(&str) -> anyhow::Result<ScraperRecipe> =
+                match path.extension().and_then(|e| e.to_str()) {
+                    Some("toml") => ScraperRecipe::from_toml,
+                    Some("json") => ScraperRecipe::from_json,
+                    _ => return None,
+                };
+            Some(
+                fs::read_to_string(&path)
+                    .map_err(Into::into)
+                    .and_then(|recipe| read(&recipe))
+                    .with_context(|| format!("Failed to read {}", path.display())),
+            )
+        })
+        .collect())
+}
+
+/// A scraper following a recipe in a browser driven through WebDriver.
+pub struct RecipeScraper {
+    pub recipe: ScraperRecipe,
+}
+
+impl RecipeScraper {
+    pub fn new_arced(recipe: ScraperRecipe) -> Arc<Self> {
+        Arc::new(Self { recipe })
+    }
+}
+
+#[async_trait]
+impl Scraper for RecipeScraper {
+    fn id(&self) -> &str {
+        &self.recipe.id
+    }
+
+    fn name(&self) -> &str {
+        &self.recipe.name
+    }
+
+    async fn get_balance(
+        &self,
+        credentials: &Credentials,
+        webdriver_location: &str,
+    ) -> anyhow::Result<Milliunits> {
+        let mut browser = WebDriverBrowser::connect(webdriver_location).await?;
+        let balance = self.recipe.run(&mut browser, credentials).await;
+        // The session is closed even when the recipe failed, not to leave the browser open.
+        // Failing to close it does not change the balance read, so it is only logged.
+        if let Err(e) = browser.close().await {
+            tracing::warn!(error = ?e, "failed to close the browser session");
+        }
+
+        balance
+    }
+}
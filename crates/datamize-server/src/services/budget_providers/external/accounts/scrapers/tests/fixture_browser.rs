@@ -0,0 +1,128 @@
+use std::{collections::HashMap, time::Duration};
+
+use anyhow::{anyhow, bail, Context};
+use datamize_domain::async_trait;
+use scraper::{ElementRef, Html, Selector};
+
+use crate::services::budget_providers::Browser;
+
+/// A browser over local HTML pages, to run recipes without WebDriver.
+/// Pages are parsed and matched against CSS selectors by `scraper`, whose `atomic` feature keeps them `Send`.
+#[derive(Default)]
+pub(super) struct FixtureBrowser {
+    pages: HashMap<String, &'static str>,
+    page: Option<Html>,
+    /// The values filled in the named inputs of the current page.
+    filled: HashMap<String, String>,
+    pub(super) visited: Vec<String>,
+    /// The values of the named inputs of each form submitted.
+    pub(super) submitted: Vec<HashMap<String, String>>,
+    pub(super) paused: Duration,
+}
+
+impl FixtureBrowser {
+    pub(super) fn with_page(mut self, url: &str, html: &'static str) -> Self {
+        self.pages.insert(url.to_owned(), html);
+        self
+    }
+
+    /// The first element matching `selector`, like a browser would return.
+    fn find(&self, selector: &str) -> anyhow::Result<ElementRef<'_>> {
+        let page = self.page.as_ref().context("No page was visited")?;
+        let parsed = Selector::parse(selector)
+            .map_err(|e| anyhow!("Invalid selector {:?}: {}", selector, e))?;
+        page.select(&parsed)
+            .next()
+            .ok_or_else(|| anyhow!("No element matches {:?}", selector))
+    }
+
+    /// The values of the named inputs of the form, and the page it is submitted to.
+    /// Elements of the page are not `Sync`, so they are not kept across awaits.
+    fn form_submission(
+        &self,
+        form: &str,
+    ) -> anyhow::Result<(HashMap<String, String>, Option<String>)> {
+        let form = self.find(form)?;
+        let values = form
+            .select(&Selector::parse("[name]").unwrap())
+            .filter_map(|e| {
+                let name = e.value().attr("name")?;
+                let value = self
+                    .filled
+                    .get(name)
+                    .map(String::as_str)
+                    .or(e.value().attr("value"))
+                    .unwrap_or_default();
+                Some((name.to_owned(), value.to_owned()))
+            })
+            .collect();
+
+        Ok((values, form.value().attr("action").map(str::to_owned)))
+    }
+}
+
+#[async_trait]
+impl Browser for FixtureBrowser {
+    async fn goto(&mut self, url: &str) -> anyhow::Result<()> {
+        let html = self
+            .pages
+            .get(url)
+            .with_context(|| format!("No fixture page for {}", url))?;
+        self.page = Some(Html::parse_document(html));
+        self.filled.clear();
+        self.visited.push(url.to_owned());
+        Ok(())
+    }
+
+    async fn click(&mut self, selector: &str) -> anyhow::Result<()> {
+        let href = self.find(selector)?.value().attr("href").map(str::to_owned);
+        match href {
+            Some(href) => self.goto(&href).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn fill(&mut self, selector: &str, value: &str) -> anyhow::Result<()> {
+        let input = self.find(selector)?.value();
+        if input.name() != "input" {
+            bail!("{:?} is not an input", selector);
+        }
+        if let Some(name) = input.attr("name").map(str::to_owned) {
+            self.filled.insert(name, value.to_owned());
+        }
+        Ok(())
+    }
+
+    async fn submit(&mut self, form: &str, button: Option<&str>) -> anyhow::Result<()> {
+        if let Some(button) = button {
+            self.find(button)?;
+        }
+
+        let (values, action) = self.form_submission(form)?;
+        self.submitted.push(values);
+
+        match action {
+            Some(action) => self.goto(&action).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn wait_for(&mut self, selector: &str, _timeout: Duration) -> anyhow::Result<()> {
+        self.find(selector)
+            .map(|_| ())
+            .with_context(|| format!("Timed out waiting for {:?}", selector))
+    }
+
+    async fn text(&mut self, selector: &str) -> anyhow::Result<String> {
+        Ok(self
+            .find(selector)?
+            .text()
+            .collect::<String>()
+            .trim()
+            .to_owned())
+    }
+
+    async fn pause(&mut self, duration: Duration) {
+        self.paused += duration;
+    }
+}
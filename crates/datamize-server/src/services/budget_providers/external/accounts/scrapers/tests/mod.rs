@@ -0,0 +1,375 @@
+mod fixture_browser;
+
+use std::{collections::HashMap, fs, time::Duration};
+
+use datamize_domain::{secrecy::Secret, Uuid};
+use fixture_browser::FixtureBrowser;
+use pretty_assertions::assert_eq;
+use ynab::Milliunits;
+
+use super::*;
+
+fn credentials() -> Credentials {
+    Credentials {
+        username: "me@example.com".to_owned(),
+        password: Secret::new("hunter2".to_owned()),
+        one_time_codes: OneTimeCodes::Challenge {
+            account_id: Uuid::new_v4(),
+            account_name: "Pension".to_owned(),
+            challenges: PendingChallenges::default(),
+        },
+    }
+}
+
+fn builtin(id: &str) -> ScraperRecipe {
+    BUILTIN_RECIPES
+        .iter()
+        .map(|r| ScraperRecipe::from_toml(r).unwrap())
+        .find(|r| r.id == id)
+        .unwrap()
+}
+
+fn first_url(recipe: &ScraperRecipe) -> &str {
+    recipe
+        .steps
+        .iter()
+        .find_map(|s| match s {
+            ScraperStep::Goto { url } => Some(url.as_str()),
+            _ => None,
+        })
+        .unwrap()
+}
+
+const STATEMENT_RECIPE: &str = r#"{
+    "id": "statement",
+    "name": "Statement",
+    "steps": [
+        { "action": "goto", "url": "https://fixtures.test/statement" },
+        { "action": "sleep", "millis": 1500 },
+        { "action": "wait_for", "selector": ".accounts li:last-child .balance" },
+        {
+            "action": "extract_balance",
+            "selector": ".accounts li:last-child .balance",
+            "remove": [" ", "$"],
+            "decimal_comma": true
+        }
+    ]
+}"#;
+
+#[test]
+fn builtin_recipes_are_registered() {
+    let registry = ScraperRegistry::with_builtin_recipes();
+
+    assert_eq!(
+        registry.scrapers().map(|s| s.id()).collect::<Vec<_>>(),
+        vec!["canada_life_rpp", "ia_rrsp", "peak_tfsa"]
+    );
+    assert!(registry.get("unknown").is_none());
+}
+
+#[tokio::test]
+async fn peak_tfsa_recipe_logs_in_and_reads_the_balance() {
+    let recipe = builtin("peak_tfsa");
+    let mut browser = FixtureBrowser::default()
+        .with_page(
+            first_url(&recipe),
+            include_str!("fixtures/peak_tfsa_login.html"),
+        )
+        .with_page(
+            "https://fixtures.test/peak/dashboard",
+            include_str!("fixtures/peak_tfsa_dashboard.html"),
+        );
+
+    let balance = recipe.run(&mut browser, &credentials()).await.unwrap();
+
+    assert_eq!(balance, Milliunits::new(12345670));
+    assert_eq!(
+        browser.submitted,
+        vec![HashMap::from([
+            ("signInEmail".to_owned(), "me@example.com".to_owned()),
+            ("signInPassword".to_owned(), "hunter2".to_owned()),
+        ])]
+    );
+}
+
+#[tokio::test]
+async fn placeholders_within_the_credentials_are_filled_as_is() {
+    let recipe = builtin("peak_tfsa");
+    let mut browser = FixtureBrowser::default()
+        .with_page(
+            first_url(&recipe),
+            include_str!("fixtures/peak_tfsa_login.html"),
+        )
+        .with_page(
+            "https://fixtures.test/peak/dashboard",
+            include_str!("fixtures/peak_tfsa_dashboard.html"),
+        );
+    let credentials = Credentials {
+        username: "{password}@example.com".to_owned(),
+        password: Secret::new("{username}{".to_owned()),
+        ..credentials()
+    };
+
+    recipe.run(&mut browser, &credentials).await.unwrap();
+
+    assert_eq!(
+        browser.submitted,
+        vec![HashMap::from([
+            (
+                "signInEmail".to_owned(),
+                "{password}@example.com".to_owned()
+            ),
+            ("signInPassword".to_owned(), "{username}{".to_owned()),
+        ])]
+    );
+}
+
+#[tokio::test]
+async fn ia_rrsp_recipe_follows_the_links_to_the_balance() {
+    let recipe = builtin("ia_rrsp");
+    let mut browser = FixtureBrowser::default()
+        .with_page(
+            first_url(&recipe),
+            include_str!("fixtures/ia_rrsp_login.html"),
+        )
+        .with_page(
+            "https://fixtures.test/ia/home",
+            include_str!("fixtures/ia_rrsp_home.html"),
+        )
+        .with_page(
+            "https://fixtures.test/ia/savings",
+            include_str!("fixtures/ia_rrsp_savings.html"),
+        );
+
+    let balance = recipe.run(&mut browser, &credentials()).await.unwrap();
+
+    assert_eq!(balance, Milliunits::new(45678900));
+    assert_eq!(
+        browser.visited[1..],
+        [
+            "https://fixtures.test/ia/home",
+            "https://fixtures.test/ia/savings"
+        ]
+    );
+    assert_eq!(browser.submitted[0]["username"], "me@example.com");
+    assert_eq!(browser.submitted[0]["password"], "hunter2");
+}
+
+#[tokio::test]
+async fn json_recipe_cleans_the_text_before_parsing_it() {
+    let recipe = ScraperRecipe::from_json(STATEMENT_RECIPE).unwrap();
+    let mut browser = FixtureBrowser::default().with_page(
+        "https://fixtures.test/statement",
+        include_str!("fixtures/statement.html"),
+    );
+
+    let balance = recipe.run(&mut browser, &credentials()).await.unwrap();
+
+    assert_eq!(balance, Milliunits::new(98765430));
+    assert_eq!(browser.paused, Duration::from_millis(1500));
+}
+
+#[tokio::test]
+async fn failing_step_is_reported() {
+    let recipe = builtin("peak_tfsa");
+    let mut browser = FixtureBrowser::default().with_page(
+        first_url(&recipe),
+        include_str!("fixtures/peak_tfsa_dashboard.html"),
+    );
+
+    let err = recipe.run(&mut browser, &credentials()).await.unwrap_err();
+
+    assert_eq!(err.to_string(), "Step 2 of the recipe \"peak_tfsa\" failed");
+}
+
+#[test]
+fn recipe_without_balance_is_rejected() {
+    let err = ScraperRecipe::from_toml(
+        r#"
+        id = "nothing"
+        name = "Nothing"
+
+        [[steps]]
+        action = "goto"
+        url = "https://fixtures.test"
+        "#,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "The recipe \"nothing\" never extracts the balance"
+    );
+}
+
+#[test]
+fn recipes_of_the_directory_are_added_to_the_builtin_ones() {
+    let directory = std::env::temp_dir().join(format!("datamize-recipes-{}", Uuid::new_v4()));
+    fs::create_dir(&directory).unwrap();
+    fs::write(directory.join("statement.json"), STATEMENT_RECIPE).unwrap();
+    fs::write(
+        directory.join("peak_tfsa.toml"),
+        include_str!("../recipes/peak_tfsa.toml").replace("Peak Investment", "Peak"),
+    )
+    .unwrap();
+    fs::write(directory.join("broken.toml"), "id = ").unwrap();
+    fs::write(directory.join("README.md"), "Recipes").unwrap();
+
+    let registry = ScraperRegistry::load(Some(&directory));
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(
+        registry.scrapers().map(|s| s.id()).collect::<Vec<_>>(),
+        vec!["canada_life_rpp", "ia_rrsp", "peak_tfsa", "statement"]
+    );
+    assert_eq!(
+        registry.get("peak_tfsa").unwrap().name(),
+        "Peak Services - TFSA"
+    );
+}
+
+/// The seed of the RFC 6238 test vectors, "12345678901234567890" in base32.
+const RFC_6238_SEED: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+const ONE_TIME_CODE_RECIPE: &str = r##"{
+    "id": "one_time_code",
+    "name": "One-time code",
+    "steps": [
+        { "action": "goto", "url": "https://fixtures.test/verify" },
+        { "action": "fill_one_time_code", "selector": "#code", "timeout_secs": 5 },
+        { "action": "submit", "form": "#verify" },
+        {
+            "action": "extract_balance",
+            "selector": ".accounts li:last-child .balance",
+            "remove": [" ", "$"],
+            "decimal_comma": true
+        }
+    ]
+}"##;
+
+fn one_time_code_browser() -> FixtureBrowser {
+    FixtureBrowser::default()
+        .with_page(
+            "https://fixtures.test/verify",
+            include_str!("fixtures/one_time_code.html"),
+        )
+        .with_page(
+            "https://fixtures.test/statement",
+            include_str!("fixtures/statement.html"),
+        )
+}
+
+#[test]
+fn totp_matches_the_rfc_6238_test_vectors() {
+    for (unix_time, code) in [
+        (59, "287082"),
+        (1111111109, "081804"),
+        (1111111111, "050471"),
+        (1234567890, "005924"),
+        (2000000000, "279037"),
+    ] {
+        assert_eq!(totp(RFC_6238_SEED, unix_time).unwrap(), code);
+    }
+}
+
+#[test]
+fn base32_seeds_are_read_like_authenticator_apps_do() {
+    assert_eq!(decode_base32("mzxw 6ytb oi======").unwrap(), b"foobar");
+    assert!(decode_base32("not base32!").is_err());
+    assert!(decode_base32("").is_err());
+}
+
+#[tokio::test]
+async fn recipe_fills_the_code_generated_from_the_seed() {
+    let recipe = ScraperRecipe::from_json(ONE_TIME_CODE_RECIPE).unwrap();
+    let mut browser = one_time_code_browser();
+    let credentials = Credentials {
+        one_time_codes: OneTimeCodes::Totp(Secret::new(RFC_6238_SEED.to_owned())),
+        ..credentials()
+    };
+
+    let before = chrono::Utc::now().timestamp() as u64;
+    let balance = recipe.run(&mut browser, &credentials).await.unwrap();
+    let after = chrono::Utc::now().timestamp() as u64;
+
+    assert_eq!(balance, Milliunits::new(98765430));
+    // The code changes every 30 seconds, which may happen during the run.
+    let codes = [
+        totp(RFC_6238_SEED, before).unwrap(),
+        totp(RFC_6238_SEED, after).unwrap(),
+    ];
+    assert!(codes.contains(&browser.submitted[0]["code"]));
+}
+
+#[tokio::test]
+async fn recipe_waits_for_the_code_submitted_by_the_user() {
+    let recipe = ScraperRecipe::from_json(ONE_TIME_CODE_RECIPE).unwrap();
+    let mut browser = one_time_code_browser();
+    let challenges = PendingChallenges::default();
+    let account_id = Uuid::new_v4();
+    let credentials = Credentials {
+        one_time_codes: OneTimeCodes::Challenge {
+            account_id,
+            account_name: "Pension".to_owned(),
+            challenges: challenges.clone(),
+        },
+        ..credentials()
+    };
+
+    let user = tokio::spawn({
+        let challenges = challenges.clone();
+        async move {
+            while challenges.get_all().is_empty() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            let pending = challenges.get_all();
+            let submitted = challenges.submit(account_id, "123456".to_owned());
+            (pending, submitted)
+        }
+    });
+    let balance = recipe.run(&mut browser, &credentials).await.unwrap();
+    let (pending, submitted) = user.await.unwrap();
+
+    assert_eq!(balance, Milliunits::new(98765430));
+    assert!(submitted);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].account_id, account_id);
+    assert_eq!(pending[0].account_name, "Pension");
+    assert_eq!(browser.submitted[0]["code"], "123456");
+    assert!(challenges.get_all().is_empty());
+}
+
+#[tokio::test]
+async fn challenge_without_code_times_out() {
+    let challenges = PendingChallenges::default();
+    let account_id = Uuid::new_v4();
+
+    let err = challenges
+        .wait_for_code(account_id, "Pension", Duration::from_millis(20))
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "No one-time code was submitted within 0 seconds"
+    );
+    assert!(challenges.get_all().is_empty());
+    assert!(!challenges.submit(account_id, "123456".to_owned()));
+}
+
+#[tokio::test]
+async fn recipe_asking_for_a_code_fails_when_none_is_submitted() {
+    let recipe = ScraperRecipe::from_json(
+        &ONE_TIME_CODE_RECIPE.replace(r#""timeout_secs": 5"#, r#""timeout_secs": 0"#),
+    )
+    .unwrap();
+    let mut browser = one_time_code_browser();
+
+    let err = recipe.run(&mut browser, &credentials()).await.unwrap_err();
+
+    assert_eq!(
+        format!("{:#}", err),
+        "Step 2 of the recipe \"one_time_code\" failed: No one-time code was submitted within 0 seconds: deadline has elapsed"
+    );
+    assert!(browser.submitted.is_empty());
+}
@@ -1,3 +1,5 @@
 mod accounts;
+mod statements;
 
 pub use accounts::*;
+pub use statements::*;
@@ -0,0 +1,130 @@
+use chrono::NaiveDate;
+use datamize_domain::{Statement, StatementTransaction};
+use ynab::Milliunits;
+
+use super::parse_amount;
+use crate::{
+    config::CsvLayout,
+    error::{AppError, DatamizeResult},
+};
+
+/// Reads a CSV statement with the layout of its institution.
+/// The balance is the one of the most recent row. When several rows have the same day, the last
+/// one written that day is used, whether the statement lists the oldest or the newest rows first.
+pub fn read_csv_statement(data: &[u8], layout: &CsvLayout) -> DatamizeResult<Statement> {
+    let data = skip_lines(data, layout.skip_lines);
+    let delimiter = u8::try_from(layout.delimiter)
+        .map_err(|_| invalid(format!("`{}` can't be a delimiter", layout.delimiter)))?;
+    let mut reader = ::csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .trim(::csv::Trim::All)
+        .flexible(true)
+        .from_reader(data);
+
+    let headers = reader
+        .headers()
+        .map_err(|e| invalid(e.to_string()))?
+        .clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|h| h == name)
+            .ok_or_else(|| invalid(format!("there is no column `{}`", name)))
+    };
+    let optional_column = |name: &Option<String>| name.as_deref().map(column).transpose();
+    let date_column = column(&layout.date_column)?;
+    let balance_column = column(&layout.balance_column)?;
+    let amount_column = optional_column(&layout.amount_column)?;
+    let debit_column = optional_column(&layout.debit_column)?;
+    let credit_column = optional_column(&layout.credit_column)?;
+    let description_column = optional_column(&layout.description_column)?;
+
+    let mut balances: Vec<(NaiveDate, Milliunits)> = vec![];
+    let mut transactions = vec![];
+
+    for record in reader.records() {
+        let record = record.map_err(|e| invalid(e.to_string()))?;
+        let line = record.position().map(|p| p.line()).unwrap_or_default() as usize;
+        let invalid =
+            |message: String| invalid(format!("line {}: {}", line + layout.skip_lines, message));
+        let cell = |column: Option<usize>| column.and_then(|c| record.get(c)).unwrap_or_default();
+        let amount = |column: Option<usize>| match cell(column) {
+            "" => Ok(None),
+            amount => parse_amount(amount, layout.decimal_comma)
+                .map(Some)
+                .map_err(invalid),
+        };
+
+        let date = cell(Some(date_column));
+        if date.is_empty() {
+            continue;
+        }
+        let date = NaiveDate::parse_from_str(date, &layout.date_format).map_err(|_| {
+            invalid(format!(
+                "`{}` is not a date as {}",
+                date, layout.date_format
+            ))
+        })?;
+
+        if let Some(balance) = amount(Some(balance_column))? {
+            balances.push((date, balance));
+        }
+
+        let transaction_amount = match (
+            amount(amount_column)?,
+            amount(debit_column)?,
+            amount(credit_column)?,
+        ) {
+            (Some(amount), _, _) => Some(amount),
+            (None, None, None) => None,
+            (None, debit, credit) => Some(
+                credit
+                    .unwrap_or_default()
+                    .checked_sub(debit.unwrap_or_default().abs())
+                    .ok_or_else(|| invalid("amount is too large".to_owned()))?,
+            ),
+        };
+        if let Some(amount) = transaction_amount {
+            transactions.push(StatementTransaction {
+                id: None,
+                date,
+                amount,
+                description: cell(description_column).to_owned(),
+            });
+        }
+    }
+
+    let newest_first = matches!(
+        (balances.first(), balances.last()),
+        (Some((first, _)), Some((last, _))) if first > last
+    );
+    let latest = balances.iter().map(|(date, _)| *date).max();
+    let mut latest_balances = balances.iter().filter(|(date, _)| Some(*date) == latest);
+    let (balance_date, balance) = match newest_first {
+        true => latest_balances.next(),
+        false => latest_balances.next_back(),
+    }
+    .copied()
+    .ok_or_else(|| invalid("no row has a balance".to_owned()))?;
+
+    Ok(Statement {
+        balance,
+        balance_date: Some(balance_date),
+        transactions,
+    })
+}
+
+fn skip_lines(data: &[u8], lines: usize) -> &[u8] {
+    let mut rest = data;
+    for _ in 0..lines {
+        rest = match rest.iter().position(|&b| b == b'\n') {
+            Some(end) => &rest[end + 1..],
+            None => &[],
+        };
+    }
+    rest
+}
+
+fn invalid(message: String) -> AppError {
+    AppError::InvalidImport(format!("Invalid CSV statement: {}", message))
+}
@@ -0,0 +1,60 @@
+mod csv;
+mod ofx;
+#[cfg(test)]
+mod tests;
+
+use datamize_domain::{Statement, StatementFormat};
+use ynab::Milliunits;
+
+use crate::{
+    config::StatementSettings,
+    error::{AppError, DatamizeResult},
+};
+
+pub use self::csv::read_csv_statement;
+pub use ofx::read_ofx;
+
+/// Reads an OFX or a CSV statement, the CSV with the layout of the settings named `layout`.
+pub fn read_statement(
+    data: &[u8],
+    format: StatementFormat,
+    layout: Option<&str>,
+    settings: &StatementSettings,
+) -> DatamizeResult<Statement> {
+    match format {
+        StatementFormat::Ofx => read_ofx(data),
+        StatementFormat::Csv => {
+            let name = layout.ok_or_else(|| {
+                AppError::InvalidImport("A CSV statement needs the name of its layout".to_owned())
+            })?;
+            let layout = settings.csv_layouts.get(name).ok_or_else(|| {
+                AppError::InvalidImport(format!("There is no CSV layout named `{}`", name))
+            })?;
+            read_csv_statement(data, layout)
+        }
+    }
+}
+
+/// Parses an amount as institutions write it, i.e. `-1,234.56`, `$1 234.56` or `(1234.56)`.
+pub(crate) fn parse_amount(s: &str, decimal_comma: bool) -> Result<Milliunits, String> {
+    let mut amount: String = s
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '$' | '€' | '£'))
+        .collect();
+    let negative = amount.starts_with('(') && amount.ends_with(')');
+    if negative {
+        amount = amount[1..amount.len() - 1].to_owned();
+    }
+    let (group_separator, decimal_separator) = match decimal_comma {
+        true => ('.', ','),
+        false => (',', '.'),
+    };
+    let amount = amount
+        .replace(group_separator, "")
+        .replace(decimal_separator, ".");
+
+    let amount = amount
+        .parse::<Milliunits>()
+        .map_err(|_| format!("`{}` is not a valid amount", s))?;
+    Ok(if negative { -amount } else { amount })
+}
@@ -0,0 +1,146 @@
+use chrono::NaiveDate;
+use datamize_domain::{Statement, StatementTransaction};
+
+use super::parse_amount;
+use crate::error::{AppError, DatamizeResult};
+
+/// Reads the balance and the transactions of an OFX or QFX statement. Both the SGML of OFX 1
+/// and the XML of OFX 2 are read, leaf elements being closed or not.
+/// The ledger balance is used, or the available one when there is none.
+pub fn read_ofx(data: &[u8]) -> DatamizeResult<Statement> {
+    let data = String::from_utf8_lossy(data);
+    let start = data
+        .find("<OFX>")
+        .ok_or_else(|| invalid("there is no <OFX> element".to_owned()))?;
+
+    let mut ledger_balance = None;
+    let mut available_balance = None;
+    let mut statements = 0;
+    let mut transactions = vec![];
+
+    for aggregate in read_aggregates(&data[start..]) {
+        match aggregate.name.as_str() {
+            "STMTRS" | "CCSTMTRS" => statements += 1,
+            "LEDGERBAL" => ledger_balance = Some(read_balance(&aggregate)?),
+            "AVAILBAL" => available_balance = Some(read_balance(&aggregate)?),
+            "STMTTRN" => transactions.push(read_transaction(&aggregate)?),
+            _ => {}
+        }
+    }
+
+    if statements > 1 {
+        return Err(invalid(
+            "it holds the statements of several accounts, download them one at a time".to_owned(),
+        ));
+    }
+    let (balance, balance_date) = ledger_balance
+        .or(available_balance)
+        .ok_or_else(|| invalid("there is no <LEDGERBAL> nor <AVAILBAL>".to_owned()))?;
+
+    Ok(Statement {
+        balance,
+        balance_date,
+        transactions,
+    })
+}
+
+/// An aggregate along with the values of its leaf elements, i.e. `<STMTTRN>` and its `<TRNAMT>`.
+struct Aggregate {
+    name: String,
+    values: Vec<(String, String)>,
+}
+
+impl Aggregate {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    fn require(&self, name: &str) -> DatamizeResult<&str> {
+        self.get(name)
+            .ok_or_else(|| invalid(format!("<{}> has no <{}>", self.name, name)))
+    }
+}
+
+/// The aggregates in the order they are closed. An element with text is a leaf, the others
+/// being aggregates closed by an end tag.
+fn read_aggregates(ofx: &str) -> Vec<Aggregate> {
+    let mut open: Vec<Aggregate> = vec![];
+    let mut closed = vec![];
+    let mut rest = ofx;
+
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim();
+        rest = &rest[start + end + 1..];
+        let text_end = rest.find('<').unwrap_or(rest.len());
+        let text = rest[..text_end].trim();
+
+        if let Some(name) = tag.strip_prefix('/') {
+            // The end tags of leaf elements in OFX 2 match no open aggregate.
+            if let Some(pos) = open.iter().rposition(|a| a.name == name) {
+                closed.extend(open.drain(pos..).rev());
+            }
+        } else if tag.starts_with('?') || tag.starts_with('!') {
+            continue;
+        } else if text.is_empty() {
+            open.push(Aggregate {
+                name: tag.to_owned(),
+                values: vec![],
+            });
+        } else if let Some(aggregate) = open.last_mut() {
+            aggregate.values.push((tag.to_owned(), decode(text)));
+        }
+    }
+
+    closed
+}
+
+fn read_balance(aggregate: &Aggregate) -> DatamizeResult<(ynab::Milliunits, Option<NaiveDate>)> {
+    let balance = parse_ofx_amount(aggregate.require("BALAMT")?)?;
+    let date = aggregate.get("DTASOF").map(parse_date).transpose()?;
+
+    Ok((balance, date))
+}
+
+fn read_transaction(aggregate: &Aggregate) -> DatamizeResult<StatementTransaction> {
+    Ok(StatementTransaction {
+        id: aggregate.get("FITID").map(str::to_owned),
+        date: parse_date(aggregate.require("DTPOSTED")?)?,
+        amount: parse_ofx_amount(aggregate.require("TRNAMT")?)?,
+        description: aggregate
+            .get("NAME")
+            .or_else(|| aggregate.get("MEMO"))
+            .unwrap_or_default()
+            .to_owned(),
+    })
+}
+
+/// Amounts have a dot as decimal separator, or a comma in some statements.
+fn parse_ofx_amount(amount: &str) -> DatamizeResult<ynab::Milliunits> {
+    parse_amount(amount, !amount.contains('.') && amount.contains(',')).map_err(invalid)
+}
+
+/// Dates are written as `YYYYMMDD`, optionally followed by the time and the time zone.
+fn parse_date(date: &str) -> DatamizeResult<NaiveDate> {
+    date.get(..8)
+        .and_then(|d| NaiveDate::parse_from_str(d, "%Y%m%d").ok())
+        .ok_or_else(|| invalid(format!("`{}` is not a date", date)))
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn invalid(message: String) -> AppError {
+    AppError::InvalidImport(format!("Invalid OFX statement: {}", message))
+}
@@ -0,0 +1,226 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use datamize_domain::{Statement, StatementFormat, StatementTransaction};
+use pretty_assertions::assert_eq;
+use ynab::Milliunits;
+
+use super::*;
+use crate::config::CsvLayout;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn transaction(
+    id: Option<&str>,
+    date: NaiveDate,
+    amount: i64,
+    description: &str,
+) -> StatementTransaction {
+    StatementTransaction {
+        id: id.map(str::to_owned),
+        date,
+        amount: Milliunits::new(amount),
+        description: description.to_owned(),
+    }
+}
+
+fn savings_layout() -> CsvLayout {
+    CsvLayout {
+        delimiter: ';',
+        skip_lines: 1,
+        date_column: "Date".to_owned(),
+        date_format: "%d/%m/%Y".to_owned(),
+        balance_column: "Solde".to_owned(),
+        amount_column: None,
+        debit_column: Some("Retrait".to_owned()),
+        credit_column: Some("Dépôt".to_owned()),
+        description_column: Some("Description".to_owned()),
+        decimal_comma: true,
+    }
+}
+
+fn error_message(error: AppError) -> String {
+    match error {
+        AppError::InvalidImport(message) => message,
+        e => panic!("Expected an invalid import, got {:?}", e),
+    }
+}
+
+#[test]
+fn ofx_reads_the_ledger_balance_and_the_transactions() {
+    let statement = read_ofx(include_bytes!("fixtures/checking.ofx")).unwrap();
+
+    assert_eq!(
+        statement,
+        Statement {
+            balance: Milliunits::new(2345670),
+            balance_date: Some(date(2024, 4, 15)),
+            transactions: vec![
+                transaction(
+                    Some("20240402001"),
+                    date(2024, 4, 2),
+                    -45670,
+                    "Groceries & Co"
+                ),
+                transaction(Some("20240410001"), date(2024, 4, 10), 1500000, "Payroll"),
+            ],
+        }
+    );
+}
+
+#[test]
+fn qfx_falls_back_to_the_available_balance() {
+    let statement = read_ofx(include_bytes!("fixtures/credit_card.qfx")).unwrap();
+
+    assert_eq!(
+        statement,
+        Statement {
+            balance: Milliunits::new(-640250),
+            balance_date: Some(date(2024, 4, 14)),
+            transactions: vec![transaction(
+                Some("A1"),
+                date(2024, 4, 5),
+                -120500,
+                "Hardware store"
+            )],
+        }
+    );
+}
+
+#[test]
+fn ofx_without_balance_is_rejected() {
+    let ofx = include_str!("fixtures/checking.ofx")
+        .replace("LEDGERBAL", "OTHERBAL")
+        .replace("AVAILBAL", "OTHERBAL");
+
+    assert_eq!(
+        error_message(read_ofx(ofx.as_bytes()).unwrap_err()),
+        "Invalid OFX statement: there is no <LEDGERBAL> nor <AVAILBAL>"
+    );
+    assert_eq!(
+        error_message(read_ofx(b"Not a statement").unwrap_err()),
+        "Invalid OFX statement: there is no <OFX> element"
+    );
+}
+
+#[test]
+fn ofx_of_several_accounts_is_rejected() {
+    let ofx = include_str!("fixtures/checking.ofx");
+    let start = ofx.find("<STMTTRNRS>").unwrap();
+    let end = ofx.find("</BANKMSGSRSV1>").unwrap();
+    let ofx = format!("{}{}", &ofx[..end], &ofx[start..]);
+
+    assert_eq!(
+        error_message(read_ofx(ofx.as_bytes()).unwrap_err()),
+        "Invalid OFX statement: it holds the statements of several accounts, download them one at a time"
+    );
+}
+
+#[test]
+fn csv_listing_the_newest_rows_first_uses_the_first_balance_of_the_day() {
+    let statement =
+        read_csv_statement(include_bytes!("fixtures/savings.csv"), &savings_layout()).unwrap();
+
+    assert_eq!(
+        statement,
+        Statement {
+            balance: Milliunits::new(3210450),
+            balance_date: Some(date(2024, 4, 15)),
+            transactions: vec![
+                transaction(None, date(2024, 4, 15), 1500000, "Paie"),
+                transaction(None, date(2024, 4, 15), -45670, "Épicerie"),
+                transaction(None, date(2024, 4, 2), -1200000, "Loyer"),
+            ],
+        }
+    );
+}
+
+#[test]
+fn csv_listing_the_oldest_rows_first_uses_the_last_balance_of_the_day() {
+    let layout = CsvLayout {
+        delimiter: ',',
+        skip_lines: 0,
+        date_column: "date".to_owned(),
+        date_format: "%Y-%m-%d".to_owned(),
+        balance_column: "balance".to_owned(),
+        amount_column: Some("amount".to_owned()),
+        debit_column: None,
+        credit_column: None,
+        description_column: None,
+        decimal_comma: false,
+    };
+
+    let statement =
+        read_csv_statement(include_bytes!("fixtures/oldest_first.csv"), &layout).unwrap();
+
+    assert_eq!(statement.balance, Milliunits::new(751750));
+    assert_eq!(statement.balance_date, Some(date(2024, 4, 10)));
+    assert_eq!(
+        statement
+            .transactions
+            .iter()
+            .map(|t| t.amount)
+            .collect::<Vec<_>>(),
+        vec![
+            Milliunits::new(1250),
+            Milliunits::new(-500000),
+            Milliunits::new(250500)
+        ]
+    );
+}
+
+#[test]
+fn csv_errors_tell_where_they_are() {
+    let layout = CsvLayout {
+        balance_column: "Balance".to_owned(),
+        ..savings_layout()
+    };
+    assert_eq!(
+        error_message(
+            read_csv_statement(include_bytes!("fixtures/savings.csv"), &layout).unwrap_err()
+        ),
+        "Invalid CSV statement: there is no column `Balance`"
+    );
+
+    let csv = include_str!("fixtures/savings.csv").replace("02/04/2024", "2024-04-02");
+    assert_eq!(
+        error_message(read_csv_statement(csv.as_bytes(), &savings_layout()).unwrap_err()),
+        "Invalid CSV statement: line 5: `2024-04-02` is not a date as %d/%m/%Y"
+    );
+
+    let csv = include_str!("fixtures/savings.csv").replace(
+        "Paie;;1 500,00",
+        "Paie;9 000 000 000 000 000,00;-9 000 000 000 000 000,00",
+    );
+    assert_eq!(
+        error_message(read_csv_statement(csv.as_bytes(), &savings_layout()).unwrap_err()),
+        "Invalid CSV statement: line 3: amount is too large"
+    );
+}
+
+#[test]
+fn csv_statements_need_a_layout_configured() {
+    let settings = StatementSettings {
+        csv_layouts: BTreeMap::from([("savings".to_owned(), savings_layout())]),
+    };
+    let data = include_bytes!("fixtures/savings.csv");
+
+    assert_eq!(
+        read_statement(data, StatementFormat::Csv, Some("savings"), &settings)
+            .unwrap()
+            .balance,
+        Milliunits::new(3210450)
+    );
+    assert_eq!(
+        error_message(read_statement(data, StatementFormat::Csv, None, &settings).unwrap_err()),
+        "A CSV statement needs the name of its layout"
+    );
+    assert_eq!(
+        error_message(
+            read_statement(data, StatementFormat::Csv, Some("checking"), &settings).unwrap_err()
+        ),
+        "There is no CSV layout named `checking`"
+    );
+}
@@ -20,6 +20,7 @@ pub struct YnabAccountService {
     pub ynab_account_repo: DynYnabAccountRepo,
     pub ynab_account_meta_repo: DynYnabAccountMetaRepo,
     pub ynab_client: Arc<dyn AccountRequests + Send + Sync>,
+    pub budget_id: String,
 }
 
 #[async_trait]
@@ -30,7 +31,7 @@ impl YnabAccountServiceExt for YnabAccountService {
 
         let accounts_delta = self
             .ynab_client
-            .get_accounts_delta(saved_accounts_delta)
+            .get_accounts_delta(&self.budget_id, saved_accounts_delta)
             .await?;
 
         let accounts = accounts_delta
@@ -56,11 +57,13 @@ impl YnabAccountService {
         ynab_account_repo: DynYnabAccountRepo,
         ynab_account_meta_repo: DynYnabAccountMetaRepo,
         ynab_client: Arc<dyn AccountRequests + Send + Sync>,
+        budget_id: &str,
     ) -> Arc<Self> {
         Arc::new(YnabAccountService {
             ynab_account_repo,
             ynab_account_meta_repo,
             ynab_client,
+            budget_id: budget_id.to_string(),
         })
     }
 }
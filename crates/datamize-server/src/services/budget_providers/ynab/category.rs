@@ -1,13 +1,13 @@
 use std::{collections::HashSet, sync::Arc};
 
-use chrono::{DateTime, Datelike, Local, NaiveDate};
+use chrono::{Datelike, NaiveDate};
 use datamize_domain::{
     async_trait,
     db::{
         ynab::{DynYnabCategoryMetaRepo, DynYnabCategoryRepo},
         DbError, DynExpenseCategorizationRepo,
     },
-    ExpenseCategorization, MonthTarget,
+    DynClock, ExpenseCategorization, MonthTarget,
 };
 use ynab::{Category, CategoryGroup, CategoryRequests, MonthRequests};
 
@@ -28,6 +28,8 @@ pub struct CategoryService<YC: CategoryRequests + MonthRequests> {
     pub ynab_category_meta_repo: DynYnabCategoryMetaRepo,
     pub expense_categorization_repo: DynExpenseCategorizationRepo,
     pub ynab_client: Arc<YC>,
+    pub budget_id: String,
+    pub clock: DynClock,
 }
 
 impl<YC> Clone for CategoryService<YC>
@@ -40,6 +42,8 @@ where
             ynab_category_meta_repo: self.ynab_category_meta_repo.clone(),
             expense_categorization_repo: self.expense_categorization_repo.clone(),
             ynab_client: self.ynab_client.clone(),
+            budget_id: self.budget_id.clone(),
+            clock: self.clock.clone(),
         }
     }
 }
@@ -53,12 +57,16 @@ where
         ynab_category_meta_repo: DynYnabCategoryMetaRepo,
         expense_categorization_repo: DynExpenseCategorizationRepo,
         ynab_client: Arc<YC>,
+        budget_id: &str,
+        clock: DynClock,
     ) -> Arc<Self> {
         Arc::new(CategoryService {
             ynab_category_repo,
             ynab_category_meta_repo,
             expense_categorization_repo,
             ynab_client,
+            budget_id: budget_id.to_string(),
+            clock,
         })
     }
 
@@ -70,7 +78,7 @@ where
 
         let category_groups_with_categories_delta = self
             .ynab_client
-            .get_categories_delta(saved_categories_delta)
+            .get_categories_delta(&self.budget_id, saved_categories_delta)
             .await?;
 
         let (category_groups, categories): (Vec<CategoryGroup>, Vec<Vec<Category>>) =
@@ -107,7 +115,7 @@ where
     }
 
     pub(crate) async fn check_last_saved(&self) -> DatamizeResult<()> {
-        let current_date = Local::now().date_naive();
+        let current_date = self.clock.today();
         if let Ok(last_saved) = self.ynab_category_meta_repo.get_last_saved().await {
             let last_saved_date: NaiveDate = last_saved.parse()?;
             if current_date.month() != last_saved_date.month() {
@@ -180,20 +188,19 @@ where
         &self,
         month: MonthTarget,
     ) -> DatamizeResult<(Vec<Category>, Vec<ExpenseCategorization>)> {
-        match month {
-            MonthTarget::Previous | MonthTarget::Next => {
-                let categories = self
-                    .ynab_client
-                    .get_month_by_date(&DateTime::<Local>::from(month).date_naive().to_string())
-                    .await
-                    .map(|month_detail| month_detail.categories)?;
-
-                let expenses_categorization =
-                    self.get_expenses_categorization(categories.clone()).await?;
-
-                Ok((categories, expenses_categorization))
-            }
-            MonthTarget::Current => self.get_latest_categories().await,
+        let today = self.clock.today();
+        if month.is_current(today) {
+            return self.get_latest_categories().await;
         }
+
+        let categories = self
+            .ynab_client
+            .get_month_by_date(&self.budget_id, &month.first_day(today).to_string())
+            .await
+            .map(|month_detail| month_detail.categories)?;
+
+        let expenses_categorization = self.get_expenses_categorization(categories.clone()).await?;
+
+        Ok((categories, expenses_categorization))
     }
 }
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use datamize_domain::async_trait;
+use tokio::sync::OnceCell;
+use ynab::{BudgetRequests, CurrencyFormat};
+
+#[async_trait]
+pub trait YnabCurrencyFormatServiceExt: Send + Sync {
+    /// The currency format of the budget, to display and read amounts the way YNAB does.
+    /// Falls back to the default format while YNAB can't be reached.
+    async fn get_currency_format(&self) -> CurrencyFormat;
+}
+
+pub type DynYnabCurrencyFormatService = Arc<dyn YnabCurrencyFormatServiceExt>;
+
+pub struct YnabCurrencyFormatService {
+    pub ynab_client: Arc<dyn BudgetRequests + Send + Sync>,
+    pub budget_id: String,
+    /// Only fetched once, since the settings of a budget rarely change.
+    pub currency_format: OnceCell<CurrencyFormat>,
+}
+
+#[async_trait]
+impl YnabCurrencyFormatServiceExt for YnabCurrencyFormatService {
+    #[tracing::instrument(skip(self))]
+    async fn get_currency_format(&self) -> CurrencyFormat {
+        let currency_format = self
+            .currency_format
+            .get_or_try_init(|| async {
+                self.ynab_client
+                    .get_budget_settings(&self.budget_id)
+                    .await
+                    .map(|settings| settings.currency_format)
+            })
+            .await;
+
+        match currency_format {
+            Ok(currency_format) => currency_format.clone(),
+            Err(e) => {
+                tracing::warn!(error = ?e, "failed to get the currency format of the budget, using the default one");
+                CurrencyFormat::default()
+            }
+        }
+    }
+}
+
+impl YnabCurrencyFormatService {
+    pub fn new_arced(
+        ynab_client: Arc<dyn BudgetRequests + Send + Sync>,
+        budget_id: &str,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            ynab_client,
+            budget_id: budget_id.to_string(),
+            currency_format: OnceCell::new(),
+        })
+    }
+}
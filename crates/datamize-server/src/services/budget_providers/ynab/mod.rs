@@ -1,5 +1,7 @@
 mod account;
 mod category;
+mod currency_format;
+mod oauth;
 mod payee;
 mod scheduled_transaction;
 #[cfg(test)]
@@ -8,6 +10,8 @@ mod transaction;
 
 pub use account::*;
 pub use category::*;
+pub use currency_format::*;
+pub use oauth::*;
 pub use payee::*;
 pub use scheduled_transaction::*;
 pub use transaction::*;
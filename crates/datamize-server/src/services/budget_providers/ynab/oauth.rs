@@ -0,0 +1,223 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use datamize_domain::{
+    async_trait,
+    db::{
+        external::{DynEncryptionKeyRepo, DynExternalAccountRepo},
+        ynab::DynYnabOAuthTokenRepo,
+        DbError,
+    },
+    Uuid,
+};
+use orion::{aead, kex::SecretKey};
+use tokio::sync::Mutex;
+use ynab::{OAuthTokenStore, OAuthTokens};
+
+use crate::{
+    error::{AppError, DatamizeResult},
+    services::budget_providers::get_or_create_encryption_key,
+};
+
+#[async_trait]
+pub trait YnabOAuthServiceExt: Send + Sync {
+    /// Returns the URL of YNAB's authorization page, tied to a new CSRF state remembering
+    /// which household member is connecting.
+    async fn get_login_url(&self, member: &str) -> DatamizeResult<String>;
+    /// Exchanges the code YNAB redirected with for tokens, once the state is validated, and saves
+    /// them for the member who started the login.
+    async fn handle_callback(&self, code: &str, state: &str) -> DatamizeResult<()>;
+    /// Loads the saved tokens in the clients. Returns the members who did not connect their YNAB
+    /// account yet.
+    async fn restore_tokens(&self) -> DatamizeResult<Vec<String>>;
+}
+
+pub type DynYnabOAuthService = Arc<dyn YnabOAuthServiceExt>;
+
+/// A login not completed within this delay has to be started again.
+pub const PENDING_STATE_TTL: Duration = Duration::from_secs(10 * 60);
+/// Past this number of logins started and not completed, the oldest ones are forgotten.
+pub const MAX_PENDING_STATES: usize = 64;
+
+/// The YNAB account of a household member, with where its tokens are saved.
+#[derive(Clone)]
+pub struct YnabConnection {
+    pub ynab_client: Arc<ynab::Client>,
+    pub token_store: Arc<EncryptedYnabOAuthTokenStore>,
+}
+
+/// A login that was started but not completed yet.
+#[derive(Debug, Clone)]
+pub struct PendingLogin {
+    pub member: String,
+    pub started_at: Instant,
+}
+
+pub struct YnabOAuthService {
+    /// The connection of each household member, by member.
+    pub connections: HashMap<String, YnabConnection>,
+    /// Logins started but not completed yet, by state.
+    pub pending_logins: Mutex<HashMap<String, PendingLogin>>,
+}
+
+#[async_trait]
+impl YnabOAuthServiceExt for YnabOAuthService {
+    #[tracing::instrument(skip(self))]
+    async fn get_login_url(&self, member: &str) -> DatamizeResult<String> {
+        let connection = self
+            .connections
+            .get(member)
+            .ok_or(AppError::ResourceNotFound)?;
+        let oauth_config = connection
+            .ynab_client
+            .oauth_config()
+            .ok_or(ynab::Error::OAuthNotConfigured)?;
+
+        let state = Uuid::new_v4().to_string();
+        let url = oauth_config.authorize_url(&state);
+        let mut pending_logins = self.pending_logins.lock().await;
+        pending_logins.retain(|_, login| login.started_at.elapsed() < PENDING_STATE_TTL);
+        if pending_logins.len() >= MAX_PENDING_STATES {
+            let oldest = pending_logins
+                .iter()
+                .min_by_key(|(_, login)| login.started_at)
+                .map(|(state, _)| state.clone());
+            if let Some(oldest) = oldest {
+                pending_logins.remove(&oldest);
+            }
+        }
+        pending_logins.insert(
+            state,
+            PendingLogin {
+                member: member.to_owned(),
+                started_at: Instant::now(),
+            },
+        );
+
+        Ok(url.to_string())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn handle_callback(&self, code: &str, state: &str) -> DatamizeResult<()> {
+        let connection = self
+            .pending_logins
+            .lock()
+            .await
+            .remove(state)
+            .filter(|login| login.started_at.elapsed() < PENDING_STATE_TTL)
+            .and_then(|login| self.connections.get(&login.member))
+            .ok_or(AppError::InvalidOAuthState)?;
+
+        let tokens = connection.ynab_client.exchange_oauth_code(code).await?;
+        connection.token_store.persist(&tokens).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn restore_tokens(&self) -> DatamizeResult<Vec<String>> {
+        let mut not_connected = vec![];
+        for (member, connection) in &self.connections {
+            match connection.token_store.load().await? {
+                Some(tokens) => connection.ynab_client.set_oauth_tokens(&tokens).await,
+                None => not_connected.push(member.clone()),
+            }
+        }
+        not_connected.sort();
+
+        Ok(not_connected)
+    }
+}
+
+impl YnabOAuthService {
+    pub fn new_arced(connections: HashMap<String, YnabConnection>) -> Arc<Self> {
+        Arc::new(Self {
+            connections,
+            pending_logins: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// The client of each household member, by member.
+    pub fn clients(&self) -> HashMap<String, Arc<ynab::Client>> {
+        self.connections
+            .iter()
+            .map(|(member, connection)| (member.clone(), connection.ynab_client.clone()))
+            .collect()
+    }
+}
+
+/// Saves the YNAB OAuth tokens of a household member encrypted with the same key as the external
+/// accounts' passwords. Given to the member's client so refreshed tokens are saved as well.
+#[derive(Clone)]
+pub struct EncryptedYnabOAuthTokenStore {
+    pub oauth_token_repo: DynYnabOAuthTokenRepo,
+    pub encryption_key_repo: DynEncryptionKeyRepo,
+    /// Checked before creating the encryption key, for the passwords encrypted with the missing one.
+    pub external_account_repo: DynExternalAccountRepo,
+    pub member: String,
+}
+
+impl EncryptedYnabOAuthTokenStore {
+    pub fn new_arced(
+        oauth_token_repo: DynYnabOAuthTokenRepo,
+        encryption_key_repo: DynEncryptionKeyRepo,
+        external_account_repo: DynExternalAccountRepo,
+        member: &str,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            oauth_token_repo,
+            encryption_key_repo,
+            external_account_repo,
+            member: member.to_owned(),
+        })
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn persist(&self, tokens: &OAuthTokens) -> DatamizeResult<()> {
+        let encryption_key = self.get_or_create_encryption_key().await?;
+        let serialized = serde_json::to_vec(tokens).expect("tokens to be serializable");
+        let encrypted_tokens = aead::seal(&encryption_key, &serialized)?;
+
+        Ok(self
+            .oauth_token_repo
+            .set(&self.member, &encrypted_tokens)
+            .await?)
+    }
+
+    /// Returns `None` when no tokens were saved, or when they can't be decrypted anymore.
+    #[tracing::instrument(skip_all)]
+    pub async fn load(&self) -> DatamizeResult<Option<OAuthTokens>> {
+        let encrypted_tokens = match self.oauth_token_repo.get(&self.member).await {
+            Ok(val) if !val.is_empty() => val,
+            Ok(_) | Err(DbError::NotFound) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let encryption_key = self.get_or_create_encryption_key().await?;
+
+        let tokens = aead::open(&encryption_key, &encrypted_tokens)
+            .ok()
+            .and_then(|tokens| serde_json::from_slice(&tokens).ok());
+        if tokens.is_none() {
+            tracing::warn!(
+                member = self.member,
+                "saved YNAB OAuth tokens could not be decrypted, a new login is needed"
+            );
+        }
+
+        Ok(tokens)
+    }
+
+    async fn get_or_create_encryption_key(&self) -> DatamizeResult<SecretKey> {
+        get_or_create_encryption_key(&self.encryption_key_repo, &self.external_account_repo).await
+    }
+}
+
+#[async_trait]
+impl OAuthTokenStore for EncryptedYnabOAuthTokenStore {
+    async fn save(&self, tokens: &OAuthTokens) {
+        if let Err(e) = self.persist(tokens).await {
+            tracing::error!(error = ?e, "failed to save refreshed YNAB OAuth tokens");
+        }
+    }
+}
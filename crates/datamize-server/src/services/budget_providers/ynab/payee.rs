@@ -20,6 +20,7 @@ pub struct YnabPayeeService {
     pub ynab_payee_repo: DynYnabPayeeRepo,
     pub ynab_payee_meta_repo: DynYnabPayeeMetaRepo,
     pub ynab_client: Arc<dyn PayeeRequests + Send + Sync>,
+    pub budget_id: String,
 }
 
 #[async_trait]
@@ -30,7 +31,7 @@ impl YnabPayeeServiceExt for YnabPayeeService {
 
         let payees_delta = self
             .ynab_client
-            .get_payees_delta(saved_payees_delta)
+            .get_payees_delta(&self.budget_id, saved_payees_delta)
             .await?;
 
         let payees = payees_delta
@@ -56,11 +57,13 @@ impl YnabPayeeService {
         ynab_payee_repo: DynYnabPayeeRepo,
         ynab_payee_meta_repo: DynYnabPayeeMetaRepo,
         ynab_client: Arc<dyn PayeeRequests + Send + Sync>,
+        budget_id: &str,
     ) -> Arc<Self> {
         Arc::new(Self {
             ynab_payee_repo,
             ynab_payee_meta_repo,
             ynab_client,
+            budget_id: budget_id.to_string(),
         })
     }
 }
@@ -24,6 +24,7 @@ pub struct ScheduledTransactionService {
     pub ynab_scheduled_transaction_repo: DynYnabScheduledTransactionRepo,
     pub ynab_scheduled_transaction_meta_repo: DynYnabScheduledTransactionMetaRepo,
     pub ynab_client: Arc<dyn ScheduledTransactionRequests + Send + Sync>,
+    pub budget_id: String,
 }
 
 impl ScheduledTransactionService {
@@ -31,11 +32,13 @@ impl ScheduledTransactionService {
         ynab_scheduled_transaction_repo: DynYnabScheduledTransactionRepo,
         ynab_scheduled_transaction_meta_repo: DynYnabScheduledTransactionMetaRepo,
         ynab_client: Arc<dyn ScheduledTransactionRequests + Send + Sync>,
+        budget_id: &str,
     ) -> Arc<Self> {
         Arc::new(ScheduledTransactionService {
             ynab_scheduled_transaction_repo,
             ynab_scheduled_transaction_meta_repo,
             ynab_client,
+            budget_id: budget_id.to_string(),
         })
     }
 
@@ -86,7 +89,7 @@ impl ScheduledTransactionServiceExt for ScheduledTransactionService {
 
         let scheduled_transactions_delta = self
             .ynab_client
-            .get_scheduled_transactions_delta(saved_scheduled_transactions_delta)
+            .get_scheduled_transactions_delta(&self.budget_id, saved_scheduled_transactions_delta)
             .await?;
 
         self.ynab_scheduled_transaction_repo
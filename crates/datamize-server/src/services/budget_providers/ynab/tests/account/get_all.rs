@@ -1,14 +1,15 @@
+use axum::{http::StatusCode, response::IntoResponse};
 use fake::{Fake, Faker};
 use pretty_assertions::{assert_eq, assert_ne};
 use sqlx::SqlitePool;
-use ynab::{Account, AccountsDelta};
+use ynab::{Account, AccountsDelta, ApiError};
 
 use crate::services::{
     budget_providers::ynab::tests::account::testutils::TestContext,
     testutils::{assert_err, ErrorType},
 };
 
-struct YnabData(AccountsDelta);
+struct YnabData(Result<AccountsDelta, ApiError>);
 
 #[derive(Clone)]
 struct DbData(Vec<Account>);
@@ -53,7 +54,7 @@ async fn returns_empty_list_when_nothing_in_db(pool: SqlitePool) {
         accounts: vec![],
         ..Faker.fake()
     };
-    check_get_all(pool, YnabData(accounts_delta), None, Some(vec![]), None).await;
+    check_get_all(pool, YnabData(Ok(accounts_delta)), None, Some(vec![]), None).await;
 }
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
@@ -65,7 +66,7 @@ async fn returns_success_with_what_is_in_db(pool: SqlitePool) {
 
     check_get_all(
         pool,
-        YnabData(accounts_delta),
+        YnabData(Ok(accounts_delta)),
         Some(DbData(accounts.clone())),
         Some(expected),
         None,
@@ -73,6 +74,40 @@ async fn returns_success_with_what_is_in_db(pool: SqlitePool) {
     .await;
 }
 
+fn not_authorized() -> ApiError {
+    ApiError {
+        id: "401".to_string(),
+        name: "unauthorized".to_string(),
+        detail: "Unauthorized".to_string(),
+    }
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_ynab_error_without_updating_saved_delta(pool: SqlitePool) {
+    check_get_all(
+        pool,
+        YnabData(Err(not_authorized())),
+        None,
+        None,
+        Some(ErrorType::Ynab),
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn ynab_error_is_returned_with_the_status_of_ynab(pool: SqlitePool) {
+    let context = TestContext::setup(pool, Err(not_authorized())).await;
+
+    let response = context
+        .service()
+        .get_all_ynab_accounts()
+        .await
+        .unwrap_err()
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
 // FIXME: For some reasons sometimes the test fails...
 // #[sqlx::test(migrations = "../db-sqlite/migrations")]
 // async fn issue_with_db_should_not_update_saved_delta(pool: SqlitePool) {
@@ -80,7 +115,7 @@ async fn returns_success_with_what_is_in_db(pool: SqlitePool) {
 
 //     check_get_all(
 //         pool,
-//         YnabData(Faker.fake()),
+//         YnabData(Ok(Faker.fake())),
 //         None,
 //         None,
 //         Some(ErrorType::Database),
@@ -5,7 +5,7 @@ use db_redis::{budget_providers::ynab::RedisYnabAccountMetaRepo, get_test_pool};
 use db_sqlite::budget_providers::ynab::SqliteYnabAccountRepo;
 use fake::{Fake, Faker};
 use sqlx::SqlitePool;
-use ynab::{Account, AccountsDelta, MockAccountRequestsImpl};
+use ynab::{Account, AccountsDelta, ApiError, MockAccountRequestsImpl};
 
 use crate::services::budget_providers::{
     DynYnabAccountService, YnabAccountService, YnabAccountServiceExt,
@@ -18,10 +18,15 @@ pub(crate) struct TestContext {
 }
 
 impl TestContext {
-    pub(crate) async fn setup(pool: SqlitePool, ynab_accounts: AccountsDelta) -> Self {
+    pub(crate) async fn setup(
+        pool: SqlitePool,
+        ynab_accounts: Result<AccountsDelta, ApiError>,
+    ) -> Self {
         let redis_conn_pool = get_test_pool().await;
-        let ynab_account_repo = SqliteYnabAccountRepo::new_arced(pool.clone());
-        let ynab_account_meta_repo = RedisYnabAccountMetaRepo::new_arced(redis_conn_pool);
+        let ynab_account_repo =
+            SqliteYnabAccountRepo::new_arced(pool.clone(), ynab::LAST_USED_BUDGET_ID);
+        let ynab_account_meta_repo =
+            RedisYnabAccountMetaRepo::new_arced(redis_conn_pool, ynab::LAST_USED_BUDGET_ID);
         ynab_account_meta_repo
             .set_delta(Faker.fake())
             .await
@@ -30,12 +35,13 @@ impl TestContext {
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_accounts_delta()
-            .returning(move |_| Ok(ynab_accounts.clone()));
+            .returning(move |_, _| ynab_accounts.clone().map_err(ynab::Error::Api));
 
         let ynab_account_service = YnabAccountService::new_arced(
             ynab_account_repo.clone(),
             ynab_account_meta_repo.clone(),
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
         );
 
         Self {
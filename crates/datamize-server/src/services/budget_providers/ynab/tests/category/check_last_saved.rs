@@ -1,11 +1,11 @@
-use chrono::{Local, Months, NaiveDate};
+use chrono::{Months, NaiveDate};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use sqlx::SqlitePool;
 
 use crate::services::{
     budget_providers::ynab::tests::category::testutils::TestContext,
-    testutils::{assert_err, ErrorType},
+    testutils::{assert_err, today, ErrorType},
 };
 
 async fn check_check_last_saved(
@@ -49,20 +49,14 @@ async fn check_check_last_saved(
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
 async fn when_nothing_currently_saved_should_update_last_saved(pool: SqlitePool) {
-    check_check_last_saved(pool, None, Some(Local::now().date_naive()), None).await;
+    check_check_last_saved(pool, None, Some(today()), None).await;
 }
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
 async fn when_saved_date_is_the_same_month_as_current_should_not_update_last_saved(
     pool: SqlitePool,
 ) {
-    check_check_last_saved(
-        pool,
-        Some(Local::now().date_naive().to_string()),
-        Some(Local::now().date_naive()),
-        None,
-    )
-    .await;
+    check_check_last_saved(pool, Some(today().to_string()), Some(today()), None).await;
 }
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
@@ -72,13 +66,12 @@ async fn when_saved_date_is_not_the_same_month_as_current_should_update_last_sav
     check_check_last_saved(
         pool,
         Some(
-            Local::now()
-                .date_naive()
+            today()
                 .checked_sub_months(Months::new(1))
                 .unwrap()
                 .to_string(),
         ),
-        Some(Local::now().date_naive()),
+        Some(today()),
         None,
     )
     .await;
@@ -1,11 +1,13 @@
+use chrono::{Datelike, Months};
 use datamize_domain::MonthTarget;
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use sqlx::SqlitePool;
 use ynab::{Category, CategoryGroupWithCategoriesDelta, MonthDetail};
 
-use crate::services::budget_providers::{
-    ynab::tests::category::testutils::TestContext, CategoryServiceExt,
+use crate::services::{
+    budget_providers::{ynab::tests::category::testutils::TestContext, CategoryServiceExt},
+    testutils::today,
 };
 
 struct YnabData(CategoryGroupWithCategoriesDelta, MonthDetail);
@@ -87,3 +89,41 @@ async fn for_next_month_should_use_month_detail_endpoint(pool: SqlitePool) {
     )
     .await;
 }
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn for_any_other_month_should_use_month_detail_endpoint(pool: SqlitePool) {
+    let month_detail = Faker.fake::<MonthDetail>();
+    let categories = Faker.fake::<Vec<Category>>();
+    let expected = month_detail.categories.clone();
+
+    check_get_categories_of_month(
+        pool,
+        MonthTarget::Month(today().checked_add_months(Months::new(14)).unwrap()),
+        YnabData(Faker.fake(), month_detail),
+        Some(DbData(categories.clone())),
+        expected,
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn for_month_of_today_should_use_categories_endpoint(pool: SqlitePool) {
+    let categories_delta = Faker.fake::<CategoryGroupWithCategoriesDelta>();
+    let categories = Faker.fake::<Vec<Category>>();
+    let mut expected: Vec<Category> = categories_delta
+        .category_groups
+        .clone()
+        .into_iter()
+        .flat_map(|cg| cg.categories)
+        .collect();
+    expected.extend(categories.clone());
+
+    check_get_categories_of_month(
+        pool,
+        MonthTarget::Month(today().with_day(1).unwrap()),
+        YnabData(categories_delta, Faker.fake()),
+        Some(DbData(categories.clone())),
+        expected,
+    )
+    .await;
+}
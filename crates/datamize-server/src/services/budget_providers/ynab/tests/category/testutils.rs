@@ -20,7 +20,7 @@ use ynab::{
     MonthDetail, MonthRequests, MonthSummary, MonthSummaryDelta, SaveMonthCategory, YnabResult,
 };
 
-use crate::services::budget_providers::CategoryService;
+use crate::services::{budget_providers::CategoryService, testutils::fixed_clock};
 
 pub(crate) struct TestContext {
     ynab_category_repo: DynYnabCategoryRepo,
@@ -36,8 +36,10 @@ impl TestContext {
         ynab_month: MonthDetail,
     ) -> Self {
         let redis_conn_pool = get_test_pool().await;
-        let ynab_category_repo = SqliteYnabCategoryRepo::new_arced(pool.clone());
-        let ynab_category_meta_repo = RedisYnabCategoryMetaRepo::new_arced(redis_conn_pool);
+        let ynab_category_repo =
+            SqliteYnabCategoryRepo::new_arced(pool.clone(), ynab::LAST_USED_BUDGET_ID);
+        let ynab_category_meta_repo =
+            RedisYnabCategoryMetaRepo::new_arced(redis_conn_pool, ynab::LAST_USED_BUDGET_ID);
         ynab_category_meta_repo
             .set_delta(Faker.fake())
             .await
@@ -47,16 +49,18 @@ impl TestContext {
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_categories_delta()
-            .returning(move |_| Ok(ynab_categories.clone()));
+            .returning(move |_, _| Ok(ynab_categories.clone()));
         ynab_client_mock
             .expect_get_month_by_date()
-            .returning(move |_| Ok(ynab_month.clone()));
+            .returning(move |_, _| Ok(ynab_month.clone()));
 
         let category_service = CategoryService {
             ynab_category_repo: ynab_category_repo.clone(),
             ynab_category_meta_repo: ynab_category_meta_repo.clone(),
             expense_categorization_repo: expense_categorization_repo.clone(),
             ynab_client,
+            budget_id: ynab::LAST_USED_BUDGET_ID.to_string(),
+            clock: fixed_clock(),
         };
 
         Self {
@@ -113,25 +117,25 @@ mockall::mock! {
 
     #[async_trait]
     impl MonthRequests for MonthAndCategoriesRequestsImpl {
-        async fn get_months(&self) -> YnabResult<Vec<MonthSummary> > ;
+        async fn get_months(&self, budget_id: &str) -> YnabResult<Vec<MonthSummary> > ;
         async fn get_months_delta(
-            &self,
+            &self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<MonthSummaryDelta>;
-        async fn get_month_by_date(&self, date: &str) -> YnabResult<MonthDetail>;
+        async fn get_month_by_date(&self, budget_id: &str, date: &str) -> YnabResult<MonthDetail>;
     }
 
     #[async_trait]
     impl CategoryRequests for MonthAndCategoriesRequestsImpl {
-        async fn get_categories(&self) -> YnabResult<Vec<CategoryGroupWithCategories>>;
+        async fn get_categories(&self, budget_id: &str) -> YnabResult<Vec<CategoryGroupWithCategories>>;
         async fn get_categories_delta(
-            &self,
+            &self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<CategoryGroupWithCategoriesDelta>;
-        async fn get_category_by_id(&self, category_id: &str) -> YnabResult<Category>;
-        async fn get_category_by_id_for(&self, category_id: &str, month: &str) -> YnabResult<Category>;
+        async fn get_category_by_id(&self, budget_id: &str, category_id: &str) -> YnabResult<Category>;
+        async fn get_category_by_id_for(&self, budget_id: &str, category_id: &str, month: &str) -> YnabResult<Category>;
         async fn update_category_for(
-            &self,
+            &self, budget_id: &str,
             category_id: &str,
             month: &str,
             data: SaveMonthCategory,
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use ynab::{BudgetSettings, CurrencyFormat, MockBudgetRequests};
+
+use crate::services::budget_providers::{YnabCurrencyFormatService, YnabCurrencyFormatServiceExt};
+
+fn euro_format() -> CurrencyFormat {
+    CurrencyFormat {
+        iso_code: "EUR".to_string(),
+        example_format: "123 456,78".to_string(),
+        decimal_digits: 2,
+        decimal_separator: ",".to_string(),
+        symbol_first: false,
+        group_separator: " ".to_string(),
+        currency_symbol: "€".to_string(),
+        display_symbol: true,
+    }
+}
+
+#[tokio::test]
+async fn returns_the_currency_format_of_the_budget_and_only_fetches_it_once() {
+    let mut ynab_client = MockBudgetRequests::new();
+    ynab_client
+        .expect_get_budget_settings()
+        .times(1)
+        .returning(|_| {
+            Ok(BudgetSettings {
+                currency_format: euro_format(),
+                ..Faker.fake()
+            })
+        });
+    let service =
+        YnabCurrencyFormatService::new_arced(Arc::new(ynab_client), ynab::LAST_USED_BUDGET_ID);
+
+    assert_eq!(service.get_currency_format().await, euro_format());
+    assert_eq!(service.get_currency_format().await, euro_format());
+}
+
+#[tokio::test]
+async fn falls_back_to_the_default_format_until_ynab_answers() {
+    let mut ynab_client = MockBudgetRequests::new();
+    let mut seq = mockall::Sequence::new();
+    ynab_client
+        .expect_get_budget_settings()
+        .times(1)
+        .in_sequence(&mut seq)
+        .returning(|_| Err(ynab::Error::OAuthNotConfigured));
+    ynab_client
+        .expect_get_budget_settings()
+        .times(1)
+        .in_sequence(&mut seq)
+        .returning(|_| {
+            Ok(BudgetSettings {
+                currency_format: euro_format(),
+                ..Faker.fake()
+            })
+        });
+    let service =
+        YnabCurrencyFormatService::new_arced(Arc::new(ynab_client), ynab::LAST_USED_BUDGET_ID);
+
+    assert_eq!(
+        service.get_currency_format().await,
+        CurrencyFormat::default()
+    );
+    assert_eq!(service.get_currency_format().await, euro_format());
+}
@@ -1,5 +1,7 @@
 mod account;
 mod category;
+mod currency_format;
+mod oauth;
 mod payee;
 mod scheduled_transaction;
 mod transaction;
@@ -0,0 +1,181 @@
+use pretty_assertions::assert_eq;
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, ResponseTemplate,
+};
+
+use crate::{
+    error::AppError,
+    services::budget_providers::{
+        ynab::tests::oauth::testutils::{tokens, TestContext},
+        MAX_PENDING_STATES,
+    },
+};
+
+fn state_of(login_url: &str) -> String {
+    let url = reqwest::Url::parse(login_url).unwrap();
+    url.query_pairs()
+        .find(|(k, _)| k == "state")
+        .map(|(_, v)| v.to_string())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn saves_tokens_when_state_is_valid() {
+    let context = TestContext::setup().await;
+    let expected = tokens("access", "refresh");
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(body_string_contains("code=the-code"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&expected))
+        .expect(1)
+        .mount(&context.server)
+        .await;
+
+    let login_url = context.service().get_login_url("al").await.unwrap();
+    context
+        .service()
+        .handle_callback("the-code", &state_of(&login_url))
+        .await
+        .unwrap();
+
+    assert_eq!(context.get_tokens("al").await, Some(expected));
+    assert_eq!(context.get_tokens("bea").await, None);
+}
+
+#[tokio::test]
+async fn rejects_unknown_state() {
+    let context = TestContext::setup().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tokens("access", "refresh")))
+        .expect(0)
+        .mount(&context.server)
+        .await;
+
+    context.service().get_login_url("al").await.unwrap();
+    let err = context
+        .service()
+        .handle_callback("the-code", "forged-state")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, AppError::InvalidOAuthState));
+}
+
+#[tokio::test]
+async fn state_can_only_be_used_once() {
+    let context = TestContext::setup().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tokens("access", "refresh")))
+        .expect(1)
+        .mount(&context.server)
+        .await;
+
+    let state = state_of(&context.service().get_login_url("al").await.unwrap());
+    context
+        .service()
+        .handle_callback("the-code", &state)
+        .await
+        .unwrap();
+    let err = context
+        .service()
+        .handle_callback("the-code", &state)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, AppError::InvalidOAuthState));
+}
+
+#[tokio::test]
+async fn rejects_expired_state() {
+    let context = TestContext::setup().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tokens("access", "refresh")))
+        .expect(0)
+        .mount(&context.server)
+        .await;
+
+    let state = state_of(&context.service().get_login_url("al").await.unwrap());
+    context.expire_pending_states().await;
+    let err = context
+        .service()
+        .handle_callback("the-code", &state)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, AppError::InvalidOAuthState));
+    assert_eq!(context.pending_states_count().await, 0);
+}
+
+#[tokio::test]
+async fn forgets_expired_and_oldest_states() {
+    let context = TestContext::setup().await;
+
+    context.service().get_login_url("al").await.unwrap();
+    context.expire_pending_states().await;
+    let oldest = state_of(&context.service().get_login_url("al").await.unwrap());
+    assert_eq!(context.pending_states_count().await, 1);
+    for _ in 0..MAX_PENDING_STATES {
+        context.service().get_login_url("al").await.unwrap();
+    }
+
+    assert_eq!(context.pending_states_count().await, MAX_PENDING_STATES);
+    let err = context
+        .service()
+        .handle_callback("the-code", &oldest)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AppError::InvalidOAuthState));
+}
+
+#[tokio::test]
+async fn saves_tokens_of_the_member_who_started_the_login() {
+    let context = TestContext::setup().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(body_string_contains("code=al-code"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tokens("al-access", "al-refresh")))
+        .mount(&context.server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/oauth/token"))
+        .and(body_string_contains("code=bea-code"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tokens("bea-access", "bea-refresh")))
+        .mount(&context.server)
+        .await;
+
+    let al_state = state_of(&context.service().get_login_url("al").await.unwrap());
+    let bea_state = state_of(&context.service().get_login_url("bea").await.unwrap());
+    context
+        .service()
+        .handle_callback("bea-code", &bea_state)
+        .await
+        .unwrap();
+    context
+        .service()
+        .handle_callback("al-code", &al_state)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        context.get_tokens("al").await,
+        Some(tokens("al-access", "al-refresh"))
+    );
+    assert_eq!(
+        context.get_tokens("bea").await,
+        Some(tokens("bea-access", "bea-refresh"))
+    );
+}
+
+#[tokio::test]
+async fn login_of_unknown_member_is_not_found() {
+    let context = TestContext::setup().await;
+
+    let err = context.service().get_login_url("carl").await.unwrap_err();
+
+    assert!(matches!(err, AppError::ResourceNotFound));
+    assert_eq!(context.pending_states_count().await, 0);
+}
@@ -0,0 +1,3 @@
+mod handle_callback;
+mod restore_tokens;
+pub(crate) mod testutils;
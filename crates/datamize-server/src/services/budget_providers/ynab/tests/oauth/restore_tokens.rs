@@ -0,0 +1,78 @@
+use pretty_assertions::assert_eq;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, ResponseTemplate,
+};
+use ynab::AccountRequests;
+
+use crate::{
+    error::AppError,
+    services::budget_providers::ynab::tests::oauth::testutils::{tokens, TestContext},
+};
+
+#[tokio::test]
+async fn returns_the_members_never_connected() {
+    let context = TestContext::setup().await;
+    context
+        .set_tokens("bea", &tokens("access", "refresh"))
+        .await;
+
+    assert_eq!(
+        context.service().restore_tokens().await.unwrap(),
+        vec!["al"]
+    );
+}
+
+#[tokio::test]
+async fn client_uses_restored_tokens() {
+    let context = TestContext::setup().await;
+    context
+        .set_tokens("al", &tokens("saved-access", "saved-refresh"))
+        .await;
+    context
+        .set_tokens("bea", &tokens("other-access", "other-refresh"))
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/budgets/last-used/accounts"))
+        .and(header("Authorization", "Bearer saved-access"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "accounts": [], "server_knowledge": 1 }
+        })))
+        .expect(1)
+        .mount(&context.server)
+        .await;
+
+    assert!(context.service().restore_tokens().await.unwrap().is_empty());
+    assert!(context
+        .client("al")
+        .get_accounts(ynab::LAST_USED_BUDGET_ID)
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn invalid_encryption_key_is_not_replaced() {
+    let context = TestContext::setup().await;
+    context.set_encryption_key(b"not a key").await;
+
+    assert!(context
+        .token_store("al")
+        .persist(&tokens("access", "refresh"))
+        .await
+        .is_err());
+    assert_eq!(context.get_encryption_key().await, b"not a key");
+}
+
+#[tokio::test]
+async fn encryption_key_is_not_created_when_external_accounts_exist() {
+    let context = TestContext::setup().await;
+    context.add_external_account("Savings").await;
+
+    assert!(matches!(
+        context
+            .token_store("al")
+            .persist(&tokens("access", "refresh"))
+            .await,
+        Err(AppError::MissingEncryptionKey)
+    ));
+}
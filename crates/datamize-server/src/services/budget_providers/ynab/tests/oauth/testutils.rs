@@ -0,0 +1,141 @@
+use std::{collections::HashMap, sync::Arc};
+
+use datamize_domain::{
+    db::external::{EncryptionKeyRepo, ExternalAccountRepo},
+    Uuid, WebScrapingAccount,
+};
+use db_memory::{budget_providers::external::MemoryExternalAccountRepo, MemoryStore};
+use db_redis::{
+    budget_providers::{external::RedisEncryptionKeyRepo, ynab::RedisYnabOAuthTokenRepo},
+    get_test_pool,
+};
+use wiremock::MockServer;
+use ynab::{OAuthConfig, OAuthTokens};
+
+use crate::services::budget_providers::{
+    EncryptedYnabOAuthTokenStore, YnabConnection, YnabOAuthService, YnabOAuthServiceExt,
+    PENDING_STATE_TTL,
+};
+
+pub(crate) const MEMBERS: [&str; 2] = ["al", "bea"];
+
+pub(crate) struct TestContext {
+    encryption_key_repo: Arc<RedisEncryptionKeyRepo>,
+    external_account_repo: Arc<MemoryExternalAccountRepo>,
+    ynab_oauth_service: Arc<YnabOAuthService>,
+    pub(crate) server: MockServer,
+}
+
+impl TestContext {
+    pub(crate) async fn setup() -> Self {
+        let redis_conn_pool = get_test_pool().await;
+        let server = MockServer::start().await;
+        let encryption_key_repo = RedisEncryptionKeyRepo::new_arced(redis_conn_pool.clone());
+        let oauth_token_repo = RedisYnabOAuthTokenRepo::new_arced(redis_conn_pool);
+        let external_account_repo = MemoryExternalAccountRepo::new_arced(MemoryStore::default());
+        let connections = MEMBERS
+            .iter()
+            .map(|member| {
+                let token_store = EncryptedYnabOAuthTokenStore::new_arced(
+                    oauth_token_repo.clone(),
+                    encryption_key_repo.clone(),
+                    external_account_repo.clone(),
+                    member,
+                );
+                let config = OAuthConfig::new("client", "secret", "http://localhost/callback")
+                    .with_oauth_base_url(&format!("{}/oauth/", server.uri()));
+                let ynab_client = Arc::new(
+                    ynab::Client::new("", &format!("{}/", server.uri()))
+                        .unwrap()
+                        .with_oauth(config)
+                        .with_oauth_token_store(token_store.clone()),
+                );
+                (
+                    member.to_string(),
+                    YnabConnection {
+                        ynab_client,
+                        token_store,
+                    },
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        let ynab_oauth_service = YnabOAuthService::new_arced(connections);
+
+        Self {
+            encryption_key_repo,
+            external_account_repo,
+            ynab_oauth_service,
+            server,
+        }
+    }
+
+    pub(crate) fn service(&self) -> &dyn YnabOAuthServiceExt {
+        self.ynab_oauth_service.as_ref()
+    }
+
+    pub(crate) async fn pending_states_count(&self) -> usize {
+        self.ynab_oauth_service.pending_logins.lock().await.len()
+    }
+
+    /// Makes the logins started so far look as if they started too long ago.
+    pub(crate) async fn expire_pending_states(&self) {
+        for login in self
+            .ynab_oauth_service
+            .pending_logins
+            .lock()
+            .await
+            .values_mut()
+        {
+            login.started_at -= PENDING_STATE_TTL;
+        }
+    }
+
+    fn connection(&self, member: &str) -> &YnabConnection {
+        &self.ynab_oauth_service.connections[member]
+    }
+
+    pub(crate) fn client(&self, member: &str) -> &ynab::Client {
+        self.connection(member).ynab_client.as_ref()
+    }
+
+    pub(crate) fn token_store(&self, member: &str) -> &EncryptedYnabOAuthTokenStore {
+        self.connection(member).token_store.as_ref()
+    }
+
+    pub(crate) async fn set_tokens(&self, member: &str, tokens: &OAuthTokens) {
+        self.token_store(member).persist(tokens).await.unwrap();
+    }
+
+    pub(crate) async fn get_tokens(&self, member: &str) -> Option<OAuthTokens> {
+        self.token_store(member).load().await.unwrap()
+    }
+
+    pub(crate) async fn set_encryption_key(&self, key: &[u8]) {
+        self.encryption_key_repo.set(key).await.unwrap();
+    }
+
+    pub(crate) async fn get_encryption_key(&self) -> Vec<u8> {
+        self.encryption_key_repo.get().await.unwrap()
+    }
+
+    pub(crate) async fn add_external_account(&self, name: &str) {
+        self.external_account_repo
+            .add(&WebScrapingAccount {
+                id: Uuid::new_v4(),
+                name: name.to_owned(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+    }
+}
+
+pub(crate) fn tokens(access_token: &str, refresh_token: &str) -> OAuthTokens {
+    OAuthTokens {
+        access_token: access_token.to_string(),
+        refresh_token: refresh_token.to_string(),
+        token_type: "Bearer".to_string(),
+        expires_in: 7200,
+        scope: None,
+    }
+}
@@ -20,19 +20,22 @@ pub(crate) struct TestContext {
 impl TestContext {
     pub(crate) async fn setup(pool: SqlitePool, ynab_payees: PayeesDelta) -> Self {
         let redis_conn_pool = get_test_pool().await;
-        let ynab_payee_repo = SqliteYnabPayeeRepo::new_arced(pool.clone());
-        let ynab_payee_meta_repo = RedisYnabPayeeMetaRepo::new_arced(redis_conn_pool);
+        let ynab_payee_repo =
+            SqliteYnabPayeeRepo::new_arced(pool.clone(), ynab::LAST_USED_BUDGET_ID);
+        let ynab_payee_meta_repo =
+            RedisYnabPayeeMetaRepo::new_arced(redis_conn_pool, ynab::LAST_USED_BUDGET_ID);
         ynab_payee_meta_repo.set_delta(Faker.fake()).await.unwrap();
         let mut ynab_client = Arc::new(MockPayeeRequestsImpl::new());
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_payees_delta()
-            .returning(move |_| Ok(ynab_payees.clone()));
+            .returning(move |_, _| Ok(ynab_payees.clone()));
 
         let ynab_payee_service = YnabPayeeService::new_arced(
             ynab_payee_repo.clone(),
             ynab_payee_meta_repo.clone(),
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
         );
 
         Self {
@@ -30,10 +30,13 @@ impl TestContext {
         ynab_transactions: ScheduledTransactionsDetailDelta,
     ) -> Self {
         let redis_conn_pool = get_test_pool().await;
-        let ynab_scheduled_transaction_repo = SqliteYnabScheduledTransactionRepo::new_arced(pool);
+        let ynab_scheduled_transaction_repo =
+            SqliteYnabScheduledTransactionRepo::new_arced(pool, ynab::LAST_USED_BUDGET_ID);
 
-        let ynab_scheduled_transaction_meta_repo =
-            RedisYnabScheduledTransactionMetaRepo::new_arced(redis_conn_pool);
+        let ynab_scheduled_transaction_meta_repo = RedisYnabScheduledTransactionMetaRepo::new_arced(
+            redis_conn_pool,
+            ynab::LAST_USED_BUDGET_ID,
+        );
         ynab_scheduled_transaction_meta_repo
             .set_delta(Faker.fake())
             .await
@@ -42,12 +45,13 @@ impl TestContext {
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_scheduled_transactions_delta()
-            .returning(move |_| Ok(ynab_transactions.clone()));
+            .returning(move |_, _| Ok(ynab_transactions.clone()));
 
         let scheduled_transaction_service = ScheduledTransactionService {
             ynab_scheduled_transaction_repo: ynab_scheduled_transaction_repo.clone(),
             ynab_scheduled_transaction_meta_repo: ynab_scheduled_transaction_meta_repo.clone(),
             ynab_client,
+            budget_id: ynab::LAST_USED_BUDGET_ID.to_string(),
         };
 
         Self {
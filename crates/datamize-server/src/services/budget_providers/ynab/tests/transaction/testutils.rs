@@ -23,9 +23,11 @@ impl TestContext {
         ynab_transactions: TransactionsDetailDelta,
     ) -> Self {
         let redis_conn_pool = get_test_pool().await;
-        let ynab_transaction_repo = SqliteYnabTransactionRepo::new_arced(pool);
+        let ynab_transaction_repo =
+            SqliteYnabTransactionRepo::new_arced(pool, ynab::LAST_USED_BUDGET_ID);
 
-        let ynab_transaction_meta_repo = RedisYnabTransactionMetaRepo::new_arced(redis_conn_pool);
+        let ynab_transaction_meta_repo =
+            RedisYnabTransactionMetaRepo::new_arced(redis_conn_pool, ynab::LAST_USED_BUDGET_ID);
         ynab_transaction_meta_repo
             .set_delta(Faker.fake())
             .await
@@ -34,12 +36,13 @@ impl TestContext {
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_transactions_delta()
-            .returning(move |_| Ok(ynab_transactions.clone()));
+            .returning(move |_, _| Ok(ynab_transactions.clone()));
 
         let transaction_service = TransactionService::new_arced(
             ynab_transaction_repo.clone(),
             ynab_transaction_meta_repo.clone(),
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
         );
 
         Self {
@@ -30,6 +30,7 @@ pub struct TransactionService {
     pub ynab_transaction_repo: DynYnabTransactionRepo,
     pub ynab_transaction_meta_repo: DynYnabTransactionMetaRepo,
     pub ynab_client: Arc<dyn TransactionRequests + Send + Sync>,
+    pub budget_id: String,
 }
 
 #[async_trait]
@@ -40,7 +41,7 @@ impl TransactionServiceExt for TransactionService {
 
         let transactions_delta = self
             .ynab_client
-            .get_transactions_delta(saved_transactions_delta)
+            .get_transactions_delta(&self.budget_id, saved_transactions_delta)
             .await?;
 
         self.ynab_transaction_repo
@@ -95,11 +96,13 @@ impl TransactionService {
         ynab_transaction_repo: DynYnabTransactionRepo,
         ynab_transaction_meta_repo: DynYnabTransactionMetaRepo,
         ynab_client: Arc<dyn TransactionRequests + Send + Sync>,
+        budget_id: &str,
     ) -> Arc<Self> {
         Arc::new(TransactionService {
             ynab_transaction_repo,
             ynab_transaction_meta_repo,
             ynab_client,
+            budget_id: budget_id.to_string(),
         })
     }
 }
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DynBudgeterConfigRepo, DynExpenseAssociationRepo},
+    ExpenseAssociation, SaveExpenseAssociation, Uuid,
+};
+
+use crate::error::{AppError, DatamizeResult};
+
+#[async_trait]
+pub trait ExpenseAssociationServiceExt: Send + Sync {
+    async fn get_all_expenses_association(&self) -> DatamizeResult<Vec<ExpenseAssociation>>;
+    async fn get_expense_association(&self, id: Uuid) -> DatamizeResult<ExpenseAssociation>;
+    async fn save_expense_association(
+        &self,
+        id: Uuid,
+        new_expense_association: SaveExpenseAssociation,
+    ) -> DatamizeResult<ExpenseAssociation>;
+    async fn delete_expense_association(&self, id: Uuid) -> DatamizeResult<ExpenseAssociation>;
+}
+
+pub type DynExpenseAssociationService = Arc<dyn ExpenseAssociationServiceExt>;
+
+pub struct ExpenseAssociationService {
+    pub expense_association_repo: DynExpenseAssociationRepo,
+    pub budgeter_config_repo: DynBudgeterConfigRepo,
+}
+
+impl ExpenseAssociationService {
+    pub fn new_arced(
+        expense_association_repo: DynExpenseAssociationRepo,
+        budgeter_config_repo: DynBudgeterConfigRepo,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            expense_association_repo,
+            budgeter_config_repo,
+        })
+    }
+}
+
+#[async_trait]
+impl ExpenseAssociationServiceExt for ExpenseAssociationService {
+    #[tracing::instrument(skip(self))]
+    async fn get_all_expenses_association(&self) -> DatamizeResult<Vec<ExpenseAssociation>> {
+        Ok(self.expense_association_repo.get_all().await?)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_expense_association(&self, id: Uuid) -> DatamizeResult<ExpenseAssociation> {
+        Ok(self.expense_association_repo.get(id).await?)
+    }
+
+    /// Associates the category (or category group) `id` with a budgeter, or marks it as shared,
+    /// replacing any previous association. Its split strategy, if any, must only refer to existing budgeters.
+    #[tracing::instrument(skip(self))]
+    async fn save_expense_association(
+        &self,
+        id: Uuid,
+        new_expense_association: SaveExpenseAssociation,
+    ) -> DatamizeResult<ExpenseAssociation> {
+        if let Some(budgeter_id) = new_expense_association.budgeter_id {
+            let Ok(_) = self.budgeter_config_repo.get(budgeter_id).await else {
+                return Err(AppError::ResourceNotFound);
+            };
+        }
+
+        if let Some(split_strategy) = &new_expense_association.split_strategy {
+            let budgeter_ids: Vec<_> = self
+                .budgeter_config_repo
+                .get_all()
+                .await?
+                .into_iter()
+                .map(|b| b.id)
+                .collect();
+            if !split_strategy.is_valid(&budgeter_ids) {
+                return Err(AppError::InvalidSplitStrategy);
+            }
+        }
+
+        let expense_association = ExpenseAssociation::new(id, new_expense_association);
+        self.expense_association_repo
+            .update(&expense_association)
+            .await?;
+
+        Ok(expense_association)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_expense_association(&self, id: Uuid) -> DatamizeResult<ExpenseAssociation> {
+        let Ok(expense_association) = self.expense_association_repo.get(id).await else {
+            return Err(AppError::ResourceNotFound);
+        };
+
+        self.expense_association_repo.delete(id).await?;
+
+        Ok(expense_association)
+    }
+}
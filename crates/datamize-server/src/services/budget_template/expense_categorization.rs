@@ -1,4 +1,8 @@
-use datamize_domain::{async_trait, db::DynExpenseCategorizationRepo, ExpenseCategorization, Uuid};
+use datamize_domain::{
+    async_trait,
+    db::{DynBudgeterConfigRepo, DynExpenseCategorizationRepo},
+    ExpenseCategorization, Uuid,
+};
 use std::sync::Arc;
 
 use crate::error::{AppError, DatamizeResult};
@@ -24,14 +28,29 @@ pub type DynExpenseCategorizationService = Arc<dyn ExpenseCategorizationServiceE
 
 pub struct ExpenseCategorizationService {
     pub expense_categorization_repo: DynExpenseCategorizationRepo,
+    pub budgeter_config_repo: DynBudgeterConfigRepo,
 }
 
 impl ExpenseCategorizationService {
-    pub fn new_arced(expense_categorization_repo: DynExpenseCategorizationRepo) -> Arc<Self> {
+    pub fn new_arced(
+        expense_categorization_repo: DynExpenseCategorizationRepo,
+        budgeter_config_repo: DynBudgeterConfigRepo,
+    ) -> Arc<Self> {
         Arc::new(Self {
             expense_categorization_repo,
+            budgeter_config_repo,
         })
     }
+
+    async fn get_budgeter_ids(&self) -> DatamizeResult<Vec<Uuid>> {
+        Ok(self
+            .budgeter_config_repo
+            .get_all()
+            .await?
+            .into_iter()
+            .map(|b| b.id)
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -46,6 +65,14 @@ impl ExpenseCategorizationServiceExt for ExpenseCategorizationService {
         &self,
         new_expenses_categorization: Vec<ExpenseCategorization>,
     ) -> DatamizeResult<Vec<ExpenseCategorization>> {
+        let budgeter_ids = self.get_budgeter_ids().await?;
+        if new_expenses_categorization
+            .iter()
+            .any(|ec| !ec.split_strategy.is_valid(&budgeter_ids))
+        {
+            return Err(AppError::InvalidSplitStrategy);
+        }
+
         self.expense_categorization_repo
             .update_all(&new_expenses_categorization)
             .await?;
@@ -69,6 +96,14 @@ impl ExpenseCategorizationServiceExt for ExpenseCategorizationService {
         &self,
         new_expense_categorization: ExpenseCategorization,
     ) -> DatamizeResult<ExpenseCategorization> {
+        let budgeter_ids = self.get_budgeter_ids().await?;
+        if !new_expense_categorization
+            .split_strategy
+            .is_valid(&budgeter_ids)
+        {
+            return Err(AppError::InvalidSplitStrategy);
+        }
+
         let Ok(_) = self
             .expense_categorization_repo
             .get(new_expense_categorization.id)
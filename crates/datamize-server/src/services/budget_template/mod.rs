@@ -1,13 +1,17 @@
 mod budgeter;
+mod expense_association;
 mod expense_categorization;
 mod template_detail;
+mod template_projection;
 mod template_summary;
 mod template_transaction;
 #[cfg(test)]
 mod tests;
 
 pub use budgeter::*;
+pub use expense_association::*;
 pub use expense_categorization::*;
 pub use template_detail::*;
+pub use template_projection::*;
 pub use template_summary::*;
 pub use template_transaction::*;
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use chrono::{Datelike, Months};
+use datamize_domain::{
+    async_trait,
+    db::{DynBudgeterConfigRepo, DynExpenseAssociationRepo},
+    BudgetProjection, DynClock, MonthTarget, DEFAULT_PROJECTION_MONTHS, MAX_PROJECTION_MONTHS,
+};
+
+use crate::{
+    error::{AppError, DatamizeResult},
+    services::budget_providers::{DynCategoryService, DynScheduledTransactionService},
+};
+
+#[async_trait]
+pub trait TemplateProjectionServiceExt: Send + Sync {
+    /// Projects the budget template on each month from `from` to `to` included.
+    /// Without `to`, 12 months are projected.
+    async fn get_template_projection(
+        &self,
+        from: MonthTarget,
+        to: Option<MonthTarget>,
+        use_category_groups_as_sub_type: bool,
+    ) -> DatamizeResult<BudgetProjection>;
+}
+
+pub type DynTemplateProjectionService = Arc<dyn TemplateProjectionServiceExt>;
+
+#[derive(Clone)]
+pub struct TemplateProjectionService {
+    pub category_service: DynCategoryService,
+    pub scheduled_transaction_service: DynScheduledTransactionService,
+    pub budgeter_config_repo: DynBudgeterConfigRepo,
+    pub expense_association_repo: DynExpenseAssociationRepo,
+    pub clock: DynClock,
+}
+
+impl TemplateProjectionService {
+    pub fn new_arced(
+        category_service: DynCategoryService,
+        scheduled_transaction_service: DynScheduledTransactionService,
+        budgeter_config_repo: DynBudgeterConfigRepo,
+        expense_association_repo: DynExpenseAssociationRepo,
+        clock: DynClock,
+    ) -> Arc<Self> {
+        Arc::new(TemplateProjectionService {
+            category_service,
+            scheduled_transaction_service,
+            budgeter_config_repo,
+            expense_association_repo,
+            clock,
+        })
+    }
+}
+
+#[async_trait]
+impl TemplateProjectionServiceExt for TemplateProjectionService {
+    #[tracing::instrument(skip(self))]
+    async fn get_template_projection(
+        &self,
+        from: MonthTarget,
+        to: Option<MonthTarget>,
+        use_category_groups_as_sub_type: bool,
+    ) -> DatamizeResult<BudgetProjection> {
+        let today = self.clock.today();
+        let num_months = match to {
+            Some(to) => {
+                let (from, to) = (from.first_day(today), to.first_day(today));
+                (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32 + 1
+            }
+            None => DEFAULT_PROJECTION_MONTHS as i32,
+        };
+        if num_months < 1 || num_months > MAX_PROJECTION_MONTHS as i32 {
+            return Err(AppError::InvalidProjectionRange);
+        }
+
+        let first_month = from.first_day_from(self.clock.now());
+        let months: Vec<_> = (0..num_months as u32)
+            .filter_map(|i| first_month.checked_add_months(Months::new(i)))
+            .collect();
+
+        // Goals are the same from one month to the other, so the current categories are used for all months.
+        let (categories, expenses_categorization) = self
+            .category_service
+            .get_categories_of_month(MonthTarget::Current)
+            .await?;
+        let scheduled_transactions = self
+            .scheduled_transaction_service
+            .get_latest_scheduled_transactions()
+            .await?;
+        let budgeters_config = self.budgeter_config_repo.get_all().await?;
+        let expenses_association = self.expense_association_repo.get_all().await?;
+
+        Ok(BudgetProjection::build(
+            categories,
+            scheduled_transactions,
+            &months,
+            today,
+            expenses_categorization,
+            budgeters_config,
+            expenses_association,
+            use_category_groups_as_sub_type,
+        ))
+    }
+}
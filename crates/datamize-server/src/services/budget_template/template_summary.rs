@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use datamize_domain::{
-    async_trait, db::DynBudgeterConfigRepo, BudgetDetails, BudgetSummary, Budgeter, Configured,
-    MonthTarget,
+    async_trait,
+    db::{DynBudgeterConfigRepo, DynExpenseAssociationRepo},
+    BudgetDetails, BudgetSummary, Budgeter, Configured, DynClock, MonthTarget,
 };
 
 use crate::{
@@ -26,6 +27,8 @@ pub struct TemplateSummaryService {
     pub category_service: DynCategoryService,
     pub scheduled_transaction_service: DynScheduledTransactionService,
     pub budgeter_config_repo: DynBudgeterConfigRepo,
+    pub expense_association_repo: DynExpenseAssociationRepo,
+    pub clock: DynClock,
 }
 
 impl TemplateSummaryService {
@@ -33,11 +36,15 @@ impl TemplateSummaryService {
         category_service: DynCategoryService,
         scheduled_transaction_service: DynScheduledTransactionService,
         budgeter_config_repo: DynBudgeterConfigRepo,
+        expense_association_repo: DynExpenseAssociationRepo,
+        clock: DynClock,
     ) -> Arc<Self> {
         Arc::new(TemplateSummaryService {
             category_service,
             scheduled_transaction_service,
             budgeter_config_repo,
+            expense_association_repo,
+            clock,
         })
     }
 }
@@ -50,6 +57,7 @@ impl TemplateSummaryServiceExt for TemplateSummaryService {
         month: MonthTarget,
         use_category_groups_as_sub_type: bool,
     ) -> DatamizeResult<BudgetSummary> {
+        let date = month.first_day_from(self.clock.now());
         let (saved_categories, expenses_categorization) =
             self.category_service.get_categories_of_month(month).await?;
         let saved_scheduled_transactions = self
@@ -62,12 +70,13 @@ impl TemplateSummaryServiceExt for TemplateSummaryService {
             .find(|c| c.name.contains("Ready to Assign"))
             .map(|c| c.id);
         let budgeters_config = self.budgeter_config_repo.get_all().await?;
+        let expenses_association = self.expense_association_repo.get_all().await?;
         let budgeters: Vec<_> = budgeters_config
             .into_iter()
             .map(|bc| {
                 Budgeter::<Configured>::from(bc).compute_salary(
                     &saved_scheduled_transactions,
-                    &month.into(),
+                    &date,
                     inflow_cat_id,
                 )
             })
@@ -76,9 +85,11 @@ impl TemplateSummaryServiceExt for TemplateSummaryService {
         let budget_details = BudgetDetails::build(
             saved_categories,
             saved_scheduled_transactions,
-            &month.into(),
+            &date,
+            self.clock.today(),
             expenses_categorization,
             &budgeters,
+            &expenses_association,
             use_category_groups_as_sub_type,
         );
 
@@ -3,7 +3,7 @@ use std::{collections::HashMap, sync::Arc};
 use anyhow::Context;
 use datamize_domain::{
     async_trait, db::ynab::DynYnabCategoryRepo, CategoryIdToNameMap, DatamizeScheduledTransaction,
-    ScheduledTransactionsDistribution, Uuid,
+    DynClock, MonthTarget, ScheduledTransactionsDistribution, Uuid,
 };
 use futures::{stream::FuturesUnordered, StreamExt};
 use ynab::CategoryRequests;
@@ -12,7 +12,10 @@ use crate::{error::DatamizeResult, services::budget_providers::DynScheduledTrans
 
 #[async_trait]
 pub trait TemplateTransactionServiceExt: Send + Sync {
-    async fn get_template_transactions(&self) -> DatamizeResult<ScheduledTransactionsDistribution>;
+    async fn get_template_transactions(
+        &self,
+        month: MonthTarget,
+    ) -> DatamizeResult<ScheduledTransactionsDistribution>;
 }
 
 pub type DynTemplateTransactionService = Arc<dyn TemplateTransactionServiceExt>;
@@ -22,6 +25,8 @@ pub struct TemplateTransactionService {
     pub scheduled_transaction_service: DynScheduledTransactionService,
     pub ynab_category_repo: DynYnabCategoryRepo,
     pub ynab_client: Arc<dyn CategoryRequests + Sync + Send>,
+    pub budget_id: String,
+    pub clock: DynClock,
 }
 
 impl TemplateTransactionService {
@@ -29,11 +34,15 @@ impl TemplateTransactionService {
         scheduled_transaction_service: DynScheduledTransactionService,
         ynab_category_repo: DynYnabCategoryRepo,
         ynab_client: Arc<dyn CategoryRequests + Sync + Send>,
+        budget_id: &str,
+        clock: DynClock,
     ) -> Arc<Self> {
         Arc::new(TemplateTransactionService {
             scheduled_transaction_service,
             ynab_category_repo,
             ynab_client,
+            budget_id: budget_id.to_string(),
+            clock,
         })
     }
 
@@ -52,7 +61,10 @@ impl TemplateTransactionService {
 #[async_trait]
 impl TemplateTransactionServiceExt for TemplateTransactionService {
     #[tracing::instrument(skip(self))]
-    async fn get_template_transactions(&self) -> DatamizeResult<ScheduledTransactionsDistribution> {
+    async fn get_template_transactions(
+        &self,
+        month: MonthTarget,
+    ) -> DatamizeResult<ScheduledTransactionsDistribution> {
         let saved_scheduled_transactions = self
             .scheduled_transaction_service
             .get_latest_scheduled_transactions()
@@ -79,16 +91,21 @@ impl TemplateTransactionServiceExt for TemplateTransactionService {
                 Ok(cat) => cat,
                 Err(_) => {
                     self.ynab_client
-                        .get_category_by_id(&category_ids[index].to_string())
+                        .get_category_by_id(&self.budget_id, &category_ids[index].to_string())
                         .await?
                 }
             };
             category_id_to_name_map.insert(category.id, category.name);
         }
 
-        let data = ScheduledTransactionsDistribution::builder(saved_scheduled_transactions)
-            .with_category_map(category_id_to_name_map)
-            .build();
+        let today = self.clock.today();
+        let mut builder = ScheduledTransactionsDistribution::builder(saved_scheduled_transactions)
+            .with_category_map(category_id_to_name_map);
+        // The current month keeps showing the upcoming 30 days.
+        if !month.is_current(today) {
+            builder = builder.with_month(month.first_day_from(self.clock.now()));
+        }
+        let data = builder.build(today);
 
         Ok(data)
     }
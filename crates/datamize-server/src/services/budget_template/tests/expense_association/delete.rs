@@ -0,0 +1,73 @@
+use datamize_domain::{db::DbError, BudgeterConfig, ExpenseAssociation};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+
+use crate::services::{
+    budget_template::tests::expense_association::testutils::TestContext,
+    testutils::{assert_err, ErrorType},
+};
+
+async fn check_delete(
+    pool: SqlitePool,
+    expected_resp: Option<ExpenseAssociation>,
+    expected_err: Option<ErrorType>,
+) {
+    let context = TestContext::setup(pool);
+
+    if let Some(expected_resp) = expected_resp.clone() {
+        if let Some(budgeter_id) = expected_resp.budgeter_id {
+            context
+                .set_budgeters(&[BudgeterConfig {
+                    id: budgeter_id,
+                    ..Faker.fake()
+                }])
+                .await;
+        }
+        context.set_expenses_association(&[expected_resp]).await;
+    }
+
+    let response = context
+        .service()
+        .delete_expense_association(expected_resp.clone().unwrap_or_else(|| Faker.fake()).id)
+        .await;
+
+    if let Some(expected_resp) = expected_resp {
+        assert_eq!(response.unwrap(), expected_resp);
+
+        // Make sure the deletion removed it from db
+        let saved = context.get_expense_association(expected_resp.id).await;
+        assert_eq!(saved, Err(DbError::NotFound));
+    } else {
+        assert_err(response.unwrap_err(), expected_err);
+    }
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_not_found_when_nothing_in_db(pool: SqlitePool) {
+    check_delete(pool, None, Some(ErrorType::NotFound)).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_the_deletion(pool: SqlitePool) {
+    check_delete(pool, Some(Faker.fake()), None).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn association_is_deleted_with_its_budgeter(pool: SqlitePool) {
+    let budgeter: BudgeterConfig = Faker.fake();
+    let association = ExpenseAssociation {
+        budgeter_id: Some(budgeter.id),
+        ..Faker.fake()
+    };
+    let context = TestContext::setup(pool);
+    context.set_budgeters(std::slice::from_ref(&budgeter)).await;
+    context
+        .set_expenses_association(std::slice::from_ref(&association))
+        .await;
+
+    context.delete_budgeter(budgeter.id).await;
+
+    let saved = context.get_expense_association(association.id).await;
+    assert_eq!(saved, Err(DbError::NotFound));
+}
@@ -0,0 +1,39 @@
+use datamize_domain::{BudgeterConfig, ExpenseAssociation};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+
+use crate::services::budget_template::tests::expense_association::testutils::TestContext;
+
+async fn check_get_all(pool: SqlitePool, expected_resp: Vec<ExpenseAssociation>) {
+    let context = TestContext::setup(pool);
+
+    let budgeters: Vec<_> = expected_resp
+        .iter()
+        .filter_map(|a| a.budgeter_id)
+        .map(|id| BudgeterConfig { id, ..Faker.fake() })
+        .collect();
+    context.set_budgeters(&budgeters).await;
+    context.set_expenses_association(&expected_resp).await;
+
+    let mut response = context
+        .service()
+        .get_all_expenses_association()
+        .await
+        .unwrap();
+    response.sort_by_key(|a| a.id);
+    let mut expected_resp = expected_resp;
+    expected_resp.sort_by_key(|a| a.id);
+
+    assert_eq!(response, expected_resp);
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_empty_list_when_nothing_in_db(pool: SqlitePool) {
+    check_get_all(pool, vec![]).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_all_that_is_in_db(pool: SqlitePool) {
+    check_get_all(pool, fake::vec![ExpenseAssociation; 1..5]).await;
+}
@@ -0,0 +1,4 @@
+mod delete;
+mod get_all;
+mod save;
+pub(crate) mod testutils;
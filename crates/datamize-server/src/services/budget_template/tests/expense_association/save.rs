@@ -0,0 +1,124 @@
+use datamize_domain::{
+    BudgeterConfig, ExpenseAssociation, SaveExpenseAssociation, SplitStrategy, Uuid,
+};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+
+use crate::services::{
+    budget_template::tests::expense_association::testutils::TestContext,
+    testutils::{assert_err, ErrorType},
+};
+
+async fn check_save(
+    pool: SqlitePool,
+    id: Uuid,
+    body: SaveExpenseAssociation,
+    budgeters: &[BudgeterConfig],
+    expected_err: Option<ErrorType>,
+) {
+    let context = TestContext::setup(pool);
+    context.set_budgeters(budgeters).await;
+
+    let response = context
+        .service()
+        .save_expense_association(id, body.clone())
+        .await;
+
+    if let Some(expected_err) = expected_err {
+        assert_err(response.unwrap_err(), Some(expected_err));
+    } else {
+        let expected_resp = ExpenseAssociation::new(id, body);
+        assert_eq!(response.unwrap(), expected_resp);
+        // Make sure the association is persisted in db
+        let saved = context.get_expense_association(id).await.unwrap();
+        assert_eq!(saved, expected_resp);
+    }
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_not_found_when_budgeter_does_not_exist(pool: SqlitePool) {
+    let body = SaveExpenseAssociation {
+        budgeter_id: Some(Faker.fake()),
+        ..Faker.fake()
+    };
+
+    check_save(pool, Faker.fake(), body, &[], Some(ErrorType::NotFound)).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_a_shared_association(pool: SqlitePool) {
+    let body = SaveExpenseAssociation {
+        budgeter_id: None,
+        ..Faker.fake()
+    };
+
+    check_save(pool, Faker.fake(), body, &[], None).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_the_association(pool: SqlitePool) {
+    let budgeter: BudgeterConfig = Faker.fake();
+    let body = SaveExpenseAssociation {
+        budgeter_id: Some(budgeter.id),
+        ..Faker.fake()
+    };
+
+    check_save(pool, Faker.fake(), body, &[budgeter], None).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_a_split_strategy(pool: SqlitePool) {
+    let budgeter: BudgeterConfig = Faker.fake();
+    let body = SaveExpenseAssociation {
+        budgeter_id: None,
+        split_strategy: Some(SplitStrategy::Single {
+            budgeter_id: budgeter.id,
+        }),
+        ..Faker.fake()
+    };
+
+    check_save(pool, Faker.fake(), body, &[budgeter], None).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_invalid_split_strategy_when_budgeter_does_not_exist(pool: SqlitePool) {
+    let budgeter: BudgeterConfig = Faker.fake();
+    let body = SaveExpenseAssociation {
+        budgeter_id: None,
+        split_strategy: Some(SplitStrategy::Single {
+            budgeter_id: Faker.fake(),
+        }),
+        ..Faker.fake()
+    };
+
+    check_save(
+        pool,
+        Faker.fake(),
+        body,
+        &[budgeter],
+        Some(ErrorType::InvalidSplitStrategy),
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn replaces_the_previous_association(pool: SqlitePool) {
+    let budgeters = fake::vec![BudgeterConfig; 2];
+    let id: Uuid = Faker.fake();
+    let context = TestContext::setup(pool.clone());
+    context.set_budgeters(&budgeters).await;
+    context
+        .set_expenses_association(&[ExpenseAssociation {
+            id,
+            budgeter_id: Some(budgeters[0].id),
+            ..Faker.fake()
+        }])
+        .await;
+    let body = SaveExpenseAssociation {
+        budgeter_id: Some(budgeters[1].id),
+        ..Faker.fake()
+    };
+
+    check_save(pool, id, body, &budgeters, None).await;
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    db::{BudgeterConfigRepo, DbResult, ExpenseAssociationRepo},
+    BudgeterConfig, ExpenseAssociation, Uuid,
+};
+use db_sqlite::budget_template::{SqliteBudgeterConfigRepo, SqliteExpenseAssociationRepo};
+use sqlx::SqlitePool;
+
+use crate::services::budget_template::{
+    DynExpenseAssociationService, ExpenseAssociationService, ExpenseAssociationServiceExt,
+};
+
+pub(crate) struct TestContext {
+    expense_association_repo: Arc<SqliteExpenseAssociationRepo>,
+    budgeter_config_repo: Arc<SqliteBudgeterConfigRepo>,
+    expense_association_service: DynExpenseAssociationService,
+}
+
+impl TestContext {
+    pub(crate) fn setup(pool: SqlitePool) -> Self {
+        let expense_association_repo = SqliteExpenseAssociationRepo::new_arced(pool.clone());
+        let budgeter_config_repo = SqliteBudgeterConfigRepo::new_arced(pool.clone());
+
+        let expense_association_service = ExpenseAssociationService::new_arced(
+            expense_association_repo.clone(),
+            budgeter_config_repo.clone(),
+        );
+
+        Self {
+            expense_association_repo,
+            budgeter_config_repo,
+            expense_association_service,
+        }
+    }
+
+    pub(crate) fn service(&self) -> &dyn ExpenseAssociationServiceExt {
+        self.expense_association_service.as_ref()
+    }
+
+    pub(crate) async fn set_budgeters(&self, budgeters: &[BudgeterConfig]) {
+        for b in budgeters {
+            self.budgeter_config_repo.update(b).await.unwrap();
+        }
+    }
+
+    pub(crate) async fn delete_budgeter(&self, budgeter_id: Uuid) {
+        self.budgeter_config_repo.delete(budgeter_id).await.unwrap();
+    }
+
+    pub(crate) async fn set_expenses_association(
+        &self,
+        expenses_association: &[ExpenseAssociation],
+    ) {
+        for a in expenses_association {
+            self.expense_association_repo.update(a).await.unwrap();
+        }
+    }
+
+    pub(crate) async fn get_expense_association(&self, id: Uuid) -> DbResult<ExpenseAssociation> {
+        self.expense_association_repo.get(id).await
+    }
+}
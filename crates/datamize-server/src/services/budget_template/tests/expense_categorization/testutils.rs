@@ -1,10 +1,10 @@
 use std::sync::Arc;
 
 use datamize_domain::{
-    db::{DbResult, ExpenseCategorizationRepo},
-    ExpenseCategorization, Uuid,
+    db::{BudgeterConfigRepo, DbResult, ExpenseCategorizationRepo},
+    BudgeterConfig, ExpenseCategorization, Uuid,
 };
-use db_sqlite::budget_template::SqliteExpenseCategorizationRepo;
+use db_sqlite::budget_template::{SqliteBudgeterConfigRepo, SqliteExpenseCategorizationRepo};
 use sqlx::SqlitePool;
 
 use crate::services::budget_template::{
@@ -13,18 +13,23 @@ use crate::services::budget_template::{
 
 pub(crate) struct TestContext {
     expense_categorization_repo: Arc<SqliteExpenseCategorizationRepo>,
+    budgeter_config_repo: Arc<SqliteBudgeterConfigRepo>,
     expense_categorization_service: DynExpenseCategorizationService,
 }
 
 impl TestContext {
     pub(crate) fn setup(pool: SqlitePool) -> Self {
         let expense_categorization_repo = SqliteExpenseCategorizationRepo::new_arced(pool.clone());
+        let budgeter_config_repo = SqliteBudgeterConfigRepo::new_arced(pool.clone());
 
-        let expense_categorization_service =
-            ExpenseCategorizationService::new_arced(expense_categorization_repo.clone());
+        let expense_categorization_service = ExpenseCategorizationService::new_arced(
+            expense_categorization_repo.clone(),
+            budgeter_config_repo.clone(),
+        );
 
         Self {
             expense_categorization_repo,
+            budgeter_config_repo,
             expense_categorization_service,
         }
     }
@@ -37,6 +42,12 @@ impl TestContext {
         self.expense_categorization_service
     }
 
+    pub(crate) async fn set_budgeters(&self, budgeters: &[BudgeterConfig]) {
+        for b in budgeters {
+            self.budgeter_config_repo.update(b).await.unwrap();
+        }
+    }
+
     pub(crate) async fn set_expenses_categorization(
         &self,
         expenses_categorization: &[ExpenseCategorization],
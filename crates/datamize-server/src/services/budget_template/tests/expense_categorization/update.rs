@@ -1,4 +1,4 @@
-use datamize_domain::ExpenseCategorization;
+use datamize_domain::{BudgeterConfig, ExpenseCategorization, SplitStrategy};
 use fake::{Fake, Faker};
 use pretty_assertions::assert_eq;
 use sqlx::SqlitePool;
@@ -12,15 +12,18 @@ fn are_equal(a: &ExpenseCategorization, b: &ExpenseCategorization) {
     assert_eq!(a.name, b.name);
     assert_eq!(a.expense_type, b.expense_type);
     assert_eq!(a.sub_expense_type, b.sub_expense_type);
+    assert_eq!(a.split_strategy, b.split_strategy);
 }
 
 async fn check_update(
     pool: SqlitePool,
+    budgeters: &[BudgeterConfig],
     new_expense_categorization: ExpenseCategorization,
     expected_resp: Option<ExpenseCategorization>,
     expected_err: Option<ErrorType>,
 ) {
     let context = TestContext::setup(pool);
+    context.set_budgeters(budgeters).await;
 
     if let Some(expected_resp) = expected_resp.clone() {
         context.set_expenses_categorization(&[expected_resp]).await;
@@ -46,7 +49,7 @@ async fn check_update(
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
 async fn returns_error_not_found_when_nothing_in_db(pool: SqlitePool) {
-    check_update(pool, Faker.fake(), None, Some(ErrorType::NotFound)).await;
+    check_update(pool, &[], Faker.fake(), None, Some(ErrorType::NotFound)).await;
 }
 
 #[sqlx::test(migrations = "../db-sqlite/migrations")]
@@ -54,5 +57,65 @@ async fn returns_success_with_the_update(pool: SqlitePool) {
     let body: ExpenseCategorization = Faker.fake();
     let expected_resp = ExpenseCategorization { ..body.clone() };
 
-    check_update(pool, body, Some(expected_resp), None).await;
+    check_update(pool, &[], body, Some(expected_resp), None).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_success_with_the_split_strategy_update(pool: SqlitePool) {
+    let budgeters = fake::vec![BudgeterConfig; 2];
+    let body = ExpenseCategorization {
+        split_strategy: SplitStrategy::FixedPercentages {
+            percentages: [(budgeters[0].id, 40), (budgeters[1].id, 60)]
+                .into_iter()
+                .collect(),
+        },
+        ..Faker.fake()
+    };
+    let expected_resp = ExpenseCategorization { ..body.clone() };
+
+    check_update(pool, &budgeters, body, Some(expected_resp), None).await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_when_fixed_percentages_do_not_add_up_to_100(pool: SqlitePool) {
+    let budgeters = fake::vec![BudgeterConfig; 2];
+    let body = ExpenseCategorization {
+        split_strategy: SplitStrategy::FixedPercentages {
+            percentages: [(budgeters[0].id, 40), (budgeters[1].id, 40)]
+                .into_iter()
+                .collect(),
+        },
+        ..Faker.fake()
+    };
+
+    check_update(
+        pool,
+        &budgeters,
+        body,
+        None,
+        Some(ErrorType::InvalidSplitStrategy),
+    )
+    .await;
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_when_fixed_percentages_are_of_an_unknown_budgeter(pool: SqlitePool) {
+    let budgeters = fake::vec![BudgeterConfig; 1];
+    let body = ExpenseCategorization {
+        split_strategy: SplitStrategy::FixedPercentages {
+            percentages: [(budgeters[0].id, 40), (Faker.fake(), 60)]
+                .into_iter()
+                .collect(),
+        },
+        ..Faker.fake()
+    };
+
+    check_update(
+        pool,
+        &budgeters,
+        body,
+        None,
+        Some(ErrorType::InvalidSplitStrategy),
+    )
+    .await;
 }
@@ -1,5 +1,7 @@
 mod budgeter;
+mod expense_association;
 mod expense_categorization;
 mod template_detail;
+mod template_projection;
 mod template_summary;
 mod template_transaction;
@@ -0,0 +1,155 @@
+use chrono::NaiveDate;
+use datamize_domain::{
+    BudgeterConfig, BudgeterExt, DatamizeScheduledTransaction, ExpenseCategorization, MonthTarget,
+    SplitStrategy,
+};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use sqlx::SqlitePool;
+use ynab::{
+    CategoryGroupWithCategoriesDelta, Milliunits, ScheduledTransactionDetail,
+    ScheduledTransactionsDetailDelta,
+};
+
+use crate::services::{
+    budget_template::tests::template_projection::testutils::TestContext,
+    testutils::{assert_err, ErrorType},
+};
+
+fn month(y: i32, m: u32) -> MonthTarget {
+    MonthTarget::Month(NaiveDate::from_ymd_opt(y, m, 1).unwrap())
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn projects_next_12_months_by_default(pool: SqlitePool) {
+    let context = TestContext::setup(pool, Faker.fake(), Faker.fake()).await;
+
+    let projection = context
+        .into_service()
+        .get_template_projection(MonthTarget::Current, None, false)
+        .await
+        .unwrap();
+
+    let months: Vec<_> = projection.months().iter().map(|m| m.month()).collect();
+    assert_eq!(months.len(), 12);
+    assert_eq!(months[0], NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+    assert_eq!(months[11], NaiveDate::from_ymd_opt(2024, 12, 1).unwrap());
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn projects_given_range_of_months(pool: SqlitePool) {
+    let ynab_scheduled_transactions = ScheduledTransactionsDetailDelta {
+        scheduled_transactions: fake::vec![ScheduledTransactionDetail; 1..10],
+        ..Faker.fake()
+    };
+    let mut scheduled_transactions: Vec<DatamizeScheduledTransaction> = ynab_scheduled_transactions
+        .clone()
+        .scheduled_transactions
+        .into_iter()
+        .map(|st| st.into())
+        .collect();
+    if scheduled_transactions[0].payee_id.is_none() {
+        scheduled_transactions[0].payee_id = Some(Faker.fake());
+    }
+    let budgeter_with_salary = BudgeterConfig {
+        payee_ids: vec![scheduled_transactions[0].payee_id.unwrap()],
+        ..Faker.fake()
+    };
+
+    let context = TestContext::setup(pool, Faker.fake(), ynab_scheduled_transactions).await;
+    context.set_budgeters(&[budgeter_with_salary]).await;
+
+    let projection = context
+        .into_service()
+        .get_template_projection(month(2025, 11), Some(month(2026, 2)), false)
+        .await
+        .unwrap();
+
+    let months: Vec<_> = projection.months().iter().map(|m| m.month()).collect();
+    assert_eq!(
+        months,
+        vec![
+            NaiveDate::from_ymd_opt(2025, 11, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 12, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(),
+        ]
+    );
+    assert!(projection.months().iter().all(|m| m.budgeters().len() == 1));
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_when_range_is_reversed(pool: SqlitePool) {
+    let context = TestContext::setup(pool, Faker.fake(), Faker.fake()).await;
+
+    let response = context
+        .into_service()
+        .get_template_projection(month(2024, 5), Some(month(2024, 4)), false)
+        .await;
+
+    assert_err(
+        response.unwrap_err(),
+        Some(ErrorType::InvalidProjectionRange),
+    );
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_error_when_range_is_too_long(pool: SqlitePool) {
+    let context = TestContext::setup(pool, Faker.fake(), Faker.fake()).await;
+
+    let response = context
+        .into_service()
+        .get_template_projection(month(2024, 1), Some(month(2029, 1)), false)
+        .await;
+
+    assert_err(
+        response.unwrap_err(),
+        Some(ErrorType::InvalidProjectionRange),
+    );
+}
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn splits_expenses_with_the_saved_split_strategy(pool: SqlitePool) {
+    let ynab_categories: CategoryGroupWithCategoriesDelta = Faker.fake();
+    let budgeters = [Faker.fake::<BudgeterConfig>(), Faker.fake()];
+    let expenses_categorization: Vec<_> = ynab_categories
+        .category_groups
+        .iter()
+        .flat_map(|cg| &cg.categories)
+        .map(|c| ExpenseCategorization {
+            id: c.category_group_id,
+            split_strategy: SplitStrategy::Single {
+                budgeter_id: budgeters[1].id,
+            },
+            ..Faker.fake()
+        })
+        .collect();
+
+    let context = TestContext::setup(pool, ynab_categories, Faker.fake()).await;
+    context.set_budgeters(&budgeters).await;
+    context
+        .set_expenses_categorization(&expenses_categorization)
+        .await;
+
+    let projection = context
+        .into_service()
+        .get_template_projection(month(2024, 1), Some(month(2024, 2)), false)
+        .await
+        .unwrap();
+
+    for m in projection.months() {
+        let total_expenses: Milliunits = m.expenses().iter().map(|e| e.projected_amount()).sum();
+        let summary_budgeters = m.summary().budgeters();
+        let first = summary_budgeters
+            .iter()
+            .find(|b| b.id() == budgeters[0].id)
+            .unwrap();
+        let second = summary_budgeters
+            .iter()
+            .find(|b| b.id() == budgeters[1].id)
+            .unwrap();
+
+        assert_eq!(first.common_expenses(), Milliunits::ZERO);
+        assert_eq!(second.common_expenses(), total_expenses);
+    }
+}
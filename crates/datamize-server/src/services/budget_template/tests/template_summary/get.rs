@@ -1,10 +1,11 @@
 use datamize_domain::{
-    BudgeterConfig, DatamizeScheduledTransaction, ExpenseCategorization, MonthTarget,
+    AssociationType, BudgeterConfig, BudgeterExt, DatamizeScheduledTransaction, ExpenseAssociation,
+    ExpenseCategorization, ExpenseType, MonthTarget,
 };
 use fake::{Fake, Faker};
 use sqlx::SqlitePool;
 use ynab::{
-    Category, CategoryGroupWithCategories, CategoryGroupWithCategoriesDelta,
+    Category, CategoryGroupWithCategories, CategoryGroupWithCategoriesDelta, Milliunits,
     ScheduledTransactionDetail, ScheduledTransactionsDetailDelta,
 };
 
@@ -122,3 +123,61 @@ async fn returns_success_with_what_is_in_db(pool: SqlitePool) {
     )
     .await;
 }
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn individual_expenses_follow_the_saved_association(pool: SqlitePool) {
+    let budgeters_config = fake::vec![BudgeterConfig; 2];
+    // The name matches the first budgeter, but the association should win.
+    let category = Category {
+        name: format!("{} Gym", budgeters_config[0].name),
+        hidden: false,
+        deleted: false,
+        ..Faker.fake()
+    };
+    let ynab_categories = CategoryGroupWithCategoriesDelta {
+        category_groups: vec![CategoryGroupWithCategories {
+            categories: vec![category.clone()],
+            hidden: false,
+            deleted: false,
+            ..Faker.fake()
+        }],
+        ..Faker.fake()
+    };
+    let expenses_categorization = vec![ExpenseCategorization {
+        id: category.category_group_id,
+        expense_type: ExpenseType::Fixed,
+        ..Faker.fake()
+    }];
+    let expenses_association = vec![ExpenseAssociation {
+        id: category.id,
+        association_type: AssociationType::Category,
+        budgeter_id: Some(budgeters_config[1].id),
+        split_strategy: None,
+    }];
+
+    let context = TestContext::setup(pool, ynab_categories, Faker.fake()).await;
+    context.set_budgeters(&budgeters_config).await;
+    context
+        .set_expenses_categorization(&expenses_categorization)
+        .await;
+    context
+        .set_expenses_association(&expenses_association)
+        .await;
+
+    let summary = context
+        .into_service()
+        .get_template_summary(MonthTarget::default(), false)
+        .await
+        .unwrap();
+
+    let budgeter = |id| summary.budgeters().iter().find(|b| b.id() == id).unwrap();
+    assert_eq!(
+        budgeter(budgeters_config[0].id).individual_expenses(),
+        Milliunits::ZERO
+    );
+    assert_eq!(
+        budgeter(budgeters_config[1].id).individual_expenses(),
+        summary.total_budgeter().individual_expenses()
+    );
+    assert_eq!(summary.total_budgeter().common_expenses(), Milliunits::ZERO);
+}
@@ -4,9 +4,9 @@ use async_trait::async_trait;
 use datamize_domain::{
     db::{
         ynab::{YnabCategoryMetaRepo, YnabScheduledTransactionMetaRepo},
-        BudgeterConfigRepo, ExpenseCategorizationRepo,
+        BudgeterConfigRepo, ExpenseAssociationRepo, ExpenseCategorizationRepo,
     },
-    BudgeterConfig, ExpenseCategorization,
+    BudgeterConfig, ExpenseAssociation, ExpenseCategorization,
 };
 use db_redis::{
     budget_providers::ynab::{RedisYnabCategoryMetaRepo, RedisYnabScheduledTransactionMetaRepo},
@@ -14,7 +14,9 @@ use db_redis::{
 };
 use db_sqlite::{
     budget_providers::ynab::{SqliteYnabCategoryRepo, SqliteYnabScheduledTransactionRepo},
-    budget_template::{SqliteBudgeterConfigRepo, SqliteExpenseCategorizationRepo},
+    budget_template::{
+        SqliteBudgeterConfigRepo, SqliteExpenseAssociationRepo, SqliteExpenseCategorizationRepo,
+    },
 };
 use fake::{Fake, Faker};
 use sqlx::SqlitePool;
@@ -27,11 +29,13 @@ use ynab::{
 use crate::services::{
     budget_providers::{CategoryService, ScheduledTransactionService},
     budget_template::{DynTemplateSummaryService, TemplateSummaryService},
+    testutils::fixed_clock,
 };
 
 pub(crate) struct TestContext {
     budgeter_config_repo: Arc<SqliteBudgeterConfigRepo>,
     expense_categorization_repo: Arc<SqliteExpenseCategorizationRepo>,
+    expense_association_repo: Arc<SqliteExpenseAssociationRepo>,
     template_summary_service: DynTemplateSummaryService,
 }
 
@@ -43,8 +47,12 @@ impl TestContext {
     ) -> Self {
         let redis_conn_pool = get_test_pool().await;
         let budgeter_config_repo = SqliteBudgeterConfigRepo::new_arced(pool.clone());
-        let ynab_category_repo = SqliteYnabCategoryRepo::new_arced(pool.clone());
-        let ynab_category_meta_repo = RedisYnabCategoryMetaRepo::new_arced(redis_conn_pool.clone());
+        let ynab_category_repo =
+            SqliteYnabCategoryRepo::new_arced(pool.clone(), ynab::LAST_USED_BUDGET_ID);
+        let ynab_category_meta_repo = RedisYnabCategoryMetaRepo::new_arced(
+            redis_conn_pool.clone(),
+            ynab::LAST_USED_BUDGET_ID,
+        );
         ynab_category_meta_repo
             .set_delta(Faker.fake())
             .await
@@ -54,20 +62,24 @@ impl TestContext {
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_categories_delta()
-            .returning(move |_| Ok(ynab_categories.clone()));
+            .returning(move |_, _| Ok(ynab_categories.clone()));
         ynab_client_mock
             .expect_get_month_by_date()
-            .returning(|_| Ok(Faker.fake()));
+            .returning(|_, _| Ok(Faker.fake()));
         let category_service = CategoryService::new_arced(
             ynab_category_repo,
             ynab_category_meta_repo,
             expense_categorization_repo.clone(),
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
+            fixed_clock(),
         );
         let ynab_scheduled_transaction_repo =
-            SqliteYnabScheduledTransactionRepo::new_arced(pool.clone());
-        let ynab_scheduled_transaction_meta_repo =
-            RedisYnabScheduledTransactionMetaRepo::new_arced(redis_conn_pool);
+            SqliteYnabScheduledTransactionRepo::new_arced(pool.clone(), ynab::LAST_USED_BUDGET_ID);
+        let ynab_scheduled_transaction_meta_repo = RedisYnabScheduledTransactionMetaRepo::new_arced(
+            redis_conn_pool,
+            ynab::LAST_USED_BUDGET_ID,
+        );
         ynab_scheduled_transaction_meta_repo
             .set_delta(Faker.fake())
             .await
@@ -76,22 +88,27 @@ impl TestContext {
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_scheduled_transactions_delta()
-            .returning(move |_| Ok(ynab_scheduled_transactions.clone()));
+            .returning(move |_, _| Ok(ynab_scheduled_transactions.clone()));
         let scheduled_transaction_service = ScheduledTransactionService::new_arced(
             ynab_scheduled_transaction_repo,
             ynab_scheduled_transaction_meta_repo,
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
         );
 
+        let expense_association_repo = SqliteExpenseAssociationRepo::new_arced(pool.clone());
         let template_summary_service = TemplateSummaryService::new_arced(
             category_service,
             scheduled_transaction_service,
             budgeter_config_repo.clone(),
+            expense_association_repo.clone(),
+            fixed_clock(),
         );
 
         Self {
             budgeter_config_repo,
             expense_categorization_repo,
+            expense_association_repo,
             template_summary_service,
         }
     }
@@ -115,6 +132,15 @@ impl TestContext {
             .await
             .unwrap();
     }
+
+    pub(crate) async fn set_expenses_association(
+        &self,
+        expenses_association: &[ExpenseAssociation],
+    ) {
+        for a in expenses_association {
+            self.expense_association_repo.update(a).await.unwrap();
+        }
+    }
 }
 
 mockall::mock! {
@@ -126,25 +152,25 @@ mockall::mock! {
 
     #[async_trait]
     impl MonthRequests for MonthAndCategoriesRequestsImpl {
-        async fn get_months(&self) -> YnabResult<Vec<MonthSummary>>;
+        async fn get_months(&self, budget_id: &str) -> YnabResult<Vec<MonthSummary>>;
         async fn get_months_delta(
-            &self,
+            &self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<MonthSummaryDelta>;
-        async fn get_month_by_date(&self, date: &str) -> YnabResult<MonthDetail>;
+        async fn get_month_by_date(&self, budget_id: &str, date: &str) -> YnabResult<MonthDetail>;
     }
 
     #[async_trait]
     impl CategoryRequests for MonthAndCategoriesRequestsImpl {
-        async fn get_categories(&self) -> YnabResult<Vec<CategoryGroupWithCategories>>;
+        async fn get_categories(&self, budget_id: &str) -> YnabResult<Vec<CategoryGroupWithCategories>>;
         async fn get_categories_delta(
-            &self,
+            &self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<CategoryGroupWithCategoriesDelta>;
-        async fn get_category_by_id(&self, category_id: &str) -> YnabResult<Category>;
-        async fn get_category_by_id_for(&self, category_id: &str, month: &str) -> YnabResult<Category>;
+        async fn get_category_by_id(&self, budget_id: &str, category_id: &str) -> YnabResult<Category>;
+        async fn get_category_by_id_for(&self, budget_id: &str, category_id: &str, month: &str) -> YnabResult<Category>;
         async fn update_category_for(
-            &self,
+            &self, budget_id: &str,
             category_id: &str,
             month: &str,
             data: SaveMonthCategory,
@@ -1,8 +1,12 @@
+use chrono::NaiveDate;
+use datamize_domain::MonthTarget;
 use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
 use rand::seq::IteratorRandom;
 use sqlx::SqlitePool;
 use ynab::{
-    Category, ScheduledSubTransaction, ScheduledTransactionDetail, ScheduledTransactionsDetailDelta,
+    Category, RecurFrequency, ScheduledSubTransaction, ScheduledTransactionDetail,
+    ScheduledTransactionsDetailDelta,
 };
 
 use crate::services::budget_template::{
@@ -26,7 +30,10 @@ async fn check_get(
         context.set_categories(&categories).await;
     }
 
-    let response = context.into_service().get_template_transactions().await;
+    let response = context
+        .into_service()
+        .get_template_transactions(MonthTarget::Current)
+        .await;
 
     // We don't really care what's the answer, as long as it is able to parse it
     response.unwrap();
@@ -120,3 +127,31 @@ async fn calls_ynab_api_when_category_is_not_in_db(pool: SqlitePool) {
     )
     .await;
 }
+
+#[sqlx::test(migrations = "../db-sqlite/migrations")]
+async fn returns_transactions_occurring_in_given_month(pool: SqlitePool) {
+    let first_date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+    let ynab_scheduled_transactions = ScheduledTransactionsDetailDelta {
+        scheduled_transactions: vec![ScheduledTransactionDetail {
+            date_first: first_date,
+            date_next: first_date,
+            frequency: RecurFrequency::Monthly,
+            deleted: false,
+            subtransactions: vec![],
+            ..Faker.fake()
+        }],
+        ..Faker.fake()
+    };
+
+    let context = TestContext::setup(pool, ynab_scheduled_transactions, 0).await;
+
+    let month = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+    let response = context
+        .into_service()
+        .get_template_transactions(MonthTarget::Month(month))
+        .await
+        .unwrap();
+
+    let dates: Vec<_> = response.map().keys().copied().collect();
+    assert_eq!(dates, vec![NaiveDate::from_ymd_opt(2026, 6, 10).unwrap()]);
+}
@@ -14,6 +14,7 @@ use ynab::{
 
 use crate::services::{
     budget_providers::ScheduledTransactionService, budget_template::TemplateTransactionService,
+    testutils::fixed_clock,
 };
 
 pub(crate) struct TestContext {
@@ -28,11 +29,14 @@ impl TestContext {
         ynab_calls: usize,
     ) -> Self {
         let redis_conn_pool = get_test_pool().await;
-        let ynab_category_repo = SqliteYnabCategoryRepo::new_arced(pool.clone());
+        let ynab_category_repo =
+            SqliteYnabCategoryRepo::new_arced(pool.clone(), ynab::LAST_USED_BUDGET_ID);
         let ynab_scheduled_transaction_repo =
-            SqliteYnabScheduledTransactionRepo::new_arced(pool.clone());
-        let ynab_scheduled_transaction_meta_repo =
-            RedisYnabScheduledTransactionMetaRepo::new_arced(redis_conn_pool);
+            SqliteYnabScheduledTransactionRepo::new_arced(pool.clone(), ynab::LAST_USED_BUDGET_ID);
+        let ynab_scheduled_transaction_meta_repo = RedisYnabScheduledTransactionMetaRepo::new_arced(
+            redis_conn_pool,
+            ynab::LAST_USED_BUDGET_ID,
+        );
         ynab_scheduled_transaction_meta_repo
             .set_delta(Faker.fake())
             .await
@@ -41,11 +45,12 @@ impl TestContext {
         let ynab_client_mock = Arc::make_mut(&mut ynab_client);
         ynab_client_mock
             .expect_get_scheduled_transactions_delta()
-            .returning(move |_| Ok(ynab_scheduled_transactions.clone()));
+            .returning(move |_, _| Ok(ynab_scheduled_transactions.clone()));
         let scheduled_transaction_service = ScheduledTransactionService::new_arced(
             ynab_scheduled_transaction_repo,
             ynab_scheduled_transaction_meta_repo,
             ynab_client,
+            ynab::LAST_USED_BUDGET_ID,
         );
 
         let mut ynab_client = Arc::new(MockCategoryRequestsImpl::new());
@@ -53,12 +58,14 @@ impl TestContext {
         ynab_client_mock
             .expect_get_category_by_id()
             .times(ynab_calls)
-            .returning(move |_| Ok(Faker.fake()));
+            .returning(move |_, _| Ok(Faker.fake()));
 
         let template_transaction_service = TemplateTransactionService {
             scheduled_transaction_service,
             ynab_category_repo: ynab_category_repo.clone(),
             ynab_client,
+            budget_id: ynab::LAST_USED_BUDGET_ID.to_string(),
+            clock: fixed_clock(),
         };
 
         Self {
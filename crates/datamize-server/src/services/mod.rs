@@ -1,5 +1,7 @@
+pub mod backup;
 pub mod balance_sheet;
 pub mod budget_providers;
 pub mod budget_template;
+pub mod scheduler;
 #[cfg(test)]
 pub(crate) mod testutils;
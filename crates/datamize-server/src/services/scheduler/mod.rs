@@ -0,0 +1,169 @@
+#[cfg(test)]
+mod tests;
+
+use std::{sync::Arc, time::Instant};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Duration, Local, Utc};
+use datamize_domain::{
+    db::{DbError, DynSchedulerRunRepo},
+    ScheduledJob, SchedulerRun, Uuid,
+};
+use tokio::time::MissedTickBehavior;
+
+use crate::{
+    config::RefreshSchedule,
+    error::DatamizeResult,
+    services::balance_sheet::{DynRefreshFinResService, DynRolloverService},
+};
+
+/// How often the scheduler checks for the jobs that are due.
+pub const SCHEDULER_TICK: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// How long to wait before running a job that failed again.
+fn retry_delay() -> Duration {
+    Duration::hours(1)
+}
+
+#[async_trait]
+pub trait SchedulerServiceExt: Send + Sync {
+    /// The latest runs of all the jobs, the most recent first.
+    async fn get_runs(&self, limit: u32) -> DatamizeResult<Vec<SchedulerRun>>;
+    /// Runs the jobs due at `now` and records their outcome.
+    /// The rollover runs once a month, before the refresh so the new month is the one refreshed.
+    async fn run_due_jobs(&self, now: DateTime<Local>) -> DatamizeResult<Vec<SchedulerRun>>;
+}
+
+pub type DynSchedulerService = Arc<dyn SchedulerServiceExt>;
+
+pub struct SchedulerService {
+    pub rollover_service: DynRolloverService,
+    pub refresh_fin_res_service: DynRefreshFinResService,
+    pub scheduler_run_repo: DynSchedulerRunRepo,
+    pub refresh: RefreshSchedule,
+}
+
+impl SchedulerService {
+    pub fn new_arced(
+        rollover_service: DynRolloverService,
+        refresh_fin_res_service: DynRefreshFinResService,
+        scheduler_run_repo: DynSchedulerRunRepo,
+        refresh: RefreshSchedule,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            rollover_service,
+            refresh_fin_res_service,
+            scheduler_run_repo,
+            refresh,
+        })
+    }
+
+    async fn is_due(&self, job: ScheduledJob, now: DateTime<Local>) -> DatamizeResult<bool> {
+        if job == ScheduledJob::Refresh && self.refresh.interval().is_none() {
+            return Ok(false);
+        }
+
+        let last_run = match self.scheduler_run_repo.get_last(job).await {
+            Ok(last_run) => last_run,
+            Err(DbError::NotFound) => return Ok(true),
+            Err(e) => return Err(e.into()),
+        };
+        let last_started_at = last_run.started_at.with_timezone(&Local);
+        // A tick of slack keeps the jobs from starting a bit later every time.
+        let has_elapsed = |delay: Duration| now - last_started_at >= delay - tick();
+        if !last_run.succeeded {
+            return Ok(has_elapsed(retry_delay()));
+        }
+
+        Ok(match job {
+            ScheduledJob::Rollover => {
+                (last_started_at.year(), last_started_at.month()) != (now.year(), now.month())
+            }
+            ScheduledJob::Refresh => self.refresh.interval().is_some_and(has_elapsed),
+        })
+    }
+
+    async fn run(
+        &self,
+        job: ScheduledJob,
+        started_at: DateTime<Utc>,
+    ) -> DatamizeResult<SchedulerRun> {
+        let now = started_at.with_timezone(&Local);
+        let started = Instant::now();
+        let outcome = match job {
+            ScheduledJob::Rollover => self
+                .rollover_service
+                .rollover(now.year(), now.month().try_into().unwrap())
+                .await
+                .map(|rollover| rollover.to_string()),
+            ScheduledJob::Refresh => self
+                .refresh_fin_res_service
+                .refresh_fin_res(None)
+                .await
+                .map(|refreshed| format!("refreshed {} resource(s)", refreshed.len())),
+        };
+
+        let (succeeded, details) = match outcome {
+            Ok(details) => (true, details),
+            Err(e) => {
+                tracing::error!(error = ?e, %job, "scheduled job failed");
+                (false, format!("{:?}", e).trim_end().to_owned())
+            }
+        };
+        let run = SchedulerRun {
+            id: Uuid::new_v4(),
+            job,
+            started_at,
+            finished_at: started_at + elapsed(started),
+            succeeded,
+            details,
+        };
+        self.scheduler_run_repo.add(&run).await?;
+
+        Ok(run)
+    }
+}
+
+#[async_trait]
+impl SchedulerServiceExt for SchedulerService {
+    #[tracing::instrument(skip(self))]
+    async fn get_runs(&self, limit: u32) -> DatamizeResult<Vec<SchedulerRun>> {
+        Ok(self.scheduler_run_repo.get_latest(limit).await?)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn run_due_jobs(&self, now: DateTime<Local>) -> DatamizeResult<Vec<SchedulerRun>> {
+        let started = Instant::now();
+        let mut runs = vec![];
+        for job in [ScheduledJob::Rollover, ScheduledJob::Refresh] {
+            if self.is_due(job, now).await? {
+                // Jobs of the same tick start one after the other.
+                let started_at = now.with_timezone(&Utc) + elapsed(started);
+                runs.push(self.run(job, started_at).await?);
+            }
+        }
+
+        Ok(runs)
+    }
+}
+
+fn tick() -> Duration {
+    Duration::from_std(SCHEDULER_TICK).unwrap()
+}
+
+fn elapsed(since: Instant) -> Duration {
+    Duration::from_std(since.elapsed()).unwrap_or(Duration::zero())
+}
+
+/// Runs the jobs that are due at every tick, until the task is aborted.
+pub async fn run_scheduler(scheduler_service: DynSchedulerService) {
+    let mut interval = tokio::time::interval(SCHEDULER_TICK);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        if let Err(e) = scheduler_service.run_due_jobs(Local::now()).await {
+            tracing::error!(error = ?e, "failed to run the scheduled jobs");
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+    Arc,
+};
+
+use chrono::TimeZone;
+use datamize_domain::{db::DynRepoRegistry, MonthNum, ResourcesToRefresh};
+use db_memory::MemoryRepoRegistry;
+use pretty_assertions::assert_eq;
+
+use super::*;
+use crate::{
+    error::AppError,
+    services::balance_sheet::{RefreshFinResServiceExt, RolloverService},
+};
+
+#[derive(Default)]
+struct RefreshStub {
+    calls: AtomicUsize,
+    fails: AtomicBool,
+}
+
+#[async_trait]
+impl RefreshFinResServiceExt for RefreshStub {
+    async fn refresh_fin_res(
+        &self,
+        _resources_to_refresh: Option<ResourcesToRefresh>,
+    ) -> DatamizeResult<Vec<Uuid>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        match self.fails.load(Ordering::SeqCst) {
+            true => Err(AppError::ResourceNotFound),
+            false => Ok(vec![Uuid::new_v4()]),
+        }
+    }
+
+    async fn backfill_fin_res(
+        &self,
+        _year: i32,
+        _month: MonthNum,
+        _resources_to_refresh: Option<ResourcesToRefresh>,
+    ) -> DatamizeResult<Vec<Uuid>> {
+        Ok(vec![])
+    }
+}
+
+fn service(
+    repos: &DynRepoRegistry,
+    refresh_stub: Arc<RefreshStub>,
+    refresh: RefreshSchedule,
+) -> SchedulerService {
+    SchedulerService {
+        rollover_service: RolloverService::new_arced(
+            repos.fin_res_repo(),
+            repos.month_repo(),
+            repos.year_repo(),
+        ),
+        refresh_fin_res_service: refresh_stub,
+        scheduler_run_repo: repos.scheduler_run_repo(),
+        refresh,
+    }
+}
+
+fn at(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Local> {
+    Local
+        .with_ymd_and_hms(year, month, day, hour, 0, 0)
+        .single()
+        .unwrap()
+}
+
+fn jobs(runs: &[SchedulerRun]) -> Vec<ScheduledJob> {
+    runs.iter().map(|r| r.job).collect()
+}
+
+#[tokio::test]
+async fn first_run_rolls_over_then_refreshes() {
+    let repos: DynRepoRegistry = MemoryRepoRegistry::new_arced();
+    let refresh_stub = Arc::new(RefreshStub::default());
+    let service = service(&repos, refresh_stub.clone(), RefreshSchedule::Daily);
+
+    let runs = service.run_due_jobs(at(2024, 3, 1, 2)).await.unwrap();
+
+    assert_eq!(
+        jobs(&runs),
+        vec![ScheduledJob::Rollover, ScheduledJob::Refresh]
+    );
+    assert!(runs.iter().all(|r| r.succeeded));
+    assert_eq!(runs[0].details, "created year 2024, created March 2024");
+    assert_eq!(runs[1].details, "refreshed 1 resource(s)");
+    assert_eq!(refresh_stub.calls.load(Ordering::SeqCst), 1);
+    repos
+        .month_repo()
+        .get_month_data_by_number(MonthNum::March, 2024)
+        .await
+        .unwrap();
+
+    let mut saved = service.get_runs(50).await.unwrap();
+    saved.reverse();
+    assert_eq!(saved, runs);
+}
+
+#[tokio::test]
+async fn jobs_only_run_again_when_due() {
+    let repos: DynRepoRegistry = MemoryRepoRegistry::new_arced();
+    let refresh_stub = Arc::new(RefreshStub::default());
+    let service = service(&repos, refresh_stub.clone(), RefreshSchedule::Daily);
+    service.run_due_jobs(at(2024, 3, 1, 2)).await.unwrap();
+
+    let runs = service.run_due_jobs(at(2024, 3, 1, 20)).await.unwrap();
+    assert!(runs.is_empty());
+
+    let runs = service.run_due_jobs(at(2024, 3, 2, 2)).await.unwrap();
+    assert_eq!(jobs(&runs), vec![ScheduledJob::Refresh]);
+
+    let runs = service.run_due_jobs(at(2024, 3, 31, 1)).await.unwrap();
+    assert_eq!(jobs(&runs), vec![ScheduledJob::Refresh]);
+
+    let runs = service.run_due_jobs(at(2024, 4, 1, 0)).await.unwrap();
+    assert_eq!(jobs(&runs), vec![ScheduledJob::Rollover]);
+    assert_eq!(refresh_stub.calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn failed_jobs_are_retried_later() {
+    let repos: DynRepoRegistry = MemoryRepoRegistry::new_arced();
+    let refresh_stub = Arc::new(RefreshStub::default());
+    refresh_stub.fails.store(true, Ordering::SeqCst);
+    let service = service(&repos, refresh_stub.clone(), RefreshSchedule::Weekly);
+
+    let runs = service.run_due_jobs(at(2024, 3, 1, 2)).await.unwrap();
+    assert_eq!(
+        jobs(&runs),
+        vec![ScheduledJob::Rollover, ScheduledJob::Refresh]
+    );
+    assert!(!runs[1].succeeded);
+    assert_eq!(runs[1].details, "Resource does not exist");
+
+    let runs = service.run_due_jobs(at(2024, 3, 1, 2)).await.unwrap();
+    assert!(runs.is_empty());
+
+    refresh_stub.fails.store(false, Ordering::SeqCst);
+    let runs = service.run_due_jobs(at(2024, 3, 1, 3)).await.unwrap();
+    assert_eq!(jobs(&runs), vec![ScheduledJob::Refresh]);
+    assert!(runs[0].succeeded);
+}
+
+#[tokio::test]
+async fn refresh_never_runs_when_disabled() {
+    let repos: DynRepoRegistry = MemoryRepoRegistry::new_arced();
+    let refresh_stub = Arc::new(RefreshStub::default());
+    let service = service(&repos, refresh_stub.clone(), RefreshSchedule::Never);
+
+    let runs = service.run_due_jobs(at(2024, 3, 1, 2)).await.unwrap();
+
+    assert_eq!(jobs(&runs), vec![ScheduledJob::Rollover]);
+    assert_eq!(refresh_stub.calls.load(Ordering::SeqCst), 0);
+}
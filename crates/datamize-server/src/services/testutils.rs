@@ -1,4 +1,5 @@
-use datamize_domain::db::DbError;
+use chrono::NaiveDate;
+use datamize_domain::{db::DbError, DynClock, FixedClock};
 
 use crate::error::AppError;
 
@@ -8,7 +9,11 @@ pub(crate) enum ErrorType {
     Database,
     // Config,
     ChronoParse,
-    // Ynab,
+    Ynab,
+    InvalidProjectionRange,
+    InvalidSplitStrategy,
+    InvalidSavingRateTarget,
+    AmountOverflow,
 }
 
 pub(crate) fn assert_err(err: AppError, expected_err: Option<ErrorType>) {
@@ -26,7 +31,26 @@ pub(crate) fn assert_err(err: AppError, expected_err: Option<ErrorType>) {
         Some(ErrorType::Database) => assert!(matches!(err, AppError::DbError(_))),
         // Some(ErrorType::Config) => assert!(matches!(err, AppError::ConfigError(_))),
         Some(ErrorType::ChronoParse) => assert!(matches!(err, AppError::ParseError(_))),
-        // Some(ErrorType::Ynab) => assert!(matches!(err, AppError::YnabError(_))),
+        Some(ErrorType::Ynab) => assert!(matches!(err, AppError::YnabError(_))),
+        Some(ErrorType::InvalidProjectionRange) => {
+            assert!(matches!(err, AppError::InvalidProjectionRange))
+        }
+        Some(ErrorType::InvalidSplitStrategy) => {
+            assert!(matches!(err, AppError::InvalidSplitStrategy))
+        }
+        Some(ErrorType::InvalidSavingRateTarget) => {
+            assert!(matches!(err, AppError::InvalidSavingRateTarget))
+        }
+        Some(ErrorType::AmountOverflow) => assert!(matches!(err, AppError::AmountOverflow)),
         None => unreachable!(),
     }
 }
+
+/// Date the services' clock is stuck at in tests.
+pub(crate) fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()
+}
+
+pub(crate) fn fixed_clock() -> DynClock {
+    FixedClock::new_arced(today())
+}
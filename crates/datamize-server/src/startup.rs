@@ -1,45 +1,177 @@
-use std::sync::Arc;
+use std::{collections::HashMap, path::Path, sync::Arc};
 
 use anyhow::{Context, Ok, Result};
 use axum::{body::Body, routing::get, Router};
 use http::{header::CONTENT_TYPE, Request};
-use sqlx::PgPool;
 use tokio::{net::TcpListener, signal};
 use tower_http::{cors::CorsLayer, services::ServeDir, trace::TraceLayer};
 use tower_request_id::{RequestId, RequestIdLayer};
 use tracing::error_span;
 
 use crate::{
-    config::Settings,
-    routes::{get_api_routes, get_ui_routes, health_check},
+    config::{
+        SchedulerSettings, Settings, StatementSettings, YnabBudgetSettings, DEFAULT_YNAB_MEMBER,
+    },
+    registry::DynRepoRegistry,
+    routes::{get_api_routes, get_auth_routes, get_ui_routes, health_check},
+    services::{
+        balance_sheet::{RefreshFinResService, RolloverService},
+        budget_providers::{
+            EncryptedYnabOAuthTokenStore, ExternalAccountService, PendingChallenges,
+            ScraperRegistry, YnabConnection, YnabOAuthService, YnabOAuthServiceExt,
+        },
+        scheduler::{run_scheduler, DynSchedulerService, SchedulerService},
+    },
 };
 
 #[derive(Clone)]
 pub struct AppState {
+    /// The client of the `DEFAULT_YNAB_MEMBER`, or using the personal access token.
     pub ynab_client: Arc<ynab::Client>,
-    pub db_conn_pool: PgPool,
-    pub redis_conn_pool: db_redis::RedisPool,
+    /// The clients of the household members connected through OAuth, by member.
+    pub ynab_member_clients: HashMap<String, Arc<ynab::Client>>,
+    pub repos: DynRepoRegistry,
+    pub ynab_budgets: Vec<YnabBudgetSettings>,
+    pub scheduler_settings: SchedulerSettings,
+    pub statement_settings: StatementSettings,
+    /// Shared by the refreshes and the API, so the codes submitted reach the waiting refresh.
+    pub pending_challenges: PendingChallenges,
+    pub scraper_registry: Arc<ScraperRegistry>,
+    pub webdriver_location: String,
+}
+
+impl AppState {
+    /// The budget used when none is specified in the request, i.e. the first one configured,
+    /// otherwise YNAB's last used budget.
+    pub fn default_budget_id(&self) -> &str {
+        self.ynab_budgets
+            .first()
+            .map(|b| b.id.as_str())
+            .unwrap_or(ynab::LAST_USED_BUDGET_ID)
+    }
+
+    /// The client connected to the YNAB account holding the budget.
+    pub fn ynab_client_of(&self, budget_id: &str) -> Arc<ynab::Client> {
+        self.ynab_budgets
+            .iter()
+            .find(|b| b.id == budget_id)
+            .and_then(|b| b.member.as_ref())
+            .and_then(|member| self.ynab_member_clients.get(member))
+            .unwrap_or(&self.ynab_client)
+            .clone()
+    }
+
+    pub fn scheduler_service(&self) -> DynSchedulerService {
+        let fin_res_repo = self.repos.fin_res_repo();
+        let month_repo = self.repos.month_repo();
+        let year_repo = self.repos.year_repo();
+        let rollover_service =
+            RolloverService::new_arced(fin_res_repo.clone(), month_repo.clone(), year_repo.clone());
+        let external_account_service = ExternalAccountService::new_arced(
+            self.repos.external_account_repo(),
+            self.repos.encryption_key_repo(),
+            self.repos.external_account_refresh_repo(),
+            self.repos.external_account_balance_repo(),
+            self.pending_challenges.clone(),
+            self.scraper_registry.clone(),
+            self.webdriver_location.clone(),
+        );
+        let refresh_fin_res_service = RefreshFinResService::new_arced(
+            fin_res_repo,
+            month_repo,
+            year_repo,
+            rollover_service.clone(),
+            external_account_service,
+            self.ynab_client_of(self.default_budget_id()),
+            self.default_budget_id(),
+        );
+
+        SchedulerService::new_arced(
+            rollover_service,
+            refresh_fin_res_service,
+            self.repos.scheduler_run_repo(),
+            self.scheduler_settings.refresh,
+        )
+    }
 }
 
 pub struct Application {
     listener: TcpListener,
     port: u16,
     app: Router,
+    scheduler_service: Option<DynSchedulerService>,
 }
 
 impl Application {
-    pub async fn build(configuration: Settings, db_conn_pool: PgPool) -> Result<Self> {
-        let redis_conn_pool =
-            db_redis::get_connection_pool(&configuration.redis.connection_string())
-                .await
-                .context("failed to get redis connection pool")?;
-        let ynab_client = Arc::new(configuration.ynab_client.client());
+    pub async fn build(configuration: Settings, repos: DynRepoRegistry) -> Result<Self> {
+        let ynab_budgets = configuration.ynab_client.budgets.clone();
+        let ynab_oauth_service = configuration.ynab_client.oauth.is_some().then(|| {
+            let connections = configuration
+                .ynab_client
+                .members()
+                .into_iter()
+                .map(|member| {
+                    let token_store = EncryptedYnabOAuthTokenStore::new_arced(
+                        repos.ynab_oauth_token_repo(),
+                        repos.encryption_key_repo(),
+                        repos.external_account_repo(),
+                        &member,
+                    );
+                    let ynab_client = Arc::new(
+                        configuration
+                            .ynab_client
+                            .clone()
+                            .client()
+                            .with_oauth_token_store(token_store.clone()),
+                    );
+                    (
+                        member,
+                        YnabConnection {
+                            ynab_client,
+                            token_store,
+                        },
+                    )
+                })
+                .collect();
+            YnabOAuthService::new_arced(connections)
+        });
+        if let Some(ynab_oauth_service) = &ynab_oauth_service {
+            for member in ynab_oauth_service.restore_tokens().await? {
+                tracing::warn!(
+                    "YNAB account of {member} not connected yet, login through /auth/ynab/login?member={member}"
+                );
+            }
+        }
+        let ynab_member_clients = ynab_oauth_service
+            .as_ref()
+            .map(|s| s.clients())
+            .unwrap_or_default();
+        let ynab_client = ynab_member_clients
+            .get(DEFAULT_YNAB_MEMBER)
+            .cloned()
+            .unwrap_or_else(|| Arc::new(configuration.ynab_client.clone().client()));
 
         let app_state = AppState {
             ynab_client,
-            db_conn_pool,
-            redis_conn_pool,
+            ynab_member_clients,
+            repos,
+            ynab_budgets,
+            scheduler_settings: configuration.scheduler.clone(),
+            statement_settings: configuration.statements.clone(),
+            pending_challenges: PendingChallenges::default(),
+            scraper_registry: Arc::new(ScraperRegistry::load(
+                configuration
+                    .webdriver
+                    .recipes_directory
+                    .as_deref()
+                    .map(Path::new),
+            )),
+            webdriver_location: configuration.webdriver.connection_string(),
         };
+        let scheduler_service = configuration
+            .scheduler
+            .enabled
+            .then(|| app_state.scheduler_service());
 
         let address = format!(
             "{}:{}",
@@ -75,11 +207,16 @@ impl Application {
         };
         let assets_directory = base_path.join("assets");
 
-        let app = Router::new()
+        let mut app = Router::new()
             .nest("/", ui_routes)
             .nest_service("/assets", ServeDir::new(assets_directory.to_str().unwrap()))
             .route("/health_check", get(health_check))
-            .nest("/api", api_routes)
+            .nest("/api", api_routes);
+        if let Some(ynab_oauth_service) = ynab_oauth_service {
+            app = app.nest("/auth", get_auth_routes(ynab_oauth_service));
+        }
+
+        let app = app
             .layer(
                 CorsLayer::new()
                     .allow_origin(origins)
@@ -117,6 +254,7 @@ impl Application {
             listener,
             port,
             app,
+            scheduler_service,
         })
     }
 
@@ -126,11 +264,18 @@ impl Application {
 
     pub async fn run(self) -> Result<()> {
         tracing::debug!("listening on {}", self.listener.local_addr()?);
+        let scheduler = self
+            .scheduler_service
+            .map(|s| tokio::spawn(run_scheduler(s)));
         // run it with hyper
-        axum::serve(self.listener, self.app.into_make_service())
+        let served = axum::serve(self.listener, self.app.into_make_service())
             .with_graceful_shutdown(Application::shutdown_signal())
             .await
-            .context("failed to start hyper server")?;
+            .context("failed to start hyper server");
+        if let Some(scheduler) = scheduler {
+            scheduler.abort();
+        }
+        served?;
         Ok(())
     }
 
@@ -22,32 +22,33 @@ struct CreateMonth {
 }
 
 #[sqlx::test(migrations = "../db-postgres/migrations")]
-async fn refresh_resources_returns_a_404_if_curent_year_does_not_exist(pool: PgPool) {
-    // Arange
-    let app = spawn_app(pool).await;
-
-    // Act
-    let response = app.refresh_resources().await;
-
-    // Assert
-    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
-}
-
-#[sqlx::test(migrations = "../db-postgres/migrations")]
-async fn refresh_resources_should_not_call_ynab_server_if_year_does_not_exist(pool: PgPool) {
+async fn refresh_resources_returns_a_200_and_create_year_in_db_if_curent_year_does_not_exist(
+    pool: PgPool,
+) {
     // Arange
     let app = spawn_app(pool).await;
-    Mock::given(any())
-        .respond_with(ResponseTemplate::new(200))
-        .expect(0)
+    Mock::given(path_regex("/accounts"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(BodyResp {
+            data: AccountsResp {
+                accounts: vec![],
+                server_knowledge: 0,
+            },
+        }))
+        .expect(1)
         .mount(&app.ynab_server)
         .await;
 
     // Act
-    app.refresh_resources().await;
+    let response = app.refresh_resources().await;
 
     // Assert
-    // Mock verifies on Drop that we haven't sent the request to ynab
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let current_date = Local::now().date_naive();
+    let saved = app
+        .get_month(current_date.year(), current_date.month())
+        .await;
+    let value: Month = serde_json::from_str(&saved.text().await.unwrap()).unwrap();
+    assert_eq!(value.year, current_date.year());
 }
 
 #[sqlx::test(migrations = "../db-postgres/migrations")]
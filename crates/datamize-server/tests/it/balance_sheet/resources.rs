@@ -7,6 +7,7 @@ use fake::{Dummy, Fake, Faker};
 use pretty_assertions::assert_eq;
 use serde::Serialize;
 use sqlx::PgPool;
+use ynab::Milliunits;
 
 use crate::helpers::spawn_app;
 
@@ -238,7 +239,7 @@ async fn update_changes_existing_month_of_resource(pool: PgPool) {
     // Arange
     let app = spawn_app(pool).await;
     let mut month_balances = BTreeMap::new();
-    let new_balance = (-1000000..1000000).fake();
+    let new_balance: i64 = (-1000000..1000000).fake();
     month_balances.insert(MonthNum::January, Some(new_balance));
     let mut balances = BTreeMap::new();
     balances.insert(2022, month_balances);
@@ -260,9 +261,12 @@ async fn update_changes_existing_month_of_resource(pool: PgPool) {
         serde_json::from_str(&response.text().await.unwrap()).unwrap();
     assert_eq!(
         value.get_balance(2022, MonthNum::January),
-        Some(new_balance)
+        Some(Milliunits::new(new_balance))
+    );
+    assert_eq!(
+        value.get_balance(2022, MonthNum::February),
+        Some(Milliunits::new(494498))
     );
-    assert_eq!(value.get_balance(2022, MonthNum::February), Some(494498));
 }
 
 #[sqlx::test(
@@ -293,7 +297,10 @@ async fn update_removes_balance_of_existing_month_of_resource(pool: PgPool) {
     let value: FinancialResourceYearly =
         serde_json::from_str(&response.text().await.unwrap()).unwrap();
     assert_eq!(value.get_balance(2022, MonthNum::January), None);
-    assert_eq!(value.get_balance(2022, MonthNum::February), Some(494498));
+    assert_eq!(
+        value.get_balance(2022, MonthNum::February),
+        Some(Milliunits::new(494498))
+    );
 }
 
 #[sqlx::test(
@@ -304,7 +311,7 @@ async fn update_adds_new_month_of_resource(pool: PgPool) {
     // Arange
     let app = spawn_app(pool).await;
     let mut month_balances = BTreeMap::new();
-    let new_balance = (-1000000..1000000).fake();
+    let new_balance: i64 = (-1000000..1000000).fake();
     month_balances.insert(MonthNum::June, Some(new_balance));
     let mut balances = BTreeMap::new();
     balances.insert(2022, month_balances);
@@ -324,8 +331,14 @@ async fn update_adds_new_month_of_resource(pool: PgPool) {
     assert_eq!(response.status(), reqwest::StatusCode::OK);
     let value: FinancialResourceYearly =
         serde_json::from_str(&response.text().await.unwrap()).unwrap();
-    assert_eq!(value.get_balance(2022, MonthNum::June), Some(new_balance));
-    assert_eq!(value.get_balance(2022, MonthNum::February), Some(494498));
+    assert_eq!(
+        value.get_balance(2022, MonthNum::June),
+        Some(Milliunits::new(new_balance))
+    );
+    assert_eq!(
+        value.get_balance(2022, MonthNum::February),
+        Some(Milliunits::new(494498))
+    );
 }
 
 #[sqlx::test(
@@ -336,7 +349,7 @@ async fn update_does_not_change_year_net_totals_of_previous_year(pool: PgPool) {
     // Arange
     let app = spawn_app(pool).await;
     let mut month_balances = BTreeMap::new();
-    let new_balance = (-1000000..1000000).fake();
+    let new_balance: i64 = (-1000000..1000000).fake();
     month_balances.insert(MonthNum::November, Some(new_balance));
     let mut balances = BTreeMap::new();
     balances.insert(2023, month_balances);
@@ -372,7 +385,7 @@ async fn update_does_not_put_same_net_totals_to_all_years(pool: PgPool) {
     // Arange
     let app = spawn_app(pool).await;
     let mut month_balances = BTreeMap::new();
-    let new_balance = (-1000000..1000000).fake();
+    let new_balance: i64 = (-1000000..1000000).fake();
     month_balances.insert(MonthNum::January, Some(new_balance));
     let mut balances = BTreeMap::new();
     balances.insert(2022, month_balances);
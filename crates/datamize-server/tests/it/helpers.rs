@@ -3,7 +3,7 @@ use datamize_server::{
     startup::Application,
     telemetry::{get_subscriber, init_subscriber},
 };
-use db_redis::{get_connection_pool, RedisPool};
+use db_postgres::PostgresRepoRegistry;
 use once_cell::sync::Lazy;
 use sqlx::PgPool;
 use wiremock::MockServer;
@@ -25,7 +25,6 @@ pub struct TestApp {
     pub address: String,
     pub port: u16,
     pub db_pool: PgPool,
-    pub redis_pool: RedisPool,
     pub api_client: reqwest::Client,
     pub ynab_server: MockServer,
     pub ynab_client: ynab::Client,
@@ -47,8 +46,10 @@ pub async fn spawn_app(db_pool: PgPool) -> TestApp {
         c
     };
 
+    let repos = PostgresRepoRegistry::new_arced(db_pool.clone());
+
     // Launch the application as a background task
-    let application = Application::build(configuration.clone(), db_pool.clone())
+    let application = Application::build(configuration.clone(), repos)
         .await
         .expect("Failed to build application.");
     let application_port = application.port();
@@ -67,9 +68,6 @@ pub async fn spawn_app(db_pool: PgPool) -> TestApp {
         address: format!("http://localhost:{}", application_port),
         port: application_port,
         db_pool,
-        redis_pool: get_connection_pool(&configuration.redis.connection_string())
-            .await
-            .expect("Failed to start connection to redis."),
         api_client: client,
         ynab_server,
         ynab_client: configuration.ynab_client.client(),
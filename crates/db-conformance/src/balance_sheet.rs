@@ -0,0 +1,620 @@
+use std::collections::BTreeMap;
+
+use chrono::{TimeZone, Utc};
+use datamize_domain::{
+    db::{DbError, DynRepoRegistry},
+    AssetType, FinancialResourceType, FinancialResourceYearly, Incomes, LiabilityType, Month,
+    MonthNum, ResourceCategory, SavingRate, Savings, Uuid, Year, YearlyBalances,
+};
+use pretty_assertions::assert_eq;
+use ynab::Milliunits;
+
+/// Timestamps are truncated by some databases, so whole seconds are used.
+fn year(year: i32) -> Year {
+    Year {
+        refreshed_at: Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap(),
+        ..Year::new(year)
+    }
+}
+
+fn resource(name: &str, resource_type: FinancialResourceType) -> FinancialResourceYearly {
+    FinancialResourceYearly::new(Uuid::new_v4(), name.to_string(), resource_type, None, None)
+}
+
+fn cash() -> FinancialResourceType {
+    FinancialResourceType::Asset(AssetType::Cash)
+}
+
+fn saving_rate(name: &str, year: i32) -> SavingRate {
+    SavingRate {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        year,
+        savings: Savings {
+            category_ids: vec![Uuid::new_v4()],
+            extra_balance: Milliunits::new(1000),
+            total: Milliunits::ZERO,
+        },
+        employer_contribution: Milliunits::new(2000),
+        employee_contribution: Milliunits::new(3000),
+        mortgage_capital: Milliunits::new(4000),
+        incomes: Incomes {
+            payee_ids: vec![Uuid::new_v4(), Uuid::new_v4()],
+            extra_balance: Milliunits::new(5000),
+            total: Milliunits::ZERO,
+        },
+        rate: 0.0,
+        target: Some(0.2),
+        months: vec![],
+    }
+}
+
+async fn add_month(repos: &DynRepoRegistry, month: MonthNum, year: i32) -> Month {
+    let month = Month::new(month, year);
+    repos.month_repo().add(&month, year).await.unwrap();
+    month
+}
+
+pub async fn years_are_unique(repos: DynRepoRegistry) {
+    let year_repo = repos.year_repo();
+    let first = year(2024);
+    year_repo.add(&first).await.unwrap();
+
+    assert_eq!(
+        year_repo.add(&year(2024)).await,
+        Err(DbError::AlreadyExists)
+    );
+    assert_eq!(
+        year_repo.get_year_data_by_number(2024).await.unwrap().id,
+        first.id
+    );
+    assert_eq!(
+        year_repo.get_year_data_by_number(2023).await.unwrap_err(),
+        DbError::NotFound
+    );
+    assert_eq!(year_repo.get(2023).await, Err(DbError::NotFound));
+    assert_eq!(
+        year_repo.get_without_resources(2023).await,
+        Err(DbError::NotFound)
+    );
+}
+
+pub async fn years_are_ordered(repos: DynRepoRegistry) {
+    let year_repo = repos.year_repo();
+    for y in [2025, 2023, 2024] {
+        year_repo.add(&year(y)).await.unwrap();
+    }
+    add_month(&repos, MonthNum::January, 2024).await;
+    add_month(&repos, MonthNum::February, 2024).await;
+    let mut res = resource("Bank", cash());
+    res.insert_balance(2024, MonthNum::February, Milliunits::new(1000));
+    repos.fin_res_repo().update(&res).await.unwrap();
+
+    let years = year_repo.get_years().await.unwrap();
+    assert_eq!(
+        years.iter().map(|y| y.year).collect::<Vec<_>>(),
+        vec![2023, 2024, 2025]
+    );
+    // Only the months with resources.
+    assert_eq!(
+        years[1].months.iter().map(|m| m.month).collect::<Vec<_>>(),
+        vec![MonthNum::February]
+    );
+
+    let years = year_repo.get_years_starting_from(2024).await.unwrap();
+    assert_eq!(
+        years.iter().map(|y| y.year).collect::<Vec<_>>(),
+        vec![2024, 2025]
+    );
+    assert_eq!(
+        years[0].months.iter().map(|m| m.month).collect::<Vec<_>>(),
+        vec![MonthNum::January, MonthNum::February]
+    );
+    assert!(years[0].months.iter().all(|m| m.resources.is_empty()));
+
+    let mut years_data = year_repo.get_years_data().await.unwrap();
+    years_data.sort_by_key(|y| y.year);
+    assert_eq!(
+        years_data.iter().map(|y| y.year).collect::<Vec<_>>(),
+        vec![2023, 2024, 2025]
+    );
+}
+
+pub async fn year_net_totals_keep_their_ids(repos: DynRepoRegistry) {
+    let year_repo = repos.year_repo();
+    let mut y = year(2024);
+    y.net_totals.assets.total = Milliunits::new(1000);
+    year_repo.add(&y).await.unwrap();
+
+    assert_eq!(year_repo.get_net_totals(y.id).await.unwrap(), y.net_totals);
+
+    y.net_totals.portfolio.total = Milliunits::new(2000);
+    year_repo
+        .insert_net_totals(y.id, &y.net_totals)
+        .await
+        .unwrap();
+    assert_eq!(year_repo.get(2024).await.unwrap().net_totals, y.net_totals);
+
+    let other = year(2025);
+    assert_eq!(
+        year_repo.insert_net_totals(y.id, &other.net_totals).await,
+        Err(DbError::AlreadyExists)
+    );
+    assert_eq!(
+        year_repo
+            .insert_net_totals(other.id, &other.net_totals)
+            .await,
+        Err(DbError::NotFound)
+    );
+}
+
+pub async fn refreshed_at_is_updated(repos: DynRepoRegistry) {
+    let year_repo = repos.year_repo();
+    let y = year(2024);
+    year_repo.add(&y).await.unwrap();
+
+    let mut year_data = year_repo.get_year_data_by_number(2024).await.unwrap();
+    year_data.refreshed_at = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+    year_repo.update_refreshed_at(&year_data).await.unwrap();
+
+    let updated = year_repo.get_year_data_by_number(2024).await.unwrap();
+    assert_eq!(updated.id, y.id);
+    assert_eq!(updated.refreshed_at, year_data.refreshed_at);
+}
+
+pub async fn deleting_a_year_deletes_what_it_holds(repos: DynRepoRegistry) {
+    let year_repo = repos.year_repo();
+    year_repo.add(&year(2024)).await.unwrap();
+    year_repo.add(&year(2025)).await.unwrap();
+    add_month(&repos, MonthNum::January, 2024).await;
+    add_month(&repos, MonthNum::January, 2025).await;
+    let mut res = resource("Bank", cash());
+    res.insert_balance(2024, MonthNum::January, Milliunits::new(1000));
+    res.insert_balance(2025, MonthNum::January, Milliunits::new(2000));
+    repos.fin_res_repo().update(&res).await.unwrap();
+    let sr = saving_rate("Budgeter", 2024);
+    repos.saving_rate_repo().update(&sr).await.unwrap();
+
+    year_repo.delete(2024).await.unwrap();
+
+    assert_eq!(year_repo.get(2024).await, Err(DbError::NotFound));
+    assert_eq!(
+        repos
+            .month_repo()
+            .get_month_data_by_number(MonthNum::January, 2024)
+            .await
+            .unwrap_err(),
+        DbError::NotFound
+    );
+    assert_eq!(
+        repos.saving_rate_repo().get(sr.id).await,
+        Err(DbError::NotFound)
+    );
+    let remaining = repos.fin_res_repo().get(res.base.id).await.unwrap();
+    assert_eq!(remaining.iter_years().collect::<Vec<_>>(), vec![2025]);
+    // Deleting what does not exist is not an error.
+    year_repo.delete(2024).await.unwrap();
+}
+
+pub async fn months_belong_to_a_year(repos: DynRepoRegistry) {
+    let month_repo = repos.month_repo();
+    assert_eq!(
+        month_repo
+            .add(&Month::new(MonthNum::January, 2024), 2024)
+            .await,
+        Err(DbError::NotFound)
+    );
+
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    let month = add_month(&repos, MonthNum::January, 2024).await;
+    assert_eq!(
+        month_repo
+            .add(&Month::new(MonthNum::January, 2024), 2024)
+            .await,
+        Err(DbError::AlreadyExists)
+    );
+
+    let month_data = month_repo
+        .get_month_data_by_number(MonthNum::January, 2024)
+        .await
+        .unwrap();
+    assert_eq!(month_data.id, month.id);
+    assert_eq!(month_data.year, 2024);
+    assert_eq!(
+        month_repo.get(MonthNum::February, 2024).await,
+        Err(DbError::NotFound)
+    );
+
+    let got = month_repo
+        .get_without_resources(MonthNum::January, 2024)
+        .await
+        .unwrap();
+    assert_eq!(got, month);
+}
+
+pub async fn months_are_ordered(repos: DynRepoRegistry) {
+    let month_repo = repos.month_repo();
+    repos.year_repo().add(&year(2023)).await.unwrap();
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    for (month, y) in [
+        (MonthNum::March, 2024),
+        (MonthNum::January, 2024),
+        (MonthNum::December, 2023),
+        (MonthNum::November, 2023),
+    ] {
+        add_month(&repos, month, y).await;
+    }
+    let mut res = resource("Bank", cash());
+    res.insert_balance(2023, MonthNum::December, Milliunits::new(1000));
+    res.insert_balance(2024, MonthNum::March, Milliunits::new(2000));
+    repos.fin_res_repo().update(&res).await.unwrap();
+
+    let months = month_repo.get_months_starting_from(MonthNum::December, 2023);
+    assert_eq!(
+        months
+            .await
+            .unwrap()
+            .iter()
+            .map(|m| (m.year, m.month, m.resources.len()))
+            .collect::<Vec<_>>(),
+        vec![
+            (2023, MonthNum::December, 1),
+            (2024, MonthNum::January, 0),
+            (2024, MonthNum::March, 1),
+        ]
+    );
+    assert_eq!(
+        month_repo
+            .get_months()
+            .await
+            .unwrap()
+            .iter()
+            .map(|m| (m.year, m.month))
+            .collect::<Vec<_>>(),
+        vec![(2023, MonthNum::December), (2024, MonthNum::March)]
+    );
+    assert_eq!(
+        month_repo
+            .get_months_of_year_without_resources(2024)
+            .await
+            .unwrap()
+            .iter()
+            .map(|m| m.month)
+            .collect::<Vec<_>>(),
+        vec![MonthNum::January, MonthNum::March]
+    );
+    assert_eq!(
+        month_repo
+            .get_months_of_year(2024)
+            .await
+            .unwrap()
+            .iter()
+            .map(|m| m.month)
+            .collect::<Vec<_>>(),
+        vec![MonthNum::March]
+    );
+}
+
+pub async fn month_net_totals_follow_resources(repos: DynRepoRegistry) {
+    let month_repo = repos.month_repo();
+    repos.year_repo().add(&year(2023)).await.unwrap();
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    add_month(&repos, MonthNum::December, 2023).await;
+    add_month(&repos, MonthNum::January, 2024).await;
+    let mut bank = resource("Bank", cash());
+    bank.insert_balance(2023, MonthNum::December, Milliunits::new(1000));
+    bank.insert_balance(2024, MonthNum::January, Milliunits::new(3000));
+    let mut house = resource("House", FinancialResourceType::Asset(AssetType::LongTerm));
+    house.insert_balance(2024, MonthNum::January, Milliunits::new(10000));
+    let mut loan = resource(
+        "Loan",
+        FinancialResourceType::Liability(LiabilityType::Cash),
+    );
+    loan.insert_balance(2024, MonthNum::January, Milliunits::new(500));
+    for res in [&bank, &house, &loan] {
+        repos.fin_res_repo().update(res).await.unwrap();
+    }
+
+    month_repo
+        .update_net_totals(MonthNum::December, 2023)
+        .await
+        .unwrap();
+
+    let december = month_repo.get(MonthNum::December, 2023).await.unwrap();
+    assert_eq!(december.net_totals.assets.total, Milliunits::new(1000));
+    let january = month_repo.get(MonthNum::January, 2024).await.unwrap();
+    assert_eq!(
+        january
+            .resources
+            .iter()
+            .map(|r| r.base.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Bank", "House", "Loan"]
+    );
+    assert_eq!(january.net_totals.assets.total, Milliunits::new(12500));
+    assert_eq!(
+        january.net_totals.assets.balance_var,
+        Milliunits::new(11500)
+    );
+    assert_eq!(january.net_totals.portfolio.total, Milliunits::new(3000));
+    assert_eq!(
+        january.net_totals.portfolio.balance_var,
+        Milliunits::new(2000)
+    );
+
+    repos.year_repo().update_net_totals(2023).await.unwrap();
+    let y = repos.year_repo().get(2024).await.unwrap();
+    assert_eq!(y.net_totals.assets.total, Milliunits::new(12500));
+    assert_eq!(y.net_totals.assets.balance_var, Milliunits::new(11500));
+}
+
+pub async fn deleting_a_month_deletes_its_balances(repos: DynRepoRegistry) {
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    add_month(&repos, MonthNum::January, 2024).await;
+    add_month(&repos, MonthNum::February, 2024).await;
+    let mut res = resource("Bank", cash());
+    res.insert_balance(2024, MonthNum::January, Milliunits::new(1000));
+    res.insert_balance(2024, MonthNum::February, Milliunits::new(2000));
+    repos.fin_res_repo().update(&res).await.unwrap();
+
+    repos
+        .month_repo()
+        .delete(MonthNum::January, 2024)
+        .await
+        .unwrap();
+
+    let remaining = repos.fin_res_repo().get(res.base.id).await.unwrap();
+    assert_eq!(
+        remaining.iter_balances().collect::<Vec<_>>(),
+        vec![(2024, MonthNum::February, Milliunits::new(2000))]
+    );
+}
+
+pub async fn resource_names_are_unique(repos: DynRepoRegistry) {
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    add_month(&repos, MonthNum::January, 2024).await;
+    let mut res = resource("Bank", cash());
+    res.insert_balance(2024, MonthNum::January, Milliunits::new(1000));
+    repos.fin_res_repo().update(&res).await.unwrap();
+
+    let mut duplicate = resource("Bank", cash());
+    duplicate.insert_balance(2024, MonthNum::January, Milliunits::new(1000));
+    assert_eq!(
+        repos.fin_res_repo().update(&duplicate).await,
+        Err(DbError::AlreadyExists)
+    );
+
+    res.base.name = "Renamed".to_string();
+    repos.fin_res_repo().update(&res).await.unwrap();
+    assert_eq!(repos.fin_res_repo().get_by_name("Renamed").await, Ok(res));
+}
+
+pub async fn resources_without_balances_are_not_found(repos: DynRepoRegistry) {
+    let fin_res_repo = repos.fin_res_repo();
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    add_month(&repos, MonthNum::January, 2024).await;
+
+    let mut res = resource("Bank", cash());
+    fin_res_repo.update(&res).await.unwrap();
+    assert_eq!(fin_res_repo.get(res.base.id).await, Err(DbError::NotFound));
+    assert_eq!(
+        fin_res_repo.get_by_name("Bank").await,
+        Err(DbError::NotFound)
+    );
+    assert_eq!(fin_res_repo.get_from_all_years().await, Ok(vec![]));
+
+    res.insert_balance(2024, MonthNum::February, Milliunits::new(1000));
+    assert_eq!(fin_res_repo.update(&res).await, Err(DbError::NotFound));
+}
+
+pub async fn resources_are_sorted_by_name(repos: DynRepoRegistry) {
+    let fin_res_repo = repos.fin_res_repo();
+    repos.year_repo().add(&year(2023)).await.unwrap();
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    add_month(&repos, MonthNum::December, 2023).await;
+    add_month(&repos, MonthNum::January, 2024).await;
+    let mut loan = resource(
+        "loan",
+        FinancialResourceType::Liability(LiabilityType::Cash),
+    );
+    loan.insert_balance(2023, MonthNum::December, Milliunits::new(100));
+    loan.insert_balance(2024, MonthNum::January, Milliunits::new(50));
+    let mut bank = resource("bank", cash());
+    bank.insert_balance(2024, MonthNum::January, Milliunits::new(1000));
+    let mut car = resource("car", FinancialResourceType::Asset(AssetType::LongTerm));
+    car.insert_balance(2023, MonthNum::December, Milliunits::new(5000));
+    for res in [&loan, &bank, &car] {
+        fin_res_repo.update(res).await.unwrap();
+    }
+
+    let names = |resources: Vec<FinancialResourceYearly>| {
+        resources
+            .into_iter()
+            .map(|r| r.base.name)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        names(fin_res_repo.get_from_all_years().await.unwrap()),
+        vec!["bank", "car", "loan"]
+    );
+    assert_eq!(
+        names(fin_res_repo.get_from_year(2024).await.unwrap()),
+        vec!["bank", "loan"]
+    );
+    assert_eq!(
+        names(
+            fin_res_repo
+                .get_from_year_and_category(2023, &ResourceCategory::Asset)
+                .await
+                .unwrap()
+        ),
+        vec!["car"]
+    );
+    assert_eq!(
+        names(
+            fin_res_repo
+                .get_from_year_and_category(2024, &ResourceCategory::Liability)
+                .await
+                .unwrap()
+        ),
+        vec!["loan"]
+    );
+
+    let in_year = fin_res_repo.get_from_year(2024).await.unwrap();
+    assert_eq!(
+        in_year[1].iter_balances().collect::<Vec<_>>(),
+        vec![(2024, MonthNum::January, Milliunits::new(50))]
+    );
+    assert_eq!(fin_res_repo.get(loan.base.id).await, Ok(loan));
+
+    let monthly = fin_res_repo
+        .get_from_month(MonthNum::January, 2024)
+        .await
+        .unwrap();
+    assert_eq!(
+        monthly
+            .iter()
+            .map(|r| (r.base.name.as_str(), r.balance))
+            .collect::<Vec<_>>(),
+        vec![
+            ("bank", Milliunits::new(1000)),
+            ("loan", Milliunits::new(50))
+        ]
+    );
+    assert_eq!(
+        fin_res_repo.get_from_month(MonthNum::March, 2024).await,
+        Ok(vec![])
+    );
+}
+
+pub async fn missing_balances_are_deleted(repos: DynRepoRegistry) {
+    let fin_res_repo = repos.fin_res_repo();
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    add_month(&repos, MonthNum::January, 2024).await;
+    add_month(&repos, MonthNum::February, 2024).await;
+    let mut res = resource("Bank", cash());
+    res.insert_balance(2024, MonthNum::January, Milliunits::new(1000));
+    res.insert_balance(2024, MonthNum::February, Milliunits::new(2000));
+    fin_res_repo.update(&res).await.unwrap();
+
+    res.balances = BTreeMap::from([(
+        2024,
+        BTreeMap::from([
+            (MonthNum::January, None),
+            (MonthNum::February, Some(Milliunits::new(3000))),
+        ]),
+    )]);
+    fin_res_repo.update_and_delete(&res).await.unwrap();
+
+    assert_eq!(
+        fin_res_repo
+            .get(res.base.id)
+            .await
+            .unwrap()
+            .iter_balances()
+            .collect::<Vec<_>>(),
+        vec![(2024, MonthNum::February, Milliunits::new(3000))]
+    );
+
+    fin_res_repo.delete(res.base.id).await.unwrap();
+    assert_eq!(fin_res_repo.get(res.base.id).await, Err(DbError::NotFound));
+    assert_eq!(
+        fin_res_repo.get_from_month(MonthNum::February, 2024).await,
+        Ok(vec![])
+    );
+}
+
+pub async fn resources_order_is_per_year_and_category(repos: DynRepoRegistry) {
+    let order_repo = repos.fin_res_order_repo();
+    assert_eq!(
+        order_repo.get_order(2024, &ResourceCategory::Asset).await,
+        Ok(vec![])
+    );
+
+    let order = vec![Uuid::new_v4(), Uuid::new_v4()];
+    order_repo
+        .set_order(2024, &ResourceCategory::Asset, &order)
+        .await
+        .unwrap();
+    assert_eq!(
+        order_repo.get_order(2024, &ResourceCategory::Asset).await,
+        Ok(order.clone())
+    );
+    assert_eq!(
+        order_repo
+            .get_order(2024, &ResourceCategory::Liability)
+            .await,
+        Ok(vec![])
+    );
+    assert_eq!(
+        order_repo.get_order(2025, &ResourceCategory::Asset).await,
+        Ok(vec![])
+    );
+
+    let reversed: Vec<Uuid> = order.into_iter().rev().collect();
+    order_repo
+        .set_order(2024, &ResourceCategory::Asset, &reversed)
+        .await
+        .unwrap();
+    assert_eq!(
+        order_repo.get_order(2024, &ResourceCategory::Asset).await,
+        Ok(reversed)
+    );
+}
+
+pub async fn saving_rates_belong_to_a_year(repos: DynRepoRegistry) {
+    let saving_rate_repo = repos.saving_rate_repo();
+    let first = saving_rate("Budgeter", 2024);
+    assert_eq!(
+        saving_rate_repo.update(&first).await,
+        Err(DbError::NotFound)
+    );
+
+    repos.year_repo().add(&year(2023)).await.unwrap();
+    repos.year_repo().add(&year(2024)).await.unwrap();
+    saving_rate_repo.update(&first).await.unwrap();
+    let second = saving_rate("Budgeter", 2023);
+    saving_rate_repo.update(&second).await.unwrap();
+    saving_rate_repo
+        .update(&saving_rate("Other", 2024))
+        .await
+        .unwrap();
+
+    assert_eq!(saving_rate_repo.get(first.id).await, Ok(first.clone()));
+    assert_eq!(
+        saving_rate_repo.get_all_by_name("Budgeter").await,
+        Ok(vec![second.clone(), first.clone()])
+    );
+    let mut in_year = saving_rate_repo.get_from_year(2024).await.unwrap();
+    in_year.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(
+        in_year
+            .iter()
+            .map(|sr| sr.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Budgeter", "Other"]
+    );
+    assert_eq!(
+        saving_rate_repo
+            .get_all()
+            .await
+            .unwrap()
+            .iter()
+            .map(|sr| (sr.year, sr.name.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(2023, "Budgeter"), (2024, "Budgeter"), (2024, "Other")]
+    );
+
+    let mut moved = first.clone();
+    moved.year = 2023;
+    moved.target = None;
+    saving_rate_repo.update(&moved).await.unwrap();
+    assert_eq!(saving_rate_repo.get(first.id).await, Ok(moved));
+
+    saving_rate_repo.delete(first.id).await.unwrap();
+    assert_eq!(saving_rate_repo.get(first.id).await, Err(DbError::NotFound));
+    assert_eq!(
+        saving_rate_repo.get_by_name("Missing").await,
+        Err(DbError::NotFound)
+    );
+}
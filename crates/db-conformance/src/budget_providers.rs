@@ -0,0 +1,522 @@
+use chrono::{Duration, DurationRound, Utc};
+use datamize_domain::{
+    db::{DbError, DynRepoRegistry},
+    secrecy::{ExposeSecret, Secret},
+    AccountType, BalanceSource, EncryptedPassword, ExternalAccountBalance, ExternalAccountRefresh,
+    Uuid, WebScrapingAccount,
+};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+use ynab::{
+    types::{Account, Category, Payee, ScheduledTransactionDetail},
+    Milliunits, TransactionDetail,
+};
+
+fn sorted(mut ids: Vec<Uuid>) -> Vec<Uuid> {
+    ids.sort();
+    ids
+}
+
+fn external_account(name: &str) -> WebScrapingAccount {
+    WebScrapingAccount {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        account_type: AccountType::Tfsa,
+        balance: Milliunits::new(1000),
+        username: "username".to_string(),
+        encrypted_password: Secret::new(EncryptedPassword::new(vec![1, 2, 3])),
+        deleted: false,
+        scraper_id: Some("peak_tfsa".to_string()),
+        encrypted_totp_seed: None,
+    }
+}
+
+fn assert_same_external_account(a: &WebScrapingAccount, b: &WebScrapingAccount) {
+    assert_eq!(a.id, b.id);
+    assert_eq!(a.name, b.name);
+    assert_eq!(a.account_type, b.account_type);
+    assert_eq!(a.balance, b.balance);
+    assert_eq!(a.username, b.username);
+    assert_eq!(
+        a.encrypted_password.expose_secret().as_ref(),
+        b.encrypted_password.expose_secret().as_ref()
+    );
+    assert_eq!(a.deleted, b.deleted);
+    assert_eq!(a.scraper_id, b.scraper_id);
+    assert_eq!(
+        a.encrypted_totp_seed
+            .as_ref()
+            .map(|seed| seed.expose_secret().as_ref()),
+        b.encrypted_totp_seed
+            .as_ref()
+            .map(|seed| seed.expose_secret().as_ref())
+    );
+}
+
+pub async fn external_accounts_are_unique(repos: DynRepoRegistry) {
+    let external_account_repo = repos.external_account_repo();
+    let mut account = external_account("Broker");
+    external_account_repo.add(&account).await.unwrap();
+    assert_eq!(
+        external_account_repo.add(&account).await.unwrap_err(),
+        DbError::AlreadyExists
+    );
+    assert_same_external_account(
+        &external_account_repo.get(account.id).await.unwrap(),
+        &account,
+    );
+
+    account.balance = Milliunits::new(2000);
+    account.deleted = true;
+    account.scraper_id = None;
+    account.encrypted_totp_seed = Some(Secret::new(EncryptedPassword::new(vec![4, 5, 6])));
+    external_account_repo.update(&account).await.unwrap();
+    assert_same_external_account(
+        &external_account_repo.get_by_name("Broker").await.unwrap(),
+        &account,
+    );
+
+    let other = external_account("Bank");
+    external_account_repo.update(&other).await.unwrap();
+    assert_eq!(
+        sorted(
+            external_account_repo
+                .get_all()
+                .await
+                .unwrap()
+                .iter()
+                .map(|a| a.id)
+                .collect()
+        ),
+        sorted(vec![account.id, other.id])
+    );
+
+    external_account_repo.delete(account.id).await.unwrap();
+    assert_eq!(
+        external_account_repo.get(account.id).await.unwrap_err(),
+        DbError::NotFound
+    );
+    assert_eq!(
+        external_account_repo
+            .get_by_name("Broker")
+            .await
+            .unwrap_err(),
+        DbError::NotFound
+    );
+}
+
+pub async fn external_account_refreshes_are_latest_first(repos: DynRepoRegistry) {
+    let external_account_repo = repos.external_account_repo();
+    let refresh_repo = repos.external_account_refresh_repo();
+    let account = external_account("Broker");
+    external_account_repo.add(&account).await.unwrap();
+    assert_eq!(
+        refresh_repo.get_last(account.id).await,
+        Err(DbError::NotFound)
+    );
+
+    // Timestamps are truncated to what every backend stores.
+    let now = Utc::now().duration_trunc(Duration::seconds(1)).unwrap();
+    let success = ExternalAccountRefresh {
+        id: Uuid::new_v4(),
+        account_id: account.id,
+        refreshed_at: now - Duration::days(1),
+        duration_ms: 1500,
+        succeeded: true,
+        balance: Some(Milliunits::new(2000)),
+        error: None,
+    };
+    let failure = ExternalAccountRefresh {
+        id: Uuid::new_v4(),
+        refreshed_at: now,
+        succeeded: false,
+        balance: None,
+        error: Some("Step 2 of the recipe \"peak_tfsa\" failed".to_string()),
+        ..success.clone()
+    };
+    for r in [&failure, &success] {
+        refresh_repo.add(r).await.unwrap();
+    }
+    assert_eq!(
+        refresh_repo.add(&success).await,
+        Err(DbError::AlreadyExists)
+    );
+    assert_eq!(
+        refresh_repo
+            .add(&ExternalAccountRefresh {
+                id: Uuid::new_v4(),
+                account_id: Uuid::new_v4(),
+                ..success.clone()
+            })
+            .await,
+        Err(DbError::NotFound)
+    );
+
+    assert_eq!(
+        refresh_repo.get_all(account.id).await,
+        Ok(vec![success.clone(), failure.clone()])
+    );
+    assert_eq!(refresh_repo.get_last(account.id).await, Ok(failure));
+    assert_eq!(refresh_repo.get_last_success(account.id).await, Ok(success));
+
+    external_account_repo.delete(account.id).await.unwrap();
+    assert_eq!(
+        refresh_repo.get_last(account.id).await,
+        Err(DbError::NotFound)
+    );
+    assert_eq!(refresh_repo.get_all(account.id).await, Ok(vec![]));
+}
+
+pub async fn external_account_balances_are_kept_in_order(repos: DynRepoRegistry) {
+    let external_account_repo = repos.external_account_repo();
+    let balance_repo = repos.external_account_balance_repo();
+    let account = external_account("Broker");
+    external_account_repo.add(&account).await.unwrap();
+    assert_eq!(
+        balance_repo.get_last(account.id, None).await,
+        Err(DbError::NotFound)
+    );
+
+    // Timestamps are truncated to what every backend stores.
+    let now = Utc::now().duration_trunc(Duration::seconds(1)).unwrap();
+    let scraped = ExternalAccountBalance {
+        id: Uuid::new_v4(),
+        account_id: account.id,
+        observed_at: now - Duration::days(30),
+        balance: Milliunits::new(1000),
+        source: BalanceSource::Scrape,
+    };
+    let imported = ExternalAccountBalance {
+        id: Uuid::new_v4(),
+        observed_at: now - Duration::days(10),
+        balance: Milliunits::new(1500),
+        source: BalanceSource::Import,
+        ..scraped.clone()
+    };
+    let manual = ExternalAccountBalance {
+        id: Uuid::new_v4(),
+        observed_at: now,
+        balance: Milliunits::new(2000),
+        source: BalanceSource::Manual,
+        ..scraped.clone()
+    };
+    for b in [&manual, &scraped, &imported] {
+        balance_repo.add(b).await.unwrap();
+    }
+    assert_eq!(balance_repo.add(&manual).await, Err(DbError::AlreadyExists));
+    assert_eq!(
+        balance_repo
+            .add(&ExternalAccountBalance {
+                id: Uuid::new_v4(),
+                account_id: Uuid::new_v4(),
+                ..manual.clone()
+            })
+            .await,
+        Err(DbError::NotFound)
+    );
+
+    assert_eq!(
+        balance_repo.get_all(account.id, None, None).await,
+        Ok(vec![scraped.clone(), imported.clone(), manual.clone()])
+    );
+    assert_eq!(
+        balance_repo
+            .get_all(
+                account.id,
+                Some(imported.observed_at),
+                Some(manual.observed_at)
+            )
+            .await,
+        Ok(vec![imported.clone()])
+    );
+    assert_eq!(
+        balance_repo.get_last(account.id, None).await,
+        Ok(manual.clone())
+    );
+    assert_eq!(
+        balance_repo
+            .get_last(account.id, Some(manual.observed_at))
+            .await,
+        Ok(imported)
+    );
+    assert_eq!(
+        balance_repo
+            .get_last(account.id, Some(scraped.observed_at))
+            .await,
+        Err(DbError::NotFound)
+    );
+
+    external_account_repo.delete(account.id).await.unwrap();
+    assert_eq!(
+        balance_repo.get_all(account.id, None, None).await,
+        Ok(vec![])
+    );
+}
+
+pub async fn secrets_are_overwritten(repos: DynRepoRegistry) {
+    let encryption_key_repo = repos.encryption_key_repo();
+    let oauth_token_repo = repos.ynab_oauth_token_repo();
+    assert_eq!(encryption_key_repo.get().await, Err(DbError::NotFound));
+    assert_eq!(oauth_token_repo.get("al").await, Err(DbError::NotFound));
+
+    encryption_key_repo.set(&[1, 2, 3]).await.unwrap();
+    assert_eq!(encryption_key_repo.get().await, Ok(vec![1, 2, 3]));
+    assert_eq!(oauth_token_repo.get("al").await, Err(DbError::NotFound));
+
+    encryption_key_repo.set(&[4, 5]).await.unwrap();
+    oauth_token_repo.set("al", &[6]).await.unwrap();
+    assert_eq!(encryption_key_repo.get().await, Ok(vec![4, 5]));
+    assert_eq!(oauth_token_repo.get("al").await, Ok(vec![6]));
+    assert_eq!(oauth_token_repo.get("bea").await, Err(DbError::NotFound));
+
+    oauth_token_repo.set("bea", &[7]).await.unwrap();
+    oauth_token_repo.set("al", &[8]).await.unwrap();
+    assert_eq!(oauth_token_repo.get("al").await, Ok(vec![8]));
+    assert_eq!(oauth_token_repo.get("bea").await, Ok(vec![7]));
+}
+
+pub async fn ynab_categories_are_scoped_to_their_budget(repos: DynRepoRegistry) {
+    let budget_repo = repos.ynab_category_repo("budget");
+    let other_budget_repo = repos.ynab_category_repo("other");
+    let category = Category {
+        deleted: false,
+        ..Faker.fake()
+    };
+    let other = Category {
+        deleted: false,
+        ..Faker.fake()
+    };
+    budget_repo
+        .update_all(&[category.clone(), other.clone()])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        sorted(
+            budget_repo
+                .get_all()
+                .await
+                .unwrap()
+                .iter()
+                .map(|c| c.id)
+                .collect()
+        ),
+        sorted(vec![category.id, other.id])
+    );
+    assert_eq!(budget_repo.get(category.id).await.unwrap().id, category.id);
+    assert_eq!(
+        other_budget_repo.get(category.id).await.unwrap_err(),
+        DbError::NotFound
+    );
+    assert_eq!(other_budget_repo.get_all().await, Ok(vec![]));
+
+    // Ids are unique across budgets, saving it for another budget moves it there.
+    other_budget_repo
+        .update_all(std::slice::from_ref(&category))
+        .await
+        .unwrap();
+    assert_eq!(
+        budget_repo
+            .get_all()
+            .await
+            .unwrap()
+            .iter()
+            .map(|c| c.id)
+            .collect::<Vec<_>>(),
+        vec![other.id]
+    );
+    assert_eq!(
+        other_budget_repo.get(category.id).await.unwrap().id,
+        category.id
+    );
+}
+
+pub async fn deleted_ynab_scheduled_transactions_are_removed(repos: DynRepoRegistry) {
+    let budget_repo = repos.ynab_scheduled_transaction_repo("budget");
+    let other_budget_repo = repos.ynab_scheduled_transaction_repo("other");
+    let kept = ScheduledTransactionDetail {
+        deleted: false,
+        subtransactions: vec![],
+        ..Faker.fake()
+    };
+    let mut removed = ScheduledTransactionDetail {
+        deleted: false,
+        subtransactions: vec![],
+        ..Faker.fake()
+    };
+    budget_repo
+        .update_all(&[kept.clone(), removed.clone()])
+        .await
+        .unwrap();
+
+    removed.deleted = true;
+    // Another budget can't remove it.
+    other_budget_repo
+        .update_all(std::slice::from_ref(&removed))
+        .await
+        .unwrap();
+    assert_eq!(budget_repo.get_all().await.unwrap().len(), 2);
+
+    budget_repo.update_all(&[removed]).await.unwrap();
+    assert_eq!(
+        budget_repo
+            .get_all()
+            .await
+            .unwrap()
+            .iter()
+            .map(|st| st.id)
+            .collect::<Vec<_>>(),
+        vec![kept.id]
+    );
+    assert_eq!(other_budget_repo.get_all().await, Ok(vec![]));
+}
+
+pub async fn ynab_transactions_are_filtered(repos: DynRepoRegistry) {
+    let budget_repo = repos.ynab_transaction_repo("budget");
+    let other_budget_repo = repos.ynab_transaction_repo("other");
+    let payee_id = Uuid::new_v4();
+    let category_id = Uuid::new_v4();
+    let transaction = |payee_id: Option<Uuid>, category_id: Option<Uuid>| {
+        let mut transaction: TransactionDetail = Faker.fake();
+        transaction.base.payee_id = payee_id;
+        transaction.base.category_id = category_id;
+        transaction.subtransactions = vec![];
+        transaction
+    };
+    let of_payee = transaction(Some(payee_id), None);
+    let of_category = transaction(None, Some(category_id));
+    let of_both = transaction(Some(payee_id), Some(category_id));
+    let of_other_budget = transaction(Some(payee_id), Some(category_id));
+    budget_repo
+        .update_all(&[of_payee.clone(), of_category.clone(), of_both.clone()])
+        .await
+        .unwrap();
+    other_budget_repo
+        .update_all(std::slice::from_ref(&of_other_budget))
+        .await
+        .unwrap();
+
+    let ids = |transactions: Vec<TransactionDetail>| {
+        sorted(transactions.iter().map(|t| t.base.id).collect())
+    };
+    assert_eq!(
+        ids(budget_repo.get_all().await.unwrap()),
+        sorted(vec![of_payee.base.id, of_category.base.id, of_both.base.id])
+    );
+    assert_eq!(
+        ids(budget_repo.get_all_with_payee_id(payee_id).await.unwrap()),
+        sorted(vec![of_payee.base.id, of_both.base.id])
+    );
+    assert_eq!(
+        ids(budget_repo
+            .get_all_with_category_id(category_id)
+            .await
+            .unwrap()),
+        sorted(vec![of_category.base.id, of_both.base.id])
+    );
+    assert_eq!(
+        ids(other_budget_repo.get_all().await.unwrap()),
+        vec![of_other_budget.base.id]
+    );
+}
+
+pub async fn ynab_accounts_and_payees_are_scoped_to_their_budget(repos: DynRepoRegistry) {
+    let account: Account = Faker.fake();
+    repos
+        .ynab_account_repo("budget")
+        .update_all(std::slice::from_ref(&account))
+        .await
+        .unwrap();
+    let payee: Payee = Faker.fake();
+    repos
+        .ynab_payee_repo("budget")
+        .update_all(std::slice::from_ref(&payee))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        repos
+            .ynab_account_repo("budget")
+            .get_all()
+            .await
+            .unwrap()
+            .iter()
+            .map(|a| a.id)
+            .collect::<Vec<_>>(),
+        vec![account.id]
+    );
+    assert_eq!(repos.ynab_account_repo("other").get_all().await, Ok(vec![]));
+    assert_eq!(
+        repos.ynab_payee_repo("budget").get_all().await,
+        Ok(vec![payee])
+    );
+    assert_eq!(repos.ynab_payee_repo("other").get_all().await, Ok(vec![]));
+}
+
+pub async fn ynab_meta_is_per_budget_and_resource(repos: DynRepoRegistry) {
+    let category_meta_repo = repos.ynab_category_meta_repo("budget");
+    assert_eq!(category_meta_repo.get_delta().await, Err(DbError::NotFound));
+    assert_eq!(
+        category_meta_repo.get_last_saved().await,
+        Err(DbError::NotFound)
+    );
+    // Nothing to delete is not an error.
+    category_meta_repo.del_delta().await.unwrap();
+
+    category_meta_repo.set_delta(10).await.unwrap();
+    category_meta_repo
+        .set_last_saved("last saved".to_string())
+        .await
+        .unwrap();
+    assert_eq!(category_meta_repo.get_delta().await, Ok(10));
+    assert_eq!(
+        category_meta_repo.get_last_saved().await,
+        Ok("last saved".to_string())
+    );
+    assert_eq!(
+        repos.ynab_category_meta_repo("other").get_delta().await,
+        Err(DbError::NotFound)
+    );
+    assert_eq!(
+        repos.ynab_payee_meta_repo("budget").get_delta().await,
+        Err(DbError::NotFound)
+    );
+
+    category_meta_repo.del_delta().await.unwrap();
+    assert_eq!(category_meta_repo.get_delta().await, Err(DbError::NotFound));
+    assert_eq!(
+        category_meta_repo.get_last_saved().await,
+        Ok("last saved".to_string())
+    );
+
+    for (set_delta, get_delta) in [
+        (
+            repos.ynab_account_meta_repo("budget").set_delta(1).await,
+            repos.ynab_account_meta_repo("budget").get_delta().await,
+        ),
+        (
+            repos.ynab_payee_meta_repo("budget").set_delta(1).await,
+            repos.ynab_payee_meta_repo("budget").get_delta().await,
+        ),
+        (
+            repos
+                .ynab_transaction_meta_repo("budget")
+                .set_delta(1)
+                .await,
+            repos.ynab_transaction_meta_repo("budget").get_delta().await,
+        ),
+        (
+            repos
+                .ynab_scheduled_transaction_meta_repo("budget")
+                .set_delta(1)
+                .await,
+            repos
+                .ynab_scheduled_transaction_meta_repo("budget")
+                .get_delta()
+                .await,
+        ),
+    ] {
+        assert_eq!(set_delta, Ok(()));
+        assert_eq!(get_delta, Ok(1));
+    }
+}
@@ -0,0 +1,134 @@
+use datamize_domain::{
+    db::{DbError, DynRepoRegistry},
+    AssociationType, BudgeterConfig, ExpenseAssociation, ExpenseCategorization, SplitStrategy,
+    Uuid,
+};
+use fake::{Fake, Faker};
+use pretty_assertions::assert_eq;
+
+fn sorted<T: Ord>(mut items: Vec<T>) -> Vec<T> {
+    items.sort();
+    items
+}
+
+pub async fn budgeters_are_upserted(repos: DynRepoRegistry) {
+    let budgeter_config_repo = repos.budgeter_config_repo();
+    let mut budgeter: BudgeterConfig = Faker.fake();
+    budgeter_config_repo.update(&budgeter).await.unwrap();
+    assert_eq!(
+        budgeter_config_repo.get(budgeter.id).await,
+        Ok(budgeter.clone())
+    );
+
+    budgeter.name = "Renamed".to_string();
+    budgeter.payee_ids = vec![Uuid::new_v4()];
+    budgeter_config_repo.update(&budgeter).await.unwrap();
+    assert_eq!(
+        budgeter_config_repo.get_by_name("Renamed").await,
+        Ok(budgeter.clone())
+    );
+    assert_eq!(
+        budgeter_config_repo.get_all().await,
+        Ok(vec![budgeter.clone()])
+    );
+
+    budgeter_config_repo.delete(budgeter.id).await.unwrap();
+    assert_eq!(
+        budgeter_config_repo.get(budgeter.id).await,
+        Err(DbError::NotFound)
+    );
+    assert_eq!(
+        budgeter_config_repo.get_by_name("Renamed").await,
+        Err(DbError::NotFound)
+    );
+}
+
+pub async fn expense_associations_belong_to_a_budgeter(repos: DynRepoRegistry) {
+    let association_repo = repos.expense_association_repo();
+    let budgeter: BudgeterConfig = Faker.fake();
+    let association = ExpenseAssociation {
+        id: Uuid::new_v4(),
+        association_type: AssociationType::CategoryGroup,
+        budgeter_id: Some(budgeter.id),
+        split_strategy: None,
+    };
+    assert_eq!(
+        association_repo.update(&association).await,
+        Err(DbError::NotFound)
+    );
+
+    repos
+        .budgeter_config_repo()
+        .update(&budgeter)
+        .await
+        .unwrap();
+    association_repo.update(&association).await.unwrap();
+    assert_eq!(
+        association_repo.get(association.id).await,
+        Ok(association.clone())
+    );
+    let other = ExpenseAssociation {
+        id: Uuid::new_v4(),
+        association_type: AssociationType::Category,
+        budgeter_id: Some(budgeter.id),
+        split_strategy: None,
+    };
+    association_repo.update(&other).await.unwrap();
+    association_repo.delete(other.id).await.unwrap();
+    assert_eq!(
+        association_repo.get_all().await,
+        Ok(vec![association.clone()])
+    );
+
+    let shared = ExpenseAssociation {
+        id: Uuid::new_v4(),
+        association_type: AssociationType::Category,
+        budgeter_id: None,
+        split_strategy: Some(SplitStrategy::Equal),
+    };
+    association_repo.update(&shared).await.unwrap();
+
+    repos
+        .budgeter_config_repo()
+        .delete(budgeter.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        association_repo.get(association.id).await,
+        Err(DbError::NotFound)
+    );
+    assert_eq!(association_repo.get_all().await, Ok(vec![shared]));
+}
+
+pub async fn expenses_categorization_are_upserted(repos: DynRepoRegistry) {
+    let expense_categorization_repo = repos.expense_categorization_repo();
+    let first: ExpenseCategorization = Faker.fake();
+    let mut second: ExpenseCategorization = Faker.fake();
+    expense_categorization_repo
+        .update_all(&[first.clone(), second.clone()])
+        .await
+        .unwrap();
+
+    second.name = "Renamed".to_string();
+    expense_categorization_repo.update(&second).await.unwrap();
+    assert_eq!(
+        expense_categorization_repo.get(second.id).await,
+        Ok(second.clone())
+    );
+    assert_eq!(
+        sorted(
+            expense_categorization_repo
+                .get_all()
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|e| e.id)
+                .collect()
+        ),
+        sorted(vec![first.id, second.id])
+    );
+    assert_eq!(
+        expense_categorization_repo.get(Uuid::new_v4()).await,
+        Err(DbError::NotFound)
+    );
+}
@@ -0,0 +1,68 @@
+//! Checks every storage backend must pass, so they all behave the same. Each backend runs them
+//! through [`conformance_tests!`], with a fresh and empty registry per check.
+
+mod balance_sheet;
+mod budget_providers;
+mod budget_template;
+mod scheduler;
+
+pub use balance_sheet::*;
+pub use budget_providers::*;
+pub use budget_template::*;
+pub use scheduler::*;
+
+/// Generates a test per check. Takes the attribute of the tests, then a closure building the
+/// registry from the arguments of the tests.
+///
+/// ```ignore
+/// db_conformance::conformance_tests! {
+///     #[sqlx::test(migrations = "./migrations")]
+///     |pool: SqlitePool| SqliteRepoRegistry::new_arced(pool)
+/// }
+/// ```
+#[macro_export]
+macro_rules! conformance_tests {
+    (#[$attr:meta] || $registry:expr) => {
+        $crate::conformance_tests!(#[$attr] | | $registry);
+    };
+    (#[$attr:meta] |$($arg:ident: $ty:ty),*| $registry:expr) => {
+        $crate::conformance_tests!(@checks #[$attr] ($($arg: $ty),*) $registry;
+            years_are_unique,
+            years_are_ordered,
+            year_net_totals_keep_their_ids,
+            refreshed_at_is_updated,
+            deleting_a_year_deletes_what_it_holds,
+            months_belong_to_a_year,
+            months_are_ordered,
+            month_net_totals_follow_resources,
+            deleting_a_month_deletes_its_balances,
+            resource_names_are_unique,
+            resources_without_balances_are_not_found,
+            resources_are_sorted_by_name,
+            missing_balances_are_deleted,
+            resources_order_is_per_year_and_category,
+            saving_rates_belong_to_a_year,
+            external_accounts_are_unique,
+            external_account_refreshes_are_latest_first,
+            external_account_balances_are_kept_in_order,
+            secrets_are_overwritten,
+            ynab_categories_are_scoped_to_their_budget,
+            deleted_ynab_scheduled_transactions_are_removed,
+            ynab_transactions_are_filtered,
+            ynab_accounts_and_payees_are_scoped_to_their_budget,
+            ynab_meta_is_per_budget_and_resource,
+            budgeters_are_upserted,
+            expense_associations_belong_to_a_budgeter,
+            expenses_categorization_are_upserted,
+            scheduler_runs_are_latest_first,
+        );
+    };
+    (@checks #[$attr:meta] $args:tt $registry:expr; $($check:ident),* $(,)?) => {
+        $(
+            #[$attr]
+            async fn $check $args {
+                $crate::$check($registry).await;
+            }
+        )*
+    };
+}
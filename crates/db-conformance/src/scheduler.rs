@@ -0,0 +1,64 @@
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use datamize_domain::{
+    db::{DbError, DynRepoRegistry},
+    ScheduledJob, SchedulerRun, Uuid,
+};
+use pretty_assertions::assert_eq;
+
+/// Timestamps are truncated to what every backend stores.
+fn run(job: ScheduledJob, started_at: DateTime<Utc>) -> SchedulerRun {
+    let started_at = started_at.duration_trunc(Duration::seconds(1)).unwrap();
+    SchedulerRun {
+        id: Uuid::new_v4(),
+        job,
+        started_at,
+        finished_at: started_at + Duration::seconds(2),
+        succeeded: true,
+        details: "Done".to_string(),
+    }
+}
+
+pub async fn scheduler_runs_are_latest_first(repos: DynRepoRegistry) {
+    let scheduler_run_repo = repos.scheduler_run_repo();
+    assert_eq!(
+        scheduler_run_repo.get_last(ScheduledJob::Refresh).await,
+        Err(DbError::NotFound)
+    );
+
+    let now = Utc::now();
+    let oldest_refresh = run(ScheduledJob::Refresh, now - Duration::days(2));
+    let rollover = run(ScheduledJob::Rollover, now - Duration::days(1));
+    let latest_refresh = SchedulerRun {
+        succeeded: false,
+        details: "YNAB could not be reached".to_string(),
+        ..run(ScheduledJob::Refresh, now)
+    };
+    for r in [&rollover, &latest_refresh, &oldest_refresh] {
+        scheduler_run_repo.add(r).await.unwrap();
+    }
+    assert_eq!(
+        scheduler_run_repo.add(&rollover).await,
+        Err(DbError::AlreadyExists)
+    );
+
+    assert_eq!(
+        scheduler_run_repo.get_latest(10).await,
+        Ok(vec![
+            latest_refresh.clone(),
+            rollover.clone(),
+            oldest_refresh.clone()
+        ])
+    );
+    assert_eq!(
+        scheduler_run_repo.get_latest(1).await,
+        Ok(vec![latest_refresh.clone()])
+    );
+    assert_eq!(
+        scheduler_run_repo.get_last(ScheduledJob::Refresh).await,
+        Ok(latest_refresh)
+    );
+    assert_eq!(
+        scheduler_run_repo.get_last(ScheduledJob::Rollover).await,
+        Ok(rollover)
+    );
+}
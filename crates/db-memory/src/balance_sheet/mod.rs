@@ -0,0 +1,9 @@
+mod month;
+mod resource;
+mod saving_rate;
+mod year;
+
+pub use month::*;
+pub use resource::*;
+pub use saving_rate::*;
+pub use year::*;
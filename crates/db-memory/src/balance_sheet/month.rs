@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DbResult, MonthData, MonthRepo, YearData},
+    Month, MonthNum, NetTotals, Uuid,
+};
+
+use crate::store::{MemoryStore, MonthRow, Tables};
+
+#[derive(Debug, Clone)]
+pub struct MemoryMonthRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryMonthRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+impl Tables {
+    fn month(&self, month_data: MonthData, with_resources: bool) -> Month {
+        let resources = if with_resources {
+            self.resources_of_month(month_data.id)
+        } else {
+            vec![]
+        };
+
+        Month {
+            id: month_data.id,
+            month: month_data.month,
+            year: month_data.year,
+            net_totals: self.month_net_totals(month_data.id),
+            resources,
+        }
+    }
+
+    pub(crate) fn months_of_year_without_resources(&self, year: i32) -> Vec<Month> {
+        self.months_data(|m| m.year == year)
+            .into_iter()
+            .map(|md| self.month(md, false))
+            .collect()
+    }
+
+    /// Months with no resources are filtered out.
+    pub(crate) fn months_of_year(&self, year: i32) -> Vec<Month> {
+        self.months_data(|m| m.year == year)
+            .into_iter()
+            .map(|md| self.month(md, true))
+            .filter(|m| !m.resources.is_empty())
+            .collect()
+    }
+
+    fn months_starting_from(&self, month_num: MonthNum, year: i32) -> Vec<Month> {
+        self.months_data(|m| m.year > year || (m.year == year && m.month >= month_num))
+            .into_iter()
+            .map(|md| self.month(md, true))
+            .collect()
+    }
+
+    fn month_without_resources(&self, month_num: MonthNum, year: i32) -> DbResult<Month> {
+        let month_data = self.month_data_by_number(month_num, year)?;
+        // The SQL backends join the net totals of the month, so it can't be found without them.
+        if !self.months_net_totals.contains_key(&month_data.id) {
+            return Err(DbError::NotFound);
+        }
+
+        Ok(self.month(month_data, false))
+    }
+
+    fn month_net_totals(&self, month_id: Uuid) -> NetTotals {
+        self.months_net_totals
+            .get(&month_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn insert_month_net_totals(&mut self, month_id: Uuid, net_totals: &NetTotals) -> DbResult<()> {
+        if !self.months.contains_key(&month_id) {
+            return Err(DbError::NotFound);
+        }
+        Self::insert_net_totals(&mut self.months_net_totals, month_id, net_totals)
+    }
+}
+
+#[async_trait]
+impl MonthRepo for MemoryMonthRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_year_data_by_number(&self, year: i32) -> DbResult<YearData> {
+        self.store.lock().year_data_by_number(year)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_month_data_by_number(&self, month: MonthNum, year: i32) -> DbResult<MonthData> {
+        self.store.lock().month_data_by_number(month, year)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_months_of_year_without_resources(&self, year: i32) -> DbResult<Vec<Month>> {
+        Ok(self.store.lock().months_of_year_without_resources(year))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_months_of_year(&self, year: i32) -> DbResult<Vec<Month>> {
+        Ok(self.store.lock().months_of_year(year))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_months(&self) -> DbResult<Vec<Month>> {
+        let tables = self.store.lock();
+        Ok(tables
+            .months_data(|_| true)
+            .into_iter()
+            .map(|md| tables.month(md, true))
+            .filter(|m| !m.resources.is_empty())
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_months_starting_from(
+        &self,
+        month_num: MonthNum,
+        year: i32,
+    ) -> DbResult<Vec<Month>> {
+        Ok(self.store.lock().months_starting_from(month_num, year))
+    }
+
+    #[tracing::instrument(skip(self, month))]
+    async fn add(&self, month: &Month, year: i32) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        let year_data = tables.year_data_by_number(year)?;
+        if tables.months.contains_key(&month.id)
+            || tables.month_data_by_number(month.month, year).is_ok()
+        {
+            return Err(DbError::AlreadyExists);
+        }
+        tables.months.insert(
+            month.id,
+            MonthRow {
+                id: month.id,
+                month: month.month,
+                year_id: year_data.id,
+            },
+        );
+
+        tables.insert_month_net_totals(month.id, &month.net_totals)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_without_resources(&self, month_num: MonthNum, year: i32) -> DbResult<Month> {
+        self.store.lock().month_without_resources(month_num, year)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, month_num: MonthNum, year: i32) -> DbResult<Month> {
+        let tables = self.store.lock();
+        let mut month = tables.month_without_resources(month_num, year)?;
+        month.resources = tables.resources_of_month(month.id);
+
+        Ok(month)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_net_totals(&self, month_id: Uuid) -> DbResult<NetTotals> {
+        Ok(self.store.lock().month_net_totals(month_id))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_net_totals(&self, month_num: MonthNum, year: i32) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        let mut months = tables.months_starting_from(month_num, year);
+        if let Some(first_month) = months.first_mut() {
+            first_month.compute_net_totals();
+            let prev_year = match month_num.pred() {
+                MonthNum::December => year - 1,
+                _ => year,
+            };
+
+            if let Ok(prev_month) = tables.month_without_resources(month_num.pred(), prev_year) {
+                first_month.compute_variation(&prev_month);
+            }
+        }
+
+        for i in 1..months.len() {
+            let (prev_months, curr_months) = months.split_at_mut(i);
+            curr_months[0].compute_net_totals();
+            curr_months[0].compute_variation(&prev_months[i - 1]);
+        }
+
+        for month in months {
+            tables.insert_month_net_totals(month.id, &month.net_totals)?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, net_totals))]
+    async fn insert_net_totals(&self, month_id: Uuid, net_totals: &NetTotals) -> DbResult<()> {
+        self.store
+            .lock()
+            .insert_month_net_totals(month_id, net_totals)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, month_num: MonthNum, year: i32) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        if let Ok(month_data) = tables.month_data_by_number(month_num, year) {
+            tables.delete_month(month_data.id);
+        }
+
+        Ok(())
+    }
+}
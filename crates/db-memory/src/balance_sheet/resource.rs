@@ -0,0 +1,235 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DbResult, FinResOrderRepo, FinResRepo},
+    BaseFinancialResource, FinancialResourceMonthly, FinancialResourceYearly, MonthNum,
+    ResourceCategory, Uuid, YearlyBalances,
+};
+
+use crate::store::{MemoryStore, Tables};
+
+#[derive(Debug, Clone)]
+pub struct MemoryFinResRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryFinResRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn update_monthly(
+        &self,
+        resource: &FinancialResourceMonthly,
+        month: MonthNum,
+        year: i32,
+    ) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        tables.upsert_resource(&resource.base)?;
+        let month_data = tables.month_data_by_number(month, year)?;
+        tables
+            .resources_months
+            .insert((resource.base.id, month_data.id), resource.balance);
+
+        Ok(())
+    }
+}
+
+impl Tables {
+    /// Ordered by name. Like the joins of the SQL backends, resources without balances are left out.
+    pub(crate) fn resources_of_month(&self, month_id: Uuid) -> Vec<FinancialResourceMonthly> {
+        let mut resources: Vec<FinancialResourceMonthly> = self
+            .resources_months
+            .iter()
+            .filter(|((_, m), _)| *m == month_id)
+            .map(|((r, _), balance)| FinancialResourceMonthly {
+                base: self.resources[r].clone(),
+                balance: *balance,
+            })
+            .collect();
+        resources.sort_by(|a, b| a.base.name.cmp(&b.base.name));
+        resources
+    }
+
+    /// Ordered by name, with the balances of the months kept by the filter.
+    fn resources_yearly(
+        &self,
+        filter: impl Fn(&BaseFinancialResource, i32) -> bool,
+    ) -> Vec<FinancialResourceYearly> {
+        let mut resources: BTreeMap<Uuid, FinancialResourceYearly> = BTreeMap::new();
+
+        for ((resource_id, month_id), balance) in &self.resources_months {
+            let base = &self.resources[resource_id];
+            let month_data = self.month_data(&self.months[month_id]);
+            if !filter(base, month_data.year) {
+                continue;
+            }
+
+            resources
+                .entry(*resource_id)
+                .or_insert_with(|| FinancialResourceYearly {
+                    base: base.clone(),
+                    balances: BTreeMap::new(),
+                })
+                .insert_balance(month_data.year, month_data.month, *balance);
+        }
+
+        let mut resources: Vec<FinancialResourceYearly> = resources.into_values().collect();
+        resources.sort_by(|a, b| a.base.name.cmp(&b.base.name));
+        resources
+    }
+
+    fn upsert_resource(&mut self, base: &BaseFinancialResource) -> DbResult<()> {
+        if self
+            .resources
+            .values()
+            .any(|r| r.name == base.name && r.id != base.id)
+        {
+            return Err(DbError::AlreadyExists);
+        }
+        self.resources.insert(base.id, base.clone());
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FinResRepo for MemoryFinResRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_from_all_years(&self) -> DbResult<Vec<FinancialResourceYearly>> {
+        Ok(self.store.lock().resources_yearly(|_, _| true))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_from_year(&self, year: i32) -> DbResult<Vec<FinancialResourceYearly>> {
+        Ok(self.store.lock().resources_yearly(|_, y| y == year))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_from_year_and_category(
+        &self,
+        year: i32,
+        category: &ResourceCategory,
+    ) -> DbResult<Vec<FinancialResourceYearly>> {
+        Ok(self
+            .store
+            .lock()
+            .resources_yearly(|r, y| y == year && r.resource_type.category() == *category))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_from_month(
+        &self,
+        month: MonthNum,
+        year: i32,
+    ) -> DbResult<Vec<FinancialResourceMonthly>> {
+        let tables = self.store.lock();
+        Ok(match tables.month_data_by_number(month, year) {
+            Ok(month_data) => tables.resources_of_month(month_data.id),
+            Err(_) => vec![],
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, resource_id: Uuid) -> DbResult<FinancialResourceYearly> {
+        self.store
+            .lock()
+            .resources_yearly(|r, _| r.id == resource_id)
+            .pop()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_name(&self, name: &str) -> DbResult<FinancialResourceYearly> {
+        self.store
+            .lock()
+            .resources_yearly(|r, _| r.name == name)
+            .pop()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update(&self, resource: &FinancialResourceYearly) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        tables.upsert_resource(&resource.base)?;
+
+        for (year, month, balance) in resource.iter_balances() {
+            let month_data = tables.month_data_by_number(month, year)?;
+            tables
+                .resources_months
+                .insert((resource.base.id, month_data.id), balance);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update_and_delete(&self, resource: &FinancialResourceYearly) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        tables.upsert_resource(&resource.base)?;
+
+        for (year, month, balance) in resource.iter_all_balances() {
+            let month_data = tables.month_data_by_number(month, year)?;
+            let key = (resource.base.id, month_data.id);
+            match balance {
+                Some(balance) => tables.resources_months.insert(key, balance),
+                None => tables.resources_months.remove(&key),
+            };
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, resource_id: Uuid) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        tables.resources.remove(&resource_id);
+        tables
+            .resources_months
+            .retain(|(r, _), _| *r != resource_id);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryFinResOrderRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryFinResOrderRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl FinResOrderRepo for MemoryFinResOrderRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_order(&self, year: i32, category: &ResourceCategory) -> DbResult<Vec<Uuid>> {
+        Ok(self
+            .store
+            .lock()
+            .resources_order
+            .get(&Tables::order_key(year, category))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_order(
+        &self,
+        year: i32,
+        category: &ResourceCategory,
+        order: &[Uuid],
+    ) -> DbResult<()> {
+        self.store
+            .lock()
+            .resources_order
+            .insert(Tables::order_key(year, category), order.to_vec());
+
+        Ok(())
+    }
+}
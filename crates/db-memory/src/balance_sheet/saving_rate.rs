@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DbResult, SavingRateRepo},
+    SavingRate, Uuid,
+};
+use ynab::Milliunits;
+
+use crate::store::{MemoryStore, SavingRateRow, Tables};
+
+#[derive(Debug, Clone)]
+pub struct MemorySavingRateRepo {
+    pub store: MemoryStore,
+}
+
+impl MemorySavingRateRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+impl Tables {
+    /// Only what the SQL backends store is kept, totals and rates are computed by the services.
+    fn saving_rate(&self, row: &SavingRateRow) -> SavingRate {
+        let mut saving_rate = row.saving_rate.clone();
+        saving_rate.year = self.years[&row.year_id].year;
+        saving_rate.savings.total = Milliunits::ZERO;
+        saving_rate.incomes.total = Milliunits::ZERO;
+        saving_rate.rate = 0.0;
+        saving_rate.months = vec![];
+        saving_rate
+    }
+
+    fn saving_rates(&self, filter: impl Fn(&SavingRate) -> bool) -> Vec<SavingRate> {
+        self.saving_rates
+            .values()
+            .map(|row| self.saving_rate(row))
+            .filter(filter)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl SavingRateRepo for MemorySavingRateRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_from_year(&self, year: i32) -> DbResult<Vec<SavingRate>> {
+        Ok(self.store.lock().saving_rates(|sr| sr.year == year))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, saving_rate_id: Uuid) -> DbResult<SavingRate> {
+        let tables = self.store.lock();
+        tables
+            .saving_rates
+            .get(&saving_rate_id)
+            .map(|row| tables.saving_rate(row))
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_name(&self, name: &str) -> DbResult<SavingRate> {
+        self.store
+            .lock()
+            .saving_rates(|sr| sr.name == name)
+            .into_iter()
+            .next()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<SavingRate>> {
+        let mut saving_rates = self.store.lock().saving_rates(|_| true);
+        saving_rates.sort_by(|a, b| a.year.cmp(&b.year).then_with(|| a.name.cmp(&b.name)));
+
+        Ok(saving_rates)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_by_name(&self, name: &str) -> DbResult<Vec<SavingRate>> {
+        let mut saving_rates = self.store.lock().saving_rates(|sr| sr.name == name);
+        saving_rates.sort_by_key(|sr| sr.year);
+
+        Ok(saving_rates)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update(&self, saving_rate: &SavingRate) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        let year_data = tables.year_data_by_number(saving_rate.year)?;
+        tables.saving_rates.insert(
+            saving_rate.id,
+            SavingRateRow {
+                saving_rate: saving_rate.clone(),
+                year_id: year_data.id,
+            },
+        );
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, saving_rate_id: Uuid) -> DbResult<()> {
+        self.store.lock().saving_rates.remove(&saving_rate_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DbResult, YearData, YearRepo},
+    NetTotals, Uuid, Year,
+};
+
+use crate::store::{MemoryStore, Tables};
+
+#[derive(Debug, Clone)]
+pub struct MemoryYearRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryYearRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+impl Tables {
+    fn year(&self, year_data: YearData, with_resources: bool) -> Year {
+        let months = if with_resources {
+            self.months_of_year(year_data.year)
+        } else {
+            self.months_of_year_without_resources(year_data.year)
+        };
+
+        Year {
+            id: year_data.id,
+            year: year_data.year,
+            refreshed_at: year_data.refreshed_at,
+            net_totals: self.year_net_totals(year_data.id),
+            months,
+        }
+    }
+
+    fn years_data(&self, filter: impl Fn(&YearData) -> bool) -> Vec<YearData> {
+        let mut years: Vec<YearData> = self.years.values().filter(|y| filter(y)).copied().collect();
+        years.sort_by_key(|y| y.year);
+        years
+    }
+
+    fn year_net_totals(&self, year_id: Uuid) -> NetTotals {
+        self.years_net_totals
+            .get(&year_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn insert_year_net_totals(&mut self, year_id: Uuid, net_totals: &NetTotals) -> DbResult<()> {
+        if !self.years.contains_key(&year_id) {
+            return Err(DbError::NotFound);
+        }
+        Self::insert_net_totals(&mut self.years_net_totals, year_id, net_totals)
+    }
+}
+
+#[async_trait]
+impl YearRepo for MemoryYearRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_years(&self) -> DbResult<Vec<Year>> {
+        let tables = self.store.lock();
+        Ok(tables
+            .years_data(|_| true)
+            .into_iter()
+            .map(|yd| tables.year(yd, true))
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_years_data(&self) -> DbResult<Vec<YearData>> {
+        Ok(self.store.lock().years_data(|_| true))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_years_starting_from(&self, year: i32) -> DbResult<Vec<Year>> {
+        let tables = self.store.lock();
+        Ok(tables
+            .years_data(|y| y.year >= year)
+            .into_iter()
+            .map(|yd| tables.year(yd, false))
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_year_data_by_number(&self, year: i32) -> DbResult<YearData> {
+        self.store.lock().year_data_by_number(year)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn add(&self, year: &Year) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        if tables.years.contains_key(&year.id) || tables.year_data_by_number(year.year).is_ok() {
+            return Err(DbError::AlreadyExists);
+        }
+        tables.years.insert(
+            year.id,
+            YearData {
+                id: year.id,
+                year: year.year,
+                refreshed_at: year.refreshed_at,
+            },
+        );
+
+        tables.insert_year_net_totals(year.id, &year.net_totals)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_without_resources(&self, year: i32) -> DbResult<Year> {
+        let tables = self.store.lock();
+        let year_data = tables.year_data_by_number(year)?;
+        Ok(tables.year(year_data, false))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, year: i32) -> DbResult<Year> {
+        let tables = self.store.lock();
+        let year_data = tables.year_data_by_number(year)?;
+        Ok(tables.year(year_data, true))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_net_totals(&self, year_id: Uuid) -> DbResult<NetTotals> {
+        Ok(self.store.lock().year_net_totals(year_id))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_net_totals(&self, year: i32) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        let mut years: Vec<Year> = tables
+            .years_data(|y| y.year >= year)
+            .into_iter()
+            .map(|yd| tables.year(yd, false))
+            .collect();
+
+        if let Some(first_year) = years.first_mut() {
+            first_year.update_net_totals();
+
+            if let Ok(prev_year) = tables.year_data_by_number(year - 1) {
+                first_year.compute_variation(&tables.year(prev_year, false));
+            }
+        }
+
+        for i in 1..years.len() {
+            let (prev_years, curr_years) = years.split_at_mut(i);
+            curr_years[0].update_net_totals();
+            curr_years[0].compute_variation(&prev_years[i - 1]);
+        }
+
+        for year in years {
+            tables.insert_year_net_totals(year.id, &year.net_totals)?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, net_totals))]
+    async fn insert_net_totals(&self, year_id: Uuid, net_totals: &NetTotals) -> DbResult<()> {
+        self.store
+            .lock()
+            .insert_year_net_totals(year_id, net_totals)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update_refreshed_at(&self, year: &YearData) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        match tables.years.get_mut(&year.id) {
+            Some(existing) => existing.refreshed_at = year.refreshed_at,
+            None => {
+                if tables.year_data_by_number(year.year).is_ok() {
+                    return Err(DbError::AlreadyExists);
+                }
+                tables.years.insert(year.id, *year);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, year: i32) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        if let Ok(year_data) = tables.year_data_by_number(year) {
+            tables.delete_year(year_data.id);
+        }
+
+        Ok(())
+    }
+}
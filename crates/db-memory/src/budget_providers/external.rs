@@ -0,0 +1,259 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{
+        external::{
+            EncryptionKeyRepo, ExternalAccountBalanceRepo, ExternalAccountRefreshRepo,
+            ExternalAccountRepo,
+        },
+        DbError, DbResult,
+    },
+    DateTime, ExternalAccountBalance, ExternalAccountRefresh, Utc, Uuid, WebScrapingAccount,
+};
+
+use crate::store::MemoryStore;
+
+#[derive(Debug, Clone)]
+pub struct MemoryExternalAccountRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryExternalAccountRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl ExternalAccountRepo for MemoryExternalAccountRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<WebScrapingAccount>> {
+        Ok(self
+            .store
+            .lock()
+            .external_accounts
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, account_id: Uuid) -> DbResult<WebScrapingAccount> {
+        self.store
+            .lock()
+            .external_accounts
+            .get(&account_id)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_name(&self, name: &str) -> DbResult<WebScrapingAccount> {
+        self.store
+            .lock()
+            .external_accounts
+            .values()
+            .find(|a| a.name == name)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn add(&self, account: &WebScrapingAccount) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        if tables.external_accounts.contains_key(&account.id) {
+            return Err(DbError::AlreadyExists);
+        }
+        tables.external_accounts.insert(account.id, account.clone());
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update(&self, account: &WebScrapingAccount) -> DbResult<()> {
+        self.store
+            .lock()
+            .external_accounts
+            .insert(account.id, account.clone());
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, account_id: Uuid) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        tables.external_accounts.remove(&account_id);
+        tables
+            .external_account_refreshes
+            .retain(|_, r| r.account_id != account_id);
+        tables
+            .external_account_balances
+            .retain(|_, b| b.account_id != account_id);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryExternalAccountRefreshRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryExternalAccountRefreshRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl ExternalAccountRefreshRepo for MemoryExternalAccountRefreshRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self, account_id: Uuid) -> DbResult<Vec<ExternalAccountRefresh>> {
+        let mut refreshes: Vec<_> = self
+            .store
+            .lock()
+            .external_account_refreshes
+            .values()
+            .filter(|r| r.account_id == account_id)
+            .cloned()
+            .collect();
+        refreshes.sort_by_key(|r| r.refreshed_at);
+
+        Ok(refreshes)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last(&self, account_id: Uuid) -> DbResult<ExternalAccountRefresh> {
+        self.store
+            .lock()
+            .external_account_refreshes
+            .values()
+            .filter(|r| r.account_id == account_id)
+            .max_by_key(|r| r.refreshed_at)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last_success(&self, account_id: Uuid) -> DbResult<ExternalAccountRefresh> {
+        self.store
+            .lock()
+            .external_account_refreshes
+            .values()
+            .filter(|r| r.account_id == account_id && r.succeeded)
+            .max_by_key(|r| r.refreshed_at)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add(&self, refresh: &ExternalAccountRefresh) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        if tables.external_account_refreshes.contains_key(&refresh.id) {
+            return Err(DbError::AlreadyExists);
+        }
+        if !tables.external_accounts.contains_key(&refresh.account_id) {
+            return Err(DbError::NotFound);
+        }
+        tables
+            .external_account_refreshes
+            .insert(refresh.id, refresh.clone());
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryExternalAccountBalanceRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryExternalAccountBalanceRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl ExternalAccountBalanceRepo for MemoryExternalAccountBalanceRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(
+        &self,
+        account_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> DbResult<Vec<ExternalAccountBalance>> {
+        let mut balances: Vec<_> = self
+            .store
+            .lock()
+            .external_account_balances
+            .values()
+            .filter(|b| {
+                b.account_id == account_id
+                    && from.map_or(true, |from| b.observed_at >= from)
+                    && to.map_or(true, |to| b.observed_at < to)
+            })
+            .cloned()
+            .collect();
+        balances.sort_by_key(|b| b.observed_at);
+
+        Ok(balances)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last(
+        &self,
+        account_id: Uuid,
+        to: Option<DateTime<Utc>>,
+    ) -> DbResult<ExternalAccountBalance> {
+        self.store
+            .lock()
+            .external_account_balances
+            .values()
+            .filter(|b| b.account_id == account_id && to.map_or(true, |to| b.observed_at < to))
+            .max_by_key(|b| b.observed_at)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add(&self, balance: &ExternalAccountBalance) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        if tables.external_account_balances.contains_key(&balance.id) {
+            return Err(DbError::AlreadyExists);
+        }
+        if !tables.external_accounts.contains_key(&balance.account_id) {
+            return Err(DbError::NotFound);
+        }
+        tables
+            .external_account_balances
+            .insert(balance.id, balance.clone());
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryEncryptionKeyRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryEncryptionKeyRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl EncryptionKeyRepo for MemoryEncryptionKeyRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get(&self) -> DbResult<Vec<u8>> {
+        self.store.get_secret("encryption_key")
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn set(&self, encryption_key_str: &[u8]) -> DbResult<()> {
+        self.store.set_secret("encryption_key", encryption_key_str)
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{ynab::YnabAccountRepo, DbResult},
+};
+use ynab::types::Account;
+
+use crate::store::{BudgetRow, MemoryStore};
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabAccountRepo {
+    pub store: MemoryStore,
+    pub budget_id: String,
+}
+
+impl MemoryYnabAccountRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            store,
+            budget_id: budget_id.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabAccountRepo for MemoryYnabAccountRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<Account>> {
+        let tables = self.store.lock();
+        Ok(BudgetRow::values_of(&tables.accounts, &self.budget_id)
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update_all(&self, accounts: &[Account]) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        for account in accounts {
+            BudgetRow::upsert(&mut tables.accounts, &self.budget_id, account.id, account);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{ynab::YnabCategoryRepo, DbError, DbResult},
+    Uuid,
+};
+use ynab::types::Category;
+
+use crate::store::{BudgetRow, MemoryStore};
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabCategoryRepo {
+    pub store: MemoryStore,
+    pub budget_id: String,
+}
+
+impl MemoryYnabCategoryRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            store,
+            budget_id: budget_id.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabCategoryRepo for MemoryYnabCategoryRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<Category>> {
+        let tables = self.store.lock();
+        Ok(BudgetRow::values_of(&tables.categories, &self.budget_id)
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, category_id: Uuid) -> DbResult<Category> {
+        let tables = self.store.lock();
+        let category = BudgetRow::values_of(&tables.categories, &self.budget_id)
+            .find(|c| c.id == category_id)
+            .cloned();
+
+        category.ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update_all(&self, categories: &[Category]) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        for c in categories {
+            BudgetRow::upsert(&mut tables.categories, &self.budget_id, c.id, c);
+        }
+
+        Ok(())
+    }
+}
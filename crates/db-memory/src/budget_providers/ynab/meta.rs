@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{
+        ynab::{
+            YnabAccountMetaRepo, YnabCategoryMetaRepo, YnabPayeeMetaRepo,
+            YnabScheduledTransactionMetaRepo, YnabTransactionMetaRepo,
+        },
+        DbError, DbResult,
+    },
+};
+
+use crate::store::{MemoryStore, YnabMetaRow};
+
+/// Delta requests state of a YNAB resource, stored like the `ynab_meta` table.
+#[derive(Debug, Clone)]
+struct YnabMeta {
+    store: MemoryStore,
+    budget_id: String,
+    resource: &'static str,
+}
+
+impl YnabMeta {
+    fn new(store: MemoryStore, budget_id: &str, resource: &'static str) -> Self {
+        Self {
+            store,
+            budget_id: budget_id.to_string(),
+            resource,
+        }
+    }
+
+    fn key(&self) -> (String, &'static str) {
+        (self.budget_id.clone(), self.resource)
+    }
+
+    fn get(&self) -> Option<YnabMetaRow> {
+        self.store.lock().ynab_meta.get(&self.key()).cloned()
+    }
+
+    fn get_delta(&self) -> DbResult<i64> {
+        self.get()
+            .and_then(|m| m.server_knowledge)
+            .ok_or(DbError::NotFound)
+    }
+
+    fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.store
+            .lock()
+            .ynab_meta
+            .entry(self.key())
+            .or_default()
+            .server_knowledge = Some(server_knowledge);
+
+        Ok(())
+    }
+
+    fn del_delta(&self) -> DbResult<()> {
+        if let Some(meta) = self.store.lock().ynab_meta.get_mut(&self.key()) {
+            meta.server_knowledge = None;
+        }
+
+        Ok(())
+    }
+
+    fn get_last_saved(&self) -> DbResult<String> {
+        self.get()
+            .and_then(|m| m.last_saved)
+            .ok_or(DbError::NotFound)
+    }
+
+    fn set_last_saved(&self, last_saved: String) -> DbResult<()> {
+        self.store
+            .lock()
+            .ynab_meta
+            .entry(self.key())
+            .or_default()
+            .last_saved = Some(last_saved);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabCategoryMetaRepo {
+    meta: YnabMeta,
+}
+
+impl MemoryYnabCategoryMetaRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(store, budget_id, "categories"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabCategoryMetaRepo for MemoryYnabCategoryMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn del_delta(&self) -> DbResult<()> {
+        self.meta.del_delta()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last_saved(&self) -> DbResult<String> {
+        self.meta.get_last_saved()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_last_saved(&self, last_saved: String) -> DbResult<()> {
+        self.meta.set_last_saved(last_saved)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabScheduledTransactionMetaRepo {
+    meta: YnabMeta,
+}
+
+impl MemoryYnabScheduledTransactionMetaRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(store, budget_id, "scheduled_transactions"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabScheduledTransactionMetaRepo for MemoryYnabScheduledTransactionMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn del_delta(&self) -> DbResult<()> {
+        self.meta.del_delta()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last_saved(&self) -> DbResult<String> {
+        self.meta.get_last_saved()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_last_saved(&self, last_saved: String) -> DbResult<()> {
+        self.meta.set_last_saved(last_saved)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabAccountMetaRepo {
+    meta: YnabMeta,
+}
+
+impl MemoryYnabAccountMetaRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(store, budget_id, "accounts"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabAccountMetaRepo for MemoryYnabAccountMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabPayeeMetaRepo {
+    meta: YnabMeta,
+}
+
+impl MemoryYnabPayeeMetaRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(store, budget_id, "payees"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabPayeeMetaRepo for MemoryYnabPayeeMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabTransactionMetaRepo {
+    meta: YnabMeta,
+}
+
+impl MemoryYnabTransactionMetaRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(store, budget_id, "transactions"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabTransactionMetaRepo for MemoryYnabTransactionMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge)
+    }
+}
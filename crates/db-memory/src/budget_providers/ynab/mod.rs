@@ -0,0 +1,15 @@
+mod account;
+mod category;
+mod meta;
+mod oauth;
+mod payee;
+mod scheduled_transaction;
+mod transaction;
+
+pub use account::*;
+pub use category::*;
+pub use meta::*;
+pub use oauth::*;
+pub use payee::*;
+pub use scheduled_transaction::*;
+pub use transaction::*;
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{ynab::YnabOAuthTokenRepo, DbResult},
+};
+
+use crate::store::MemoryStore;
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabOAuthTokenRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryYnabOAuthTokenRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl YnabOAuthTokenRepo for MemoryYnabOAuthTokenRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, member: &str) -> DbResult<Vec<u8>> {
+        self.store
+            .get_secret(&format!("ynab_oauth_tokens_{}", member))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn set(&self, member: &str, encrypted_tokens: &[u8]) -> DbResult<()> {
+        self.store
+            .set_secret(&format!("ynab_oauth_tokens_{}", member), encrypted_tokens)
+    }
+}
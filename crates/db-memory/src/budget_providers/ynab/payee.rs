@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{ynab::YnabPayeeRepo, DbResult},
+};
+use ynab::types::Payee;
+
+use crate::store::{BudgetRow, MemoryStore};
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabPayeeRepo {
+    pub store: MemoryStore,
+    pub budget_id: String,
+}
+
+impl MemoryYnabPayeeRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            store,
+            budget_id: budget_id.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabPayeeRepo for MemoryYnabPayeeRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<Payee>> {
+        let tables = self.store.lock();
+        Ok(BudgetRow::values_of(&tables.payees, &self.budget_id)
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update_all(&self, payees: &[Payee]) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        for payee in payees {
+            BudgetRow::upsert(&mut tables.payees, &self.budget_id, payee.id, payee);
+        }
+
+        Ok(())
+    }
+}
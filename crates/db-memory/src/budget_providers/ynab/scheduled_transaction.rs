@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{ynab::YnabScheduledTransactionRepo, DbResult},
+};
+use ynab::types::ScheduledTransactionDetail;
+
+use crate::store::{BudgetRow, MemoryStore};
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabScheduledTransactionRepo {
+    pub store: MemoryStore,
+    pub budget_id: String,
+}
+
+impl MemoryYnabScheduledTransactionRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            store,
+            budget_id: budget_id.to_string(),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabScheduledTransactionRepo for MemoryYnabScheduledTransactionRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<ScheduledTransactionDetail>> {
+        let tables = self.store.lock();
+        Ok(
+            BudgetRow::values_of(&tables.scheduled_transactions, &self.budget_id)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update_all(
+        &self,
+        scheduled_transactions: &[ScheduledTransactionDetail],
+    ) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        for st in scheduled_transactions {
+            if st.deleted {
+                if tables
+                    .scheduled_transactions
+                    .get(&st.id)
+                    .is_some_and(|row| row.budget_id == self.budget_id)
+                {
+                    tables.scheduled_transactions.remove(&st.id);
+                }
+            } else {
+                BudgetRow::upsert(
+                    &mut tables.scheduled_transactions,
+                    &self.budget_id,
+                    st.id,
+                    st,
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
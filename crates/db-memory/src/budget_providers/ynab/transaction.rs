@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{ynab::YnabTransactionRepo, DbResult},
+    Uuid,
+};
+use ynab::TransactionDetail;
+
+use crate::store::{BudgetRow, MemoryStore};
+
+#[derive(Debug, Clone)]
+pub struct MemoryYnabTransactionRepo {
+    pub store: MemoryStore,
+    pub budget_id: String,
+}
+
+impl MemoryYnabTransactionRepo {
+    pub fn new_arced(store: MemoryStore, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            store,
+            budget_id: budget_id.to_string(),
+        })
+    }
+
+    fn get_all_with(&self, filter: impl Fn(&TransactionDetail) -> bool) -> Vec<TransactionDetail> {
+        let tables = self.store.lock();
+        BudgetRow::values_of(&tables.transactions, &self.budget_id)
+            .filter(|t| filter(t))
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl YnabTransactionRepo for MemoryYnabTransactionRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<TransactionDetail>> {
+        Ok(self.get_all_with(|_| true))
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update_all(&self, transactions: &[TransactionDetail]) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        for t in transactions {
+            BudgetRow::upsert(&mut tables.transactions, &self.budget_id, t.base.id, t);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_with_payee_id(&self, payee_id: Uuid) -> DbResult<Vec<TransactionDetail>> {
+        Ok(self.get_all_with(|t| t.base.payee_id == Some(payee_id)))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_with_category_id(
+        &self,
+        category_id: Uuid,
+    ) -> DbResult<Vec<TransactionDetail>> {
+        Ok(self.get_all_with(|t| t.base.category_id == Some(category_id)))
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{BudgeterConfigRepo, DbError, DbResult},
+    BudgeterConfig, Uuid,
+};
+
+use crate::store::MemoryStore;
+
+#[derive(Debug, Clone)]
+pub struct MemoryBudgeterConfigRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryBudgeterConfigRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl BudgeterConfigRepo for MemoryBudgeterConfigRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<BudgeterConfig>> {
+        Ok(self
+            .store
+            .lock()
+            .budgeters_config
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, budgeter_id: Uuid) -> DbResult<BudgeterConfig> {
+        self.store
+            .lock()
+            .budgeters_config
+            .get(&budgeter_id)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_by_name(&self, name: &str) -> DbResult<BudgeterConfig> {
+        self.store
+            .lock()
+            .budgeters_config
+            .values()
+            .find(|b| b.name == name)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update(&self, budgeter: &BudgeterConfig) -> DbResult<()> {
+        self.store
+            .lock()
+            .budgeters_config
+            .insert(budgeter.id, budgeter.clone());
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, budgeter_id: Uuid) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        tables.budgeters_config.remove(&budgeter_id);
+        tables
+            .expenses_association
+            .retain(|_, a| a.budgeter_id != Some(budgeter_id));
+
+        Ok(())
+    }
+}
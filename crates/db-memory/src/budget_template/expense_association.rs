@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DbResult, ExpenseAssociationRepo},
+    ExpenseAssociation, Uuid,
+};
+
+use crate::store::MemoryStore;
+
+#[derive(Debug, Clone)]
+pub struct MemoryExpenseAssociationRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryExpenseAssociationRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl ExpenseAssociationRepo for MemoryExpenseAssociationRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<ExpenseAssociation>> {
+        Ok(self
+            .store
+            .lock()
+            .expenses_association
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, id: Uuid) -> DbResult<ExpenseAssociation> {
+        self.store
+            .lock()
+            .expenses_association
+            .get(&id)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update(&self, expense_association: &ExpenseAssociation) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        if let Some(budgeter_id) = expense_association.budgeter_id {
+            if !tables.budgeters_config.contains_key(&budgeter_id) {
+                return Err(DbError::NotFound);
+            }
+        }
+        tables
+            .expenses_association
+            .insert(expense_association.id, expense_association.clone());
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DbResult<()> {
+        self.store.lock().expenses_association.remove(&id);
+
+        Ok(())
+    }
+}
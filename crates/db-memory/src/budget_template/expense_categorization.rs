@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DbResult, ExpenseCategorizationRepo},
+    ExpenseCategorization, Uuid,
+};
+
+use crate::store::MemoryStore;
+
+#[derive(Debug, Clone)]
+pub struct MemoryExpenseCategorizationRepo {
+    pub store: MemoryStore,
+}
+
+impl MemoryExpenseCategorizationRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl ExpenseCategorizationRepo for MemoryExpenseCategorizationRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<ExpenseCategorization>> {
+        Ok(self
+            .store
+            .lock()
+            .expenses_categorization
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, id: Uuid) -> DbResult<ExpenseCategorization> {
+        self.store
+            .lock()
+            .expenses_categorization
+            .get(&id)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update_all(&self, expenses_categorization: &[ExpenseCategorization]) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        for expense_categorization in expenses_categorization {
+            tables
+                .expenses_categorization
+                .insert(expense_categorization.id, expense_categorization.clone());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update(&self, expense_categorization: &ExpenseCategorization) -> DbResult<()> {
+        self.store
+            .lock()
+            .expenses_categorization
+            .insert(expense_categorization.id, expense_categorization.clone());
+
+        Ok(())
+    }
+}
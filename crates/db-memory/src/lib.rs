@@ -0,0 +1,9 @@
+pub mod balance_sheet;
+pub mod budget_providers;
+pub mod budget_template;
+mod registry;
+pub mod scheduler;
+mod store;
+
+pub use registry::*;
+pub use store::*;
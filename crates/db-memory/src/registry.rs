@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use crate::{
+    balance_sheet::{
+        MemoryFinResOrderRepo, MemoryFinResRepo, MemoryMonthRepo, MemorySavingRateRepo,
+        MemoryYearRepo,
+    },
+    budget_providers::{
+        external::{
+            MemoryEncryptionKeyRepo, MemoryExternalAccountBalanceRepo,
+            MemoryExternalAccountRefreshRepo, MemoryExternalAccountRepo,
+        },
+        ynab::{
+            MemoryYnabAccountMetaRepo, MemoryYnabAccountRepo, MemoryYnabCategoryMetaRepo,
+            MemoryYnabCategoryRepo, MemoryYnabOAuthTokenRepo, MemoryYnabPayeeMetaRepo,
+            MemoryYnabPayeeRepo, MemoryYnabScheduledTransactionMetaRepo,
+            MemoryYnabScheduledTransactionRepo, MemoryYnabTransactionMetaRepo,
+            MemoryYnabTransactionRepo,
+        },
+    },
+    budget_template::{
+        MemoryBudgeterConfigRepo, MemoryExpenseAssociationRepo, MemoryExpenseCategorizationRepo,
+    },
+    scheduler::MemorySchedulerRunRepo,
+    store::MemoryStore,
+};
+use datamize_domain::db::{
+    external::{
+        DynEncryptionKeyRepo, DynExternalAccountBalanceRepo, DynExternalAccountRefreshRepo,
+        DynExternalAccountRepo,
+    },
+    ynab::{
+        DynYnabAccountMetaRepo, DynYnabAccountRepo, DynYnabCategoryMetaRepo, DynYnabCategoryRepo,
+        DynYnabOAuthTokenRepo, DynYnabPayeeMetaRepo, DynYnabPayeeRepo,
+        DynYnabScheduledTransactionMetaRepo, DynYnabScheduledTransactionRepo,
+        DynYnabTransactionMetaRepo, DynYnabTransactionRepo,
+    },
+    DynBudgeterConfigRepo, DynExpenseAssociationRepo, DynExpenseCategorizationRepo,
+    DynFinResOrderRepo, DynFinResRepo, DynMonthRepo, DynSavingRateRepo, DynSchedulerRunRepo,
+    DynYearRepo, RepoRegistry,
+};
+
+/// Everything in memory, lost when the last repository is dropped. Meant for tests.
+#[derive(Clone)]
+pub struct MemoryRepoRegistry {
+    pub store: MemoryStore,
+}
+
+impl MemoryRepoRegistry {
+    pub fn new_arced() -> Arc<Self> {
+        Arc::new(Self {
+            store: MemoryStore::new(),
+        })
+    }
+}
+
+impl RepoRegistry for MemoryRepoRegistry {
+    fn year_repo(&self) -> DynYearRepo {
+        MemoryYearRepo::new_arced(self.store.clone())
+    }
+
+    fn month_repo(&self) -> DynMonthRepo {
+        MemoryMonthRepo::new_arced(self.store.clone())
+    }
+
+    fn fin_res_repo(&self) -> DynFinResRepo {
+        MemoryFinResRepo::new_arced(self.store.clone())
+    }
+
+    fn fin_res_order_repo(&self) -> DynFinResOrderRepo {
+        MemoryFinResOrderRepo::new_arced(self.store.clone())
+    }
+
+    fn saving_rate_repo(&self) -> DynSavingRateRepo {
+        MemorySavingRateRepo::new_arced(self.store.clone())
+    }
+
+    fn external_account_repo(&self) -> DynExternalAccountRepo {
+        MemoryExternalAccountRepo::new_arced(self.store.clone())
+    }
+
+    fn external_account_refresh_repo(&self) -> DynExternalAccountRefreshRepo {
+        MemoryExternalAccountRefreshRepo::new_arced(self.store.clone())
+    }
+
+    fn external_account_balance_repo(&self) -> DynExternalAccountBalanceRepo {
+        MemoryExternalAccountBalanceRepo::new_arced(self.store.clone())
+    }
+
+    fn encryption_key_repo(&self) -> DynEncryptionKeyRepo {
+        MemoryEncryptionKeyRepo::new_arced(self.store.clone())
+    }
+
+    fn budgeter_config_repo(&self) -> DynBudgeterConfigRepo {
+        MemoryBudgeterConfigRepo::new_arced(self.store.clone())
+    }
+
+    fn expense_categorization_repo(&self) -> DynExpenseCategorizationRepo {
+        MemoryExpenseCategorizationRepo::new_arced(self.store.clone())
+    }
+
+    fn expense_association_repo(&self) -> DynExpenseAssociationRepo {
+        MemoryExpenseAssociationRepo::new_arced(self.store.clone())
+    }
+
+    fn ynab_oauth_token_repo(&self) -> DynYnabOAuthTokenRepo {
+        MemoryYnabOAuthTokenRepo::new_arced(self.store.clone())
+    }
+
+    fn scheduler_run_repo(&self) -> DynSchedulerRunRepo {
+        MemorySchedulerRunRepo::new_arced(self.store.clone())
+    }
+
+    fn ynab_category_repo(&self, budget_id: &str) -> DynYnabCategoryRepo {
+        MemoryYnabCategoryRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_category_meta_repo(&self, budget_id: &str) -> DynYnabCategoryMetaRepo {
+        MemoryYnabCategoryMetaRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_scheduled_transaction_repo(&self, budget_id: &str) -> DynYnabScheduledTransactionRepo {
+        MemoryYnabScheduledTransactionRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_scheduled_transaction_meta_repo(
+        &self,
+        budget_id: &str,
+    ) -> DynYnabScheduledTransactionMetaRepo {
+        MemoryYnabScheduledTransactionMetaRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_account_repo(&self, budget_id: &str) -> DynYnabAccountRepo {
+        MemoryYnabAccountRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_account_meta_repo(&self, budget_id: &str) -> DynYnabAccountMetaRepo {
+        MemoryYnabAccountMetaRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_payee_repo(&self, budget_id: &str) -> DynYnabPayeeRepo {
+        MemoryYnabPayeeRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_payee_meta_repo(&self, budget_id: &str) -> DynYnabPayeeMetaRepo {
+        MemoryYnabPayeeMetaRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_transaction_repo(&self, budget_id: &str) -> DynYnabTransactionRepo {
+        MemoryYnabTransactionRepo::new_arced(self.store.clone(), budget_id)
+    }
+
+    fn ynab_transaction_meta_repo(&self, budget_id: &str) -> DynYnabTransactionMetaRepo {
+        MemoryYnabTransactionMetaRepo::new_arced(self.store.clone(), budget_id)
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DbResult, SchedulerRunRepo},
+    ScheduledJob, SchedulerRun,
+};
+
+use crate::store::MemoryStore;
+
+#[derive(Debug, Clone)]
+pub struct MemorySchedulerRunRepo {
+    pub store: MemoryStore,
+}
+
+impl MemorySchedulerRunRepo {
+    pub fn new_arced(store: MemoryStore) -> Arc<Self> {
+        Arc::new(Self { store })
+    }
+}
+
+#[async_trait]
+impl SchedulerRunRepo for MemorySchedulerRunRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_latest(&self, limit: u32) -> DbResult<Vec<SchedulerRun>> {
+        let tables = self.store.lock();
+        let mut runs: Vec<_> = tables.scheduler_runs.values().cloned().collect();
+        runs.sort_by_key(|r| std::cmp::Reverse(r.started_at));
+        runs.truncate(limit as usize);
+
+        Ok(runs)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last(&self, job: ScheduledJob) -> DbResult<SchedulerRun> {
+        self.store
+            .lock()
+            .scheduler_runs
+            .values()
+            .filter(|r| r.job == job)
+            .max_by_key(|r| r.started_at)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add(&self, run: &SchedulerRun) -> DbResult<()> {
+        let mut tables = self.store.lock();
+        if tables.scheduler_runs.contains_key(&run.id) {
+            return Err(DbError::AlreadyExists);
+        }
+        tables.scheduler_runs.insert(run.id, run.clone());
+
+        Ok(())
+    }
+}
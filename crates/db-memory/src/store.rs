@@ -0,0 +1,214 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use datamize_domain::{
+    db::{DbError, DbResult, MonthData, YearData},
+    BaseFinancialResource, BudgeterConfig, ExpenseAssociation, ExpenseCategorization,
+    ExternalAccountBalance, ExternalAccountRefresh, MonthNum, NetTotals, ResourceCategory,
+    SavingRate, SchedulerRun, Uuid, WebScrapingAccount,
+};
+use ynab::{
+    types::{Account, Category, Payee, ScheduledTransactionDetail},
+    Milliunits, TransactionDetail,
+};
+
+/// Tables of the in-memory backend, shared by all the repositories built from the same store.
+/// They mirror the SQL schema, constraints and cascades included, so the repositories behave
+/// like the SQL ones.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore {
+    tables: Arc<Mutex<Tables>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Never held across an await, every repository method runs synchronously inside it.
+    pub(crate) fn lock(&self) -> MutexGuard<'_, Tables> {
+        self.tables.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Tables {
+    pub(crate) years: BTreeMap<Uuid, YearData>,
+    pub(crate) years_net_totals: BTreeMap<Uuid, NetTotals>,
+    pub(crate) months: BTreeMap<Uuid, MonthRow>,
+    pub(crate) months_net_totals: BTreeMap<Uuid, NetTotals>,
+    pub(crate) resources: BTreeMap<Uuid, BaseFinancialResource>,
+    /// Balance of a resource, keyed by resource and month ids.
+    pub(crate) resources_months: BTreeMap<(Uuid, Uuid), Milliunits>,
+    pub(crate) resources_order: BTreeMap<(i32, String), Vec<Uuid>>,
+    pub(crate) saving_rates: BTreeMap<Uuid, SavingRateRow>,
+    pub(crate) external_accounts: BTreeMap<Uuid, WebScrapingAccount>,
+    pub(crate) external_account_refreshes: BTreeMap<Uuid, ExternalAccountRefresh>,
+    pub(crate) external_account_balances: BTreeMap<Uuid, ExternalAccountBalance>,
+    pub(crate) secrets: BTreeMap<String, Vec<u8>>,
+    pub(crate) budgeters_config: BTreeMap<Uuid, BudgeterConfig>,
+    pub(crate) expenses_categorization: BTreeMap<Uuid, ExpenseCategorization>,
+    pub(crate) expenses_association: BTreeMap<Uuid, ExpenseAssociation>,
+    pub(crate) categories: BTreeMap<Uuid, BudgetRow<Category>>,
+    pub(crate) scheduled_transactions: BTreeMap<Uuid, BudgetRow<ScheduledTransactionDetail>>,
+    pub(crate) accounts: BTreeMap<Uuid, BudgetRow<Account>>,
+    pub(crate) payees: BTreeMap<Uuid, BudgetRow<Payee>>,
+    pub(crate) transactions: BTreeMap<Uuid, BudgetRow<TransactionDetail>>,
+    pub(crate) ynab_meta: BTreeMap<(String, &'static str), YnabMetaRow>,
+    pub(crate) scheduler_runs: BTreeMap<Uuid, SchedulerRun>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct MonthRow {
+    pub(crate) id: Uuid,
+    pub(crate) month: MonthNum,
+    pub(crate) year_id: Uuid,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SavingRateRow {
+    pub(crate) saving_rate: SavingRate,
+    pub(crate) year_id: Uuid,
+}
+
+/// A YNAB resource, along with the budget it belongs to.
+#[derive(Debug, Clone)]
+pub(crate) struct BudgetRow<T> {
+    pub(crate) budget_id: String,
+    pub(crate) value: T,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct YnabMetaRow {
+    pub(crate) server_knowledge: Option<i64>,
+    pub(crate) last_saved: Option<String>,
+}
+
+impl Tables {
+    pub(crate) fn year_data_by_number(&self, year: i32) -> DbResult<YearData> {
+        self.years
+            .values()
+            .find(|y| y.year == year)
+            .copied()
+            .ok_or(DbError::NotFound)
+    }
+
+    pub(crate) fn month_data(&self, month: &MonthRow) -> MonthData {
+        MonthData {
+            id: month.id,
+            month: month.month,
+            year: self.years[&month.year_id].year,
+        }
+    }
+
+    pub(crate) fn month_data_by_number(&self, month: MonthNum, year: i32) -> DbResult<MonthData> {
+        let year_data = self.year_data_by_number(year)?;
+        self.months
+            .values()
+            .find(|m| m.year_id == year_data.id && m.month == month)
+            .map(|m| self.month_data(m))
+            .ok_or(DbError::NotFound)
+    }
+
+    /// Months ordered by year then month, like the `ORDER BY y.year, m.month` of the SQL backends.
+    pub(crate) fn months_data(&self, filter: impl Fn(&MonthData) -> bool) -> Vec<MonthData> {
+        let mut months: Vec<MonthData> = self
+            .months
+            .values()
+            .map(|m| self.month_data(m))
+            .filter(filter)
+            .collect();
+        months.sort_by_key(|m| (m.year, m.month));
+        months
+    }
+
+    pub(crate) fn delete_year(&mut self, year_id: Uuid) {
+        self.years.remove(&year_id);
+        self.years_net_totals.remove(&year_id);
+        self.saving_rates.retain(|_, sr| sr.year_id != year_id);
+        let month_ids: Vec<Uuid> = self
+            .months
+            .values()
+            .filter(|m| m.year_id == year_id)
+            .map(|m| m.id)
+            .collect();
+        for month_id in month_ids {
+            self.delete_month(month_id);
+        }
+    }
+
+    pub(crate) fn delete_month(&mut self, month_id: Uuid) {
+        self.months.remove(&month_id);
+        self.months_net_totals.remove(&month_id);
+        self.resources_months.retain(|(_, m), _| *m != month_id);
+    }
+
+    /// Upserts net totals by their ids, which can't be swapped for new ones once inserted.
+    pub(crate) fn insert_net_totals(
+        net_totals_table: &mut BTreeMap<Uuid, NetTotals>,
+        owner_id: Uuid,
+        net_totals: &NetTotals,
+    ) -> DbResult<()> {
+        if let Some(existing) = net_totals_table.get(&owner_id) {
+            if existing.assets.id != net_totals.assets.id
+                || existing.portfolio.id != net_totals.portfolio.id
+            {
+                return Err(DbError::AlreadyExists);
+            }
+        }
+        net_totals_table.insert(owner_id, net_totals.clone());
+
+        Ok(())
+    }
+
+    pub(crate) fn order_key(year: i32, category: &ResourceCategory) -> (i32, String) {
+        (year, category.to_string())
+    }
+}
+
+impl MemoryStore {
+    pub(crate) fn get_secret(&self, name: &str) -> DbResult<Vec<u8>> {
+        self.lock()
+            .secrets
+            .get(name)
+            .cloned()
+            .ok_or(DbError::NotFound)
+    }
+
+    pub(crate) fn set_secret(&self, name: &str, value: &[u8]) -> DbResult<()> {
+        self.lock().secrets.insert(name.to_owned(), value.to_vec());
+
+        Ok(())
+    }
+}
+
+impl<T: Clone> BudgetRow<T> {
+    /// Values of a budget, rows belonging to other budgets are left out.
+    pub(crate) fn values_of<'a>(
+        table: &'a BTreeMap<Uuid, BudgetRow<T>>,
+        budget_id: &'a str,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        table
+            .values()
+            .filter(move |row| row.budget_id == budget_id)
+            .map(|row| &row.value)
+    }
+
+    /// Rows are keyed by id only, so a value of another budget moves to this one.
+    pub(crate) fn upsert(
+        table: &mut BTreeMap<Uuid, BudgetRow<T>>,
+        budget_id: &str,
+        id: Uuid,
+        value: &T,
+    ) {
+        table.insert(
+            id,
+            BudgetRow {
+                budget_id: budget_id.to_string(),
+                value: value.clone(),
+            },
+        );
+    }
+}
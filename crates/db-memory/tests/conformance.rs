@@ -0,0 +1,6 @@
+use db_memory::MemoryRepoRegistry;
+
+db_conformance::conformance_tests! {
+    #[tokio::test]
+    || MemoryRepoRegistry::new_arced()
+}
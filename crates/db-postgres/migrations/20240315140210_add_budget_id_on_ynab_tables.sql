@@ -0,0 +1,11 @@
+-- Add budget_id on YNAB tables to support multiple budgets
+ALTER TABLE categories ADD COLUMN budget_id TEXT NOT NULL DEFAULT 'last-used';
+ALTER TABLE scheduled_transactions ADD COLUMN budget_id TEXT NOT NULL DEFAULT 'last-used';
+ALTER TABLE transactions ADD COLUMN budget_id TEXT NOT NULL DEFAULT 'last-used';
+ALTER TABLE accounts ADD COLUMN budget_id TEXT NOT NULL DEFAULT 'last-used';
+ALTER TABLE payees ADD COLUMN budget_id TEXT NOT NULL DEFAULT 'last-used';
+CREATE INDEX categories_budget_id_idx ON categories (budget_id);
+CREATE INDEX scheduled_transactions_budget_id_idx ON scheduled_transactions (budget_id);
+CREATE INDEX transactions_budget_id_idx ON transactions (budget_id);
+CREATE INDEX accounts_budget_id_idx ON accounts (budget_id);
+CREATE INDEX payees_budget_id_idx ON payees (budget_id);
@@ -0,0 +1,2 @@
+-- YNAB's import_id are not UUIDs, e.g. YNAB:-294230:2015-12-30:1
+ALTER TABLE transactions ALTER COLUMN import_id TYPE TEXT USING import_id::TEXT;
@@ -0,0 +1,23 @@
+-- Delta requests state of each YNAB resource, per budget
+CREATE TABLE ynab_meta(
+  budget_id TEXT NOT NULL,
+  resource TEXT NOT NULL,
+  server_knowledge BIGINT,
+  last_saved TEXT,
+  PRIMARY KEY (budget_id, resource)
+);
+
+-- Order in which the financial resources of a category are displayed, per year
+CREATE TABLE balance_sheet_resources_order(
+  year INTEGER NOT NULL,
+  category TEXT NOT NULL,
+  resource_ids UUID[] NOT NULL,
+  PRIMARY KEY (year, category)
+);
+
+-- Encryption key and encrypted secrets, by name
+CREATE TABLE secrets(
+  name TEXT NOT NULL,
+  value BYTEA NOT NULL,
+  PRIMARY KEY (name)
+);
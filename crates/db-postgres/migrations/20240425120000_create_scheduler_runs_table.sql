@@ -0,0 +1,12 @@
+-- Create Scheduler Runs Table, keeping the outcome of each job run in the background
+CREATE TABLE scheduler_runs(
+  id uuid NOT NULL,
+  job TEXT NOT NULL,
+  started_at timestamptz NOT NULL,
+  finished_at timestamptz NOT NULL,
+  succeeded BOOLEAN NOT NULL,
+  details TEXT NOT NULL,
+  PRIMARY KEY (id)
+);
+
+CREATE INDEX scheduler_runs_job_started_at_idx ON scheduler_runs(job, started_at);
@@ -0,0 +1,7 @@
+-- Add the scraper getting the balance of each external account
+ALTER TABLE external_accounts ADD COLUMN scraper_id TEXT;
+
+-- The scrapers used to be chosen by the type of the account
+UPDATE external_accounts SET scraper_id = 'peak_tfsa' WHERE type = 'tfsa';
+UPDATE external_accounts SET scraper_id = 'ia_rrsp' WHERE type = 'rrsp';
+UPDATE external_accounts SET scraper_id = 'canada_life_rpp' WHERE type = 'rpp';
@@ -0,0 +1,11 @@
+-- Create External Account Balances Table, keeping every observed balance of an external account
+CREATE TABLE external_account_balances(
+  id uuid NOT NULL,
+  account_id uuid NOT NULL REFERENCES external_accounts(id) ON DELETE CASCADE,
+  observed_at timestamptz NOT NULL,
+  balance BIGINT NOT NULL,
+  source TEXT NOT NULL,
+  PRIMARY KEY (id)
+);
+
+CREATE INDEX external_account_balances_account_id_observed_at_idx ON external_account_balances(account_id, observed_at);
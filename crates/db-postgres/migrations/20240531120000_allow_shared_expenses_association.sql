@@ -0,0 +1,2 @@
+-- Allow associations without budgeter, marking the expenses of a category (or category group) as shared
+ALTER TABLE expenses_association ALTER COLUMN budgeter_id DROP NOT NULL;
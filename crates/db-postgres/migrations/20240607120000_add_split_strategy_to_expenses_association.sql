@@ -0,0 +1,2 @@
+-- Allow overriding the split strategy of the category group for the expenses of a category (or category group)
+ALTER TABLE expenses_association ADD COLUMN split_strategy TEXT;
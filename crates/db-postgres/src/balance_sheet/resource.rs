@@ -5,11 +5,12 @@ use std::{
 
 use datamize_domain::{
     async_trait,
-    db::{DbError, DbResult, FinResRepo},
+    db::{DbError, DbResult, FinResOrderRepo, FinResRepo},
     FinancialResourceMonthly, FinancialResourceYearly, MonthNum, ResourceCategory, Uuid,
     YearlyBalances,
 };
 use sqlx::PgPool;
+use ynab::Milliunits;
 
 #[derive(Debug, Clone)]
 pub struct PostgresFinResRepo {
@@ -36,7 +37,7 @@ impl FinResRepo for PostgresFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_unique_resources AS r
@@ -82,7 +83,7 @@ impl FinResRepo for PostgresFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_unique_resources AS r
@@ -133,7 +134,7 @@ impl FinResRepo for PostgresFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_unique_resources AS r
@@ -186,7 +187,7 @@ impl FinResRepo for PostgresFinResRepo {
                     r.resource_type,
                     r.ynab_account_ids,
                     r.external_account_ids,
-                    rm.balance
+                    rm.balance as "balance: Milliunits"
                 FROM balance_sheet_unique_resources AS r
                 JOIN resources_balance_per_months AS rm ON r.resource_id = rm.resource_id
                 JOIN balance_sheet_months AS m ON rm.month_id = m.month_id AND m.month = $1
@@ -224,7 +225,7 @@ impl FinResRepo for PostgresFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_unique_resources AS r
@@ -273,7 +274,7 @@ impl FinResRepo for PostgresFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_unique_resources AS r
@@ -376,7 +377,7 @@ impl FinResRepo for PostgresFinResRepo {
                 "#,
                 resource.base.id,
                 month_data.id,
-                balance,
+                balance as _,
             )
             .execute(&mut *transaction)
             .await?;
@@ -479,7 +480,7 @@ impl FinResRepo for PostgresFinResRepo {
                     "#,
                     resource.base.id,
                     month_data.id,
-                    balance,
+                    balance as _,
                 )
                 .execute(&mut *transaction)
                 .await?;
@@ -559,3 +560,58 @@ impl FinResRepo for PostgresFinResRepo {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct PostgresFinResOrderRepo {
+    pub db_conn_pool: PgPool,
+}
+
+impl PostgresFinResOrderRepo {
+    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl FinResOrderRepo for PostgresFinResOrderRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_order(&self, year: i32, category: &ResourceCategory) -> DbResult<Vec<Uuid>> {
+        let res = sqlx::query_scalar!(
+            r#"
+            SELECT resource_ids
+            FROM balance_sheet_resources_order
+            WHERE year = $1 AND category = $2;
+            "#,
+            year,
+            category.to_string(),
+        )
+        .fetch_optional(&self.db_conn_pool)
+        .await?;
+
+        Ok(res.unwrap_or_default())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_order(
+        &self,
+        year: i32,
+        category: &ResourceCategory,
+        order: &[Uuid],
+    ) -> DbResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO balance_sheet_resources_order (year, category, resource_ids)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (year, category) DO UPDATE SET
+            resource_ids = EXCLUDED.resource_ids;
+            "#,
+            year,
+            category.to_string(),
+            order,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -6,6 +6,7 @@ use datamize_domain::{
     Incomes, SavingRate, Savings, Uuid,
 };
 use sqlx::{postgres::PgHasArrayType, PgPool};
+use ynab::Milliunits;
 
 #[derive(Debug, Clone)]
 pub struct PostgresSavingRateRepo {
@@ -44,10 +45,11 @@ impl SavingRateRepo for PostgresSavingRateRepo {
                 sr.saving_rate_id as saving_rate_id,
                 sr.name,
                 sr.savings AS "savings!: IdsAndBalanceRecord",
-                sr.employer_contribution,
-                sr.employee_contribution,
-                sr.mortgage_capital,
-                sr.incomes AS "incomes!: IdsAndBalanceRecord"
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
+                sr.incomes AS "incomes!: IdsAndBalanceRecord",
+                sr.target
             FROM balance_sheet_saving_rates AS sr
             JOIN balance_sheet_years AS y ON y.year_id = sr.year_id AND y.year = $1;
             "#,
@@ -64,7 +66,7 @@ impl SavingRateRepo for PostgresSavingRateRepo {
                 savings: Savings {
                     category_ids: r.savings.ids,
                     extra_balance: r.savings.extra_balance,
-                    total: 0,
+                    total: Milliunits::ZERO,
                 },
                 employer_contribution: r.employer_contribution,
                 employee_contribution: r.employee_contribution,
@@ -72,9 +74,12 @@ impl SavingRateRepo for PostgresSavingRateRepo {
                 incomes: Incomes {
                     payee_ids: r.incomes.ids,
                     extra_balance: r.incomes.extra_balance,
-                    total: 0,
+                    total: Milliunits::ZERO,
                 },
                 year,
+                rate: 0.0,
+                target: r.target,
+                months: vec![],
             })
             .collect())
     }
@@ -87,10 +92,11 @@ impl SavingRateRepo for PostgresSavingRateRepo {
                 sr.saving_rate_id AS "id",
                 sr.name,
                 sr.savings AS "savings!: IdsAndBalanceRecord",
-                sr.employer_contribution,
-                sr.employee_contribution,
-                sr.mortgage_capital,
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
                 sr.incomes AS "incomes!: IdsAndBalanceRecord",
+                sr.target,
                 y.year
             FROM balance_sheet_saving_rates AS sr
             JOIN balance_sheet_years AS y ON y.year_id = sr.year_id
@@ -107,7 +113,7 @@ impl SavingRateRepo for PostgresSavingRateRepo {
             savings: Savings {
                 category_ids: db_row.savings.ids,
                 extra_balance: db_row.savings.extra_balance,
-                total: 0,
+                total: Milliunits::ZERO,
             },
             employer_contribution: db_row.employer_contribution,
             employee_contribution: db_row.employee_contribution,
@@ -115,9 +121,12 @@ impl SavingRateRepo for PostgresSavingRateRepo {
             incomes: Incomes {
                 payee_ids: db_row.incomes.ids,
                 extra_balance: db_row.incomes.extra_balance,
-                total: 0,
+                total: Milliunits::ZERO,
             },
             year: db_row.year,
+            rate: 0.0,
+            target: db_row.target,
+            months: vec![],
         })
     }
 
@@ -129,10 +138,11 @@ impl SavingRateRepo for PostgresSavingRateRepo {
                 sr.saving_rate_id AS "id",
                 sr.name,
                 sr.savings AS "savings!: IdsAndBalanceRecord",
-                sr.employer_contribution,
-                sr.employee_contribution,
-                sr.mortgage_capital,
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
                 sr.incomes AS "incomes!: IdsAndBalanceRecord",
+                sr.target,
                 y.year
             FROM balance_sheet_saving_rates AS sr
             JOIN balance_sheet_years AS y ON y.year_id = sr.year_id
@@ -149,7 +159,7 @@ impl SavingRateRepo for PostgresSavingRateRepo {
             savings: Savings {
                 category_ids: db_row.savings.ids,
                 extra_balance: db_row.savings.extra_balance,
-                total: 0,
+                total: Milliunits::ZERO,
             },
             employer_contribution: db_row.employer_contribution,
             employee_contribution: db_row.employee_contribution,
@@ -157,20 +167,121 @@ impl SavingRateRepo for PostgresSavingRateRepo {
             incomes: Incomes {
                 payee_ids: db_row.incomes.ids,
                 extra_balance: db_row.incomes.extra_balance,
-                total: 0,
+                total: Milliunits::ZERO,
             },
             year: db_row.year,
+            rate: 0.0,
+            target: db_row.target,
+            months: vec![],
         })
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<SavingRate>> {
+        let db_rows = sqlx::query!(
+            r#"
+            SELECT
+                sr.saving_rate_id AS "id",
+                sr.name,
+                sr.savings AS "savings!: IdsAndBalanceRecord",
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
+                sr.incomes AS "incomes!: IdsAndBalanceRecord",
+                sr.target,
+                y.year
+            FROM balance_sheet_saving_rates AS sr
+            JOIN balance_sheet_years AS y ON y.year_id = sr.year_id
+            ORDER BY y.year, sr.name;
+            "#
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_rows
+            .into_iter()
+            .map(|r| SavingRate {
+                id: r.id,
+                name: r.name,
+                savings: Savings {
+                    category_ids: r.savings.ids,
+                    extra_balance: r.savings.extra_balance,
+                    total: Milliunits::ZERO,
+                },
+                employer_contribution: r.employer_contribution,
+                employee_contribution: r.employee_contribution,
+                mortgage_capital: r.mortgage_capital,
+                incomes: Incomes {
+                    payee_ids: r.incomes.ids,
+                    extra_balance: r.incomes.extra_balance,
+                    total: Milliunits::ZERO,
+                },
+                year: r.year,
+                rate: 0.0,
+                target: r.target,
+                months: vec![],
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_by_name(&self, name: &str) -> DbResult<Vec<SavingRate>> {
+        let db_rows = sqlx::query!(
+            r#"
+            SELECT
+                sr.saving_rate_id AS "id",
+                sr.name,
+                sr.savings AS "savings!: IdsAndBalanceRecord",
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
+                sr.incomes AS "incomes!: IdsAndBalanceRecord",
+                sr.target,
+                y.year
+            FROM balance_sheet_saving_rates AS sr
+            JOIN balance_sheet_years AS y ON y.year_id = sr.year_id
+            WHERE sr.name = $1
+            ORDER BY y.year;
+            "#,
+            name,
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_rows
+            .into_iter()
+            .map(|r| SavingRate {
+                id: r.id,
+                name: r.name,
+                savings: Savings {
+                    category_ids: r.savings.ids,
+                    extra_balance: r.savings.extra_balance,
+                    total: Milliunits::ZERO,
+                },
+                employer_contribution: r.employer_contribution,
+                employee_contribution: r.employee_contribution,
+                mortgage_capital: r.mortgage_capital,
+                incomes: Incomes {
+                    payee_ids: r.incomes.ids,
+                    extra_balance: r.incomes.extra_balance,
+                    total: Milliunits::ZERO,
+                },
+                year: r.year,
+                rate: 0.0,
+                target: r.target,
+                months: vec![],
+            })
+            .collect())
+    }
+
     #[tracing::instrument(skip_all)]
     async fn update(&self, saving_rate: &SavingRate) -> DbResult<()> {
         let year_data = self.get_year_data_by_number(saving_rate.year).await?;
 
         sqlx::query_unchecked!(
             r#"
-            INSERT INTO balance_sheet_saving_rates (saving_rate_id, name, savings, employer_contribution, employee_contribution, mortgage_capital, incomes, year_id)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO balance_sheet_saving_rates (saving_rate_id, name, savings, employer_contribution, employee_contribution, mortgage_capital, incomes, year_id, target)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             ON CONFLICT (saving_rate_id) DO UPDATE SET
             name = EXCLUDED.name,
             savings = EXCLUDED.savings,
@@ -178,16 +289,18 @@ impl SavingRateRepo for PostgresSavingRateRepo {
             employee_contribution = EXCLUDED.employee_contribution,
             mortgage_capital = EXCLUDED.mortgage_capital,
             incomes = EXCLUDED.incomes,
-            year_id = EXCLUDED.year_id;
+            year_id = EXCLUDED.year_id,
+            target = EXCLUDED.target;
             "#,
             saving_rate.id,
             saving_rate.name,
             IdsAndBalanceRecord { ids: saving_rate.savings.category_ids.clone(), extra_balance: saving_rate.savings.extra_balance },
-            saving_rate.employer_contribution,
-            saving_rate.employee_contribution,
-            saving_rate.mortgage_capital,
+            saving_rate.employer_contribution as _,
+            saving_rate.employee_contribution as _,
+            saving_rate.mortgage_capital as _,
             IdsAndBalanceRecord { ids: saving_rate.incomes.payee_ids.clone(), extra_balance: saving_rate.incomes.extra_balance },
-            year_data.id
+            year_data.id,
+            saving_rate.target
         )
         .execute(&self.db_conn_pool)
         .await?;
@@ -215,7 +328,7 @@ impl SavingRateRepo for PostgresSavingRateRepo {
 #[sqlx(type_name = "ids_and_balance")]
 struct IdsAndBalanceRecord {
     ids: Vec<Uuid>,
-    extra_balance: i64,
+    extra_balance: Milliunits,
 }
 
 impl PgHasArrayType for IdsAndBalanceRecord {
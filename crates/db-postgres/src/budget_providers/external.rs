@@ -1,13 +1,23 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
+
 use datamize_domain::{
     async_trait,
-    db::{external::ExternalAccountRepo, DbResult},
+    db::{
+        external::{
+            EncryptionKeyRepo, ExternalAccountBalanceRepo, ExternalAccountRefreshRepo,
+            ExternalAccountRepo,
+        },
+        DbError, DbResult,
+    },
     secrecy::{ExposeSecret, Secret},
-    EncryptedPassword, Uuid, WebScrapingAccount,
+    EncryptedPassword, ExternalAccountBalance, ExternalAccountRefresh, Uuid, WebScrapingAccount,
 };
 use sqlx::PgPool;
 
+use crate::secrets::{get_secret, set_secret};
+
 #[derive(Debug, Clone)]
 pub struct PostgresExternalAccountRepo {
     pub db_conn_pool: PgPool,
@@ -32,10 +42,14 @@ impl ExternalAccountRepo for PostgresExternalAccountRepo {
             id: row.id,
             name: row.name,
             account_type: row.r#type.parse().unwrap(),
-            balance: row.balance,
+            balance: row.balance.into(),
             username: row.username,
             encrypted_password: Secret::new(EncryptedPassword::new(row.encrypted_password)),
             deleted: row.deleted,
+            scraper_id: row.scraper_id,
+            encrypted_totp_seed: row
+                .encrypted_totp_seed
+                .map(|seed| Secret::new(EncryptedPassword::new(seed))),
         })
         .fetch_all(&self.db_conn_pool)
         .await
@@ -55,10 +69,14 @@ impl ExternalAccountRepo for PostgresExternalAccountRepo {
             id: row.id,
             name: row.name,
             account_type: row.r#type.parse().unwrap(),
-            balance: row.balance,
+            balance: row.balance.into(),
             username: row.username,
             encrypted_password: Secret::new(EncryptedPassword::new(row.encrypted_password)),
             deleted: row.deleted,
+            scraper_id: row.scraper_id,
+            encrypted_totp_seed: row
+                .encrypted_totp_seed
+                .map(|seed| Secret::new(EncryptedPassword::new(seed))),
         })
         .fetch_one(&self.db_conn_pool)
         .await
@@ -78,10 +96,14 @@ impl ExternalAccountRepo for PostgresExternalAccountRepo {
             id: row.id,
             name: row.name,
             account_type: row.r#type.parse().unwrap(),
-            balance: row.balance,
+            balance: row.balance.into(),
             username: row.username,
             encrypted_password: Secret::new(EncryptedPassword::new(row.encrypted_password)),
             deleted: row.deleted,
+            scraper_id: row.scraper_id,
+            encrypted_totp_seed: row
+                .encrypted_totp_seed
+                .map(|seed| Secret::new(EncryptedPassword::new(seed))),
         })
         .fetch_one(&self.db_conn_pool)
         .await
@@ -90,18 +112,24 @@ impl ExternalAccountRepo for PostgresExternalAccountRepo {
 
     #[tracing::instrument(skip_all)]
     async fn add(&self, account: &WebScrapingAccount) -> DbResult<()> {
+        let encrypted_totp_seed = account
+            .encrypted_totp_seed
+            .as_ref()
+            .map(|seed| seed.expose_secret().as_ref());
         sqlx::query!(
             r#"
-            INSERT INTO external_accounts (id, name, type, balance, username, encrypted_password, deleted)
-            VALUES ($1, $2, $3, $4, $5, $6, $7);
+            INSERT INTO external_accounts (id, name, type, balance, username, encrypted_password, deleted, scraper_id, encrypted_totp_seed)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9);
             "#,
             account.id,
             account.name,
             account.account_type.to_string(),
-            account.balance,
+            account.balance as _,
             account.username,
             account.encrypted_password.expose_secret().as_ref(),
             account.deleted,
+            account.scraper_id,
+            encrypted_totp_seed,
         )
         .execute(&self.db_conn_pool)
         .await?;
@@ -111,25 +139,33 @@ impl ExternalAccountRepo for PostgresExternalAccountRepo {
 
     #[tracing::instrument(skip_all)]
     async fn update(&self, account: &WebScrapingAccount) -> DbResult<()> {
+        let encrypted_totp_seed = account
+            .encrypted_totp_seed
+            .as_ref()
+            .map(|seed| seed.expose_secret().as_ref());
         sqlx::query!(
             r#"
-            INSERT INTO external_accounts (id, name, type, balance, username, encrypted_password, deleted)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            INSERT INTO external_accounts (id, name, type, balance, username, encrypted_password, deleted, scraper_id, encrypted_totp_seed)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             ON CONFLICT (id) DO UPDATE SET
             name = EXCLUDED.name,
             type = EXCLUDED.type,
             balance = EXCLUDED.balance,
             username = EXCLUDED.username,
             encrypted_password = EXCLUDED.encrypted_password,
-            deleted = EXCLUDED.deleted;
+            deleted = EXCLUDED.deleted,
+            scraper_id = EXCLUDED.scraper_id,
+            encrypted_totp_seed = EXCLUDED.encrypted_totp_seed;
             "#,
             account.id,
             account.name,
             account.account_type.to_string(),
-            account.balance,
+            account.balance as _,
             account.username,
             account.encrypted_password.expose_secret().as_ref(),
             account.deleted,
+            account.scraper_id,
+            encrypted_totp_seed,
         )
         .execute(&self.db_conn_pool)
         .await?;
@@ -152,3 +188,282 @@ impl ExternalAccountRepo for PostgresExternalAccountRepo {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct PostgresExternalAccountRefreshRepo {
+    pub db_conn_pool: PgPool,
+}
+
+impl PostgresExternalAccountRefreshRepo {
+    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl ExternalAccountRefreshRepo for PostgresExternalAccountRefreshRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self, account_id: Uuid) -> DbResult<Vec<ExternalAccountRefresh>> {
+        let db_rows = sqlx::query_as!(
+            ExternalAccountRefreshRow,
+            r#"
+            SELECT
+                id,
+                account_id,
+                refreshed_at,
+                duration_ms,
+                succeeded,
+                balance,
+                error
+            FROM external_account_refreshes
+            WHERE account_id = $1
+            ORDER BY refreshed_at;
+            "#,
+            account_id,
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last(&self, account_id: Uuid) -> DbResult<ExternalAccountRefresh> {
+        let db_row = sqlx::query_as!(
+            ExternalAccountRefreshRow,
+            r#"
+            SELECT
+                id,
+                account_id,
+                refreshed_at,
+                duration_ms,
+                succeeded,
+                balance,
+                error
+            FROM external_account_refreshes
+            WHERE account_id = $1
+            ORDER BY refreshed_at DESC
+            LIMIT 1;
+            "#,
+            account_id,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_row.into())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last_success(&self, account_id: Uuid) -> DbResult<ExternalAccountRefresh> {
+        let db_row = sqlx::query_as!(
+            ExternalAccountRefreshRow,
+            r#"
+            SELECT
+                id,
+                account_id,
+                refreshed_at,
+                duration_ms,
+                succeeded,
+                balance,
+                error
+            FROM external_account_refreshes
+            WHERE account_id = $1 AND succeeded
+            ORDER BY refreshed_at DESC
+            LIMIT 1;
+            "#,
+            account_id,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_row.into())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add(&self, refresh: &ExternalAccountRefresh) -> DbResult<()> {
+        let balance = refresh.balance.map(i64::from);
+        sqlx::query!(
+            r#"
+            INSERT INTO external_account_refreshes (id, account_id, refreshed_at, duration_ms, succeeded, balance, error)
+            VALUES ($1, $2, $3, $4, $5, $6, $7);
+            "#,
+            refresh.id,
+            refresh.account_id,
+            refresh.refreshed_at,
+            refresh.duration_ms,
+            refresh.succeeded,
+            balance,
+            refresh.error,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+struct ExternalAccountRefreshRow {
+    id: Uuid,
+    account_id: Uuid,
+    refreshed_at: DateTime<Utc>,
+    duration_ms: i64,
+    succeeded: bool,
+    balance: Option<i64>,
+    error: Option<String>,
+}
+
+impl From<ExternalAccountRefreshRow> for ExternalAccountRefresh {
+    fn from(value: ExternalAccountRefreshRow) -> Self {
+        Self {
+            id: value.id,
+            account_id: value.account_id,
+            refreshed_at: value.refreshed_at,
+            duration_ms: value.duration_ms,
+            succeeded: value.succeeded,
+            balance: value.balance.map(Into::into),
+            error: value.error,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresExternalAccountBalanceRepo {
+    pub db_conn_pool: PgPool,
+}
+
+impl PostgresExternalAccountBalanceRepo {
+    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl ExternalAccountBalanceRepo for PostgresExternalAccountBalanceRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(
+        &self,
+        account_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> DbResult<Vec<ExternalAccountBalance>> {
+        let db_rows = sqlx::query_as!(
+            ExternalAccountBalanceRow,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                account_id as "account_id: Uuid",
+                observed_at as "observed_at: DateTime<Utc>",
+                balance,
+                source
+            FROM external_account_balances
+            WHERE account_id = $1
+                AND ($2::timestamptz IS NULL OR observed_at >= $2)
+                AND ($3::timestamptz IS NULL OR observed_at < $3)
+            ORDER BY observed_at;
+            "#,
+            account_id,
+            from,
+            to,
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        db_rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last(
+        &self,
+        account_id: Uuid,
+        to: Option<DateTime<Utc>>,
+    ) -> DbResult<ExternalAccountBalance> {
+        let db_row = sqlx::query_as!(
+            ExternalAccountBalanceRow,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                account_id as "account_id: Uuid",
+                observed_at as "observed_at: DateTime<Utc>",
+                balance,
+                source
+            FROM external_account_balances
+            WHERE account_id = $1 AND ($2::timestamptz IS NULL OR observed_at < $2)
+            ORDER BY observed_at DESC
+            LIMIT 1;
+            "#,
+            account_id,
+            to,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?;
+
+        db_row.try_into()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add(&self, balance: &ExternalAccountBalance) -> DbResult<()> {
+        let amount = i64::from(balance.balance);
+        let source = balance.source.to_string();
+        sqlx::query!(
+            r#"
+            INSERT INTO external_account_balances (id, account_id, observed_at, balance, source)
+            VALUES ($1, $2, $3, $4, $5);
+            "#,
+            balance.id,
+            balance.account_id,
+            balance.observed_at,
+            amount,
+            source,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+struct ExternalAccountBalanceRow {
+    id: Uuid,
+    account_id: Uuid,
+    observed_at: DateTime<Utc>,
+    balance: i64,
+    source: String,
+}
+
+impl TryFrom<ExternalAccountBalanceRow> for ExternalAccountBalance {
+    type Error = DbError;
+
+    fn try_from(value: ExternalAccountBalanceRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            account_id: value.account_id,
+            observed_at: value.observed_at,
+            balance: value.balance.into(),
+            source: value.source.parse().map_err(DbError::DataIntegrityError)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresEncryptionKeyRepo {
+    pub db_conn_pool: PgPool,
+}
+
+impl PostgresEncryptionKeyRepo {
+    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl EncryptionKeyRepo for PostgresEncryptionKeyRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get(&self) -> DbResult<Vec<u8>> {
+        get_secret(&self.db_conn_pool, "encryption_key").await
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn set(&self, encryption_key_str: &[u8]) -> DbResult<()> {
+        set_secret(&self.db_conn_pool, "encryption_key", encryption_key_str).await
+    }
+}
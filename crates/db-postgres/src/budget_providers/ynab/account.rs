@@ -10,11 +10,15 @@ use ynab::Account;
 #[derive(Debug, Clone)]
 pub struct PostgresYnabAccountRepo {
     pub db_conn_pool: PgPool,
+    pub budget_id: String,
 }
 
 impl PostgresYnabAccountRepo {
-    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
-        Arc::new(Self { db_conn_pool })
+    pub fn new_arced(db_conn_pool: PgPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
     }
 }
 
@@ -44,7 +48,9 @@ impl YnabAccountRepo for PostgresYnabAccountRepo {
                 debt_minimum_payments,
                 debt_escrow_amounts
             FROM accounts
-            "#
+            WHERE budget_id = $1
+            "#,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -79,9 +85,10 @@ impl YnabAccountRepo for PostgresYnabAccountRepo {
         for a in accounts {
             sqlx::query!(
                     r#"
-                    INSERT INTO accounts (id, name, type, on_budget, closed, note, balance, cleared_balance, uncleared_balance, transfer_payee_id, direct_import_linked, direct_import_in_error, deleted, last_reconciled_at, debt_original_balance, debt_interest_rates, debt_minimum_payments, debt_escrow_amounts)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+                    INSERT INTO accounts (id, name, type, on_budget, closed, note, balance, cleared_balance, uncleared_balance, transfer_payee_id, direct_import_linked, direct_import_in_error, deleted, last_reconciled_at, debt_original_balance, debt_interest_rates, debt_minimum_payments, debt_escrow_amounts, budget_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
                     ON CONFLICT (id) DO UPDATE SET
+                    budget_id = EXCLUDED.budget_id,
                     name = EXCLUDED.name,
                     type = EXCLUDED.type,
                     on_budget = EXCLUDED.on_budget,
@@ -118,6 +125,7 @@ impl YnabAccountRepo for PostgresYnabAccountRepo {
                     serde_json::to_value(&a.debt_interest_rates).unwrap(),
                     serde_json::to_value(&a.debt_minimum_payments).unwrap(),
                     serde_json::to_value(&a.debt_escrow_amounts).unwrap(),
+                    self.budget_id,
                 ).execute(&self.db_conn_pool).await?;
         }
 
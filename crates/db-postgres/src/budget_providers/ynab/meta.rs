@@ -0,0 +1,273 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{
+        ynab::{
+            YnabAccountMetaRepo, YnabCategoryMetaRepo, YnabPayeeMetaRepo,
+            YnabScheduledTransactionMetaRepo, YnabTransactionMetaRepo,
+        },
+        DbError, DbResult,
+    },
+};
+use sqlx::PgPool;
+
+/// Delta requests state of a YNAB resource, stored in the `ynab_meta` table.
+#[derive(Debug, Clone)]
+struct YnabMeta {
+    db_conn_pool: PgPool,
+    budget_id: String,
+    resource: &'static str,
+}
+
+impl YnabMeta {
+    fn new(db_conn_pool: PgPool, budget_id: &str, resource: &'static str) -> Self {
+        Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+            resource,
+        }
+    }
+
+    async fn get_delta(&self) -> DbResult<i64> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT server_knowledge
+            FROM ynab_meta
+            WHERE budget_id = $1 AND resource = $2;
+            "#,
+            self.budget_id,
+            self.resource,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?
+        .ok_or(DbError::NotFound)
+    }
+
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO ynab_meta (budget_id, resource, server_knowledge)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (budget_id, resource) DO UPDATE SET
+            server_knowledge = EXCLUDED.server_knowledge;
+            "#,
+            self.budget_id,
+            self.resource,
+            server_knowledge,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn del_delta(&self) -> DbResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE ynab_meta
+            SET server_knowledge = NULL
+            WHERE budget_id = $1 AND resource = $2;
+            "#,
+            self.budget_id,
+            self.resource,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_last_saved(&self) -> DbResult<String> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT last_saved
+            FROM ynab_meta
+            WHERE budget_id = $1 AND resource = $2;
+            "#,
+            self.budget_id,
+            self.resource,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?
+        .ok_or(DbError::NotFound)
+    }
+
+    async fn set_last_saved(&self, last_saved: String) -> DbResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO ynab_meta (budget_id, resource, last_saved)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (budget_id, resource) DO UPDATE SET
+            last_saved = EXCLUDED.last_saved;
+            "#,
+            self.budget_id,
+            self.resource,
+            last_saved,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresYnabCategoryMetaRepo {
+    meta: YnabMeta,
+}
+
+impl PostgresYnabCategoryMetaRepo {
+    pub fn new_arced(db_conn_pool: PgPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(db_conn_pool, budget_id, "categories"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabCategoryMetaRepo for PostgresYnabCategoryMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn del_delta(&self) -> DbResult<()> {
+        self.meta.del_delta().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last_saved(&self) -> DbResult<String> {
+        self.meta.get_last_saved().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_last_saved(&self, last_saved: String) -> DbResult<()> {
+        self.meta.set_last_saved(last_saved).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresYnabScheduledTransactionMetaRepo {
+    meta: YnabMeta,
+}
+
+impl PostgresYnabScheduledTransactionMetaRepo {
+    pub fn new_arced(db_conn_pool: PgPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(db_conn_pool, budget_id, "scheduled_transactions"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabScheduledTransactionMetaRepo for PostgresYnabScheduledTransactionMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn del_delta(&self) -> DbResult<()> {
+        self.meta.del_delta().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last_saved(&self) -> DbResult<String> {
+        self.meta.get_last_saved().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_last_saved(&self, last_saved: String) -> DbResult<()> {
+        self.meta.set_last_saved(last_saved).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresYnabAccountMetaRepo {
+    meta: YnabMeta,
+}
+
+impl PostgresYnabAccountMetaRepo {
+    pub fn new_arced(db_conn_pool: PgPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(db_conn_pool, budget_id, "accounts"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabAccountMetaRepo for PostgresYnabAccountMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresYnabPayeeMetaRepo {
+    meta: YnabMeta,
+}
+
+impl PostgresYnabPayeeMetaRepo {
+    pub fn new_arced(db_conn_pool: PgPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(db_conn_pool, budget_id, "payees"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabPayeeMetaRepo for PostgresYnabPayeeMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresYnabTransactionMetaRepo {
+    meta: YnabMeta,
+}
+
+impl PostgresYnabTransactionMetaRepo {
+    pub fn new_arced(db_conn_pool: PgPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            meta: YnabMeta::new(db_conn_pool, budget_id, "transactions"),
+        })
+    }
+}
+
+#[async_trait]
+impl YnabTransactionMetaRepo for PostgresYnabTransactionMetaRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_delta(&self) -> DbResult<i64> {
+        self.meta.get_delta().await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
+        self.meta.set_delta(server_knowledge).await
+    }
+}
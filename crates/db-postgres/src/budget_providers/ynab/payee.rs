@@ -10,11 +10,15 @@ use ynab::Payee;
 #[derive(Debug, Clone)]
 pub struct PostgresYnabPayeeRepo {
     pub db_conn_pool: PgPool,
+    pub budget_id: String,
 }
 
 impl PostgresYnabPayeeRepo {
-    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
-        Arc::new(Self { db_conn_pool })
+    pub fn new_arced(db_conn_pool: PgPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
     }
 }
 
@@ -31,8 +35,10 @@ impl YnabPayeeRepo for PostgresYnabPayeeRepo {
                 transfer_account_id,
                 deleted
             FROM payees
+            WHERE budget_id = $1
             ORDER BY name
-            "#
+            "#,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await
@@ -44,9 +50,10 @@ impl YnabPayeeRepo for PostgresYnabPayeeRepo {
         for p in payees {
             sqlx::query!(
                 r#"
-                    INSERT INTO payees (id, name, transfer_account_id, deleted)
-                    VALUES ($1, $2, $3, $4)
+                    INSERT INTO payees (id, name, transfer_account_id, deleted, budget_id)
+                    VALUES ($1, $2, $3, $4, $5)
                     ON CONFLICT (id) DO UPDATE SET
+                    budget_id = EXCLUDED.budget_id,
                     name = EXCLUDED.name,
                     transfer_account_id = EXCLUDED.transfer_account_id,
                     deleted = EXCLUDED.deleted;
@@ -54,7 +61,8 @@ impl YnabPayeeRepo for PostgresYnabPayeeRepo {
                 p.id,
                 p.name,
                 p.transfer_account_id,
-                p.deleted
+                p.deleted,
+                self.budget_id,
             )
             .execute(&self.db_conn_pool)
             .await?;
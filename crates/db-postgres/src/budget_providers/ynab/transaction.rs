@@ -11,11 +11,15 @@ use ynab::{BaseTransactionDetail, DebtTransactionType, TransactionDetail};
 #[derive(Debug, Clone)]
 pub struct PostgresYnabTransactionRepo {
     pub db_conn_pool: PgPool,
+    pub budget_id: String,
 }
 
 impl PostgresYnabTransactionRepo {
-    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
-        Arc::new(Self { db_conn_pool })
+    pub fn new_arced(db_conn_pool: PgPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
     }
 }
 
@@ -49,7 +53,9 @@ impl YnabTransactionRepo for PostgresYnabTransactionRepo {
                 debt_transaction_type as "debt_transaction_type?: DebtTransactionType",
                 subtransactions
             FROM transactions
-            "#
+            WHERE budget_id = $1
+            "#,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -90,9 +96,10 @@ impl YnabTransactionRepo for PostgresYnabTransactionRepo {
         for t in transactions {
             sqlx::query!(
                     r#"
-                    INSERT INTO transactions (id, date, amount, memo, cleared, approved, flag_color, account_id, payee_id, category_id, transfer_account_id, transfer_transaction_id, matched_transaction_id, import_id, deleted, account_name, payee_name, category_name, import_payee_name, import_payee_name_original, debt_transaction_type, subtransactions)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
+                    INSERT INTO transactions (id, date, amount, memo, cleared, approved, flag_color, account_id, payee_id, category_id, transfer_account_id, transfer_transaction_id, matched_transaction_id, import_id, deleted, account_name, payee_name, category_name, import_payee_name, import_payee_name_original, debt_transaction_type, subtransactions, budget_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23)
                     ON CONFLICT (id) DO UPDATE SET
+                    budget_id = EXCLUDED.budget_id,
                     date = EXCLUDED.date,
                     amount = EXCLUDED.amount,
                     memo = EXCLUDED.memo,
@@ -136,7 +143,8 @@ impl YnabTransactionRepo for PostgresYnabTransactionRepo {
                     t.base.import_payee_name,
                     t.base.import_payee_name_original,
                     t.base.debt_transaction_type.clone().map(|t| t.to_string()),
-                    serde_json::to_value(&t.subtransactions).unwrap()
+                    serde_json::to_value(&t.subtransactions).unwrap(),
+                    self.budget_id,
                 ).execute(&self.db_conn_pool).await?;
         }
 
@@ -171,9 +179,10 @@ impl YnabTransactionRepo for PostgresYnabTransactionRepo {
                 debt_transaction_type as "debt_transaction_type?: DebtTransactionType",
                 subtransactions
             FROM transactions
-            WHERE payee_id = $1
+            WHERE payee_id = $1 AND budget_id = $2
             "#,
-            payee_id
+            payee_id,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -240,9 +249,10 @@ impl YnabTransactionRepo for PostgresYnabTransactionRepo {
                 debt_transaction_type as "debt_transaction_type?: DebtTransactionType",
                 subtransactions
             FROM transactions
-            WHERE category_id = $1
+            WHERE category_id = $1 AND budget_id = $2
             "#,
-            category_id
+            category_id,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbResult, ExpenseAssociationRepo},
+    ExpenseAssociation, Uuid,
+};
+use sqlx::PgPool;
+
+#[derive(Debug, Clone)]
+pub struct PostgresExpenseAssociationRepo {
+    pub db_conn_pool: PgPool,
+}
+
+impl PostgresExpenseAssociationRepo {
+    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl ExpenseAssociationRepo for PostgresExpenseAssociationRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<ExpenseAssociation>> {
+        Ok(sqlx::query!(
+            r#"
+            SELECT
+                id,
+                type,
+                budgeter_id,
+                split_strategy
+            FROM expenses_association
+            "#
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?
+        .into_iter()
+        .map(|row| ExpenseAssociation {
+            id: row.id,
+            association_type: row.r#type.parse().unwrap(),
+            budgeter_id: row.budgeter_id,
+            split_strategy: row.split_strategy.map(|s| s.parse().unwrap()),
+        })
+        .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, id: Uuid) -> DbResult<ExpenseAssociation> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                type,
+                budgeter_id,
+                split_strategy
+            FROM expenses_association
+            WHERE id = $1;
+            "#,
+            id,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?;
+
+        Ok(ExpenseAssociation {
+            id: row.id,
+            association_type: row.r#type.parse().unwrap(),
+            budgeter_id: row.budgeter_id,
+            split_strategy: row.split_strategy.map(|s| s.parse().unwrap()),
+        })
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update(&self, expense_association: &ExpenseAssociation) -> DbResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO expenses_association (id, type, budgeter_id, split_strategy)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (id) DO UPDATE
+            SET type = EXCLUDED.type,
+            budgeter_id = EXCLUDED.budgeter_id,
+            split_strategy = EXCLUDED.split_strategy;
+            "#,
+            expense_association.id,
+            expense_association.association_type.to_string(),
+            expense_association.budgeter_id,
+            expense_association
+                .split_strategy
+                .as_ref()
+                .map(|s| s.to_string()),
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DbResult<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM expenses_association
+            WHERE id = $1;
+            "#,
+            id,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
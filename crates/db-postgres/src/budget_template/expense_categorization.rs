@@ -28,7 +28,8 @@ impl ExpenseCategorizationRepo for PostgresExpenseCategorizationRepo {
                 id,
                 name,
                 type,
-                sub_type
+                sub_type,
+                split_strategy
             FROM expenses_categorization
             "#
         )
@@ -40,6 +41,7 @@ impl ExpenseCategorizationRepo for PostgresExpenseCategorizationRepo {
             name: row.name,
             expense_type: row.r#type.parse().unwrap(),
             sub_expense_type: row.sub_type.parse().unwrap(),
+            split_strategy: row.split_strategy.parse().unwrap(),
         })
         .collect())
     }
@@ -52,7 +54,8 @@ impl ExpenseCategorizationRepo for PostgresExpenseCategorizationRepo {
                 id,
                 name,
                 type,
-                sub_type
+                sub_type,
+                split_strategy
             FROM expenses_categorization
             WHERE id = $1;
             "#,
@@ -66,6 +69,7 @@ impl ExpenseCategorizationRepo for PostgresExpenseCategorizationRepo {
             name: row.name,
             expense_type: row.r#type.parse().unwrap(),
             sub_expense_type: row.sub_type.parse().unwrap(),
+            split_strategy: row.split_strategy.parse().unwrap(),
         })
     }
 
@@ -74,17 +78,19 @@ impl ExpenseCategorizationRepo for PostgresExpenseCategorizationRepo {
         for expense_categorization in expenses_categorization {
             sqlx::query!(
                 r#"
-                INSERT INTO expenses_categorization (id, name, type, sub_type)
-                VALUES ($1, $2, $3, $4)
+                INSERT INTO expenses_categorization (id, name, type, sub_type, split_strategy)
+                VALUES ($1, $2, $3, $4, $5)
                 ON CONFLICT (id) DO UPDATE
                 SET name = EXCLUDED.name,
                 type = EXCLUDED.type,
-                sub_type = EXCLUDED.sub_type;
+                sub_type = EXCLUDED.sub_type,
+                split_strategy = EXCLUDED.split_strategy;
                 "#,
                 expense_categorization.id,
                 expense_categorization.name,
                 expense_categorization.expense_type.to_string(),
                 expense_categorization.sub_expense_type.to_string(),
+                expense_categorization.split_strategy.to_string(),
             )
             .execute(&self.db_conn_pool)
             .await?;
@@ -97,17 +103,19 @@ impl ExpenseCategorizationRepo for PostgresExpenseCategorizationRepo {
     async fn update(&self, expense_categorization: &ExpenseCategorization) -> DbResult<()> {
         sqlx::query!(
             r#"
-            INSERT INTO expenses_categorization (id, name, type, sub_type)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO expenses_categorization (id, name, type, sub_type, split_strategy)
+            VALUES ($1, $2, $3, $4, $5)
             ON CONFLICT (id) DO UPDATE
             SET name = EXCLUDED.name,
             type = EXCLUDED.type,
-            sub_type = EXCLUDED.sub_type;
+            sub_type = EXCLUDED.sub_type,
+            split_strategy = EXCLUDED.split_strategy;
             "#,
             expense_categorization.id,
             expense_categorization.name,
             expense_categorization.expense_type.to_string(),
             expense_categorization.sub_expense_type.to_string(),
+            expense_categorization.split_strategy.to_string(),
         )
         .execute(&self.db_conn_pool)
         .await?;
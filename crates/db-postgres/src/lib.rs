@@ -1,6 +1,11 @@
 pub mod balance_sheet;
 pub mod budget_providers;
 pub mod budget_template;
+mod registry;
+pub mod scheduler;
+mod secrets;
+
+pub use registry::*;
 
 use sqlx::PgPool;
 pub use sqlx::{
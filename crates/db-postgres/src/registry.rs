@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use datamize_domain::db::{
+    external::{
+        DynEncryptionKeyRepo, DynExternalAccountBalanceRepo, DynExternalAccountRefreshRepo,
+        DynExternalAccountRepo,
+    },
+    ynab::{
+        DynYnabAccountMetaRepo, DynYnabAccountRepo, DynYnabCategoryMetaRepo, DynYnabCategoryRepo,
+        DynYnabOAuthTokenRepo, DynYnabPayeeMetaRepo, DynYnabPayeeRepo,
+        DynYnabScheduledTransactionMetaRepo, DynYnabScheduledTransactionRepo,
+        DynYnabTransactionMetaRepo, DynYnabTransactionRepo,
+    },
+    DynBudgeterConfigRepo, DynExpenseAssociationRepo, DynExpenseCategorizationRepo,
+    DynFinResOrderRepo, DynFinResRepo, DynMonthRepo, DynSavingRateRepo, DynSchedulerRunRepo,
+    DynYearRepo, RepoRegistry,
+};
+use sqlx::PgPool;
+
+use crate::{
+    balance_sheet::{
+        PostgresFinResOrderRepo, PostgresFinResRepo, PostgresMonthRepo, PostgresSavingRateRepo,
+        PostgresYearRepo,
+    },
+    budget_providers::{
+        external::{
+            PostgresEncryptionKeyRepo, PostgresExternalAccountBalanceRepo,
+            PostgresExternalAccountRefreshRepo, PostgresExternalAccountRepo,
+        },
+        ynab::{
+            PostgresYnabAccountMetaRepo, PostgresYnabAccountRepo, PostgresYnabCategoryMetaRepo,
+            PostgresYnabCategoryRepo, PostgresYnabOAuthTokenRepo, PostgresYnabPayeeMetaRepo,
+            PostgresYnabPayeeRepo, PostgresYnabScheduledTransactionMetaRepo,
+            PostgresYnabScheduledTransactionRepo, PostgresYnabTransactionMetaRepo,
+            PostgresYnabTransactionRepo,
+        },
+    },
+    budget_template::{
+        PostgresBudgeterConfigRepo, PostgresExpenseAssociationRepo,
+        PostgresExpenseCategorizationRepo,
+    },
+    scheduler::PostgresSchedulerRunRepo,
+};
+
+/// Everything in Postgres.
+#[derive(Clone)]
+pub struct PostgresRepoRegistry {
+    pub db_conn_pool: PgPool,
+}
+
+impl PostgresRepoRegistry {
+    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+impl RepoRegistry for PostgresRepoRegistry {
+    fn year_repo(&self) -> DynYearRepo {
+        PostgresYearRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn month_repo(&self) -> DynMonthRepo {
+        PostgresMonthRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn fin_res_repo(&self) -> DynFinResRepo {
+        PostgresFinResRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn fin_res_order_repo(&self) -> DynFinResOrderRepo {
+        PostgresFinResOrderRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn saving_rate_repo(&self) -> DynSavingRateRepo {
+        PostgresSavingRateRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn external_account_repo(&self) -> DynExternalAccountRepo {
+        PostgresExternalAccountRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn external_account_refresh_repo(&self) -> DynExternalAccountRefreshRepo {
+        PostgresExternalAccountRefreshRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn external_account_balance_repo(&self) -> DynExternalAccountBalanceRepo {
+        PostgresExternalAccountBalanceRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn encryption_key_repo(&self) -> DynEncryptionKeyRepo {
+        PostgresEncryptionKeyRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn budgeter_config_repo(&self) -> DynBudgeterConfigRepo {
+        PostgresBudgeterConfigRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn expense_categorization_repo(&self) -> DynExpenseCategorizationRepo {
+        PostgresExpenseCategorizationRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn expense_association_repo(&self) -> DynExpenseAssociationRepo {
+        PostgresExpenseAssociationRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn ynab_oauth_token_repo(&self) -> DynYnabOAuthTokenRepo {
+        PostgresYnabOAuthTokenRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn scheduler_run_repo(&self) -> DynSchedulerRunRepo {
+        PostgresSchedulerRunRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn ynab_category_repo(&self, budget_id: &str) -> DynYnabCategoryRepo {
+        PostgresYnabCategoryRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_category_meta_repo(&self, budget_id: &str) -> DynYnabCategoryMetaRepo {
+        PostgresYnabCategoryMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_scheduled_transaction_repo(&self, budget_id: &str) -> DynYnabScheduledTransactionRepo {
+        PostgresYnabScheduledTransactionRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_scheduled_transaction_meta_repo(
+        &self,
+        budget_id: &str,
+    ) -> DynYnabScheduledTransactionMetaRepo {
+        PostgresYnabScheduledTransactionMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_account_repo(&self, budget_id: &str) -> DynYnabAccountRepo {
+        PostgresYnabAccountRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_account_meta_repo(&self, budget_id: &str) -> DynYnabAccountMetaRepo {
+        PostgresYnabAccountMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_payee_repo(&self, budget_id: &str) -> DynYnabPayeeRepo {
+        PostgresYnabPayeeRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_payee_meta_repo(&self, budget_id: &str) -> DynYnabPayeeMetaRepo {
+        PostgresYnabPayeeMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_transaction_repo(&self, budget_id: &str) -> DynYnabTransactionRepo {
+        PostgresYnabTransactionRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_transaction_meta_repo(&self, budget_id: &str) -> DynYnabTransactionMetaRepo {
+        PostgresYnabTransactionMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+}
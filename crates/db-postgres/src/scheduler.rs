@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use datamize_domain::{
+    async_trait,
+    db::{DbError, DbResult, SchedulerRunRepo},
+    ScheduledJob, SchedulerRun, Uuid,
+};
+use sqlx::PgPool;
+
+#[derive(Debug, Clone)]
+pub struct PostgresSchedulerRunRepo {
+    pub db_conn_pool: PgPool,
+}
+
+impl PostgresSchedulerRunRepo {
+    pub fn new_arced(db_conn_pool: PgPool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl SchedulerRunRepo for PostgresSchedulerRunRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_latest(&self, limit: u32) -> DbResult<Vec<SchedulerRun>> {
+        let db_rows = sqlx::query_as!(
+            SchedulerRunRow,
+            r#"
+            SELECT
+                id,
+                job,
+                started_at,
+                finished_at,
+                succeeded,
+                details
+            FROM scheduler_runs
+            ORDER BY started_at DESC
+            LIMIT $1;
+            "#,
+            i64::from(limit),
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        db_rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last(&self, job: ScheduledJob) -> DbResult<SchedulerRun> {
+        let job = job.to_string();
+        let db_row = sqlx::query_as!(
+            SchedulerRunRow,
+            r#"
+            SELECT
+                id,
+                job,
+                started_at,
+                finished_at,
+                succeeded,
+                details
+            FROM scheduler_runs
+            WHERE job = $1
+            ORDER BY started_at DESC
+            LIMIT 1;
+            "#,
+            job,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?;
+
+        db_row.try_into()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add(&self, run: &SchedulerRun) -> DbResult<()> {
+        let job = run.job.to_string();
+        sqlx::query!(
+            r#"
+            INSERT INTO scheduler_runs (id, job, started_at, finished_at, succeeded, details)
+            VALUES ($1, $2, $3, $4, $5, $6);
+            "#,
+            run.id,
+            job,
+            run.started_at,
+            run.finished_at,
+            run.succeeded,
+            run.details,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+struct SchedulerRunRow {
+    id: Uuid,
+    job: String,
+    started_at: DateTime<Utc>,
+    finished_at: DateTime<Utc>,
+    succeeded: bool,
+    details: String,
+}
+
+impl TryFrom<SchedulerRunRow> for SchedulerRun {
+    type Error = DbError;
+
+    fn try_from(value: SchedulerRunRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            job: value.job.parse().map_err(DbError::DataIntegrityError)?,
+            started_at: value.started_at,
+            finished_at: value.finished_at,
+            succeeded: value.succeeded,
+            details: value.details,
+        })
+    }
+}
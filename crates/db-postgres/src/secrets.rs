@@ -0,0 +1,33 @@
+use datamize_domain::db::DbResult;
+use sqlx::PgPool;
+
+pub(crate) async fn get_secret(db_conn_pool: &PgPool, name: &str) -> DbResult<Vec<u8>> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT value
+        FROM secrets
+        WHERE name = $1;
+        "#,
+        name,
+    )
+    .fetch_one(db_conn_pool)
+    .await
+    .map_err(Into::into)
+}
+
+pub(crate) async fn set_secret(db_conn_pool: &PgPool, name: &str, value: &[u8]) -> DbResult<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO secrets (name, value)
+        VALUES ($1, $2)
+        ON CONFLICT (name) DO UPDATE SET
+        value = EXCLUDED.value;
+        "#,
+        name,
+        value,
+    )
+    .execute(db_conn_pool)
+    .await?;
+
+    Ok(())
+}
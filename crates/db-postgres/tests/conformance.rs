@@ -0,0 +1,7 @@
+use db_postgres::PostgresRepoRegistry;
+use sqlx::PgPool;
+
+db_conformance::conformance_tests! {
+    #[sqlx::test(migrations = "./migrations")]
+    |pool: PgPool| PostgresRepoRegistry::new_arced(pool)
+}
@@ -9,11 +9,19 @@ use fred::{clients::RedisPool, interfaces::KeysInterface};
 #[derive(Clone)]
 pub struct RedisYnabAccountMetaRepo {
     pub redis_conn_pool: RedisPool,
+    pub budget_id: String,
 }
 
 impl RedisYnabAccountMetaRepo {
-    pub fn new_arced(redis_conn_pool: RedisPool) -> Arc<Self> {
-        Arc::new(Self { redis_conn_pool })
+    pub fn new_arced(redis_conn_pool: RedisPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            redis_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
+    }
+
+    fn key(&self, name: &str) -> String {
+        format!("{}_{}", self.budget_id, name)
     }
 }
 
@@ -22,7 +30,7 @@ impl YnabAccountMetaRepo for RedisYnabAccountMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn get_delta(&self) -> DbResult<i64> {
         self.redis_conn_pool
-            .get("accounts_delta")
+            .get(self.key("accounts_delta"))
             .await
             .map_err(Into::into)
     }
@@ -30,7 +38,13 @@ impl YnabAccountMetaRepo for RedisYnabAccountMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
         self.redis_conn_pool
-            .set("accounts_delta", server_knowledge, None, None, false)
+            .set(
+                self.key("accounts_delta"),
+                server_knowledge,
+                None,
+                None,
+                false,
+            )
             .await?;
         Ok(())
     }
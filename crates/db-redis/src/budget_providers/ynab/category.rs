@@ -9,11 +9,19 @@ use fred::{clients::RedisPool, interfaces::KeysInterface};
 #[derive(Clone)]
 pub struct RedisYnabCategoryMetaRepo {
     pub redis_conn_pool: RedisPool,
+    pub budget_id: String,
 }
 
 impl RedisYnabCategoryMetaRepo {
-    pub fn new_arced(redis_conn_pool: RedisPool) -> Arc<Self> {
-        Arc::new(Self { redis_conn_pool })
+    pub fn new_arced(redis_conn_pool: RedisPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            redis_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
+    }
+
+    fn key(&self, name: &str) -> String {
+        format!("{}_{}", self.budget_id, name)
     }
 }
 
@@ -22,7 +30,7 @@ impl YnabCategoryMetaRepo for RedisYnabCategoryMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn get_delta(&self) -> DbResult<i64> {
         self.redis_conn_pool
-            .get("categories_delta")
+            .get(self.key("categories_delta"))
             .await
             .map_err(Into::into)
     }
@@ -30,7 +38,13 @@ impl YnabCategoryMetaRepo for RedisYnabCategoryMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
         self.redis_conn_pool
-            .set("categories_delta", server_knowledge, None, None, false)
+            .set(
+                self.key("categories_delta"),
+                server_knowledge,
+                None,
+                None,
+                false,
+            )
             .await?;
         Ok(())
     }
@@ -38,7 +52,7 @@ impl YnabCategoryMetaRepo for RedisYnabCategoryMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn del_delta(&self) -> DbResult<()> {
         self.redis_conn_pool
-            .del("categories_delta")
+            .del(self.key("categories_delta"))
             .await
             .map_err(Into::into)
     }
@@ -46,7 +60,7 @@ impl YnabCategoryMetaRepo for RedisYnabCategoryMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn get_last_saved(&self) -> DbResult<String> {
         self.redis_conn_pool
-            .get("categories_last_saved")
+            .get(self.key("categories_last_saved"))
             .await
             .map_err(Into::into)
     }
@@ -54,7 +68,13 @@ impl YnabCategoryMetaRepo for RedisYnabCategoryMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn set_last_saved(&self, last_saved: String) -> DbResult<()> {
         self.redis_conn_pool
-            .set("categories_last_saved", last_saved, None, None, false)
+            .set(
+                self.key("categories_last_saved"),
+                last_saved,
+                None,
+                None,
+                false,
+            )
             .await?;
         Ok(())
     }
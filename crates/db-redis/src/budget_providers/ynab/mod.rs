@@ -1,11 +1,13 @@
 mod account;
 mod category;
+mod oauth;
 mod payee;
 mod scheduled_transaction;
 mod transaction;
 
 pub use account::*;
 pub use category::*;
+pub use oauth::*;
 pub use payee::*;
 pub use scheduled_transaction::*;
 pub use transaction::*;
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{ynab::YnabOAuthTokenRepo, DbResult},
+};
+use fred::{clients::RedisPool, interfaces::KeysInterface};
+
+#[derive(Clone)]
+pub struct RedisYnabOAuthTokenRepo {
+    pub redis_conn_pool: RedisPool,
+}
+
+impl RedisYnabOAuthTokenRepo {
+    pub fn new_arced(redis_conn_pool: RedisPool) -> Arc<Self> {
+        Arc::new(Self { redis_conn_pool })
+    }
+}
+
+#[async_trait]
+impl YnabOAuthTokenRepo for RedisYnabOAuthTokenRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, member: &str) -> DbResult<Vec<u8>> {
+        self.redis_conn_pool
+            .get(format!("ynab_oauth_tokens_{}", member))
+            .await
+            .map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn set(&self, member: &str, encrypted_tokens: &[u8]) -> DbResult<()> {
+        self.redis_conn_pool
+            .set::<(), _, _>(
+                format!("ynab_oauth_tokens_{}", member),
+                encrypted_tokens,
+                None,
+                None,
+                false,
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -9,11 +9,19 @@ use fred::{clients::RedisPool, interfaces::KeysInterface};
 #[derive(Clone)]
 pub struct RedisYnabScheduledTransactionMetaRepo {
     pub redis_conn_pool: RedisPool,
+    pub budget_id: String,
 }
 
 impl RedisYnabScheduledTransactionMetaRepo {
-    pub fn new_arced(redis_conn_pool: RedisPool) -> Arc<Self> {
-        Arc::new(Self { redis_conn_pool })
+    pub fn new_arced(redis_conn_pool: RedisPool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            redis_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
+    }
+
+    fn key(&self, name: &str) -> String {
+        format!("{}_{}", self.budget_id, name)
     }
 }
 
@@ -22,7 +30,7 @@ impl YnabScheduledTransactionMetaRepo for RedisYnabScheduledTransactionMetaRepo
     #[tracing::instrument(skip(self))]
     async fn get_delta(&self) -> DbResult<i64> {
         self.redis_conn_pool
-            .get("scheduled_transactions_delta")
+            .get(self.key("scheduled_transactions_delta"))
             .await
             .map_err(Into::into)
     }
@@ -31,7 +39,7 @@ impl YnabScheduledTransactionMetaRepo for RedisYnabScheduledTransactionMetaRepo
     async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
         self.redis_conn_pool
             .set(
-                "scheduled_transactions_delta",
+                self.key("scheduled_transactions_delta"),
                 server_knowledge,
                 None,
                 None,
@@ -44,7 +52,7 @@ impl YnabScheduledTransactionMetaRepo for RedisYnabScheduledTransactionMetaRepo
     #[tracing::instrument(skip(self))]
     async fn del_delta(&self) -> DbResult<()> {
         self.redis_conn_pool
-            .del("scheduled_transactions_delta")
+            .del(self.key("scheduled_transactions_delta"))
             .await
             .map_err(Into::into)
     }
@@ -52,7 +60,7 @@ impl YnabScheduledTransactionMetaRepo for RedisYnabScheduledTransactionMetaRepo
     #[tracing::instrument(skip(self))]
     async fn get_last_saved(&self) -> DbResult<String> {
         self.redis_conn_pool
-            .get("scheduled_transactions_last_saved")
+            .get(self.key("scheduled_transactions_last_saved"))
             .await
             .map_err(Into::into)
     }
@@ -61,7 +69,7 @@ impl YnabScheduledTransactionMetaRepo for RedisYnabScheduledTransactionMetaRepo
     async fn set_last_saved(&self, last_saved: String) -> DbResult<()> {
         self.redis_conn_pool
             .set(
-                "scheduled_transactions_last_saved",
+                self.key("scheduled_transactions_last_saved"),
                 last_saved,
                 None,
                 None,
@@ -9,11 +9,22 @@ use fred::{clients::RedisPool, interfaces::KeysInterface};
 #[derive(Clone)]
 pub struct RedisYnabTransactionMetaRepo {
     pub redis_conn_pool: RedisPool,
+    pub budget_id: String,
 }
 
 impl RedisYnabTransactionMetaRepo {
-    pub fn new_arced(redis_conn_pool: RedisPool) -> Arc<dyn YnabTransactionMetaRepo> {
-        Arc::new(Self { redis_conn_pool })
+    pub fn new_arced(
+        redis_conn_pool: RedisPool,
+        budget_id: &str,
+    ) -> Arc<dyn YnabTransactionMetaRepo> {
+        Arc::new(Self {
+            redis_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
+    }
+
+    fn key(&self, name: &str) -> String {
+        format!("{}_{}", self.budget_id, name)
     }
 }
 
@@ -22,7 +33,7 @@ impl YnabTransactionMetaRepo for RedisYnabTransactionMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn get_delta(&self) -> DbResult<i64> {
         self.redis_conn_pool
-            .get("transactions_delta")
+            .get(self.key("transactions_delta"))
             .await
             .map_err(Into::into)
     }
@@ -30,7 +41,13 @@ impl YnabTransactionMetaRepo for RedisYnabTransactionMetaRepo {
     #[tracing::instrument(skip(self))]
     async fn set_delta(&self, server_knowledge: i64) -> DbResult<()> {
         self.redis_conn_pool
-            .set("transactions_delta", server_knowledge, None, None, false)
+            .set(
+                self.key("transactions_delta"),
+                server_knowledge,
+                None,
+                None,
+                false,
+            )
             .await?;
         Ok(())
     }
@@ -0,0 +1,12 @@
+-- YNAB's import_id are not UUIDs, e.g. YNAB:-294230:2015-12-30:1
+ALTER TABLE transactions ADD COLUMN import_id_text TEXT;
+UPDATE transactions SET import_id_text = lower(
+  substr(hex(import_id), 1, 8) || '-' ||
+  substr(hex(import_id), 9, 4) || '-' ||
+  substr(hex(import_id), 13, 4) || '-' ||
+  substr(hex(import_id), 17, 4) || '-' ||
+  substr(hex(import_id), 21)
+) WHERE typeof(import_id) = 'blob';
+UPDATE transactions SET import_id_text = import_id WHERE typeof(import_id) = 'text';
+ALTER TABLE transactions DROP COLUMN import_id;
+ALTER TABLE transactions RENAME COLUMN import_id_text TO import_id;
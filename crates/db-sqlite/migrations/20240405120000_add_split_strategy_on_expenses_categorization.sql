@@ -0,0 +1,2 @@
+-- How expenses of a category group are split between budgeters, as JSON
+ALTER TABLE expenses_categorization ADD COLUMN split_strategy TEXT NOT NULL DEFAULT '{"type":"proportional"}';
@@ -0,0 +1,7 @@
+-- Create Expenses Association Table, linking a category (or category group) to the budgeter paying for it
+CREATE TABLE expenses_association(
+  id BLOB NOT NULL,
+  type TEXT NOT NULL,
+  budgeter_id BLOB NOT NULL REFERENCES budgeters_config(id) ON DELETE CASCADE,
+  PRIMARY KEY (id)
+);
@@ -0,0 +1,2 @@
+-- Saving rate aimed for the year, between 0 and 1
+ALTER TABLE balance_sheet_saving_rates ADD COLUMN target REAL;
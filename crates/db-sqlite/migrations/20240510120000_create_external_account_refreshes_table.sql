@@ -0,0 +1,13 @@
+-- Create External Account Refreshes Table, keeping the outcome of each refresh of an external account
+CREATE TABLE external_account_refreshes(
+  id BLOB NOT NULL,
+  account_id BLOB NOT NULL REFERENCES external_accounts(id) ON DELETE CASCADE,
+  refreshed_at DATETIME NOT NULL,
+  duration_ms BIGINT NOT NULL,
+  succeeded BOOLEAN NOT NULL,
+  balance BIGINT,
+  error TEXT,
+  PRIMARY KEY (id)
+);
+
+CREATE INDEX external_account_refreshes_account_id_refreshed_at_idx ON external_account_refreshes(account_id, refreshed_at);
@@ -0,0 +1,3 @@
+-- Add the seed of the authenticator app generating the one-time codes of an external account,
+-- sealed like the password
+ALTER TABLE external_accounts ADD COLUMN encrypted_totp_seed BLOB;
@@ -0,0 +1,14 @@
+-- Allow associations without budgeter, marking the expenses of a category (or category group) as shared
+CREATE TABLE expenses_association_new(
+  id BLOB NOT NULL,
+  type TEXT NOT NULL,
+  budgeter_id BLOB REFERENCES budgeters_config(id) ON DELETE CASCADE,
+  PRIMARY KEY (id)
+);
+
+INSERT INTO expenses_association_new (id, type, budgeter_id)
+SELECT id, type, budgeter_id FROM expenses_association;
+
+DROP TABLE expenses_association;
+
+ALTER TABLE expenses_association_new RENAME TO expenses_association;
@@ -8,6 +8,7 @@ use datamize_domain::{
 };
 use itertools::Itertools;
 use sqlx::SqlitePool;
+use ynab::Milliunits;
 
 use super::SqliteFinResRepo;
 
@@ -224,9 +225,9 @@ impl MonthRepo for SqliteMonthRepo {
                 m.month as "month: MonthNum",
                 n.net_total_id as "net_total_id: Uuid",
                 n.type as "net_type: NetTotalType",
-                n.total,
+                n.total as "total: Milliunits",
                 n.percent_var as "percent_var: f32",
-                n.balance_var,
+                n.balance_var as "balance_var: Milliunits",
                 n.last_updated as "last_updated?: DateTime<Utc>"
             FROM balance_sheet_months AS m
             JOIN balance_sheet_net_totals_months AS n ON m.month_id = n.month_id
@@ -296,9 +297,9 @@ impl MonthRepo for SqliteMonthRepo {
             SELECT
                 net_total_id AS "id: Uuid",
                 type AS "net_type: NetTotalType",
-                total,
+                total as "total: Milliunits",
                 percent_var as "percent_var: f32",
-                balance_var,
+                balance_var as "balance_var: Milliunits",
                 last_updated as "last_updated?: DateTime<Utc>"
             FROM balance_sheet_net_totals_months
             WHERE month_id = $1;
@@ -390,9 +391,9 @@ impl MonthRepo for SqliteMonthRepo {
             "#,
             net_totals.assets.id,
             net_type,
-            net_totals.assets.total,
+            net_totals.assets.total as _,
             net_totals.assets.percent_var,
-            net_totals.assets.balance_var,
+            net_totals.assets.balance_var as _,
             net_totals.assets.last_updated,
             month_id,
         )
@@ -413,9 +414,9 @@ impl MonthRepo for SqliteMonthRepo {
             "#,
             net_totals.portfolio.id,
             net_type,
-            net_totals.portfolio.total,
+            net_totals.portfolio.total as _,
             net_totals.portfolio.percent_var,
-            net_totals.portfolio.balance_var,
+            net_totals.portfolio.balance_var as _,
             net_totals.portfolio.last_updated,
             month_id,
         )
@@ -5,12 +5,13 @@ use std::{
 
 use datamize_domain::{
     async_trait,
-    db::{DbError, DbResult, FinResRepo},
+    db::{DbError, DbResult, FinResOrderRepo, FinResRepo},
     FinancialResourceMonthly, FinancialResourceYearly, MonthNum, ResourceCategory, Uuid,
     YearlyBalances,
 };
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
+use ynab::Milliunits;
 
 #[derive(Debug, Clone)]
 pub struct SqliteFinResRepo {
@@ -112,7 +113,7 @@ impl FinResRepo for SqliteFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_resources AS r
@@ -171,7 +172,7 @@ impl FinResRepo for SqliteFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_resources AS r
@@ -236,7 +237,7 @@ impl FinResRepo for SqliteFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_resources AS r
@@ -302,7 +303,7 @@ impl FinResRepo for SqliteFinResRepo {
                     r.resource_type,
                     r.ynab_account_ids,
                     r.external_account_ids,
-                    rm.balance
+                    rm.balance as "balance: Milliunits"
                 FROM balance_sheet_resources AS r
                 JOIN balance_sheet_resources_months AS rm ON r.resource_id = rm.resource_id
                 JOIN balance_sheet_months AS m ON rm.month_id = m.month_id AND m.month = $1
@@ -353,7 +354,7 @@ impl FinResRepo for SqliteFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_resources AS r
@@ -415,7 +416,7 @@ impl FinResRepo for SqliteFinResRepo {
                 r.resource_type,
                 r.ynab_account_ids,
                 r.external_account_ids,
-                rm.balance,
+                rm.balance as "balance: Milliunits",
                 m.month AS "month: MonthNum",
                 y.year AS "year: i32"
             FROM balance_sheet_resources AS r
@@ -530,7 +531,7 @@ impl FinResRepo for SqliteFinResRepo {
                 "#,
                 resource.base.id,
                 month_data.id,
-                balance,
+                balance as _,
             )
             .execute(&self.db_conn_pool)
             .await?;
@@ -602,7 +603,7 @@ impl FinResRepo for SqliteFinResRepo {
                     "#,
                     resource.base.id,
                     month_data.id,
-                    balance,
+                    balance as _,
                 )
                 .execute(&self.db_conn_pool)
                 .await?;
@@ -639,6 +640,68 @@ impl FinResRepo for SqliteFinResRepo {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct SqliteFinResOrderRepo {
+    pub db_conn_pool: SqlitePool,
+}
+
+impl SqliteFinResOrderRepo {
+    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl FinResOrderRepo for SqliteFinResOrderRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_order(&self, year: i32, category: &ResourceCategory) -> DbResult<Vec<Uuid>> {
+        let category = category.to_string();
+        let res = sqlx::query_scalar!(
+            r#"
+            SELECT resource_ids
+            FROM balance_sheet_resources_order
+            WHERE year = $1 AND category = $2;
+            "#,
+            year,
+            category,
+        )
+        .fetch_optional(&self.db_conn_pool)
+        .await?;
+
+        match res {
+            Some(res) => Ok(serde_json::from_str(&res)?),
+            None => Ok(vec![]),
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn set_order(
+        &self,
+        year: i32,
+        category: &ResourceCategory,
+        order: &[Uuid],
+    ) -> DbResult<()> {
+        let category = category.to_string();
+        let serialized = serde_json::to_string(order)?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO balance_sheet_resources_order (year, category, resource_ids)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (year, category) DO UPDATE SET
+            resource_ids = EXCLUDED.resource_ids;
+            "#,
+            year,
+            category,
+            serialized,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct IdsRecord {
     ids: Option<Vec<Uuid>>,
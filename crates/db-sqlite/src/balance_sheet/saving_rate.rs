@@ -8,6 +8,7 @@ use datamize_domain::{
 };
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
+use ynab::Milliunits;
 
 #[derive(Debug, Clone)]
 pub struct SqliteSavingRateRepo {
@@ -46,10 +47,11 @@ impl SavingRateRepo for SqliteSavingRateRepo {
                 sr.saving_rate_id as "saving_rate_id: Uuid",
                 sr.name,
                 sr.savings,
-                sr.employer_contribution,
-                sr.employee_contribution,
-                sr.mortgage_capital,
-                sr.incomes
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
+                sr.incomes,
+                sr.target
             FROM balance_sheet_saving_rates AS sr
             JOIN balance_sheet_years AS y ON y.year_id = sr.year_id AND y.year = $1;
             "#,
@@ -69,7 +71,7 @@ impl SavingRateRepo for SqliteSavingRateRepo {
                     savings: Savings {
                         category_ids: savings.ids,
                         extra_balance: savings.extra_balance,
-                        total: 0,
+                        total: Milliunits::ZERO,
                     },
                     employer_contribution: r.employer_contribution,
                     employee_contribution: r.employee_contribution,
@@ -77,9 +79,12 @@ impl SavingRateRepo for SqliteSavingRateRepo {
                     incomes: Incomes {
                         payee_ids: incomes.ids,
                         extra_balance: incomes.extra_balance,
-                        total: 0,
+                        total: Milliunits::ZERO,
                     },
                     year,
+                    rate: 0.0,
+                    target: r.target,
+                    months: vec![],
                 }
             })
             .collect())
@@ -93,10 +98,11 @@ impl SavingRateRepo for SqliteSavingRateRepo {
                 sr.saving_rate_id as "id: Uuid",
                 sr.name,
                 sr.savings,
-                sr.employer_contribution,
-                sr.employee_contribution,
-                sr.mortgage_capital,
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
                 sr.incomes,
+                sr.target,
                 y.year as "year: i32"
             FROM balance_sheet_saving_rates AS sr
             JOIN balance_sheet_years AS y ON y.year_id = sr.year_id
@@ -116,7 +122,7 @@ impl SavingRateRepo for SqliteSavingRateRepo {
             savings: Savings {
                 category_ids: savings.ids,
                 extra_balance: savings.extra_balance,
-                total: 0,
+                total: Milliunits::ZERO,
             },
             employer_contribution: db_row.employer_contribution,
             employee_contribution: db_row.employee_contribution,
@@ -124,9 +130,12 @@ impl SavingRateRepo for SqliteSavingRateRepo {
             incomes: Incomes {
                 payee_ids: incomes.ids,
                 extra_balance: incomes.extra_balance,
-                total: 0,
+                total: Milliunits::ZERO,
             },
             year: db_row.year,
+            rate: 0.0,
+            target: db_row.target,
+            months: vec![],
         })
     }
 
@@ -138,10 +147,11 @@ impl SavingRateRepo for SqliteSavingRateRepo {
                 sr.saving_rate_id as "id: Uuid",
                 sr.name,
                 sr.savings,
-                sr.employer_contribution,
-                sr.employee_contribution,
-                sr.mortgage_capital,
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
                 sr.incomes,
+                sr.target,
                 y.year as "year: i32"
             FROM balance_sheet_saving_rates AS sr
             JOIN balance_sheet_years AS y ON y.year_id = sr.year_id
@@ -161,7 +171,7 @@ impl SavingRateRepo for SqliteSavingRateRepo {
             savings: Savings {
                 category_ids: savings.ids,
                 extra_balance: savings.extra_balance,
-                total: 0,
+                total: Milliunits::ZERO,
             },
             employer_contribution: db_row.employer_contribution,
             employee_contribution: db_row.employee_contribution,
@@ -169,12 +179,121 @@ impl SavingRateRepo for SqliteSavingRateRepo {
             incomes: Incomes {
                 payee_ids: incomes.ids,
                 extra_balance: incomes.extra_balance,
-                total: 0,
+                total: Milliunits::ZERO,
             },
             year: db_row.year,
+            rate: 0.0,
+            target: db_row.target,
+            months: vec![],
         })
     }
 
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<SavingRate>> {
+        let db_rows = sqlx::query!(
+            r#"
+            SELECT
+                sr.saving_rate_id as "id: Uuid",
+                sr.name,
+                sr.savings,
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
+                sr.incomes,
+                sr.target,
+                y.year as "year: i32"
+            FROM balance_sheet_saving_rates AS sr
+            JOIN balance_sheet_years AS y ON y.year_id = sr.year_id
+            ORDER BY y.year, sr.name;
+            "#
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_rows
+            .into_iter()
+            .map(|r| {
+                let savings: IdsAndBalanceRecord = serde_json::from_str(&r.savings).unwrap();
+                let incomes: IdsAndBalanceRecord = serde_json::from_str(&r.incomes).unwrap();
+                SavingRate {
+                    id: r.id,
+                    name: r.name,
+                    savings: Savings {
+                        category_ids: savings.ids,
+                        extra_balance: savings.extra_balance,
+                        total: Milliunits::ZERO,
+                    },
+                    employer_contribution: r.employer_contribution,
+                    employee_contribution: r.employee_contribution,
+                    mortgage_capital: r.mortgage_capital,
+                    incomes: Incomes {
+                        payee_ids: incomes.ids,
+                        extra_balance: incomes.extra_balance,
+                        total: Milliunits::ZERO,
+                    },
+                    year: r.year,
+                    rate: 0.0,
+                    target: r.target,
+                    months: vec![],
+                }
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_by_name(&self, name: &str) -> DbResult<Vec<SavingRate>> {
+        let db_rows = sqlx::query!(
+            r#"
+            SELECT
+                sr.saving_rate_id as "id: Uuid",
+                sr.name,
+                sr.savings,
+                sr.employer_contribution as "employer_contribution: Milliunits",
+                sr.employee_contribution as "employee_contribution: Milliunits",
+                sr.mortgage_capital as "mortgage_capital: Milliunits",
+                sr.incomes,
+                sr.target,
+                y.year as "year: i32"
+            FROM balance_sheet_saving_rates AS sr
+            JOIN balance_sheet_years AS y ON y.year_id = sr.year_id
+            WHERE sr.name = $1
+            ORDER BY y.year;
+            "#,
+            name,
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_rows
+            .into_iter()
+            .map(|r| {
+                let savings: IdsAndBalanceRecord = serde_json::from_str(&r.savings).unwrap();
+                let incomes: IdsAndBalanceRecord = serde_json::from_str(&r.incomes).unwrap();
+                SavingRate {
+                    id: r.id,
+                    name: r.name,
+                    savings: Savings {
+                        category_ids: savings.ids,
+                        extra_balance: savings.extra_balance,
+                        total: Milliunits::ZERO,
+                    },
+                    employer_contribution: r.employer_contribution,
+                    employee_contribution: r.employee_contribution,
+                    mortgage_capital: r.mortgage_capital,
+                    incomes: Incomes {
+                        payee_ids: incomes.ids,
+                        extra_balance: incomes.extra_balance,
+                        total: Milliunits::ZERO,
+                    },
+                    year: r.year,
+                    rate: 0.0,
+                    target: r.target,
+                    months: vec![],
+                }
+            })
+            .collect())
+    }
+
     #[tracing::instrument(skip_all)]
     async fn update(&self, saving_rate: &SavingRate) -> DbResult<()> {
         let year_data = self.get_year_data_by_number(saving_rate.year).await?;
@@ -191,8 +310,8 @@ impl SavingRateRepo for SqliteSavingRateRepo {
 
         sqlx::query!(
             r#"
-            INSERT INTO balance_sheet_saving_rates (saving_rate_id, name, savings, employer_contribution, employee_contribution, mortgage_capital, incomes, year_id)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            INSERT INTO balance_sheet_saving_rates (saving_rate_id, name, savings, employer_contribution, employee_contribution, mortgage_capital, incomes, year_id, target)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             ON CONFLICT (saving_rate_id) DO UPDATE SET
             name = EXCLUDED.name,
             savings = EXCLUDED.savings,
@@ -200,16 +319,18 @@ impl SavingRateRepo for SqliteSavingRateRepo {
             employee_contribution = EXCLUDED.employee_contribution,
             mortgage_capital = EXCLUDED.mortgage_capital,
             incomes = EXCLUDED.incomes,
-            year_id = EXCLUDED.year_id;
+            year_id = EXCLUDED.year_id,
+            target = EXCLUDED.target;
             "#,
             saving_rate.id,
             saving_rate.name,
             savings,
-            saving_rate.employer_contribution,
-            saving_rate.employee_contribution,
-            saving_rate.mortgage_capital,
+            saving_rate.employer_contribution as _,
+            saving_rate.employee_contribution as _,
+            saving_rate.mortgage_capital as _,
             incomes,
-            year_data.id
+            year_data.id,
+            saving_rate.target
         )
         .execute(&self.db_conn_pool)
         .await?;
@@ -236,7 +357,7 @@ impl SavingRateRepo for SqliteSavingRateRepo {
 #[derive(Debug, Serialize, Deserialize, sqlx::Type)]
 struct IdsAndBalanceRecord {
     ids: Vec<Uuid>,
-    extra_balance: i64,
+    extra_balance: Milliunits,
 }
 
 pub async fn sabotage_saving_rates_table(pool: &SqlitePool) -> DbResult<()> {
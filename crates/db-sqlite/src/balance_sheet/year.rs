@@ -8,6 +8,7 @@ use datamize_domain::{async_trait, NetTotal, NetTotals, Uuid, Year};
 use futures::try_join;
 use itertools::Itertools;
 use sqlx::SqlitePool;
+use ynab::Milliunits;
 
 use super::{SqliteFinResRepo, SqliteMonthRepo};
 
@@ -200,9 +201,9 @@ impl YearRepo for SqliteYearRepo {
             SELECT
                 net_total_id AS "id: Uuid",
                 type AS "net_type: NetTotalType",
-                total,
+                total as "total: Milliunits",
                 percent_var as "percent_var: f32",
-                balance_var,
+                balance_var as "balance_var: Milliunits",
                 last_updated as "last_updated?: DateTime<Utc>"
             FROM balance_sheet_net_totals_years
             WHERE year_id = $1;
@@ -287,9 +288,9 @@ impl YearRepo for SqliteYearRepo {
             "#,
             net_totals.assets.id,
             net_type,
-            net_totals.assets.total,
+            net_totals.assets.total as _,
             net_totals.assets.percent_var,
-            net_totals.assets.balance_var,
+            net_totals.assets.balance_var as _,
             net_totals.assets.last_updated,
             year_id,
         )
@@ -310,9 +311,9 @@ impl YearRepo for SqliteYearRepo {
             "#,
             net_totals.portfolio.id,
             net_type,
-            net_totals.portfolio.total,
+            net_totals.portfolio.total as _,
             net_totals.portfolio.percent_var,
-            net_totals.portfolio.balance_var,
+            net_totals.portfolio.balance_var as _,
             net_totals.portfolio.last_updated,
             year_id,
         )
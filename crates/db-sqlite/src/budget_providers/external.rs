@@ -1,12 +1,24 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
+
 use datamize_domain::{
     async_trait,
-    db::{external::ExternalAccountRepo, DbResult},
+    db::{
+        external::{
+            EncryptionKeyRepo, ExternalAccountBalanceRepo, ExternalAccountRefreshRepo,
+            ExternalAccountRepo,
+        },
+        DbError, DbResult,
+    },
     secrecy::{ExposeSecret, Secret},
-    AccountType, EncryptedPassword, Uuid, WebScrapingAccount,
+    AccountType, EncryptedPassword, ExternalAccountBalance, ExternalAccountRefresh, Uuid,
+    WebScrapingAccount,
 };
 use sqlx::SqlitePool;
+use ynab::Milliunits;
+
+use crate::secrets::{get_secret, set_secret};
 
 #[derive(Debug, Clone)]
 pub struct SqliteExternalAccountRepo {
@@ -29,10 +41,12 @@ impl ExternalAccountRepo for SqliteExternalAccountRepo {
             id as "id: Uuid",
             name,
             type as "type: AccountType",
-            balance,
+            balance as "balance: Milliunits",
             username,
             encrypted_password,
-            deleted
+            deleted,
+            scraper_id,
+            encrypted_totp_seed
             FROM external_accounts;
             "#
         )
@@ -44,6 +58,10 @@ impl ExternalAccountRepo for SqliteExternalAccountRepo {
             username: row.username,
             encrypted_password: Secret::new(EncryptedPassword::new(row.encrypted_password)),
             deleted: row.deleted,
+            scraper_id: row.scraper_id,
+            encrypted_totp_seed: row
+                .encrypted_totp_seed
+                .map(|seed| Secret::new(EncryptedPassword::new(seed))),
         })
         .fetch_all(&self.db_conn_pool)
         .await
@@ -58,10 +76,12 @@ impl ExternalAccountRepo for SqliteExternalAccountRepo {
             id as "id: Uuid",
             name,
             type as "type: AccountType",
-            balance,
+            balance as "balance: Milliunits",
             username,
             encrypted_password,
-            deleted
+            deleted,
+            scraper_id,
+            encrypted_totp_seed
             FROM external_accounts
             WHERE id = $1;
             "#,
@@ -75,6 +95,10 @@ impl ExternalAccountRepo for SqliteExternalAccountRepo {
             username: row.username,
             encrypted_password: Secret::new(EncryptedPassword::new(row.encrypted_password)),
             deleted: row.deleted,
+            scraper_id: row.scraper_id,
+            encrypted_totp_seed: row
+                .encrypted_totp_seed
+                .map(|seed| Secret::new(EncryptedPassword::new(seed))),
         })
         .fetch_one(&self.db_conn_pool)
         .await
@@ -89,10 +113,12 @@ impl ExternalAccountRepo for SqliteExternalAccountRepo {
             id as "id: Uuid",
             name,
             type as "type: AccountType",
-            balance,
+            balance as "balance: Milliunits",
             username,
             encrypted_password,
-            deleted
+            deleted,
+            scraper_id,
+            encrypted_totp_seed
             FROM external_accounts
             WHERE name = $1;
             "#,
@@ -106,6 +132,10 @@ impl ExternalAccountRepo for SqliteExternalAccountRepo {
             username: row.username,
             encrypted_password: Secret::new(EncryptedPassword::new(row.encrypted_password)),
             deleted: row.deleted,
+            scraper_id: row.scraper_id,
+            encrypted_totp_seed: row
+                .encrypted_totp_seed
+                .map(|seed| Secret::new(EncryptedPassword::new(seed))),
         })
         .fetch_one(&self.db_conn_pool)
         .await
@@ -115,19 +145,25 @@ impl ExternalAccountRepo for SqliteExternalAccountRepo {
     #[tracing::instrument(skip_all)]
     async fn add(&self, account: &WebScrapingAccount) -> DbResult<()> {
         let encrypted_password = account.encrypted_password.expose_secret().as_ref();
+        let encrypted_totp_seed = account
+            .encrypted_totp_seed
+            .as_ref()
+            .map(|seed| seed.expose_secret().as_ref());
 
         sqlx::query!(
             r#"
-            INSERT INTO external_accounts (id, name, type, balance, username, encrypted_password, deleted)
-            VALUES ($1, $2, $3, $4, $5, $6, $7);
+            INSERT INTO external_accounts (id, name, type, balance, username, encrypted_password, deleted, scraper_id, encrypted_totp_seed)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9);
             "#,
             account.id,
             account.name,
             account.account_type,
-            account.balance,
+            account.balance as _,
             account.username,
             encrypted_password,
             account.deleted,
+            account.scraper_id,
+            encrypted_totp_seed,
         )
         .execute(&self.db_conn_pool)
         .await?;
@@ -138,26 +174,34 @@ impl ExternalAccountRepo for SqliteExternalAccountRepo {
     #[tracing::instrument(skip_all)]
     async fn update(&self, account: &WebScrapingAccount) -> DbResult<()> {
         let encrypted_password = account.encrypted_password.expose_secret().as_ref();
+        let encrypted_totp_seed = account
+            .encrypted_totp_seed
+            .as_ref()
+            .map(|seed| seed.expose_secret().as_ref());
 
         sqlx::query!(
             r#"
-            INSERT INTO external_accounts (id, name, type, balance, username, encrypted_password, deleted)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            INSERT INTO external_accounts (id, name, type, balance, username, encrypted_password, deleted, scraper_id, encrypted_totp_seed)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             ON CONFLICT (id) DO UPDATE SET
             name = EXCLUDED.name,
             type = EXCLUDED.type,
             balance = EXCLUDED.balance,
             username = EXCLUDED.username,
             encrypted_password = EXCLUDED.encrypted_password,
-            deleted = EXCLUDED.deleted;
+            deleted = EXCLUDED.deleted,
+            scraper_id = EXCLUDED.scraper_id,
+            encrypted_totp_seed = EXCLUDED.encrypted_totp_seed;
             "#,
             account.id,
             account.name,
             account.account_type,
-            account.balance,
+            account.balance as _,
             account.username,
             encrypted_password,
             account.deleted,
+            account.scraper_id,
+            encrypted_totp_seed,
         )
         .execute(&self.db_conn_pool)
         .await?;
@@ -188,3 +232,282 @@ pub async fn sabotage_external_accounts_table(pool: &SqlitePool) -> DbResult<()>
 
     Ok(())
 }
+
+#[derive(Debug, Clone)]
+pub struct SqliteExternalAccountRefreshRepo {
+    pub db_conn_pool: SqlitePool,
+}
+
+impl SqliteExternalAccountRefreshRepo {
+    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl ExternalAccountRefreshRepo for SqliteExternalAccountRefreshRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self, account_id: Uuid) -> DbResult<Vec<ExternalAccountRefresh>> {
+        let db_rows = sqlx::query_as!(
+            ExternalAccountRefreshRow,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                account_id as "account_id: Uuid",
+                refreshed_at as "refreshed_at: DateTime<Utc>",
+                duration_ms,
+                succeeded,
+                balance,
+                error
+            FROM external_account_refreshes
+            WHERE account_id = $1
+            ORDER BY refreshed_at;
+            "#,
+            account_id,
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_rows.into_iter().map(Into::into).collect())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last(&self, account_id: Uuid) -> DbResult<ExternalAccountRefresh> {
+        let db_row = sqlx::query_as!(
+            ExternalAccountRefreshRow,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                account_id as "account_id: Uuid",
+                refreshed_at as "refreshed_at: DateTime<Utc>",
+                duration_ms,
+                succeeded,
+                balance,
+                error
+            FROM external_account_refreshes
+            WHERE account_id = $1
+            ORDER BY refreshed_at DESC
+            LIMIT 1;
+            "#,
+            account_id,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_row.into())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last_success(&self, account_id: Uuid) -> DbResult<ExternalAccountRefresh> {
+        let db_row = sqlx::query_as!(
+            ExternalAccountRefreshRow,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                account_id as "account_id: Uuid",
+                refreshed_at as "refreshed_at: DateTime<Utc>",
+                duration_ms,
+                succeeded,
+                balance,
+                error
+            FROM external_account_refreshes
+            WHERE account_id = $1 AND succeeded
+            ORDER BY refreshed_at DESC
+            LIMIT 1;
+            "#,
+            account_id,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?;
+
+        Ok(db_row.into())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add(&self, refresh: &ExternalAccountRefresh) -> DbResult<()> {
+        let balance = refresh.balance.map(i64::from);
+        sqlx::query!(
+            r#"
+            INSERT INTO external_account_refreshes (id, account_id, refreshed_at, duration_ms, succeeded, balance, error)
+            VALUES ($1, $2, $3, $4, $5, $6, $7);
+            "#,
+            refresh.id,
+            refresh.account_id,
+            refresh.refreshed_at,
+            refresh.duration_ms,
+            refresh.succeeded,
+            balance,
+            refresh.error,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+struct ExternalAccountRefreshRow {
+    id: Uuid,
+    account_id: Uuid,
+    refreshed_at: DateTime<Utc>,
+    duration_ms: i64,
+    succeeded: bool,
+    balance: Option<i64>,
+    error: Option<String>,
+}
+
+impl From<ExternalAccountRefreshRow> for ExternalAccountRefresh {
+    fn from(value: ExternalAccountRefreshRow) -> Self {
+        Self {
+            id: value.id,
+            account_id: value.account_id,
+            refreshed_at: value.refreshed_at,
+            duration_ms: value.duration_ms,
+            succeeded: value.succeeded,
+            balance: value.balance.map(Into::into),
+            error: value.error,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteExternalAccountBalanceRepo {
+    pub db_conn_pool: SqlitePool,
+}
+
+impl SqliteExternalAccountBalanceRepo {
+    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl ExternalAccountBalanceRepo for SqliteExternalAccountBalanceRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(
+        &self,
+        account_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> DbResult<Vec<ExternalAccountBalance>> {
+        let db_rows = sqlx::query_as!(
+            ExternalAccountBalanceRow,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                account_id as "account_id: Uuid",
+                observed_at as "observed_at: DateTime<Utc>",
+                balance,
+                source
+            FROM external_account_balances
+            WHERE account_id = $1
+                AND ($2 IS NULL OR observed_at >= $2)
+                AND ($3 IS NULL OR observed_at < $3)
+            ORDER BY observed_at;
+            "#,
+            account_id,
+            from,
+            to,
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await?;
+
+        db_rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_last(
+        &self,
+        account_id: Uuid,
+        to: Option<DateTime<Utc>>,
+    ) -> DbResult<ExternalAccountBalance> {
+        let db_row = sqlx::query_as!(
+            ExternalAccountBalanceRow,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                account_id as "account_id: Uuid",
+                observed_at as "observed_at: DateTime<Utc>",
+                balance,
+                source
+            FROM external_account_balances
+            WHERE account_id = $1 AND ($2 IS NULL OR observed_at < $2)
+            ORDER BY observed_at DESC
+            LIMIT 1;
+            "#,
+            account_id,
+            to,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await?;
+
+        db_row.try_into()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn add(&self, balance: &ExternalAccountBalance) -> DbResult<()> {
+        let amount = i64::from(balance.balance);
+        let source = balance.source.to_string();
+        sqlx::query!(
+            r#"
+            INSERT INTO external_account_balances (id, account_id, observed_at, balance, source)
+            VALUES ($1, $2, $3, $4, $5);
+            "#,
+            balance.id,
+            balance.account_id,
+            balance.observed_at,
+            amount,
+            source,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+struct ExternalAccountBalanceRow {
+    id: Uuid,
+    account_id: Uuid,
+    observed_at: DateTime<Utc>,
+    balance: i64,
+    source: String,
+}
+
+impl TryFrom<ExternalAccountBalanceRow> for ExternalAccountBalance {
+    type Error = DbError;
+
+    fn try_from(value: ExternalAccountBalanceRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            account_id: value.account_id,
+            observed_at: value.observed_at,
+            balance: value.balance.into(),
+            source: value.source.parse().map_err(DbError::DataIntegrityError)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteEncryptionKeyRepo {
+    pub db_conn_pool: SqlitePool,
+}
+
+impl SqliteEncryptionKeyRepo {
+    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl EncryptionKeyRepo for SqliteEncryptionKeyRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get(&self) -> DbResult<Vec<u8>> {
+        get_secret(&self.db_conn_pool, "encryption_key").await
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn set(&self, encryption_key_str: &[u8]) -> DbResult<()> {
+        set_secret(&self.db_conn_pool, "encryption_key", encryption_key_str).await
+    }
+}
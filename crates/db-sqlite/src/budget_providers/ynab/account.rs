@@ -12,11 +12,15 @@ use ynab::{Account, AccountType};
 #[derive(Debug, Clone)]
 pub struct SqliteYnabAccountRepo {
     pub db_conn_pool: SqlitePool,
+    pub budget_id: String,
 }
 
 impl SqliteYnabAccountRepo {
-    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
-        Arc::new(Self { db_conn_pool })
+    pub fn new_arced(db_conn_pool: SqlitePool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
     }
 }
 
@@ -46,7 +50,9 @@ impl YnabAccountRepo for SqliteYnabAccountRepo {
                 debt_minimum_payments,
                 debt_escrow_amounts
             FROM accounts
-            "#
+            WHERE budget_id = $1
+            "#,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -85,9 +91,10 @@ impl YnabAccountRepo for SqliteYnabAccountRepo {
 
             sqlx::query!(
                     r#"
-                    INSERT INTO accounts (id, name, type, on_budget, closed, note, balance, cleared_balance, uncleared_balance, transfer_payee_id, direct_import_linked, direct_import_in_error, deleted, last_reconciled_at, debt_original_balance, debt_interest_rates, debt_minimum_payments, debt_escrow_amounts)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+                    INSERT INTO accounts (id, name, type, on_budget, closed, note, balance, cleared_balance, uncleared_balance, transfer_payee_id, direct_import_linked, direct_import_in_error, deleted, last_reconciled_at, debt_original_balance, debt_interest_rates, debt_minimum_payments, debt_escrow_amounts, budget_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
                     ON CONFLICT (id) DO UPDATE SET
+                    budget_id = EXCLUDED.budget_id,
                     name = EXCLUDED.name,
                     type = EXCLUDED.type,
                     on_budget = EXCLUDED.on_budget,
@@ -124,6 +131,7 @@ impl YnabAccountRepo for SqliteYnabAccountRepo {
                     debt_interest_rates,
                     debt_minimum_payments,
                     debt_escrow_amounts,
+                    self.budget_id,
                 ).execute(&self.db_conn_pool).await?;
         }
 
@@ -6,16 +6,20 @@ use datamize_domain::{
     Uuid,
 };
 use sqlx::SqlitePool;
-use ynab::{Category, GoalType};
+use ynab::{Category, GoalType, Milliunits};
 
 #[derive(Debug, Clone)]
 pub struct SqliteYnabCategoryRepo {
     pub db_conn_pool: SqlitePool,
+    pub budget_id: String,
 }
 
 impl SqliteYnabCategoryRepo {
-    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
-        Arc::new(Self { db_conn_pool })
+    pub fn new_arced(db_conn_pool: SqlitePool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
     }
 }
 
@@ -34,12 +38,12 @@ impl YnabCategoryRepo for SqliteYnabCategoryRepo {
                 hidden,
                 original_category_group_id as "original_category_group_id?: Uuid",
                 note,
-                budgeted,
-                activity,
-                balance,
+                budgeted as "budgeted: Milliunits",
+                activity as "activity: Milliunits",
+                balance as "balance: Milliunits",
                 goal_type AS "goal_type?: GoalType",
                 goal_creation_month,
-                goal_target,
+                goal_target as "goal_target?: Milliunits",
                 goal_target_month,
                 goal_percentage_complete as "goal_percentage_complete?: i32",
                 goal_months_to_budget as "goal_months_to_budget?: i32",
@@ -51,7 +55,9 @@ impl YnabCategoryRepo for SqliteYnabCategoryRepo {
                 goal_cadence as "goal_cadence?: i32",
                 goal_cadence_frequency as "goal_cadence_frequency?: i32"
             FROM categories
-            "#
+            WHERE budget_id = $1
+            "#,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await
@@ -71,12 +77,12 @@ impl YnabCategoryRepo for SqliteYnabCategoryRepo {
                 hidden,
                 original_category_group_id as "original_category_group_id?: Uuid",
                 note,
-                budgeted,
-                activity,
-                balance,
+                budgeted as "budgeted: Milliunits",
+                activity as "activity: Milliunits",
+                balance as "balance: Milliunits",
                 goal_type AS "goal_type?: GoalType",
                 goal_creation_month,
-                goal_target,
+                goal_target as "goal_target?: Milliunits",
                 goal_target_month,
                 goal_percentage_complete as "goal_percentage_complete?: i32",
                 goal_months_to_budget as "goal_months_to_budget?: i32",
@@ -88,9 +94,10 @@ impl YnabCategoryRepo for SqliteYnabCategoryRepo {
                 goal_cadence as "goal_cadence?: i32",
                 goal_cadence_frequency as "goal_cadence_frequency?: i32"
             FROM categories
-            WHERE id = $1
+            WHERE id = $1 AND budget_id = $2
             "#,
             category_id,
+            self.budget_id,
         )
         .fetch_one(&self.db_conn_pool)
         .await
@@ -102,9 +109,10 @@ impl YnabCategoryRepo for SqliteYnabCategoryRepo {
         for c in categories {
             sqlx::query!(
                     r#"
-                    INSERT INTO categories (id, category_group_id, category_group_name, name, hidden, original_category_group_id, note, budgeted, activity, balance, goal_type, goal_creation_month, goal_target, goal_target_month, goal_percentage_complete, goal_months_to_budget, goal_under_funded, goal_overall_funded, goal_overall_left, deleted, goal_day, goal_cadence, goal_cadence_frequency)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23)
+                    INSERT INTO categories (id, category_group_id, category_group_name, name, hidden, original_category_group_id, note, budgeted, activity, balance, goal_type, goal_creation_month, goal_target, goal_target_month, goal_percentage_complete, goal_months_to_budget, goal_under_funded, goal_overall_funded, goal_overall_left, deleted, goal_day, goal_cadence, goal_cadence_frequency, budget_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24)
                     ON CONFLICT (id) DO UPDATE SET
+                    budget_id = EXCLUDED.budget_id,
                     category_group_id = EXCLUDED.category_group_id,
                     category_group_name = EXCLUDED.category_group_name,
                     name = EXCLUDED.name,
@@ -135,12 +143,12 @@ impl YnabCategoryRepo for SqliteYnabCategoryRepo {
                     c.hidden,
                     c.original_category_group_id,
                     c.note,
-                    c.budgeted,
-                    c.activity,
-                    c.balance,
+                    c.budgeted as _,
+                    c.activity as _,
+                    c.balance as _,
                     c.goal_type,
                     c.goal_creation_month,
-                    c.goal_target,
+                    c.goal_target as _,
                     c.goal_target_month,
                     c.goal_percentage_complete,
                     c.goal_months_to_budget,
@@ -151,6 +159,7 @@ impl YnabCategoryRepo for SqliteYnabCategoryRepo {
                     c.goal_day,
                     c.goal_cadence,
                     c.goal_cadence_frequency,
+                    self.budget_id,
                 ).execute(&self.db_conn_pool).await?;
         }
 
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{ynab::YnabOAuthTokenRepo, DbResult},
+};
+use sqlx::SqlitePool;
+
+use crate::secrets::{get_secret, set_secret};
+
+#[derive(Debug, Clone)]
+pub struct SqliteYnabOAuthTokenRepo {
+    pub db_conn_pool: SqlitePool,
+}
+
+impl SqliteYnabOAuthTokenRepo {
+    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl YnabOAuthTokenRepo for SqliteYnabOAuthTokenRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, member: &str) -> DbResult<Vec<u8>> {
+        get_secret(&self.db_conn_pool, &format!("ynab_oauth_tokens_{}", member)).await
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn set(&self, member: &str, encrypted_tokens: &[u8]) -> DbResult<()> {
+        set_secret(
+            &self.db_conn_pool,
+            &format!("ynab_oauth_tokens_{}", member),
+            encrypted_tokens,
+        )
+        .await
+    }
+}
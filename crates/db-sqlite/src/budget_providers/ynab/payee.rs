@@ -11,11 +11,15 @@ use ynab::Payee;
 #[derive(Debug, Clone)]
 pub struct SqliteYnabPayeeRepo {
     pub db_conn_pool: SqlitePool,
+    pub budget_id: String,
 }
 
 impl SqliteYnabPayeeRepo {
-    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
-        Arc::new(Self { db_conn_pool })
+    pub fn new_arced(db_conn_pool: SqlitePool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
     }
 }
 
@@ -32,7 +36,9 @@ impl YnabPayeeRepo for SqliteYnabPayeeRepo {
                 transfer_account_id as "transfer_account_id?: Uuid",
                 deleted
             FROM payees
-            "#
+            WHERE budget_id = $1
+            "#,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await
@@ -44,9 +50,10 @@ impl YnabPayeeRepo for SqliteYnabPayeeRepo {
         for p in payees {
             sqlx::query!(
                 r#"
-                    INSERT INTO payees (id, name, transfer_account_id, deleted)
-                    VALUES ($1, $2, $3, $4)
+                    INSERT INTO payees (id, name, transfer_account_id, deleted, budget_id)
+                    VALUES ($1, $2, $3, $4, $5)
                     ON CONFLICT (id) DO UPDATE SET
+                    budget_id = EXCLUDED.budget_id,
                     name = EXCLUDED.name,
                     transfer_account_id = EXCLUDED.transfer_account_id,
                     deleted = EXCLUDED.deleted;
@@ -54,7 +61,8 @@ impl YnabPayeeRepo for SqliteYnabPayeeRepo {
                 p.id,
                 p.name,
                 p.transfer_account_id,
-                p.deleted
+                p.deleted,
+                self.budget_id,
             )
             .execute(&self.db_conn_pool)
             .await?;
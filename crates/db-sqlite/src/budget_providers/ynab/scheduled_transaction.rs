@@ -12,11 +12,15 @@ use ynab::{RecurFrequency, ScheduledTransactionDetail};
 #[derive(Debug, Clone)]
 pub struct SqliteYnabScheduledTransactionRepo {
     pub db_conn_pool: SqlitePool,
+    pub budget_id: String,
 }
 
 impl SqliteYnabScheduledTransactionRepo {
-    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
-        Arc::new(Self { db_conn_pool })
+    pub fn new_arced(db_conn_pool: SqlitePool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
     }
 }
 
@@ -44,7 +48,9 @@ impl YnabScheduledTransactionRepo for SqliteYnabScheduledTransactionRepo {
                 category_name,
                 subtransactions
             FROM scheduled_transactions
-            "#
+            WHERE budget_id = $1
+            "#,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -85,9 +91,10 @@ impl YnabScheduledTransactionRepo for SqliteYnabScheduledTransactionRepo {
                 sqlx::query!(
                     r#"
                     DELETE FROM scheduled_transactions
-                    WHERE id = $1
+                    WHERE id = $1 AND budget_id = $2
                     "#,
-                    st.id
+                    st.id,
+                    self.budget_id,
                 )
                 .execute(&self.db_conn_pool)
                 .await?;
@@ -96,8 +103,8 @@ impl YnabScheduledTransactionRepo for SqliteYnabScheduledTransactionRepo {
 
                 sqlx::query!(
                     r#"
-                    INSERT INTO scheduled_transactions (id, date_first, date_next, frequency, amount, memo, flag_color, account_id, payee_id, category_id, transfer_account_id, deleted, account_name, payee_name, category_name, subtransactions)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                    INSERT INTO scheduled_transactions (id, date_first, date_next, frequency, amount, memo, flag_color, account_id, payee_id, category_id, transfer_account_id, deleted, account_name, payee_name, category_name, subtransactions, budget_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
                     ON CONFLICT (id) DO UPDATE
                     SET date_first = EXCLUDED.date_first,
                     date_next = EXCLUDED.date_next,
@@ -131,6 +138,7 @@ impl YnabScheduledTransactionRepo for SqliteYnabScheduledTransactionRepo {
                     st.payee_name,
                     st.category_name,
                     subtransactions,
+                    self.budget_id,
                 ).execute(&self.db_conn_pool).await?;
             }
         }
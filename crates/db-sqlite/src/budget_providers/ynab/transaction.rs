@@ -12,11 +12,15 @@ use ynab::{BaseTransactionDetail, ClearedType, DebtTransactionType, TransactionD
 #[derive(Debug, Clone)]
 pub struct SqliteYnabTransactionRepo {
     pub db_conn_pool: SqlitePool,
+    pub budget_id: String,
 }
 
 impl SqliteYnabTransactionRepo {
-    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
-        Arc::new(Self { db_conn_pool })
+    pub fn new_arced(db_conn_pool: SqlitePool, budget_id: &str) -> Arc<Self> {
+        Arc::new(Self {
+            db_conn_pool,
+            budget_id: budget_id.to_string(),
+        })
     }
 }
 
@@ -40,7 +44,7 @@ impl YnabTransactionRepo for SqliteYnabTransactionRepo {
                 transfer_account_id as "transfer_account_id?: Uuid",
                 transfer_transaction_id as "transfer_transaction_id?: Uuid",
                 matched_transaction_id as "matched_transaction_id?: Uuid",
-                import_id as "import_id?: Uuid",
+                import_id as "import_id?: String",
                 deleted,
                 account_name,
                 payee_name,
@@ -50,7 +54,9 @@ impl YnabTransactionRepo for SqliteYnabTransactionRepo {
                 debt_transaction_type as "debt_transaction_type?: DebtTransactionType",
                 subtransactions
             FROM transactions
-            "#
+            WHERE budget_id = $1
+            "#,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -93,9 +99,10 @@ impl YnabTransactionRepo for SqliteYnabTransactionRepo {
 
             sqlx::query!(
                     r#"
-                    INSERT INTO transactions (id, date, amount, memo, cleared, approved, flag_color, account_id, payee_id, category_id, transfer_account_id, transfer_transaction_id, matched_transaction_id, import_id, deleted, account_name, payee_name, category_name, import_payee_name, import_payee_name_original, debt_transaction_type, subtransactions)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
+                    INSERT INTO transactions (id, date, amount, memo, cleared, approved, flag_color, account_id, payee_id, category_id, transfer_account_id, transfer_transaction_id, matched_transaction_id, import_id, deleted, account_name, payee_name, category_name, import_payee_name, import_payee_name_original, debt_transaction_type, subtransactions, budget_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23)
                     ON CONFLICT (id) DO UPDATE SET
+                    budget_id = EXCLUDED.budget_id,
                     date = EXCLUDED.date,
                     amount = EXCLUDED.amount,
                     memo = EXCLUDED.memo,
@@ -139,7 +146,8 @@ impl YnabTransactionRepo for SqliteYnabTransactionRepo {
                     t.base.import_payee_name,
                     t.base.import_payee_name_original,
                     t.base.debt_transaction_type,
-                    subtransactions
+                    subtransactions,
+                    self.budget_id,
                 ).execute(&self.db_conn_pool).await?;
         }
 
@@ -164,7 +172,7 @@ impl YnabTransactionRepo for SqliteYnabTransactionRepo {
                 transfer_account_id as "transfer_account_id?: Uuid",
                 transfer_transaction_id as "transfer_transaction_id?: Uuid",
                 matched_transaction_id as "matched_transaction_id?: Uuid",
-                import_id as "import_id?: Uuid",
+                import_id as "import_id?: String",
                 deleted,
                 account_name,
                 payee_name,
@@ -174,9 +182,10 @@ impl YnabTransactionRepo for SqliteYnabTransactionRepo {
                 debt_transaction_type as "debt_transaction_type?: DebtTransactionType",
                 subtransactions
             FROM transactions
-            WHERE payee_id = $1
+            WHERE payee_id = $1 AND budget_id = $2
             "#,
-            payee_id
+            payee_id,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -233,7 +242,7 @@ impl YnabTransactionRepo for SqliteYnabTransactionRepo {
                 transfer_account_id as "transfer_account_id?: Uuid",
                 transfer_transaction_id as "transfer_transaction_id?: Uuid",
                 matched_transaction_id as "matched_transaction_id?: Uuid",
-                import_id as "import_id?: Uuid",
+                import_id as "import_id?: String",
                 deleted,
                 account_name,
                 payee_name,
@@ -243,9 +252,10 @@ impl YnabTransactionRepo for SqliteYnabTransactionRepo {
                 debt_transaction_type as "debt_transaction_type?: DebtTransactionType",
                 subtransactions
             FROM transactions
-            WHERE category_id = $1
+            WHERE category_id = $1 AND budget_id = $2
             "#,
-            category_id
+            category_id,
+            self.budget_id,
         )
         .fetch_all(&self.db_conn_pool)
         .await?;
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use datamize_domain::{
+    async_trait,
+    db::{DbResult, ExpenseAssociationRepo},
+    AssociationType, ExpenseAssociation, SplitStrategy, Uuid,
+};
+use sqlx::SqlitePool;
+
+#[derive(Debug, Clone)]
+pub struct SqliteExpenseAssociationRepo {
+    pub db_conn_pool: SqlitePool,
+}
+
+impl SqliteExpenseAssociationRepo {
+    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+#[async_trait]
+impl ExpenseAssociationRepo for SqliteExpenseAssociationRepo {
+    #[tracing::instrument(skip(self))]
+    async fn get_all(&self) -> DbResult<Vec<ExpenseAssociation>> {
+        sqlx::query_as!(
+            ExpenseAssociation,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                type as "association_type: AssociationType",
+                budgeter_id as "budgeter_id?: Uuid",
+                split_strategy as "split_strategy?: SplitStrategy"
+            FROM expenses_association
+            "#
+        )
+        .fetch_all(&self.db_conn_pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get(&self, id: Uuid) -> DbResult<ExpenseAssociation> {
+        sqlx::query_as!(
+            ExpenseAssociation,
+            r#"
+            SELECT
+                id as "id: Uuid",
+                type as "association_type: AssociationType",
+                budgeter_id as "budgeter_id?: Uuid",
+                split_strategy as "split_strategy?: SplitStrategy"
+            FROM expenses_association
+            WHERE id = $1;
+            "#,
+            id,
+        )
+        .fetch_one(&self.db_conn_pool)
+        .await
+        .map_err(Into::into)
+    }
+
+    #[tracing::instrument(skip_all)]
+    async fn update(&self, expense_association: &ExpenseAssociation) -> DbResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO expenses_association (id, type, budgeter_id, split_strategy)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (id) DO UPDATE
+            SET type = EXCLUDED.type,
+            budgeter_id = EXCLUDED.budgeter_id,
+            split_strategy = EXCLUDED.split_strategy;
+            "#,
+            expense_association.id,
+            expense_association.association_type,
+            expense_association.budgeter_id,
+            expense_association.split_strategy,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete(&self, id: Uuid) -> DbResult<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM expenses_association
+            WHERE id = $1;
+            "#,
+            id,
+        )
+        .execute(&self.db_conn_pool)
+        .await?;
+
+        Ok(())
+    }
+}
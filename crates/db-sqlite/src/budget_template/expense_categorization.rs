@@ -3,7 +3,7 @@ use std::sync::Arc;
 use datamize_domain::{
     async_trait,
     db::{DbResult, ExpenseCategorizationRepo},
-    ExpenseCategorization, ExpenseType, SubExpenseType, Uuid,
+    ExpenseCategorization, ExpenseType, SplitStrategy, SubExpenseType, Uuid,
 };
 use sqlx::SqlitePool;
 
@@ -29,7 +29,8 @@ impl ExpenseCategorizationRepo for SqliteExpenseCategorizationRepo {
                 id as "id: Uuid",
                 name,
                 type as "expense_type: ExpenseType",
-                sub_type as "sub_expense_type: SubExpenseType"
+                sub_type as "sub_expense_type: SubExpenseType",
+                split_strategy as "split_strategy: SplitStrategy"
             FROM expenses_categorization
             "#
         )
@@ -47,7 +48,8 @@ impl ExpenseCategorizationRepo for SqliteExpenseCategorizationRepo {
                 id as "id: Uuid",
                 name,
                 type as "expense_type: ExpenseType",
-                sub_type as "sub_expense_type: SubExpenseType"
+                sub_type as "sub_expense_type: SubExpenseType",
+                split_strategy as "split_strategy: SplitStrategy"
             FROM expenses_categorization
             WHERE id = $1;
             "#,
@@ -63,17 +65,19 @@ impl ExpenseCategorizationRepo for SqliteExpenseCategorizationRepo {
         for expense_categorization in expenses_categorization {
             sqlx::query!(
                 r#"
-                INSERT INTO expenses_categorization (id, name, type, sub_type)
-                VALUES ($1, $2, $3, $4)
+                INSERT INTO expenses_categorization (id, name, type, sub_type, split_strategy)
+                VALUES ($1, $2, $3, $4, $5)
                 ON CONFLICT (id) DO UPDATE
                 SET name = EXCLUDED.name,
                 type = EXCLUDED.type,
-                sub_type = EXCLUDED.sub_type;
+                sub_type = EXCLUDED.sub_type,
+                split_strategy = EXCLUDED.split_strategy;
                 "#,
                 expense_categorization.id,
                 expense_categorization.name,
                 expense_categorization.expense_type,
                 expense_categorization.sub_expense_type,
+                expense_categorization.split_strategy,
             )
             .execute(&self.db_conn_pool)
             .await?;
@@ -86,17 +90,19 @@ impl ExpenseCategorizationRepo for SqliteExpenseCategorizationRepo {
     async fn update(&self, expense_categorization: &ExpenseCategorization) -> DbResult<()> {
         sqlx::query!(
             r#"
-            INSERT INTO expenses_categorization (id, name, type, sub_type)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO expenses_categorization (id, name, type, sub_type, split_strategy)
+            VALUES ($1, $2, $3, $4, $5)
             ON CONFLICT (id) DO UPDATE
             SET name = EXCLUDED.name,
             type = EXCLUDED.type,
-            sub_type = EXCLUDED.sub_type;
+            sub_type = EXCLUDED.sub_type,
+            split_strategy = EXCLUDED.split_strategy;
             "#,
             expense_categorization.id,
             expense_categorization.name,
             expense_categorization.expense_type,
             expense_categorization.sub_expense_type,
+            expense_categorization.split_strategy,
         )
         .execute(&self.db_conn_pool)
         .await?;
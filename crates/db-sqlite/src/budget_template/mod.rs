@@ -1,5 +1,7 @@
 mod budgeter;
+mod expense_association;
 mod expense_categorization;
 
 pub use budgeter::*;
+pub use expense_association::*;
 pub use expense_categorization::*;
@@ -1,3 +1,27 @@
 pub mod balance_sheet;
 pub mod budget_providers;
 pub mod budget_template;
+mod registry;
+pub mod scheduler;
+mod secrets;
+
+pub use registry::*;
+
+use sqlx::SqlitePool;
+pub use sqlx::{
+    migrate::MigrateError,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    Error,
+};
+
+pub fn get_connection_pool(options: SqliteConnectOptions) -> SqlitePool {
+    SqlitePoolOptions::new()
+        .max_connections(4)
+        .acquire_timeout(std::time::Duration::from_secs(2))
+        .connect_lazy_with(options.create_if_missing(true).foreign_keys(true))
+}
+
+/// Brings the database file up to date, since nothing else manages its schema.
+pub async fn migrate(pool: &SqlitePool) -> Result<(), MigrateError> {
+    sqlx::migrate!("./migrations").run(pool).await
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use datamize_domain::db::{
+    external::{
+        DynEncryptionKeyRepo, DynExternalAccountBalanceRepo, DynExternalAccountRefreshRepo,
+        DynExternalAccountRepo,
+    },
+    ynab::{
+        DynYnabAccountMetaRepo, DynYnabAccountRepo, DynYnabCategoryMetaRepo, DynYnabCategoryRepo,
+        DynYnabOAuthTokenRepo, DynYnabPayeeMetaRepo, DynYnabPayeeRepo,
+        DynYnabScheduledTransactionMetaRepo, DynYnabScheduledTransactionRepo,
+        DynYnabTransactionMetaRepo, DynYnabTransactionRepo,
+    },
+    DynBudgeterConfigRepo, DynExpenseAssociationRepo, DynExpenseCategorizationRepo,
+    DynFinResOrderRepo, DynFinResRepo, DynMonthRepo, DynSavingRateRepo, DynSchedulerRunRepo,
+    DynYearRepo, RepoRegistry,
+};
+use sqlx::SqlitePool;
+
+use crate::{
+    balance_sheet::{
+        SqliteFinResOrderRepo, SqliteFinResRepo, SqliteMonthRepo, SqliteSavingRateRepo,
+        SqliteYearRepo,
+    },
+    budget_providers::{
+        external::{
+            SqliteEncryptionKeyRepo, SqliteExternalAccountBalanceRepo,
+            SqliteExternalAccountRefreshRepo, SqliteExternalAccountRepo,
+        },
+        ynab::{
+            SqliteYnabAccountMetaRepo, SqliteYnabAccountRepo, SqliteYnabCategoryMetaRepo,
+            SqliteYnabCategoryRepo, SqliteYnabOAuthTokenRepo, SqliteYnabPayeeMetaRepo,
+            SqliteYnabPayeeRepo, SqliteYnabScheduledTransactionMetaRepo,
+            SqliteYnabScheduledTransactionRepo, SqliteYnabTransactionMetaRepo,
+            SqliteYnabTransactionRepo,
+        },
+    },
+    budget_template::{
+        SqliteBudgeterConfigRepo, SqliteExpenseAssociationRepo, SqliteExpenseCategorizationRepo,
+    },
+    scheduler::SqliteSchedulerRunRepo,
+};
+
+/// Everything in a single SQLite database.
+#[derive(Clone)]
+pub struct SqliteRepoRegistry {
+    pub db_conn_pool: SqlitePool,
+}
+
+impl SqliteRepoRegistry {
+    pub fn new_arced(db_conn_pool: SqlitePool) -> Arc<Self> {
+        Arc::new(Self { db_conn_pool })
+    }
+}
+
+impl RepoRegistry for SqliteRepoRegistry {
+    fn year_repo(&self) -> DynYearRepo {
+        SqliteYearRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn month_repo(&self) -> DynMonthRepo {
+        SqliteMonthRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn fin_res_repo(&self) -> DynFinResRepo {
+        SqliteFinResRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn fin_res_order_repo(&self) -> DynFinResOrderRepo {
+        SqliteFinResOrderRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn saving_rate_repo(&self) -> DynSavingRateRepo {
+        SqliteSavingRateRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn external_account_repo(&self) -> DynExternalAccountRepo {
+        SqliteExternalAccountRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn external_account_refresh_repo(&self) -> DynExternalAccountRefreshRepo {
+        SqliteExternalAccountRefreshRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn external_account_balance_repo(&self) -> DynExternalAccountBalanceRepo {
+        SqliteExternalAccountBalanceRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn encryption_key_repo(&self) -> DynEncryptionKeyRepo {
+        SqliteEncryptionKeyRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn budgeter_config_repo(&self) -> DynBudgeterConfigRepo {
+        SqliteBudgeterConfigRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn expense_categorization_repo(&self) -> DynExpenseCategorizationRepo {
+        SqliteExpenseCategorizationRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn expense_association_repo(&self) -> DynExpenseAssociationRepo {
+        SqliteExpenseAssociationRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn ynab_oauth_token_repo(&self) -> DynYnabOAuthTokenRepo {
+        SqliteYnabOAuthTokenRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn scheduler_run_repo(&self) -> DynSchedulerRunRepo {
+        SqliteSchedulerRunRepo::new_arced(self.db_conn_pool.clone())
+    }
+
+    fn ynab_category_repo(&self, budget_id: &str) -> DynYnabCategoryRepo {
+        SqliteYnabCategoryRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_category_meta_repo(&self, budget_id: &str) -> DynYnabCategoryMetaRepo {
+        SqliteYnabCategoryMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_scheduled_transaction_repo(&self, budget_id: &str) -> DynYnabScheduledTransactionRepo {
+        SqliteYnabScheduledTransactionRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_scheduled_transaction_meta_repo(
+        &self,
+        budget_id: &str,
+    ) -> DynYnabScheduledTransactionMetaRepo {
+        SqliteYnabScheduledTransactionMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_account_repo(&self, budget_id: &str) -> DynYnabAccountRepo {
+        SqliteYnabAccountRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_account_meta_repo(&self, budget_id: &str) -> DynYnabAccountMetaRepo {
+        SqliteYnabAccountMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_payee_repo(&self, budget_id: &str) -> DynYnabPayeeRepo {
+        SqliteYnabPayeeRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_payee_meta_repo(&self, budget_id: &str) -> DynYnabPayeeMetaRepo {
+        SqliteYnabPayeeMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_transaction_repo(&self, budget_id: &str) -> DynYnabTransactionRepo {
+        SqliteYnabTransactionRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+
+    fn ynab_transaction_meta_repo(&self, budget_id: &str) -> DynYnabTransactionMetaRepo {
+        SqliteYnabTransactionMetaRepo::new_arced(self.db_conn_pool.clone(), budget_id)
+    }
+}
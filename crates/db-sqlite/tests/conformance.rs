@@ -0,0 +1,7 @@
+use db_sqlite::SqliteRepoRegistry;
+use sqlx::SqlitePool;
+
+db_conformance::conformance_tests! {
+    #[sqlx::test(migrations = "./migrations")]
+    |pool: SqlitePool| SqliteRepoRegistry::new_arced(pool)
+}
@@ -5,32 +5,35 @@ use crate::{client::Response, error::YnabResult, Account, AccountsDelta, Client,
 
 #[async_trait]
 pub trait AccountRequests {
-    async fn get_accounts(&self) -> YnabResult<Vec<Account>>;
+    async fn get_accounts(&self, budget_id: &str) -> YnabResult<Vec<Account>>;
 
     async fn get_accounts_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<AccountsDelta>;
 
-    async fn create_account(&self, data: SaveAccount) -> YnabResult<Account>;
+    async fn create_account(&self, budget_id: &str, data: SaveAccount) -> YnabResult<Account>;
 
-    async fn get_account_by_id(&self, account_id: &str) -> YnabResult<Account>;
+    async fn get_account_by_id(&self, budget_id: &str, account_id: &str) -> YnabResult<Account>;
 }
 
 #[async_trait]
 impl AccountRequests for Client {
-    async fn get_accounts(&self) -> YnabResult<Vec<Account>> {
-        Ok(self.get_accounts_request(None).await?.accounts)
+    async fn get_accounts(&self, budget_id: &str) -> YnabResult<Vec<Account>> {
+        Ok(self.get_accounts_request(budget_id, None).await?.accounts)
     }
 
     async fn get_accounts_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<AccountsDelta> {
-        self.get_accounts_request(last_knowledge_of_server).await
+        self.get_accounts_request(budget_id, last_knowledge_of_server)
+            .await
     }
 
-    async fn create_account(&self, data: SaveAccount) -> YnabResult<Account> {
+    async fn create_account(&self, budget_id: &str, data: SaveAccount) -> YnabResult<Account> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Body {
             account: SaveAccount,
@@ -42,23 +45,23 @@ impl AccountRequests for Client {
             account: Account,
         }
 
-        let path = format!("budgets/{}/accounts", self.get_budget_id());
+        let path = format!("budgets/{}/accounts", budget_id);
 
-        let body_resp = self.post(&path, Some(&body)).send().await?.text().await?;
+        let body_resp = self.send(self.post(&path, Some(&body))).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body_resp)?;
         Ok(resp.data.account)
     }
 
-    async fn get_account_by_id(&self, account_id: &str) -> YnabResult<Account> {
+    async fn get_account_by_id(&self, budget_id: &str, account_id: &str) -> YnabResult<Account> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Inner {
             account: Account,
         }
 
-        let path = format!("budgets/{}/accounts/{}", self.get_budget_id(), account_id);
+        let path = format!("budgets/{}/accounts/{}", budget_id, account_id);
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body)?;
         Ok(resp.data.account)
@@ -68,18 +71,17 @@ impl AccountRequests for Client {
 impl Client {
     async fn get_accounts_request(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<AccountsDelta> {
-        let path = format!("budgets/{}/accounts", self.get_budget_id());
+        let path = format!("budgets/{}/accounts", budget_id);
 
-        let body = match last_knowledge_of_server {
+        let request_builder = match last_knowledge_of_server {
             Some(k) => self.get_with_query(&path, &[("last_knowledge_of_server", k)]),
             None => self.get(&path),
-        }
-        .send()
-        .await?
-        .text()
-        .await?;
+        };
+
+        let body = self.send(request_builder).await?;
 
         let resp: Response<AccountsDelta> = Client::convert_resp(body)?;
         Ok(resp.data)
@@ -96,12 +98,11 @@ mockall::mock! {
 
     #[async_trait]
     impl AccountRequests for AccountRequestsImpl {
-        async fn get_accounts(&self) -> YnabResult<Vec<Account>>;
-        async fn get_accounts_delta(
-            &self,
+        async fn get_accounts(&self, budget_id: &str) -> YnabResult<Vec<Account>>;
+        async fn get_accounts_delta(&self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<AccountsDelta>;
-        async fn create_account(&self, data: SaveAccount) -> YnabResult<Account>;
-        async fn get_account_by_id(&self, account_id: &str) -> YnabResult<Account>;
+        async fn create_account(&self, budget_id: &str, data: SaveAccount) -> YnabResult<Account>;
+        async fn get_account_by_id(&self, budget_id: &str, account_id: &str) -> YnabResult<Account>;
     }
 }
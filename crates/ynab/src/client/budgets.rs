@@ -13,14 +13,15 @@ pub trait BudgetRequests {
 
     async fn get_budgets_with_accounts(&self) -> YnabResult<Vec<BudgetSummaryWithAccounts>>;
 
-    async fn get_budget(&self) -> YnabResult<BudgetDetail>;
+    async fn get_budget(&self, budget_id: &str) -> YnabResult<BudgetDetail>;
 
     async fn get_budget_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<BudgetDetailDelta>;
 
-    async fn get_budget_settings(&self) -> YnabResult<BudgetSettings>;
+    async fn get_budget_settings(&self, budget_id: &str) -> YnabResult<BudgetSettings>;
 }
 
 #[async_trait]
@@ -33,26 +34,28 @@ impl BudgetRequests for Client {
         self.get_budgets_request(true).await
     }
 
-    async fn get_budget(&self) -> YnabResult<BudgetDetail> {
-        Ok(self.get_budget_request(None).await?.budget)
+    async fn get_budget(&self, budget_id: &str) -> YnabResult<BudgetDetail> {
+        Ok(self.get_budget_request(budget_id, None).await?.budget)
     }
 
     async fn get_budget_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<BudgetDetailDelta> {
-        self.get_budget_request(last_knowledge_of_server).await
+        self.get_budget_request(budget_id, last_knowledge_of_server)
+            .await
     }
 
-    async fn get_budget_settings(&self) -> YnabResult<BudgetSettings> {
+    async fn get_budget_settings(&self, budget_id: &str) -> YnabResult<BudgetSettings> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Inner {
             settings: BudgetSettings,
         }
 
-        let path = format!("budgets/{}/settings", self.get_budget_id());
+        let path = format!("budgets/{}/settings", budget_id);
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body)?;
         Ok(resp.data.settings)
@@ -70,14 +73,12 @@ impl Client {
             default_budget: Option<I>,
         }
 
-        let body = match with_accounts {
+        let request_builder = match with_accounts {
             true => self.get_with_query("budgets", &[("include_accounts", "true")]),
             false => self.get("budgets"),
-        }
-        .send()
-        .await?
-        .text()
-        .await?;
+        };
+
+        let body = self.send(request_builder).await?;
 
         let resp: Response<Inner<T>> = Client::convert_resp(body)?;
         Ok(resp.data.budgets)
@@ -85,18 +86,17 @@ impl Client {
 
     async fn get_budget_request(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<BudgetDetailDelta> {
-        let path = format!("budgets/{}", self.get_budget_id());
+        let path = format!("budgets/{}", budget_id);
 
-        let body = match last_knowledge_of_server {
+        let request_builder = match last_knowledge_of_server {
             Some(k) => self.get_with_query(&path, &[("last_knowledge_of_server", k)]),
             None => self.get(&path),
-        }
-        .send()
-        .await?
-        .text()
-        .await?;
+        };
+
+        let body = self.send(request_builder).await?;
 
         let resp: Response<BudgetDetailDelta> = Client::convert_resp(body)?;
         Ok(resp.data)
@@ -8,19 +8,27 @@ use crate::{
 
 #[async_trait]
 pub trait CategoryRequests {
-    async fn get_categories(&self) -> YnabResult<Vec<CategoryGroupWithCategories>>;
+    async fn get_categories(&self, budget_id: &str)
+        -> YnabResult<Vec<CategoryGroupWithCategories>>;
 
     async fn get_categories_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<CategoryGroupWithCategoriesDelta>;
 
-    async fn get_category_by_id(&self, category_id: &str) -> YnabResult<Category>;
+    async fn get_category_by_id(&self, budget_id: &str, category_id: &str) -> YnabResult<Category>;
 
-    async fn get_category_by_id_for(&self, category_id: &str, month: &str) -> YnabResult<Category>;
+    async fn get_category_by_id_for(
+        &self,
+        budget_id: &str,
+        category_id: &str,
+        month: &str,
+    ) -> YnabResult<Category>;
 
     async fn update_category_for(
         &self,
+        budget_id: &str,
         category_id: &str,
         month: &str,
         data: SaveMonthCategory,
@@ -29,28 +37,43 @@ pub trait CategoryRequests {
 
 #[async_trait]
 impl CategoryRequests for Client {
-    async fn get_categories(&self) -> YnabResult<Vec<CategoryGroupWithCategories>> {
-        Ok(self.get_categories_request(None).await?.category_groups)
+    async fn get_categories(
+        &self,
+        budget_id: &str,
+    ) -> YnabResult<Vec<CategoryGroupWithCategories>> {
+        Ok(self
+            .get_categories_request(budget_id, None)
+            .await?
+            .category_groups)
     }
 
     async fn get_categories_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<CategoryGroupWithCategoriesDelta> {
-        self.get_categories_request(last_knowledge_of_server).await
+        self.get_categories_request(budget_id, last_knowledge_of_server)
+            .await
     }
 
-    async fn get_category_by_id(&self, category_id: &str) -> YnabResult<Category> {
-        self.get_category_by_id_request(category_id, None).await
+    async fn get_category_by_id(&self, budget_id: &str, category_id: &str) -> YnabResult<Category> {
+        self.get_category_by_id_request(budget_id, category_id, None)
+            .await
     }
 
-    async fn get_category_by_id_for(&self, category_id: &str, month: &str) -> YnabResult<Category> {
-        self.get_category_by_id_request(category_id, Some(month))
+    async fn get_category_by_id_for(
+        &self,
+        budget_id: &str,
+        category_id: &str,
+        month: &str,
+    ) -> YnabResult<Category> {
+        self.get_category_by_id_request(budget_id, category_id, Some(month))
             .await
     }
 
     async fn update_category_for(
         &self,
+        budget_id: &str,
         category_id: &str,
         month: &str,
         data: SaveMonthCategory,
@@ -68,12 +91,10 @@ impl CategoryRequests for Client {
 
         let path = format!(
             "budgets/{}/months/{}/categories/{}",
-            self.get_budget_id(),
-            month,
-            category_id
+            budget_id, month, category_id
         );
 
-        let body_resp = self.patch(&path, &body).send().await?.text().await?;
+        let body_resp = self.send(self.patch(&path, &body)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body_resp)?;
         Ok(resp.data.category)
@@ -83,18 +104,17 @@ impl CategoryRequests for Client {
 impl Client {
     async fn get_categories_request(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<CategoryGroupWithCategoriesDelta> {
-        let path = format!("budgets/{}/categories", self.get_budget_id());
+        let path = format!("budgets/{}/categories", budget_id);
 
-        let body = match last_knowledge_of_server {
+        let request_builder = match last_knowledge_of_server {
             Some(k) => self.get_with_query(&path, &[("last_knowledge_of_server", k)]),
             None => self.get(&path),
-        }
-        .send()
-        .await?
-        .text()
-        .await?;
+        };
+
+        let body = self.send(request_builder).await?;
 
         let resp: Response<CategoryGroupWithCategoriesDelta> = Client::convert_resp(body)?;
         Ok(resp.data)
@@ -102,6 +122,7 @@ impl Client {
 
     async fn get_category_by_id_request(
         &self,
+        budget_id: &str,
         category_id: &str,
         month: Option<&str>,
     ) -> YnabResult<Category> {
@@ -113,18 +134,12 @@ impl Client {
         let path = match month {
             Some(m) => format!(
                 "budgets/{}/months/{}/categories/{}",
-                self.get_budget_id(),
-                m,
-                category_id
-            ),
-            None => format!(
-                "budgets/{}/categories/{}",
-                self.get_budget_id(),
-                category_id
+                budget_id, m, category_id
             ),
+            None => format!("budgets/{}/categories/{}", budget_id, category_id),
         };
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body)?;
         Ok(resp.data.category)
@@ -141,15 +156,13 @@ mockall::mock! {
 
     #[async_trait]
     impl CategoryRequests for CategoryRequestsImpl {
-        async fn get_categories(&self) -> YnabResult<Vec<CategoryGroupWithCategories>>;
-        async fn get_categories_delta(
-            &self,
+        async fn get_categories(&self, budget_id: &str) -> YnabResult<Vec<CategoryGroupWithCategories>>;
+        async fn get_categories_delta(&self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<CategoryGroupWithCategoriesDelta>;
-        async fn get_category_by_id(&self, category_id: &str) -> YnabResult<Category>;
-        async fn get_category_by_id_for(&self, category_id: &str, month: &str) -> YnabResult<Category>;
-        async fn update_category_for(
-            &self,
+        async fn get_category_by_id(&self, budget_id: &str, category_id: &str) -> YnabResult<Category>;
+        async fn get_category_by_id_for(&self, budget_id: &str, category_id: &str, month: &str) -> YnabResult<Category>;
+        async fn update_category_for(&self, budget_id: &str,
             category_id: &str,
             month: &str,
             data: SaveMonthCategory,
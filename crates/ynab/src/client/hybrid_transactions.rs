@@ -10,23 +10,27 @@ use crate::{
 pub trait HybridTransactionRequests {
     async fn get_transactions_by_category_id(
         &self,
+        budget_id: &str,
         category_id: &str,
     ) -> YnabResult<Vec<HybridTransaction>>;
 
     async fn get_transactions_by_category_id_delta(
         &self,
+        budget_id: &str,
         category_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<HybridTransationsDelta>;
 
     async fn get_transactions_by_category_id_since(
         &self,
+        budget_id: &str,
         category_id: &str,
         since_date: &str,
     ) -> YnabResult<Vec<HybridTransaction>>;
 
     async fn get_transactions_by_category_id_since_delta(
         &self,
+        budget_id: &str,
         category_id: &str,
         since_date: &str,
         last_knowledge_of_server: Option<i64>,
@@ -34,12 +38,14 @@ pub trait HybridTransactionRequests {
 
     async fn get_transactions_by_category_id_of(
         &self,
+        budget_id: &str,
         category_id: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<HybridTransaction>>;
 
     async fn get_transactions_by_category_id_of_delta(
         &self,
+        budget_id: &str,
         category_id: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
@@ -47,6 +53,7 @@ pub trait HybridTransactionRequests {
 
     async fn get_transactions_by_category_id_since_date_of(
         &self,
+        budget_id: &str,
         category_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -54,6 +61,7 @@ pub trait HybridTransactionRequests {
 
     async fn get_transactions_by_category_id_since_date_of_delta(
         &self,
+        budget_id: &str,
         category_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -62,23 +70,27 @@ pub trait HybridTransactionRequests {
 
     async fn get_transactions_by_payee_id(
         &self,
+        budget_id: &str,
         payee_id: &str,
     ) -> YnabResult<Vec<HybridTransaction>>;
 
     async fn get_transactions_by_payee_id_delta(
         &self,
+        budget_id: &str,
         payee_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<HybridTransationsDelta>;
 
     async fn get_transactions_by_payee_id_since(
         &self,
+        budget_id: &str,
         payee_id: &str,
         since_date: &str,
     ) -> YnabResult<Vec<HybridTransaction>>;
 
     async fn get_transactions_by_payee_id_since_delta(
         &self,
+        budget_id: &str,
         payee_id: &str,
         since_date: &str,
         last_knowledge_of_server: Option<i64>,
@@ -86,12 +98,14 @@ pub trait HybridTransactionRequests {
 
     async fn get_transactions_by_payee_id_of(
         &self,
+        budget_id: &str,
         payee_id: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<HybridTransaction>>;
 
     async fn get_transactions_by_payee_id_of_delta(
         &self,
+        budget_id: &str,
         payee_id: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
@@ -99,6 +113,7 @@ pub trait HybridTransactionRequests {
 
     async fn get_transactions_by_payee_id_since_date_of(
         &self,
+        budget_id: &str,
         payee_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -106,6 +121,7 @@ pub trait HybridTransactionRequests {
 
     async fn get_transactions_by_payee_id_since_date_of_delta(
         &self,
+        budget_id: &str,
         payee_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -117,10 +133,12 @@ pub trait HybridTransactionRequests {
 impl HybridTransactionRequests for Client {
     async fn get_transactions_by_category_id(
         &self,
+        budget_id: &str,
         category_id: &str,
     ) -> YnabResult<Vec<HybridTransaction>> {
         Ok(self
             .get_hybrid_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Categories(category_id)),
                 None,
             )
@@ -130,12 +148,14 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_category_id_delta(
         &self,
+        budget_id: &str,
         category_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<HybridTransationsDelta> {
         let query =
             TransactionsRequestQuery::default().with_last_knowledge(last_knowledge_of_server);
         self.get_hybrid_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Categories(category_id)),
             Some(query),
         )
@@ -144,12 +164,14 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_category_id_since(
         &self,
+        budget_id: &str,
         category_id: &str,
         since_date: &str,
     ) -> YnabResult<Vec<HybridTransaction>> {
         let query = TransactionsRequestQuery::default().with_date(since_date);
         Ok(self
             .get_hybrid_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Categories(category_id)),
                 Some(query),
             )
@@ -159,6 +181,7 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_category_id_since_delta(
         &self,
+        budget_id: &str,
         category_id: &str,
         since_date: &str,
         last_knowledge_of_server: Option<i64>,
@@ -167,6 +190,7 @@ impl HybridTransactionRequests for Client {
             .with_last_knowledge(last_knowledge_of_server)
             .with_date(since_date);
         self.get_hybrid_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Categories(category_id)),
             Some(query),
         )
@@ -175,12 +199,14 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_category_id_of(
         &self,
+        budget_id: &str,
         category_id: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<HybridTransaction>> {
         let query = TransactionsRequestQuery::default().with_transaction_type(transaction_type);
         Ok(self
             .get_hybrid_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Categories(category_id)),
                 Some(query),
             )
@@ -190,6 +216,7 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_category_id_of_delta(
         &self,
+        budget_id: &str,
         category_id: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
@@ -198,6 +225,7 @@ impl HybridTransactionRequests for Client {
             .with_last_knowledge(last_knowledge_of_server)
             .with_transaction_type(transaction_type);
         self.get_hybrid_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Categories(category_id)),
             Some(query),
         )
@@ -206,6 +234,7 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_category_id_since_date_of(
         &self,
+        budget_id: &str,
         category_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -215,6 +244,7 @@ impl HybridTransactionRequests for Client {
             .with_transaction_type(transaction_type);
         Ok(self
             .get_hybrid_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Categories(category_id)),
                 Some(query),
             )
@@ -224,6 +254,7 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_category_id_since_date_of_delta(
         &self,
+        budget_id: &str,
         category_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -234,6 +265,7 @@ impl HybridTransactionRequests for Client {
             .with_date(since_date)
             .with_transaction_type(transaction_type);
         self.get_hybrid_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Categories(category_id)),
             Some(query),
         )
@@ -242,22 +274,29 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_payee_id(
         &self,
+        budget_id: &str,
         payee_id: &str,
     ) -> YnabResult<Vec<HybridTransaction>> {
         Ok(self
-            .get_hybrid_transactions_request(Some(TransactionsParentPath::Payees(payee_id)), None)
+            .get_hybrid_transactions_request(
+                budget_id,
+                Some(TransactionsParentPath::Payees(payee_id)),
+                None,
+            )
             .await?
             .transactions)
     }
 
     async fn get_transactions_by_payee_id_delta(
         &self,
+        budget_id: &str,
         payee_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<HybridTransationsDelta> {
         let query =
             TransactionsRequestQuery::default().with_last_knowledge(last_knowledge_of_server);
         self.get_hybrid_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Payees(payee_id)),
             Some(query),
         )
@@ -266,12 +305,14 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_payee_id_since(
         &self,
+        budget_id: &str,
         payee_id: &str,
         since_date: &str,
     ) -> YnabResult<Vec<HybridTransaction>> {
         let query = TransactionsRequestQuery::default().with_date(since_date);
         Ok(self
             .get_hybrid_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Payees(payee_id)),
                 Some(query),
             )
@@ -281,6 +322,7 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_payee_id_since_delta(
         &self,
+        budget_id: &str,
         payee_id: &str,
         since_date: &str,
         last_knowledge_of_server: Option<i64>,
@@ -289,6 +331,7 @@ impl HybridTransactionRequests for Client {
             .with_last_knowledge(last_knowledge_of_server)
             .with_date(since_date);
         self.get_hybrid_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Payees(payee_id)),
             Some(query),
         )
@@ -297,12 +340,14 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_payee_id_of(
         &self,
+        budget_id: &str,
         payee_id: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<HybridTransaction>> {
         let query = TransactionsRequestQuery::default().with_transaction_type(transaction_type);
         Ok(self
             .get_hybrid_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Payees(payee_id)),
                 Some(query),
             )
@@ -312,6 +357,7 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_payee_id_of_delta(
         &self,
+        budget_id: &str,
         payee_id: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
@@ -320,6 +366,7 @@ impl HybridTransactionRequests for Client {
             .with_last_knowledge(last_knowledge_of_server)
             .with_transaction_type(transaction_type);
         self.get_hybrid_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Payees(payee_id)),
             Some(query),
         )
@@ -328,6 +375,7 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_payee_id_since_date_of(
         &self,
+        budget_id: &str,
         payee_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -337,6 +385,7 @@ impl HybridTransactionRequests for Client {
             .with_transaction_type(transaction_type);
         Ok(self
             .get_hybrid_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Payees(payee_id)),
                 Some(query),
             )
@@ -346,6 +395,7 @@ impl HybridTransactionRequests for Client {
 
     async fn get_transactions_by_payee_id_since_date_of_delta(
         &self,
+        budget_id: &str,
         payee_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -356,6 +406,7 @@ impl HybridTransactionRequests for Client {
             .with_date(since_date)
             .with_transaction_type(transaction_type);
         self.get_hybrid_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Payees(payee_id)),
             Some(query),
         )
@@ -366,28 +417,23 @@ impl HybridTransactionRequests for Client {
 impl Client {
     async fn get_hybrid_transactions_request(
         &self,
+        budget_id: &str,
         parent_path: Option<TransactionsParentPath<&str>>,
         query: Option<TransactionsRequestQuery>,
     ) -> YnabResult<HybridTransationsDelta> {
         let path = match parent_path {
             Some(tp) => match tp {
-                TransactionsParentPath::Accounts(id) => format!(
-                    "budgets/{}/accounts/{}/transactions",
-                    self.get_budget_id(),
-                    id
-                ),
-                TransactionsParentPath::Categories(id) => format!(
-                    "budgets/{}/categories/{}/transactions",
-                    self.get_budget_id(),
-                    id
-                ),
-                TransactionsParentPath::Payees(id) => format!(
-                    "budgets/{}/payees/{}/transactions",
-                    self.get_budget_id(),
-                    id
-                ),
+                TransactionsParentPath::Accounts(id) => {
+                    format!("budgets/{}/accounts/{}/transactions", budget_id, id)
+                }
+                TransactionsParentPath::Categories(id) => {
+                    format!("budgets/{}/categories/{}/transactions", budget_id, id)
+                }
+                TransactionsParentPath::Payees(id) => {
+                    format!("budgets/{}/payees/{}/transactions", budget_id, id)
+                }
             },
-            None => format!("budgets/{}/transactions", self.get_budget_id()),
+            None => format!("budgets/{}/transactions", budget_id),
         };
 
         let request_builder = if query.is_some() {
@@ -396,7 +442,7 @@ impl Client {
             self.get(&path)
         };
 
-        let body = request_builder.send().await?.text().await?;
+        let body = self.send(request_builder).await?;
 
         let resp: Response<HybridTransationsDelta> = Client::convert_resp(body)?;
         Ok(resp.data)
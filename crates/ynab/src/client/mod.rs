@@ -1,3 +1,8 @@
+use std::{
+    sync::{Mutex, RwLock},
+    time::Instant,
+};
+
 use crate::error::{ApiErrorResponse, Error, YnabResult};
 use reqwest::{header, Client as ReqwestClient, RequestBuilder, Url};
 use serde::de::DeserializeOwned;
@@ -8,8 +13,10 @@ mod budgets;
 mod categories;
 mod hybrid_transactions;
 mod months;
+mod oauth;
 mod payee_locations;
 mod payees;
+mod rate_limit;
 mod scheduled_transactions;
 mod transactions;
 
@@ -18,11 +25,16 @@ pub use budgets::*;
 pub use categories::*;
 pub use hybrid_transactions::*;
 pub use months::*;
+pub use oauth::*;
 pub use payee_locations::*;
 pub use payees::*;
+pub use rate_limit::*;
 pub use scheduled_transactions::*;
 pub use transactions::*;
 
+/// Special budget id understood by YNAB's API as the last budget used in YNAB.
+pub const LAST_USED_BUDGET_ID: &str = "last-used";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Response<T> {
     data: T,
@@ -30,10 +42,14 @@ struct Response<T> {
 
 #[derive(Debug)]
 pub struct Client {
-    ynab_api_token: String,
+    /// Personal access token, or the current OAuth access token when using `with_oauth`.
+    ynab_api_token: RwLock<String>,
     ynab_base_url: Url,
-    default_budget_id: Option<String>,
     http_client: ReqwestClient,
+    retry_policy: RetryPolicy,
+    /// Last rate limit reported by YNAB, along with until when requests are held back when it is exhausted.
+    rate_limit: Mutex<Option<RateLimitState>>,
+    oauth: Option<OAuth>,
 }
 
 impl Client {
@@ -43,26 +59,35 @@ impl Client {
         let http_client = Client::build_http_client()?;
 
         Ok(Self {
-            ynab_api_token: ynab_api_token.into(),
+            ynab_api_token: RwLock::new(ynab_api_token.into()),
             ynab_base_url,
             http_client,
-            default_budget_id: None,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: Mutex::new(None),
+            oauth: None,
         })
     }
 
-    /// Returns the `default_budget_id` if previously set, or the special
-    /// string `"last-used"` to be used in YNAB's API.
-    fn get_budget_id(&self) -> &str {
-        match self.default_budget_id.as_ref() {
-            Some(b_id) => b_id,
-            None => "last-used",
-        }
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+
+        self
     }
 
-    pub fn with_default_budget_id(mut self, default_budget_id: &str) -> Self {
-        self.default_budget_id = Some(default_budget_id.to_string());
+    /// Returns the last rate limit reported by YNAB, if any request was made.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit
+            .lock()
+            .unwrap()
+            .map(|state| state.rate_limit)
+    }
 
-        self
+    fn access_token(&self) -> String {
+        self.ynab_api_token.read().unwrap().clone()
+    }
+
+    fn set_access_token(&self, access_token: &str) {
+        *self.ynab_api_token.write().unwrap() = access_token.to_string();
     }
 
     /// Builds the ReqwestClient with some default headers staying the same for all requests.
@@ -83,14 +108,12 @@ impl Client {
         Ok(http_client)
     }
 
-    /// Builds a `GET` request by joining the path to `ynab_base_url` and setting `ynab_api_token` as a bearer_auth token.
+    /// Builds a `GET` request by joining the path to `ynab_base_url`. The access token is set by `send`.
     fn get(&self, path: &str) -> RequestBuilder {
-        self.http_client
-            .get(self.ynab_base_url.join(path).unwrap())
-            .bearer_auth(self.ynab_api_token.as_str())
+        self.http_client.get(self.ynab_base_url.join(path).unwrap())
     }
 
-    /// Builds a `GET` request by joining the path to `ynab_base_url` and setting `ynab_api_token` as a bearer_auth token.
+    /// Builds a `GET` request by joining the path to `ynab_base_url`. The access token is set by `send`.
     /// Also adds some query string to the request.
     /// # Example
     ///
@@ -107,11 +130,9 @@ impl Client {
         self.http_client
             .get(self.ynab_base_url.join(path).unwrap())
             .query(query)
-            .bearer_auth(self.ynab_api_token.as_str())
     }
 
     /// Builds a `POST` request by joining the path to `ynab_base_url` and setting the body (if present) as json data.
-    /// Also sets `ynab_api_token` as a bearer_auth token.
     fn post<T>(&self, path: &str, body: Option<&T>) -> RequestBuilder
     where
         T: Serialize,
@@ -120,39 +141,136 @@ impl Client {
             Some(b) => self
                 .http_client
                 .post(self.ynab_base_url.join(path).unwrap())
-                .bearer_auth(self.ynab_api_token.as_str())
                 .json(b),
             None => self
                 .http_client
-                .post(self.ynab_base_url.join(path).unwrap())
-                .bearer_auth(self.ynab_api_token.as_str()),
+                .post(self.ynab_base_url.join(path).unwrap()),
         }
     }
 
     /// Builds a `PATCH` request by joining the path to `ynab_base_url` and setting the body as json data.
-    /// Also sets `ynab_api_token` as a bearer_auth token.
     fn patch<T>(&self, path: &str, body: &T) -> RequestBuilder
     where
         T: Serialize,
     {
         self.http_client
             .patch(self.ynab_base_url.join(path).unwrap())
-            .bearer_auth(self.ynab_api_token.as_str())
             .json(body)
     }
 
     /// Builds a `PUT` request by joining the path to `ynab_base_url` and setting the body as json data.
-    /// Also sets `ynab_api_token` as a bearer_auth token.
     fn put<T>(&self, path: &str, body: &T) -> RequestBuilder
     where
         T: Serialize,
     {
         self.http_client
             .put(self.ynab_base_url.join(path).unwrap())
-            .bearer_auth(self.ynab_api_token.as_str())
             .json(body)
     }
 
+    /// Builds a `DELETE` request by joining the path to `ynab_base_url`. The access token is set by `send`.
+    fn delete(&self, path: &str) -> RequestBuilder {
+        self.http_client
+            .delete(self.ynab_base_url.join(path).unwrap())
+    }
+
+    /// Sends the request and returns its body as text.
+    /// Requests failing with a `429` or a `5xx` are retried according to the `retry_policy`,
+    /// waiting for the delay given by YNAB's `Retry-After` header or an exponential backoff,
+    /// never longer than the `max_delay` of the policy.
+    /// If the rate limit is known to be exhausted, or is still reached once retries are used up,
+    /// it will return an `Error::RateLimited` without sending anything else.
+    /// When using OAuth, a `401` triggers a refresh of the access token and the request is sent again once.
+    async fn send(&self, request_builder: RequestBuilder) -> YnabResult<String> {
+        self.check_rate_limit()?;
+
+        let mut attempt = 0;
+        let mut refreshed = false;
+        loop {
+            let access_token = self.access_token();
+            let request = request_builder
+                .try_clone()
+                .expect("request bodies are json and can always be cloned")
+                .bearer_auth(&access_token);
+            let resp = request.send().await?;
+            self.record_rate_limit(&resp);
+
+            let status = resp.status();
+            if status == reqwest::StatusCode::UNAUTHORIZED
+                && !refreshed
+                && self.refresh_oauth_tokens(&access_token).await?
+            {
+                refreshed = true;
+                continue;
+            }
+            if !self.retry_policy.should_retry(status) {
+                return Ok(resp.text().await?);
+            }
+
+            // Only the wait is capped, callers get the delay asked by YNAB.
+            let retry_after = retry_after_from_headers(resp.headers());
+            let delay = retry_after
+                .map(|retry_after| retry_after.min(self.retry_policy.max_delay))
+                .unwrap_or_else(|| self.retry_policy.backoff(attempt));
+
+            if attempt >= self.retry_policy.max_retries {
+                return match status {
+                    reqwest::StatusCode::TOO_MANY_REQUESTS => Err(Error::RateLimited {
+                        retry_after: retry_after.unwrap_or(delay),
+                    }),
+                    _ => Ok(resp.text().await?),
+                };
+            }
+
+            tracing::debug!(%status, ?delay, attempt, "retrying YNAB request");
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    fn check_rate_limit(&self) -> YnabResult<()> {
+        if let Some(RateLimitState {
+            locked_until: Some(locked_until),
+            ..
+        }) = *self.rate_limit.lock().unwrap()
+        {
+            let now = Instant::now();
+            if now < locked_until {
+                return Err(Error::RateLimited {
+                    retry_after: locked_until - now,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// An exhausted rate limit holds requests back for the `Retry-After` sent by YNAB, otherwise for a
+    /// backoff growing with each response in a row reporting it exhausted. Any later response reporting
+    /// remaining requests lifts it.
+    fn record_rate_limit(&self, resp: &reqwest::Response) {
+        if let Some(rate_limit) = RateLimit::from_headers(resp.headers()) {
+            let mut state = self.rate_limit.lock().unwrap();
+            let exhausted_in_a_row = match *state {
+                Some(previous) if rate_limit.is_exhausted() => previous.exhausted_in_a_row + 1,
+                None if rate_limit.is_exhausted() => 1,
+                _ => 0,
+            };
+            let locked_until = rate_limit.is_exhausted().then(|| {
+                Instant::now()
+                    + retry_after_from_headers(resp.headers()).unwrap_or_else(|| {
+                        self.retry_policy
+                            .backoff(exhausted_in_a_row.saturating_sub(1))
+                    })
+            });
+            *state = Some(RateLimitState {
+                rate_limit,
+                exhausted_in_a_row,
+                locked_until,
+            });
+        }
+    }
+
     /// Converts a string body into a rust's T representation of it.
     /// If the body contains an error from the api, it will return an `Error::Api()` enum.
     /// If the conversion fails using serde, it will return an `Error::Conversion()` enum.
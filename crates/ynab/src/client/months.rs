@@ -7,38 +7,41 @@ use crate::{
 
 #[async_trait]
 pub trait MonthRequests {
-    async fn get_months(&self) -> YnabResult<Vec<MonthSummary>>;
+    async fn get_months(&self, budget_id: &str) -> YnabResult<Vec<MonthSummary>>;
 
     async fn get_months_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<MonthSummaryDelta>;
 
-    async fn get_month_by_date(&self, date: &str) -> YnabResult<MonthDetail>;
+    async fn get_month_by_date(&self, budget_id: &str, date: &str) -> YnabResult<MonthDetail>;
 }
 
 #[async_trait]
 impl MonthRequests for Client {
-    async fn get_months(&self) -> YnabResult<Vec<MonthSummary>> {
-        Ok(self.get_months_request(None).await?.months)
+    async fn get_months(&self, budget_id: &str) -> YnabResult<Vec<MonthSummary>> {
+        Ok(self.get_months_request(budget_id, None).await?.months)
     }
 
     async fn get_months_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<MonthSummaryDelta> {
-        self.get_months_request(last_knowledge_of_server).await
+        self.get_months_request(budget_id, last_knowledge_of_server)
+            .await
     }
 
-    async fn get_month_by_date(&self, date: &str) -> YnabResult<MonthDetail> {
+    async fn get_month_by_date(&self, budget_id: &str, date: &str) -> YnabResult<MonthDetail> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Inner {
             month: MonthDetail,
         }
 
-        let path = format!("budgets/{}/months/{}", self.get_budget_id(), date);
+        let path = format!("budgets/{}/months/{}", budget_id, date);
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body)?;
         Ok(resp.data.month)
@@ -48,18 +51,17 @@ impl MonthRequests for Client {
 impl Client {
     async fn get_months_request(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<MonthSummaryDelta> {
-        let path = format!("budgets/{}/months", self.get_budget_id());
+        let path = format!("budgets/{}/months", budget_id);
 
-        let body = match last_knowledge_of_server {
+        let request_builder = match last_knowledge_of_server {
             Some(k) => self.get_with_query(&path, &[("last_knowledge_of_server", k)]),
             None => self.get(&path),
-        }
-        .send()
-        .await?
-        .text()
-        .await?;
+        };
+
+        let body = self.send(request_builder).await?;
 
         let resp: Response<MonthSummaryDelta> = Client::convert_resp(body)?;
         Ok(resp.data)
@@ -76,11 +78,10 @@ mockall::mock! {
 
     #[async_trait]
     impl MonthRequests for MonthRequestsImpl {
-        async fn get_months(&self) -> YnabResult<Vec<MonthSummary>>;
-        async fn get_months_delta(
-            &self,
+        async fn get_months(&self, budget_id: &str) -> YnabResult<Vec<MonthSummary>>;
+        async fn get_months_delta(&self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<MonthSummaryDelta>;
-        async fn get_month_by_date(&self, date: &str) -> YnabResult<MonthDetail>;
+        async fn get_month_by_date(&self, budget_id: &str, date: &str) -> YnabResult<MonthDetail>;
     }
 }
@@ -0,0 +1,367 @@
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, OAuthError, YnabResult},
+    Client,
+};
+
+/// Base URL of YNAB's OAuth endpoints.
+pub const YNAB_OAUTH_BASE_URL: &str = "https://app.ynab.com/oauth/";
+
+/// The OAuth application registered in YNAB's developer settings.
+/// See https://api.ynab.com/#oauth-applications
+#[derive(Clone)]
+pub struct OAuthConfig {
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+    pub oauth_base_url: Url,
+}
+
+impl fmt::Debug for OAuthConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthConfig")
+            .field("client_id", &self.client_id)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("oauth_base_url", &self.oauth_base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OAuthConfig {
+    pub fn new(client_id: &str, client_secret: &str, redirect_uri: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            oauth_base_url: Url::parse(YNAB_OAUTH_BASE_URL).unwrap(),
+        }
+    }
+
+    pub fn with_oauth_base_url(mut self, oauth_base_url: &str) -> Self {
+        self.oauth_base_url = Url::parse(oauth_base_url)
+            .unwrap_or_else(|_| panic!("`{}` to be a valid URL", oauth_base_url));
+        self
+    }
+
+    /// The URL where the user needs to be redirected to authorize the application.
+    /// `state` is sent back untouched to the redirect uri and should be checked against CSRF.
+    pub fn authorize_url(&self, state: &str) -> Url {
+        let mut url = self.oauth_base_url.join("authorize").unwrap();
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.client_id)
+            .append_pair("redirect_uri", &self.redirect_uri)
+            .append_pair("response_type", "code")
+            .append_pair("state", state);
+        url
+    }
+}
+
+/// Tokens returned by YNAB's token endpoint.
+/// The access token expires after `expires_in` seconds, at which point the refresh token
+/// can be used to get new ones.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OAuthTokens {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+impl fmt::Debug for OAuthTokens {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuthTokens")
+            .field("token_type", &self.token_type)
+            .field("expires_in", &self.expires_in)
+            .field("scope", &self.scope)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Persists tokens obtained by the client on its own, i.e. when refreshing an expired access token.
+#[async_trait]
+pub trait OAuthTokenStore: Send + Sync {
+    async fn save(&self, tokens: &OAuthTokens);
+}
+
+pub(crate) struct OAuth {
+    pub(crate) config: OAuthConfig,
+    /// Also held while refreshing, so concurrent requests only refresh once.
+    pub(crate) refresh_token: tokio::sync::Mutex<Option<String>>,
+    pub(crate) store: Option<Arc<dyn OAuthTokenStore>>,
+}
+
+impl fmt::Debug for OAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Client {
+    /// Uses OAuth's authorization code flow instead of a personal access token.
+    /// When a request is rejected with a `401`, the access token is refreshed and the request sent again.
+    pub fn with_oauth(mut self, config: OAuthConfig) -> Self {
+        self.oauth = Some(OAuth {
+            config,
+            refresh_token: tokio::sync::Mutex::new(None),
+            store: None,
+        });
+
+        self
+    }
+
+    /// Sets where refreshed tokens are saved. Only used with `with_oauth`.
+    pub fn with_oauth_token_store(mut self, store: Arc<dyn OAuthTokenStore>) -> Self {
+        if let Some(oauth) = self.oauth.as_mut() {
+            oauth.store = Some(store);
+        }
+
+        self
+    }
+
+    pub fn oauth_config(&self) -> Option<&OAuthConfig> {
+        self.oauth.as_ref().map(|oauth| &oauth.config)
+    }
+
+    /// Starts using the given tokens for the following requests.
+    pub async fn set_oauth_tokens(&self, tokens: &OAuthTokens) {
+        if let Some(oauth) = &self.oauth {
+            *oauth.refresh_token.lock().await = Some(tokens.refresh_token.clone());
+        }
+        self.set_access_token(&tokens.access_token);
+    }
+
+    /// Exchanges the authorization code received on the redirect uri for tokens, and starts using them.
+    pub async fn exchange_oauth_code(&self, code: &str) -> YnabResult<OAuthTokens> {
+        let oauth = self.oauth.as_ref().ok_or(Error::OAuthNotConfigured)?;
+
+        let tokens = self
+            .request_oauth_tokens(
+                &oauth.config,
+                &[
+                    ("grant_type", "authorization_code"),
+                    ("code", code),
+                    ("redirect_uri", &oauth.config.redirect_uri),
+                ],
+            )
+            .await?;
+        self.set_oauth_tokens(&tokens).await;
+
+        Ok(tokens)
+    }
+
+    /// Refreshes the tokens, unless another request already did it since `rejected_token` was used.
+    /// Returns `false` when there is nothing to refresh with.
+    pub(crate) async fn refresh_oauth_tokens(&self, rejected_token: &str) -> YnabResult<bool> {
+        let Some(oauth) = &self.oauth else {
+            return Ok(false);
+        };
+
+        let mut refresh_token = oauth.refresh_token.lock().await;
+        if self.access_token() != rejected_token {
+            return Ok(true);
+        }
+        let Some(current_refresh_token) = refresh_token.clone() else {
+            return Ok(false);
+        };
+
+        tracing::debug!("refreshing YNAB access token");
+        let tokens = self
+            .request_oauth_tokens(
+                &oauth.config,
+                &[
+                    ("grant_type", "refresh_token"),
+                    ("refresh_token", &current_refresh_token),
+                ],
+            )
+            .await?;
+
+        *refresh_token = Some(tokens.refresh_token.clone());
+        self.set_access_token(&tokens.access_token);
+        if let Some(store) = &oauth.store {
+            store.save(&tokens).await;
+        }
+
+        Ok(true)
+    }
+
+    async fn request_oauth_tokens(
+        &self,
+        config: &OAuthConfig,
+        params: &[(&str, &str)],
+    ) -> YnabResult<OAuthTokens> {
+        let mut form = vec![
+            ("client_id", config.client_id.as_str()),
+            ("client_secret", config.client_secret.as_str()),
+        ];
+        form.extend_from_slice(params);
+
+        let body = self
+            .http_client
+            .post(config.oauth_base_url.join("token").unwrap())
+            .form(&form)
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        serde_json::from_str(&body).map_err(|e| match serde_json::from_str::<OAuthError>(&body) {
+            Ok(oauth_err) => Error::OAuth(oauth_err),
+            Err(_) => Error::Conversion(e),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use wiremock::{
+        matchers::{body_string_contains, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{AccountRequests, LAST_USED_BUDGET_ID};
+
+    #[derive(Default)]
+    struct MemoryStore(Mutex<Option<OAuthTokens>>);
+
+    #[async_trait]
+    impl OAuthTokenStore for MemoryStore {
+        async fn save(&self, tokens: &OAuthTokens) {
+            *self.0.lock().unwrap() = Some(tokens.clone());
+        }
+    }
+
+    fn tokens_body(access_token: &str, refresh_token: &str) -> serde_json::Value {
+        serde_json::json!({
+            "access_token": access_token,
+            "refresh_token": refresh_token,
+            "token_type": "Bearer",
+            "expires_in": 7200,
+        })
+    }
+
+    fn accounts_body() -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "accounts": [],
+                "server_knowledge": 1
+            }
+        })
+    }
+
+    async fn setup(store: Arc<MemoryStore>) -> (MockServer, Client) {
+        let server = MockServer::start().await;
+        let config = OAuthConfig::new("client", "secret", "http://localhost/callback")
+            .with_oauth_base_url(&format!("{}/oauth/", server.uri()));
+        let client = Client::new("", &format!("{}/", server.uri()))
+            .unwrap()
+            .with_oauth(config)
+            .with_oauth_token_store(store);
+        (server, client)
+    }
+
+    #[test]
+    fn authorize_url_has_all_params() {
+        let config = OAuthConfig::new("client", "secret", "http://localhost/callback");
+        let url = config.authorize_url("some-state");
+
+        assert_eq!(
+            url.as_str(),
+            "https://app.ynab.com/oauth/authorize?client_id=client&redirect_uri=http%3A%2F%2Flocalhost%2Fcallback&response_type=code&state=some-state"
+        );
+    }
+
+    #[tokio::test]
+    async fn exchanges_code_for_tokens() {
+        let (server, client) = setup(Default::default()).await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(body_string_contains("grant_type=authorization_code"))
+            .and(body_string_contains("code=the-code"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(tokens_body("access", "refresh")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tokens = client.exchange_oauth_code("the-code").await.unwrap();
+        assert_eq!(tokens.access_token, "access");
+        assert_eq!(client.access_token(), "access");
+    }
+
+    #[tokio::test]
+    async fn returns_oauth_error_when_code_is_rejected() {
+        let (server, client) = setup(Default::default()).await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": "invalid_grant",
+                "error_description": "The authorization code is invalid"
+            })))
+            .mount(&server)
+            .await;
+
+        let err = client.exchange_oauth_code("bad-code").await.unwrap_err();
+        assert!(
+            matches!(err, Error::OAuth(OAuthError { ref error, .. }) if error == "invalid_grant")
+        );
+    }
+
+    #[tokio::test]
+    async fn refreshes_expired_token_and_retries() {
+        let store = Arc::new(MemoryStore::default());
+        let (server, client) = setup(store.clone()).await;
+        let expired = OAuthTokens {
+            access_token: "expired".to_string(),
+            refresh_token: "refresh".to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: 7200,
+            scope: None,
+        };
+        client.set_oauth_tokens(&expired).await;
+
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/accounts"))
+            .and(header("Authorization", "Bearer expired"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "error": { "id": "401", "name": "unauthorized", "detail": "Unauthorized" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(body_string_contains("grant_type=refresh_token"))
+            .and(body_string_contains("refresh_token=refresh"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(tokens_body("fresh", "refresh2")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/accounts"))
+            .and(header("Authorization", "Bearer fresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(accounts_body()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        assert!(client.get_accounts(LAST_USED_BUDGET_ID).await.is_ok());
+        let saved = store.0.lock().unwrap().clone().unwrap();
+        assert_eq!(saved.access_token, "fresh");
+        assert_eq!(saved.refresh_token, "refresh2");
+    }
+}
@@ -6,24 +6,41 @@ use crate::{client::Response, error::YnabResult, Client, PayeeLocation};
 #[cfg_attr(any(feature = "testutils", test), mockall::automock)]
 #[async_trait]
 pub trait PayeeLocationRequests {
-    async fn get_payee_locations(&self) -> YnabResult<Vec<PayeeLocation>>;
+    async fn get_payee_locations(&self, budget_id: &str) -> YnabResult<Vec<PayeeLocation>>;
 
-    async fn get_payee_locations_for(&self, payee_id: &str) -> YnabResult<Vec<PayeeLocation>>;
+    async fn get_payee_locations_for(
+        &self,
+        budget_id: &str,
+        payee_id: &str,
+    ) -> YnabResult<Vec<PayeeLocation>>;
 
-    async fn get_payee_location_by_id(&self, payee_location_id: &str) -> YnabResult<PayeeLocation>;
+    async fn get_payee_location_by_id(
+        &self,
+        budget_id: &str,
+        payee_location_id: &str,
+    ) -> YnabResult<PayeeLocation>;
 }
 
 #[async_trait]
 impl PayeeLocationRequests for Client {
-    async fn get_payee_locations(&self) -> YnabResult<Vec<PayeeLocation>> {
-        self.get_payee_locations_request(None).await
+    async fn get_payee_locations(&self, budget_id: &str) -> YnabResult<Vec<PayeeLocation>> {
+        self.get_payee_locations_request(budget_id, None).await
     }
 
-    async fn get_payee_locations_for(&self, payee_id: &str) -> YnabResult<Vec<PayeeLocation>> {
-        self.get_payee_locations_request(Some(payee_id)).await
+    async fn get_payee_locations_for(
+        &self,
+        budget_id: &str,
+        payee_id: &str,
+    ) -> YnabResult<Vec<PayeeLocation>> {
+        self.get_payee_locations_request(budget_id, Some(payee_id))
+            .await
     }
 
-    async fn get_payee_location_by_id(&self, payee_location_id: &str) -> YnabResult<PayeeLocation> {
+    async fn get_payee_location_by_id(
+        &self,
+        budget_id: &str,
+        payee_location_id: &str,
+    ) -> YnabResult<PayeeLocation> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Inner {
             payee_location: PayeeLocation,
@@ -31,11 +48,10 @@ impl PayeeLocationRequests for Client {
 
         let path = format!(
             "budgets/{}/payee_locations/{}",
-            self.get_budget_id(),
-            payee_location_id
+            budget_id, payee_location_id
         );
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body)?;
         Ok(resp.data.payee_location)
@@ -45,6 +61,7 @@ impl PayeeLocationRequests for Client {
 impl Client {
     async fn get_payee_locations_request(
         &self,
+        budget_id: &str,
         payee_id: Option<&str>,
     ) -> YnabResult<Vec<PayeeLocation>> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,15 +70,11 @@ impl Client {
         }
 
         let path = match payee_id {
-            Some(p) => format!(
-                "budgets/{}/payees/{}/payee_locations",
-                self.get_budget_id(),
-                p
-            ),
-            None => format!("budgets/{}/payee_locations", self.get_budget_id()),
+            Some(p) => format!("budgets/{}/payees/{}/payee_locations", budget_id, p),
+            None => format!("budgets/{}/payee_locations", budget_id),
         };
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body)?;
         Ok(resp.data.payee_locations)
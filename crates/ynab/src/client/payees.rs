@@ -5,38 +5,41 @@ use crate::{client::Response, error::YnabResult, Client, Payee, PayeesDelta};
 
 #[async_trait]
 pub trait PayeeRequests {
-    async fn get_payees(&self) -> YnabResult<Vec<Payee>>;
+    async fn get_payees(&self, budget_id: &str) -> YnabResult<Vec<Payee>>;
 
     async fn get_payees_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<PayeesDelta>;
 
-    async fn get_payee_by_id(&self, payee_id: &str) -> YnabResult<Payee>;
+    async fn get_payee_by_id(&self, budget_id: &str, payee_id: &str) -> YnabResult<Payee>;
 }
 
 #[async_trait]
 impl PayeeRequests for Client {
-    async fn get_payees(&self) -> YnabResult<Vec<Payee>> {
-        Ok(self.get_payees_request(None).await?.payees)
+    async fn get_payees(&self, budget_id: &str) -> YnabResult<Vec<Payee>> {
+        Ok(self.get_payees_request(budget_id, None).await?.payees)
     }
 
     async fn get_payees_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<PayeesDelta> {
-        self.get_payees_request(last_knowledge_of_server).await
+        self.get_payees_request(budget_id, last_knowledge_of_server)
+            .await
     }
 
-    async fn get_payee_by_id(&self, payee_id: &str) -> YnabResult<Payee> {
+    async fn get_payee_by_id(&self, budget_id: &str, payee_id: &str) -> YnabResult<Payee> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Inner {
             payee: Payee,
         }
 
-        let path = format!("budgets/{}/payees/{}", self.get_budget_id(), payee_id);
+        let path = format!("budgets/{}/payees/{}", budget_id, payee_id);
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body)?;
         Ok(resp.data.payee)
@@ -46,18 +49,17 @@ impl PayeeRequests for Client {
 impl Client {
     async fn get_payees_request(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<PayeesDelta> {
-        let path = format!("budgets/{}/payees", self.get_budget_id());
+        let path = format!("budgets/{}/payees", budget_id);
 
-        let body = match last_knowledge_of_server {
+        let request_builder = match last_knowledge_of_server {
             Some(k) => self.get_with_query(&path, &[("last_knowledge_of_server", k)]),
             None => self.get(&path),
-        }
-        .send()
-        .await?
-        .text()
-        .await?;
+        };
+
+        let body = self.send(request_builder).await?;
 
         let resp: Response<PayeesDelta> = Client::convert_resp(body)?;
         Ok(resp.data)
@@ -74,11 +76,10 @@ mockall::mock! {
 
     #[async_trait]
     impl PayeeRequests for PayeeRequestsImpl {
-        async fn get_payees(&self) -> YnabResult<Vec<Payee>>;
-        async fn get_payees_delta(
-            &self,
+        async fn get_payees(&self, budget_id: &str) -> YnabResult<Vec<Payee>>;
+        async fn get_payees_delta(&self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<PayeesDelta>;
-        async fn get_payee_by_id(&self, payee_id: &str) -> YnabResult<Payee>;
+        async fn get_payee_by_id(&self, budget_id: &str, payee_id: &str) -> YnabResult<Payee>;
     }
 }
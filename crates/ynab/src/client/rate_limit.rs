@@ -0,0 +1,407 @@
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use rand::Rng;
+use reqwest::{header::HeaderMap, StatusCode};
+
+/// Name of the header YNAB uses to report how many requests were made with the current token.
+pub const RATE_LIMIT_HEADER: &str = "X-Rate-Limit";
+
+/// The rate limit state reported by YNAB's API through the `X-Rate-Limit` header.
+/// The header has the form `"36/200"`, meaning 36 requests were made out of the 200 allowed.
+/// See https://api.youneedabudget.com/#rate-limiting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub used: u32,
+    pub limit: u32,
+}
+
+impl RateLimit {
+    pub fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.used)
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get(RATE_LIMIT_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+    }
+}
+
+impl FromStr for RateLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (used, limit) = s
+            .trim()
+            .split_once('/')
+            .ok_or_else(|| format!("`{}` is not a valid rate limit", s))?;
+
+        Ok(Self {
+            used: used
+                .trim()
+                .parse()
+                .map_err(|_| format!("`{}` is not a number", used))?,
+            limit: limit
+                .trim()
+                .parse()
+                .map_err(|_| format!("`{}` is not a number", limit))?,
+        })
+    }
+}
+
+/// The last rate limit reported to the client, and until when it holds requests back.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RateLimitState {
+    pub(crate) rate_limit: RateLimit,
+    /// Number of responses in a row reporting the rate limit as exhausted.
+    pub(crate) exhausted_in_a_row: u32,
+    pub(crate) locked_until: Option<Instant>,
+}
+
+/// Controls how the client retries requests that failed because of YNAB's rate limiting (`429`)
+/// or because of a server error (`5xx`).
+/// Delays grow exponentially from `base_delay` up to `max_delay`, with some jitter added to avoid
+/// retrying in lockstep.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries failed requests.
+    pub fn no_retry() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub(crate) fn should_retry(&self, status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Computes the delay to wait before the given retry attempt (starting at 0).
+    /// Half of the exponential delay is kept and the other half is randomized.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let half = exponential / 2;
+        let jitter = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+
+        half + Duration::from_millis(jitter)
+    }
+}
+
+/// Reads the `Retry-After` header, when present, as a number of seconds to wait.
+pub(crate) fn retry_after_from_headers(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::{BudgetRequests, Client, Error, LAST_USED_BUDGET_ID};
+
+    fn fast_retries(max_retries: u32) -> RetryPolicy {
+        RetryPolicy::default()
+            .with_max_retries(max_retries)
+            .with_base_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(5))
+    }
+
+    fn budget_settings_body() -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "settings": {
+                    "date_format": { "format": "YYYY-MM-DD" },
+                    "currency_format": {
+                        "iso_code": "CAD",
+                        "example_format": "123 456,78",
+                        "decimal_digits": 2,
+                        "decimal_separator": ",",
+                        "symbol_first": false,
+                        "group_separator": " ",
+                        "currency_symbol": "$",
+                        "display_symbol": true
+                    }
+                }
+            }
+        })
+    }
+
+    async fn setup(policy: RetryPolicy) -> (MockServer, Client) {
+        let server = MockServer::start().await;
+        let client = Client::new("token", &format!("{}/", server.uri()))
+            .unwrap()
+            .with_retry_policy(policy);
+        (server, client)
+    }
+
+    #[test]
+    fn parses_rate_limit_header_value() {
+        let rate_limit: RateLimit = "36/200".parse().unwrap();
+        assert_eq!(
+            rate_limit,
+            RateLimit {
+                used: 36,
+                limit: 200
+            }
+        );
+        assert_eq!(rate_limit.remaining(), 164);
+        assert!(!rate_limit.is_exhausted());
+        assert!("200/200".parse::<RateLimit>().unwrap().is_exhausted());
+        assert!("36".parse::<RateLimit>().is_err());
+        assert!("a/200".parse::<RateLimit>().is_err());
+    }
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(1000));
+
+        for attempt in 0..10 {
+            let delay = policy.backoff(attempt);
+            let expected =
+                (Duration::from_millis(100) * 2_u32.pow(attempt)).min(Duration::from_millis(1000));
+            assert!(delay >= expected / 2);
+            assert!(delay <= expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn records_rate_limit_from_response() {
+        let (server, client) = setup(fast_retries(0)).await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(RATE_LIMIT_HEADER, "12/200")
+                    .set_body_json(budget_settings_body()),
+            )
+            .mount(&server)
+            .await;
+
+        assert_eq!(client.rate_limit(), None);
+        client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .unwrap();
+        assert_eq!(
+            client.rate_limit(),
+            Some(RateLimit {
+                used: 12,
+                limit: 200
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_after_too_many_requests() {
+        let (server, client) = setup(fast_retries(3)).await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(2)
+            .expect(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(budget_settings_body()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        assert!(client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn retries_after_server_error() {
+        let (server, client) = setup(fast_retries(3)).await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(budget_settings_body()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        assert!(client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn returns_rate_limited_when_retries_are_exhausted() {
+        let (server, client) = setup(fast_retries(2)).await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let err = client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::RateLimited { retry_after } if retry_after == Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn only_the_wait_for_retry_after_is_capped_by_max_delay() {
+        let (server, client) = setup(fast_retries(1)).await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let started = Instant::now();
+        let err = client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(
+            matches!(err, Error::RateLimited { retry_after } if retry_after == Duration::from_secs(3600))
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_send_when_rate_limit_is_exhausted() {
+        let (server, client) = setup(fast_retries(0)).await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(RATE_LIMIT_HEADER, "200/200")
+                    .set_body_json(budget_settings_body()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .unwrap();
+        let err = client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::RateLimited { retry_after } if retry_after <= Duration::from_millis(5))
+        );
+    }
+
+    #[tokio::test]
+    async fn waits_for_retry_after_when_rate_limit_is_exhausted() {
+        let (server, client) = setup(fast_retries(0)).await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(RATE_LIMIT_HEADER, "200/200")
+                    .insert_header("Retry-After", "0")
+                    .set_body_json(budget_settings_body()),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        for _ in 0..2 {
+            client
+                .get_budget_settings(LAST_USED_BUDGET_ID)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn sends_again_after_a_backoff_when_rate_limit_is_exhausted() {
+        let (server, client) = setup(fast_retries(0)).await;
+        Mock::given(method("GET"))
+            .and(path("/budgets/last-used/settings"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(RATE_LIMIT_HEADER, "200/200")
+                    .set_body_json(budget_settings_body()),
+            )
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        client
+            .get_budget_settings(LAST_USED_BUDGET_ID)
+            .await
+            .unwrap();
+    }
+}
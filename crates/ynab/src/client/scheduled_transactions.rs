@@ -2,44 +2,73 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::Response, error::YnabResult, Client, ScheduledTransactionDetail,
-    ScheduledTransactionsDetailDelta,
+    client::Response, error::YnabResult, Client, SaveScheduledTransaction,
+    ScheduledTransactionDetail, ScheduledTransactionsDetailDelta,
 };
 
 #[async_trait]
 pub trait ScheduledTransactionRequests {
-    async fn get_scheduled_transactions(&self) -> YnabResult<Vec<ScheduledTransactionDetail>>;
+    async fn get_scheduled_transactions(
+        &self,
+        budget_id: &str,
+    ) -> YnabResult<Vec<ScheduledTransactionDetail>>;
 
     async fn get_scheduled_transactions_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<ScheduledTransactionsDetailDelta>;
 
     async fn get_scheduled_transaction_by_id(
         &self,
+        budget_id: &str,
+        scheduled_transaction_id: &str,
+    ) -> YnabResult<ScheduledTransactionDetail>;
+
+    async fn create_scheduled_transaction(
+        &self,
+        budget_id: &str,
+        data: SaveScheduledTransaction,
+    ) -> YnabResult<ScheduledTransactionDetail>;
+
+    async fn update_scheduled_transaction(
+        &self,
+        budget_id: &str,
+        scheduled_transaction_id: &str,
+        data: SaveScheduledTransaction,
+    ) -> YnabResult<ScheduledTransactionDetail>;
+
+    async fn delete_scheduled_transaction(
+        &self,
+        budget_id: &str,
         scheduled_transaction_id: &str,
     ) -> YnabResult<ScheduledTransactionDetail>;
 }
 
 #[async_trait]
 impl ScheduledTransactionRequests for Client {
-    async fn get_scheduled_transactions(&self) -> YnabResult<Vec<ScheduledTransactionDetail>> {
+    async fn get_scheduled_transactions(
+        &self,
+        budget_id: &str,
+    ) -> YnabResult<Vec<ScheduledTransactionDetail>> {
         Ok(self
-            .get_scheduled_transactions_request(None)
+            .get_scheduled_transactions_request(budget_id, None)
             .await?
             .scheduled_transactions)
     }
 
     async fn get_scheduled_transactions_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<ScheduledTransactionsDetailDelta> {
-        self.get_scheduled_transactions_request(last_knowledge_of_server)
+        self.get_scheduled_transactions_request(budget_id, last_knowledge_of_server)
             .await
     }
 
     async fn get_scheduled_transaction_by_id(
         &self,
+        budget_id: &str,
         scheduled_transaction_id: &str,
     ) -> YnabResult<ScheduledTransactionDetail> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,32 +78,107 @@ impl ScheduledTransactionRequests for Client {
 
         let path = format!(
             "budgets/{}/scheduled_transactions/{}",
-            self.get_budget_id(),
-            scheduled_transaction_id
+            budget_id, scheduled_transaction_id
         );
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body.as_str())?;
         Ok(resp.data.scheduled_transaction)
     }
+
+    async fn create_scheduled_transaction(
+        &self,
+        budget_id: &str,
+        data: SaveScheduledTransaction,
+    ) -> YnabResult<ScheduledTransactionDetail> {
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        struct Body {
+            scheduled_transaction: SaveScheduledTransaction,
+        }
+        let body: Body = Body {
+            scheduled_transaction: data,
+        };
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        struct Inner {
+            scheduled_transaction: ScheduledTransactionDetail,
+        }
+
+        let path = format!("budgets/{}/scheduled_transactions", budget_id);
+
+        let body_resp = self.send(self.post(&path, Some(&body))).await?;
+
+        let resp: Response<Inner> = Client::convert_resp(body_resp)?;
+        Ok(resp.data.scheduled_transaction)
+    }
+
+    async fn update_scheduled_transaction(
+        &self,
+        budget_id: &str,
+        scheduled_transaction_id: &str,
+        data: SaveScheduledTransaction,
+    ) -> YnabResult<ScheduledTransactionDetail> {
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        struct Body {
+            scheduled_transaction: SaveScheduledTransaction,
+        }
+        let body: Body = Body {
+            scheduled_transaction: data,
+        };
+
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        struct Inner {
+            scheduled_transaction: ScheduledTransactionDetail,
+        }
+
+        let path = format!(
+            "budgets/{}/scheduled_transactions/{}",
+            budget_id, scheduled_transaction_id
+        );
+
+        let body_resp = self.send(self.put(&path, &body)).await?;
+
+        let resp: Response<Inner> = Client::convert_resp(body_resp)?;
+        Ok(resp.data.scheduled_transaction)
+    }
+
+    async fn delete_scheduled_transaction(
+        &self,
+        budget_id: &str,
+        scheduled_transaction_id: &str,
+    ) -> YnabResult<ScheduledTransactionDetail> {
+        #[derive(Debug, Clone, Serialize, Deserialize)]
+        struct Inner {
+            scheduled_transaction: ScheduledTransactionDetail,
+        }
+
+        let path = format!(
+            "budgets/{}/scheduled_transactions/{}",
+            budget_id, scheduled_transaction_id
+        );
+
+        let body_resp = self.send(self.delete(&path)).await?;
+
+        let resp: Response<Inner> = Client::convert_resp(body_resp)?;
+        Ok(resp.data.scheduled_transaction)
+    }
 }
 
 impl Client {
     async fn get_scheduled_transactions_request(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<ScheduledTransactionsDetailDelta> {
-        let path = format!("budgets/{}/scheduled_transactions", self.get_budget_id());
+        let path = format!("budgets/{}/scheduled_transactions", budget_id);
 
-        let body = match last_knowledge_of_server {
+        let request_builder = match last_knowledge_of_server {
             Some(k) => self.get_with_query(&path, &[("last_knowledge_of_server", k)]),
             None => self.get(&path),
-        }
-        .send()
-        .await?
-        .text()
-        .await?;
+        };
+
+        let body = self.send(request_builder).await?;
 
         let resp: Response<ScheduledTransactionsDetailDelta> = Client::convert_resp(body.as_str())?;
         Ok(resp.data)
@@ -91,14 +195,150 @@ mockall::mock! {
 
     #[async_trait]
     impl ScheduledTransactionRequests for ScheduledTransactionRequestsImpl {
-        async fn get_scheduled_transactions(&self) -> YnabResult<Vec<ScheduledTransactionDetail>>;
-        async fn get_scheduled_transactions_delta(
-            &self,
+        async fn get_scheduled_transactions(&self, budget_id: &str) -> YnabResult<Vec<ScheduledTransactionDetail>>;
+        async fn get_scheduled_transactions_delta(&self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<ScheduledTransactionsDetailDelta>;
-        async fn get_scheduled_transaction_by_id(
-            &self,
+        async fn get_scheduled_transaction_by_id(&self, budget_id: &str,
+            scheduled_transaction_id: &str,
+        ) -> YnabResult<ScheduledTransactionDetail>;
+        async fn create_scheduled_transaction(&self, budget_id: &str,
+            data: SaveScheduledTransaction,
+        ) -> YnabResult<ScheduledTransactionDetail>;
+        async fn update_scheduled_transaction(&self, budget_id: &str,
+            scheduled_transaction_id: &str,
+            data: SaveScheduledTransaction,
+        ) -> YnabResult<ScheduledTransactionDetail>;
+        async fn delete_scheduled_transaction(&self, budget_id: &str,
             scheduled_transaction_id: &str,
         ) -> YnabResult<ScheduledTransactionDetail>;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, Faker};
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::LAST_USED_BUDGET_ID;
+
+    fn scheduled_transaction_body(
+        scheduled_transaction: &ScheduledTransactionDetail,
+    ) -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "scheduled_transaction": scheduled_transaction
+            }
+        })
+    }
+
+    async fn setup() -> (MockServer, Client) {
+        let server = MockServer::start().await;
+        let client = Client::new("token", &format!("{}/", server.uri())).unwrap();
+        (server, client)
+    }
+
+    #[tokio::test]
+    async fn creates_scheduled_transaction() {
+        let (server, client) = setup().await;
+        let data: SaveScheduledTransaction = Faker.fake();
+        let created: ScheduledTransactionDetail = Faker.fake();
+        Mock::given(method("POST"))
+            .and(path("/budgets/last-used/scheduled_transactions"))
+            .and(body_json(
+                serde_json::json!({ "scheduled_transaction": data }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(scheduled_transaction_body(&created)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let resp = client
+            .create_scheduled_transaction(LAST_USED_BUDGET_ID, data)
+            .await
+            .unwrap();
+        assert_eq!(resp, created);
+    }
+
+    #[tokio::test]
+    async fn updates_scheduled_transaction() {
+        let (server, client) = setup().await;
+        let data: SaveScheduledTransaction = Faker.fake();
+        let updated: ScheduledTransactionDetail = Faker.fake();
+        Mock::given(method("PUT"))
+            .and(path(format!(
+                "/budgets/last-used/scheduled_transactions/{}",
+                updated.id
+            )))
+            .and(body_json(
+                serde_json::json!({ "scheduled_transaction": data }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(scheduled_transaction_body(&updated)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let resp = client
+            .update_scheduled_transaction(LAST_USED_BUDGET_ID, &updated.id.to_string(), data)
+            .await
+            .unwrap();
+        assert_eq!(resp, updated);
+    }
+
+    #[tokio::test]
+    async fn deletes_scheduled_transaction() {
+        let (server, client) = setup().await;
+        let deleted = ScheduledTransactionDetail {
+            deleted: true,
+            ..Faker.fake()
+        };
+        Mock::given(method("DELETE"))
+            .and(path(format!(
+                "/budgets/last-used/scheduled_transactions/{}",
+                deleted.id
+            )))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(scheduled_transaction_body(&deleted)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let resp = client
+            .delete_scheduled_transaction(LAST_USED_BUDGET_ID, &deleted.id.to_string())
+            .await
+            .unwrap();
+        assert_eq!(resp, deleted);
+    }
+
+    #[tokio::test]
+    async fn returns_api_error_of_rejected_scheduled_transaction() {
+        let (server, client) = setup().await;
+        Mock::given(method("POST"))
+            .and(path("/budgets/last-used/scheduled_transactions"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": {
+                    "id": "400",
+                    "name": "bad_request",
+                    "detail": "date must be in the future"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = client
+            .create_scheduled_transaction(LAST_USED_BUDGET_ID, Faker.fake())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::Api(_)));
+    }
+}
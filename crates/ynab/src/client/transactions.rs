@@ -2,47 +2,57 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::Response, error::YnabResult, Client, SaveTransaction, TransactionDetail,
-    TransactionType, TransactionsDetailDelta, TransactionsParentPath, TransactionsRequestQuery,
-    UpdateTransaction,
+    client::Response, error::YnabResult, Client, SaveTransaction, SaveTransactionsResponse,
+    TransactionDetail, TransactionType, TransactionsDetailDelta, TransactionsParentPath,
+    TransactionsRequestQuery, UpdateTransaction,
 };
 
 #[async_trait]
 pub trait TransactionRequests {
-    async fn get_transactions(&self) -> YnabResult<Vec<TransactionDetail>>;
+    async fn get_transactions(&self, budget_id: &str) -> YnabResult<Vec<TransactionDetail>>;
 
     async fn get_transactions_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta>;
 
-    async fn get_transactions_since(&self, since_date: &str) -> YnabResult<Vec<TransactionDetail>>;
+    async fn get_transactions_since(
+        &self,
+        budget_id: &str,
+        since_date: &str,
+    ) -> YnabResult<Vec<TransactionDetail>>;
 
     async fn get_transactions_since_delta(
         &self,
+        budget_id: &str,
         since_date: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta>;
 
     async fn get_transactions_of(
         &self,
+        budget_id: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<TransactionDetail>>;
 
     async fn get_transactions_of_delta(
         &self,
+        budget_id: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta>;
 
     async fn get_transactions_since_date_of(
         &self,
+        budget_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<TransactionDetail>>;
 
     async fn get_transactions_since_date_of_delta(
         &self,
+        budget_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
@@ -50,23 +60,27 @@ pub trait TransactionRequests {
 
     async fn get_transactions_by_account_id(
         &self,
+        budget_id: &str,
         account_id: &str,
     ) -> YnabResult<Vec<TransactionDetail>>;
 
     async fn get_transactions_by_account_id_delta(
         &self,
+        budget_id: &str,
         account_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta>;
 
     async fn get_transactions_by_account_id_since(
         &self,
+        budget_id: &str,
         account_id: &str,
         since_date: &str,
     ) -> YnabResult<Vec<TransactionDetail>>;
 
     async fn get_transactions_by_account_id_since_delta(
         &self,
+        budget_id: &str,
         account_id: &str,
         since_date: &str,
         last_knowledge_of_server: Option<i64>,
@@ -74,12 +88,14 @@ pub trait TransactionRequests {
 
     async fn get_transactions_by_account_id_of(
         &self,
+        budget_id: &str,
         account_id: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<TransactionDetail>>;
 
     async fn get_transactions_by_account_id_of_delta(
         &self,
+        budget_id: &str,
         account_id: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
@@ -87,6 +103,7 @@ pub trait TransactionRequests {
 
     async fn get_transactions_by_account_id_since_date_of(
         &self,
+        budget_id: &str,
         account_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -94,30 +111,45 @@ pub trait TransactionRequests {
 
     async fn get_transactions_by_account_id_since_date_of_delta(
         &self,
+        budget_id: &str,
         account_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta>;
 
-    async fn create_transaction(&self, data: SaveTransaction) -> YnabResult<TransactionDetail>;
+    async fn create_transaction(
+        &self,
+        budget_id: &str,
+        data: SaveTransaction,
+    ) -> YnabResult<TransactionDetail>;
 
+    /// Creates several transactions at once. Transactions whose `import_id` already exists
+    /// on the same account are skipped and returned in `duplicate_import_ids`.
     async fn create_transactions(
         &self,
+        budget_id: &str,
         data: Vec<SaveTransaction>,
-    ) -> YnabResult<Vec<TransactionDetail>>;
+    ) -> YnabResult<SaveTransactionsResponse>;
 
     async fn update_transactions(
         &self,
+        budget_id: &str,
         data: Vec<UpdateTransaction>,
     ) -> YnabResult<Vec<TransactionDetail>>;
 
-    async fn import_transactions(&self) -> YnabResult<Vec<String>>;
+    /// Triggers an import of transactions from linked accounts and returns the ids of the imported transactions.
+    async fn import_transactions(&self, budget_id: &str) -> YnabResult<Vec<String>>;
 
-    async fn get_transaction_by_id(&self, transaction_id: &str) -> YnabResult<TransactionDetail>;
+    async fn get_transaction_by_id(
+        &self,
+        budget_id: &str,
+        transaction_id: &str,
+    ) -> YnabResult<TransactionDetail>;
 
     async fn update_transaction(
         &self,
+        budget_id: &str,
         transaction_id: &str,
         data: SaveTransaction,
     ) -> YnabResult<TransactionDetail>;
@@ -125,65 +157,77 @@ pub trait TransactionRequests {
 
 #[async_trait]
 impl TransactionRequests for Client {
-    async fn get_transactions(&self) -> YnabResult<Vec<TransactionDetail>> {
+    async fn get_transactions(&self, budget_id: &str) -> YnabResult<Vec<TransactionDetail>> {
         Ok(self
-            .get_transactions_request(None, None)
+            .get_transactions_request(budget_id, None, None)
             .await?
             .transactions)
     }
 
     async fn get_transactions_delta(
         &self,
+        budget_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta> {
         let query =
             TransactionsRequestQuery::default().with_last_knowledge(last_knowledge_of_server);
-        self.get_transactions_request(None, Some(query)).await
+        self.get_transactions_request(budget_id, None, Some(query))
+            .await
     }
 
-    async fn get_transactions_since(&self, since_date: &str) -> YnabResult<Vec<TransactionDetail>> {
+    async fn get_transactions_since(
+        &self,
+        budget_id: &str,
+        since_date: &str,
+    ) -> YnabResult<Vec<TransactionDetail>> {
         let query = TransactionsRequestQuery::default().with_date(since_date);
         Ok(self
-            .get_transactions_request(None, Some(query))
+            .get_transactions_request(budget_id, None, Some(query))
             .await?
             .transactions)
     }
 
     async fn get_transactions_since_delta(
         &self,
+        budget_id: &str,
         since_date: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta> {
         let query = TransactionsRequestQuery::default()
             .with_last_knowledge(last_knowledge_of_server)
             .with_date(since_date);
-        self.get_transactions_request(None, Some(query)).await
+        self.get_transactions_request(budget_id, None, Some(query))
+            .await
     }
 
     async fn get_transactions_of(
         &self,
+        budget_id: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<TransactionDetail>> {
         let query = TransactionsRequestQuery::default().with_transaction_type(transaction_type);
         Ok(self
-            .get_transactions_request(None, Some(query))
+            .get_transactions_request(budget_id, None, Some(query))
             .await?
             .transactions)
     }
 
     async fn get_transactions_of_delta(
         &self,
+        budget_id: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta> {
         let query = TransactionsRequestQuery::default()
             .with_last_knowledge(last_knowledge_of_server)
             .with_transaction_type(transaction_type);
-        self.get_transactions_request(None, Some(query)).await
+        self.get_transactions_request(budget_id, None, Some(query))
+            .await
     }
 
     async fn get_transactions_since_date_of(
         &self,
+        budget_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<TransactionDetail>> {
@@ -191,13 +235,14 @@ impl TransactionRequests for Client {
             .with_date(since_date)
             .with_transaction_type(transaction_type);
         Ok(self
-            .get_transactions_request(None, Some(query))
+            .get_transactions_request(budget_id, None, Some(query))
             .await?
             .transactions)
     }
 
     async fn get_transactions_since_date_of_delta(
         &self,
+        budget_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
@@ -206,27 +251,35 @@ impl TransactionRequests for Client {
             .with_last_knowledge(last_knowledge_of_server)
             .with_date(since_date)
             .with_transaction_type(transaction_type);
-        self.get_transactions_request(None, Some(query)).await
+        self.get_transactions_request(budget_id, None, Some(query))
+            .await
     }
 
     async fn get_transactions_by_account_id(
         &self,
+        budget_id: &str,
         account_id: &str,
     ) -> YnabResult<Vec<TransactionDetail>> {
         Ok(self
-            .get_transactions_request(Some(TransactionsParentPath::Accounts(account_id)), None)
+            .get_transactions_request(
+                budget_id,
+                Some(TransactionsParentPath::Accounts(account_id)),
+                None,
+            )
             .await?
             .transactions)
     }
 
     async fn get_transactions_by_account_id_delta(
         &self,
+        budget_id: &str,
         account_id: &str,
         last_knowledge_of_server: Option<i64>,
     ) -> YnabResult<TransactionsDetailDelta> {
         let query =
             TransactionsRequestQuery::default().with_last_knowledge(last_knowledge_of_server);
         self.get_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Accounts(account_id)),
             Some(query),
         )
@@ -235,12 +288,14 @@ impl TransactionRequests for Client {
 
     async fn get_transactions_by_account_id_since(
         &self,
+        budget_id: &str,
         account_id: &str,
         since_date: &str,
     ) -> YnabResult<Vec<TransactionDetail>> {
         let query = TransactionsRequestQuery::default().with_date(since_date);
         Ok(self
             .get_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Accounts(account_id)),
                 Some(query),
             )
@@ -250,6 +305,7 @@ impl TransactionRequests for Client {
 
     async fn get_transactions_by_account_id_since_delta(
         &self,
+        budget_id: &str,
         account_id: &str,
         since_date: &str,
         last_knowledge_of_server: Option<i64>,
@@ -258,6 +314,7 @@ impl TransactionRequests for Client {
             .with_last_knowledge(last_knowledge_of_server)
             .with_date(since_date);
         self.get_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Accounts(account_id)),
             Some(query),
         )
@@ -266,12 +323,14 @@ impl TransactionRequests for Client {
 
     async fn get_transactions_by_account_id_of(
         &self,
+        budget_id: &str,
         account_id: &str,
         transaction_type: TransactionType,
     ) -> YnabResult<Vec<TransactionDetail>> {
         let query = TransactionsRequestQuery::default().with_transaction_type(transaction_type);
         Ok(self
             .get_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Accounts(account_id)),
                 Some(query),
             )
@@ -281,6 +340,7 @@ impl TransactionRequests for Client {
 
     async fn get_transactions_by_account_id_of_delta(
         &self,
+        budget_id: &str,
         account_id: &str,
         transaction_type: TransactionType,
         last_knowledge_of_server: Option<i64>,
@@ -289,6 +349,7 @@ impl TransactionRequests for Client {
             .with_last_knowledge(last_knowledge_of_server)
             .with_transaction_type(transaction_type);
         self.get_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Accounts(account_id)),
             Some(query),
         )
@@ -297,6 +358,7 @@ impl TransactionRequests for Client {
 
     async fn get_transactions_by_account_id_since_date_of(
         &self,
+        budget_id: &str,
         account_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -306,6 +368,7 @@ impl TransactionRequests for Client {
             .with_transaction_type(transaction_type);
         Ok(self
             .get_transactions_request(
+                budget_id,
                 Some(TransactionsParentPath::Accounts(account_id)),
                 Some(query),
             )
@@ -315,6 +378,7 @@ impl TransactionRequests for Client {
 
     async fn get_transactions_by_account_id_since_date_of_delta(
         &self,
+        budget_id: &str,
         account_id: &str,
         since_date: &str,
         transaction_type: TransactionType,
@@ -325,13 +389,18 @@ impl TransactionRequests for Client {
             .with_date(since_date)
             .with_transaction_type(transaction_type);
         self.get_transactions_request(
+            budget_id,
             Some(TransactionsParentPath::Accounts(account_id)),
             Some(query),
         )
         .await
     }
 
-    async fn create_transaction(&self, data: SaveTransaction) -> YnabResult<TransactionDetail> {
+    async fn create_transaction(
+        &self,
+        budget_id: &str,
+        data: SaveTransaction,
+    ) -> YnabResult<TransactionDetail> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Body {
             transaction: SaveTransaction,
@@ -345,9 +414,9 @@ impl TransactionRequests for Client {
             duplicate_import_ids: Vec<String>,
         }
 
-        let path = format!("budgets/{}/transactions", self.get_budget_id());
+        let path = format!("budgets/{}/transactions", budget_id);
 
-        let body_resp = self.post(&path, Some(&body)).send().await?.text().await?;
+        let body_resp = self.send(self.post(&path, Some(&body))).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body_resp)?;
         Ok(resp.data.transaction)
@@ -355,31 +424,26 @@ impl TransactionRequests for Client {
 
     async fn create_transactions(
         &self,
+        budget_id: &str,
         data: Vec<SaveTransaction>,
-    ) -> YnabResult<Vec<TransactionDetail>> {
+    ) -> YnabResult<SaveTransactionsResponse> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Body {
             transactions: Vec<SaveTransaction>,
         }
         let body: Body = Body { transactions: data };
 
-        #[derive(Debug, Clone, Serialize, Deserialize)]
-        struct Inner {
-            transaction_ids: Vec<String>,
-            transactions: Vec<TransactionDetail>,
-            duplicate_import_ids: Vec<String>,
-        }
-
-        let path = format!("budgets/{}/transactions", self.get_budget_id());
+        let path = format!("budgets/{}/transactions", budget_id);
 
-        let body_resp = self.post(&path, Some(&body)).send().await?.text().await?;
+        let body_resp = self.send(self.post(&path, Some(&body))).await?;
 
-        let resp: Response<Inner> = Client::convert_resp(body_resp)?;
-        Ok(resp.data.transactions)
+        let resp: Response<SaveTransactionsResponse> = Client::convert_resp(body_resp)?;
+        Ok(resp.data)
     }
 
     async fn update_transactions(
         &self,
+        budget_id: &str,
         data: Vec<UpdateTransaction>,
     ) -> YnabResult<Vec<TransactionDetail>> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -395,46 +459,41 @@ impl TransactionRequests for Client {
             duplicate_import_ids: Vec<String>,
         }
 
-        let path = format!("budgets/{}/transactions", self.get_budget_id());
+        let path = format!("budgets/{}/transactions", budget_id);
 
-        let body_resp = self.patch(&path, &body).send().await?.text().await?;
+        let body_resp = self.send(self.patch(&path, &body)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body_resp)?;
         Ok(resp.data.transactions)
     }
 
-    async fn import_transactions(&self) -> YnabResult<Vec<String>> {
+    async fn import_transactions(&self, budget_id: &str) -> YnabResult<Vec<String>> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Inner {
             transaction_ids: Vec<String>,
         }
 
-        let path = format!("budgets/{}/transactions/import", self.get_budget_id());
+        let path = format!("budgets/{}/transactions/import", budget_id);
 
-        let body_resp = self
-            .post::<String>(&path, None)
-            .send()
-            .await?
-            .text()
-            .await?;
+        let body_resp = self.send(self.post::<String>(&path, None)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body_resp)?;
         Ok(resp.data.transaction_ids)
     }
 
-    async fn get_transaction_by_id(&self, transaction_id: &str) -> YnabResult<TransactionDetail> {
+    async fn get_transaction_by_id(
+        &self,
+        budget_id: &str,
+        transaction_id: &str,
+    ) -> YnabResult<TransactionDetail> {
         #[derive(Debug, Clone, Serialize, Deserialize)]
         struct Inner {
             transaction: TransactionDetail,
         }
 
-        let path = format!(
-            "budgets/{}/transactions/{}",
-            self.get_budget_id(),
-            transaction_id
-        );
+        let path = format!("budgets/{}/transactions/{}", budget_id, transaction_id);
 
-        let body = self.get(&path).send().await?.text().await?;
+        let body = self.send(self.get(&path)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body)?;
         Ok(resp.data.transaction)
@@ -442,6 +501,7 @@ impl TransactionRequests for Client {
 
     async fn update_transaction(
         &self,
+        budget_id: &str,
         transaction_id: &str,
         data: SaveTransaction,
     ) -> YnabResult<TransactionDetail> {
@@ -456,13 +516,9 @@ impl TransactionRequests for Client {
             transaction: TransactionDetail,
         }
 
-        let path = format!(
-            "budgets/{}/transactions/{}",
-            self.get_budget_id(),
-            transaction_id
-        );
+        let path = format!("budgets/{}/transactions/{}", budget_id, transaction_id);
 
-        let body_resp = self.put(&path, &body).send().await?.text().await?;
+        let body_resp = self.send(self.put(&path, &body)).await?;
 
         let resp: Response<Inner> = Client::convert_resp(body_resp)?;
         Ok(resp.data.transaction)
@@ -472,28 +528,23 @@ impl TransactionRequests for Client {
 impl Client {
     async fn get_transactions_request(
         &self,
+        budget_id: &str,
         parent_path: Option<TransactionsParentPath<&str>>,
         query: Option<TransactionsRequestQuery>,
     ) -> YnabResult<TransactionsDetailDelta> {
         let path = match parent_path {
             Some(tp) => match tp {
-                TransactionsParentPath::Accounts(id) => format!(
-                    "budgets/{}/accounts/{}/transactions",
-                    self.get_budget_id(),
-                    id
-                ),
-                TransactionsParentPath::Categories(id) => format!(
-                    "budgets/{}/categories/{}/transactions",
-                    self.get_budget_id(),
-                    id
-                ),
-                TransactionsParentPath::Payees(id) => format!(
-                    "budgets/{}/payees/{}/transactions",
-                    self.get_budget_id(),
-                    id
-                ),
+                TransactionsParentPath::Accounts(id) => {
+                    format!("budgets/{}/accounts/{}/transactions", budget_id, id)
+                }
+                TransactionsParentPath::Categories(id) => {
+                    format!("budgets/{}/categories/{}/transactions", budget_id, id)
+                }
+                TransactionsParentPath::Payees(id) => {
+                    format!("budgets/{}/payees/{}/transactions", budget_id, id)
+                }
             },
-            None => format!("budgets/{}/transactions", self.get_budget_id()),
+            None => format!("budgets/{}/transactions", budget_id),
         };
 
         let request_builder = if query.is_some() {
@@ -502,7 +553,7 @@ impl Client {
             self.get(&path)
         };
 
-        let body = request_builder.send().await?.text().await?;
+        let body = self.send(request_builder).await?;
 
         let resp: Response<TransactionsDetailDelta> = Client::convert_resp(body)?;
         Ok(resp.data)
@@ -519,96 +570,179 @@ mockall::mock! {
 
     #[async_trait]
     impl TransactionRequests for TransactionRequestsImpl {
-        async fn get_transactions(&self) -> YnabResult<Vec<TransactionDetail>>;
-        async fn get_transactions_delta(
-            &self,
+        async fn get_transactions(&self, budget_id: &str) -> YnabResult<Vec<TransactionDetail>>;
+        async fn get_transactions_delta(&self, budget_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<TransactionsDetailDelta>;
-        async fn get_transactions_since(&self, since_date: &str) -> YnabResult<Vec<TransactionDetail>>;
-        async fn get_transactions_since_delta(
-            &self,
+        async fn get_transactions_since(&self, budget_id: &str, since_date: &str) -> YnabResult<Vec<TransactionDetail>>;
+        async fn get_transactions_since_delta(&self, budget_id: &str,
             since_date: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<TransactionsDetailDelta>;
-        async fn get_transactions_of(
-            &self,
+        async fn get_transactions_of(&self, budget_id: &str,
             transaction_type: TransactionType,
         ) -> YnabResult<Vec<TransactionDetail>>;
-        async fn get_transactions_of_delta(
-            &self,
+        async fn get_transactions_of_delta(&self, budget_id: &str,
             transaction_type: TransactionType,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<TransactionsDetailDelta>;
-        async fn get_transactions_since_date_of(
-            &self,
+        async fn get_transactions_since_date_of(&self, budget_id: &str,
             since_date: &str,
             transaction_type: TransactionType,
         ) -> YnabResult<Vec<TransactionDetail>>;
-        async fn get_transactions_since_date_of_delta(
-            &self,
+        async fn get_transactions_since_date_of_delta(&self, budget_id: &str,
             since_date: &str,
             transaction_type: TransactionType,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<TransactionsDetailDelta>;
-        async fn get_transactions_by_account_id(
-            &self,
+        async fn get_transactions_by_account_id(&self, budget_id: &str,
             account_id: &str,
         ) -> YnabResult<Vec<TransactionDetail>>;
-        async fn get_transactions_by_account_id_delta(
-            &self,
+        async fn get_transactions_by_account_id_delta(&self, budget_id: &str,
             account_id: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<TransactionsDetailDelta>;
-        async fn get_transactions_by_account_id_since(
-            &self,
+        async fn get_transactions_by_account_id_since(&self, budget_id: &str,
             account_id: &str,
             since_date: &str,
         ) -> YnabResult<Vec<TransactionDetail>>;
-        async fn get_transactions_by_account_id_since_delta(
-            &self,
+        async fn get_transactions_by_account_id_since_delta(&self, budget_id: &str,
             account_id: &str,
             since_date: &str,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<TransactionsDetailDelta>;
-        async fn get_transactions_by_account_id_of(
-            &self,
+        async fn get_transactions_by_account_id_of(&self, budget_id: &str,
             account_id: &str,
             transaction_type: TransactionType,
         ) -> YnabResult<Vec<TransactionDetail>>;
-        async fn get_transactions_by_account_id_of_delta(
-            &self,
+        async fn get_transactions_by_account_id_of_delta(&self, budget_id: &str,
             account_id: &str,
             transaction_type: TransactionType,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<TransactionsDetailDelta>;
-        async fn get_transactions_by_account_id_since_date_of(
-            &self,
+        async fn get_transactions_by_account_id_since_date_of(&self, budget_id: &str,
             account_id: &str,
             since_date: &str,
             transaction_type: TransactionType,
         ) -> YnabResult<Vec<TransactionDetail>>;
-        async fn get_transactions_by_account_id_since_date_of_delta(
-            &self,
+        async fn get_transactions_by_account_id_since_date_of_delta(&self, budget_id: &str,
             account_id: &str,
             since_date: &str,
             transaction_type: TransactionType,
             last_knowledge_of_server: Option<i64>,
         ) -> YnabResult<TransactionsDetailDelta>;
-        async fn create_transaction(&self, data: SaveTransaction) -> YnabResult<TransactionDetail>;
-        async fn create_transactions(
-            &self,
+        async fn create_transaction(&self, budget_id: &str, data: SaveTransaction) -> YnabResult<TransactionDetail>;
+        async fn create_transactions(&self, budget_id: &str,
             data: Vec<SaveTransaction>,
-        ) -> YnabResult<Vec<TransactionDetail>>;
-        async fn update_transactions(
-            &self,
+        ) -> YnabResult<SaveTransactionsResponse>;
+        async fn update_transactions(&self, budget_id: &str,
             data: Vec<UpdateTransaction>,
         ) -> YnabResult<Vec<TransactionDetail>>;
-        async fn import_transactions(&self) -> YnabResult<Vec<String>>;
-        async fn get_transaction_by_id(&self, transaction_id: &str) -> YnabResult<TransactionDetail>;
-        async fn update_transaction(
-            &self,
+        async fn import_transactions(&self, budget_id: &str) -> YnabResult<Vec<String>>;
+        async fn get_transaction_by_id(&self, budget_id: &str, transaction_id: &str) -> YnabResult<TransactionDetail>;
+        async fn update_transaction(&self, budget_id: &str,
             transaction_id: &str,
             data: SaveTransaction,
         ) -> YnabResult<TransactionDetail>;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fake::{Fake, Faker};
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+    use crate::LAST_USED_BUDGET_ID;
+
+    fn save_transactions_body(response: &SaveTransactionsResponse) -> serde_json::Value {
+        serde_json::json!({ "data": response })
+    }
+
+    async fn setup() -> (MockServer, Client) {
+        let server = MockServer::start().await;
+        let client = Client::new("token", &format!("{}/", server.uri())).unwrap();
+        (server, client)
+    }
+
+    #[tokio::test]
+    async fn creates_transactions_in_bulk() {
+        let (server, client) = setup().await;
+        let data = fake::vec![SaveTransaction; 2..5];
+        let created = SaveTransactionsResponse {
+            duplicate_import_ids: vec![],
+            ..Faker.fake()
+        };
+        Mock::given(method("POST"))
+            .and(path("/budgets/last-used/transactions"))
+            .and(body_json(serde_json::json!({ "transactions": data })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(save_transactions_body(&created)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let resp = client
+            .create_transactions(LAST_USED_BUDGET_ID, data)
+            .await
+            .unwrap();
+        assert_eq!(resp, created);
+    }
+
+    #[tokio::test]
+    async fn returns_duplicate_import_ids_skipped() {
+        let (server, client) = setup().await;
+        let data = fake::vec![SaveTransaction; 2];
+        let skipped = SaveTransactionsResponse {
+            transaction_ids: vec![],
+            transactions: vec![],
+            duplicate_import_ids: vec![
+                "YNAB:-294230:2015-12-30:1".to_string(),
+                "YNAB:-294230:2015-12-30:2".to_string(),
+            ],
+            server_knowledge: Faker.fake(),
+        };
+        Mock::given(method("POST"))
+            .and(path("/budgets/last-used/transactions"))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(save_transactions_body(&skipped)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let resp = client
+            .create_transactions(LAST_USED_BUDGET_ID, data)
+            .await
+            .unwrap();
+        assert!(resp.transactions.is_empty());
+        assert_eq!(resp.duplicate_import_ids, skipped.duplicate_import_ids);
+    }
+
+    #[tokio::test]
+    async fn returns_api_error_of_rejected_transactions() {
+        let (server, client) = setup().await;
+        Mock::given(method("POST"))
+            .and(path("/budgets/last-used/transactions"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": {
+                    "id": "400",
+                    "name": "bad_request",
+                    "detail": "account_id is required"
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let err = client
+            .create_transactions(LAST_USED_BUDGET_ID, fake::vec![SaveTransaction; 1])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, crate::Error::Api(_)));
+    }
+}
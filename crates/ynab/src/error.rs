@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 pub type YnabResult<T> = std::result::Result<T, Error>;
@@ -10,6 +12,12 @@ pub enum Error {
     Api(ApiError),
     #[error("{0:?}")]
     Conversion(#[from] serde_json::Error),
+    #[error("YNAB API rate limit reached, retry after {retry_after:?}")]
+    RateLimited { retry_after: Duration },
+    #[error("YNAB OAuth Error: {0:?}")]
+    OAuth(OAuthError),
+    #[error("YNAB OAuth is not configured on this client")]
+    OAuthNotConfigured,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,12 +28,137 @@ pub struct ApiError {
 }
 
 impl ApiError {
+    pub fn kind(&self) -> ApiErrorKind {
+        ApiErrorKind::from(self.id.as_str())
+    }
+
     pub fn is_resource_not_found(&self) -> bool {
-        self.id == "404.2"
+        self.kind() == ApiErrorKind::ResourceNotFound
     }
 }
 
+/// Every error documented by YNAB's API, identified by the `id` of the returned error.
+/// See https://api.youneedabudget.com/#errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    /// `400`: The request could not be understood due to malformed syntax or validation error(s).
+    BadRequest,
+    /// `401`: Missing, invalid, revoked or expired access token.
+    NotAuthorized,
+    /// `403.1`: The subscription for this account has lapsed.
+    SubscriptionLapsed,
+    /// `403.2`: The trial for this account has expired.
+    TrialExpired,
+    /// `403.3`: The access token does not have the required scope for this request.
+    UnauthorizedScope,
+    /// `403.4`: The request will exceed one or more data limits in place to prevent abuse.
+    DataLimitReached,
+    /// `404.1`: The specified URI does not exist.
+    NotFound,
+    /// `404.2`: The requested resource (budget, account, category...) was not found.
+    ResourceNotFound,
+    /// `409`: The resource cannot be saved because it conflicts with an existing resource.
+    Conflict,
+    /// `429`: The access token has exceeded its rate limit.
+    TooManyRequests,
+    /// `500`: An unexpected error occurred on YNAB's side.
+    InternalServerError,
+    /// `503`: YNAB's API is temporarily disabled or a request timed out.
+    ServiceUnavailable,
+    /// Any error id that is not documented by YNAB.
+    Unknown,
+}
+
+impl From<&str> for ApiErrorKind {
+    fn from(id: &str) -> Self {
+        match id {
+            "400" => Self::BadRequest,
+            "401" => Self::NotAuthorized,
+            "403.1" => Self::SubscriptionLapsed,
+            "403.2" => Self::TrialExpired,
+            "403.3" => Self::UnauthorizedScope,
+            "403.4" => Self::DataLimitReached,
+            "404.1" => Self::NotFound,
+            "404.2" => Self::ResourceNotFound,
+            "409" => Self::Conflict,
+            "429" => Self::TooManyRequests,
+            "500" => Self::InternalServerError,
+            "503" => Self::ServiceUnavailable,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Error {
+    /// Returns the kind of the API error, if the error was returned by YNAB's API.
+    /// A `RateLimited` error is reported as `ApiErrorKind::TooManyRequests`.
+    pub fn api_error_kind(&self) -> Option<ApiErrorKind> {
+        match self {
+            Error::Api(api_err) => Some(api_err.kind()),
+            Error::RateLimited { .. } => Some(ApiErrorKind::TooManyRequests),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned by YNAB's OAuth token endpoint, i.e. an invalid authorization code or a revoked refresh token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuthError {
+    pub error: String,
+    #[serde(default)]
+    pub error_description: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiErrorResponse {
     pub error: ApiError,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(id: &str) -> ApiError {
+        ApiError {
+            id: id.to_string(),
+            name: "name".to_string(),
+            detail: "detail".to_string(),
+        }
+    }
+
+    #[test]
+    fn documented_ids_are_mapped_to_their_kind() {
+        let expected = [
+            ("400", ApiErrorKind::BadRequest),
+            ("401", ApiErrorKind::NotAuthorized),
+            ("403.1", ApiErrorKind::SubscriptionLapsed),
+            ("403.2", ApiErrorKind::TrialExpired),
+            ("403.3", ApiErrorKind::UnauthorizedScope),
+            ("403.4", ApiErrorKind::DataLimitReached),
+            ("404.1", ApiErrorKind::NotFound),
+            ("404.2", ApiErrorKind::ResourceNotFound),
+            ("409", ApiErrorKind::Conflict),
+            ("429", ApiErrorKind::TooManyRequests),
+            ("500", ApiErrorKind::InternalServerError),
+            ("503", ApiErrorKind::ServiceUnavailable),
+        ];
+
+        for (id, kind) in expected {
+            assert_eq!(api_error(id).kind(), kind);
+        }
+    }
+
+    #[test]
+    fn undocumented_id_is_unknown() {
+        assert_eq!(api_error("418").kind(), ApiErrorKind::Unknown);
+    }
+
+    #[test]
+    fn rate_limited_is_too_many_requests() {
+        let err = Error::RateLimited {
+            retry_after: Duration::from_secs(1),
+        };
+        assert_eq!(err.api_error_kind(), Some(ApiErrorKind::TooManyRequests));
+        assert!(api_error("404.2").is_resource_not_found());
+    }
+}
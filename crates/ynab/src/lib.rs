@@ -2,5 +2,5 @@ mod client;
 mod error;
 pub mod types;
 pub use client::*;
-pub use error::{ApiError, Error, YnabResult};
+pub use error::{ApiError, ApiErrorKind, Error, OAuthError, YnabResult};
 pub use types::*;
@@ -20,6 +20,22 @@ pub struct CurrencyFormat {
     pub display_symbol: bool,
 }
 
+/// Defaults to YNAB's format for US dollars.
+impl Default for CurrencyFormat {
+    fn default() -> Self {
+        Self {
+            iso_code: "USD".to_string(),
+            example_format: "123,456.78".to_string(),
+            decimal_digits: 2,
+            decimal_separator: ".".to_string(),
+            symbol_first: true,
+            group_separator: ",".to_string(),
+            currency_symbol: "$".to_string(),
+            display_symbol: true,
+        }
+    }
+}
+
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DateFormat {
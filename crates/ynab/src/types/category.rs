@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 use uuid::Uuid;
 
+use crate::Milliunits;
+
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
 #[cfg_attr(feature = "sqlx-postgres", derive(sqlx::FromRow))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -17,11 +19,11 @@ pub struct Category {
     pub original_category_group_id: Option<Uuid>,
     pub note: Option<String>,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0..100000"))]
-    pub budgeted: i64,
+    pub budgeted: Milliunits,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-100000..100000"))]
-    pub activity: i64,
+    pub activity: Milliunits,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0..100000"))]
-    pub balance: i64,
+    pub balance: Milliunits,
     pub goal_type: Option<GoalType>,
     /// A day offset modifier for the goal's due date. When goal_cadence is 2 (Weekly),
     /// this value specifies which day of the week the goal is due (0 = Sunday, 6 = Saturday).
@@ -46,7 +48,7 @@ pub struct Category {
     #[cfg_attr(any(feature = "testutils", test), dummy(default))]
     pub goal_creation_month: Option<chrono::NaiveDate>,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0..100000"))]
-    pub goal_target: Option<i64>,
+    pub goal_target: Option<Milliunits>,
     #[cfg_attr(any(feature = "testutils", test), dummy(default))]
     pub goal_target_month: Option<chrono::NaiveDate>,
     #[cfg_attr(any(feature = "testutils", test), dummy(faker = "0..100"))]
@@ -164,7 +166,7 @@ impl FromStr for GoalType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 /// See https://api.youneedabudget.com/v1#/Categories/updateMonthCategory
 pub struct SaveMonthCategory {
-    pub budgeted: i64,
+    pub budgeted: Milliunits,
 }
 
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
@@ -0,0 +1,475 @@
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::CurrencyFormat;
+
+/// An amount of money in YNAB's milliunits format, i.e. `1234560` is `1234.56`.
+/// See https://api.ynab.com/#formats
+///
+/// Arithmetic operators saturate at the bounds instead of overflowing. Use the `checked_*` methods
+/// when the amounts come from user input, to report the overflow instead.
+#[cfg_attr(
+    any(feature = "sqlx-postgres", feature = "sqlx-sqlite"),
+    derive(sqlx::Type),
+    sqlx(transparent)
+)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Milliunits(i64);
+
+const MILLIUNITS_PER_UNIT: i64 = 1000;
+
+impl Milliunits {
+    pub const ZERO: Milliunits = Milliunits(0);
+
+    pub const fn new(milliunits: i64) -> Self {
+        Self(milliunits)
+    }
+
+    pub const fn get(self) -> i64 {
+        self.0
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    pub fn checked_div(self, rhs: i64) -> Option<Self> {
+        self.0.checked_div(rhs).map(Self)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+
+    /// Sums the amounts, or `None` as soon as the total overflows.
+    pub fn checked_sum<I: IntoIterator<Item = Self>>(amounts: I) -> Option<Self> {
+        amounts
+            .into_iter()
+            .try_fold(Self::ZERO, |total, amount| total.checked_add(amount))
+    }
+
+    pub fn abs(self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Multiplies by a ratio, i.e. a proportion or a percentage, rounding to the nearest milliunit.
+    pub fn mul_f64(self, ratio: f64) -> Self {
+        Self((self.0 as f64 * ratio).round() as i64)
+    }
+
+    /// Returns the amount in units, i.e. `1234.56`. Only meant for display or ratios, as it loses precision.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / MILLIUNITS_PER_UNIT as f64
+    }
+
+    /// Formats the amount the way YNAB displays it for a budget using `currency_format`.
+    pub fn format(self, currency_format: &CurrencyFormat) -> String {
+        let decimal_digits = currency_format.decimal_digits.clamp(0, 3) as u32;
+        self.format_with_digits(currency_format, decimal_digits)
+    }
+
+    /// Same as `format`, but without any decimals.
+    pub fn format_rounded(self, currency_format: &CurrencyFormat) -> String {
+        self.format_with_digits(currency_format, 0)
+    }
+
+    fn format_with_digits(self, currency_format: &CurrencyFormat, decimal_digits: u32) -> String {
+        // Round half away from zero to `decimal_digits`, in i128 so i64::MIN can't overflow.
+        let divisor = 10_i128.pow(3 - decimal_digits);
+        let milliunits = i128::from(self.0);
+        let rounded = (milliunits.abs() + divisor / 2) / divisor;
+        let scale = 10_i128.pow(decimal_digits);
+        let (units, decimals) = (rounded / scale, rounded % scale);
+
+        let digits = units.to_string();
+        let mut number = String::with_capacity(digits.len() * 2);
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                number.push_str(&currency_format.group_separator);
+            }
+            number.push(c);
+        }
+        if decimal_digits > 0 {
+            number.push_str(&currency_format.decimal_separator);
+            number.push_str(&format!(
+                "{:0width$}",
+                decimals,
+                width = decimal_digits as usize
+            ));
+        }
+
+        let number = match currency_format.display_symbol {
+            true if currency_format.symbol_first => {
+                format!("{}{}", currency_format.currency_symbol, number)
+            }
+            true => format!("{}{}", number, currency_format.currency_symbol),
+            false => number,
+        };
+
+        match rounded != 0 && self.is_negative() {
+            true => format!("-{}", number),
+            false => number,
+        }
+    }
+
+    /// Parses an amount formatted with `currency_format`, i.e. `$1,234.56`, `-1 234,56 €` or `(1,234.56)`.
+    /// The currency symbol and group separators are optional. At most one sign is accepted.
+    pub fn parse(s: &str, currency_format: &CurrencyFormat) -> Result<Self, ParseMilliunitsError> {
+        let input = s;
+        let invalid = || ParseMilliunitsError::Invalid(input.to_string());
+        let mut s = s.trim();
+        let mut negative = false;
+        if let Some(inner) = s.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            negative = true;
+            s = inner.trim();
+        }
+        if let Some(rest) = s.strip_prefix('-') {
+            if negative {
+                return Err(invalid());
+            }
+            negative = true;
+            s = rest.trim();
+        }
+        if !currency_format.currency_symbol.is_empty() {
+            s = s
+                .strip_prefix(currency_format.currency_symbol.as_str())
+                .or_else(|| s.strip_suffix(currency_format.currency_symbol.as_str()))
+                .unwrap_or(s)
+                .trim();
+        }
+        if let Some(rest) = s.strip_prefix('-') {
+            if negative {
+                return Err(invalid());
+            }
+            negative = true;
+            s = rest.trim();
+        }
+        if s.starts_with('-') {
+            return Err(invalid());
+        }
+
+        let number = match currency_format.group_separator.as_str() {
+            "" => s.to_string(),
+            sep => s.replace(sep, ""),
+        };
+        let number = number.replace(currency_format.decimal_separator.as_str(), ".");
+        let milliunits = parse_decimal(&number)?;
+
+        match negative {
+            true => milliunits
+                .checked_neg()
+                .ok_or(ParseMilliunitsError::Overflow),
+            false => Ok(milliunits),
+        }
+    }
+}
+
+/// Parses a plain decimal number, i.e. `-1234.56`, with at most 3 decimals.
+fn parse_decimal(s: &str) -> Result<Milliunits, ParseMilliunitsError> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (units, decimals) = s.split_once('.').unwrap_or((s, ""));
+
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if (units.is_empty() && decimals.is_empty()) || !is_digits(units) || !is_digits(decimals) {
+        return Err(ParseMilliunitsError::Invalid(s.to_string()));
+    }
+    if decimals.len() > 3 {
+        return Err(ParseMilliunitsError::TooPrecise(s.to_string()));
+    }
+
+    let units = match units {
+        "" => 0,
+        units => units
+            .parse::<i64>()
+            .map_err(|_| ParseMilliunitsError::Overflow)?,
+    };
+    let decimals = format!("{:0<3}", decimals)
+        .parse::<i64>()
+        .map_err(|_| ParseMilliunitsError::Invalid(s.to_string()))?;
+
+    let milliunits = units
+        .checked_mul(MILLIUNITS_PER_UNIT)
+        .and_then(|u| u.checked_add(decimals))
+        .ok_or(ParseMilliunitsError::Overflow)?;
+
+    Ok(Milliunits(if negative { -milliunits } else { milliunits }))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseMilliunitsError {
+    #[error("`{0}` is not a valid amount")]
+    Invalid(String),
+    #[error("`{0}` has more than 3 decimals")]
+    TooPrecise(String),
+    #[error("amount is too large")]
+    Overflow,
+}
+
+/// Parses a plain decimal amount in units, i.e. `1234.56` or `-0.5`.
+impl FromStr for Milliunits {
+    type Err = ParseMilliunitsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_decimal(s.trim())
+    }
+}
+
+/// Displays the amount in units with all 3 decimals, i.e. `1234.560`.
+impl fmt::Display for Milliunits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let abs = i128::from(self.0).abs();
+        write!(f, "{}{}.{:03}", sign, abs / 1000, abs % 1000)
+    }
+}
+
+impl From<i64> for Milliunits {
+    fn from(milliunits: i64) -> Self {
+        Self(milliunits)
+    }
+}
+
+impl From<Milliunits> for i64 {
+    fn from(milliunits: Milliunits) -> Self {
+        milliunits.0
+    }
+}
+
+impl Add for Milliunits {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Milliunits {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Milliunits {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl SubAssign for Milliunits {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Milliunits {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
+/// Panics when dividing by zero, like integers do.
+impl Div<i64> for Milliunits {
+    type Output = Self;
+
+    fn div(self, rhs: i64) -> Self::Output {
+        Self(self.0.saturating_div(rhs))
+    }
+}
+
+impl Neg for Milliunits {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.saturating_neg())
+    }
+}
+
+impl Sum for Milliunits {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Milliunits> for Milliunits {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(any(feature = "testutils", test))]
+impl fake::Dummy<fake::Faker> for Milliunits {
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(config: &fake::Faker, rng: &mut R) -> Self {
+        Self(fake::Dummy::dummy_with_rng(config, rng))
+    }
+}
+
+#[cfg(any(feature = "testutils", test))]
+impl fake::Dummy<std::ops::Range<i64>> for Milliunits {
+    fn dummy_with_rng<R: rand::Rng + ?Sized>(config: &std::ops::Range<i64>, rng: &mut R) -> Self {
+        Self(fake::Dummy::dummy_with_rng(config, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd() -> CurrencyFormat {
+        CurrencyFormat::default()
+    }
+
+    fn eur() -> CurrencyFormat {
+        CurrencyFormat {
+            iso_code: "EUR".to_string(),
+            example_format: "123 456,78".to_string(),
+            decimal_digits: 2,
+            decimal_separator: ",".to_string(),
+            symbol_first: false,
+            group_separator: " ".to_string(),
+            currency_symbol: "€".to_string(),
+            display_symbol: true,
+        }
+    }
+
+    #[test]
+    fn formats_with_currency_format() {
+        assert_eq!(Milliunits::new(1234567890).format(&usd()), "$1,234,567.89");
+        assert_eq!(Milliunits::new(-1234560).format(&usd()), "-$1,234.56");
+        assert_eq!(Milliunits::new(1234560).format(&eur()), "1 234,56€");
+        assert_eq!(Milliunits::new(0).format(&usd()), "$0.00");
+        assert_eq!(Milliunits::new(-4).format(&usd()), "$0.00");
+        assert!(Milliunits::new(i64::MIN)
+            .format(&usd())
+            .starts_with("-$9,223,372"));
+    }
+
+    #[test]
+    fn rounds_half_away_from_zero() {
+        assert_eq!(Milliunits::new(1005).format(&usd()), "$1.01");
+        assert_eq!(Milliunits::new(-1005).format(&usd()), "-$1.01");
+        assert_eq!(Milliunits::new(1499500).format_rounded(&usd()), "$1,500");
+    }
+
+    #[test]
+    fn parses_what_it_formats() {
+        for amount in [0, 10, -10, 1234560, -987654320, 1_000_000_000] {
+            let milliunits = Milliunits::new(amount);
+            assert_eq!(
+                Milliunits::parse(&milliunits.format(&usd()), &usd()),
+                Ok(milliunits)
+            );
+            assert_eq!(
+                Milliunits::parse(&milliunits.format(&eur()), &eur()),
+                Ok(milliunits)
+            );
+        }
+        assert_eq!(
+            Milliunits::parse("($1,234.56)", &usd()),
+            Ok(Milliunits::new(-1234560))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_more_than_one_sign() {
+        assert_eq!(Milliunits::parse("-$5", &usd()), Ok(Milliunits::new(-5000)));
+        assert_eq!(Milliunits::parse("$-5", &usd()), Ok(Milliunits::new(-5000)));
+        for input in ["--5", "-$-5", "$--5", "(-5)", "-(5)", "- -5"] {
+            assert!(
+                matches!(
+                    Milliunits::parse(input, &usd()),
+                    Err(ParseMilliunitsError::Invalid(_))
+                ),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_does_not_lose_precision() {
+        assert_eq!("123456.789".parse(), Ok(Milliunits::new(123456789)));
+        assert_eq!("-0.5".parse(), Ok(Milliunits::new(-500)));
+        assert_eq!("16777217.01".parse(), Ok(Milliunits::new(16777217010)));
+        assert!(matches!(
+            "1.2345".parse::<Milliunits>(),
+            Err(ParseMilliunitsError::TooPrecise(_))
+        ));
+        assert!(matches!(
+            "abc".parse::<Milliunits>(),
+            Err(ParseMilliunitsError::Invalid(_))
+        ));
+        assert_eq!(
+            "99999999999999999".parse::<Milliunits>(),
+            Err(ParseMilliunitsError::Overflow)
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic_detects_overflow() {
+        let max = Milliunits::new(i64::MAX);
+        assert_eq!(max.checked_add(Milliunits::new(1)), None);
+        assert_eq!(Milliunits::new(i64::MIN).checked_neg(), None);
+        assert_eq!(
+            Milliunits::new(1500).checked_mul(2),
+            Some(Milliunits::new(3000))
+        );
+    }
+
+    #[test]
+    fn operators_saturate_instead_of_overflowing() {
+        let max = Milliunits::new(i64::MAX);
+        let min = Milliunits::new(i64::MIN);
+        assert_eq!(max + Milliunits::new(1), max);
+        assert_eq!(min - Milliunits::new(1), min);
+        assert_eq!(-min, max);
+        assert_eq!(min.abs(), max);
+        assert_eq!(max * 2, max);
+        assert_eq!(min * 2, min);
+        assert_eq!(min / -1, max);
+        assert_eq!(Milliunits::new(3000) / 2, Milliunits::new(1500));
+        assert_eq!([max, max].into_iter().sum::<Milliunits>(), max);
+        assert_eq!(Milliunits::checked_sum([max, Milliunits::new(1)]), None);
+        assert_eq!(
+            Milliunits::checked_sum([Milliunits::new(1), Milliunits::new(2)]),
+            Some(Milliunits::new(3))
+        );
+    }
+
+    #[test]
+    fn serializes_as_plain_milliunits() {
+        let milliunits = Milliunits::new(-1234560);
+        assert_eq!(serde_json::to_string(&milliunits).unwrap(), "-1234560");
+        assert_eq!(
+            serde_json::from_str::<Milliunits>("-1234560").unwrap(),
+            milliunits
+        );
+    }
+}
@@ -1,6 +1,7 @@
 mod account;
 mod budget;
 mod category;
+mod milliunits;
 mod month;
 mod payee;
 mod scheduled_transaction;
@@ -9,6 +10,7 @@ mod transaction;
 pub use account::*;
 pub use budget::*;
 pub use category::*;
+pub use milliunits::*;
 pub use month::*;
 pub use payee::*;
 pub use scheduled_transaction::*;
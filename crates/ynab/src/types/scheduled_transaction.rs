@@ -171,3 +171,21 @@ pub struct ScheduledTransactionsDetailDelta {
     pub scheduled_transactions: Vec<ScheduledTransactionDetail>,
     pub server_knowledge: i64,
 }
+
+#[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// See https://api.youneedabudget.com/v1#/Scheduled_Transactions/createScheduledTransaction
+pub struct SaveScheduledTransaction {
+    pub account_id: Uuid,
+    /// The first date of the recurrence. Must be a future date no more than 5 years out.
+    #[cfg_attr(any(feature = "testutils", test), dummy(default))]
+    pub date: chrono::NaiveDate,
+    #[cfg_attr(any(feature = "testutils", test), dummy(faker = "-100000..100000"))]
+    pub amount: i64,
+    pub payee_id: Option<Uuid>,
+    pub payee_name: Option<String>,
+    pub category_id: Option<Uuid>,
+    pub memo: Option<String>,
+    pub flag_color: Option<String>,
+    pub frequency: RecurFrequency,
+}
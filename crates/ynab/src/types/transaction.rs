@@ -1,5 +1,6 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
-use std::{fmt, str::FromStr};
+use std::{collections::HashMap, fmt, str::FromStr};
 use uuid::Uuid;
 
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
@@ -22,7 +23,7 @@ pub struct TransactionSummary {
     pub transfer_account_id: Option<Uuid>,
     pub transfer_transaction_id: Option<Uuid>,
     pub matched_transaction_id: Option<Uuid>,
-    pub import_id: Option<Uuid>,
+    pub import_id: Option<String>,
     pub import_payee_name: Option<String>,
     pub import_payee_name_original: Option<String>,
     pub debt_transaction_type: Option<DebtTransactionType>,
@@ -48,7 +49,7 @@ pub struct BaseTransactionDetail {
     pub transfer_account_id: Option<Uuid>,
     pub transfer_transaction_id: Option<Uuid>,
     pub matched_transaction_id: Option<Uuid>,
-    pub import_id: Option<Uuid>,
+    pub import_id: Option<String>,
     pub import_payee_name: Option<String>,
     pub import_payee_name_original: Option<String>,
     pub debt_transaction_type: Option<DebtTransactionType>,
@@ -250,10 +251,48 @@ pub struct SaveTransaction {
     pub cleared: ClearedType,
     pub approved: bool,
     pub flag_color: Option<String>,
-    pub import_id: Option<Uuid>,
+    pub import_id: Option<String>,
     pub subtransactions: Option<Vec<SaveSubTransaction>>,
 }
 
+#[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// See https://api.youneedabudget.com/v1#/Transactions/createTransaction
+pub struct SaveTransactionsResponse {
+    pub transaction_ids: Vec<Uuid>,
+    pub transactions: Vec<TransactionDetail>,
+    /// The `import_id`s that were skipped because a transaction with the same one
+    /// already exists on the same account.
+    pub duplicate_import_ids: Vec<String>,
+    pub server_knowledge: i64,
+}
+
+/// Builds an `import_id` the same way YNAB does for its own imports, i.e. `YNAB:<milliunits>:<date>:<occurrence>`.
+/// The occurrence starts at 1 and distinguishes transactions of the same amount on the same date.
+/// e.g. `YNAB:-294230:2015-12-30:1`
+pub fn import_id(amount: i64, date: NaiveDate, occurrence: u32) -> String {
+    format!("YNAB:{}:{}:{}", amount, date.format("%Y-%m-%d"), occurrence)
+}
+
+/// Generates YNAB-compatible `import_id`s for a batch of transactions of a single account,
+/// keeping track of the occurrence of each amount and date pair.
+#[derive(Debug, Clone, Default)]
+pub struct ImportIdGenerator {
+    occurrences: HashMap<(i64, NaiveDate), u32>,
+}
+
+impl ImportIdGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_id(&mut self, amount: i64, date: NaiveDate) -> String {
+        let occurrence = self.occurrences.entry((amount, date)).or_default();
+        *occurrence += 1;
+        import_id(amount, date, *occurrence)
+    }
+}
+
 #[cfg_attr(any(feature = "testutils", test), derive(fake::Dummy))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 /// See https://api.youneedabudget.com/v1#/Transactions/updateTransactions
@@ -271,7 +310,7 @@ pub struct UpdateTransaction {
     pub cleared: ClearedType,
     pub approved: bool,
     pub flag_color: Option<String>,
-    pub import_id: Option<Uuid>,
+    pub import_id: Option<String>,
     pub subtransactions: Option<Vec<SaveSubTransaction>>,
 }
 
@@ -303,3 +342,28 @@ pub struct SaveSubTransaction {
     pub category_id: Option<Uuid>,
     pub memo: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_id_has_ynab_format() {
+        let date = NaiveDate::from_ymd_opt(2015, 12, 30).unwrap();
+        assert_eq!(import_id(-294230, date, 1), "YNAB:-294230:2015-12-30:1");
+    }
+
+    #[test]
+    fn import_id_generator_increments_occurrence_of_same_amount_and_date() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let mut generator = ImportIdGenerator::new();
+
+        assert_eq!(generator.next_id(-1000, date), "YNAB:-1000:2024-01-05:1");
+        assert_eq!(generator.next_id(-1000, date), "YNAB:-1000:2024-01-05:2");
+        assert_eq!(generator.next_id(2500, date), "YNAB:2500:2024-01-05:1");
+        assert_eq!(
+            generator.next_id(-1000, date.succ_opt().unwrap()),
+            "YNAB:-1000:2024-01-06:1"
+        );
+    }
+}